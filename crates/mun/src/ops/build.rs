@@ -80,6 +80,8 @@ fn compiler_options(matches: &ArgMatches) -> Result<mun_compiler::Config, anyhow
             .value_of("target")
             .map_or_else(Target::host_target, Target::search)?,
         optimization_lvl,
+        // Arithmetic is only checked in debug builds
+        arithmetic_checks: optimization_lvl == mun_compiler::OptimizationLevel::None,
         out_dir: None,
         display_color,
     })
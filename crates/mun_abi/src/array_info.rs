@@ -0,0 +1,78 @@
+use crate::{StructMemoryKind, TypeInfo};
+use std::convert::TryInto;
+
+/// Represents an array declaration.
+#[repr(C)]
+pub struct ArrayInfo {
+    /// Type information of the array's elements
+    pub(crate) element_type: *const TypeInfo,
+    /// The number of elements of a fixed-size array. Always zero for a garbage collected array,
+    /// whose length is only known at runtime.
+    pub(crate) length: u64,
+    /// Array memory kind
+    ///
+    /// A fixed-size array (`[T; N]`) is a `Value` type, whereas a dynamically sized array (`[T]`) is
+    /// allocated by the garbage collector.
+    pub memory_kind: StructMemoryKind,
+}
+
+impl ArrayInfo {
+    /// Returns the type information of the array's elements.
+    pub fn element_type(&self) -> &TypeInfo {
+        unsafe { &*self.element_type }
+    }
+
+    /// Returns the number of elements of a fixed-size array, or `None` if the length is only known
+    /// at runtime.
+    pub fn length(&self) -> Option<usize> {
+        match self.memory_kind {
+            StructMemoryKind::Value => Some(
+                self.length
+                    .try_into()
+                    .expect("cannot convert array length to platform size"),
+            ),
+            StructMemoryKind::GC => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_utils::{fake_array_info, fake_type_info, FAKE_TYPE_NAME},
+        StructMemoryKind, TypeGroup,
+    };
+    use std::ffi::CString;
+
+    #[test]
+    fn test_array_info_element_type() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let type_info = fake_type_info(&type_name, TypeGroup::FundamentalTypes, 1, 1);
+
+        let array_info = fake_array_info(&type_info, 0, StructMemoryKind::GC);
+
+        assert_eq!(array_info.element_type(), &type_info);
+    }
+
+    #[test]
+    fn test_array_info_length_gc() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let type_info = fake_type_info(&type_name, TypeGroup::FundamentalTypes, 1, 1);
+
+        let array_info = fake_array_info(&type_info, 0, StructMemoryKind::GC);
+
+        assert_eq!(array_info.memory_kind, StructMemoryKind::GC);
+        assert_eq!(array_info.length(), None);
+    }
+
+    #[test]
+    fn test_array_info_length_value() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let type_info = fake_type_info(&type_name, TypeGroup::FundamentalTypes, 1, 1);
+
+        let array_info = fake_array_info(&type_info, 3, StructMemoryKind::Value);
+
+        assert_eq!(array_info.memory_kind, StructMemoryKind::Value);
+        assert_eq!(array_info.length(), Some(3));
+    }
+}
@@ -0,0 +1,166 @@
+use crate::StructInfo;
+use std::{convert::TryInto, ffi::CStr, os::raw::c_char, slice, str};
+
+/// Represents an enum declaration.
+///
+/// An enum is stored as its discriminant, followed by the fields of the active variant. Enums
+/// always use value semantics.
+#[repr(C)]
+pub struct EnumInfo {
+    /// Enum variants' names
+    pub variant_names: *const *const c_char,
+    /// Enum variants' fields. The offsets of the fields are relative to the start of the enum.
+    pub(crate) variant_types: *const StructInfo,
+    /// Number of variants
+    pub(crate) num_variants: u16,
+    /// The size of the discriminant in bytes
+    pub(crate) discriminant_size: u8,
+}
+
+impl EnumInfo {
+    /// Returns the enum's variant names.
+    pub fn variant_names(&self) -> impl Iterator<Item = &str> {
+        let variant_names = if self.num_variants == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.variant_names, self.num_variants as usize) }
+        };
+
+        variant_names
+            .iter()
+            .map(|n| unsafe { str::from_utf8_unchecked(CStr::from_ptr(*n).to_bytes()) })
+    }
+
+    /// Returns the fields of the enum's variants.
+    pub fn variant_types(&self) -> &[StructInfo] {
+        if self.num_variants == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.variant_types, self.num_variants as usize) }
+        }
+    }
+
+    /// Returns the number of enum variants.
+    pub fn num_variants(&self) -> usize {
+        self.num_variants.into()
+    }
+
+    /// Returns the size of the discriminant in bytes.
+    pub fn discriminant_size(&self) -> usize {
+        self.discriminant_size.into()
+    }
+
+    /// Reads the discriminant of the enum value stored at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of the enum described by this `EnumInfo`.
+    pub unsafe fn read_discriminant(&self, ptr: *const u8) -> usize {
+        match self.discriminant_size {
+            1 => (*ptr).into(),
+            2 => (*ptr.cast::<u16>()).into(),
+            size => unreachable!("invalid discriminant size: {}", size),
+        }
+    }
+
+    /// Writes the `discriminant` of the enum value stored at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to memory that can hold a value of the enum described by this `EnumInfo`.
+    pub unsafe fn write_discriminant(&self, ptr: *mut u8, discriminant: usize) {
+        match self.discriminant_size {
+            1 => *ptr = discriminant.try_into().expect("invalid discriminant"),
+            2 => *ptr.cast::<u16>() = discriminant.try_into().expect("invalid discriminant"),
+            size => unreachable!("invalid discriminant size: {}", size),
+        }
+    }
+
+    /// Returns the index of the variant matching the specified `variant_name`.
+    pub fn find_variant_index(
+        type_name: &str,
+        enum_info: &EnumInfo,
+        variant_name: &str,
+    ) -> Result<usize, String> {
+        enum_info
+            .variant_names()
+            .enumerate()
+            .find(|(_, name)| *name == variant_name)
+            .map(|(idx, _)| idx)
+            .ok_or_else(|| {
+                format!(
+                    "Enum `{}` does not contain variant `{}`.",
+                    type_name, variant_name
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnumInfo;
+    use crate::{
+        test_utils::{
+            fake_enum_info, fake_struct_info, fake_type_info, FAKE_FIELD_NAME, FAKE_TYPE_NAME,
+            FAKE_VARIANT_NAME,
+        },
+        StructMemoryKind, TypeGroup,
+    };
+    use std::ffi::CString;
+
+    #[test]
+    fn test_enum_info_variants_none() {
+        let enum_info = fake_enum_info(&[], &[], 1);
+
+        assert_eq!(enum_info.variant_names().count(), 0);
+        assert_eq!(enum_info.variant_types().len(), 0);
+        assert_eq!(enum_info.num_variants(), 0);
+    }
+
+    #[test]
+    fn test_enum_info_variants_some() {
+        let variant_name = CString::new(FAKE_VARIANT_NAME).expect("Invalid fake variant name.");
+        let field_name = CString::new(FAKE_FIELD_NAME).expect("Invalid fake field name.");
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let type_info = fake_type_info(&type_name, TypeGroup::FundamentalTypes, 32, 4);
+
+        let field_names = &[field_name.as_ptr()];
+        let field_types = &[&type_info];
+        let field_offsets = &[4];
+        let variant_type = fake_struct_info(
+            field_names,
+            field_types,
+            field_offsets,
+            StructMemoryKind::Value,
+        );
+
+        let variant_names = &[variant_name.as_ptr()];
+        let variant_types = [variant_type];
+        let enum_info = fake_enum_info(variant_names, &variant_types, 1);
+
+        for (lhs, rhs) in enum_info.variant_names().zip([FAKE_VARIANT_NAME].iter()) {
+            assert_eq!(lhs, *rhs)
+        }
+        assert_eq!(enum_info.num_variants(), 1);
+        assert_eq!(enum_info.variant_types()[0].field_types(), field_types);
+        assert_eq!(enum_info.variant_types()[0].field_offsets(), field_offsets);
+        assert_eq!(
+            EnumInfo::find_variant_index(FAKE_TYPE_NAME, &enum_info, FAKE_VARIANT_NAME),
+            Ok(0)
+        );
+        assert!(EnumInfo::find_variant_index(FAKE_TYPE_NAME, &enum_info, "Unknown").is_err());
+    }
+
+    #[test]
+    fn test_enum_info_discriminant() {
+        let enum_info = fake_enum_info(&[], &[], 2);
+        assert_eq!(enum_info.discriminant_size(), 2);
+
+        let mut memory = [0u16; 2];
+        let ptr = memory.as_mut_ptr().cast::<u8>();
+        unsafe {
+            enum_info.write_discriminant(ptr, 300);
+            assert_eq!(enum_info.read_discriminant(ptr), 300);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::TypeInfo;
+use std::{
+    ffi::{c_void, CStr},
+    os::raw::c_char,
+    str,
+};
+
+/// Represents a global value of a module: either a constant or a static. A global definition
+/// contains the name, type, and a pointer to the memory that holds the value.
+///
+/// The value of a global must only be modified if it is mutable.
+#[repr(C)]
+#[derive(Clone)]
+pub struct GlobalDefinition {
+    /// Global name
+    pub(crate) name: *const c_char,
+    /// The type of the value
+    pub(crate) type_info: *const TypeInfo,
+    /// Pointer to the value
+    pub ptr: *mut c_void,
+    /// Whether the value can be modified, which is only the case for `static mut`
+    pub is_mutable: bool,
+}
+
+impl GlobalDefinition {
+    /// Returns the global's name.
+    pub fn name(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(CStr::from_ptr(self.name).to_bytes()) }
+    }
+
+    /// Returns the type of the global's value.
+    pub fn type_info(&self) -> &TypeInfo {
+        // Safety: `type_info` is never null
+        unsafe { &*self.type_info }
+    }
+}
+
+unsafe impl Send for GlobalDefinition {}
+unsafe impl Sync for GlobalDefinition {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{fake_global_definition, fake_type_info, FAKE_GLOBAL_NAME};
+    use crate::TypeGroup;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_global_definition_name() {
+        let type_name = CString::new("core::i32").expect("Invalid fake type name.");
+        let type_info = fake_type_info(&type_name, TypeGroup::FundamentalTypes, 32, 4);
+
+        let global_name = CString::new(FAKE_GLOBAL_NAME).expect("Invalid fake global name.");
+        let global = fake_global_definition(&global_name, &type_info, true);
+
+        assert_eq!(global.name(), FAKE_GLOBAL_NAME);
+    }
+
+    #[test]
+    fn test_global_definition_type_info() {
+        let type_name = CString::new("core::i32").expect("Invalid fake type name.");
+        let type_info = fake_type_info(&type_name, TypeGroup::FundamentalTypes, 32, 4);
+
+        let global_name = CString::new(FAKE_GLOBAL_NAME).expect("Invalid fake global name.");
+        let global = fake_global_definition(&global_name, &type_info, false);
+
+        assert_eq!(global.type_info(), &type_info);
+        assert!(!global.is_mutable);
+    }
+}
@@ -5,9 +5,12 @@
 #![warn(missing_docs)]
 
 // C bindings can be manually generated by running `cargo gen-abi`.
+mod array_info;
 mod assembly_info;
 mod dispatch_table;
+mod enum_info;
 mod function_info;
+mod global_info;
 mod module_info;
 mod static_type_map;
 mod struct_info;
@@ -16,12 +19,15 @@ mod type_info;
 #[cfg(test)]
 mod test_utils;
 
+pub use array_info::ArrayInfo;
 pub use assembly_info::AssemblyInfo;
 pub use dispatch_table::DispatchTable;
+pub use enum_info::EnumInfo;
 pub use function_info::{
     FunctionDefinition, FunctionDefinitionStorage, FunctionPrototype, FunctionSignature,
     IntoFunctionDefinition,
 };
+pub use global_info::GlobalDefinition;
 pub use module_info::ModuleInfo;
 pub use struct_info::{StructInfo, StructMemoryKind};
 pub use type_info::{HasStaticTypeInfo, TypeGroup, TypeInfo};
@@ -35,7 +41,7 @@ pub mod prelude {
 
 /// Defines the current ABI version
 #[allow(clippy::zero_prefixed_literal)]
-pub const ABI_VERSION: u32 = 00_03_00;
+pub const ABI_VERSION: u32 = 00_06_00;
 /// Defines the name for the `get_info` function
 pub const GET_INFO_FN_NAME: &str = "get_info";
 /// Defines the name for the `get_version` function
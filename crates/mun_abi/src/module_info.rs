@@ -1,4 +1,4 @@
-use crate::{FunctionDefinition, TypeInfo};
+use crate::{FunctionDefinition, GlobalDefinition, TypeInfo};
 use std::{ffi::CStr, os::raw::c_char, slice, str};
 
 /// Represents a module declaration.
@@ -10,10 +10,14 @@ pub struct ModuleInfo {
     pub(crate) functions: *const FunctionDefinition,
     /// Module types
     pub(crate) types: *const *const TypeInfo,
+    /// Module constants and statics
+    pub(crate) globals: *const GlobalDefinition,
     /// Number of module functions
     pub num_functions: u32,
     /// Number of module types
     pub num_types: u32,
+    /// Number of module constants and statics
+    pub num_globals: u32,
 }
 
 impl ModuleInfo {
@@ -56,6 +60,15 @@ impl ModuleInfo {
             }
         }
     }
+
+    /// Returns the module's constants and statics.
+    pub fn globals(&self) -> &[GlobalDefinition] {
+        if self.num_globals == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.globals, self.num_globals as usize) }
+        }
+    }
 }
 
 unsafe impl Send for ModuleInfo {}
@@ -65,8 +78,9 @@ unsafe impl Sync for ModuleInfo {}
 mod tests {
     use crate::{
         test_utils::{
-            fake_fn_prototype, fake_module_info, fake_struct_info, fake_struct_type_info,
-            fake_type_info, FAKE_FN_NAME, FAKE_MODULE_PATH, FAKE_STRUCT_NAME, FAKE_TYPE_NAME,
+            fake_fn_prototype, fake_global_definition, fake_module_info, fake_struct_info,
+            fake_struct_type_info, fake_type_info, FAKE_FN_NAME, FAKE_GLOBAL_NAME,
+            FAKE_MODULE_PATH, FAKE_STRUCT_NAME, FAKE_TYPE_NAME,
         },
         FunctionDefinition, TypeGroup, TypeInfo,
     };
@@ -75,7 +89,7 @@ mod tests {
     #[test]
     fn test_module_info_path() {
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, &[], &[]);
+        let module = fake_module_info(&module_path, &[], &[], &[]);
 
         assert_eq!(module.path(), FAKE_MODULE_PATH);
     }
@@ -85,10 +99,32 @@ mod tests {
         let functions = &[];
         let types = &[];
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, functions, types);
+        let module = fake_module_info(&module_path, functions, types, &[]);
 
         assert_eq!(module.functions().len(), functions.len());
         assert_eq!(module.types().len(), types.len());
+        assert_eq!(module.globals().len(), 0);
+    }
+
+    #[test]
+    fn test_module_info_globals_some() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let type_info = fake_type_info(&type_name, TypeGroup::FundamentalTypes, 1, 1);
+
+        let global_name = CString::new(FAKE_GLOBAL_NAME).expect("Invalid fake global name.");
+        let globals = &[fake_global_definition(&global_name, &type_info, true)];
+
+        let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
+        let module = fake_module_info(&module_path, &[], &[], globals);
+
+        let result_globals = module.globals();
+        assert_eq!(result_globals.len(), globals.len());
+        for (lhs, rhs) in result_globals.iter().zip(globals.iter()) {
+            assert_eq!(lhs.name(), rhs.name());
+            assert_eq!(lhs.type_info(), rhs.type_info());
+            assert_eq!(lhs.ptr, rhs.ptr);
+            assert_eq!(lhs.is_mutable, rhs.is_mutable);
+        }
     }
 
     #[test]
@@ -112,7 +148,7 @@ mod tests {
         let types = &[unsafe { mem::transmute(&struct_type_info) }];
 
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, functions, types);
+        let module = fake_module_info(&module_path, functions, types, &[]);
 
         let result_functions = module.functions();
         assert_eq!(result_functions.len(), functions.len());
@@ -1,6 +1,7 @@
 use crate::{
-    AssemblyInfo, DispatchTable, FunctionDefinition, FunctionPrototype, FunctionSignature, Guid,
-    ModuleInfo, StructInfo, StructMemoryKind, TypeGroup, TypeInfo,
+    ArrayInfo, AssemblyInfo, DispatchTable, EnumInfo, FunctionDefinition, FunctionPrototype,
+    FunctionSignature, GlobalDefinition, Guid, ModuleInfo, StructInfo, StructMemoryKind, TypeGroup,
+    TypeInfo,
 };
 use std::{
     ffi::{c_void, CStr},
@@ -13,9 +14,11 @@ pub(crate) const FAKE_TYPE_GUID: Guid =
 pub(crate) const FAKE_DEPENDENCY: &str = "path/to/dependency.munlib";
 pub(crate) const FAKE_FIELD_NAME: &str = "field_name";
 pub(crate) const FAKE_FN_NAME: &str = "fn_name";
+pub(crate) const FAKE_GLOBAL_NAME: &str = "GLOBAL_NAME";
 pub(crate) const FAKE_MODULE_PATH: &str = "path::to::module";
 pub(crate) const FAKE_STRUCT_NAME: &str = "StructName";
 pub(crate) const FAKE_TYPE_NAME: &str = "TypeName";
+pub(crate) const FAKE_VARIANT_NAME: &str = "VariantName";
 
 /// A dummy struct for initializing a struct's `TypeInfo`
 pub(crate) struct StructTypeInfo {
@@ -23,6 +26,66 @@ pub(crate) struct StructTypeInfo {
     _struct_info: StructInfo,
 }
 
+/// A dummy struct for initializing an array's `TypeInfo`
+#[repr(C)]
+pub(crate) struct ArrayTypeInfo {
+    type_info: TypeInfo,
+    _array_info: ArrayInfo,
+}
+
+impl std::ops::Deref for ArrayTypeInfo {
+    type Target = TypeInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.type_info
+    }
+}
+
+/// A dummy struct for initializing an enum's `TypeInfo`
+#[repr(C)]
+pub(crate) struct EnumTypeInfo {
+    type_info: TypeInfo,
+    _enum_info: EnumInfo,
+}
+
+impl std::ops::Deref for EnumTypeInfo {
+    type Target = TypeInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.type_info
+    }
+}
+
+/// A dummy struct for initializing a function type's `TypeInfo`
+#[repr(C)]
+pub(crate) struct FunctionTypeInfo {
+    type_info: TypeInfo,
+    _signature: FunctionSignature,
+}
+
+impl std::ops::Deref for FunctionTypeInfo {
+    type Target = TypeInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.type_info
+    }
+}
+
+/// A dummy struct for initializing a nullable type's `TypeInfo`
+#[repr(C)]
+pub(crate) struct NullableTypeInfo {
+    type_info: TypeInfo,
+    _inner_type: *const TypeInfo,
+}
+
+impl std::ops::Deref for NullableTypeInfo {
+    type Target = TypeInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.type_info
+    }
+}
+
 pub(crate) fn fake_assembly_info(
     symbols: ModuleInfo,
     dispatch_table: DispatchTable,
@@ -36,6 +99,57 @@ pub(crate) fn fake_assembly_info(
     }
 }
 
+pub(crate) fn fake_array_info(
+    element_type: &TypeInfo,
+    length: u64,
+    memory_kind: StructMemoryKind,
+) -> ArrayInfo {
+    ArrayInfo {
+        element_type,
+        length,
+        memory_kind,
+    }
+}
+
+pub(crate) fn fake_array_type_info(
+    name: &CStr,
+    array_info: ArrayInfo,
+    size: u32,
+    alignment: u8,
+) -> ArrayTypeInfo {
+    ArrayTypeInfo {
+        type_info: fake_type_info(name, TypeGroup::ArrayTypes, size, alignment),
+        _array_info: array_info,
+    }
+}
+
+pub(crate) fn fake_enum_info(
+    variant_names: &[*const c_char],
+    variant_types: &[StructInfo],
+    discriminant_size: u8,
+) -> EnumInfo {
+    assert!(variant_names.len() == variant_types.len());
+
+    EnumInfo {
+        variant_names: variant_names.as_ptr(),
+        variant_types: variant_types.as_ptr(),
+        num_variants: variant_names.len() as u16,
+        discriminant_size,
+    }
+}
+
+pub(crate) fn fake_enum_type_info(
+    name: &CStr,
+    enum_info: EnumInfo,
+    size: u32,
+    alignment: u8,
+) -> EnumTypeInfo {
+    EnumTypeInfo {
+        type_info: fake_type_info(name, TypeGroup::EnumTypes, size, alignment),
+        _enum_info: enum_info,
+    }
+}
+
 pub(crate) fn fake_dispatch_table(
     fn_prototypes: &[FunctionPrototype],
     fn_ptrs: &mut [*const c_void],
@@ -60,6 +174,30 @@ pub(crate) fn fake_fn_signature(
     }
 }
 
+pub(crate) fn fake_fn_type_info(
+    name: &CStr,
+    signature: FunctionSignature,
+    size: u32,
+    alignment: u8,
+) -> FunctionTypeInfo {
+    FunctionTypeInfo {
+        type_info: fake_type_info(name, TypeGroup::FunctionTypes, size, alignment),
+        _signature: signature,
+    }
+}
+
+pub(crate) fn fake_nullable_type_info(
+    name: &CStr,
+    inner_type: &TypeInfo,
+    size: u32,
+    alignment: u8,
+) -> NullableTypeInfo {
+    NullableTypeInfo {
+        type_info: fake_type_info(name, TypeGroup::NullableTypes, size, alignment),
+        _inner_type: inner_type,
+    }
+}
+
 pub(crate) fn fake_fn_prototype(
     name: &CStr,
     arg_types: &[&TypeInfo],
@@ -71,10 +209,24 @@ pub(crate) fn fake_fn_prototype(
     }
 }
 
+pub(crate) fn fake_global_definition(
+    name: &CStr,
+    type_info: &TypeInfo,
+    is_mutable: bool,
+) -> GlobalDefinition {
+    GlobalDefinition {
+        name: name.as_ptr(),
+        type_info,
+        ptr: ptr::null_mut(),
+        is_mutable,
+    }
+}
+
 pub(crate) fn fake_module_info(
     path: &CStr,
     functions: &[FunctionDefinition],
     types: &[&TypeInfo],
+    globals: &[GlobalDefinition],
 ) -> ModuleInfo {
     ModuleInfo {
         path: path.as_ptr(),
@@ -82,6 +234,8 @@ pub(crate) fn fake_module_info(
         num_functions: functions.len() as u32,
         types: types.as_ptr().cast::<*const TypeInfo>(),
         num_types: types.len() as u32,
+        globals: globals.as_ptr(),
+        num_globals: globals.len() as u32,
     }
 }
 
@@ -1,4 +1,6 @@
-use crate::{static_type_map::StaticTypeMap, Guid, StructInfo};
+use crate::{
+    static_type_map::StaticTypeMap, ArrayInfo, EnumInfo, FunctionSignature, Guid, StructInfo,
+};
 use once_cell::sync::OnceCell;
 use std::{
     convert::TryInto,
@@ -12,8 +14,8 @@ use std::{
 
 /// Represents the type declaration for a value type.
 ///
-/// TODO: add support for polymorphism, enumerations, type parameters, generic type definitions, and
-/// constructed generic types.
+/// Generic types are represented by their instantiations: every instantiation of a generic struct,
+/// e.g. `Pair<i32>`, has its own `TypeInfo` with a distinct GUID.
 #[repr(C)]
 #[derive(Debug)]
 pub struct TypeInfo {
@@ -37,6 +39,17 @@ pub enum TypeGroup {
     FundamentalTypes = 0,
     /// Struct types (i.e. record, tuple, or unit structs)
     StructTypes = 1,
+    /// Array types (i.e. `[T; N]` or `[T]`)
+    ArrayTypes = 2,
+    /// Enum types (i.e. C-like enums or enums with variants that contain fields)
+    EnumTypes = 3,
+    /// String types (i.e. `string`)
+    StringTypes = 4,
+    /// Function types (i.e. `fn(T) -> U`), of which the values are closures
+    FunctionTypes = 5,
+    /// Nullable types (i.e. `?T`), of which the values are either a reference to a `gc` struct or
+    /// `nil`
+    NullableTypes = 6,
 }
 
 impl TypeInfo {
@@ -58,6 +71,58 @@ impl TypeInfo {
         }
     }
 
+    /// Retrieves the type's array information, if available.
+    pub fn as_array(&self) -> Option<&ArrayInfo> {
+        if self.group.is_array() {
+            let ptr = (self as *const TypeInfo).cast::<u8>();
+            let ptr = ptr.wrapping_add(mem::size_of::<TypeInfo>());
+            let offset = ptr.align_offset(mem::align_of::<ArrayInfo>());
+            let ptr = ptr.wrapping_add(offset);
+            Some(unsafe { &*ptr.cast::<ArrayInfo>() })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the type's enum information, if available.
+    pub fn as_enum(&self) -> Option<&EnumInfo> {
+        if self.group.is_enum() {
+            let ptr = (self as *const TypeInfo).cast::<u8>();
+            let ptr = ptr.wrapping_add(mem::size_of::<TypeInfo>());
+            let offset = ptr.align_offset(mem::align_of::<EnumInfo>());
+            let ptr = ptr.wrapping_add(offset);
+            Some(unsafe { &*ptr.cast::<EnumInfo>() })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the signature of the type's closures, if available.
+    pub fn as_function(&self) -> Option<&FunctionSignature> {
+        if self.group.is_function() {
+            let ptr = (self as *const TypeInfo).cast::<u8>();
+            let ptr = ptr.wrapping_add(mem::size_of::<TypeInfo>());
+            let offset = ptr.align_offset(mem::align_of::<FunctionSignature>());
+            let ptr = ptr.wrapping_add(offset);
+            Some(unsafe { &*ptr.cast::<FunctionSignature>() })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the type that is referred to by a nullable type, if available.
+    pub fn as_nullable(&self) -> Option<&TypeInfo> {
+        if self.group.is_nullable() {
+            let ptr = (self as *const TypeInfo).cast::<u8>();
+            let ptr = ptr.wrapping_add(mem::size_of::<TypeInfo>());
+            let offset = ptr.align_offset(mem::align_of::<*const TypeInfo>());
+            let ptr = ptr.wrapping_add(offset);
+            Some(unsafe { &**ptr.cast::<*const TypeInfo>() })
+        } else {
+            None
+        }
+    }
+
     /// Returns the size of the type in bits
     pub fn size_in_bits(&self) -> usize {
         self.size_in_bits
@@ -111,6 +176,31 @@ impl TypeGroup {
     pub fn is_struct(self) -> bool {
         self == TypeGroup::StructTypes
     }
+
+    /// Returns whether this is an array type.
+    pub fn is_array(self) -> bool {
+        self == TypeGroup::ArrayTypes
+    }
+
+    /// Returns whether this is an enum type.
+    pub fn is_enum(self) -> bool {
+        self == TypeGroup::EnumTypes
+    }
+
+    /// Returns whether this is a string type.
+    pub fn is_string(self) -> bool {
+        self == TypeGroup::StringTypes
+    }
+
+    /// Returns whether this is a function type.
+    pub fn is_function(self) -> bool {
+        self == TypeGroup::FunctionTypes
+    }
+
+    /// Returns whether this is a nullable type.
+    pub fn is_nullable(self) -> bool {
+        self == TypeGroup::NullableTypes
+    }
 }
 
 /// A trait that defines that for a type we can statically return a `TypeInfo`.
@@ -262,6 +352,31 @@ impl_has_type_info_name!(
     TypeInfo => "TypeInfo"
 );
 
+/// A garbage collected string is stored as its length in bytes, followed by its UTF-8 encoded text.
+/// The `TypeInfo` describes the layout of the length.
+impl HasStaticTypeInfo for str {
+    fn type_info() -> &'static TypeInfo {
+        static TYPE_INFO: OnceCell<TypeInfo> = OnceCell::new();
+        TYPE_INFO.get_or_init(|| {
+            static TYPE_INFO_NAME: OnceCell<CString> = OnceCell::new();
+            let type_info_name: &'static CString =
+                TYPE_INFO_NAME.get_or_init(|| CString::new("core::string").unwrap());
+
+            TypeInfo {
+                guid: Guid(md5::compute(&type_info_name.as_bytes()).0),
+                name: type_info_name.as_ptr(),
+                group: TypeGroup::StringTypes,
+                size_in_bits: (std::mem::size_of::<usize>() * 8)
+                    .try_into()
+                    .expect("size of usize is larger than the maximum allowed ABI size. Please file a bug."),
+                alignment: (std::mem::align_of::<usize>())
+                    .try_into()
+                    .expect("alignment of usize is larger than the maximum allowed ABI size. Please file a bug."),
+            }
+        })
+    }
+}
+
 #[cfg(target_pointer_width = "64")]
 impl HasStaticTypeInfo for usize {
     fn type_info() -> &'static TypeInfo {
@@ -292,8 +407,15 @@ impl HasStaticTypeInfo for isize {
 
 #[cfg(test)]
 mod tests {
-    use super::{HasStaticTypeInfoName, TypeGroup};
-    use crate::test_utils::{fake_type_info, FAKE_TYPE_NAME};
+    use super::{HasStaticTypeInfo, HasStaticTypeInfoName, TypeGroup};
+    use crate::{
+        test_utils::{
+            fake_array_info, fake_array_type_info, fake_enum_info, fake_enum_type_info,
+            fake_fn_signature, fake_fn_type_info, fake_nullable_type_info, fake_struct_info,
+            fake_type_info, FAKE_STRUCT_NAME, FAKE_TYPE_NAME, FAKE_VARIANT_NAME,
+        },
+        StructMemoryKind,
+    };
     use std::ffi::CString;
 
     #[test]
@@ -334,6 +456,139 @@ mod tests {
         assert_eq!(type_info.group, type_group);
         assert!(type_info.group.is_struct());
         assert!(!type_info.group.is_fundamental());
+        assert!(!type_info.group.is_array());
+    }
+
+    #[test]
+    fn test_type_info_group_array() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let type_group = TypeGroup::ArrayTypes;
+        let type_info = fake_type_info(&type_name, type_group, 1, 1);
+
+        assert_eq!(type_info.group, type_group);
+        assert!(type_info.group.is_array());
+        assert!(!type_info.group.is_struct());
+        assert!(!type_info.group.is_fundamental());
+    }
+
+    #[test]
+    fn test_type_info_as_array() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let element_type_info = fake_type_info(&type_name, TypeGroup::FundamentalTypes, 32, 4);
+        let array_info = fake_array_info(&element_type_info, 4, StructMemoryKind::Value);
+        let array_type_info = fake_array_type_info(&type_name, array_info, 128, 4);
+
+        let array_info = array_type_info.as_array().expect("expected an array type");
+        assert_eq!(array_info.element_type(), &element_type_info);
+        assert_eq!(array_info.length(), Some(4));
+        assert!(array_type_info.as_struct().is_none());
+    }
+
+    #[test]
+    fn test_type_info_group_enum() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let type_group = TypeGroup::EnumTypes;
+        let type_info = fake_type_info(&type_name, type_group, 1, 1);
+
+        assert_eq!(type_info.group, type_group);
+        assert!(type_info.group.is_enum());
+        assert!(!type_info.group.is_struct());
+        assert!(!type_info.group.is_array());
+        assert!(!type_info.group.is_fundamental());
+    }
+
+    #[test]
+    fn test_type_info_group_string() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let type_group = TypeGroup::StringTypes;
+        let type_info = fake_type_info(&type_name, type_group, 1, 1);
+
+        assert_eq!(type_info.group, type_group);
+        assert!(type_info.group.is_string());
+        assert!(!type_info.group.is_array());
+        assert!(!type_info.group.is_fundamental());
+    }
+
+    #[test]
+    fn test_type_info_string() {
+        let type_info = <str as HasStaticTypeInfo>::type_info();
+
+        assert_eq!(type_info.name(), "core::string");
+        assert_eq!(type_info.group, TypeGroup::StringTypes);
+        assert_eq!(type_info.size_in_bytes(), std::mem::size_of::<usize>());
+        assert_eq!(type_info.alignment(), std::mem::align_of::<usize>());
+    }
+
+    #[test]
+    fn test_type_info_group_function() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let type_group = TypeGroup::FunctionTypes;
+        let type_info = fake_type_info(&type_name, type_group, 1, 1);
+
+        assert_eq!(type_info.group, type_group);
+        assert!(type_info.group.is_function());
+        assert!(!type_info.group.is_struct());
+        assert!(!type_info.group.is_fundamental());
+    }
+
+    #[test]
+    fn test_type_info_as_function() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let arg_type_info = fake_type_info(&type_name, TypeGroup::FundamentalTypes, 32, 4);
+        let arg_types = &[&arg_type_info];
+        let signature = fake_fn_signature(arg_types, Some(&arg_type_info));
+        let fn_type_info = fake_fn_type_info(&type_name, signature, 64, 8);
+
+        let signature = fn_type_info
+            .as_function()
+            .expect("expected a function type");
+        assert_eq!(signature.arg_types(), arg_types);
+        assert_eq!(signature.return_type(), Some(&arg_type_info));
+        assert!(fn_type_info.as_struct().is_none());
+        assert!(fn_type_info.as_array().is_none());
+    }
+
+    #[test]
+    fn test_type_info_as_enum() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let variant_name = CString::new(FAKE_VARIANT_NAME).expect("Invalid fake variant name.");
+        let variant_names = &[variant_name.as_ptr()];
+        let variant_types = [fake_struct_info(&[], &[], &[], StructMemoryKind::Value)];
+        let enum_info = fake_enum_info(variant_names, &variant_types, 1);
+        let enum_type_info = fake_enum_type_info(&type_name, enum_info, 8, 1);
+
+        let enum_info = enum_type_info.as_enum().expect("expected an enum type");
+        assert_eq!(enum_info.num_variants(), 1);
+        assert_eq!(enum_info.variant_names().next(), Some(FAKE_VARIANT_NAME));
+        assert!(enum_type_info.as_struct().is_none());
+        assert!(enum_type_info.as_array().is_none());
+    }
+
+    #[test]
+    fn test_type_info_group_nullable() {
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let type_group = TypeGroup::NullableTypes;
+        let type_info = fake_type_info(&type_name, type_group, 1, 1);
+
+        assert_eq!(type_info.group, type_group);
+        assert!(type_info.group.is_nullable());
+        assert!(!type_info.group.is_struct());
+        assert!(!type_info.group.is_fundamental());
+    }
+
+    #[test]
+    fn test_type_info_as_nullable() {
+        let struct_name = CString::new(FAKE_STRUCT_NAME).expect("Invalid fake struct name.");
+        let struct_type_info = fake_type_info(&struct_name, TypeGroup::StructTypes, 64, 8);
+        let type_name = CString::new(FAKE_TYPE_NAME).expect("Invalid fake type name.");
+        let nullable_type_info = fake_nullable_type_info(&type_name, &struct_type_info, 64, 8);
+
+        let inner_type_info = nullable_type_info
+            .as_nullable()
+            .expect("expected a nullable type");
+        assert_eq!(inner_type_info.name(), FAKE_STRUCT_NAME);
+        assert!(nullable_type_info.as_struct().is_none());
+        assert!(struct_type_info.as_nullable().is_none());
     }
 
     #[test]
@@ -71,7 +71,9 @@ impl<'db, 'ink, 'ctx> ModuleBuilder<'db, 'ink, 'ctx> {
         symbols::gen_reflection_ir(
             self.code_gen.db,
             &value_context,
+            hir::Module::from(self.file_id),
             &file.api,
+            &file.globals,
             &group_ir.dispatch_table,
             &group_ir.type_table,
             &self.code_gen.hir_types,
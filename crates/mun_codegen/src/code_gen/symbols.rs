@@ -4,14 +4,18 @@ use crate::{
     ir::{
         dispatch_table::{DispatchTable, DispatchableFunction},
         function,
+        globals::GlobalDef,
         type_table::TypeTable,
     },
     type_info::TypeInfo,
     value::{AsValue, CanInternalize, Global, IrValueContext, IterAsIrValue, Value},
 };
 use hir::{HirDatabase, Ty};
-use inkwell::{attributes::Attribute, module::Linkage, AddressSpace};
-use std::{collections::HashSet, ffi::CString};
+use inkwell::{attributes::Attribute, module::Linkage};
+use std::{
+    collections::{BTreeSet, HashSet},
+    ffi::CString,
+};
 
 /// Construct a `MunFunctionPrototype` struct for the specified HIR function.
 fn gen_prototype_from_function<'ink>(
@@ -21,7 +25,7 @@ fn gen_prototype_from_function<'ink>(
     hir_types: &HirTypeCache,
 ) -> ir::FunctionPrototype<'ink> {
     let module = context.module;
-    let name = function.name(db).to_string();
+    let name = function.full_name(db);
 
     // Internalize the name of the function prototype
     let name_str = CString::new(name.clone())
@@ -137,7 +141,7 @@ fn get_function_definition_array<'ink, 'a>(
     let module = context.module;
     functions
         .map(|f| {
-            let name = f.name(db).to_string();
+            let name = f.full_name(db);
 
             // Get the function from the cloned module and modify the linkage of the function.
             let value = module
@@ -162,6 +166,38 @@ fn get_function_definition_array<'ink, 'a>(
         .into_const_private_global("fn.get_info.functions", context)
 }
 
+/// Construct a global that holds a reference to all constants and statics. e.g.:
+/// MunGlobalDefinition[] definitions = { ... }
+fn get_global_definition_array<'ink, 'a>(
+    db: &dyn HirDatabase,
+    context: &IrValueContext<'ink, '_, '_>,
+    globals: impl Iterator<Item = &'a GlobalDef>,
+    hir_types: &HirTypeCache,
+) -> Global<'ink, [ir::GlobalDefinition<'ink>]> {
+    let module = context.module;
+    globals
+        .map(|global| {
+            let name = global.full_name(db);
+
+            // Get the global from the cloned module and modify its linkage.
+            let value = module.get_global(&name).unwrap();
+            value.set_linkage(Linkage::Private);
+
+            ir::GlobalDefinition {
+                name: CString::new(name.clone())
+                    .expect("global name is not a valid CString")
+                    .intern(format!("global::<{}>::name", &name), context)
+                    .as_value(context),
+                type_info: TypeTable::get(module, &hir_types.type_info(&global.ty(db)), context)
+                    .expect("expected a TypeInfo for a global but it was not found"),
+                ptr: Value::<*mut u8>::with_cast(value.as_pointer_value(), context),
+                is_mutable: global.is_mutable(db),
+            }
+        })
+        .as_value(context)
+        .into_const_private_global("fn.get_info.globals", context)
+}
+
 /// Generate the dispatch table information. e.g.:
 /// ```c
 /// MunDispatchTable dispatchTable = { ... }
@@ -197,13 +233,43 @@ fn gen_dispatch_table<'ink>(
     }
 }
 
+/// Returns the paths of the assemblies that define the functions of other modules that are called
+/// through the dispatch table. The assemblies of a package are stored in the same directory
+/// structure as their source files, so the paths are relative to the directory of the assembly of
+/// `module`, e.g. `../physics.munlib`.
+fn gen_dependencies(
+    db: &dyn HirDatabase,
+    module: hir::Module,
+    dispatch_table: &DispatchTable,
+) -> BTreeSet<String> {
+    let depth = db
+        .file_relative_path(module.file_id())
+        .parent()
+        .map_or(0, |parent| parent.components().count());
+
+    dispatch_table
+        .entries()
+        .iter()
+        .filter_map(|entry| entry.hir.as_ref())
+        .filter(|function| dispatch_table.is_external(db, function))
+        .map(|function| {
+            let file_id = function.function.module(db.upcast()).file_id();
+            let assembly_path = db.file_relative_path(file_id).with_extension("munlib");
+            format!("{}{}", "../".repeat(depth), assembly_path)
+        })
+        .collect()
+}
+
 /// Constructs IR that exposes the types and symbols in the specified module. A function called
 /// `get_info` is constructed that returns a struct `MunAssemblyInfo`. See the `mun_abi` crate
 /// for the ABI that `get_info` exposes.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn gen_reflection_ir<'db, 'ink>(
     db: &'db dyn HirDatabase,
     context: &IrValueContext<'ink, '_, '_>,
+    hir_module: hir::Module,
     api: &HashSet<hir::Function>,
+    globals: &[GlobalDef],
     dispatch_table: &DispatchTable<'ink>,
     type_table: &TypeTable<'ink>,
     hir_types: &HirTypeCache<'db, 'ink>,
@@ -214,14 +280,23 @@ pub(super) fn gen_reflection_ir<'db, 'ink>(
     let num_functions = api.len() as u32;
     let functions = get_function_definition_array(db, context, api.iter(), hir_types);
 
+    let num_globals = globals.len() as u32;
+    let globals = get_global_definition_array(db, context, globals.iter(), hir_types);
+
     // Get the TypeTable global
     let types = TypeTable::find_global(module)
         .map(|g| g.as_value(context))
         .unwrap_or_else(|| Value::null(context));
 
     // Construct the module info struct
+    let module_path = hir_module
+        .path(db.upcast())
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("::");
     let module_info = ir::ModuleInfo {
-        path: CString::new("")
+        path: CString::new(module_path)
             .unwrap()
             .intern("module_info::path", context)
             .as_value(context),
@@ -229,13 +304,36 @@ pub(super) fn gen_reflection_ir<'db, 'ink>(
         num_functions,
         types,
         num_types: type_table.num_types() as u32,
+        globals: globals.as_value(context),
+        num_globals,
     };
 
+    // Construct the list of assemblies that this assembly depends on
+    let dependencies = gen_dependencies(db, hir_module, dispatch_table);
+    let num_dependencies = dependencies.len() as u32;
+    let dependencies = dependencies
+        .iter()
+        .map(|path| {
+            CString::new(path.as_str())
+                .expect("dependency path is not a valid CString")
+                .intern(format!("dependency::<{}>", path), context)
+                .as_value(context)
+        })
+        .into_const_private_pointer_or_null("assembly_info::dependencies", context);
+
     // Construct the dispatch table struct
     let dispatch_table = gen_dispatch_table(context, dispatch_table);
 
     // Construct the actual `get_info` function
-    gen_get_info_fn(db, context, module_info, dispatch_table, optimization_level);
+    gen_get_info_fn(
+        db,
+        context,
+        module_info,
+        dispatch_table,
+        dependencies,
+        num_dependencies,
+        optimization_level,
+    );
     gen_set_allocator_handle_fn(context);
     gen_get_version_fn(context);
 }
@@ -246,10 +344,11 @@ fn gen_get_info_fn<'ink>(
     context: &IrValueContext<'ink, '_, '_>,
     module_info: ir::ModuleInfo<'ink>,
     dispatch_table: ir::DispatchTable<'ink>,
+    dependencies: Value<'ink, *const *const u8>,
+    num_dependencies: u32,
     optimization_level: inkwell::OptimizationLevel,
 ) {
     let target = db.target();
-    let str_type = context.context.i8_type().ptr_type(AddressSpace::Generic);
 
     // Construct the return type of the `get_info` method. Depending on the C ABI this is either the
     // `MunAssemblyInfo` struct or void. On windows the return argument is passed back to the caller
@@ -310,13 +409,13 @@ fn gen_get_info_fn<'ink>(
     // Assign the struct values one by one.
     builder.build_store(symbols_addr, module_info.as_value(context).value);
     builder.build_store(dispatch_table_addr, dispatch_table.as_value(context).value);
-    builder.build_store(
-        dependencies_addr,
-        str_type.ptr_type(AddressSpace::Generic).const_null(),
-    );
+    builder.build_store(dependencies_addr, dependencies.value);
     builder.build_store(
         num_dependencies_addr,
-        context.context.i32_type().const_int(0 as u64, false),
+        context
+            .context
+            .i32_type()
+            .const_int(num_dependencies as u64, false),
     );
 
     // Construct the return statement of the function.
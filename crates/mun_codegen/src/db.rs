@@ -15,6 +15,11 @@ pub trait CodeGenDatabase: hir::HirDatabase + hir::Upcast<dyn hir::HirDatabase>
     #[salsa::input]
     fn optimization_level(&self) -> inkwell::OptimizationLevel;
 
+    /// Set whether integer arithmetic traps on overflow. Divisions by zero and oversized shifts
+    /// always trap.
+    #[salsa::input]
+    fn arithmetic_checks(&self) -> bool;
+
     /// Returns the inkwell target machine that completely describes the code generation target. All
     /// target-specific information should be accessible through this interface.
     fn target_machine(&self) -> ByAddress<Arc<inkwell::targets::TargetMachine>>;
@@ -21,4 +21,24 @@ pub trait Intrinsic: Sync {
 intrinsics! {
     /// Allocates memory for the specified `type` in the allocator referred to by `alloc_handle`.
     pub fn new(type: *const TypeInfo, alloc_handle: *mut ffi::c_void) -> *const *mut ffi::c_void;
+
+    /// Allocates memory for an array of the specified `type` with `length` elements in the
+    /// allocator referred to by `alloc_handle`.
+    pub fn new_array(type: *const TypeInfo, length: usize, alloc_handle: *mut ffi::c_void) -> *const *mut ffi::c_void;
+
+    /// Allocates a new string that contains the text of `lhs` followed by the text of `rhs` in the
+    /// allocator referred to by `alloc_handle`.
+    pub fn string_concat(lhs: *const *mut ffi::c_void, rhs: *const *mut ffi::c_void, alloc_handle: *mut ffi::c_void) -> *const *mut ffi::c_void;
+
+    /// Returns whether the strings `lhs` and `rhs` contain the same text.
+    pub fn string_eq(lhs: *const *mut ffi::c_void, rhs: *const *mut ffi::c_void) -> bool;
+
+    /// Aborts the invocation of a function, reporting the null-terminated `message` and the
+    /// source location at which it occurred to the host. The `line` and `column` are one-based.
+    /// The calling function returns immediately afterwards.
+    pub fn panic(message: *const u8, file: *const u8, line: u32, column: u32) -> ();
+
+    /// Returns whether a function that was called by the calling function panicked, in which case
+    /// the calling function returns immediately as well.
+    pub fn panicking() -> bool;
 }
@@ -12,6 +12,8 @@ pub(crate) mod dispatch_table;
 pub mod file;
 pub(crate) mod file_group;
 pub mod function;
+pub(crate) mod globals;
+pub(crate) mod instance;
 mod intrinsics;
 pub mod ty;
 pub(crate) mod type_table;
@@ -2,29 +2,174 @@ use crate::{
     intrinsics,
     ir::ty::HirTypeCache,
     ir::types as ir,
-    ir::{dispatch_table::DispatchTable, type_table::TypeTable},
+    ir::{
+        dispatch_table::DispatchTable,
+        globals::{self, GlobalDef},
+        instance::FunctionInstance,
+        type_table::TypeTable,
+    },
+    type_info::TypeInfo,
     value::Global,
 };
 use hir::{
-    ArithOp, BinaryOp, Body, CmpOp, Expr, ExprId, HirDatabase, HirDisplay, InferenceResult,
-    Literal, LogicOp, Name, Ordering, Pat, PatId, Path, Resolution, ResolveBitness, Resolver,
-    Statement, TypeCtor, UnaryOp,
+    ArithOp, BinaryOp, Body, CmpOp, ControlFlowGraph, Expr, ExprId, FloatBitness, HirDatabase,
+    HirDisplay, InferenceResult, Literal, LogicOp, Name, Ordering, OverflowBehavior, Pat, PatId,
+    Path, RangeOp, Resolution, ResolveBitness, Resolver, Statement, Step, Terminator, TypeCtor,
+    UnaryOp,
 };
 use inkwell::{
     basic_block::BasicBlock,
     builder::Builder,
     context::Context,
-    values::{AggregateValueEnum, GlobalValue, PointerValue},
-    values::{BasicValueEnum, CallSiteValue, FloatValue, FunctionValue, IntValue, StructValue},
+    module::{Linkage, Module},
+    types::{BasicTypeEnum, IntType},
+    values::{AggregateValueEnum, ArrayValue, GlobalValue, PointerValue},
+    values::{BasicValueEnum, FloatValue, FunctionValue, IntValue, StructValue},
     AddressSpace, FloatPredicate, IntPredicate,
 };
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 struct LoopInfo<'ink> {
+    /// The block of the control-flow graph that a `continue` expression jumps to
+    header: hir::BasicBlock,
+    /// The block of the control-flow graph that a `break` expression jumps to
+    exit: hir::BasicBlock,
     break_values: Vec<(BasicValueEnum<'ink>, BasicBlock<'ink>)>,
+    continue_block: BasicBlock<'ink>,
     exit_block: BasicBlock<'ink>,
 }
 
+/// The control-flow graph of a body, together with the relations between its expressions that are
+/// needed to generate IR from the graph.
+struct BodyGraph {
+    cfg: Arc<ControlFlowGraph>,
+    /// The expression that contains each expression. The body of a closure has no parent.
+    parents: HashMap<ExprId, ExprId>,
+    /// The expressions of which the evaluation spans multiple blocks of the graph, e.g. an `if`
+    /// expression or a block that contains one
+    control_flow_exprs: HashSet<ExprId>,
+    /// The initializers of the `let` statements in blocks that contain control flow
+    let_initializers: HashMap<PatId, ExprId>,
+    /// The `loop`, `while` and `for` expressions by the header block of their loop
+    loops_by_header: HashMap<hir::BasicBlock, ExprId>,
+}
+
+impl BodyGraph {
+    fn new(db: &dyn HirDatabase, body: &Body) -> Self {
+        let mut graph = BodyGraph {
+            cfg: db.control_flow_graph(body.owner()),
+            parents: HashMap::new(),
+            control_flow_exprs: HashSet::new(),
+            let_initializers: HashMap::new(),
+            loops_by_header: HashMap::new(),
+        };
+
+        graph.find_control_flow(body, body.body_expr());
+        for (expr, data) in body.exprs() {
+            match data {
+                Expr::Lambda {
+                    body: lambda_body, ..
+                } => {
+                    graph.find_control_flow(body, *lambda_body);
+                    continue;
+                }
+                Expr::Loop { .. } | Expr::While { .. } | Expr::For { .. } => {
+                    if let Some(header) = graph.cfg.loop_header(expr) {
+                        graph.loops_by_header.insert(header, expr);
+                    }
+                }
+                _ => {}
+            }
+            data.walk_child_exprs(|child| {
+                graph.parents.insert(child, expr);
+            });
+        }
+
+        for expr in graph.control_flow_exprs.iter() {
+            if let Expr::Block { statements, .. } = &body[*expr] {
+                for statement in statements.iter() {
+                    if let Statement::Let {
+                        pat,
+                        initializer: Some(initializer),
+                        ..
+                    } = statement
+                    {
+                        graph.let_initializers.insert(*pat, *initializer);
+                    }
+                }
+            }
+        }
+        graph
+    }
+
+    /// Finds the expressions in `expr` of which the evaluation spans multiple blocks and returns
+    /// true if `expr` is one of them. The body of a closure is a separate sub-graph, so a closure
+    /// does not contain control flow itself.
+    fn find_control_flow(&mut self, body: &Body, expr: ExprId) -> bool {
+        let data = &body[expr];
+        if let Expr::Lambda { .. } = data {
+            return false;
+        }
+        let mut has_control_flow = matches!(
+            data,
+            Expr::If { .. }
+                | Expr::Match { .. }
+                | Expr::Loop { .. }
+                | Expr::While { .. }
+                | Expr::For { .. }
+                | Expr::Return { .. }
+                | Expr::Break { .. }
+                | Expr::Continue { .. }
+                | Expr::BinaryOp {
+                    op: Some(BinaryOp::LogicOp(_)),
+                    ..
+                }
+        );
+        data.walk_child_exprs(|child| has_control_flow |= self.find_control_flow(body, child));
+        if has_control_flow {
+            self.control_flow_exprs.insert(expr);
+        }
+        has_control_flow
+    }
+}
+
+/// Describes how the generation of a sequence of blocks of the control-flow graph ended
+enum Flow {
+    /// Execution continues in a block that is generated by the caller, e.g. the join block of an
+    /// `if` expression
+    Goto(hir::BasicBlock),
+    /// The condition of a `while` loop is evaluated; if it holds, execution continues in the block
+    /// of the body of the loop
+    Condition(hir::BasicBlock),
+    /// The body of the function or closure has evaluated to its value
+    Return,
+    /// Execution does not continue after the generated blocks
+    Diverged,
+}
+
+impl Flow {
+    /// Returns the block in which execution continues, if any
+    fn goto(&self) -> Option<hir::BasicBlock> {
+        match self {
+            Flow::Goto(block) => Some(*block),
+            _ => None,
+        }
+    }
+}
+
+/// Describes how the bindings of a pattern are initialized when its `Bind` step is generated
+enum PendingBinding<'ink> {
+    /// The pattern of a match arm, or of an `if let` or `while let` condition, is bound to the
+    /// value stored at the pointer. Paths in the pattern are resolved in the scope of the
+    /// expression.
+    Scrutinee(PointerValue<'ink>, ExprId),
+    /// The binding of a `for` loop is assigned the current value of the loop counter
+    Counter(PointerValue<'ink>, IntValue<'ink>),
+}
+
 #[derive(Clone)]
 pub(crate) struct ExternalGlobals<'ink> {
     pub alloc_handle: Option<GlobalValue<'ink>>,
@@ -34,6 +179,7 @@ pub(crate) struct ExternalGlobals<'ink> {
 
 pub(crate) struct BodyIrGenerator<'db, 'ink, 't> {
     context: &'ink Context,
+    module: &'t Module<'ink>,
     db: &'db dyn HirDatabase,
     body: Arc<Body>,
     infer: Arc<InferenceResult>,
@@ -42,32 +188,41 @@ pub(crate) struct BodyIrGenerator<'db, 'ink, 't> {
     pat_to_param: HashMap<PatId, inkwell::values::BasicValueEnum<'ink>>,
     pat_to_local: HashMap<PatId, inkwell::values::PointerValue<'ink>>,
     pat_to_name: HashMap<PatId, String>,
-    function_map: &'t HashMap<hir::Function, FunctionValue<'ink>>,
+    function_map: &'t HashMap<FunctionInstance, FunctionValue<'ink>>,
     dispatch_table: &'t DispatchTable<'ink>,
     type_table: &'t TypeTable<'ink>,
     hir_types: &'t HirTypeCache<'db, 'ink>,
-    active_loop: Option<LoopInfo<'ink>>,
-    hir_function: hir::Function,
+    active_loops: Vec<LoopInfo<'ink>>,
+    graph: Arc<BodyGraph>,
+    /// The values of the expressions that have been generated by a step of the control-flow graph
+    values: HashMap<ExprId, Option<BasicValueEnum<'ink>>>,
+    pending_bindings: HashMap<PatId, PendingBinding<'ink>>,
+    instance: FunctionInstance,
     external_globals: ExternalGlobals<'ink>,
+    arithmetic_checks: bool,
 }
 
 impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         context: &'ink Context,
+        module: &'t Module<'ink>,
         db: &'db dyn HirDatabase,
-        function: (hir::Function, FunctionValue<'ink>),
-        function_map: &'t HashMap<hir::Function, FunctionValue<'ink>>,
+        function: (FunctionInstance, FunctionValue<'ink>),
+        function_map: &'t HashMap<FunctionInstance, FunctionValue<'ink>>,
         dispatch_table: &'t DispatchTable<'ink>,
         type_table: &'t TypeTable<'ink>,
         external_globals: ExternalGlobals<'ink>,
         hir_types: &'t HirTypeCache<'db, 'ink>,
+        arithmetic_checks: bool,
     ) -> Self {
-        let (hir_function, ir_function) = function;
+        let (instance, ir_function) = function;
 
-        // Get the type information from the `hir::Function`
-        let body = hir_function.body(db);
-        let infer = hir_function.infer(db);
+        // Get the type information from the `hir::Function`. In case of an instantiation of a
+        // generic function, its generic parameters are replaced by the type arguments.
+        let body = instance.function.body(db);
+        let infer = instance.infer(db);
+        let graph = Arc::new(BodyGraph::new(db, &body));
 
         // Construct a builder for the IR function
         let builder = context.create_builder();
@@ -76,6 +231,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
 
         BodyIrGenerator {
             context,
+            module,
             db,
             body,
             infer,
@@ -87,10 +243,46 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             function_map,
             dispatch_table,
             type_table,
-            active_loop: None,
-            hir_function,
+            active_loops: Vec::new(),
+            graph,
+            values: HashMap::default(),
+            pending_bindings: HashMap::default(),
+            instance,
             external_globals,
             hir_types,
+            arithmetic_checks,
+        }
+    }
+
+    /// Constructs a `BodyIrGenerator` that generates the IR function `fn_value` for a closure
+    /// defined in the body of this generator's function.
+    fn new_closure_generator(&self, fn_value: FunctionValue<'ink>) -> Self {
+        let builder = self.context.create_builder();
+        let body_ir = self.context.append_basic_block(fn_value, "body");
+        builder.position_at_end(body_ir);
+
+        BodyIrGenerator {
+            context: self.context,
+            module: self.module,
+            db: self.db,
+            body: self.body.clone(),
+            infer: self.infer.clone(),
+            builder,
+            fn_value,
+            pat_to_param: HashMap::default(),
+            pat_to_local: HashMap::default(),
+            pat_to_name: HashMap::default(),
+            function_map: self.function_map,
+            dispatch_table: self.dispatch_table,
+            type_table: self.type_table,
+            active_loops: Vec::new(),
+            graph: self.graph.clone(),
+            values: HashMap::default(),
+            pending_bindings: HashMap::default(),
+            instance: self.instance.clone(),
+            external_globals: self.external_globals.clone(),
+            hir_types: self.hir_types,
+            arithmetic_checks: self.arithmetic_checks,
         }
     }
 
@@ -102,7 +294,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             let body = self.body.clone(); // Avoid borrow issues
 
             match &body[*pat] {
-                Pat::Bind { name } => {
+                Pat::Bind { name, .. } => {
                     let name = name.to_string();
                     let param = self.fn_value.get_nth_param(i as u32).unwrap();
                     let builder = self.new_alloca_builder();
@@ -114,8 +306,12 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 Pat::Wild => {
                     // Wildcard patterns cannot be referenced from code. So nothing to do.
                 }
-                Pat::Path(_) => unreachable!(
-                    "Path patterns are not supported as parameters, are we missing a diagnostic?"
+                Pat::Tuple(_) => {
+                    let param = self.fn_value.get_nth_param(i as u32).unwrap();
+                    self.gen_param_destructuring(*pat, param);
+                }
+                Pat::Path(_) | Pat::TupleStruct { .. } | Pat::Lit(_) => unreachable!(
+                    "Refutable parameter patterns are not supported, are we missing a diagnostic?"
                 ),
                 Pat::Missing => unreachable!(
                     "found missing Pattern, should not be generating IR for incomplete code"
@@ -123,20 +319,20 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             }
         }
 
-        // Generate code for the body of the function
-        let ret_value = self.gen_expr(self.body.body_expr());
+        // Generate code for the body of the function, starting at the entry block of its
+        // control-flow graph
+        self.gen_uninitialized_lets(self.body.body_expr());
+        let entry = self.graph.cfg.entry();
+        let ret_value = match self.gen_blocks(entry) {
+            Flow::Return => self.gen_expr(self.body.body_expr()),
+            _ => None,
+        };
 
         // Construct a return statement from the returned value of the body if a return is expected
         // in the first place. If the return type of the body is `never` there is no need to
         // generate a return statement.
         let block_ret_type = &self.infer[self.body.body_expr()];
-        let fn_ret_type = self
-            .hir_function
-            .ty(self.db)
-            .callable_sig(self.db)
-            .unwrap()
-            .ret()
-            .clone();
+        let fn_ret_type = self.instance.callable_sig(self.db).ret().clone();
         if !block_ret_type.is_never() {
             if fn_ret_type.is_empty() {
                 self.builder.build_return(None);
@@ -147,50 +343,33 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     }
 
     pub fn gen_fn_wrapper(&mut self) {
-        let fn_sig = self.hir_function.ty(self.db).callable_sig(self.db).unwrap();
+        let fn_sig = self.instance.callable_sig(self.db);
         let args: Vec<BasicValueEnum> = fn_sig
             .params()
             .iter()
             .enumerate()
             .map(|(idx, ty)| {
                 let param = self.fn_value.get_nth_param(idx as u32).unwrap();
-                if let Some(s) = ty.as_struct() {
-                    if s.data(self.db.upcast()).memory_kind == abi::StructMemoryKind::Value {
-                        deref_heap_value(&self.builder, param)
-                    } else {
-                        param
-                    }
+                if is_heap_value_in_public_api(self.db, ty) {
+                    deref_heap_value(&self.builder, param)
                 } else {
                     param
                 }
             })
             .collect();
 
-        let ret_value = self
-            .gen_call(self.hir_function, &args, false)
-            .try_as_basic_value()
-            .left();
+        let instance = self.instance.clone();
+        let ret_value = self.gen_call(&instance, &args, false);
 
         let call_return_type = &self.infer[self.body.body_expr()];
         if !call_return_type.is_never() {
-            let fn_ret_type = self
-                .hir_function
-                .ty(self.db)
-                .callable_sig(self.db)
-                .unwrap()
-                .ret()
-                .clone();
+            let fn_ret_type = fn_sig.ret().clone();
 
             if fn_ret_type.is_empty() {
                 self.builder.build_return(None);
             } else if let Some(value) = ret_value {
-                let ret_value = if let Some(hir_struct) = fn_ret_type.as_struct() {
-                    if hir_struct.data(self.db.upcast()).memory_kind == hir::StructMemoryKind::Value
-                    {
-                        self.gen_struct_alloc_on_heap(hir_struct, value.into_struct_value())
-                    } else {
-                        value
-                    }
+                let ret_value = if is_heap_value_in_public_api(self.db, &fn_ret_type) {
+                    self.gen_alloc_on_heap(&fn_ret_type, value.into_struct_value())
                 } else {
                     value
                 };
@@ -200,8 +379,13 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     }
 
     /// Generates IR for the specified expression. Dependending on the type of expression an IR
-    /// value is returned.
+    /// value is returned. The value of an expression that was evaluated by a step of the
+    /// control-flow graph is not generated again.
     fn gen_expr(&mut self, expr: ExprId) -> Option<inkwell::values::BasicValueEnum<'ink>> {
+        if let Some(value) = self.values.get(&expr) {
+            return *value;
+        }
+
         let body = self.body.clone();
         match &body[expr] {
             Expr::Block {
@@ -209,6 +393,10 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 tail,
             } => self.gen_block(expr, statements, *tail),
             Expr::Path(ref p) => {
+                if let Some((function, substs)) = self.infer.function_value(expr) {
+                    let function = FunctionInstance::resolve(self.db, function, &substs);
+                    return Some(self.gen_function_value(expr, &function));
+                }
                 let resolver = hir::resolver_for_expr(self.body.clone(), self.db, expr);
                 Some(self.gen_path_expr(p, expr, &resolver))
             }
@@ -217,7 +405,10 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             Expr::BinaryOp { lhs, rhs, op } => {
                 self.gen_binary_op(expr, *lhs, *rhs, op.expect("missing op"))
             }
-            Expr::UnaryOp { expr, op } => self.gen_unary_op(*expr, *op),
+            Expr::UnaryOp { expr: operand, op } => self.gen_unary_op(expr, *operand, *op),
+            Expr::Cast {
+                expr: value_expr, ..
+            } => self.gen_cast(expr, *value_expr),
             Expr::Call {
                 ref callee,
                 ref args,
@@ -231,42 +422,229 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                             .map(|expr| self.gen_expr(*expr).expect("expected a value"))
                             .collect();
 
-                        self.gen_call(def, &args, true)
-                            .try_as_basic_value()
-                            .left()
-                            // If the called function is a void function it doesn't return anything.
-                            // If this method (`gen_expr`) returns None we assume the return value
-                            // is `never`. We return a const unit struct here to ensure that at
-                            // least something is returned. This matches with the hir where a
-                            // `nothing` is returned instead of a `never`.
-                            //
-                            // This unit value will also be optimized out.
-                            .or_else(|| match self.infer[expr] {
-                                hir::ty_app!(hir::TypeCtor::Never) => None,
-                                _ => Some(self.context.const_struct(&[], false).into()),
-                            })
+                        let function = FunctionInstance {
+                            function: def,
+                            substs: self.infer[*callee]
+                                .substs()
+                                .expect("expected a function type"),
+                        };
+                        self.gen_call_expr(expr, &function, &args)
                     }
                     Some(hir::CallableDef::Struct(_)) => Some(self.gen_named_tuple_lit(expr, args)),
-                    None => panic!("expected a callable expression"),
+                    Some(hir::CallableDef::EnumVariant(variant)) => {
+                        let args: Vec<BasicValueEnum> = args
+                            .iter()
+                            .map(|expr| self.gen_expr(*expr).expect("expected a field value"))
+                            .collect();
+                        Some(self.gen_enum_variant_lit(variant, &args))
+                    }
+                    None => self.gen_closure_call(expr, *callee, args),
                 }
             }
-            Expr::If {
-                condition,
-                then_branch,
-                else_branch,
-            } => self.gen_if(expr, *condition, *then_branch, *else_branch),
-            Expr::Return { expr: ret_expr } => self.gen_return(expr, *ret_expr),
-            Expr::Loop { body } => self.gen_loop(expr, *body),
-            Expr::While { condition, body } => self.gen_while(expr, *condition, *body),
-            Expr::Break { expr: break_expr } => self.gen_break(expr, *break_expr),
+            Expr::If { .. }
+            | Expr::Match { .. }
+            | Expr::Loop { .. }
+            | Expr::While { .. }
+            | Expr::For { .. }
+            | Expr::Return { .. }
+            | Expr::Break { .. }
+            | Expr::Continue { .. } => {
+                // The terminator that starts a control flow expression stores its value before
+                // the step that uses it is generated, and an expression that contains control
+                // flow is never deferred to its parent. So the value is always found above.
+                unreachable!("control flow is generated from the control-flow graph")
+            }
             Expr::Field {
                 expr: receiver_expr,
                 name,
             } => self.gen_field(expr, *receiver_expr, name),
+            Expr::Array(elements) => self.gen_array(expr, elements),
+            Expr::Tuple(elements) => self.gen_tuple(expr, elements),
+            Expr::Index { base, index } => self.gen_index(expr, *base, *index),
+            Expr::MethodCall {
+                receiver,
+                method_name,
+                args,
+            } => self.gen_method_call(expr, *receiver, method_name, args),
+            Expr::Lambda { .. } => Some(self.gen_lambda(expr)),
+            Expr::Nil => Some(self.gen_nil(expr)),
             _ => unimplemented!("unimplemented expr type {:?}", &body[expr]),
         }
     }
 
+    /// Generates IR for the blocks of the control-flow graph, starting at `block`, until execution
+    /// leaves the blocks that are generated by this call.
+    fn gen_blocks(&mut self, mut block: hir::BasicBlock) -> Flow {
+        let graph = self.graph.clone();
+        let body = self.body.clone();
+        loop {
+            if !graph.cfg.is_reachable(block) {
+                return Flow::Diverged;
+            }
+
+            let data = &graph.cfg[block];
+            for step in data.steps.iter() {
+                match *step {
+                    Step::Eval(expr) => self.gen_eval_step(expr),
+                    Step::Bind(pat) => self.gen_bind_step(pat),
+                }
+            }
+
+            let next = match &data.terminator {
+                Terminator::Goto(target) => {
+                    if self.gen_loop_jump(block, *target) {
+                        None
+                    } else if let Some(loop_expr) = graph.loops_by_header.get(target) {
+                        match &body[*loop_expr] {
+                            Expr::Loop { .. } => self.gen_loop(*loop_expr, *target),
+                            Expr::While { .. } => self.gen_while(*loop_expr, *target),
+                            _ => self.gen_for(*loop_expr, *target),
+                        }
+                    } else {
+                        return Flow::Goto(*target);
+                    }
+                }
+                Terminator::Branch {
+                    condition,
+                    then_block,
+                    else_block,
+                } => {
+                    let expr = graph.parents[condition];
+                    match &body[expr] {
+                        Expr::If { .. } => self.gen_if(expr, *then_block, *else_block),
+                        Expr::While { .. } => return Flow::Condition(*then_block),
+                        _ => self.gen_logic_op_rhs(expr, *then_block, *else_block),
+                    }
+                }
+                Terminator::Switch { expr, targets } => {
+                    self.gen_match(graph.parents[expr], targets)
+                }
+                Terminator::Iterate { .. } => {
+                    unreachable!("the header of a `for` loop is generated by the loop")
+                }
+                Terminator::Return(Some(expr)) if !graph.parents.contains_key(expr) => {
+                    return Flow::Return;
+                }
+                Terminator::Return(expr) => {
+                    self.gen_return(*expr);
+                    None
+                }
+                Terminator::Unreachable => None,
+            };
+
+            match next {
+                Some(next) => block = next,
+                None => return Flow::Diverged,
+            }
+        }
+    }
+
+    /// Generates IR for a step of the control-flow graph that evaluates `expr`. The value is
+    /// stored, so the expression that uses it does not generate it again.
+    fn gen_eval_step(&mut self, expr: ExprId) {
+        let body = self.body.clone();
+        let value = match &body[expr] {
+            // The value of a control flow expression is generated by the terminator of the block
+            // in which it starts. Conditions and ranges are generated by the expression that
+            // contains them.
+            Expr::If { .. }
+            | Expr::Match { .. }
+            | Expr::Loop { .. }
+            | Expr::While { .. }
+            | Expr::For { .. }
+            | Expr::Let { .. }
+            | Expr::Range { .. } => return,
+            _ if self.is_deferred(expr) => return,
+            Expr::Block { tail, .. } if self.has_control_flow(expr) => match tail {
+                Some(tail) => self.gen_expr(*tail),
+                None => Some(self.gen_empty()),
+            },
+            _ => self.gen_expr(expr),
+        };
+        self.values.insert(expr, value);
+    }
+
+    /// Generates IR for a step of the control-flow graph that initializes the bindings of `pat`.
+    /// The parameters of the function are bound before its body is generated.
+    fn gen_bind_step(&mut self, pat: PatId) {
+        match self.pending_bindings.remove(&pat) {
+            Some(PendingBinding::Scrutinee(ptr, expr)) => {
+                let resolver = hir::resolver_for_expr(self.body.clone(), self.db, expr);
+                self.gen_pat_bindings(pat, ptr, &resolver);
+            }
+            Some(PendingBinding::Counter(ptr, value)) => {
+                self.builder.build_store(ptr, value);
+            }
+            None => {
+                if let Some(initializer) = self.graph.let_initializers.get(&pat).copied() {
+                    self.gen_let_statement(pat, Some(initializer));
+                }
+            }
+        }
+    }
+
+    /// Returns true if the evaluation of `expr` spans multiple blocks of the control-flow graph.
+    fn has_control_flow(&self, expr: ExprId) -> bool {
+        self.graph.control_flow_exprs.contains(&expr)
+    }
+
+    /// Returns true if `expr` is generated by the expression that contains it, instead of by its
+    /// own step in the control-flow graph. An expression without control flow is generated in one
+    /// go, so the steps of its sub-expressions are skipped. The same holds for the function of a
+    /// call and for the array that is indexed, which are not evaluated as values.
+    fn is_deferred(&self, expr: ExprId) -> bool {
+        if self.has_control_flow(expr) {
+            return false;
+        }
+        let parent = match self.graph.parents.get(&expr) {
+            Some(parent) => *parent,
+            None => return false,
+        };
+        if !self.has_control_flow(parent) {
+            return true;
+        }
+        match &self.body[parent] {
+            Expr::Call { callee, .. } => {
+                *callee == expr && self.infer[expr].as_callable_def().is_some()
+            }
+            Expr::Index { base, .. } => *base == expr && self.is_place_expr(expr),
+            _ => false,
+        }
+    }
+
+    /// Allocates the bindings of the `let` statements without an initializer in `expr`. These
+    /// statements have no step in the control-flow graph. Blocks without control flow allocate
+    /// their own bindings.
+    fn gen_uninitialized_lets(&mut self, expr: ExprId) {
+        if !self.has_control_flow(expr) {
+            return;
+        }
+        let body = self.body.clone();
+        if let Expr::Block { statements, .. } = &body[expr] {
+            for statement in statements.iter() {
+                if let Statement::Let {
+                    pat,
+                    initializer: None,
+                    ..
+                } = statement
+                {
+                    self.gen_let_statement(*pat, None);
+                }
+            }
+        }
+        body[expr].walk_child_exprs(|child| self.gen_uninitialized_lets(child));
+    }
+
+    /// Generates IR for `nil`, which is a null reference to a `gc` struct.
+    fn gen_nil(&mut self, expr: ExprId) -> BasicValueEnum<'ink> {
+        self.hir_types
+            .get_basic_type(&self.infer[expr])
+            .expect("expected a nullable type")
+            .into_pointer_type()
+            .const_null()
+            .into()
+    }
+
     /// Generates an IR value that represents the given `Literal`.
     fn gen_literal(&mut self, lit: &Literal, expr: ExprId) -> BasicValueEnum<'ink> {
         match lit {
@@ -325,7 +703,15 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 }
             }
 
-            Literal::String(_) => unimplemented!("string literals are not implemented yet"),
+            Literal::String(value) => {
+                let string_ty = self.infer[expr].clone();
+                let byte_ty = self.context.i8_type();
+                let bytes: Vec<IntValue> = value
+                    .bytes()
+                    .map(|byte| byte_ty.const_int(byte.into(), false))
+                    .collect();
+                self.gen_array_alloc_on_heap(&string_ty, byte_ty.const_array(&bytes), bytes.len())
+            }
         }
     }
 
@@ -337,11 +723,14 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// Allocate a struct literal either on the stack or the heap based on the type of the struct.
     fn gen_struct_alloc(
         &mut self,
-        hir_struct: hir::Struct,
+        ty: &hir::Ty,
         args: Vec<BasicValueEnum<'ink>>,
     ) -> BasicValueEnum<'ink> {
+        let hir_struct = ty.as_struct().expect("expected a struct type");
+        let substs = ty.substs().expect("expected a struct type");
+
         // Construct the struct literal
-        let struct_ty = self.hir_types.get_struct_type(hir_struct);
+        let struct_ty = self.hir_types.get_struct_type(hir_struct, &substs);
         let mut value: AggregateValueEnum = struct_ty.get_undef().into();
         for (i, arg) in args.into_iter().enumerate() {
             value = self
@@ -355,17 +744,25 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             hir::StructMemoryKind::Value => struct_lit.into(),
             hir::StructMemoryKind::GC => {
                 // TODO: Root memory in GC
-                self.gen_struct_alloc_on_heap(hir_struct, struct_lit)
+                self.gen_alloc_on_heap(ty, struct_lit)
             }
         }
     }
 
-    fn gen_struct_alloc_on_heap(
+    /// Allocates memory for a value of type `ty` on the heap and stores `value` in it. Returns a
+    /// pointer to the object pointer of the allocated memory.
+    fn gen_alloc_on_heap(&mut self, ty: &hir::Ty, value: StructValue) -> BasicValueEnum<'ink> {
+        let type_info = self.hir_types.type_info(ty);
+        self.gen_alloc_type_on_heap(&type_info, value)
+    }
+
+    /// Allocates memory for a value described by `type_info` on the heap and stores `value` in it.
+    /// Returns a pointer to the object pointer of the allocated memory.
+    fn gen_alloc_type_on_heap(
         &mut self,
-        hir_struct: hir::Struct,
-        struct_lit: StructValue,
+        type_info: &TypeInfo,
+        value: StructValue,
     ) -> BasicValueEnum<'ink> {
-        let struct_ir_ty = self.hir_types.get_struct_type(hir_struct);
         let new_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
             self.external_globals.dispatch_table,
             &self.builder,
@@ -375,7 +772,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         let type_info_ptr = self.type_table.gen_type_info_lookup(
             self.context,
             &self.builder,
-            &self.hir_types.type_info(&hir_struct.ty(self.db)),
+            type_info,
             self.external_globals.type_table,
         );
 
@@ -409,24 +806,22 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             .builder
             .build_bitcast(
                 object_ptr,
-                struct_ir_ty
+                value
+                    .get_type()
                     .ptr_type(AddressSpace::Generic)
                     .ptr_type(AddressSpace::Generic),
-                &format!("{}_ptr_ptr", hir_struct.name(self.db.upcast()).to_string()),
+                &format!("{}_ptr_ptr", type_info.name),
             )
             .into_pointer_value();
 
         // Load the actual memory location of the struct
         let mem_ptr = self
             .builder
-            .build_load(
-                struct_ptr_ptr,
-                &format!("{}_mem_ptr", hir_struct.name(self.db.upcast()).to_string()),
-            )
+            .build_load(struct_ptr_ptr, &format!("{}_mem_ptr", type_info.name))
             .into_pointer_value();
 
         // Store the struct value
-        self.builder.build_store(mem_ptr, struct_lit);
+        self.builder.build_store(mem_ptr, value);
 
         struct_ptr_ptr.into()
     }
@@ -438,32 +833,228 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         fields: &[hir::RecordLitField],
     ) -> BasicValueEnum<'ink> {
         let struct_ty = self.infer[type_expr].clone();
-        let hir_struct = struct_ty.as_struct().unwrap(); // Can only really get here if the type is a struct
         let fields: Vec<BasicValueEnum> = fields
             .iter()
             .map(|field| self.gen_expr(field.expr).expect("expected a field value"))
             .collect();
 
-        self.gen_struct_alloc(hir_struct, fields)
+        self.gen_struct_alloc(&struct_ty, fields)
     }
 
     /// Generates IR for a named tuple literal, e.g. `Foo(1.23, 4)`
     fn gen_named_tuple_lit(&mut self, type_expr: ExprId, args: &[ExprId]) -> BasicValueEnum<'ink> {
         let struct_ty = self.infer[type_expr].clone();
-        let hir_struct = struct_ty.as_struct().unwrap(); // Can only really get here if the type is a struct
         let args: Vec<BasicValueEnum> = args
             .iter()
             .map(|expr| self.gen_expr(*expr).expect("expected a field value"))
             .collect();
 
-        self.gen_struct_alloc(hir_struct, args)
+        self.gen_struct_alloc(&struct_ty, args)
     }
 
     /// Generates IR for a unit struct literal, e.g `Foo`
     fn gen_unit_struct_lit(&mut self, type_expr: ExprId) -> BasicValueEnum<'ink> {
         let struct_ty = self.infer[type_expr].clone();
-        let hir_struct = struct_ty.as_struct().unwrap(); // Can only really get here if the type is a struct
-        self.gen_struct_alloc(hir_struct, Vec::new())
+        self.gen_struct_alloc(&struct_ty, Vec::new())
+    }
+
+    /// Generates IR for an enum variant literal, e.g. `Foo::A` or `Foo::B(1.23, 4)`
+    fn gen_enum_variant_lit(
+        &mut self,
+        variant: hir::EnumVariant,
+        args: &[BasicValueEnum<'ink>],
+    ) -> BasicValueEnum<'ink> {
+        let hir_enum = variant.parent_enum();
+        let enum_ty = self.hir_types.get_enum_type(hir_enum);
+
+        // The payload of an enum can only be reinterpreted through memory
+        let enum_ptr = self
+            .new_alloca_builder()
+            .build_alloca(enum_ty, &hir_enum.name(self.db.upcast()).to_string());
+
+        let discriminant_ptr = unsafe {
+            self.builder
+                .build_struct_gep(enum_ptr, 0, "discriminant_ptr")
+        };
+        let discriminant = self
+            .hir_types
+            .get_enum_discriminant_type(hir_enum)
+            .const_int(variant.index() as u64, false);
+        self.builder.build_store(discriminant_ptr, discriminant);
+
+        let variant_ptr = self.gen_enum_variant_ptr(enum_ptr, variant);
+        for (idx, arg) in args.iter().enumerate() {
+            let field_ptr = unsafe {
+                self.builder
+                    .build_struct_gep(variant_ptr, idx as u32, &format!("{}_ptr", idx))
+            };
+            self.builder.build_store(field_ptr, *arg);
+        }
+
+        self.builder.build_load(enum_ptr, "enum")
+    }
+
+    /// Returns a pointer to the fields of `variant`, given a pointer to an enum value.
+    fn gen_enum_variant_ptr(
+        &self,
+        enum_ptr: PointerValue<'ink>,
+        variant: hir::EnumVariant,
+    ) -> PointerValue<'ink> {
+        let payload_ptr = unsafe { self.builder.build_struct_gep(enum_ptr, 1, "payload_ptr") };
+        self.builder
+            .build_bitcast(
+                payload_ptr,
+                self.hir_types
+                    .get_enum_variant_type(variant)
+                    .ptr_type(AddressSpace::Generic),
+                &format!("{}_ptr", variant.name(self.db)),
+            )
+            .into_pointer_value()
+    }
+
+    /// Generates IR for an array literal, e.g. `[1, 2, 3]`
+    fn gen_array(&mut self, expr: ExprId, elements: &[ExprId]) -> Option<BasicValueEnum<'ink>> {
+        let array_ty = self.infer[expr].clone();
+        let (element_ty, len) = array_ty.as_array().expect("expected an array type");
+
+        let mut values = Vec::with_capacity(elements.len());
+        for element in elements.iter() {
+            // If an element never returns, neither does the array literal
+            values.push(self.gen_expr(*element)?);
+        }
+
+        // Construct the elements of the array
+        let elements_ir_ty = self
+            .hir_types
+            .get_fixed_array_type(element_ty, values.len() as u64);
+        let mut value: AggregateValueEnum = elements_ir_ty.get_undef().into();
+        for (i, element) in values.into_iter().enumerate() {
+            value = self
+                .builder
+                .build_insert_value(value, element, i as u32, "init")
+                .expect("Failed to initialize array element.");
+        }
+        let elements_lit = value.into_array_value();
+
+        match len {
+            Some(_) => Some(elements_lit.into()),
+            None => Some(self.gen_array_alloc_on_heap(&array_ty, elements_lit, elements.len())),
+        }
+    }
+
+    /// Allocates a garbage collected array, or string, and initializes it with `elements`.
+    fn gen_array_alloc_on_heap(
+        &mut self,
+        array_ty: &hir::Ty,
+        elements: ArrayValue<'ink>,
+        length: usize,
+    ) -> BasicValueEnum<'ink> {
+        let array_ir_ty = self
+            .hir_types
+            .get_any_type(array_ty)
+            .expect("expected an array or string type")
+            .into_struct_type();
+        let new_array_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::new_array,
+        );
+
+        let type_info_ptr = self.type_table.gen_type_info_lookup(
+            self.context,
+            &self.builder,
+            &self.hir_types.type_info(array_ty),
+            self.external_globals.type_table,
+        );
+
+        // HACK: We should be able to use pointers for built-in struct types like `TypeInfo` in intrinsics
+        let type_info_ptr = self.builder.build_bitcast(
+            type_info_ptr,
+            self.context.i8_type().ptr_type(AddressSpace::Generic),
+            "type_info_ptr_to_i8_ptr",
+        );
+
+        let length = self
+            .hir_types
+            .get_int_type(hir::IntTy::usize())
+            .const_int(length as u64, false);
+
+        let allocator_handle = self.builder.build_load(
+            self.external_globals
+                .alloc_handle
+                .expect("no allocator handle was specified, this is required for arrays")
+                .as_pointer_value(),
+            "allocator_handle",
+        );
+
+        // An object pointer adds an extra layer of indirection to allow for hot reloading. The
+        // runtime also stores the length of the array in its memory.
+        let object_ptr = self
+            .builder
+            .build_call(
+                new_array_fn_ptr,
+                &[type_info_ptr, length.into(), allocator_handle],
+                "new_array",
+            )
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+
+        // Cast the object pointer to the array type
+        let array_ptr_ptr = self
+            .builder
+            .build_bitcast(
+                object_ptr,
+                array_ir_ty
+                    .ptr_type(AddressSpace::Generic)
+                    .ptr_type(AddressSpace::Generic),
+                "array_ptr_ptr",
+            )
+            .into_pointer_value();
+
+        // Load the actual memory location of the array
+        let mem_ptr = self
+            .builder
+            .build_load(array_ptr_ptr, "array_mem_ptr")
+            .into_pointer_value();
+
+        // Store the elements behind the length of the array
+        let elements_ptr = unsafe { self.builder.build_struct_gep(mem_ptr, 1, "elements_ptr") };
+        let elements_ptr = self
+            .builder
+            .build_bitcast(
+                elements_ptr,
+                elements.get_type().ptr_type(AddressSpace::Generic),
+                "elements_ptr",
+            )
+            .into_pointer_value();
+        self.builder.build_store(elements_ptr, elements);
+
+        array_ptr_ptr.into()
+    }
+
+    /// Generates IR for a tuple expression, e.g. `(a, 1.0)`. The unit tuple `()` is the empty
+    /// value.
+    fn gen_tuple(&mut self, expr: ExprId, elements: &[ExprId]) -> Option<BasicValueEnum<'ink>> {
+        let element_tys = match self.infer[expr].as_tuple() {
+            Some(element_tys) => element_tys.clone(),
+            None => return Some(self.gen_empty()),
+        };
+
+        let mut value: AggregateValueEnum = self
+            .hir_types
+            .get_tuple_type(&element_tys)
+            .get_undef()
+            .into();
+        for (i, element) in elements.iter().enumerate() {
+            let element = self.gen_expr(*element)?;
+            value = self
+                .builder
+                .build_insert_value(value, element, i as u32, "init")
+                .expect("Failed to initialize tuple element.");
+        }
+        Some(value.into_struct_value().into())
     }
 
     /// Generates IR for the specified block expression.
@@ -528,7 +1119,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         };
 
         match &self.body[pat] {
-            Pat::Bind { name } => {
+            Pat::Bind { name, .. } => {
                 let builder = self.new_alloca_builder();
                 let pat_ty = self.infer[pat].clone();
                 let ty = self
@@ -544,12 +1135,37 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                     };
                 }
             }
+            Pat::Tuple(_) => {
+                let pat_ty = self.infer[pat].clone();
+                let ty = self
+                    .hir_types
+                    .get_basic_type(&pat_ty)
+                    .expect("expected basic type");
+                let ptr = self.new_alloca_builder().build_alloca(ty, "tuple");
+                if let Some(value) = initializer {
+                    self.builder.build_store(ptr, value);
+                }
+                let resolver =
+                    hir::resolver_for_expr(self.body.clone(), self.db, self.body.body_expr());
+                self.gen_pat_bindings(pat, ptr, &resolver);
+            }
             Pat::Wild => {}
-            Pat::Missing | Pat::Path(_) => unreachable!(),
+            Pat::Missing | Pat::Path(_) | Pat::TupleStruct { .. } | Pat::Lit(_) => unreachable!(),
         }
         true
     }
 
+    /// Generates IR that binds the variables of the tuple pattern `pat` of a parameter to the
+    /// elements of its value `param`.
+    fn gen_param_destructuring(&mut self, pat: PatId, param: BasicValueEnum<'ink>) {
+        let param_ptr = self
+            .new_alloca_builder()
+            .build_alloca(param.get_type(), "tuple");
+        self.builder.build_store(param_ptr, param);
+        let resolver = hir::resolver_for_expr(self.body.clone(), self.db, self.body.body_expr());
+        self.gen_pat_bindings(pat, param_ptr, &resolver);
+    }
+
     /// Generates IR for looking up a certain path expression.
     fn gen_path_expr(
         &mut self,
@@ -563,18 +1179,35 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             .expect("unknown path");
 
         match resolution {
-            Resolution::LocalBinding(pat) => {
-                if let Some(param) = self.pat_to_param.get(&pat) {
-                    *param
-                } else if let Some(ptr) = self.pat_to_local.get(&pat) {
-                    let name = self.pat_to_name.get(&pat).expect("could not find pat name");
-                    self.builder.build_load(*ptr, &name)
-                } else {
-                    unreachable!("could not find the pattern..");
-                }
-            }
+            Resolution::LocalBinding(pat) => self.gen_local_binding(pat),
             Resolution::Def(hir::ModuleDef::Struct(_)) => self.gen_unit_struct_lit(expr),
+            Resolution::Def(hir::ModuleDef::EnumVariant(variant)) => {
+                self.gen_enum_variant_lit(variant, &[])
+            }
+            Resolution::Def(hir::ModuleDef::Const(c)) => {
+                // Constants are inlined where they are used
+                let value = GlobalDef::Const(c).value(self.db);
+                globals::gen_const_value(self.db, self.hir_types, &c.ty(self.db), &value)
+            }
+            Resolution::Def(hir::ModuleDef::Static(s)) => {
+                let ptr = self.gen_static_ptr(s);
+                self.builder
+                    .build_load(ptr, &s.name(self.db.upcast()).to_string())
+            }
             Resolution::Def(_) => panic!("no support for module definitions"),
+            Resolution::GenericParam(_) => unreachable!("generic parameters are not values"),
+        }
+    }
+
+    /// Generates IR that loads the value of the local binding `pat`.
+    fn gen_local_binding(&self, pat: PatId) -> BasicValueEnum<'ink> {
+        if let Some(param) = self.pat_to_param.get(&pat) {
+            *param
+        } else if let Some(ptr) = self.pat_to_local.get(&pat) {
+            let name = self.pat_to_name.get(&pat).expect("could not find pat name");
+            self.builder.build_load(*ptr, &name)
+        } else {
+            unreachable!("could not find the pattern..");
         }
     }
 
@@ -615,47 +1248,204 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 .pat_to_local
                 .get(&pat)
                 .expect("unresolved local binding"),
+            Resolution::Def(hir::ModuleDef::Static(s)) => self.gen_static_ptr(s),
             Resolution::Def(_) => panic!("no support for module definitions"),
+            Resolution::GenericParam(_) => unreachable!("generic parameters are not values"),
         }
     }
 
+    /// Returns a pointer to the global that stores the value of the specified static.
+    fn gen_static_ptr(&self, s: hir::Static) -> PointerValue<'ink> {
+        self.module
+            .get_global(&s.full_name(self.db))
+            .expect("could not find the global of a static")
+            .as_pointer_value()
+    }
+
     /// Generates IR to calculate a binary operation between two expressions.
     fn gen_binary_op(
         &mut self,
-        _tgt_expr: ExprId,
+        tgt_expr: ExprId,
         lhs: ExprId,
         rhs: ExprId,
         op: BinaryOp,
     ) -> Option<BasicValueEnum<'ink>> {
+        // An operator that is overloaded by a method is a call to that method
+        if let Some((function, substs)) = self.infer.method_resolution(tgt_expr) {
+            let function = FunctionInstance::resolve(self.db, function, &substs);
+            return self.gen_overloaded_binary_op(tgt_expr, lhs, rhs, op, &function);
+        }
+
         let lhs_type = self.infer[lhs].clone();
         match lhs_type.as_simple() {
             Some(TypeCtor::Bool) => self.gen_binary_op_bool(lhs, rhs, op),
             Some(TypeCtor::Float(_ty)) => self.gen_binary_op_float(lhs, rhs, op),
-            Some(TypeCtor::Int(ty)) => self.gen_binary_op_int(lhs, rhs, op, ty.signedness),
-            Some(TypeCtor::Struct(s)) => {
-                if s.data(self.db.upcast()).memory_kind == hir::StructMemoryKind::Value {
-                    self.gen_binary_op_value_struct(lhs, rhs, op)
-                } else {
-                    self.gen_binary_op_heap_struct(lhs, rhs, op)
-                }
-            }
-            _ => {
-                let rhs_type = self.infer[rhs].clone();
-                unimplemented!(
-                    "unimplemented operation {0}op{1}",
-                    lhs_type.display(self.db),
-                    rhs_type.display(self.db)
-                )
+            Some(TypeCtor::Int(ty)) => {
+                self.gen_binary_op_int(tgt_expr, lhs, rhs, op, ty.signedness)
             }
-        }
-    }
+            Some(TypeCtor::String) => self.gen_binary_op_string(lhs, rhs, op),
+            // Instantiations of generic structs are not simple types
+            _ if lhs_type.as_struct().is_some() => match op {
+                BinaryOp::CmpOp(CmpOp::Eq { negated }) => self.gen_eq_op(lhs, rhs, negated),
+                _ => {
+                    let s = lhs_type.as_struct().unwrap();
+                    if s.data(self.db.upcast()).memory_kind == hir::StructMemoryKind::Value {
+                        self.gen_binary_op_value_struct(lhs, rhs, op)
+                    } else {
+                        self.gen_binary_op_heap_struct(lhs, rhs, op)
+                    }
+                }
+            },
+            _ if lhs_type.as_tuple().is_some() => match op {
+                BinaryOp::CmpOp(CmpOp::Eq { negated }) => self.gen_eq_op(lhs, rhs, negated),
+                _ => self.gen_binary_op_assignment(lhs, rhs, op),
+            },
+            _ => self.gen_binary_op_assignment(lhs, rhs, op),
+        }
+    }
+
+    /// Generates IR for a binary operation of which the operator is overloaded by the method
+    /// `function`, e.g. `add` for `a + b`. The method is called with both operands. The result of
+    /// `a != b` is the negation of `a.eq(b)` and the result of `a += b` is assigned to `a`.
+    fn gen_overloaded_binary_op(
+        &mut self,
+        expr: ExprId,
+        lhs_expr: ExprId,
+        rhs_expr: ExprId,
+        op: BinaryOp,
+        function: &FunctionInstance,
+    ) -> Option<BasicValueEnum<'ink>> {
+        // The place of a compound assignment is computed once; the operand is loaded from it and
+        // the result is stored back through it.
+        if let BinaryOp::Assignment { .. } = op {
+            let place = self.gen_place_expr(lhs_expr);
+            let lhs = self.builder.build_load(place, "lhs");
+            let rhs = self.gen_expr(rhs_expr).expect("no rhs value");
+            let value = self.gen_call_expr(expr, function, &[lhs, rhs])?;
+            self.builder.build_store(place, value);
+            return Some(self.gen_empty());
+        }
+
+        let lhs = self.gen_expr(lhs_expr).expect("no lhs value");
+        let rhs = self.gen_expr(rhs_expr).expect("no rhs value");
+        let value = self.gen_call_expr(expr, function, &[lhs, rhs])?;
+        match op {
+            BinaryOp::CmpOp(CmpOp::Eq { negated: true }) => {
+                Some(self.builder.build_not(value.into_int_value(), "neq").into())
+            }
+            _ => Some(value),
+        }
+    }
+
+    /// Generates IR that compares two structs or tuples for equality. They are equal if all of
+    /// their fields are equal.
+    fn gen_eq_op(
+        &mut self,
+        lhs_expr: ExprId,
+        rhs_expr: ExprId,
+        negated: bool,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let ty = self.infer[lhs_expr].clone();
+        let lhs = self.gen_expr(lhs_expr).expect("no lhs value");
+        let rhs = self.gen_expr(rhs_expr).expect("no rhs value");
+        let eq = self.gen_eq(&ty, lhs, rhs);
+        if negated {
+            Some(self.builder.build_not(eq, "neq").into())
+        } else {
+            Some(eq.into())
+        }
+    }
+
+    /// Generates IR that compares the values `lhs` and `rhs` of type `ty` for equality. Structs and
+    /// tuples are compared field by field.
+    fn gen_eq(
+        &mut self,
+        ty: &hir::Ty,
+        lhs: BasicValueEnum<'ink>,
+        rhs: BasicValueEnum<'ink>,
+    ) -> IntValue<'ink> {
+        match ty {
+            hir::ty_app!(TypeCtor::Bool) | hir::ty_app!(TypeCtor::Int(_)) => {
+                self.builder.build_int_compare(
+                    IntPredicate::EQ,
+                    lhs.into_int_value(),
+                    rhs.into_int_value(),
+                    "eq",
+                )
+            }
+            hir::ty_app!(TypeCtor::Float(_)) => self.builder.build_float_compare(
+                FloatPredicate::OEQ,
+                lhs.into_float_value(),
+                rhs.into_float_value(),
+                "eq",
+            ),
+            hir::ty_app!(TypeCtor::String) => {
+                self.gen_string_eq(lhs.into_pointer_value(), rhs.into_pointer_value())
+            }
+            hir::ty_app!(TypeCtor::Struct(s), parameters) => {
+                let (lhs, rhs) =
+                    if s.data(self.db.upcast()).memory_kind == hir::StructMemoryKind::GC {
+                        (
+                            deref_heap_value(&self.builder, lhs),
+                            deref_heap_value(&self.builder, rhs),
+                        )
+                    } else {
+                        (lhs, rhs)
+                    };
+                let field_tys: Vec<_> = s
+                    .fields(self.db)
+                    .into_iter()
+                    .map(|field| field.ty(self.db).subst(parameters))
+                    .collect();
+                self.gen_fields_eq(&field_tys, lhs.into_struct_value(), rhs.into_struct_value())
+            }
+            hir::ty_app!(TypeCtor::Tuple { .. }, element_tys) => self.gen_fields_eq(
+                element_tys,
+                lhs.into_struct_value(),
+                rhs.into_struct_value(),
+            ),
+            _ => unreachable!(
+                "values of type {} cannot be compared for equality",
+                ty.display(self.db)
+            ),
+        }
+    }
+
+    /// Generates IR that compares all fields of the aggregate values `lhs` and `rhs`, of which the
+    /// fields have the types `field_tys`, for equality.
+    fn gen_fields_eq(
+        &mut self,
+        field_tys: &[hir::Ty],
+        lhs: StructValue<'ink>,
+        rhs: StructValue<'ink>,
+    ) -> IntValue<'ink> {
+        let mut eq = self.context.bool_type().const_int(1, false);
+        for (idx, field_ty) in field_tys.iter().enumerate() {
+            let lhs_field = self
+                .builder
+                .build_extract_value(lhs, idx as u32, "lhs_field")
+                .expect("could not extract field");
+            let rhs_field = self
+                .builder
+                .build_extract_value(rhs, idx as u32, "rhs_field")
+                .expect("could not extract field");
+            let field_eq = self.gen_eq(field_ty, lhs_field, rhs_field);
+            eq = self.builder.build_and(eq, field_eq, "eq");
+        }
+        eq
+    }
 
     /// Generates IR to calculate a unary operation on an expression.
-    fn gen_unary_op(&mut self, expr: ExprId, op: UnaryOp) -> Option<BasicValueEnum<'ink>> {
+    fn gen_unary_op(
+        &mut self,
+        tgt_expr: ExprId,
+        expr: ExprId,
+        op: UnaryOp,
+    ) -> Option<BasicValueEnum<'ink>> {
         let ty = self.infer[expr].clone();
         match ty.as_simple() {
             Some(TypeCtor::Float(_ty)) => self.gen_unary_op_float(expr, op),
-            Some(TypeCtor::Int(ty)) => self.gen_unary_op_int(expr, op, ty.signedness),
+            Some(TypeCtor::Int(ty)) => self.gen_unary_op_int(tgt_expr, expr, op, ty.signedness),
             Some(TypeCtor::Bool) => self.gen_unary_op_bool(expr, op),
             _ => unimplemented!("unimplemented operation op{0}", ty.display(self.db)),
         }
@@ -677,6 +1467,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// Generates IR to calculate a unary operation on an integer value.
     fn gen_unary_op_int(
         &mut self,
+        tgt_expr: ExprId,
         expr: ExprId,
         op: UnaryOp,
         signedness: hir::Signedness,
@@ -689,9 +1480,20 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         match op {
             UnaryOp::Neg => {
                 if signedness == hir::Signedness::Signed {
+                    if self.arithmetic_checks {
+                        let zero = value.get_type().const_zero();
+                        let (value, overflow) = self.gen_arith_with_overflow_int(
+                            zero,
+                            value,
+                            ArithOp::Subtract,
+                            signedness,
+                        );
+                        self.gen_panic_if(tgt_expr, overflow, "attempt to negate with overflow");
+                        return Some(value.into());
+                    }
                     Some(self.builder.build_int_neg(value, "neg").into())
                 } else {
-                    unimplemented!("Operator {:?} is not implemented for unsigned integer", op)
+                    unreachable!("Operator {:?} is not implemented for unsigned integer", op)
                 }
             }
             UnaryOp::Not => Some(self.builder.build_not(value, "not").into()),
@@ -712,6 +1514,133 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates IR to convert a value to the builtin type of the cast `expr`. Integers are truncated
+    /// or extended, a conversion of a floating-point value to an integer saturates at the bounds of
+    /// the integer type and converts `NaN` to zero.
+    fn gen_cast(&mut self, expr: ExprId, value_expr: ExprId) -> Option<BasicValueEnum<'ink>> {
+        let value = self
+            .gen_expr(value_expr)
+            .map(|value| self.opt_deref_value(value_expr, value))?;
+        let value_ty = self.infer[value_expr].clone();
+        let cast_ty = self.infer[expr].clone();
+        let value = match (value_ty.as_simple(), cast_ty.as_simple()) {
+            (Some(TypeCtor::Int(from_ty)), Some(TypeCtor::Int(to_ty))) => {
+                let value = value.into_int_value();
+                let int_type = self.hir_types.get_int_type(to_ty);
+                let from_width = value.get_type().get_bit_width();
+                let to_width = int_type.get_bit_width();
+                if from_width > to_width {
+                    self.builder.build_int_truncate(value, int_type, "cast")
+                } else if from_width == to_width {
+                    value
+                } else if from_ty.signedness == hir::Signedness::Signed {
+                    self.builder.build_int_s_extend(value, int_type, "cast")
+                } else {
+                    self.builder.build_int_z_extend(value, int_type, "cast")
+                }
+                .into()
+            }
+            (Some(TypeCtor::Bool), Some(TypeCtor::Int(to_ty))) => self
+                .builder
+                .build_int_z_extend(
+                    value.into_int_value(),
+                    self.hir_types.get_int_type(to_ty),
+                    "cast",
+                )
+                .into(),
+            (Some(TypeCtor::Int(from_ty)), Some(TypeCtor::Float(to_ty))) => {
+                let float_type = self.hir_types.get_float_type(to_ty);
+                if from_ty.signedness == hir::Signedness::Signed {
+                    self.builder.build_signed_int_to_float(
+                        value.into_int_value(),
+                        float_type,
+                        "cast",
+                    )
+                } else {
+                    self.builder.build_unsigned_int_to_float(
+                        value.into_int_value(),
+                        float_type,
+                        "cast",
+                    )
+                }
+                .into()
+            }
+            (Some(TypeCtor::Float(_)), Some(TypeCtor::Int(to_ty))) => self
+                .gen_float_to_int_cast(value.into_float_value(), to_ty)
+                .into(),
+            (Some(TypeCtor::Float(from_ty)), Some(TypeCtor::Float(to_ty))) => {
+                let float_type = self.hir_types.get_float_type(to_ty);
+                match (from_ty.bitness, to_ty.bitness) {
+                    (FloatBitness::X32, FloatBitness::X64) => self
+                        .builder
+                        .build_float_ext(value.into_float_value(), float_type, "cast")
+                        .into(),
+                    (FloatBitness::X64, FloatBitness::X32) => self
+                        .builder
+                        .build_float_trunc(value.into_float_value(), float_type, "cast")
+                        .into(),
+                    _ => value,
+                }
+            }
+            // Any other cast is an implicit conversion, which doesn't change the representation
+            _ => value,
+        };
+        Some(value)
+    }
+
+    /// Generates IR to convert a floating-point value to an integer of type `ty`. Values outside of
+    /// the range of the integer type saturate at its bounds and `NaN` is converted to zero.
+    fn gen_float_to_int_cast(&mut self, value: FloatValue<'ink>, ty: hir::IntTy) -> IntValue<'ink> {
+        let int_type = self.hir_types.get_int_type(ty);
+        let float_type = value.get_type();
+        let bit_width = int_type.get_bit_width();
+        let is_signed = ty.signedness == hir::Signedness::Signed;
+
+        // The bounds of the floating-point range are powers of two, so they can be represented
+        // exactly. Values greater than or equal to the upper bound don't fit in the integer type.
+        let (min_int, max_int) = int_bounds(int_type, is_signed);
+        let (min_float, max_float) = if is_signed {
+            let exp = bit_width as i32 - 1;
+            (-(2f64.powi(exp)), 2f64.powi(exp))
+        } else {
+            (0.0, 2f64.powi(bit_width as i32))
+        };
+
+        let int_value = if is_signed {
+            self.builder
+                .build_float_to_signed_int(value, int_type, "cast")
+        } else {
+            self.builder
+                .build_float_to_unsigned_int(value, int_type, "cast")
+        };
+        let is_below_min = self.builder.build_float_compare(
+            FloatPredicate::OLT,
+            value,
+            float_type.const_float(min_float),
+            "below_min",
+        );
+        let int_value = self
+            .builder
+            .build_select(is_below_min, min_int, int_value, "cast")
+            .into_int_value();
+        let is_above_max = self.builder.build_float_compare(
+            FloatPredicate::OGE,
+            value,
+            float_type.const_float(max_float),
+            "above_max",
+        );
+        let int_value = self
+            .builder
+            .build_select(is_above_max, max_int, int_value, "cast")
+            .into_int_value();
+        let is_nan = self
+            .builder
+            .build_float_compare(FloatPredicate::UNO, value, value, "is_nan");
+        self.builder
+            .build_select(is_nan, int_type.const_zero(), int_value, "cast")
+            .into_int_value()
+    }
+
     /// Generates IR to calculate a binary operation between two boolean value.
     fn gen_binary_op_bool(
         &mut self,
@@ -740,7 +1669,9 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 self.builder.build_store(place, rhs);
                 Some(self.gen_empty())
             }
-            BinaryOp::LogicOp(op) => Some(self.gen_logic_bin_op(lhs, rhs, op).into()),
+            BinaryOp::LogicOp(_) => {
+                unreachable!("logical operators are generated from the control-flow graph")
+            }
             BinaryOp::CmpOp(op) => Some(
                 self.gen_cmp_bin_op_int(lhs, rhs, op, hir::Signedness::Unsigned)
                     .into(),
@@ -810,6 +1741,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// Generates IR to calculate a binary operation between two integer values.
     fn gen_binary_op_int(
         &mut self,
+        tgt_expr: ExprId,
         lhs_expr: ExprId,
         rhs_expr: ExprId,
         op: BinaryOp,
@@ -826,13 +1758,14 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             .expect("no rhs value")
             .into_int_value();
         match op {
-            BinaryOp::ArithOp(op) => {
-                Some(self.gen_arith_bin_op_int(lhs, rhs, op, signedness).into())
-            }
+            BinaryOp::ArithOp(op) => Some(
+                self.gen_arith_bin_op_int(tgt_expr, lhs, rhs, op, signedness)
+                    .into(),
+            ),
             BinaryOp::CmpOp(op) => Some(self.gen_cmp_bin_op_int(lhs, rhs, op, signedness).into()),
             BinaryOp::Assignment { op } => {
                 let rhs = match op {
-                    Some(op) => self.gen_arith_bin_op_int(lhs, rhs, op, signedness),
+                    Some(op) => self.gen_arith_bin_op_int(tgt_expr, lhs, rhs, op, signedness),
                     None => rhs,
                 };
                 let place = self.gen_place_expr(lhs_expr);
@@ -843,6 +1776,150 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates IR to calculate a binary operation between two values that can only be assigned,
+    /// e.g. arrays, nullable values and function pointers.
+    fn gen_binary_op_assignment(
+        &mut self,
+        lhs_expr: ExprId,
+        rhs_expr: ExprId,
+        op: BinaryOp,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let rhs = self.gen_expr(rhs_expr).expect("no rhs value");
+        match op {
+            BinaryOp::Assignment { op: None } => {
+                let place = self.gen_place_expr(lhs_expr);
+                self.builder.build_store(place, rhs);
+                Some(self.gen_empty())
+            }
+            _ => unreachable!(format!(
+                "Operator {:?} is not implemented for {}",
+                op,
+                self.infer[lhs_expr].display(self.db)
+            )),
+        }
+    }
+
+    /// Generates IR to calculate a binary operation between two strings. Strings can be
+    /// concatenated, compared for equality, and assigned.
+    fn gen_binary_op_string(
+        &mut self,
+        lhs_expr: ExprId,
+        rhs_expr: ExprId,
+        op: BinaryOp,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let lhs = self
+            .gen_expr(lhs_expr)
+            .expect("no lhs value")
+            .into_pointer_value();
+        let rhs = self
+            .gen_expr(rhs_expr)
+            .expect("no rhs value")
+            .into_pointer_value();
+        match op {
+            BinaryOp::ArithOp(ArithOp::Add) => Some(self.gen_string_concat(lhs, rhs).into()),
+            BinaryOp::CmpOp(CmpOp::Eq { negated }) => {
+                let eq = self.gen_string_eq(lhs, rhs);
+                if negated {
+                    Some(self.builder.build_not(eq, "neq").into())
+                } else {
+                    Some(eq.into())
+                }
+            }
+            BinaryOp::Assignment { op } => {
+                let rhs = match op {
+                    Some(ArithOp::Add) => self.gen_string_concat(lhs, rhs),
+                    Some(op) => unreachable!(format!(
+                        "Assignment with {:?} operator is not implemented for strings",
+                        op
+                    )),
+                    None => rhs,
+                };
+                let place = self.gen_place_expr(lhs_expr);
+                self.builder.build_store(place, rhs);
+                Some(self.gen_empty())
+            }
+            _ => unreachable!(format!("Operator {:?} is not implemented for strings", op)),
+        }
+    }
+
+    /// Generates IR that allocates a new string containing the text of `lhs` followed by the text
+    /// of `rhs`.
+    fn gen_string_concat(
+        &mut self,
+        lhs: PointerValue<'ink>,
+        rhs: PointerValue<'ink>,
+    ) -> PointerValue<'ink> {
+        let string_concat_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::string_concat,
+        );
+
+        let allocator_handle = self.builder.build_load(
+            self.external_globals
+                .alloc_handle
+                .expect("no allocator handle was specified, this is required for strings")
+                .as_pointer_value(),
+            "allocator_handle",
+        );
+
+        let lhs = self.gen_string_to_object_ptr(lhs);
+        let rhs = self.gen_string_to_object_ptr(rhs);
+        let object_ptr = self
+            .builder
+            .build_call(
+                string_concat_fn_ptr,
+                &[lhs, rhs, allocator_handle],
+                "string_concat",
+            )
+            .try_as_basic_value()
+            .left()
+            .unwrap();
+
+        // Cast the object pointer back to the string type
+        self.builder
+            .build_bitcast(
+                object_ptr,
+                self.hir_types.get_string_reference_type(),
+                "string_ptr_ptr",
+            )
+            .into_pointer_value()
+    }
+
+    /// Generates IR that compares the text of the strings `lhs` and `rhs` for equality.
+    fn gen_string_eq(
+        &mut self,
+        lhs: PointerValue<'ink>,
+        rhs: PointerValue<'ink>,
+    ) -> IntValue<'ink> {
+        let string_eq_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::string_eq,
+        );
+
+        let lhs = self.gen_string_to_object_ptr(lhs);
+        let rhs = self.gen_string_to_object_ptr(rhs);
+        self.builder
+            .build_call(string_eq_fn_ptr, &[lhs, rhs], "string_eq")
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value()
+    }
+
+    /// Casts a string to the object pointer type that is used by intrinsics.
+    fn gen_string_to_object_ptr(&self, string: PointerValue<'ink>) -> BasicValueEnum<'ink> {
+        self.builder.build_bitcast(
+            string,
+            self.context
+                .i8_type()
+                .ptr_type(AddressSpace::Generic)
+                .ptr_type(AddressSpace::Generic),
+            "object_ptr",
+        )
+    }
+
     /// Generates IR to calculate a binary operation between two heap struct values (e.g. a Mun
     /// `struct(gc)`).
     fn gen_binary_op_heap_struct(
@@ -973,13 +2050,31 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         self.builder.build_int_compare(predicate, lhs, rhs, name)
     }
 
+    /// Generates IR to calculate an arithmetic operation between two integers. A division by zero
+    /// aborts the invocation of the function, as does an overflow if arithmetic checks are enabled.
     fn gen_arith_bin_op_int(
         &mut self,
+        tgt_expr: ExprId,
         lhs: IntValue<'ink>,
         rhs: IntValue<'ink>,
         op: ArithOp,
         signedness: hir::Signedness,
     ) -> IntValue<'ink> {
+        // Only overflow checks can be disabled. A division by zero or an oversized shift is
+        // undefined behavior in LLVM, so those are always checked.
+        match op {
+            ArithOp::Add | ArithOp::Subtract | ArithOp::Multiply => {
+                if self.arithmetic_checks {
+                    return self.gen_checked_arith_op_int(tgt_expr, lhs, rhs, op, signedness);
+                }
+            }
+            ArithOp::Divide | ArithOp::Remainder => {
+                self.gen_division_check(tgt_expr, lhs, rhs, op, signedness)
+            }
+            ArithOp::LeftShift | ArithOp::RightShift => self.gen_shift_check(tgt_expr, rhs, op),
+            ArithOp::BitAnd | ArithOp::BitOr | ArithOp::BitXor => (),
+        }
+
         match op {
             ArithOp::Add => self.builder.build_int_add(lhs, rhs, "add"),
             ArithOp::Subtract => self.builder.build_int_sub(lhs, rhs, "sub"),
@@ -1003,6 +2098,202 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates IR that calls the LLVM intrinsic that calculates an addition, subtraction, or
+    /// multiplication of two integers, e.g. `llvm.sadd.with.overflow.i32`. Returns the wrapped
+    /// result and whether the operation overflowed.
+    fn gen_arith_with_overflow_int(
+        &mut self,
+        lhs: IntValue<'ink>,
+        rhs: IntValue<'ink>,
+        op: ArithOp,
+        signedness: hir::Signedness,
+    ) -> (IntValue<'ink>, IntValue<'ink>) {
+        let op_name = match op {
+            ArithOp::Add => "add",
+            ArithOp::Subtract => "sub",
+            ArithOp::Multiply => "mul",
+            _ => unreachable!(format!("Operator {:?} has no overflow intrinsic", op)),
+        };
+        let int_type = lhs.get_type();
+        let intrinsic_name = format!(
+            "llvm.{}{}.with.overflow.i{}",
+            if signedness.is_signed() { "s" } else { "u" },
+            op_name,
+            int_type.get_bit_width()
+        );
+        let intrinsic = self
+            .module
+            .get_function(&intrinsic_name)
+            .unwrap_or_else(|| {
+                let return_type = self
+                    .context
+                    .struct_type(&[int_type.into(), self.context.bool_type().into()], false);
+                self.module.add_function(
+                    &intrinsic_name,
+                    return_type.fn_type(&[int_type.into(), int_type.into()], false),
+                    None,
+                )
+            });
+
+        let result = self
+            .builder
+            .build_call(intrinsic, &[lhs.into(), rhs.into()], op_name)
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_struct_value();
+        let value = self
+            .builder
+            .build_extract_value(result, 0, op_name)
+            .unwrap()
+            .into_int_value();
+        let overflow = self
+            .builder
+            .build_extract_value(result, 1, "overflow")
+            .unwrap()
+            .into_int_value();
+        (value, overflow)
+    }
+
+    /// Generates IR to calculate an addition, subtraction, or multiplication of two integers that
+    /// aborts the invocation of the function if it overflows.
+    fn gen_checked_arith_op_int(
+        &mut self,
+        tgt_expr: ExprId,
+        lhs: IntValue<'ink>,
+        rhs: IntValue<'ink>,
+        op: ArithOp,
+        signedness: hir::Signedness,
+    ) -> IntValue<'ink> {
+        let (value, overflow) = self.gen_arith_with_overflow_int(lhs, rhs, op, signedness);
+        let message = match op {
+            ArithOp::Add => "attempt to add with overflow",
+            ArithOp::Subtract => "attempt to subtract with overflow",
+            _ => "attempt to multiply with overflow",
+        };
+        self.gen_panic_if(tgt_expr, overflow, message);
+        value
+    }
+
+    /// Generates IR to calculate an addition, subtraction, or multiplication of two integers of
+    /// which the result saturates at the bounds of their type.
+    fn gen_saturating_arith_op_int(
+        &mut self,
+        lhs: IntValue<'ink>,
+        rhs: IntValue<'ink>,
+        op: ArithOp,
+        signedness: hir::Signedness,
+    ) -> IntValue<'ink> {
+        let (value, overflow) = self.gen_arith_with_overflow_int(lhs, rhs, op, signedness);
+        let int_type = lhs.get_type();
+        let (min, max) = int_bounds(int_type, signedness.is_signed());
+
+        // An unsigned operation can only overflow in one direction, except for a subtraction.
+        // The direction in which a signed operation overflows depends on the signs of its
+        // operands.
+        let saturated = if signedness.is_signed() {
+            let zero = int_type.const_zero();
+            let overflows_to_max = match op {
+                ArithOp::Add => {
+                    self.builder
+                        .build_int_compare(IntPredicate::SGE, rhs, zero, "is_positive")
+                }
+                ArithOp::Subtract => {
+                    self.builder
+                        .build_int_compare(IntPredicate::SLT, rhs, zero, "is_negative")
+                }
+                _ => {
+                    let lhs_is_negative = self.builder.build_int_compare(
+                        IntPredicate::SLT,
+                        lhs,
+                        zero,
+                        "lhs_is_negative",
+                    );
+                    let rhs_is_negative = self.builder.build_int_compare(
+                        IntPredicate::SLT,
+                        rhs,
+                        zero,
+                        "rhs_is_negative",
+                    );
+                    let is_negative =
+                        self.builder
+                            .build_xor(lhs_is_negative, rhs_is_negative, "is_negative");
+                    self.builder.build_not(is_negative, "is_positive")
+                }
+            };
+            self.builder
+                .build_select(overflows_to_max, max, min, "bound")
+                .into_int_value()
+        } else if op == ArithOp::Subtract {
+            min
+        } else {
+            max
+        };
+
+        self.builder
+            .build_select(overflow, saturated, value, "saturating")
+            .into_int_value()
+    }
+
+    /// Generates IR that aborts the invocation of the function if the integer division or
+    /// remainder of `lhs` by `rhs` is undefined. That is the case if `rhs` is zero, or if the
+    /// minimum value of a signed type is divided by `-1`.
+    fn gen_division_check(
+        &mut self,
+        tgt_expr: ExprId,
+        lhs: IntValue<'ink>,
+        rhs: IntValue<'ink>,
+        op: ArithOp,
+        signedness: hir::Signedness,
+    ) {
+        let int_type = rhs.get_type();
+        let is_zero =
+            self.builder
+                .build_int_compare(IntPredicate::EQ, rhs, int_type.const_zero(), "is_zero");
+        let message = if op == ArithOp::Divide {
+            "attempt to divide by zero"
+        } else {
+            "attempt to calculate the remainder with a divisor of zero"
+        };
+        self.gen_panic_if(tgt_expr, is_zero, message);
+
+        if signedness.is_signed() {
+            let (min, _) = int_bounds(int_type, true);
+            let is_min = self
+                .builder
+                .build_int_compare(IntPredicate::EQ, lhs, min, "is_min");
+            let is_minus_one = self.builder.build_int_compare(
+                IntPredicate::EQ,
+                rhs,
+                int_type.const_all_ones(),
+                "is_minus_one",
+            );
+            let overflow = self.builder.build_and(is_min, is_minus_one, "overflow");
+            let message = if op == ArithOp::Divide {
+                "attempt to divide with overflow"
+            } else {
+                "attempt to calculate the remainder with overflow"
+            };
+            self.gen_panic_if(tgt_expr, overflow, message);
+        }
+    }
+
+    /// Generates IR that aborts the invocation of the function if the shift amount `rhs` is not
+    /// smaller than the number of bits of its type.
+    fn gen_shift_check(&mut self, tgt_expr: ExprId, rhs: IntValue<'ink>, op: ArithOp) {
+        let int_type = rhs.get_type();
+        let bit_width = int_type.const_int(int_type.get_bit_width() as u64, false);
+        let overflow =
+            self.builder
+                .build_int_compare(IntPredicate::UGE, rhs, bit_width, "overflow");
+        let message = if op == ArithOp::LeftShift {
+            "attempt to shift left with overflow"
+        } else {
+            "attempt to shift right with overflow"
+        };
+        self.gen_panic_if(tgt_expr, overflow, message);
+    }
+
     fn gen_arith_bin_op_float(
         &mut self,
         lhs: FloatValue<'ink>,
@@ -1025,18 +2316,6 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
-    fn gen_logic_bin_op(
-        &mut self,
-        lhs: IntValue<'ink>,
-        rhs: IntValue<'ink>,
-        op: LogicOp,
-    ) -> IntValue<'ink> {
-        match op {
-            LogicOp::And => self.builder.build_and(lhs, rhs, "and"),
-            LogicOp::Or => self.builder.build_or(lhs, rhs, "or"),
-        }
-    }
-
     /// Given an expression generate code that results in a memory address that can be used for
     /// other place operations.
     fn gen_place_expr(&mut self, expr: ExprId) -> PointerValue<'ink> {
@@ -1050,6 +2329,9 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 expr: receiver_expr,
                 name,
             } => self.gen_place_field(expr, *receiver_expr, name),
+            Expr::Index { base, index } => self
+                .gen_array_element_ptr(expr, *base, *index)
+                .expect("index expression never returns"),
             _ => unreachable!("invalid place expression"),
         }
     }
@@ -1059,28 +2341,38 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     fn is_place_expr(&self, expr: ExprId) -> bool {
         let body = self.body.clone();
         match &body[expr] {
-            Expr::Path(..) => true,
+            // Constants are inlined, so they don't have a memory address
+            Expr::Path(p) => {
+                let resolver = hir::resolver_for_expr(self.body.clone(), self.db, expr);
+                !matches!(
+                    resolver
+                        .resolve_path_without_assoc_items(self.db, p)
+                        .take_values(),
+                    Some(Resolution::Def(hir::ModuleDef::Const(_)))
+                )
+            }
             Expr::Field { expr, .. } => self.is_place_expr(*expr),
+            Expr::Index { .. } => true,
             _ => false,
         }
     }
 
     /// Returns true if a call to the specified function should be looked up in the dispatch table;
     /// if false is returned the function should be called directly.
-    fn should_use_dispatch_table(&self, _function: hir::Function) -> bool {
+    fn should_use_dispatch_table(&self, _function: &FunctionInstance) -> bool {
         // TODO: add logic to determine when to use the dispatch table (dont use it in release for
         //  example)
         true
     }
 
-    /// Generates IR for a function call.
+    /// Generates IR for a function call and returns the value of the call, if any.
     fn gen_call(
         &mut self,
-        function: hir::Function,
+        function: &FunctionInstance,
         args: &[BasicValueEnum<'ink>],
         allow_dispatch_table: bool,
-    ) -> CallSiteValue<'ink> {
-        if self.dispatch_table.contains(function)
+    ) -> Option<BasicValueEnum<'ink>> {
+        let ret_value = if self.dispatch_table.contains(function)
             && allow_dispatch_table
             && self.should_use_dispatch_table(function)
         {
@@ -1090,33 +2382,397 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 &self.builder,
                 function,
             );
+
+            // Functions of other modules are called through the public API of their assembly
+            if self.dispatch_table.is_external(self.db, function) {
+                return self.gen_public_call(function, ptr_value, args);
+            }
+
             self.builder
-                .build_call(ptr_value, &args, &function.name(self.db).to_string())
+                .build_call(ptr_value, &args, &function.name(self.db))
+                .try_as_basic_value()
+                .left()
         } else {
-            let llvm_function = self.function_map.get(&function).unwrap_or_else(|| {
+            let llvm_function = self.function_map.get(function).unwrap_or_else(|| {
                 panic!(
                     "missing function value for hir function: '{}'",
                     function.name(self.db),
                 )
             });
             self.builder
-                .build_call(*llvm_function, &args, &function.name(self.db).to_string())
+                .build_call(*llvm_function, &args, &function.name(self.db))
+                .try_as_basic_value()
+                .left()
+        };
+
+        // Extern functions are implemented by the host, so they cannot panic
+        if !function.function.is_extern(self.db) {
+            self.gen_return_if_panicking();
+        }
+        ret_value
+    }
+
+    /// Generates IR for a call to the public API of a function, e.g. to a function that is defined
+    /// in another assembly. Value structs, enums, and tuples are passed to and returned from the
+    /// public API as heap-allocated values.
+    fn gen_public_call(
+        &mut self,
+        function: &FunctionInstance,
+        ptr_value: PointerValue<'ink>,
+        args: &[BasicValueEnum<'ink>],
+    ) -> Option<BasicValueEnum<'ink>> {
+        let fn_sig = function.callable_sig(self.db);
+        let args: Vec<BasicValueEnum> = fn_sig
+            .params()
+            .iter()
+            .zip(args.iter())
+            .map(|(ty, arg)| {
+                if is_heap_value_in_public_api(self.db, ty) {
+                    self.gen_alloc_on_heap(ty, arg.into_struct_value())
+                } else {
+                    *arg
+                }
+            })
+            .collect();
+
+        let ret_value = self
+            .builder
+            .build_call(ptr_value, &args, &function.name(self.db))
+            .try_as_basic_value()
+            .left();
+        self.gen_return_if_panicking();
+
+        let ret_value = ret_value?;
+        if is_heap_value_in_public_api(self.db, fn_sig.ret()) {
+            Some(deref_heap_value(&self.builder, ret_value))
+        } else {
+            Some(ret_value)
+        }
+    }
+
+    /// Generates IR for a call expression to the specified function and returns the value of the
+    /// call.
+    fn gen_call_expr(
+        &mut self,
+        expr: ExprId,
+        function: &FunctionInstance,
+        args: &[BasicValueEnum<'ink>],
+    ) -> Option<BasicValueEnum<'ink>> {
+        self.gen_call(function, args, true)
+            // If the called function is a void function it doesn't return anything.
+            // If this method (`gen_expr`) returns None we assume the return value
+            // is `never`. We return a const unit struct here to ensure that at
+            // least something is returned. This matches with the hir where a
+            // `nothing` is returned instead of a `never`.
+            //
+            // This unit value will also be optimized out.
+            .or_else(|| match self.infer[expr] {
+                hir::ty_app!(hir::TypeCtor::Never) => None,
+                _ => Some(self.context.const_struct(&[], false).into()),
+            })
+    }
+
+    /// Generates IR for a call to the closure `callee`, e.g. `f(1)` where `f: fn(i32) -> i32`, and
+    /// returns the value of the call.
+    fn gen_closure_call(
+        &mut self,
+        expr: ExprId,
+        callee: ExprId,
+        args: &[ExprId],
+    ) -> Option<BasicValueEnum<'ink>> {
+        let sig = self.infer[callee]
+            .callable_sig(self.db)
+            .expect("expected a callable expression");
+
+        let closure_ptr_ptr = self.gen_expr(callee)?.into_pointer_value();
+        let args: Vec<BasicValueEnum> = args
+            .iter()
+            .map(|expr| self.gen_expr(*expr).expect("expected a value"))
+            .collect();
+
+        // Load the function pointer and the handle to the captured environment of the closure
+        let closure_ptr = self
+            .builder
+            .build_load(closure_ptr_ptr, "closure_mem_ptr")
+            .into_pointer_value();
+        let fn_ptr_ptr = unsafe { self.builder.build_struct_gep(closure_ptr, 0, "fn_ptr_ptr") };
+        let env_ptr = unsafe { self.builder.build_struct_gep(closure_ptr, 1, "env_ptr") };
+        let fn_ptr = self.builder.build_load(fn_ptr_ptr, "fn_ptr");
+        let env = self.builder.build_load(env_ptr, "env");
+
+        let fn_ptr = self
+            .builder
+            .build_bitcast(
+                fn_ptr,
+                self.hir_types
+                    .get_closure_function_type(&sig)
+                    .ptr_type(AddressSpace::Generic),
+                "closure_fn_ptr",
+            )
+            .into_pointer_value();
+
+        let args: Vec<BasicValueEnum> = std::iter::once(env).chain(args).collect();
+        let ret_value = self
+            .builder
+            .build_call(fn_ptr, &args, "closure_call")
+            .try_as_basic_value()
+            .left();
+        self.gen_return_if_panicking();
+
+        // See `gen_call_expr`
+        ret_value.or_else(|| match self.infer[expr] {
+            hir::ty_app!(hir::TypeCtor::Never) => None,
+            _ => Some(self.context.const_struct(&[], false).into()),
+        })
+    }
+
+    /// Generates IR for a lambda expression, e.g. `|x| x + a`. The body of the lambda is generated
+    /// as a separate function and the variables it captures are copied into a garbage collected
+    /// environment. Returns a pointer to the object pointer of the resulting closure.
+    fn gen_lambda(&mut self, expr: ExprId) -> BasicValueEnum<'ink> {
+        let closure_ty = self.infer[expr].clone();
+        let sig = closure_ty
+            .callable_sig(self.db)
+            .expect("expected a function type");
+        let captures = self
+            .db
+            .expr_scopes(self.instance.function.into())
+            .captures(expr);
+
+        // Generate the function that contains the body of the lambda
+        let fn_value = self.module.add_function(
+            &format!(
+                "{}::lambda.{}",
+                self.instance.name(self.db),
+                u32::from(expr.into_raw())
+            ),
+            self.hir_types.get_closure_function_type(&sig),
+            Some(Linkage::Private),
+        );
+        self.new_closure_generator(fn_value)
+            .gen_lambda_body(expr, &captures);
+
+        // Copy the captured variables into the environment of the closure
+        let env_ptr_ty = self
+            .context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic);
+        let env = if captures.is_empty() {
+            env_ptr_ty.const_null().into()
+        } else {
+            let capture_types: Vec<hir::Ty> = captures
+                .iter()
+                .map(|pat| self.infer[*pat].clone())
+                .collect();
+            let env_ty = self.hir_types.get_closure_env_type(&capture_types);
+            let mut value: AggregateValueEnum = env_ty.get_undef().into();
+            for (idx, pat) in captures.iter().enumerate() {
+                let capture = self.gen_local_binding(*pat);
+                value = self
+                    .builder
+                    .build_insert_value(value, capture, idx as u32, "capture")
+                    .expect("Failed to initialize closure environment.");
+            }
+            let type_info = self.hir_types.closure_env_type_info(capture_types);
+            let env = self.gen_alloc_type_on_heap(&type_info, value.into_struct_value());
+            self.builder.build_bitcast(env, env_ptr_ty, "env")
+        };
+
+        self.gen_closure_alloc(&closure_ty, fn_value, env)
+    }
+
+    /// Generates IR for the body of the lambda `expr` in this generator's function. The first
+    /// parameter of the function is the handle to the captured variables `captures`.
+    fn gen_lambda_body(&mut self, expr: ExprId, captures: &[PatId]) {
+        let body = self.body.clone(); // Avoid borrow issues
+        let (args, body_expr) = match &body[expr] {
+            Expr::Lambda { args, body, .. } => (args, *body),
+            _ => unreachable!("expected a lambda expression"),
+        };
+
+        // Load the captured variables from the environment and store them so we can reference
+        // them later in code.
+        if !captures.is_empty() {
+            let capture_types: Vec<hir::Ty> = captures
+                .iter()
+                .map(|pat| self.infer[*pat].clone())
+                .collect();
+            let env_ty = self.hir_types.get_closure_env_type(&capture_types);
+            let env_ptr_ptr = self
+                .builder
+                .build_bitcast(
+                    self.fn_value.get_first_param().unwrap(),
+                    env_ty
+                        .ptr_type(AddressSpace::Generic)
+                        .ptr_type(AddressSpace::Generic),
+                    "env_ptr_ptr",
+                )
+                .into_pointer_value();
+            let env_ptr = self
+                .builder
+                .build_load(env_ptr_ptr, "env_mem_ptr")
+                .into_pointer_value();
+
+            for (idx, pat) in captures.iter().enumerate() {
+                let name = match &body[*pat] {
+                    Pat::Bind { name, .. } => name.to_string(),
+                    _ => unreachable!("only bindings can be captured"),
+                };
+                let capture_ptr = unsafe {
+                    self.builder
+                        .build_struct_gep(env_ptr, idx as u32, &format!("{}_ptr", name))
+                };
+                let capture = self.builder.build_load(capture_ptr, &name);
+                let local_ptr = self
+                    .new_alloca_builder()
+                    .build_alloca(capture.get_type(), &name);
+                self.builder.build_store(local_ptr, capture);
+                self.pat_to_local.insert(*pat, local_ptr);
+                self.pat_to_name.insert(*pat, name);
+            }
+        }
+
+        // The parameters of the lambda follow the handle to its environment
+        for (i, (pat, _)) in args.iter().enumerate() {
+            match &body[*pat] {
+                Pat::Bind { name, .. } => {
+                    let name = name.to_string();
+                    let param = self.fn_value.get_nth_param(i as u32 + 1).unwrap();
+                    let builder = self.new_alloca_builder();
+                    let param_ptr = builder.build_alloca(param.get_type(), &name);
+                    builder.build_store(param_ptr, param);
+                    self.pat_to_local.insert(*pat, param_ptr);
+                    self.pat_to_name.insert(*pat, name);
+                }
+                Pat::Wild => {
+                    // Wildcard patterns cannot be referenced from code. So nothing to do.
+                }
+                Pat::Tuple(_) => {
+                    let param = self.fn_value.get_nth_param(i as u32 + 1).unwrap();
+                    self.gen_param_destructuring(*pat, param);
+                }
+                Pat::Path(_) | Pat::TupleStruct { .. } | Pat::Lit(_) => unreachable!(
+                    "Refutable parameter patterns are not supported, are we missing a diagnostic?"
+                ),
+                Pat::Missing => unreachable!(
+                    "found missing Pattern, should not be generating IR for incomplete code"
+                ),
+            }
+        }
+
+        self.gen_uninitialized_lets(body_expr);
+        let entry = self
+            .graph
+            .cfg
+            .lambda_entry(expr)
+            .expect("a lambda must have an entry block");
+        let ret_value = match self.gen_blocks(entry) {
+            Flow::Return => self.gen_expr(body_expr),
+            _ => None,
+        };
+        let sig = self.infer[expr]
+            .callable_sig(self.db)
+            .expect("expected a function type");
+        if !self.infer[body_expr].is_never() {
+            if sig.ret().is_empty() {
+                self.builder.build_return(None);
+            } else if let Some(value) = ret_value {
+                self.builder.build_return(Some(&value));
+            }
         }
     }
 
-    /// Generates IR for an if statement.
+    /// Generates IR for a reference to the function `function` as a value, e.g. `let f = foo`. The
+    /// resulting closure calls a thunk that forwards its arguments to the function.
+    fn gen_function_value(
+        &mut self,
+        expr: ExprId,
+        function: &FunctionInstance,
+    ) -> BasicValueEnum<'ink> {
+        let closure_ty = self.infer[expr].clone();
+        let thunk_name = format!("{}::closure", function.name(self.db));
+        let thunk = match self.module.get_function(&thunk_name) {
+            Some(thunk) => thunk,
+            None => {
+                let sig = function.callable_sig(self.db);
+                let thunk = self.module.add_function(
+                    &thunk_name,
+                    self.hir_types.get_closure_function_type(&sig),
+                    Some(Linkage::Private),
+                );
+
+                let mut generator = self.new_closure_generator(thunk);
+                let args: Vec<BasicValueEnum> = thunk.get_params().into_iter().skip(1).collect();
+                let ret_value = generator.gen_call(function, &args, true);
+                match ret_value {
+                    Some(value) if !sig.ret().is_empty() => {
+                        generator.builder.build_return(Some(&value))
+                    }
+                    _ if sig.ret().is_never() => generator.builder.build_unreachable(),
+                    _ => generator.builder.build_return(None),
+                };
+                thunk
+            }
+        };
+
+        let env = self
+            .context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic)
+            .const_null();
+        self.gen_closure_alloc(&closure_ty, thunk, env.into())
+    }
+
+    /// Allocates a closure of type `ty` on the heap, that calls `fn_value` with the handle to its
+    /// captured environment `env`.
+    fn gen_closure_alloc(
+        &mut self,
+        ty: &hir::Ty,
+        fn_value: FunctionValue<'ink>,
+        env: BasicValueEnum<'ink>,
+    ) -> BasicValueEnum<'ink> {
+        let fn_ptr = self.builder.build_bitcast(
+            fn_value.as_global_value().as_pointer_value(),
+            self.context.i8_type().ptr_type(AddressSpace::Generic),
+            "fn_ptr",
+        );
+
+        let closure_ty = self.hir_types.get_closure_type();
+        let closure = closure_ty.const_zero();
+        let closure = self
+            .builder
+            .build_insert_value(closure, fn_ptr, 0, "closure_fn")
+            .expect("Failed to initialize closure function.");
+        let closure = self
+            .builder
+            .build_insert_value(closure, env, 1, "closure_env")
+            .expect("Failed to initialize closure environment.");
+
+        self.gen_alloc_on_heap(ty, closure.into_struct_value())
+    }
+
+    /// Generates IR for an if statement, of which the condition has been evaluated. The branches
+    /// start at `cfg_then_block` and `cfg_else_block` of the control-flow graph. Returns the block
+    /// of the graph in which execution continues after the statement.
     fn gen_if(
         &mut self,
-        _expr: ExprId,
-        condition: ExprId,
-        then_branch: ExprId,
-        else_branch: Option<ExprId>,
-    ) -> Option<inkwell::values::BasicValueEnum<'ink>> {
+        expr: ExprId,
+        cfg_then_block: hir::BasicBlock,
+        cfg_else_block: hir::BasicBlock,
+    ) -> Option<hir::BasicBlock> {
+        let (condition, then_branch, else_branch) = match &self.body[expr] {
+            Expr::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => (*condition, *then_branch, *else_branch),
+            _ => unreachable!("expected an if expression"),
+        };
+
         // Generate IR for the condition
-        let condition_ir = self
-            .gen_expr(condition)
-            .map(|value| self.opt_deref_value(condition, value))?
-            .into_int_value();
+        let (condition_ir, scrutinee_ptr) = self.gen_condition(condition)?;
 
         // Generate the code blocks to branch to
         let mut then_block = self.context.append_basic_block(self.fn_value, "then");
@@ -1136,24 +2792,33 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
 
         // Fill the then block
         self.builder.position_at_end(then_block);
-        let then_block_ir = self.gen_expr(then_branch);
+        if let Some(scrutinee_ptr) = scrutinee_ptr {
+            self.bind_condition(condition, scrutinee_ptr);
+        }
+        let then_join_block = self.gen_blocks(cfg_then_block).goto();
+        let then_block_ir = then_join_block.and_then(|_| self.gen_expr(then_branch));
         if !self.infer[then_branch].is_never() {
             self.builder.build_unconditional_branch(merge_block);
         }
         then_block = self.builder.get_insert_block().unwrap();
 
-        // Fill the else block, if it exists and get the result back
+        // Fill the else block, if it exists and get the result back. Without an else block, the
+        // condition jumps to the join block of the control-flow graph directly.
+        let mut join_block = then_join_block;
         let else_ir_and_block = if let Some((else_block, else_branch)) = else_block_and_expr {
             else_block
                 .move_after(then_block)
                 .expect("programmer error, then_block is invalid");
             self.builder.position_at_end(else_block);
-            let result_ir = self.gen_expr(*else_branch);
+            let else_join_block = self.gen_blocks(cfg_else_block).goto();
+            join_block = join_block.or(else_join_block);
+            let result_ir = else_join_block.and_then(|_| self.gen_expr(*else_branch));
             if !self.infer[*else_branch].is_never() {
                 self.builder.build_unconditional_branch(merge_block);
             }
             Some((result_ir, self.builder.get_insert_block().unwrap()))
         } else {
+            join_block = Some(cfg_else_block);
             None
         };
 
@@ -1163,7 +2828,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         self.builder.position_at_end(merge_block);
 
         // Construct phi block if a value was returned
-        if let Some(then_block_ir) = then_block_ir {
+        let value = if let Some(then_block_ir) = then_block_ir {
             if let Some((Some(else_block_ir), else_block)) = else_ir_and_block {
                 let phi = self.builder.build_phi(then_block_ir.get_type(), "iftmp");
                 phi.add_incoming(&[(&then_block_ir, then_block), (&else_block_ir, else_block)]);
@@ -1175,14 +2840,391 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             else_block_ir
         } else {
             Some(self.gen_empty())
+        };
+        self.values.insert(expr, value);
+        join_block
+    }
+
+    /// Generates IR for the right-hand side of the logical operator `expr`, of which the left-hand
+    /// side has been evaluated. The right-hand side starts at `cfg_then_block` of the control-flow
+    /// graph for `&&` and at `cfg_else_block` for `||`, and is only evaluated if the left-hand side
+    /// does not determine the result. Returns the block of the graph in which the operator is
+    /// evaluated.
+    fn gen_logic_op_rhs(
+        &mut self,
+        expr: ExprId,
+        cfg_then_block: hir::BasicBlock,
+        cfg_else_block: hir::BasicBlock,
+    ) -> Option<hir::BasicBlock> {
+        let (lhs, rhs, op) = match &self.body[expr] {
+            Expr::BinaryOp {
+                lhs,
+                rhs,
+                op: Some(BinaryOp::LogicOp(op)),
+            } => (*lhs, *rhs, *op),
+            _ => unreachable!("expected a logical operator"),
+        };
+        let (cfg_rhs_block, cfg_join_block) = match op {
+            LogicOp::And => (cfg_then_block, cfg_else_block),
+            LogicOp::Or => (cfg_else_block, cfg_then_block),
+        };
+
+        let lhs_value = self.gen_expr(lhs).expect("no lhs value").into_int_value();
+        let lhs_block = self.builder.get_insert_block().unwrap();
+        let rhs_block = self.context.append_basic_block(self.fn_value, "rhs");
+        let merge_block = self
+            .context
+            .append_basic_block(self.fn_value, "logic_merge");
+        let (then_block, else_block) = match op {
+            LogicOp::And => (rhs_block, merge_block),
+            LogicOp::Or => (merge_block, rhs_block),
+        };
+        self.builder
+            .build_conditional_branch(lhs_value, then_block, else_block);
+
+        // The right-hand side can diverge, e.g. `a && return`, in which case the result is
+        // determined by the left-hand side alone
+        self.builder.position_at_end(rhs_block);
+        let rhs_value_and_block = self
+            .gen_blocks(cfg_rhs_block)
+            .goto()
+            .and_then(|_| self.gen_expr(rhs))
+            .map(|value| {
+                let rhs_block = self.builder.get_insert_block().unwrap();
+                self.builder.build_unconditional_branch(merge_block);
+                (value.into_int_value(), rhs_block)
+            });
+
+        let current_block = self.builder.get_insert_block().unwrap();
+        merge_block.move_after(current_block).unwrap();
+        self.builder.position_at_end(merge_block);
+        let bool_type = self.context.bool_type();
+        let short_circuit_value = match op {
+            LogicOp::And => bool_type.const_zero(),
+            LogicOp::Or => bool_type.const_all_ones(),
+        };
+        let phi = self.builder.build_phi(bool_type, "logic");
+        phi.add_incoming(&[(&short_circuit_value, lhs_block)]);
+        if let Some((rhs_value, rhs_block)) = rhs_value_and_block {
+            phi.add_incoming(&[(&rhs_value, rhs_block)]);
         }
+        self.values.insert(expr, Some(phi.as_basic_value()));
+        Some(cfg_join_block)
     }
 
-    fn gen_return(
+    /// Generates IR for the condition of an `if` or `while` expression. The value of an `if let` or
+    /// `while let` condition is stored in memory and tested against the pattern. The pointer to the
+    /// stored value is also returned, so the bindings of the pattern can be generated in the block
+    /// that the condition guards.
+    fn gen_condition(
         &mut self,
-        _expr: ExprId,
-        ret_expr: Option<ExprId>,
-    ) -> Option<BasicValueEnum<'ink>> {
+        condition: ExprId,
+    ) -> Option<(IntValue<'ink>, Option<PointerValue<'ink>>)> {
+        let body = self.body.clone();
+        match &body[condition] {
+            Expr::Let { pat, expr } => {
+                let value = self.gen_expr(*expr)?;
+                let scrutinee_ptr = self
+                    .new_alloca_builder()
+                    .build_alloca(value.get_type(), "scrutinee");
+                self.builder.build_store(scrutinee_ptr, value);
+
+                let resolver = hir::resolver_for_expr(self.body.clone(), self.db, condition);
+                if self.infer[*expr].as_nullable().is_none() {
+                    let matches = self
+                        .gen_pat_test(*pat, scrutinee_ptr, &resolver)
+                        .unwrap_or_else(|| self.context.bool_type().const_int(1, false));
+                    return Some((matches, Some(scrutinee_ptr)));
+                }
+
+                // A nullable value only matches if it is not `nil`, in which case the pattern is
+                // tested against the struct that it refers to.
+                let is_not_nil = self
+                    .builder
+                    .build_is_not_null(value.into_pointer_value(), "is_not_nil");
+                let nil_block = self.builder.get_insert_block().unwrap();
+                let test_block = self.context.append_basic_block(self.fn_value, "let_test");
+                let merge_block = self.context.append_basic_block(self.fn_value, "let_merge");
+                self.builder
+                    .build_conditional_branch(is_not_nil, test_block, merge_block);
+
+                self.builder.position_at_end(test_block);
+                let matches = self
+                    .gen_pat_test(*pat, scrutinee_ptr, &resolver)
+                    .unwrap_or(is_not_nil);
+                let test_block = self.builder.get_insert_block().unwrap();
+                self.builder.build_unconditional_branch(merge_block);
+
+                self.builder.position_at_end(merge_block);
+                let phi = self.builder.build_phi(self.context.bool_type(), "matches");
+                phi.add_incoming(&[(&is_not_nil, nil_block), (&matches, test_block)]);
+                Some((phi.as_basic_value().into_int_value(), Some(scrutinee_ptr)))
+            }
+            _ => {
+                let condition_ir = self
+                    .gen_expr(condition)
+                    .map(|value| self.opt_deref_value(condition, value))?
+                    .into_int_value();
+                Some((condition_ir, None))
+            }
+        }
+    }
+
+    /// Binds the variables of an `if let` or `while let` condition to the value stored at
+    /// `scrutinee_ptr`, once the `Bind` step of its pattern is generated.
+    fn bind_condition(&mut self, condition: ExprId, scrutinee_ptr: PointerValue<'ink>) {
+        if let Expr::Let { pat, .. } = &self.body[condition] {
+            self.pending_bindings
+                .insert(*pat, PendingBinding::Scrutinee(scrutinee_ptr, condition));
+        }
+    }
+
+    /// Generates IR for a match expression, of which the scrutinee has been evaluated. The patterns
+    /// of the arms are tested in order and the expression of the first arm whose pattern matches is
+    /// evaluated. The arms start at `cfg_targets` of the control-flow graph. Returns the block of
+    /// the graph in which execution continues after the expression.
+    fn gen_match(
+        &mut self,
+        expr: ExprId,
+        cfg_targets: &[hir::BasicBlock],
+    ) -> Option<hir::BasicBlock> {
+        let body = self.body.clone();
+        let (scrutinee, arms) = match &body[expr] {
+            Expr::Match {
+                expr: scrutinee,
+                arms,
+            } => (*scrutinee, arms),
+            _ => unreachable!("expected a match expression"),
+        };
+        let value = self.gen_expr(scrutinee)?;
+
+        // Store the value in memory so the fields of enum variants can be accessed
+        let scrutinee_ptr = self
+            .new_alloca_builder()
+            .build_alloca(value.get_type(), "scrutinee");
+        self.builder.build_store(scrutinee_ptr, value);
+
+        let resolver = hir::resolver_for_expr(self.body.clone(), self.db, expr);
+        let merge_block = self
+            .context
+            .append_basic_block(self.fn_value, "match_merge");
+        let mut incoming = Vec::with_capacity(arms.len());
+        let mut join_block = None;
+        for (arm, cfg_arm_block) in arms.iter().zip(cfg_targets) {
+            let arm_block = self.context.append_basic_block(self.fn_value, "match_arm");
+            let next_block = self.context.append_basic_block(self.fn_value, "match_next");
+            match self.gen_pat_test(arm.pat, scrutinee_ptr, &resolver) {
+                Some(condition) => {
+                    self.builder
+                        .build_conditional_branch(condition, arm_block, next_block);
+                }
+                None => {
+                    self.builder.build_unconditional_branch(arm_block);
+                }
+            }
+
+            // Fill the block of the arm
+            self.builder.position_at_end(arm_block);
+            self.pending_bindings
+                .insert(arm.pat, PendingBinding::Scrutinee(scrutinee_ptr, expr));
+            let arm_join_block = self.gen_blocks(*cfg_arm_block).goto();
+            join_block = join_block.or(arm_join_block);
+            let arm_ir = arm_join_block.and_then(|_| self.gen_expr(arm.expr));
+            if !self.infer[arm.expr].is_never() {
+                if let Some(arm_ir) = arm_ir {
+                    incoming.push((arm_ir, self.builder.get_insert_block().unwrap()));
+                }
+                self.builder.build_unconditional_branch(merge_block);
+            }
+
+            self.builder.position_at_end(next_block);
+        }
+
+        // Exhaustiveness checking guarantees that one of the arms matches
+        self.builder.build_unreachable();
+
+        let current_block = self.builder.get_insert_block().unwrap();
+        merge_block.move_after(current_block).unwrap();
+        self.builder.position_at_end(merge_block);
+
+        // Construct phi block if a value was returned
+        let value = if incoming.is_empty() {
+            if self.infer[expr].is_never() {
+                None
+            } else {
+                Some(self.gen_empty())
+            }
+        } else {
+            let phi = self.builder.build_phi(incoming[0].0.get_type(), "matchtmp");
+            for (value, block) in incoming.iter() {
+                phi.add_incoming(&[(value, *block)]);
+            }
+            Some(phi.as_basic_value())
+        };
+        self.values.insert(expr, value);
+        join_block
+    }
+
+    /// Generates IR that tests whether the value stored at `ptr` matches the pattern `pat`.
+    /// Returns `None` if the pattern always matches.
+    fn gen_pat_test(
+        &mut self,
+        pat: PatId,
+        ptr: PointerValue<'ink>,
+        resolver: &Resolver,
+    ) -> Option<IntValue<'ink>> {
+        let body = self.body.clone();
+        match &body[pat] {
+            Pat::Wild | Pat::Bind { .. } => None,
+            Pat::Path(_) | Pat::TupleStruct { .. } => {
+                let variant = self.resolve_pat_variant(pat, resolver);
+                let hir_enum = variant.parent_enum();
+                let discriminant_ptr =
+                    unsafe { self.builder.build_struct_gep(ptr, 0, "discriminant_ptr") };
+                let discriminant = self
+                    .builder
+                    .build_load(discriminant_ptr, "discriminant")
+                    .into_int_value();
+                let mut condition = self.builder.build_int_compare(
+                    IntPredicate::EQ,
+                    discriminant,
+                    self.hir_types
+                        .get_enum_discriminant_type(hir_enum)
+                        .const_int(variant.index() as u64, false),
+                    &format!("is_{}", variant.name(self.db)),
+                );
+
+                if let Pat::TupleStruct { args, .. } = &body[pat] {
+                    let variant_ptr = self.gen_enum_variant_ptr(ptr, variant);
+                    for (idx, arg) in args.iter().enumerate() {
+                        let field_ptr = unsafe {
+                            self.builder.build_struct_gep(
+                                variant_ptr,
+                                idx as u32,
+                                &format!("{}_ptr", idx),
+                            )
+                        };
+                        let field_condition = self.gen_pat_test(*arg, field_ptr, resolver);
+                        if let Some(field_condition) = field_condition {
+                            condition =
+                                self.builder
+                                    .build_and(condition, field_condition, "matches");
+                        }
+                    }
+                }
+
+                Some(condition)
+            }
+            Pat::Tuple(args) => {
+                let mut condition = None;
+                for (idx, arg) in args.iter().enumerate() {
+                    let field_ptr = unsafe {
+                        self.builder
+                            .build_struct_gep(ptr, idx as u32, &format!("{}_ptr", idx))
+                    };
+                    if let Some(field_condition) = self.gen_pat_test(*arg, field_ptr, resolver) {
+                        condition = Some(match condition {
+                            Some(condition) => {
+                                self.builder
+                                    .build_and(condition, field_condition, "matches")
+                            }
+                            None => field_condition,
+                        });
+                    }
+                }
+                condition
+            }
+            Pat::Lit(expr) => {
+                let value = self.builder.build_load(ptr, "value");
+                let literal = self.gen_expr(*expr).expect("expected a literal value");
+                let condition = match self.infer[pat].as_simple() {
+                    Some(TypeCtor::Float(_)) => self.builder.build_float_compare(
+                        FloatPredicate::OEQ,
+                        value.into_float_value(),
+                        literal.into_float_value(),
+                        "matches",
+                    ),
+                    Some(TypeCtor::Int(_)) | Some(TypeCtor::Bool) => {
+                        self.builder.build_int_compare(
+                            IntPredicate::EQ,
+                            value.into_int_value(),
+                            literal.into_int_value(),
+                            "matches",
+                        )
+                    }
+                    Some(TypeCtor::String) => {
+                        self.gen_string_eq(value.into_pointer_value(), literal.into_pointer_value())
+                    }
+                    _ => unreachable!("literal patterns must be numbers, booleans or strings"),
+                };
+                Some(condition)
+            }
+            Pat::Missing => unreachable!(),
+        }
+    }
+
+    /// Generates IR that binds the variables of the pattern `pat` to the value stored at `ptr`.
+    fn gen_pat_bindings(&mut self, pat: PatId, ptr: PointerValue<'ink>, resolver: &Resolver) {
+        let body = self.body.clone();
+        match &body[pat] {
+            Pat::Bind { name, .. } => {
+                let pat_ty = self.infer[pat].clone();
+                let ty = self
+                    .hir_types
+                    .get_basic_type(&pat_ty)
+                    .expect("expected basic type");
+                let local = self
+                    .new_alloca_builder()
+                    .build_alloca(ty, &name.to_string());
+                self.pat_to_local.insert(pat, local);
+                self.pat_to_name.insert(pat, name.to_string());
+                let value = self.builder.build_load(ptr, &name.to_string());
+                self.builder.build_store(local, value);
+            }
+            Pat::TupleStruct { args, .. } => {
+                let variant = self.resolve_pat_variant(pat, resolver);
+                let variant_ptr = self.gen_enum_variant_ptr(ptr, variant);
+                for (idx, arg) in args.iter().enumerate() {
+                    let field_ptr = unsafe {
+                        self.builder.build_struct_gep(
+                            variant_ptr,
+                            idx as u32,
+                            &format!("{}_ptr", idx),
+                        )
+                    };
+                    self.gen_pat_bindings(*arg, field_ptr, resolver);
+                }
+            }
+            Pat::Tuple(args) => {
+                for (idx, arg) in args.iter().enumerate() {
+                    let field_ptr = unsafe {
+                        self.builder
+                            .build_struct_gep(ptr, idx as u32, &format!("{}_ptr", idx))
+                    };
+                    self.gen_pat_bindings(*arg, field_ptr, resolver);
+                }
+            }
+            Pat::Wild | Pat::Path(_) | Pat::Lit(_) => {}
+            Pat::Missing => unreachable!(),
+        }
+    }
+
+    /// Resolves the enum variant of a path or tuple struct pattern.
+    fn resolve_pat_variant(&self, pat: PatId, resolver: &Resolver) -> hir::EnumVariant {
+        let path = match &self.body[pat] {
+            Pat::Path(path) | Pat::TupleStruct { path, .. } => path,
+            _ => unreachable!("expected a path pattern"),
+        };
+        match resolver
+            .resolve_path_without_assoc_items(self.db, path)
+            .take_values()
+        {
+            Some(Resolution::Def(hir::ModuleDef::EnumVariant(variant))) => variant,
+            _ => unreachable!("pattern must resolve to an enum variant"),
+        }
+    }
+
+    fn gen_return(&mut self, ret_expr: Option<ExprId>) {
         let ret_value = ret_expr.and_then(|expr| self.gen_expr(expr));
 
         // Construct a return statement from the returned value of the body
@@ -1191,62 +3233,84 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         } else {
             self.builder.build_return(None);
         }
-
-        None
     }
 
-    fn gen_break(
-        &mut self,
-        _expr: ExprId,
-        break_expr: Option<ExprId>,
-    ) -> Option<BasicValueEnum<'ink>> {
-        let break_value = break_expr.and_then(|expr| self.gen_expr(expr));
-        let loop_info = self.active_loop.as_mut().unwrap();
+    /// Generates IR for a jump from `block` to `target` in the control-flow graph, if `target` is
+    /// the header or the exit of an active loop, i.e. for a `continue` or `break` expression or
+    /// at the end of the body of the loop. Returns false if the jump is not generated.
+    fn gen_loop_jump(&mut self, block: hir::BasicBlock, target: hir::BasicBlock) -> bool {
+        let idx = match self
+            .active_loops
+            .iter()
+            .rposition(|loop_info| loop_info.header == target || loop_info.exit == target)
+        {
+            Some(idx) => idx,
+            None => return false,
+        };
+
+        if self.active_loops[idx].header == target {
+            let continue_block = self.active_loops[idx].continue_block;
+            self.builder.build_unconditional_branch(continue_block);
+            return true;
+        }
+
+        // The value of a `break` expression is evaluated by the last step of its block
+        let graph = self.graph.clone();
+        let break_value = match graph.cfg[block].steps.last() {
+            Some(Step::Eval(expr)) => match graph.parents.get(expr) {
+                Some(parent) if matches!(self.body[*parent], Expr::Break { .. }) => {
+                    self.gen_expr(*expr)
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        let insert_block = self.builder.get_insert_block().unwrap();
+        let loop_info = &mut self.active_loops[idx];
         if let Some(break_value) = break_value {
-            loop_info
-                .break_values
-                .push((break_value, self.builder.get_insert_block().unwrap()));
+            loop_info.break_values.push((break_value, insert_block));
         }
-        self.builder
-            .build_unconditional_branch(loop_info.exit_block);
-        None
+        let exit_block = loop_info.exit_block;
+        self.builder.build_unconditional_branch(exit_block);
+        true
     }
 
-    fn gen_loop_block_expr(
+    /// Generates the body of the loop `expr`, which starts at `cfg_block` of the control-flow
+    /// graph. A `continue` expression and the end of the body branch to `continue_block` and a
+    /// `break` expression to `exit_block`. Returns the values of the `break` expressions.
+    fn gen_loop_body(
         &mut self,
-        block: ExprId,
+        expr: ExprId,
+        cfg_block: hir::BasicBlock,
+        continue_block: BasicBlock<'ink>,
         exit_block: BasicBlock<'ink>,
-    ) -> (
-        BasicBlock<'ink>,
-        Vec<(BasicValueEnum<'ink>, BasicBlock<'ink>)>,
-        Option<BasicValueEnum<'ink>>,
-    ) {
-        // Build a new loop info struct
-        let loop_info = LoopInfo {
-            exit_block,
+    ) -> Vec<(BasicValueEnum<'ink>, BasicBlock<'ink>)> {
+        let cfg = &self.graph.cfg;
+        let header = cfg.loop_header(expr).expect("a loop must have a header");
+        let exit = cfg.loop_exit(expr).expect("a loop must have an exit");
+        self.active_loops.push(LoopInfo {
+            header,
+            exit,
             break_values: Vec::new(),
-        };
-
-        // Replace previous loop info
-        let prev_loop = std::mem::replace(&mut self.active_loop, Some(loop_info));
+            continue_block,
+            exit_block,
+        });
 
         // Start generating code inside the loop
-        let value = self.gen_expr(block);
+        self.gen_blocks(cfg_block);
 
-        let LoopInfo {
-            exit_block,
-            break_values,
-        } = std::mem::replace(&mut self.active_loop, prev_loop).unwrap();
-
-        (exit_block, break_values, value)
+        self.active_loops.pop().unwrap().break_values
     }
 
-    fn gen_while(
-        &mut self,
-        _expr: ExprId,
-        condition_expr: ExprId,
-        body_expr: ExprId,
-    ) -> Option<BasicValueEnum<'ink>> {
+    /// Generates IR for the `while` loop `expr`. Its condition is evaluated in `cfg_header` of the
+    /// control-flow graph. Returns the block of the graph in which execution continues after the
+    /// loop.
+    fn gen_while(&mut self, expr: ExprId, cfg_header: hir::BasicBlock) -> Option<hir::BasicBlock> {
+        let condition_expr = match &self.body[expr] {
+            Expr::While { condition, .. } => *condition,
+            _ => unreachable!("expected a while expression"),
+        };
+
         let context = self.context;
         let cond_block = context.append_basic_block(self.fn_value, "whilecond");
         let loop_block = context.append_basic_block(self.fn_value, "while");
@@ -1255,37 +3319,141 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         // Insert an explicit fall through from the current block to the condition check
         self.builder.build_unconditional_branch(cond_block);
 
-        // Generate condition block
+        // Generate condition block. If the condition doesn't return a value, we also immediately
+        // return without a value. This can happen if the expression is a `never` expression.
         self.builder.position_at_end(cond_block);
-        let condition_ir = self
-            .gen_expr(condition_expr)
-            .map(|value| self.opt_deref_value(condition_expr, value));
-        if let Some(condition_ir) = condition_ir {
-            self.builder.build_conditional_branch(
-                condition_ir.into_int_value(),
-                loop_block,
-                exit_block,
-            );
-        } else {
-            // If the condition doesn't return a value, we also immediately return without a value.
-            // This can happen if the expression is a `never` expression.
-            return None;
+        let cfg_body_block = match self.gen_blocks(cfg_header) {
+            Flow::Condition(cfg_body_block) => cfg_body_block,
+            _ => return None,
+        };
+        let (condition_ir, scrutinee_ptr) = self.gen_condition(condition_expr)?;
+        self.builder
+            .build_conditional_branch(condition_ir, loop_block, exit_block);
+
+        // Generate loop block
+        self.builder.position_at_end(loop_block);
+        if let Some(scrutinee_ptr) = scrutinee_ptr {
+            self.bind_condition(condition_expr, scrutinee_ptr);
         }
+        self.gen_loop_body(expr, cfg_body_block, cond_block, exit_block);
+
+        // Generate exit block
+        self.builder.position_at_end(exit_block);
+
+        let value = self.gen_empty();
+        self.values.insert(expr, Some(value));
+        self.graph.cfg.loop_exit(expr)
+    }
+
+    /// Generates IR for the `for` loop `expr`, of which the range has been evaluated. The range is
+    /// iterated in `cfg_header` of the control-flow graph. Returns the block of the graph in which
+    /// execution continues after the loop.
+    fn gen_for(&mut self, expr: ExprId, cfg_header: hir::BasicBlock) -> Option<hir::BasicBlock> {
+        let (pat, iterable_expr) = match &self.body[expr] {
+            Expr::For { pat, iterable, .. } => (*pat, *iterable),
+            _ => unreachable!("expected a for expression"),
+        };
+        let (start_expr, end_expr, op) = match &self.body[iterable_expr] {
+            Expr::Range { lhs, rhs, op } => (*lhs, *rhs, *op),
+            _ => unreachable!("the iterable of a `for` loop must be a range"),
+        };
+        let signedness = match self.infer[start_expr].as_simple() {
+            Some(TypeCtor::Int(ty)) => ty.signedness,
+            _ => unreachable!("the bounds of a range must be integers"),
+        };
+        let cfg_body_block = match &self.graph.cfg[cfg_header].terminator {
+            Terminator::Iterate { body_block, .. } => *body_block,
+            _ => unreachable!("the header of a `for` loop must iterate over its range"),
+        };
+
+        // The bounds of the range are only evaluated once, before entering the loop
+        let start = self
+            .gen_expr(start_expr)
+            .map(|value| self.opt_deref_value(start_expr, value))?
+            .into_int_value();
+        let end = self
+            .gen_expr(end_expr)
+            .map(|value| self.opt_deref_value(end_expr, value))?
+            .into_int_value();
+
+        // Allocate the loop counter and the binding of the pattern
+        let builder = self.new_alloca_builder();
+        let counter = builder.build_alloca(start.get_type(), "counter");
+        let binding = match &self.body[pat] {
+            Pat::Bind { name, .. } => {
+                let ptr = builder.build_alloca(start.get_type(), &name.to_string());
+                self.pat_to_local.insert(pat, ptr);
+                self.pat_to_name.insert(pat, name.to_string());
+                Some(ptr)
+            }
+            Pat::Wild => None,
+            Pat::Missing | Pat::Path(_) | Pat::TupleStruct { .. } | Pat::Tuple(_) | Pat::Lit(_) => {
+                unreachable!()
+            }
+        };
+        self.builder.build_store(counter, start);
+
+        let context = self.context;
+        let cond_block = context.append_basic_block(self.fn_value, "forcond");
+        let loop_block = context.append_basic_block(self.fn_value, "for");
+        let step_block = context.append_basic_block(self.fn_value, "forstep");
+        let exit_block = context.append_basic_block(self.fn_value, "afterfor");
+
+        // Insert an explicit fall through from the current block to the condition check
+        self.builder.build_unconditional_branch(cond_block);
+
+        // Generate condition block
+        self.builder.position_at_end(cond_block);
+        let value = self.builder.build_load(counter, "value").into_int_value();
+        let condition_ir = self.gen_cmp_bin_op_int(
+            value,
+            end,
+            CmpOp::Ord {
+                ordering: Ordering::Less,
+                strict: op == RangeOp::Exclusive,
+            },
+            signedness,
+        );
+        self.builder
+            .build_conditional_branch(condition_ir, loop_block, exit_block);
 
         // Generate loop block
         self.builder.position_at_end(loop_block);
-        let (exit_block, _, value) = self.gen_loop_block_expr(body_expr, exit_block);
-        if value.is_some() {
+        if let Some(binding) = binding {
+            self.pending_bindings
+                .insert(pat, PendingBinding::Counter(binding, value));
+        }
+        self.gen_loop_body(expr, cfg_body_block, step_block, exit_block);
+
+        // Generate step block
+        self.builder.position_at_end(step_block);
+        let next = self
+            .builder
+            .build_int_add(value, start.get_type().const_int(1, false), "next");
+        self.builder.build_store(counter, next);
+        if op == RangeOp::Inclusive {
+            // The last value of an inclusive range might be the maximum value of its type, in
+            // which case incrementing the counter wraps around. Exit before that happens.
+            let is_last =
+                self.gen_cmp_bin_op_int(value, end, CmpOp::Eq { negated: false }, signedness);
+            self.builder
+                .build_conditional_branch(is_last, exit_block, cond_block);
+        } else {
             self.builder.build_unconditional_branch(cond_block);
         }
 
         // Generate exit block
         self.builder.position_at_end(exit_block);
 
-        Some(self.gen_empty())
+        let value = self.gen_empty();
+        self.values.insert(expr, Some(value));
+        self.graph.cfg.loop_exit(expr)
     }
 
-    fn gen_loop(&mut self, _expr: ExprId, body_expr: ExprId) -> Option<BasicValueEnum<'ink>> {
+    /// Generates IR for the `loop` expression `expr`, of which the body starts at `cfg_header` of
+    /// the control-flow graph. Returns the block of the graph in which execution continues after
+    /// the loop.
+    fn gen_loop(&mut self, expr: ExprId, cfg_header: hir::BasicBlock) -> Option<hir::BasicBlock> {
         let context = self.context;
         let loop_block = context.append_basic_block(self.fn_value, "loop");
         let exit_block = context.append_basic_block(self.fn_value, "exit");
@@ -1295,23 +3463,40 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
 
         // Generate the body of the loop
         self.builder.position_at_end(loop_block);
-        let (exit_block, break_values, value) = self.gen_loop_block_expr(body_expr, exit_block);
-        if value.is_some() {
-            self.builder.build_unconditional_branch(loop_block);
-        }
+        let break_values = self.gen_loop_body(expr, cfg_header, loop_block, exit_block);
 
         // Move the builder to the exit block
         self.builder.position_at_end(exit_block);
 
-        if !break_values.is_empty() {
+        let value = if !break_values.is_empty() {
             let (value, _) = break_values.first().unwrap();
             let phi = self.builder.build_phi(value.get_type(), "exit");
             for (value, block) in break_values {
                 phi.add_incoming(&[(&value, block)])
             }
-            Some(phi.as_basic_value())
+            phi.as_basic_value()
         } else {
-            None
+            self.gen_empty()
+        };
+        self.values.insert(expr, Some(value));
+        self.graph.cfg.loop_exit(expr)
+    }
+
+    /// Returns the name of the type of `receiver_expr` and the index of its field `name`. The
+    /// receiver is either a struct or a tuple.
+    fn field_index(&self, receiver_expr: ExprId, name: &Name) -> (String, u32) {
+        let receiver_ty = &self.infer[receiver_expr];
+        if let Some(hir_struct) = receiver_ty.as_struct() {
+            let field_idx = hir_struct
+                .field(self.db, name)
+                .expect("expected a struct field")
+                .id()
+                .into_raw()
+                .into();
+            (hir_struct.name(self.db.upcast()).to_string(), field_idx)
+        } else {
+            let field_idx = name.as_tuple_index().expect("expected a tuple field");
+            (String::from("tuple"), field_idx as u32)
         }
     }
 
@@ -1321,18 +3506,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         receiver_expr: ExprId,
         name: &Name,
     ) -> Option<BasicValueEnum<'ink>> {
-        let hir_struct = self.infer[receiver_expr]
-            .as_struct()
-            .expect("expected a struct");
-
-        let hir_struct_name = hir_struct.name(self.db.upcast());
-
-        let field_idx = hir_struct
-            .field(self.db, name)
-            .expect("expected a struct field")
-            .id()
-            .into_raw()
-            .into();
+        let (hir_struct_name, field_idx) = self.field_index(receiver_expr, name);
 
         let field_ir_name = &format!("{}.{}", hir_struct_name, name);
         if self.is_place_expr(receiver_expr) {
@@ -1372,18 +3546,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         receiver_expr: ExprId,
         name: &Name,
     ) -> PointerValue<'ink> {
-        let hir_struct = self.infer[receiver_expr]
-            .as_struct()
-            .expect("expected a struct");
-
-        let hir_struct_name = hir_struct.name(self.db.upcast());
-
-        let field_idx = hir_struct
-            .field(self.db, name)
-            .expect("expected a struct field")
-            .id()
-            .into_raw()
-            .into();
+        let (hir_struct_name, field_idx) = self.field_index(receiver_expr, name);
 
         let receiver_ptr = self.gen_place_expr(receiver_expr);
         let receiver_ptr = self
@@ -1397,6 +3560,307 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             )
         }
     }
+
+    /// Generates IR for an index expression, e.g. `a[i]`
+    fn gen_index(
+        &mut self,
+        expr: ExprId,
+        base_expr: ExprId,
+        index_expr: ExprId,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let element_ptr = self.gen_array_element_ptr(expr, base_expr, index_expr)?;
+        Some(self.builder.build_load(element_ptr, "element"))
+    }
+
+    /// Generates IR that computes a pointer to the element at `index_expr` of the array
+    /// `base_expr`. Execution is aborted if the index is out of bounds.
+    fn gen_array_element_ptr(
+        &mut self,
+        expr: ExprId,
+        base_expr: ExprId,
+        index_expr: ExprId,
+    ) -> Option<PointerValue<'ink>> {
+        let (_, len) = self.infer[base_expr]
+            .as_array()
+            .expect("only arrays can be indexed");
+
+        // Get a pointer to the array, temporaries are stored on the stack
+        let array_ptr = if self.is_place_expr(base_expr) {
+            self.gen_place_expr(base_expr)
+        } else {
+            let array = self.gen_expr(base_expr)?;
+            let array_ptr = self
+                .new_alloca_builder()
+                .build_alloca(array.get_type(), "array");
+            self.builder.build_store(array_ptr, array);
+            array_ptr
+        };
+
+        let index = self.gen_expr(index_expr)?.into_int_value();
+        let zero = self.context.i32_type().const_zero();
+        match len {
+            Some(len) => {
+                let len = self
+                    .hir_types
+                    .get_int_type(hir::IntTy::usize())
+                    .const_int(len, false);
+                self.gen_bounds_check(expr, index, len);
+                Some(unsafe {
+                    self.builder
+                        .build_in_bounds_gep(array_ptr, &[zero, index], "element_ptr")
+                })
+            }
+            None => {
+                // Garbage collected arrays add an extra layer of indirection
+                let array_ptr_ptr = self
+                    .builder
+                    .build_load(array_ptr, "array_ptr_ptr")
+                    .into_pointer_value();
+                let mem_ptr = self
+                    .builder
+                    .build_load(array_ptr_ptr, "array_mem_ptr")
+                    .into_pointer_value();
+                let len = self.gen_array_len(mem_ptr);
+                self.gen_bounds_check(expr, index, len);
+                let elements_idx = self.context.i32_type().const_int(1, false);
+                Some(unsafe {
+                    self.builder.build_in_bounds_gep(
+                        mem_ptr,
+                        &[zero, elements_idx, index],
+                        "element_ptr",
+                    )
+                })
+            }
+        }
+    }
+
+    /// Generates IR that loads the length of a garbage collected array from its memory.
+    fn gen_array_len(&self, mem_ptr: PointerValue<'ink>) -> IntValue<'ink> {
+        let len_ptr = unsafe { self.builder.build_struct_gep(mem_ptr, 0, "len_ptr") };
+        self.builder.build_load(len_ptr, "len").into_int_value()
+    }
+
+    /// Generates IR that aborts the invocation of the function of the index expression `expr` if
+    /// `index` is not smaller than `len`.
+    fn gen_bounds_check(&mut self, expr: ExprId, index: IntValue<'ink>, len: IntValue<'ink>) {
+        let out_of_bounds =
+            self.builder
+                .build_int_compare(IntPredicate::UGE, index, len, "out_of_bounds");
+        self.gen_panic_if(expr, out_of_bounds, "index out of bounds");
+    }
+
+    /// Generates IR that aborts the invocation of the function if `condition` is true. The
+    /// `message` and the source location of `expr` are reported to the host through the `panic`
+    /// intrinsic.
+    fn gen_panic_if(&mut self, expr: ExprId, condition: IntValue<'ink>, message: &str) {
+        let context = self.context;
+        let panic_block = context.append_basic_block(self.fn_value, "panic");
+        let continue_block = context.append_basic_block(self.fn_value, "no_panic");
+        self.builder
+            .build_conditional_branch(condition, panic_block, continue_block);
+
+        self.builder.position_at_end(panic_block);
+        let panic_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::panic,
+        );
+
+        // Lines and columns are reported one-based, like the diagnostics of the compiler
+        let function = self.instance.function;
+        let file_id = function.module(self.db.upcast()).file_id();
+        let file = self.db.file_relative_path(file_id);
+        let line_col = function
+            .expr_line_col(self.db, expr)
+            .expect("expression does not originate from source code");
+        let u32_type = self.context.i32_type();
+        let args = [
+            self.builder
+                .build_global_string_ptr(message, "panic_message")
+                .as_pointer_value()
+                .into(),
+            self.builder
+                .build_global_string_ptr(file.as_str(), "panic_file")
+                .as_pointer_value()
+                .into(),
+            u32_type
+                .const_int(u64::from(line_col.line) + 1, false)
+                .into(),
+            u32_type
+                .const_int(u64::from(line_col.col) + 1, false)
+                .into(),
+        ];
+        self.builder.build_call(panic_fn_ptr, &args, "");
+        self.gen_panic_return();
+
+        self.builder.position_at_end(continue_block);
+    }
+
+    /// Generates IR that checks whether the function that was just called panicked, in which case
+    /// the invocation of this function is aborted as well.
+    fn gen_return_if_panicking(&mut self) {
+        let panicking_fn_ptr = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::panicking,
+        );
+        let panicking = self
+            .builder
+            .build_call(panicking_fn_ptr, &[], "panicking")
+            .try_as_basic_value()
+            .left()
+            .expect("the panicking intrinsic returns a bool")
+            .into_int_value();
+
+        let context = self.context;
+        let panic_block = context.append_basic_block(self.fn_value, "panicked");
+        let continue_block = context.append_basic_block(self.fn_value, "no_panic");
+        self.builder
+            .build_conditional_branch(panicking, panic_block, continue_block);
+
+        self.builder.position_at_end(panic_block);
+        self.gen_panic_return();
+
+        self.builder.position_at_end(continue_block);
+    }
+
+    /// Generates IR that returns from the function after a panic. Mun code does not unwind the
+    /// stack, so every caller checks whether its callee panicked and returns in turn. The returned
+    /// value is never used.
+    fn gen_panic_return(&mut self) {
+        match self.fn_value.get_type().get_return_type() {
+            Some(ty) => {
+                let value: BasicValueEnum = match ty {
+                    BasicTypeEnum::ArrayType(ty) => ty.get_undef().into(),
+                    BasicTypeEnum::FloatType(ty) => ty.get_undef().into(),
+                    BasicTypeEnum::IntType(ty) => ty.get_undef().into(),
+                    BasicTypeEnum::PointerType(ty) => ty.get_undef().into(),
+                    BasicTypeEnum::StructType(ty) => ty.get_undef().into(),
+                    BasicTypeEnum::VectorType(ty) => ty.get_undef().into(),
+                };
+                self.builder.build_return(Some(&value))
+            }
+            None => self.builder.build_return(None),
+        };
+    }
+
+    /// Generates IR for a method call, e.g. `a.len()` or `foo.bar()`
+    fn gen_method_call(
+        &mut self,
+        expr: ExprId,
+        receiver_expr: ExprId,
+        method_name: &Name,
+        args: &[ExprId],
+    ) -> Option<BasicValueEnum<'ink>> {
+        // Methods defined in `impl` blocks receive the receiver as their first argument
+        if let Some((function, substs)) = self.infer.method_resolution(expr) {
+            let args: Vec<BasicValueEnum> = std::iter::once(receiver_expr)
+                .chain(args.iter().cloned())
+                .map(|arg| self.gen_expr(arg).expect("expected a value"))
+                .collect();
+            let function = FunctionInstance::resolve(self.db, function, &substs);
+            return self.gen_call_expr(expr, &function, &args);
+        }
+
+        // Integers have arithmetic intrinsics with an explicit overflow behavior
+        let receiver_ty = &self.infer[receiver_expr];
+        if let hir::ty_app!(TypeCtor::Int(int_ty)) = receiver_ty {
+            let (op, behavior) = ArithOp::from_int_method_name(method_name)
+                .unwrap_or_else(|| unreachable!("unknown method `{}`", method_name));
+            let signedness = int_ty.signedness;
+            let lhs = self
+                .gen_expr(receiver_expr)
+                .map(|value| self.opt_deref_value(receiver_expr, value))?
+                .into_int_value();
+            let rhs = self
+                .gen_expr(args[0])
+                .map(|value| self.opt_deref_value(args[0], value))?
+                .into_int_value();
+            let value = match behavior {
+                OverflowBehavior::Wrapping => match op {
+                    ArithOp::Add => self.builder.build_int_add(lhs, rhs, "add"),
+                    ArithOp::Subtract => self.builder.build_int_sub(lhs, rhs, "sub"),
+                    _ => self.builder.build_int_mul(lhs, rhs, "mul"),
+                },
+                OverflowBehavior::Saturating => {
+                    self.gen_saturating_arith_op_int(lhs, rhs, op, signedness)
+                }
+                OverflowBehavior::Checked => {
+                    // The wrapped result is returned together with whether the operation
+                    // overflowed
+                    let (value, overflow) =
+                        self.gen_arith_with_overflow_int(lhs, rhs, op, signedness);
+                    let result_type = self.hir_types.get_tuple_type(
+                        self.infer[expr].as_tuple().expect("expected a tuple type"),
+                    );
+                    let result = self
+                        .builder
+                        .build_insert_value(result_type.get_undef(), value, 0, "checked")
+                        .expect("Failed to initialize the result of a checked operation.");
+                    let result = self
+                        .builder
+                        .build_insert_value(result, overflow, 1, "checked")
+                        .expect("Failed to initialize the result of a checked operation.");
+                    return Some(result.into_struct_value().into());
+                }
+            };
+            return Some(value.into());
+        }
+
+        // The only other supported method is the `len` intrinsic of arrays and strings
+        let len = match receiver_ty.as_array() {
+            Some((_, len)) => len,
+            None if receiver_ty.as_simple() == Some(TypeCtor::String) => None,
+            None => unreachable!("unknown method `{}`", method_name),
+        };
+
+        let receiver = self.gen_expr(receiver_expr)?;
+        match len {
+            Some(len) => Some(
+                self.hir_types
+                    .get_int_type(hir::IntTy::usize())
+                    .const_int(len, false)
+                    .into(),
+            ),
+            None => {
+                let mem_ptr = self
+                    .builder
+                    .build_load(receiver.into_pointer_value(), "array_mem_ptr")
+                    .into_pointer_value();
+                Some(self.gen_array_len(mem_ptr).into())
+            }
+        }
+    }
+}
+
+/// Returns the minimum and maximum value of the integer type `int_type`.
+fn int_bounds(int_type: IntType, is_signed: bool) -> (IntValue, IntValue) {
+    let bit_width = int_type.get_bit_width();
+    let const_int = |value: u128| {
+        if bit_width > 64 {
+            int_type.const_int_arbitrary_precision(&[value as u64, (value >> 64) as u64])
+        } else {
+            int_type.const_int(value as u64, false)
+        }
+    };
+    if is_signed {
+        (
+            const_int(1 << (bit_width - 1)),
+            const_int((1 << (bit_width - 1)) - 1),
+        )
+    } else {
+        (const_int(0), const_int(u128::MAX >> (128 - bit_width)))
+    }
+}
+
+/// Returns true if values of the specified type are passed to and returned from the public API of
+/// a function as heap-allocated values. This is the case for value structs, enums, and tuples.
+fn is_heap_value_in_public_api(db: &dyn HirDatabase, ty: &hir::Ty) -> bool {
+    if let Some(s) = ty.as_struct() {
+        s.data(db.upcast()).memory_kind == hir::StructMemoryKind::Value
+    } else {
+        ty.as_enum().is_some() || ty.as_tuple().is_some()
+    }
 }
 
 /// Derefs a heap-allocated value. As we introduce a layer of indirection for hot
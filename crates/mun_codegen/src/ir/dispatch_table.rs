@@ -1,5 +1,8 @@
-use crate::{intrinsics::Intrinsic, ir::function, ir::ty::HirTypeCache, type_info::TypeInfo};
-use hir::{Body, Expr, ExprId, HirDatabase, InferenceResult};
+use crate::{
+    intrinsics::Intrinsic, ir::function, ir::instance::FunctionInstance, ir::ty::HirTypeCache,
+    type_info::TypeInfo,
+};
+use hir::{Body, ExprId, HirDatabase, InferenceResult};
 use inkwell::{
     context::Context,
     module::Module,
@@ -30,8 +33,10 @@ pub struct DispatchTable<'ink> {
     context: &'ink Context,
     // The target for which to create the dispatch table
     target: TargetData,
+    // The module for which the dispatch table is created
+    hir_module: hir::Module,
     // This contains the function that map to the DispatchTable struct fields
-    function_to_idx: HashMap<hir::Function, usize>,
+    function_to_idx: HashMap<FunctionInstance, usize>,
     // Prototype to function index
     prototype_to_idx: HashMap<FunctionPrototype, usize>,
     // This contains an ordered list of all the function in the dispatch table
@@ -55,13 +60,19 @@ pub struct FunctionPrototype {
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct DispatchableFunction {
     pub prototype: FunctionPrototype,
-    pub hir: Option<hir::Function>,
+    pub hir: Option<FunctionInstance>,
 }
 
 impl<'ink> DispatchTable<'ink> {
     /// Returns whether the `DispatchTable` contains the specified `function`.
-    pub fn contains(&self, function: hir::Function) -> bool {
-        self.function_to_idx.contains_key(&function)
+    pub fn contains(&self, function: &FunctionInstance) -> bool {
+        self.function_to_idx.contains_key(function)
+    }
+
+    /// Returns whether the specified `function` is defined in another module. A function of another
+    /// module is called through the public API of the assembly that defines it.
+    pub fn is_external(&self, db: &dyn HirDatabase, function: &FunctionInstance) -> bool {
+        function.is_external(db, self.hir_module)
     }
 
     /// Returns a slice containing all the functions in the dispatch table.
@@ -77,14 +88,14 @@ impl<'ink> DispatchTable<'ink> {
         db: &dyn HirDatabase,
         table_ref: Option<inkwell::values::GlobalValue<'ink>>,
         builder: &inkwell::builder::Builder<'ink>,
-        function: hir::Function,
+        function: &FunctionInstance,
     ) -> PointerValue<'ink> {
-        let function_name = function.name(db).to_string();
+        let function_name = function.name(db);
 
         // Get the index of the function
         let index = *self
             .function_to_idx
-            .get(&function)
+            .get(function)
             .expect("unknown function");
 
         self.gen_function_lookup_by_index(table_ref, builder, &function_name, index)
@@ -157,10 +168,12 @@ pub(crate) struct DispatchTableBuilder<'db, 'ink, 't> {
     module: &'t Module<'ink>,
     // The target for which to create the dispatch table
     target_data: TargetData,
+    // The module for which to create the dispatch table
+    hir_module: hir::Module,
     // Converts HIR ty's to inkwell types
     hir_types: &'t HirTypeCache<'db, 'ink>,
     // This contains the functions that map to the DispatchTable struct fields
-    function_to_idx: HashMap<hir::Function, usize>,
+    function_to_idx: HashMap<FunctionInstance, usize>,
     // Prototype to function index
     prototype_to_idx: HashMap<FunctionPrototype, usize>,
     // These are *all* called functions in the modules
@@ -183,6 +196,7 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
         target_data: TargetData,
         db: &'db dyn HirDatabase,
         module: &'t Module<'ink>,
+        hir_module: hir::Module,
         intrinsics: &BTreeMap<FunctionPrototype, FunctionType<'ink>>,
         hir_types: &'t HirTypeCache<'db, 'ink>,
     ) -> Self {
@@ -191,6 +205,7 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
             context,
             module,
             target_data,
+            hir_module,
             function_to_idx: Default::default(),
             prototype_to_idx: Default::default(),
             entries: Default::default(),
@@ -231,32 +246,50 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
 
     /// Collects call expression from the given expression and sub expressions.
     fn collect_expr(&mut self, expr_id: ExprId, body: &Arc<Body>, infer: &InferenceResult) {
-        let expr = &body[expr_id];
-
-        // If this expression is a call, store it in the dispatch table
-        if let Expr::Call { callee, .. } = expr {
-            match infer[*callee].as_callable_def() {
-                Some(hir::CallableDef::Function(def)) => self.collect_fn_def(def),
-                Some(hir::CallableDef::Struct(_)) => (),
-                None => panic!("expected a callable expression"),
+        // If this expression is a call to a function or method, store it in the dispatch table
+        if let Some(function) = FunctionInstance::called_by(self.db, expr_id, body, infer) {
+            self.collect_fn_def(function);
+        }
+
+        // Recurse further
+        body[expr_id].walk_child_exprs(|expr_id| self.collect_expr(expr_id, body, infer));
+    }
+
+    /// Collects the calls to functions of other modules from the given expression and sub
+    /// expressions.
+    fn collect_external_expr(
+        &mut self,
+        expr_id: ExprId,
+        body: &Arc<Body>,
+        infer: &InferenceResult,
+    ) {
+        if let Some(function) = FunctionInstance::called_by(self.db, expr_id, body, infer) {
+            if function.is_external(self.db, self.hir_module) {
+                self.collect_fn_def(function);
             }
         }
 
         // Recurse further
-        expr.walk_child_exprs(|expr_id| self.collect_expr(expr_id, body, infer));
+        body[expr_id].walk_child_exprs(|expr_id| self.collect_external_expr(expr_id, body, infer));
     }
 
     /// Collects function call expression from the given expression.
     #[allow(clippy::map_entry)]
-    fn collect_fn_def(&mut self, function: hir::Function) {
+    fn collect_fn_def(&mut self, function: FunctionInstance) {
         self.ensure_table_ref();
 
         // If the function is not yet contained in the table, add it
         if !self.function_to_idx.contains_key(&function) {
-            let name = function.name(self.db).to_string();
-            let hir_type = function.ty(self.db);
-            let sig = hir_type.callable_sig(self.db).unwrap();
-            let ir_type = self.hir_types.get_function_type(function);
+            let name = function.name(self.db);
+            let sig = function.callable_sig(self.db);
+            let is_external = function.is_external(self.db, self.hir_module);
+
+            // A function of another module is called through its public API
+            let ir_type = if is_external && !sig.marshallable(self.db) {
+                self.hir_types.get_public_function_type(&function)
+            } else {
+                self.hir_types.get_function_type(&function)
+            };
             let arg_types = sig
                 .params()
                 .iter()
@@ -277,15 +310,20 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
             self.entries.push(TypedDispatchableFunction {
                 function: DispatchableFunction {
                     prototype: prototype.clone(),
-                    hir: Some(function),
+                    hir: Some(function.clone()),
                 },
                 ir_type,
             });
             self.prototype_to_idx.insert(prototype, index);
-            self.function_to_idx.insert(function, index);
+            self.function_to_idx.insert(function.clone(), index);
+
+            // The body of a function of another module is part of that module's assembly
+            if is_external {
+                return;
+            }
 
             // Recurse further
-            let fn_body = function.body(self.db);
+            let fn_body = function.function.body(self.db);
             self.collect_expr(
                 fn_body.body_expr(),
                 &fn_body,
@@ -300,6 +338,12 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
         self.collect_expr(body.body_expr(), body, infer);
     }
 
+    /// Collect the calls to functions of other modules from the specified body with the given type
+    /// inference result. Calls to functions of the same module are ignored.
+    pub fn collect_external_calls(&mut self, body: &Arc<Body>, infer: &InferenceResult) {
+        self.collect_external_expr(body.body_expr(), body, infer);
+    }
+
     /// Builds the final DispatchTable with all *called* functions from within the module
     /// # Parameters
     /// * **functions**: Mapping of *defined* Mun functions to their respective IR values.
@@ -324,10 +368,14 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
                 .map(|(i, entry)| {
                     let function_type = table_body[i].into_pointer_type();
                     // Find the associated IR function if it exists
-                    match entry.function.hir {
+                    match &entry.function.hir {
                         // Case external function: Convert to typed null for the given function
                         None => function_type.const_null(),
-                        Some(f) if f.is_extern(self.db) => function_type.const_null(),
+                        Some(f) if f.function.is_extern(self.db) => function_type.const_null(),
+                        // Case function of another module: linked by the runtime
+                        Some(f) if f.is_external(self.db, self.hir_module) => {
+                            function_type.const_null()
+                        }
                         // Case mun function: Get the function location as the initializer
                         Some(f) => function::gen_prototype(self.db, self.hir_types, f, self.module)
                             .as_global_value()
@@ -345,6 +393,7 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
         DispatchTable {
             context: self.context,
             target: self.target_data,
+            hir_module: self.hir_module,
             function_to_idx: self.function_to_idx,
             prototype_to_idx: self.prototype_to_idx,
             table_ref: self.table_ref,
@@ -2,9 +2,14 @@ use super::body::ExternalGlobals;
 use crate::code_gen::CodeGenContext;
 use crate::ir::body::BodyIrGenerator;
 use crate::ir::file_group::FileGroupIR;
-use crate::ir::{function, type_table::TypeTable};
+use crate::ir::{
+    function,
+    globals::{self, GlobalDef},
+    instance::{self, FunctionInstance},
+    type_table::TypeTable,
+};
 use crate::value::Global;
-use hir::{FileId, ModuleDef};
+use hir::FileId;
 use inkwell::module::Module;
 use std::collections::{BTreeMap, HashMap, HashSet};
 
@@ -17,6 +22,8 @@ pub struct FileIR<'ink> {
     pub llvm_module: Module<'ink>,
     /// The `hir::Function`s that constitute the file's API.
     pub api: HashSet<hir::Function>,
+    /// The constants and statics that are stored in globals
+    pub globals: Vec<GlobalDef>,
 }
 
 /// Generates IR for the specified file.
@@ -31,29 +38,39 @@ pub(crate) fn gen_file_ir<'db, 'ink>(
 
     let hir_types = &code_gen.hir_types;
 
-    // Generate all exposed function and wrapper function signatures.
+    // Generate the globals that store the values of statics and public constants
+    let globals = globals::collect_globals(code_gen.db, hir::Module::from(file_id));
+    globals::gen_globals(code_gen.db, hir_types, &llvm_module, &globals);
+
+    // Generate all exposed function and wrapper function signatures. Generic functions are
+    // generated for every instantiation that is used.
     // Use a `BTreeMap` to guarantee deterministically ordered output.ures
     let mut functions = HashMap::new();
     let mut wrapper_functions = BTreeMap::new();
-    for def in code_gen.db.module_data(file_id).definitions() {
-        if let ModuleDef::Function(f) = def {
-            if !f.is_extern(code_gen.db) {
-                let fun = function::gen_prototype(code_gen.db, hir_types, *f, &llvm_module);
-                functions.insert(*f, fun);
+    let instances = instance::collect_instances(
+        code_gen.db,
+        hir::Module::from(file_id).functions(code_gen.db),
+    );
+    for instance in instances {
+        let f = instance.function;
+        if !f.is_extern(code_gen.db) {
+            let fun = function::gen_prototype(code_gen.db, hir_types, &instance, &llvm_module);
 
-                let fn_sig = f.ty(code_gen.db).callable_sig(code_gen.db).unwrap();
-                if !f.data(code_gen.db).visibility().is_private()
-                    && !fn_sig.marshallable(code_gen.db)
-                {
-                    let wrapper_fun = function::gen_public_prototype(
-                        code_gen.db,
-                        &code_gen.hir_types,
-                        *f,
-                        &llvm_module,
-                    );
-                    wrapper_functions.insert(*f, wrapper_fun);
-                }
+            let fn_sig = instance.callable_sig(code_gen.db);
+            if instance.substs.is_empty()
+                && !f.data(code_gen.db).visibility().is_private()
+                && !fn_sig.marshallable(code_gen.db)
+            {
+                let wrapper_fun = function::gen_public_prototype(
+                    code_gen.db,
+                    &code_gen.hir_types,
+                    &instance,
+                    &llvm_module,
+                );
+                wrapper_functions.insert(f, wrapper_fun);
             }
+
+            functions.insert(instance, fun);
         }
     }
 
@@ -81,16 +98,18 @@ pub(crate) fn gen_file_ir<'db, 'ink>(
     let fn_pass_manager = function::create_pass_manager(&llvm_module, code_gen.optimization_level);
 
     // Generate the function bodies
-    for (hir_function, llvm_function) in functions.iter() {
+    for (instance, llvm_function) in functions.iter() {
         let mut code_gen = BodyIrGenerator::new(
             code_gen.context,
+            &llvm_module,
             code_gen.db,
-            (*hir_function, *llvm_function),
+            (instance.clone(), *llvm_function),
             &functions,
             &group_ir.dispatch_table,
             &group_ir.type_table,
             external_globals.clone(),
             &code_gen.hir_types,
+            code_gen.arithmetic_checks,
         );
 
         code_gen.gen_fn_body();
@@ -100,29 +119,34 @@ pub(crate) fn gen_file_ir<'db, 'ink>(
     for (hir_function, llvm_function) in wrapper_functions.iter() {
         let mut code_gen = BodyIrGenerator::new(
             code_gen.context,
+            &llvm_module,
             code_gen.db,
-            (*hir_function, *llvm_function),
+            (FunctionInstance::new(*hir_function), *llvm_function),
             &functions,
             &group_ir.dispatch_table,
             &group_ir.type_table,
             external_globals.clone(),
             &code_gen.hir_types,
+            code_gen.arithmetic_checks,
         );
 
         code_gen.gen_fn_wrapper();
         fn_pass_manager.run_on(llvm_function);
     }
 
-    // Filter private methods
+    // Filter private methods. Generic functions are not exposed, as they only exist for the type
+    // arguments with which they are used.
     let api: HashSet<hir::Function> = functions
         .keys()
+        .filter(|f| f.substs.is_empty())
+        .map(|f| f.function)
         .filter(|f| f.visibility(code_gen.db) != hir::Visibility::Private)
-        .cloned()
         .collect();
 
     FileIR {
         file_id,
         llvm_module,
         api,
+        globals,
     }
 }
@@ -1,6 +1,6 @@
 use super::{
     dispatch_table::{DispatchTable, DispatchTableBuilder},
-    intrinsics,
+    globals, instance, intrinsics,
     type_table::{TypeTable, TypeTableBuilder},
 };
 use crate::code_gen::CodeGenContext;
@@ -31,60 +31,78 @@ pub(crate) fn gen_file_group_ir<'db, 'ink>(
     file_id: hir::FileId,
 ) -> FileGroupIR<'ink> {
     let llvm_module = code_gen.context.create_module("group_name");
+    let hir_module = hir::Module::from(file_id);
 
     // Use a `BTreeMap` to guarantee deterministically ordered output.
     let mut intrinsics_map = BTreeMap::new();
     let mut needs_alloc = false;
 
     // Collect all intrinsic functions, wrapper function, and generate struct declarations.
-    for def in code_gen.db.module_data(file_id).definitions() {
-        match def {
-            ModuleDef::Function(f) if !f.is_extern(code_gen.db) => {
-                intrinsics::collect_fn_body(
-                    &code_gen.context,
-                    code_gen.target_machine.get_target_data(),
-                    code_gen.db,
-                    &mut intrinsics_map,
-                    &mut needs_alloc,
-                    &f.body(code_gen.db),
-                    &f.infer(code_gen.db),
-                );
+    for f in hir_module.functions(code_gen.db) {
+        // TODO: Extern types?
+        if f.is_extern(code_gen.db) {
+            continue;
+        }
 
-                let fn_sig = f.ty(code_gen.db).callable_sig(code_gen.db).unwrap();
-                if !f.data(code_gen.db).visibility().is_private()
-                    && !fn_sig.marshallable(code_gen.db)
-                {
-                    intrinsics::collect_wrapper_body(
-                        &code_gen.context,
-                        code_gen.target_machine.get_target_data(),
-                        &mut intrinsics_map,
-                        &mut needs_alloc,
-                    );
-                }
-            }
-            ModuleDef::Function(_) => (), // TODO: Extern types?
-            ModuleDef::Struct(_) => (),
-            ModuleDef::BuiltinType(_) => (),
-            ModuleDef::TypeAlias(_) => (),
+        intrinsics::collect_fn_body(
+            &code_gen.context,
+            code_gen.target_machine.get_target_data(),
+            code_gen.db,
+            &mut intrinsics_map,
+            &mut needs_alloc,
+            &f.body(code_gen.db),
+            &f.infer(code_gen.db),
+            code_gen.arithmetic_checks,
+        );
+        intrinsics::collect_external_calls(
+            &code_gen.context,
+            code_gen.target_machine.get_target_data(),
+            code_gen.db,
+            hir_module,
+            &mut intrinsics_map,
+            &mut needs_alloc,
+            &f.body(code_gen.db),
+            &f.infer(code_gen.db),
+        );
+
+        // Generic functions are not exposed, so they never need a wrapper
+        let fn_sig = f.ty(code_gen.db).callable_sig(code_gen.db).unwrap();
+        if !f.is_generic(code_gen.db)
+            && !f.data(code_gen.db).visibility().is_private()
+            && !fn_sig.marshallable(code_gen.db)
+        {
+            intrinsics::collect_wrapper_body(
+                &code_gen.context,
+                code_gen.target_machine.get_target_data(),
+                &mut intrinsics_map,
+                &mut needs_alloc,
+            );
         }
     }
 
-    // Collect all exposed functions' bodies.
+    // Collect all exposed functions' bodies. The instantiations of generic functions are collected
+    // from the bodies that use them. Functions of other modules can only be called through the
+    // dispatch table, so they are also collected from private functions.
     let mut dispatch_table_builder = DispatchTableBuilder::new(
         code_gen.context,
         code_gen.target_machine.get_target_data(),
         code_gen.db,
         &llvm_module,
+        hir_module,
         &intrinsics_map,
         &code_gen.hir_types,
     );
-    for def in code_gen.db.module_data(file_id).definitions() {
-        if let ModuleDef::Function(f) = def {
-            if !f.data(code_gen.db).visibility().is_private() && !f.is_extern(code_gen.db) {
-                let body = f.body(code_gen.db);
-                let infer = f.infer(code_gen.db);
-                dispatch_table_builder.collect_body(&body, &infer);
-            }
+    for f in hir_module.functions(code_gen.db) {
+        if f.is_extern(code_gen.db) || f.is_generic(code_gen.db) {
+            continue;
+        }
+
+        let body = f.body(code_gen.db);
+        let infer = f.infer(code_gen.db);
+        if f.data(code_gen.db).visibility().is_private() {
+            dispatch_table_builder.collect_external_calls(&body, &infer);
+        } else {
+            dispatch_table_builder.collect_body(&body, &infer);
         }
     }
 
@@ -116,10 +134,30 @@ pub(crate) fn gen_file_group_ir<'db, 'ink>(
             ModuleDef::Struct(s) => {
                 type_table_builder.collect_struct(*s);
             }
-            ModuleDef::Function(f) => {
-                type_table_builder.collect_fn(*f);
+            ModuleDef::Enum(e) => {
+                type_table_builder.collect_enum(*e);
+            }
+            ModuleDef::Function(_)
+            | ModuleDef::Const(_)
+            | ModuleDef::Static(_)
+            | ModuleDef::EnumVariant(_)
+            | ModuleDef::BuiltinType(_)
+            | ModuleDef::TypeAlias(_)
+            | ModuleDef::Trait(_) => (),
+        }
+    }
+    for global in globals::collect_globals(code_gen.db, hir_module) {
+        type_table_builder.collect_global(&global.ty(code_gen.db));
+    }
+    let instances = instance::collect_instances(code_gen.db, hir_module.functions(code_gen.db));
+    for instance in instances.iter() {
+        type_table_builder.collect_fn(instance);
+    }
+    for entry in dispatch_table.entries() {
+        if let Some(function) = &entry.hir {
+            if dispatch_table.is_external(code_gen.db, function) {
+                type_table_builder.collect_fn_sig(function);
             }
-            ModuleDef::BuiltinType(_) | ModuleDef::TypeAlias(_) => (),
         }
     }
 
@@ -1,4 +1,4 @@
-use crate::{ir::ty::HirTypeCache, Module, OptimizationLevel};
+use crate::{ir::instance::FunctionInstance, ir::ty::HirTypeCache, Module, OptimizationLevel};
 use inkwell::{
     passes::{PassManager, PassManagerBuilder},
     values::FunctionValue,
@@ -21,17 +21,17 @@ pub(crate) fn create_pass_manager<'ink>(
     function_pass_manager
 }
 
-/// Generates a `FunctionValue` for a `hir::Function`. This function does not generate a body for
-/// the `hir::Function`. That task is left to the `gen_body` function. The reason this is split
-/// between two functions is that first all signatures are generated and then all bodies. This
-/// allows bodies to reference `FunctionValue` wherever they are declared in the file.
+/// Generates a `FunctionValue` for a `FunctionInstance`. This function does not generate a body
+/// for the `FunctionInstance`. That task is left to the `gen_body` function. The reason this is
+/// split between two functions is that first all signatures are generated and then all bodies.
+/// This allows bodies to reference `FunctionValue` wherever they are declared in the file.
 pub(crate) fn gen_prototype<'db, 'ink>(
     db: &'db dyn HirDatabase,
     types: &HirTypeCache<'db, 'ink>,
-    func: hir::Function,
+    func: &FunctionInstance,
     module: &Module<'ink>,
 ) -> FunctionValue<'ink> {
-    let name = func.name(db).to_string();
+    let name = func.name(db);
     let ir_ty = types.get_function_type(func);
     module.add_function(&name, ir_ty, None)
 }
@@ -44,7 +44,7 @@ pub(crate) fn gen_prototype<'db, 'ink>(
 pub(crate) fn gen_public_prototype<'db, 'ink>(
     db: &'db dyn HirDatabase,
     types: &HirTypeCache<'db, 'ink>,
-    func: hir::Function,
+    func: &FunctionInstance,
     module: &Module<'ink>,
 ) -> FunctionValue<'ink> {
     let name = format!("{}_wrapper", func.name(db));
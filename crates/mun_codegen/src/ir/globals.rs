@@ -0,0 +1,137 @@
+//! Generates IR for the constants and statics of a module.
+//!
+//! The values of constants and statics are evaluated at compile time. Constants are inlined where
+//! they are used, but public constants are also stored in a global so they can be reflected.
+//! Statics are always stored in a global, which is reflected so the runtime can retain its value
+//! when the module is hot reloaded.
+
+use crate::ir::ty::HirTypeCache;
+use hir::{ConstValue, HirDatabase, Ty, TypeCtor};
+use inkwell::{module::Module, values::BasicValueEnum};
+
+/// A constant or static that is stored in a global
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GlobalDef {
+    Const(hir::Const),
+    Static(hir::Static),
+}
+
+impl GlobalDef {
+    /// Returns the name of the global, which includes the path of its module
+    pub fn full_name(self, db: &dyn HirDatabase) -> String {
+        match self {
+            GlobalDef::Const(c) => c.full_name(db),
+            GlobalDef::Static(s) => s.full_name(db),
+        }
+    }
+
+    /// Returns the type of the value stored in the global
+    pub fn ty(self, db: &dyn HirDatabase) -> Ty {
+        match self {
+            GlobalDef::Const(c) => c.ty(db),
+            GlobalDef::Static(s) => s.ty(db),
+        }
+    }
+
+    /// Returns the compile-time evaluated value with which the global is initialized
+    pub fn value(self, db: &dyn HirDatabase) -> ConstValue {
+        let value = match self {
+            GlobalDef::Const(c) => c.value(db),
+            GlobalDef::Static(s) => s.initial_value(db),
+        };
+        value.expect("the value of a global must be evaluated at compile time")
+    }
+
+    /// Returns true if the value of the global can be modified, which is only the case for
+    /// `static mut`.
+    pub fn is_mutable(self, db: &dyn HirDatabase) -> bool {
+        match self {
+            GlobalDef::Const(_) => false,
+            GlobalDef::Static(s) => s.is_mut(db.upcast()),
+        }
+    }
+}
+
+/// Returns the constants and statics of the specified module that are stored in a global: all
+/// statics and all public constants.
+pub(crate) fn collect_globals(db: &dyn HirDatabase, hir_module: hir::Module) -> Vec<GlobalDef> {
+    let consts = hir_module
+        .consts(db)
+        .into_iter()
+        .filter(|c| !c.visibility(db.upcast()).is_private())
+        .map(GlobalDef::Const);
+    let statics = hir_module.statics(db).into_iter().map(GlobalDef::Static);
+    consts.chain(statics).collect()
+}
+
+/// Adds a global for each of the specified constants and statics to the `module`. Every global is
+/// named after the full name of its definition.
+pub(crate) fn gen_globals<'ink>(
+    db: &dyn HirDatabase,
+    hir_types: &HirTypeCache<'_, 'ink>,
+    module: &Module<'ink>,
+    globals: &[GlobalDef],
+) {
+    for def in globals.iter() {
+        let value = gen_const_value(db, hir_types, &def.ty(db), &def.value(db));
+        let global = module.add_global(value.get_type(), None, &def.full_name(db));
+        global.set_initializer(&value);
+        global.set_constant(!def.is_mutable(db));
+    }
+}
+
+/// Constructs an IR constant of type `ty` from the compile-time evaluated `value`.
+pub(crate) fn gen_const_value<'ink>(
+    db: &dyn HirDatabase,
+    hir_types: &HirTypeCache<'_, 'ink>,
+    ty: &Ty,
+    value: &ConstValue,
+) -> BasicValueEnum<'ink> {
+    match (value, ty) {
+        (ConstValue::Bool(value), _) => hir_types
+            .get_bool_type()
+            .const_int(*value as u64, false)
+            .into(),
+        (ConstValue::Int(value), hir::ty_app!(TypeCtor::Int(int_ty))) => {
+            let int_type = hir_types.get_int_type(*int_ty);
+            if int_type.get_bit_width() > 64 {
+                let value = *value as u128;
+                int_type
+                    .const_int_arbitrary_precision(&[value as u64, (value >> 64) as u64])
+                    .into()
+            } else {
+                int_type.const_int(*value as u64, false).into()
+            }
+        }
+        (ConstValue::Float(value), hir::ty_app!(TypeCtor::Float(float_ty))) => hir_types
+            .get_float_type(*float_ty)
+            .const_float(*value)
+            .into(),
+        (ConstValue::Struct(values), hir::ty_app!(TypeCtor::Struct(s), substs)) => {
+            let fields: Vec<_> = s
+                .fields(db)
+                .into_iter()
+                .zip(values.iter())
+                .map(|(field, value)| {
+                    gen_const_value(db, hir_types, &field.ty(db).subst(substs), value)
+                })
+                .collect();
+            hir_types
+                .get_struct_type(*s, substs)
+                .const_named_struct(&fields)
+                .into()
+        }
+        (ConstValue::Struct(values), hir::ty_app!(TypeCtor::Tuple { .. }, element_tys)) => {
+            let elements: Vec<_> = element_tys
+                .iter()
+                .zip(values.iter())
+                .map(|(ty, value)| gen_const_value(db, hir_types, ty, value))
+                .collect();
+            hir_types
+                .get_tuple_type(element_tys)
+                .const_named_struct(&elements)
+                .into()
+        }
+        _ => unreachable!("the value of a constant does not match its type"),
+    }
+}
@@ -0,0 +1,146 @@
+use hir::{Body, Expr, ExprId, FnSig, HirDatabase, HirDisplay, InferenceResult, Substs};
+use std::{collections::HashSet, sync::Arc};
+
+/// A function together with the type arguments of its generic parameters. Generic functions are
+/// monomorphized: IR is generated for every instantiation of a generic function, as if it were a
+/// separate function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FunctionInstance {
+    pub function: hir::Function,
+    pub substs: Substs,
+}
+
+impl FunctionInstance {
+    /// Constructs the instance of a function that is not generic.
+    pub fn new(function: hir::Function) -> Self {
+        Self {
+            function,
+            substs: Substs::empty(),
+        }
+    }
+
+    /// Constructs the instance of `function` that is called with the type arguments `substs`. A
+    /// call to a function declared in a trait calls the implementation of the trait for the `Self`
+    /// type argument.
+    pub fn resolve(db: &dyn HirDatabase, function: hir::Function, substs: &Substs) -> Self {
+        let (function, substs) = function.resolve_call(db, substs);
+        Self { function, substs }
+    }
+
+    /// Returns the instance of the function that is called by the specified call, method call or
+    /// overloaded operator expression, or that is used as a value by the specified path
+    /// expression. Returns `None` if the expression does not refer to a function.
+    pub fn called_by(
+        db: &dyn HirDatabase,
+        expr: ExprId,
+        body: &Body,
+        infer: &InferenceResult,
+    ) -> Option<Self> {
+        match &body[expr] {
+            Expr::Call { callee, .. } => match &infer[*callee] {
+                hir::ty_app!(
+                    hir::TypeCtor::FnDef(hir::CallableDef::Function(function)),
+                    parameters
+                ) => Some(Self {
+                    function: *function,
+                    substs: parameters.clone(),
+                }),
+                _ => None,
+            },
+            Expr::MethodCall { .. } | Expr::BinaryOp { .. } => infer
+                .method_resolution(expr)
+                .map(|(function, substs)| Self::resolve(db, function, &substs)),
+            Expr::Path(_) => infer
+                .function_value(expr)
+                .map(|(function, substs)| Self::resolve(db, function, &substs)),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the instance. The type arguments of a generic function are part of the
+    /// name, e.g. `max<i32>`, to distinguish its instantiations.
+    pub fn name(&self, db: &dyn HirDatabase) -> String {
+        let name = self.function.full_name(db);
+        if self.substs.is_empty() {
+            name
+        } else {
+            let type_args: Vec<String> = self
+                .substs
+                .iter()
+                .map(|ty| ty.display(db).to_string())
+                .collect();
+            format!("{}<{}>", name, type_args.join(", "))
+        }
+    }
+
+    /// Returns true if the instance is defined by another module than `module`. Such an instance is
+    /// not generated in `module`, so it can only be called through the dispatch table. Generic
+    /// instances are generated by every module that uses them, so they are never external.
+    pub fn is_external(&self, db: &dyn HirDatabase, module: hir::Module) -> bool {
+        self.substs.is_empty()
+            && !self.function.is_extern(db)
+            && self.function.module(db.upcast()) != module
+    }
+
+    /// Returns the signature of the instance.
+    pub fn callable_sig(&self, db: &dyn HirDatabase) -> FnSig {
+        db.callable_sig(self.function.into()).subst(&self.substs)
+    }
+
+    /// Returns the type inference result of the body of the instance, in which all generic
+    /// parameters have been replaced by their type arguments.
+    pub fn infer(&self, db: &dyn HirDatabase) -> Arc<InferenceResult> {
+        let infer = self.function.infer(db);
+        if self.substs.is_empty() {
+            infer
+        } else {
+            Arc::new(infer.subst(&self.substs))
+        }
+    }
+}
+
+/// Collects the instances of the specified functions that are not generic and the instantiations
+/// of generic functions that are (transitively) called by them.
+pub(crate) fn collect_instances(
+    db: &dyn HirDatabase,
+    functions: impl IntoIterator<Item = hir::Function>,
+) -> Vec<FunctionInstance> {
+    let mut instances: Vec<FunctionInstance> = functions
+        .into_iter()
+        .filter(|f| !f.is_generic(db))
+        .map(FunctionInstance::new)
+        .collect();
+    let mut visited: HashSet<FunctionInstance> = instances.iter().cloned().collect();
+
+    // Walk the bodies of all instances, including the ones that are discovered along the way
+    let mut idx = 0;
+    while idx < instances.len() {
+        let instance = instances[idx].clone();
+        let body = instance.function.body(db);
+        let infer = instance.infer(db);
+        collect_expr(db, body.body_expr(), &body, &infer, &mut |callee| {
+            if !callee.substs.is_empty() && visited.insert(callee.clone()) {
+                instances.push(callee);
+            }
+        });
+        idx += 1;
+    }
+
+    instances
+}
+
+/// Calls `f` for every instance of a function that is called from the specified expression and
+/// its sub-expressions.
+fn collect_expr(
+    db: &dyn HirDatabase,
+    expr: ExprId,
+    body: &Arc<Body>,
+    infer: &InferenceResult,
+    f: &mut dyn FnMut(FunctionInstance),
+) {
+    if let Some(callee) = FunctionInstance::called_by(db, expr, body, infer) {
+        f(callee);
+    }
+
+    body[expr].walk_child_exprs(|expr| collect_expr(db, expr, body, infer, f));
+}
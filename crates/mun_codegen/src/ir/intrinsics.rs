@@ -1,8 +1,11 @@
 use crate::{
     intrinsics::{self, Intrinsic},
-    ir::dispatch_table::FunctionPrototype,
+    ir::{dispatch_table::FunctionPrototype, instance::FunctionInstance},
+};
+use hir::{
+    ArithOp, BinaryOp, Body, CmpOp, Expr, ExprId, HirDatabase, InferenceResult, Literal, Pat,
+    PatId, TypeCtor, UnaryOp,
 };
-use hir::{Body, Expr, ExprId, HirDatabase, InferenceResult};
 use inkwell::{context::Context, targets::TargetData, types::FunctionType};
 use std::{collections::BTreeMap, sync::Arc};
 
@@ -22,6 +25,22 @@ fn collect_intrinsic<'ink>(
         .or_insert_with(|| intrinsic.ir_type(context, target));
 }
 
+/// Returns whether comparing values of type `ty` for equality compares strings, i.e. whether `ty`
+/// is a string or a struct or tuple that contains a string.
+fn compares_strings(db: &dyn HirDatabase, ty: &hir::Ty) -> bool {
+    match ty {
+        hir::ty_app!(TypeCtor::String) => true,
+        hir::ty_app!(TypeCtor::Struct(s), parameters) => s
+            .fields(db)
+            .into_iter()
+            .any(|field| compares_strings(db, &field.ty(db).subst(parameters))),
+        hir::ty_app!(TypeCtor::Tuple { .. }, element_tys) => {
+            element_tys.iter().any(|ty| compares_strings(db, ty))
+        }
+        _ => false,
+    }
+}
+
 /// Iterates over all expressions and stores information on which intrinsics they use in `entries`.
 #[allow(clippy::too_many_arguments)]
 fn collect_expr<'db, 'ink>(
@@ -44,8 +63,32 @@ fn collect_expr<'db, 'ink>(
                 // self.collect_intrinsic(module, entries, &intrinsics::drop);
                 *needs_alloc = true;
             }
-            Some(hir::CallableDef::Function(_)) => (),
-            None => panic!("expected a callable expression"),
+            Some(hir::CallableDef::Function(_)) | Some(hir::CallableDef::EnumVariant(_)) => (),
+            None => {
+                if infer[*callee].callable_sig(db).is_none() {
+                    panic!("expected a callable expression")
+                }
+            }
+        }
+    }
+
+    // Calls to Mun functions and closures are followed by a check whether the callee panicked
+    let calls_mun_code = match FunctionInstance::called_by(db, expr_id, body, infer) {
+        Some(function) => !function.function.is_extern(db),
+        None => match expr {
+            Expr::Call { callee, .. } => infer[*callee].as_callable_def().is_none(),
+            _ => false,
+        },
+    };
+    if calls_mun_code {
+        collect_intrinsic(context, &target, &intrinsics::panicking, intrinsics);
+    }
+
+    // Closures, and functions that are used as values, are allocated on the heap
+    if let hir::ty_app!(TypeCtor::FnPtr { .. }) = &infer[expr_id] {
+        if let Expr::Lambda { .. } | Expr::Path(_) = expr {
+            collect_intrinsic(context, &target, &intrinsics::new, intrinsics);
+            *needs_alloc = true;
         }
     }
 
@@ -55,14 +98,93 @@ fn collect_expr<'db, 'ink>(
         *needs_alloc = true;
     }
 
+    if let Expr::Array(_) = expr {
+        if let Some((_, None)) = infer[expr_id].as_array() {
+            collect_intrinsic(context, &target, &intrinsics::new_array, intrinsics);
+            *needs_alloc = true;
+        }
+    }
+
+    // Strings are allocated like arrays
+    if let Expr::Literal(Literal::String(_)) = expr {
+        collect_intrinsic(context, &target, &intrinsics::new_array, intrinsics);
+        *needs_alloc = true;
+    }
+
+    if let Expr::BinaryOp {
+        lhs, op: Some(op), ..
+    } = expr
+    {
+        if infer[*lhs].as_simple() == Some(TypeCtor::String) {
+            match op {
+                BinaryOp::ArithOp(ArithOp::Add)
+                | BinaryOp::Assignment {
+                    op: Some(ArithOp::Add),
+                } => {
+                    collect_intrinsic(context, &target, &intrinsics::string_concat, intrinsics);
+                    *needs_alloc = true;
+                }
+                BinaryOp::CmpOp(CmpOp::Eq { .. }) => {
+                    collect_intrinsic(context, &target, &intrinsics::string_eq, intrinsics);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    // Structs and tuples are compared field by field, unless the struct overloads `==`
+    if let Expr::BinaryOp {
+        lhs,
+        op: Some(BinaryOp::CmpOp(CmpOp::Eq { .. })),
+        ..
+    } = expr
+    {
+        let lhs_ty = &infer[*lhs];
+        if (lhs_ty.as_struct().is_some() || lhs_ty.as_tuple().is_some())
+            && infer.method_resolution(expr_id).is_none()
+            && compares_strings(db, lhs_ty)
+        {
+            collect_intrinsic(context, &target, &intrinsics::string_eq, intrinsics);
+        }
+    }
+
+    // Literal patterns are stored as expressions of the patterns of `match` arms and `if let` or
+    // `while let` conditions
+    if let Expr::Match { arms, .. } = expr {
+        for arm in arms.iter() {
+            collect_pat(
+                context,
+                target,
+                db,
+                intrinsics,
+                needs_alloc,
+                arm.pat,
+                body,
+                infer,
+            );
+        }
+    }
+    if let Expr::Let { pat, .. } = expr {
+        collect_pat(
+            context,
+            target,
+            db,
+            intrinsics,
+            needs_alloc,
+            *pat,
+            body,
+            infer,
+        );
+    }
+
     if let Expr::Path(path) = expr {
         let resolver = hir::resolver_for_expr(body.clone(), db, expr_id);
+        // Paths to associated functions (e.g. `Foo::new`) are not resolved by the resolver
         let resolution = resolver
             .resolve_path_without_assoc_items(db, path)
-            .take_values()
-            .expect("unknown path");
+            .take_values();
 
-        if let hir::Resolution::Def(hir::ModuleDef::Struct(_)) = resolution {
+        if let Some(hir::Resolution::Def(hir::ModuleDef::Struct(_))) = resolution {
             collect_intrinsic(context, &target, &intrinsics::new, intrinsics);
             // self.collect_intrinsic( module, entries, &intrinsics::drop);
             *needs_alloc = true;
@@ -84,7 +206,53 @@ fn collect_expr<'db, 'ink>(
     })
 }
 
+/// Iterates over all patterns and stores information on which intrinsics their literals use in
+/// `entries`.
+#[allow(clippy::too_many_arguments)]
+fn collect_pat<'db, 'ink>(
+    context: &'ink Context,
+    target: &TargetData,
+    db: &'db dyn HirDatabase,
+    intrinsics: &mut IntrinsicsMap<'ink>,
+    needs_alloc: &mut bool,
+    pat_id: PatId,
+    body: &Arc<Body>,
+    infer: &InferenceResult,
+) {
+    match &body[pat_id] {
+        Pat::Lit(expr_id) => {
+            // String literal patterns are compared using an intrinsic
+            if infer[pat_id].as_simple() == Some(TypeCtor::String) {
+                collect_intrinsic(context, &target, &intrinsics::string_eq, intrinsics);
+            }
+            collect_expr(
+                context,
+                target,
+                db,
+                intrinsics,
+                needs_alloc,
+                *expr_id,
+                body,
+                infer,
+            )
+        }
+        pat => pat.walk_child_pats(|pat_id| {
+            collect_pat(
+                context,
+                target,
+                db,
+                intrinsics,
+                needs_alloc,
+                pat_id,
+                body,
+                infer,
+            )
+        }),
+    }
+}
+
 /// Collects all intrinsics from the specified `body`.
+#[allow(clippy::too_many_arguments)]
 pub fn collect_fn_body<'db, 'ink>(
     context: &'ink Context,
     target: TargetData,
@@ -93,6 +261,7 @@ pub fn collect_fn_body<'db, 'ink>(
     needs_alloc: &mut bool,
     body: &Arc<Body>,
     infer: &InferenceResult,
+    arithmetic_checks: bool,
 ) {
     collect_expr(
         context,
@@ -104,6 +273,98 @@ pub fn collect_fn_body<'db, 'ink>(
         body,
         infer,
     );
+
+    if uses_panic(body.body_expr(), body, infer, arithmetic_checks) {
+        collect_intrinsic(context, &target, &intrinsics::panic, intrinsics);
+    }
+}
+
+/// Returns true if the expression `expr_id`, or one of its child expressions, can abort the
+/// invocation of its function through the `panic` intrinsic. Indexing an array panics if the index
+/// is out of bounds, and integer division and shifts panic if their result is undefined. Other
+/// integer arithmetic panics on overflow if `arithmetic_checks` are enabled. The arithmetic methods
+/// of integers, e.g. `checked_add`, never panic.
+fn uses_panic(
+    expr_id: ExprId,
+    body: &Arc<Body>,
+    infer: &InferenceResult,
+    arithmetic_checks: bool,
+) -> bool {
+    let is_int = |expr_id: ExprId| matches!(infer[expr_id], hir::ty_app!(TypeCtor::Int(_)));
+    let panics = match &body[expr_id] {
+        Expr::BinaryOp {
+            lhs,
+            op: Some(BinaryOp::ArithOp(op)),
+            ..
+        }
+        | Expr::BinaryOp {
+            lhs,
+            op: Some(BinaryOp::Assignment { op: Some(op) }),
+            ..
+        } => {
+            is_int(*lhs)
+                && match op {
+                    ArithOp::Add | ArithOp::Subtract | ArithOp::Multiply => arithmetic_checks,
+                    ArithOp::Divide
+                    | ArithOp::Remainder
+                    | ArithOp::LeftShift
+                    | ArithOp::RightShift => true,
+                    ArithOp::BitAnd | ArithOp::BitOr | ArithOp::BitXor => false,
+                }
+        }
+        Expr::UnaryOp {
+            expr,
+            op: UnaryOp::Neg,
+        } => arithmetic_checks && is_int(*expr),
+        Expr::Index { base, .. } => infer[*base].as_array().is_some(),
+        _ => false,
+    };
+
+    let mut child_panics = false;
+    body[expr_id].walk_child_exprs(|expr_id| {
+        child_panics = child_panics || uses_panic(expr_id, body, infer, arithmetic_checks)
+    });
+    panics || child_panics
+}
+
+/// Collects the intrinsics that are required to call functions of other modules from the specified
+/// `body`. These functions are called through their public API, to which value structs, enums, and
+/// tuples are passed as heap-allocated values.
+#[allow(clippy::too_many_arguments)]
+pub fn collect_external_calls<'db, 'ink>(
+    context: &'ink Context,
+    target: TargetData,
+    db: &'db dyn HirDatabase,
+    module: hir::Module,
+    intrinsics: &mut IntrinsicsMap<'ink>,
+    needs_alloc: &mut bool,
+    body: &Arc<Body>,
+    infer: &InferenceResult,
+) {
+    fn collect_external_expr(
+        db: &dyn HirDatabase,
+        module: hir::Module,
+        needs_new: &mut bool,
+        expr_id: ExprId,
+        body: &Arc<Body>,
+        infer: &InferenceResult,
+    ) {
+        if let Some(function) = FunctionInstance::called_by(db, expr_id, body, infer) {
+            if function.is_external(db, module) && !function.callable_sig(db).marshallable(db) {
+                *needs_new = true;
+            }
+        }
+        body[expr_id].walk_child_exprs(|expr_id| {
+            collect_external_expr(db, module, needs_new, expr_id, body, infer)
+        });
+    }
+
+    let mut needs_new = false;
+    collect_external_expr(db, module, &mut needs_new, body.body_expr(), body, infer);
+    if needs_new {
+        collect_intrinsic(context, &target, &intrinsics::new, intrinsics);
+        *needs_alloc = true;
+    }
 }
 
 /// Collects all intrinsics from a function wrapper body.
@@ -115,5 +376,6 @@ pub fn collect_wrapper_body<'ink>(
 ) {
     collect_intrinsic(context, &target, &intrinsics::new, intrinsics);
     // self.collect_intrinsic(entries, &intrinsics::drop, module);
+    collect_intrinsic(context, &target, &intrinsics::panicking, intrinsics);
     *needs_alloc = true;
 }
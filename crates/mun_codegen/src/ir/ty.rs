@@ -1,16 +1,19 @@
 use crate::{
-    ir::IsIrType,
+    ir::{instance::FunctionInstance, IsIrType},
     type_info::{TypeInfo, TypeSize},
 };
-use hir::{ty_app, FloatBitness, HirDatabase, IntBitness, ResolveBitness, Ty, TypeCtor};
+use hir::{
+    ty_app, ApplicationTy, FloatBitness, HirDatabase, HirDisplay, IntBitness, ResolveBitness,
+    Substs, Ty, TypeCtor,
+};
 use inkwell::{
     context::Context,
     targets::TargetData,
     types::FunctionType,
-    types::{AnyTypeEnum, BasicType, BasicTypeEnum, FloatType, IntType, StructType},
+    types::{AnyTypeEnum, ArrayType, BasicType, BasicTypeEnum, FloatType, IntType, StructType},
     AddressSpace,
 };
-use std::{cell::RefCell, collections::HashMap};
+use std::{cell::RefCell, collections::HashMap, convert::TryInto};
 
 /// An object to cache and convert HIR types to Inkwell types.
 pub struct HirTypeCache<'db, 'ink> {
@@ -56,13 +59,17 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         self.context.bool_type()
     }
 
-    /// Returns the type of the specified integer type
-    pub fn get_struct_type(&self, struct_ty: hir::Struct) -> StructType<'ink> {
+    /// Returns the type of the specified struct, instantiated with the type arguments `substs`.
+    /// Every instantiation of a generic struct results in a different type.
+    pub fn get_struct_type(&self, struct_ty: hir::Struct, substs: &Substs) -> StructType<'ink> {
         // TODO: This assumes the contents of the hir::Struct does not change. It definitely does
         //  between compilations. We have to have a way to uniquely identify the `hir::Struct` and
         //  its contents.
 
-        let ty = Ty::simple(TypeCtor::Struct(struct_ty));
+        let ty = Ty::Apply(ApplicationTy {
+            ctor: TypeCtor::Struct(struct_ty),
+            parameters: substs.clone(),
+        });
 
         // Get the type from the cache
         if let Some(ir_ty) = self.types.borrow().get(&ty) {
@@ -72,14 +79,14 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         // Opaquely construct the struct type and store it in the cache
         let ir_ty = self
             .context
-            .opaque_struct_type(&struct_ty.name(self.db.upcast()).to_string());
+            .opaque_struct_type(&ty.display(self.db).to_string());
         self.types.borrow_mut().insert(ty, ir_ty);
 
         // Fill the struct members
         let field_types: Vec<_> = struct_ty
             .fields(self.db)
             .into_iter()
-            .map(|field| field.ty(self.db))
+            .map(|field| field.ty(self.db).subst(substs))
             .map(|ty| {
                 self.get_basic_type(&ty)
                     .expect("could not convert struct field to basic type")
@@ -91,8 +98,12 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
     }
 
     /// Returns the type of the struct that should be used for variables.
-    pub fn get_struct_reference_type(&self, struct_ty: hir::Struct) -> BasicTypeEnum<'ink> {
-        let ir_ty = self.get_struct_type(struct_ty);
+    pub fn get_struct_reference_type(
+        &self,
+        struct_ty: hir::Struct,
+        substs: &Substs,
+    ) -> BasicTypeEnum<'ink> {
+        let ir_ty = self.get_struct_type(struct_ty, substs);
         match struct_ty.data(self.db.upcast()).memory_kind {
             hir::StructMemoryKind::GC => {
                 // GC values are pointers to pointers
@@ -112,8 +123,12 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
 
     /// Returns the type of the struct that should be used in the public API. In the public API we
     /// don't deal with value types, only with pointers.
-    pub fn get_public_struct_reference_type(&self, struct_ty: hir::Struct) -> BasicTypeEnum<'ink> {
-        let ir_ty = self.get_struct_type(struct_ty);
+    pub fn get_public_struct_reference_type(
+        &self,
+        struct_ty: hir::Struct,
+        substs: &Substs,
+    ) -> BasicTypeEnum<'ink> {
+        let ir_ty = self.get_struct_type(struct_ty, substs);
 
         // GC values are pointers to pointers
         // struct Foo {}
@@ -126,9 +141,240 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             .into()
     }
 
-    /// Returns the type of the specified function definition
-    pub fn get_function_type(&self, ty: hir::Function) -> FunctionType<'ink> {
-        let ty = self.db.callable_sig(ty.into());
+    /// Returns the type of the specified enum. An enum is stored as its discriminant, followed by
+    /// enough memory to store the fields of any of its variants:
+    ///
+    /// ```text
+    /// { iN, [K x iA] }
+    /// ```
+    ///
+    /// where `iN` is the integer type returned by `get_enum_discriminant_type` and the payload
+    /// consists of `K` integers with the size of the largest alignment `A` of all variants.
+    pub fn get_enum_type(&self, enum_ty: hir::Enum) -> StructType<'ink> {
+        let ty = Ty::simple(TypeCtor::Enum(enum_ty));
+
+        // Get the type from the cache
+        if let Some(ir_ty) = self.types.borrow().get(&ty) {
+            return *ir_ty;
+        };
+
+        // Opaquely construct the enum type and store it in the cache
+        let ir_ty = self
+            .context
+            .opaque_struct_type(&enum_ty.name(self.db.upcast()).to_string());
+        self.types.borrow_mut().insert(ty, ir_ty);
+
+        // Determine the size and alignment of the largest variant
+        let (payload_size, payload_alignment) = enum_ty
+            .variants(self.db)
+            .into_iter()
+            .map(|variant| {
+                let variant_ir_ty = self.get_enum_variant_type(variant);
+                (
+                    self.target_data.get_abi_size(&variant_ir_ty),
+                    self.target_data.get_abi_alignment(&variant_ir_ty),
+                )
+            })
+            .fold(
+                (0, 1),
+                |(size, alignment), (variant_size, variant_alignment)| {
+                    (size.max(variant_size), alignment.max(variant_alignment))
+                },
+            );
+
+        let payload_element_ty = self.context.custom_width_int_type(payload_alignment * 8);
+        let payload_len =
+            (payload_size + u64::from(payload_alignment) - 1) / u64::from(payload_alignment);
+        ir_ty.set_body(
+            &[
+                self.get_enum_discriminant_type(enum_ty).into(),
+                payload_element_ty
+                    .array_type(payload_len.try_into().expect("enum variant is too large"))
+                    .into(),
+            ],
+            false,
+        );
+
+        ir_ty
+    }
+
+    /// Returns the type of the discriminant of the specified enum.
+    pub fn get_enum_discriminant_type(&self, enum_ty: hir::Enum) -> IntType<'ink> {
+        if enum_ty.variants(self.db).len() <= 256 {
+            self.context.i8_type()
+        } else {
+            self.context.i16_type()
+        }
+    }
+
+    /// Returns the type of the fields of an enum variant. The payload of an enum is reinterpreted
+    /// as this type to access the fields of the variant.
+    pub fn get_enum_variant_type(&self, variant: hir::EnumVariant) -> StructType<'ink> {
+        let field_types: Vec<_> = variant
+            .field_types(self.db)
+            .iter()
+            .map(|ty| {
+                self.get_basic_type(ty)
+                    .expect("could not convert enum variant field to basic type")
+            })
+            .collect();
+        self.context.struct_type(&field_types, false)
+    }
+
+    /// Returns the type of the enum that should be used in the public API. Just like value structs,
+    /// enums are converted to garbage collected types in the public API.
+    pub fn get_public_enum_reference_type(&self, enum_ty: hir::Enum) -> BasicTypeEnum<'ink> {
+        self.get_enum_type(enum_ty)
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic)
+            .into()
+    }
+
+    /// Returns the type of a fixed-size array with `len` elements of type `element_ty`.
+    pub fn get_fixed_array_type(&self, element_ty: &hir::Ty, len: u64) -> ArrayType<'ink> {
+        self.get_basic_type(element_ty)
+            .expect("could not convert array element to basic type")
+            .array_type(len.try_into().expect("array length is too large"))
+    }
+
+    /// Returns the type of the memory of a garbage collected array with elements of type
+    /// `element_ty`. The memory starts with the length of the array, followed by its elements.
+    pub fn get_array_type(&self, element_ty: &hir::Ty) -> StructType<'ink> {
+        let element_ir_ty = self
+            .get_basic_type(element_ty)
+            .expect("could not convert array element to basic type");
+        self.context.struct_type(
+            &[
+                usize::ir_type(self.context, &self.target_data).into(),
+                element_ir_ty.array_type(0).into(),
+            ],
+            false,
+        )
+    }
+
+    /// Returns the type of a garbage collected array that should be used for variables.
+    pub fn get_array_reference_type(&self, element_ty: &hir::Ty) -> BasicTypeEnum<'ink> {
+        // GC arrays are pointers to pointers, just like GC structs
+        // { usize, [0 x T] }**
+        self.get_array_type(element_ty)
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic)
+            .into()
+    }
+
+    /// Returns the type of the memory of a garbage collected string. The memory starts with the
+    /// length of the string in bytes, followed by its UTF-8 encoded text.
+    pub fn get_string_type(&self) -> StructType<'ink> {
+        self.context.struct_type(
+            &[
+                usize::ir_type(self.context, &self.target_data).into(),
+                self.context.i8_type().array_type(0).into(),
+            ],
+            false,
+        )
+    }
+
+    /// Returns the type of a garbage collected string that should be used for variables.
+    pub fn get_string_reference_type(&self) -> BasicTypeEnum<'ink> {
+        // GC strings are pointers to pointers, just like GC arrays
+        // { usize, [0 x i8] }**
+        self.get_string_type()
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic)
+            .into()
+    }
+
+    /// Returns the type of the memory of a closure. A closure stores a pointer to its function,
+    /// followed by a handle to the garbage collected struct that contains its captured variables:
+    ///
+    /// ```text
+    /// { i8*, i8** }
+    /// ```
+    ///
+    /// The handle is null if the closure does not capture any variables.
+    pub fn get_closure_type(&self) -> StructType<'ink> {
+        let i8_ptr_ty = self.context.i8_type().ptr_type(AddressSpace::Generic);
+        self.context.struct_type(
+            &[
+                i8_ptr_ty.into(),
+                i8_ptr_ty.ptr_type(AddressSpace::Generic).into(),
+            ],
+            false,
+        )
+    }
+
+    /// Returns the type of a closure that should be used for variables.
+    pub fn get_closure_reference_type(&self) -> BasicTypeEnum<'ink> {
+        // Closures are always garbage collected
+        // { i8*, i8** }**
+        self.get_closure_type()
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic)
+            .into()
+    }
+
+    /// Returns the type of a tuple with elements of the types `element_tys`. Tuples are anonymous
+    /// structs that are passed as values.
+    pub fn get_tuple_type(&self, element_tys: &Substs) -> StructType<'ink> {
+        let field_types: Vec<_> = element_tys
+            .iter()
+            .map(|ty| {
+                self.get_basic_type(ty)
+                    .expect("could not convert tuple element to basic type")
+            })
+            .collect();
+        self.context.struct_type(&field_types, false)
+    }
+
+    /// Returns the type of the tuple that should be used in the public API. Just like value
+    /// structs, tuples are converted to garbage collected types in the public API.
+    pub fn get_public_tuple_reference_type(&self, element_tys: &Substs) -> BasicTypeEnum<'ink> {
+        self.get_tuple_type(element_tys)
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic)
+            .into()
+    }
+
+    /// Returns the type of the struct that stores the variables captured by a closure, of the
+    /// types `captures`.
+    pub fn get_closure_env_type(&self, captures: &[hir::Ty]) -> StructType<'ink> {
+        let field_types: Vec<_> = captures
+            .iter()
+            .map(|ty| {
+                self.get_basic_type(ty)
+                    .expect("could not convert captured variable to basic type")
+            })
+            .collect();
+        self.context.struct_type(&field_types, false)
+    }
+
+    /// Returns the type of the function of a closure with the signature `sig`. Its first parameter
+    /// is the handle to the captured variables of the closure.
+    pub fn get_closure_function_type(&self, sig: &hir::FnSig) -> FunctionType<'ink> {
+        let env_ty = self
+            .context
+            .i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .ptr_type(AddressSpace::Generic);
+        let param_tys: Vec<_> = std::iter::once(env_ty.into())
+            .chain(sig.params().iter().map(|p| {
+                self.get_basic_type(p)
+                    .expect("could not convert function argument to basic type")
+            }))
+            .collect();
+
+        match sig.ret() {
+            Ty::Empty => self.context.void_type().fn_type(&param_tys, false),
+            ty => self
+                .get_basic_type(&ty)
+                .expect("could not convert return value")
+                .fn_type(&param_tys, false),
+        }
+    }
+
+    /// Returns the type of the specified function instance
+    pub fn get_function_type(&self, instance: &FunctionInstance) -> FunctionType<'ink> {
+        let ty = instance.callable_sig(self.db);
         let param_tys: Vec<_> = ty
             .params()
             .iter()
@@ -149,8 +395,8 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
 
     /// Returns the type of a specified function definition that is callable from the outside of the
     /// Mun code. This function should be C ABI compatible.
-    pub fn get_public_function_type(&self, ty: hir::Function) -> FunctionType<'ink> {
-        let ty = self.db.callable_sig(ty.into());
+    pub fn get_public_function_type(&self, instance: &FunctionInstance) -> FunctionType<'ink> {
+        let ty = instance.callable_sig(self.db);
         let param_tys: Vec<_> = ty
             .params()
             .iter()
@@ -176,10 +422,24 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             Ty::Empty => Some(self.get_empty_type().into()),
             ty_app!(hir::TypeCtor::Float(float_ty)) => Some(self.get_float_type(*float_ty).into()),
             ty_app!(hir::TypeCtor::Int(int_ty)) => Some(self.get_int_type(*int_ty).into()),
-            ty_app!(hir::TypeCtor::Struct(struct_ty)) => {
-                Some(self.get_struct_reference_type(*struct_ty))
+            ty_app!(hir::TypeCtor::Struct(struct_ty), parameters) => {
+                Some(self.get_struct_reference_type(*struct_ty, parameters))
             }
+            ty_app!(hir::TypeCtor::Enum(enum_ty)) => Some(self.get_enum_type(*enum_ty).into()),
             ty_app!(hir::TypeCtor::Bool) => Some(self.get_bool_type().into()),
+            ty_app!(hir::TypeCtor::String) => Some(self.get_string_reference_type()),
+            ty_app!(hir::TypeCtor::FixedArray(len), parameters) => {
+                Some(self.get_fixed_array_type(&parameters[0], *len).into())
+            }
+            ty_app!(hir::TypeCtor::Array, parameters) => {
+                Some(self.get_array_reference_type(&parameters[0]))
+            }
+            ty_app!(hir::TypeCtor::FnPtr { .. }) => Some(self.get_closure_reference_type()),
+            ty_app!(hir::TypeCtor::Tuple { .. }, parameters) => {
+                Some(self.get_tuple_type(parameters).into())
+            }
+            // A `nil` reference is a null pointer
+            ty_app!(hir::TypeCtor::Nullable, parameters) => self.get_basic_type(&parameters[0]),
             _ => None,
         }
     }
@@ -192,10 +452,27 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             Ty::Empty => Some(self.get_empty_type().into()),
             ty_app!(hir::TypeCtor::Float(float_ty)) => Some(self.get_float_type(*float_ty).into()),
             ty_app!(hir::TypeCtor::Int(int_ty)) => Some(self.get_int_type(*int_ty).into()),
-            ty_app!(hir::TypeCtor::Struct(struct_ty)) => {
-                Some(self.get_public_struct_reference_type(*struct_ty))
+            ty_app!(hir::TypeCtor::Struct(struct_ty), parameters) => {
+                Some(self.get_public_struct_reference_type(*struct_ty, parameters))
+            }
+            ty_app!(hir::TypeCtor::Enum(enum_ty)) => {
+                Some(self.get_public_enum_reference_type(*enum_ty))
             }
             ty_app!(hir::TypeCtor::Bool) => Some(self.get_bool_type().into()),
+            ty_app!(hir::TypeCtor::String) => Some(self.get_string_reference_type()),
+            ty_app!(hir::TypeCtor::FixedArray(len), parameters) => {
+                Some(self.get_fixed_array_type(&parameters[0], *len).into())
+            }
+            ty_app!(hir::TypeCtor::Array, parameters) => {
+                Some(self.get_array_reference_type(&parameters[0]))
+            }
+            ty_app!(hir::TypeCtor::FnPtr { .. }) => Some(self.get_closure_reference_type()),
+            ty_app!(hir::TypeCtor::Tuple { .. }, parameters) => {
+                Some(self.get_public_tuple_reference_type(parameters))
+            }
+            ty_app!(hir::TypeCtor::Nullable, parameters) => {
+                self.get_public_basic_type(&parameters[0])
+            }
             _ => None,
         }
     }
@@ -207,13 +484,33 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             Ty::Empty => Some(self.get_empty_type().into()),
             ty_app!(hir::TypeCtor::Float(float_ty)) => Some(self.get_float_type(*float_ty).into()),
             ty_app!(hir::TypeCtor::Int(int_ty)) => Some(self.get_int_type(*int_ty).into()),
-            ty_app!(hir::TypeCtor::Struct(struct_ty)) => {
-                Some(self.get_struct_type(*struct_ty).into())
+            ty_app!(hir::TypeCtor::Struct(struct_ty), parameters) => {
+                Some(self.get_struct_type(*struct_ty, parameters).into())
             }
+            ty_app!(hir::TypeCtor::Enum(enum_ty)) => Some(self.get_enum_type(*enum_ty).into()),
             ty_app!(hir::TypeCtor::Bool) => Some(self.context.bool_type().into()),
-            ty_app!(hir::TypeCtor::FnDef(hir::CallableDef::Function(fn_ty))) => {
-                Some(self.get_function_type(*fn_ty).into())
+            ty_app!(hir::TypeCtor::String) => Some(self.get_string_type().into()),
+            ty_app!(hir::TypeCtor::FixedArray(len), parameters) => {
+                Some(self.get_fixed_array_type(&parameters[0], *len).into())
+            }
+            ty_app!(hir::TypeCtor::Array, parameters) => {
+                Some(self.get_array_type(&parameters[0]).into())
             }
+            ty_app!(hir::TypeCtor::FnPtr { .. }) => Some(self.get_closure_type().into()),
+            ty_app!(hir::TypeCtor::Tuple { .. }, parameters) => {
+                Some(self.get_tuple_type(parameters).into())
+            }
+            ty_app!(hir::TypeCtor::Nullable, parameters) => self.get_any_type(&parameters[0]),
+            ty_app!(
+                hir::TypeCtor::FnDef(hir::CallableDef::Function(fn_ty)),
+                parameters
+            ) => Some(
+                self.get_function_type(&FunctionInstance {
+                    function: *fn_ty,
+                    substs: parameters.clone(),
+                })
+                .into(),
+            ),
             _ => None,
         }
     }
@@ -248,14 +545,69 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
                     let type_size = TypeSize::from_ir_type(&ir_ty, &self.target_data);
                     TypeInfo::new_fundamental("core::bool", type_size)
                 }
+                TypeCtor::String => {
+                    let ir_ty = self.get_string_type();
+                    let type_size = TypeSize::from_ir_type(&ir_ty, &self.target_data);
+                    TypeInfo::new_string(type_size)
+                }
                 TypeCtor::Struct(s) => {
-                    let ir_ty = self.get_struct_type(s);
+                    let ir_ty = self.get_struct_type(s, &ctor.parameters);
+                    let type_size = TypeSize::from_ir_type(&ir_ty, &self.target_data);
+                    TypeInfo::new_struct(self.db, ty.clone(), type_size)
+                }
+                TypeCtor::Enum(e) => {
+                    let ir_ty = self.get_enum_type(e);
                     let type_size = TypeSize::from_ir_type(&ir_ty, &self.target_data);
-                    TypeInfo::new_struct(self.db, s, type_size)
+                    TypeInfo::new_enum(self.db, e, type_size)
+                }
+                TypeCtor::FixedArray(len) => {
+                    let ir_ty = self.get_fixed_array_type(&ctor.parameters[0], len);
+                    let type_size = TypeSize::from_ir_type(&ir_ty, &self.target_data);
+                    TypeInfo::new_array(self.db, ty.clone(), type_size)
+                }
+                TypeCtor::Array => {
+                    let ir_ty = self.get_array_type(&ctor.parameters[0]);
+                    let type_size = TypeSize::from_ir_type(&ir_ty, &self.target_data);
+                    TypeInfo::new_array(self.db, ty.clone(), type_size)
+                }
+                TypeCtor::FnPtr { .. } => {
+                    let ir_ty = self.get_closure_type();
+                    let type_size = TypeSize::from_ir_type(&ir_ty, &self.target_data);
+                    TypeInfo::new_function(self.db, ty.clone(), type_size)
+                }
+                TypeCtor::Tuple { .. } => {
+                    let ir_ty = self.get_tuple_type(&ctor.parameters);
+                    let type_size = TypeSize::from_ir_type(&ir_ty, &self.target_data);
+                    let element_names: Vec<_> = ctor
+                        .parameters
+                        .iter()
+                        .map(|ty| self.type_info(ty).name)
+                        .collect();
+                    TypeInfo::new_tuple(
+                        self.db,
+                        format!("({})", element_names.join(", ")),
+                        ty.clone(),
+                        type_size,
+                    )
+                }
+                TypeCtor::Nullable => {
+                    let ir_ty = self
+                        .get_basic_type(ty)
+                        .expect("expected a nullable reference type");
+                    let type_size = TypeSize::from_ir_type(&ir_ty, &self.target_data);
+                    TypeInfo::new_nullable(self.db, ty.clone(), type_size)
                 }
                 _ => unreachable!("{:?} unhandled", ctor),
             },
             _ => unreachable!("{:?} unhandled", ty),
         }
     }
+
+    /// Returns a `TypeInfo` for the environment of a closure that captures variables of the types
+    /// `captures`.
+    pub fn closure_env_type_info(&self, captures: Vec<Ty>) -> TypeInfo {
+        let ir_ty = self.get_closure_env_type(&captures);
+        let type_size = TypeSize::from_ir_type(&ir_ty, &self.target_data);
+        TypeInfo::new_closure_env(self.db, captures, type_size)
+    }
 }
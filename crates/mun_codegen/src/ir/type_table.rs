@@ -1,14 +1,15 @@
 use super::types as ir;
 use crate::{
     ir::dispatch_table::{DispatchTable, FunctionPrototype},
+    ir::instance::FunctionInstance,
     ir::ty::HirTypeCache,
     type_info::{TypeGroup, TypeInfo},
     value::{AsValue, CanInternalize, Global, IrValueContext, IterAsIrValue, Value},
 };
-use hir::{Body, ExprId, HirDatabase, InferenceResult};
+use hir::{Body, Expr, ExprId, HirDatabase, HirDisplay, InferenceResult, Literal, Pat, PatId};
 use inkwell::{
     context::Context, module::Linkage, module::Module, targets::TargetData, types::ArrayType,
-    values::PointerValue,
+    types::StructType, values::PointerValue,
 };
 use std::{
     collections::{BTreeSet, HashMap},
@@ -143,10 +144,53 @@ impl<'db, 'ink, 't> TypeTableBuilder<'db, 'ink, 't> {
 
     /// Collects unique `TypeInfo` from the given `Ty`.
     fn collect_type(&mut self, type_info: TypeInfo) {
-        if let TypeGroup::StructTypes(hir_struct) = type_info.group {
-            self.collect_struct(hir_struct);
-        } else {
-            self.entries.insert(type_info);
+        match type_info.group {
+            TypeGroup::StructTypes(ref ty) => self.collect_struct_ty(ty),
+            TypeGroup::ArrayTypes(ref ty) => {
+                let (element_ty, _) = ty.as_array().expect("expected an array type");
+                let element_type_info = self.hir_types.type_info(element_ty);
+                self.entries.insert(type_info);
+                self.collect_type(element_type_info);
+            }
+            TypeGroup::EnumTypes(hir_enum) => self.collect_enum(hir_enum),
+            TypeGroup::FunctionTypes(ref ty) => {
+                let sig = ty.callable_sig(self.db).expect("expected a function type");
+                if !self.entries.insert(type_info) {
+                    return;
+                }
+                for ty in sig.params().iter().chain(Some(sig.ret())) {
+                    if !ty.is_empty() {
+                        self.collect_type(self.hir_types.type_info(ty));
+                    }
+                }
+            }
+            TypeGroup::ClosureEnvTypes(ref captures) => {
+                let captures = captures.clone();
+                if !self.entries.insert(type_info) {
+                    return;
+                }
+                for ty in captures.iter() {
+                    self.collect_type(self.hir_types.type_info(ty));
+                }
+            }
+            TypeGroup::TupleTypes(ref ty) => {
+                let element_tys = ty.as_tuple().expect("expected a tuple type").clone();
+                if !self.entries.insert(type_info) {
+                    return;
+                }
+                for ty in element_tys.iter() {
+                    self.collect_type(self.hir_types.type_info(ty));
+                }
+            }
+            TypeGroup::NullableTypes(ref ty) => {
+                let inner_ty = ty.as_nullable().expect("expected a nullable type");
+                let inner_type_info = self.hir_types.type_info(inner_ty);
+                self.entries.insert(type_info);
+                self.collect_type(inner_type_info);
+            }
+            TypeGroup::FundamentalTypes | TypeGroup::StringTypes => {
+                self.entries.insert(type_info);
+            }
         }
     }
 
@@ -154,44 +198,146 @@ impl<'db, 'ink, 't> TypeTableBuilder<'db, 'ink, 't> {
     fn collect_expr(&mut self, expr_id: ExprId, body: &Arc<Body>, infer: &InferenceResult) {
         let expr = &body[expr_id];
 
+        // Garbage collected arrays are allocated using their `TypeInfo`
+        if let Expr::Array(_) = expr {
+            if let Some((_, None)) = infer[expr_id].as_array() {
+                self.collect_type(self.hir_types.type_info(&infer[expr_id]));
+            }
+        }
+
+        // Strings are allocated using their `TypeInfo`
+        if let Expr::Literal(Literal::String(_)) = expr {
+            self.collect_type(self.hir_types.type_info(&infer[expr_id]));
+        }
+
+        // Closures and functions that are used as values are allocated using the `TypeInfo` of
+        // their function type
+        if let hir::ty_app!(hir::TypeCtor::FnPtr { .. }) = &infer[expr_id] {
+            if let Expr::Lambda { .. } | Expr::Path(_) = expr {
+                self.collect_type(self.hir_types.type_info(&infer[expr_id]));
+            }
+        }
+
+        // Instantiations of generic structs are not declared in the module, so they are collected
+        // from the expressions that use them
+        if let hir::ty_app!(hir::TypeCtor::Struct(_), parameters) = &infer[expr_id] {
+            if !parameters.is_empty() {
+                self.collect_type(self.hir_types.type_info(&infer[expr_id]));
+            }
+        }
+
+        // Literal patterns are stored as expressions of the patterns of `match` arms and `if let`
+        // or `while let` conditions
+        if let Expr::Match { arms, .. } = expr {
+            for arm in arms.iter() {
+                self.collect_pat(arm.pat, body, infer);
+            }
+        }
+        if let Expr::Let { pat, .. } = expr {
+            self.collect_pat(*pat, body, infer);
+        }
+
         // TODO: Collect used external `TypeInfo` for the type dispatch table
 
         // Recurse further
         expr.walk_child_exprs(|expr_id| self.collect_expr(expr_id, body, infer))
     }
 
-    /// Collects unique `TypeInfo` from the specified function signature and body.
-    pub fn collect_fn(&mut self, hir_fn: hir::Function) {
-        // Collect type info for exposed function
-        if !hir_fn.data(self.db).visibility().is_private() || self.dispatch_table.contains(hir_fn) {
-            let fn_sig = hir_fn.ty(self.db).callable_sig(self.db).unwrap();
+    /// Collects unique `TypeInfo` from the literals of the specified pattern and its sub-patterns.
+    fn collect_pat(&mut self, pat_id: PatId, body: &Arc<Body>, infer: &InferenceResult) {
+        match &body[pat_id] {
+            Pat::Lit(expr_id) => self.collect_expr(*expr_id, body, infer),
+            pat => pat.walk_child_pats(|pat_id| self.collect_pat(pat_id, body, infer)),
+        }
+    }
 
-            // Collect argument types
-            for ty in fn_sig.params().iter() {
-                self.collect_type(self.hir_types.type_info(ty));
-            }
+    /// Collects unique `TypeInfo` from the specified function signature and body.
+    pub fn collect_fn(&mut self, instance: &FunctionInstance) {
+        let hir_fn = instance.function;
 
-            // Collect return type
-            let ret_ty = fn_sig.ret();
-            if !ret_ty.is_empty() {
-                self.collect_type(self.hir_types.type_info(ret_ty));
-            }
+        // Collect type info for exposed function
+        if !hir_fn.data(self.db).visibility().is_private() || self.dispatch_table.contains(instance)
+        {
+            self.collect_fn_sig(instance);
         }
 
         // Collect used types from body
         let body = hir_fn.body(self.db);
-        let infer = hir_fn.infer(self.db);
+        let infer = instance.infer(self.db);
         self.collect_expr(body.body_expr(), &body, &infer);
+
+        // The variables captured by a closure are allocated using the `TypeInfo` of its
+        // environment
+        let scopes = self.db.expr_scopes(hir_fn.into());
+        for (expr_id, expr) in body.exprs() {
+            if let Expr::Lambda { .. } = expr {
+                let captures = scopes.captures(expr_id);
+                if !captures.is_empty() {
+                    let capture_types = captures.iter().map(|pat| infer[*pat].clone()).collect();
+                    self.collect_type(self.hir_types.closure_env_type_info(capture_types));
+                }
+            }
+        }
+    }
+
+    /// Collects unique `TypeInfo` from the signature of the specified function, e.g. of a function
+    /// of another module that is called through the dispatch table.
+    pub fn collect_fn_sig(&mut self, instance: &FunctionInstance) {
+        let fn_sig = instance.callable_sig(self.db);
+
+        // Collect argument types
+        for ty in fn_sig.params().iter() {
+            self.collect_type(self.hir_types.type_info(ty));
+        }
+
+        // Collect return type
+        let ret_ty = fn_sig.ret();
+        if !ret_ty.is_empty() {
+            self.collect_type(self.hir_types.type_info(ret_ty));
+        }
+    }
+
+    /// Collects unique `TypeInfo` from the type of a reflected constant or static.
+    pub fn collect_global(&mut self, ty: &hir::Ty) {
+        self.collect_type(self.hir_types.type_info(ty));
     }
 
     /// Collects unique `TypeInfo` from the specified struct type.
     pub fn collect_struct(&mut self, hir_struct: hir::Struct) {
-        let type_info = self.hir_types.type_info(&hir_struct.ty(self.db));
-        self.entries.insert(type_info);
+        // Generic structs are collected for each of their instantiations instead
+        if hir_struct.is_generic(self.db.upcast()) {
+            return;
+        }
 
-        let fields = hir_struct.fields(self.db);
-        for field in fields.into_iter() {
-            self.collect_type(self.hir_types.type_info(&field.ty(self.db)));
+        self.collect_struct_ty(&hir_struct.ty(self.db));
+    }
+
+    /// Collects unique `TypeInfo` from the specified (instantiated) struct type.
+    fn collect_struct_ty(&mut self, ty: &hir::Ty) {
+        let type_info = self.hir_types.type_info(ty);
+        if !self.entries.insert(type_info) {
+            return;
+        }
+
+        let hir_struct = ty.as_struct().expect("expected a struct type");
+        let substs = ty.substs().expect("expected a struct type");
+        for field in hir_struct.fields(self.db).into_iter() {
+            let field_ty = field.ty(self.db).subst(&substs);
+            self.collect_type(self.hir_types.type_info(&field_ty));
+        }
+    }
+
+    /// Collects unique `TypeInfo` from the specified enum type.
+    pub fn collect_enum(&mut self, hir_enum: hir::Enum) {
+        let type_info = self.hir_types.type_info(&hir_enum.ty(self.db));
+        if !self.entries.insert(type_info) {
+            return;
+        }
+
+        for variant in hir_enum.variants(self.db) {
+            for ty in variant.field_types(self.db) {
+                self.collect_type(self.hir_types.type_info(&ty));
+            }
         }
     }
 
@@ -232,13 +378,100 @@ impl<'db, 'ink, 't> TypeTableBuilder<'db, 'ink, 't> {
         // Build the global value for the ir::TypeInfo
         let type_ir_name = type_info_global_name(type_info);
         let value = match type_info.group {
-            TypeGroup::FundamentalTypes => type_info_ir
+            TypeGroup::FundamentalTypes | TypeGroup::StringTypes => type_info_ir
                 .into_const_private_global(&type_ir_name, self.value_context)
                 .as_value(self.value_context),
-            TypeGroup::StructTypes(s) => {
+            TypeGroup::StructTypes(ref ty) => {
                 // In case of a struct the `Global<ir::TypeInfo>` is actually a
                 // `Global<(ir::TypeInfo, ir::StructInfo)>`.
-                let struct_info_ir = self.gen_struct_info(type_info_to_ir, s);
+                let struct_info_ir = self.gen_struct_info(type_info_to_ir, ty);
+                let compound_type_ir = (type_info_ir, struct_info_ir).as_value(self.value_context);
+                let compound_global =
+                    compound_type_ir.into_const_private_global(&type_ir_name, self.value_context);
+                Value::<*const ir::TypeInfo>::with_cast(
+                    compound_global.value.as_pointer_value(),
+                    self.value_context,
+                )
+            }
+            TypeGroup::ArrayTypes(ref ty) => {
+                // In case of an array the `Global<ir::TypeInfo>` is actually a
+                // `Global<(ir::TypeInfo, ir::ArrayInfo)>`.
+                let array_info_ir = self.gen_array_info(type_info_to_ir, ty);
+                let compound_type_ir = (type_info_ir, array_info_ir).as_value(self.value_context);
+                let compound_global =
+                    compound_type_ir.into_const_private_global(&type_ir_name, self.value_context);
+                Value::<*const ir::TypeInfo>::with_cast(
+                    compound_global.value.as_pointer_value(),
+                    self.value_context,
+                )
+            }
+            TypeGroup::EnumTypes(e) => {
+                // In case of an enum the `Global<ir::TypeInfo>` is actually a
+                // `Global<(ir::TypeInfo, ir::EnumInfo)>`.
+                let enum_info_ir = self.gen_enum_info(type_info_to_ir, e);
+                let compound_type_ir = (type_info_ir, enum_info_ir).as_value(self.value_context);
+                let compound_global =
+                    compound_type_ir.into_const_private_global(&type_ir_name, self.value_context);
+                Value::<*const ir::TypeInfo>::with_cast(
+                    compound_global.value.as_pointer_value(),
+                    self.value_context,
+                )
+            }
+            TypeGroup::FunctionTypes(ref ty) => {
+                // In case of a function type the `Global<ir::TypeInfo>` is actually a
+                // `Global<(ir::TypeInfo, ir::FunctionSignature)>`.
+                let signature_ir = self.gen_function_signature(type_info_to_ir, ty);
+                let compound_type_ir = (type_info_ir, signature_ir).as_value(self.value_context);
+                let compound_global =
+                    compound_type_ir.into_const_private_global(&type_ir_name, self.value_context);
+                Value::<*const ir::TypeInfo>::with_cast(
+                    compound_global.value.as_pointer_value(),
+                    self.value_context,
+                )
+            }
+            TypeGroup::ClosureEnvTypes(ref captures) => {
+                // The environment of a closure is a garbage collected struct, so the
+                // `Global<ir::TypeInfo>` is actually a `Global<(ir::TypeInfo, ir::StructInfo)>`.
+                let struct_info_ir = self.gen_anonymous_struct_info(
+                    type_info_to_ir,
+                    &type_info.name,
+                    self.hir_types.get_closure_env_type(captures),
+                    captures,
+                    abi::StructMemoryKind::GC,
+                );
+                let compound_type_ir = (type_info_ir, struct_info_ir).as_value(self.value_context);
+                let compound_global =
+                    compound_type_ir.into_const_private_global(&type_ir_name, self.value_context);
+                Value::<*const ir::TypeInfo>::with_cast(
+                    compound_global.value.as_pointer_value(),
+                    self.value_context,
+                )
+            }
+            TypeGroup::NullableTypes(ref ty) => {
+                // In case of a nullable type the `Global<ir::TypeInfo>` is actually a
+                // `Global<(ir::TypeInfo, *const ir::TypeInfo)>` that refers to the struct type.
+                let inner_ty = ty.as_nullable().expect("expected a nullable type");
+                let inner_type_info = self.hir_types.type_info(inner_ty);
+                let inner_type_ir = self.gen_type_info(type_info_to_ir, &inner_type_info);
+                let compound_type_ir = (type_info_ir, inner_type_ir).as_value(self.value_context);
+                let compound_global =
+                    compound_type_ir.into_const_private_global(&type_ir_name, self.value_context);
+                Value::<*const ir::TypeInfo>::with_cast(
+                    compound_global.value.as_pointer_value(),
+                    self.value_context,
+                )
+            }
+            TypeGroup::TupleTypes(ref ty) => {
+                // A tuple is a value struct, so the `Global<ir::TypeInfo>` is actually a
+                // `Global<(ir::TypeInfo, ir::StructInfo)>`.
+                let element_tys = ty.as_tuple().expect("expected a tuple type");
+                let struct_info_ir = self.gen_anonymous_struct_info(
+                    type_info_to_ir,
+                    &type_info.name,
+                    self.hir_types.get_tuple_type(element_tys),
+                    element_tys,
+                    abi::StructMemoryKind::Value,
+                );
                 let compound_type_ir = (type_info_ir, struct_info_ir).as_value(self.value_context);
                 let compound_global =
                     compound_type_ir.into_const_private_global(&type_ir_name, self.value_context);
@@ -258,10 +491,12 @@ impl<'db, 'ink, 't> TypeTableBuilder<'db, 'ink, 't> {
     fn gen_struct_info(
         &self,
         type_info_to_ir: &mut HashMap<TypeInfo, Value<'ink, *const ir::TypeInfo<'ink>>>,
-        hir_struct: hir::Struct,
+        ty: &hir::Ty,
     ) -> Value<'ink, ir::StructInfo<'ink>> {
-        let struct_ir = self.hir_types.get_struct_type(hir_struct);
-        let name = hir_struct.name(self.db.upcast()).to_string();
+        let hir_struct = ty.as_struct().expect("expected a struct type");
+        let substs = ty.substs().expect("expected a struct type");
+        let struct_ir = self.hir_types.get_struct_type(hir_struct, &substs);
+        let name = ty.display(self.db).to_string();
         let fields = hir_struct.fields(self.db);
 
         // Construct an array of field names (or null if there are no fields)
@@ -286,7 +521,7 @@ impl<'db, 'ink, 't> TypeTableBuilder<'db, 'ink, 't> {
         let field_types = fields
             .iter()
             .map(|field| {
-                let field_type_info = self.hir_types.type_info(&field.ty(self.db));
+                let field_type_info = self.hir_types.type_info(&field.ty(self.db).subst(&substs));
                 self.gen_type_info(type_info_to_ir, &field_type_info)
             })
             .into_const_private_pointer_or_null(
@@ -321,6 +556,244 @@ impl<'db, 'ink, 't> TypeTableBuilder<'db, 'ink, 't> {
         .as_value(self.value_context)
     }
 
+    /// Generates the `StructInfo` of a struct without a declaration, i.e. the environment of a
+    /// closure or a tuple. Its fields are named after their index.
+    fn gen_anonymous_struct_info(
+        &self,
+        type_info_to_ir: &mut HashMap<TypeInfo, Value<'ink, *const ir::TypeInfo<'ink>>>,
+        name: &str,
+        struct_ir: StructType<'ink>,
+        field_tys: &[hir::Ty],
+        memory_kind: abi::StructMemoryKind,
+    ) -> Value<'ink, ir::StructInfo<'ink>> {
+        let field_names = (0..field_tys.len())
+            .map(|idx| {
+                CString::new(idx.to_string())
+                    .expect("field name is not a valid CString")
+                    .intern(
+                        format!("struct_info::<{}>::field_names.{}", name, idx),
+                        self.value_context,
+                    )
+                    .as_value(self.value_context)
+            })
+            .into_const_private_pointer_or_null(
+                format!("struct_info::<{}>::field_names", name),
+                self.value_context,
+            );
+
+        let field_types = field_tys
+            .iter()
+            .map(|ty| {
+                let field_type_info = self.hir_types.type_info(ty);
+                self.gen_type_info(type_info_to_ir, &field_type_info)
+            })
+            .into_const_private_pointer_or_null(
+                format!("struct_info::<{}>::field_types", name),
+                self.value_context,
+            );
+
+        let field_offsets = (0..field_tys.len())
+            .map(|idx| {
+                self.target_data
+                    .offset_of_element(&struct_ir, idx as u32)
+                    .unwrap() as u16
+            })
+            .into_const_private_pointer_or_null(
+                format!("struct_info::<{}>::field_offsets", name),
+                self.value_context,
+            );
+
+        ir::StructInfo {
+            field_names,
+            field_types,
+            field_offsets,
+            num_fields: field_tys
+                .len()
+                .try_into()
+                .expect("could not convert num_fields to smaller bit size"),
+            memory_kind,
+        }
+        .as_value(self.value_context)
+    }
+
+    fn gen_function_signature(
+        &self,
+        type_info_to_ir: &mut HashMap<TypeInfo, Value<'ink, *const ir::TypeInfo<'ink>>>,
+        ty: &hir::Ty,
+    ) -> Value<'ink, ir::FunctionSignature<'ink>> {
+        let sig = ty.callable_sig(self.db).expect("expected a function type");
+        let name = ty.display(self.db).to_string();
+
+        // Construct an array of argument types (or null if there are no arguments)
+        let arg_types = sig
+            .params()
+            .iter()
+            .map(|ty| {
+                let arg_type_info = self.hir_types.type_info(ty);
+                self.gen_type_info(type_info_to_ir, &arg_type_info)
+            })
+            .into_const_private_pointer_or_null(
+                format!("fn_sig::<{}>::arg_types", name),
+                self.value_context,
+            );
+
+        // The return type is null if the function does not return a value
+        let return_type = if sig.ret().is_empty() {
+            Value::null(self.value_context)
+        } else {
+            let ret_type_info = self.hir_types.type_info(sig.ret());
+            self.gen_type_info(type_info_to_ir, &ret_type_info)
+        };
+
+        ir::FunctionSignature {
+            arg_types,
+            return_type,
+            num_arg_types: sig
+                .params()
+                .len()
+                .try_into()
+                .expect("could not convert num_arg_types to smaller bit size"),
+        }
+        .as_value(self.value_context)
+    }
+
+    fn gen_array_info(
+        &self,
+        type_info_to_ir: &mut HashMap<TypeInfo, Value<'ink, *const ir::TypeInfo<'ink>>>,
+        ty: &hir::Ty,
+    ) -> Value<'ink, ir::ArrayInfo<'ink>> {
+        let (element_ty, length) = ty.as_array().expect("expected an array type");
+        let element_type_info = self.hir_types.type_info(element_ty);
+
+        ir::ArrayInfo {
+            element_type: self.gen_type_info(type_info_to_ir, &element_type_info),
+            length: length.unwrap_or(0),
+            memory_kind: if length.is_some() {
+                abi::StructMemoryKind::Value
+            } else {
+                abi::StructMemoryKind::GC
+            },
+        }
+        .as_value(self.value_context)
+    }
+
+    fn gen_enum_info(
+        &self,
+        type_info_to_ir: &mut HashMap<TypeInfo, Value<'ink, *const ir::TypeInfo<'ink>>>,
+        hir_enum: hir::Enum,
+    ) -> Value<'ink, ir::EnumInfo<'ink>> {
+        let enum_ir = self.hir_types.get_enum_type(hir_enum);
+        let name = hir_enum.name(self.db.upcast()).to_string();
+        let variants = hir_enum.variants(self.db);
+
+        // The fields of all variants are stored in the payload of the enum
+        let payload_offset = self.target_data.offset_of_element(&enum_ir, 1).unwrap();
+
+        // Construct an array of variant names (or null if there are no variants)
+        let variant_names = variants
+            .iter()
+            .enumerate()
+            .map(|(idx, variant)| {
+                CString::new(variant.name(self.db).to_string())
+                    .expect("variant name is not a valid CString")
+                    .intern(
+                        format!("enum_info::<{}>::variant_names.{}", name, idx),
+                        self.value_context,
+                    )
+                    .as_value(self.value_context)
+            })
+            .into_const_private_pointer_or_null(
+                format!("enum_info::<{}>::variant_names", name),
+                self.value_context,
+            );
+
+        // Construct an array that describes the fields of each variant (or null if there are no
+        // variants)
+        let variant_types = variants
+            .iter()
+            .enumerate()
+            .map(|(idx, variant)| {
+                let variant_ir = self.hir_types.get_enum_variant_type(*variant);
+                let field_types = variant.field_types(self.db);
+                let variant_name = format!("{}::{}", name, variant.name(self.db));
+
+                let field_names = (0..field_types.len())
+                    .map(|field_idx| {
+                        CString::new(field_idx.to_string())
+                            .expect("field name is not a valid CString")
+                            .intern(
+                                format!(
+                                    "enum_info::<{}>::variant_types.{}.field_names.{}",
+                                    name, idx, field_idx
+                                ),
+                                self.value_context,
+                            )
+                            .as_value(self.value_context)
+                    })
+                    .into_const_private_pointer_or_null(
+                        format!("struct_info::<{}>::field_names", variant_name),
+                        self.value_context,
+                    );
+
+                let field_type_ptrs = field_types
+                    .iter()
+                    .map(|ty| {
+                        let field_type_info = self.hir_types.type_info(ty);
+                        self.gen_type_info(type_info_to_ir, &field_type_info)
+                    })
+                    .into_const_private_pointer_or_null(
+                        format!("struct_info::<{}>::field_types", variant_name),
+                        self.value_context,
+                    );
+
+                let field_offsets = (0..field_types.len())
+                    .map(|field_idx| {
+                        (payload_offset
+                            + self
+                                .target_data
+                                .offset_of_element(&variant_ir, field_idx as u32)
+                                .unwrap()) as u16
+                    })
+                    .into_const_private_pointer_or_null(
+                        format!("struct_info::<{}>::field_offsets", variant_name),
+                        self.value_context,
+                    );
+
+                ir::StructInfo {
+                    field_names,
+                    field_types: field_type_ptrs,
+                    field_offsets,
+                    num_fields: field_types
+                        .len()
+                        .try_into()
+                        .expect("could not convert num_fields to smaller bit size"),
+                    memory_kind: abi::StructMemoryKind::Value,
+                }
+                .as_value(self.value_context)
+            })
+            .into_const_private_pointer_or_null(
+                format!("enum_info::<{}>::variant_types", name),
+                self.value_context,
+            );
+
+        ir::EnumInfo {
+            variant_names,
+            variant_types,
+            num_variants: variants
+                .len()
+                .try_into()
+                .expect("could not convert num_variants to smaller bit size"),
+            discriminant_size: (self
+                .hir_types
+                .get_enum_discriminant_type(hir_enum)
+                .get_bit_width()
+                / 8)
+            .try_into()
+            .expect("could not convert discriminant size to smaller bit size"),
+        }
+        .as_value(self.value_context)
+    }
+
     /// Constructs a `TypeTable` from all *used* types.
     pub fn build(mut self) -> TypeTable<'ink> {
         let mut entries = BTreeSet::new();
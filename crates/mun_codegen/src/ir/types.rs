@@ -33,6 +33,14 @@ impl<'ink> TransparentValue<'ink> for abi::StructMemoryKind {
     }
 }
 
+impl<'ink> TransparentValue<'ink> for bool {
+    type Target = u8;
+
+    fn as_target_value(&self, context: &IrValueContext<'ink, '_, '_>) -> Value<'ink, Self::Target> {
+        (*self as u8).as_value(context)
+    }
+}
+
 #[derive(AsValue)]
 pub struct TypeInfo<'ink> {
     pub guid: abi::Guid,
@@ -70,13 +78,38 @@ pub struct StructInfo<'ink> {
     pub memory_kind: abi::StructMemoryKind,
 }
 
+#[derive(AsValue)]
+pub struct ArrayInfo<'ink> {
+    pub element_type: Value<'ink, *const TypeInfo<'ink>>,
+    pub length: u64,
+    pub memory_kind: abi::StructMemoryKind,
+}
+
+#[derive(AsValue)]
+pub struct EnumInfo<'ink> {
+    pub variant_names: Value<'ink, *const *const u8>,
+    pub variant_types: Value<'ink, *const StructInfo<'ink>>,
+    pub num_variants: u16,
+    pub discriminant_size: u8,
+}
+
+#[derive(AsValue)]
+pub struct GlobalDefinition<'ink> {
+    pub name: Value<'ink, *const u8>,
+    pub type_info: Value<'ink, *const TypeInfo<'ink>>,
+    pub ptr: Value<'ink, *mut u8>,
+    pub is_mutable: bool,
+}
+
 #[derive(AsValue)]
 pub struct ModuleInfo<'ink> {
     pub path: Value<'ink, *const u8>,
     pub functions: Value<'ink, *const FunctionDefinition<'ink>>,
     pub types: Value<'ink, *const *const TypeInfo<'ink>>,
+    pub globals: Value<'ink, *const GlobalDefinition<'ink>>,
     pub num_functions: u32,
     pub num_types: u32,
+    pub num_globals: u32,
 }
 
 #[derive(AsValue)]
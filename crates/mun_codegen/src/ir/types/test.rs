@@ -145,6 +145,19 @@ fn test_struct_info_abi_compatible() {
     super::StructInfo::test(&abi_type);
 }
 
+#[test]
+#[cfg(test)]
+fn test_global_definition_abi_compatible() {
+    let abi_type = abi::GlobalDefinition {
+        name: std::ptr::null(),
+        type_info: std::ptr::null(),
+        ptr: std::ptr::null_mut(),
+        is_mutable: false,
+    };
+
+    super::GlobalDefinition::test(&abi_type);
+}
+
 #[test]
 #[cfg(test)]
 fn test_module_info_abi_compatible() {
@@ -154,6 +167,8 @@ fn test_module_info_abi_compatible() {
         num_functions: 0,
         types: std::ptr::null(),
         num_types: 0,
+        globals: std::ptr::null(),
+        num_globals: 0,
     };
 
     super::ModuleInfo::test(&abi_type);
@@ -181,6 +196,8 @@ fn test_assembly_info_abi_compatible() {
             num_functions: 0,
             types: std::ptr::null(),
             num_types: 0,
+            globals: std::ptr::null(),
+            num_globals: 0,
         },
         dispatch_table: abi::DispatchTable {
             prototypes: std::ptr::null(),
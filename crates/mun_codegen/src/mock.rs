@@ -78,6 +78,7 @@ impl MockDatabase {
 
         db.set_source_root(source_root_id, Arc::new(source_root));
         db.set_optimization_level(OptimizationLevel::None);
+        db.set_arithmetic_checks(false);
 
         (db, file_id)
     }
@@ -6,7 +6,9 @@ use crate::{
     CodeGenDatabase,
 };
 use hir::{
-    diagnostics::DiagnosticSink, line_index::LineIndex, HirDatabase, Module, SourceDatabase, Upcast,
+    diagnostics::{DiagnosticSink, Severity},
+    line_index::LineIndex,
+    HirDatabase, Module, SourceDatabase, Upcast,
 };
 use inkwell::{context::Context, OptimizationLevel};
 use mun_target::spec::Target;
@@ -172,7 +174,7 @@ fn function_arguments() {
 fn assignment_op_bool() {
     test_snapshot(
         r#"
-    pub fn assign(a: bool, b: bool) -> bool {
+    pub fn assign(mut a: bool, b: bool) -> bool {
         a = b;
         a
     }
@@ -207,12 +209,12 @@ fn assignment_op_struct() {
     struct(value) Value(i32, i32);
     struct(gc) Heap(f64, f64);
 
-    pub fn assign_value(a: Value, b: Value) -> Value {
+    pub fn assign_value(mut a: Value, b: Value) -> Value {
         a = b;
         a
     }
 
-    pub fn assign_heap(a: Heap, b: Heap) -> Heap {
+    pub fn assign_heap(mut a: Heap, b: Heap) -> Heap {
         a = b;
         a
     }
@@ -235,27 +237,27 @@ macro_rules! test_number_operator_types {
                 #[test]
                 fn [<assignment_op_ $ty>]() {
                     test_snapshot(&format!(r#"
-    pub fn assign(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign(mut a: {ty}, b: {ty}) -> {ty} {{
         a = b;
         a
     }}
-    pub fn assign_add(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign_add(mut a: {ty}, b: {ty}) -> {ty} {{
         a += b;
         a
     }}
-    pub fn assign_subtract(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign_subtract(mut a: {ty}, b: {ty}) -> {ty} {{
         a -= b;
         a
     }}
-    pub fn assign_multiply(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign_multiply(mut a: {ty}, b: {ty}) -> {ty} {{
         a *= b;
         a
     }}
-    pub fn assign_divide(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign_divide(mut a: {ty}, b: {ty}) -> {ty} {{
         a /= b;
         a
     }}
-    pub fn assign_remainder(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign_remainder(mut a: {ty}, b: {ty}) -> {ty} {{
         a %= b;
         a
     }}
@@ -335,15 +337,15 @@ macro_rules! test_bit_operator_types  {
                 #[test]
                 fn [<assign_bit_op_ $ty>]() {
                     test_snapshot(&format!(r#"
-    pub fn assign_bitand(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign_bitand(mut a: {ty}, b: {ty}) -> {ty} {{
         a &= b;
         a
     }}
-    pub fn assign_bitor(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign_bitor(mut a: {ty}, b: {ty}) -> {ty} {{
         a |= b;
         a
     }}
-    pub fn assign_bitxor(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign_bitxor(mut a: {ty}, b: {ty}) -> {ty} {{
         a ^= b;
         a
     }}
@@ -377,11 +379,11 @@ macro_rules! test_shift_operator_types  {
                 #[test]
                 fn [<assign_shift_op_ $ty>]() {
                     test_snapshot(&format!(r#"
-    pub fn assign_leftshift(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign_leftshift(mut a: {ty}, b: {ty}) -> {ty} {{
         a <<= b;
         a
     }}
-    pub fn assign_rightshift(a: {ty}, b: {ty}) -> {ty} {{
+    pub fn assign_rightshift(mut a: {ty}, b: {ty}) -> {ty} {{
         a >>= b;
         a
     }}
@@ -433,31 +435,31 @@ fn update_operators() {
     test_snapshot(
         r#"
     pub fn add(a:i32, b:i32) -> i32 {
-      let result = a
+      let mut result = a
       result += b
       result
     }
 
     pub fn subtract(a:i32, b:i32) -> i32 {
-      let result = a
+      let mut result = a
       result -= b
       result
     }
 
     pub fn multiply(a:i32, b:i32) -> i32 {
-      let result = a
+      let mut result = a
       result *= b
       result
     }
 
     pub fn divide(a:i32, b:i32) -> i32 {
-      let result = a
+      let mut result = a
       result /= b
       result
     }
 
     pub fn remainder(a:i32, b:i32) -> i32 {
-      let result = a
+      let mut result = a
       result %= b
       result
     }
@@ -469,7 +471,7 @@ fn update_operators() {
 fn update_parameter() {
     test_snapshot(
         r#"
-    pub fn add_three(a:i32) -> i32 {
+    pub fn add_three(mut a:i32) -> i32 {
       a += 3;
       a
     }
@@ -553,9 +555,9 @@ fn fibonacci_loop() {
     test_snapshot(
         r#"
     pub fn fibonacci(n:i32) -> i32 {
-        let a = 0;
-        let b = 1;
-        let i = 1;
+        let mut a = 0;
+        let mut b = 1;
+        let mut i = 1;
         loop {
             if i > n {
                 return a
@@ -664,7 +666,7 @@ fn loop_expr() {
 fn loop_break_expr() {
     test_snapshot(
         r#"
-    pub fn foo(n:i32) -> i32 {
+    pub fn foo(mut n:i32) -> i32 {
         loop {
             if n > 5 {
                 break n;
@@ -683,7 +685,7 @@ fn loop_break_expr() {
 fn while_expr() {
     test_snapshot(
         r#"
-    pub fn foo(n:i32) {
+    pub fn foo(mut n:i32) {
         while n<3 {
             n += 1;
         };
@@ -697,6 +699,66 @@ fn while_expr() {
     )
 }
 
+#[test]
+fn logic_op_short_circuit() {
+    test_snapshot(
+        r#"
+    extern fn check(a: i32) -> bool;
+
+    pub fn and(a: i32) -> bool {
+        a > 0 && check(a)
+    }
+
+    pub fn or(a: i32) -> bool {
+        a > 0 || check(a)
+    }
+    "#,
+    )
+}
+
+#[test]
+fn match_expr() {
+    test_snapshot(
+        r#"
+    pub fn classify(value: i32) -> i32 {
+        match value {
+            0 => 10,
+            1 => 20,
+            _ => 30,
+        }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn if_let_expr() {
+    test_snapshot(
+        r#"
+    pub fn first_if(a: i32, b: bool) -> i32 {
+        if let (x, true) = (a, b) {
+            x
+        } else {
+            0
+        }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn control_flow_in_call_args() {
+    test_snapshot(
+        r#"
+    extern fn consume(a: i32, b: i32) -> i32;
+
+    pub fn foo(a: i32, b: bool) -> i32 {
+        consume(if b { a } else { 0 }, loop { break a + 1; })
+    }
+    "#,
+    )
+}
+
 #[test]
 fn struct_test() {
     test_snapshot_unoptimized(
@@ -963,6 +1025,9 @@ fn test_snapshot_with_optimization(text: &str, opt: OptimizationLevel) {
     let line_index: Arc<LineIndex> = db.line_index(file_id);
     let messages = RefCell::new(Vec::new());
     let mut sink = DiagnosticSink::new(|diag| {
+        if diag.severity() != Severity::Error {
+            return;
+        }
         let line_col = line_index.line_col(diag.highlight_range().start());
         messages.borrow_mut().push(format!(
             "error {}:{}: {}",
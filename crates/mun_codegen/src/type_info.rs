@@ -1,6 +1,6 @@
 use super::ir::IsIrType;
 use abi::Guid;
-use hir::HirDatabase;
+use hir::{HirDatabase, HirDisplay};
 use inkwell::context::Context;
 use inkwell::targets::TargetData;
 use inkwell::types::AnyType;
@@ -9,14 +9,33 @@ use std::hash::{Hash, Hasher};
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TypeGroup {
     FundamentalTypes,
-    StructTypes(hir::Struct),
+    StructTypes(hir::Ty),
+    ArrayTypes(hir::Ty),
+    EnumTypes(hir::Enum),
+    StringTypes,
+    FunctionTypes(hir::Ty),
+    /// The garbage collected struct that stores the variables captured by a closure, described by
+    /// the types of the captured variables
+    ClosureEnvTypes(Vec<hir::Ty>),
+    /// An anonymous tuple, e.g. `(i32, f32)`. Tuples are described as value structs with fields
+    /// named after their index.
+    TupleTypes(hir::Ty),
+    /// A reference to a `gc` struct that may be `nil`, e.g. `?Foo`
+    NullableTypes(hir::Ty),
 }
 
 impl From<TypeGroup> for u64 {
     fn from(group: TypeGroup) -> Self {
         match group {
             TypeGroup::FundamentalTypes => 0,
-            TypeGroup::StructTypes(_) => 1,
+            TypeGroup::StructTypes(_)
+            | TypeGroup::ClosureEnvTypes(_)
+            | TypeGroup::TupleTypes(_) => 1,
+            TypeGroup::ArrayTypes(_) => 2,
+            TypeGroup::EnumTypes(_) => 3,
+            TypeGroup::StringTypes => 4,
+            TypeGroup::FunctionTypes(_) => 5,
+            TypeGroup::NullableTypes(_) => 6,
         }
     }
 }
@@ -25,7 +44,14 @@ impl TypeGroup {
     pub fn to_abi_type(&self) -> abi::TypeGroup {
         match self {
             TypeGroup::FundamentalTypes => abi::TypeGroup::FundamentalTypes,
-            TypeGroup::StructTypes(_) => abi::TypeGroup::StructTypes,
+            TypeGroup::StructTypes(_)
+            | TypeGroup::ClosureEnvTypes(_)
+            | TypeGroup::TupleTypes(_) => abi::TypeGroup::StructTypes,
+            TypeGroup::ArrayTypes(_) => abi::TypeGroup::ArrayTypes,
+            TypeGroup::EnumTypes(_) => abi::TypeGroup::EnumTypes,
+            TypeGroup::StringTypes => abi::TypeGroup::StringTypes,
+            TypeGroup::FunctionTypes(_) => abi::TypeGroup::FunctionTypes,
+            TypeGroup::NullableTypes(_) => abi::TypeGroup::NullableTypes,
         }
     }
 }
@@ -98,8 +124,18 @@ impl TypeInfo {
         }
     }
 
-    pub fn new_struct(db: &dyn HirDatabase, s: hir::Struct, type_size: TypeSize) -> TypeInfo {
-        let name = s.name(db.upcast()).to_string();
+    /// Constructs the `TypeInfo` of a struct type. Every instantiation of a generic struct has its
+    /// own `TypeInfo`, e.g. `Pair<i32>` and `Pair<f64>`. The name of a struct includes the path of
+    /// its module, e.g. `physics::Body`.
+    pub fn new_struct(db: &dyn HirDatabase, ty: hir::Ty, type_size: TypeSize) -> TypeInfo {
+        let s = ty.as_struct().expect("expected a struct type");
+        let substs = ty.substs().expect("expected a struct type");
+        let name = if substs.is_empty() {
+            s.full_name(db)
+        } else {
+            let args: Vec<String> = substs.iter().map(|ty| ty.display(db).to_string()).collect();
+            format!("{}<{}>", s.full_name(db), args.join(", "))
+        };
         let guid_string = {
             let fields: Vec<String> = s
                 .fields(db)
@@ -107,6 +143,7 @@ impl TypeInfo {
                 .map(|f| {
                     let ty_string = f
                         .ty(db)
+                        .subst(&substs)
                         .guid_string(db)
                         .expect("type should be convertible to a string");
                     format!("{}: {}", f.name(db).to_string(), ty_string)
@@ -122,7 +159,112 @@ impl TypeInfo {
         Self {
             guid: Guid(md5::compute(&guid_string).0),
             name,
-            group: TypeGroup::StructTypes(s),
+            group: TypeGroup::StructTypes(ty),
+            size: type_size,
+        }
+    }
+
+    pub fn new_enum(db: &dyn HirDatabase, e: hir::Enum, type_size: TypeSize) -> TypeInfo {
+        let guid_string = e
+            .ty(db)
+            .guid_string(db)
+            .expect("enum type should be convertible to a string");
+        Self {
+            guid: Guid(md5::compute(&guid_string).0),
+            name: e.full_name(db),
+            group: TypeGroup::EnumTypes(e),
+            size: type_size,
+        }
+    }
+
+    pub fn new_array(db: &dyn HirDatabase, ty: hir::Ty, type_size: TypeSize) -> TypeInfo {
+        let name = ty
+            .guid_string(db)
+            .expect("array type should be convertible to a string");
+        Self {
+            guid: Guid(md5::compute(&name).0),
+            name,
+            group: TypeGroup::ArrayTypes(ty),
+            size: type_size,
+        }
+    }
+
+    /// Constructs the `TypeInfo` of a nullable type, e.g. `?Foo`. Its values are references to a
+    /// `gc` struct that may be null.
+    pub fn new_nullable(db: &dyn HirDatabase, ty: hir::Ty, type_size: TypeSize) -> TypeInfo {
+        let name = ty
+            .guid_string(db)
+            .expect("nullable type should be convertible to a string");
+        Self {
+            guid: Guid(md5::compute(&name).0),
+            name,
+            group: TypeGroup::NullableTypes(ty),
+            size: type_size,
+        }
+    }
+
+    pub fn new_string(type_size: TypeSize) -> TypeInfo {
+        let name = "core::string";
+        Self {
+            guid: Guid(md5::compute(name).0),
+            name: name.to_owned(),
+            group: TypeGroup::StringTypes,
+            size: type_size,
+        }
+    }
+
+    /// Constructs the `TypeInfo` of a function type, e.g. `fn(i32) -> i32`. Values of a function
+    /// type are closures.
+    pub fn new_function(db: &dyn HirDatabase, ty: hir::Ty, type_size: TypeSize) -> TypeInfo {
+        let name = ty
+            .guid_string(db)
+            .expect("function type should be convertible to a string");
+        Self {
+            guid: Guid(md5::compute(&name).0),
+            name,
+            group: TypeGroup::FunctionTypes(ty),
+            size: type_size,
+        }
+    }
+
+    /// Constructs the `TypeInfo` of the environment of a closure that captures variables of the
+    /// types `captures`. Closures that capture the same types share the type of their environment.
+    pub fn new_closure_env(
+        db: &dyn HirDatabase,
+        captures: Vec<hir::Ty>,
+        type_size: TypeSize,
+    ) -> TypeInfo {
+        let fields: Vec<String> = captures
+            .iter()
+            .map(|ty| {
+                ty.guid_string(db)
+                    .expect("type should be convertible to a string")
+            })
+            .collect();
+        let name = format!("closure_env<{}>", fields.join(","));
+        Self {
+            guid: Guid(md5::compute(&name).0),
+            name,
+            group: TypeGroup::ClosureEnvTypes(captures),
+            size: type_size,
+        }
+    }
+
+    /// Constructs the `TypeInfo` of a tuple type. The `name` of a tuple is composed of the names of
+    /// its elements, e.g. `(core::i32, core::f32)`.
+    pub fn new_tuple(
+        db: &dyn HirDatabase,
+        name: String,
+        ty: hir::Ty,
+        type_size: TypeSize,
+    ) -> TypeInfo {
+        let guid_string = ty
+            .guid_string(db)
+            .expect("tuple type should be convertible to a string");
+        Self {
+            guid: Guid(md5::compute(&guid_string).0),
+            name,
+            group: TypeGroup::TupleTypes(ty),
             size: type_size,
         }
     }
@@ -11,6 +11,7 @@ fn test_abi_compatibility() {
     let fn_name2 = "bar";
     let struct_name = "Foo";
     let struct_name2 = "Bar";
+    let enum_name = "Baz";
     let driver = CompileTestDriver::new(&format!(
         r#"
     pub fn {fn_name}(_: f64) -> i32 {{ 0 }}
@@ -18,11 +19,13 @@ fn test_abi_compatibility() {
 
     pub struct {struct_name}(f64, f64);
     pub struct(value) {struct_name2} {{ a: i32, b: i32 }};
+    pub enum {enum_name} {{ A, B(i64, i32) }}
     "#,
         fn_name = fn_name,
         fn_name2 = fn_name2,
         struct_name = struct_name,
         struct_name2 = struct_name2,
+        enum_name = enum_name,
     ));
 
     // Assert that all library functions are exposed
@@ -70,6 +73,28 @@ fn test_abi_compatibility() {
         StructMemoryKind::Value,
     );
 
+    let enum_type_info = module_info
+        .types()
+        .iter()
+        .find(|ty| ty.name() == enum_name)
+        .expect(&format!("Failed to retrieve enum '{}'", enum_name));
+    assert_eq!(enum_type_info.group, TypeGroup::EnumTypes);
+    assert_eq!(enum_type_info.size_in_bytes(), 24);
+    assert_eq!(enum_type_info.alignment(), 8);
+
+    let enum_info = enum_type_info.as_enum().expect("Expected an enum");
+    assert_eq!(enum_info.num_variants(), 2);
+    assert_eq!(enum_info.discriminant_size(), 1);
+    for (lhs, rhs) in enum_info.variant_names().zip(&["A", "B"]) {
+        assert_eq!(lhs, *rhs);
+    }
+    assert_eq!(enum_info.variant_types()[0].num_fields(), 0);
+    assert_eq!(enum_info.variant_types()[1].field_offsets(), &[8, 16]);
+    assert_eq!(
+        enum_info.variant_types()[1].field_types()[0].guid,
+        i64::type_guid()
+    );
+
     fn get_function_info<'m>(
         module_info: &'m abi::ModuleInfo,
         fn_name: &str,
@@ -62,6 +62,7 @@ impl CompilerDatabase {
     pub fn set_config(&mut self, config: &Config) {
         self.set_target(config.target.clone());
         self.set_optimization_level(config.optimization_lvl);
+        self.set_arithmetic_checks(config.arithmetic_checks);
     }
 }
 
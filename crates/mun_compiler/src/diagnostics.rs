@@ -94,4 +94,37 @@ mod tests {
     fn test_cyclic_type_alias_error() {
         insta::assert_display_snapshot!(compilation_errors("\n\ntype Foo = Foo;"));
     }
+
+    #[test]
+    fn test_unused_variable_warning() {
+        insta::assert_display_snapshot!(compilation_errors("\n\npub fn main() {\nlet a = 5;\n}"));
+    }
+
+    #[test]
+    fn test_denied_lint_error() {
+        insta::assert_display_snapshot!(compilation_errors(
+            "\n\n#[deny(unused_variables)]\npub fn main() {\nlet a = 5;\n#[allow(unused_variables)]\n{\nlet b = 6;\n}\n}"
+        ));
+    }
+
+    #[test]
+    fn test_assign_to_immutable_static_error() {
+        insta::assert_display_snapshot!(compilation_errors(
+            "\n\nstatic LIMIT: i32 = 10;\npub fn main() {\nLIMIT = 5;\n}"
+        ));
+    }
+
+    #[test]
+    fn test_assign_to_immutable_variable_error() {
+        insta::assert_display_snapshot!(compilation_errors(
+            "\n\npub fn main(a: i32) -> i32 {\nlet b = a;\nb += 1;\nb\n}"
+        ));
+    }
+
+    #[test]
+    fn test_missing_return_value_error() {
+        insta::assert_display_snapshot!(compilation_errors(
+            "\n\npub fn main(a: i32) -> i32 {\nif a > 0 { return 1; }\n}"
+        ));
+    }
 }
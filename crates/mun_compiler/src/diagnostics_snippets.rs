@@ -56,22 +56,33 @@ pub(crate) fn emit_syntax_error(
     write!(writer, "{}", dl)
 }
 
-/// Emits all diagnostics that are a result of HIR validation.
+/// Emits all diagnostics that are a result of HIR validation. The `annotation_type` determines
+/// whether the diagnostic is displayed as an error or a warning.
 pub(crate) fn emit_hir_diagnostic(
     diagnostic: &dyn mun_hir::Diagnostic,
+    annotation_type: AnnotationType,
     db: &impl HirDatabase,
     file_id: FileId,
     display_colors: bool,
     writer: &mut dyn std::io::Write,
 ) -> std::io::Result<()> {
     diagnostic.with_diagnostic(db, |diagnostic| {
-        emit_diagnostic(diagnostic, db, file_id, display_colors, writer)
+        emit_diagnostic(
+            diagnostic,
+            annotation_type,
+            db,
+            file_id,
+            display_colors,
+            writer,
+        )
     })
 }
 
-/// Emits a diagnostic by writting a snippet to the specified `writer`.
+/// Emits a diagnostic by writting a snippet to the specified `writer`. The `annotation_type`
+/// determines whether the diagnostic is displayed as an error or a warning.
 fn emit_diagnostic(
     diagnostic: &dyn mun_diagnostics::Diagnostic,
+    annotation_type: AnnotationType,
     db: &impl HirDatabase,
     file_id: FileId,
     display_colors: bool,
@@ -143,7 +154,7 @@ fn emit_diagnostic(
         title: Some(Annotation {
             id: None,
             label: Some(&title),
-            annotation_type: AnnotationType::Error,
+            annotation_type,
         }),
         slices: annotations
             .iter()
@@ -173,7 +184,7 @@ fn emit_diagnostic(
                                 annotation.range.end().to_usize() - line_offset,
                             ),
                             label: annotation.message.as_str(),
-                            annotation_type: AnnotationType::Error,
+                            annotation_type,
                         })
                         .collect(),
                     fold: true,
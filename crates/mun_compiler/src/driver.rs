@@ -7,7 +7,8 @@ use crate::{
 };
 use mun_codegen::{Assembly, CodeGenDatabase};
 use mun_hir::{
-    AstDatabase, DiagnosticSink, FileId, RelativePathBuf, SourceDatabase, SourceRoot, SourceRootId,
+    AstDatabase, DiagnosticSink, FileId, Lint, LintLevel, RelativePathBuf, Severity,
+    SourceDatabase, SourceRoot, SourceRootId,
 };
 
 use std::{path::PathBuf, sync::Arc};
@@ -19,6 +20,7 @@ pub use self::config::Config;
 pub use self::display_color::DisplayColor;
 
 use crate::diagnostics_snippets::{emit_hir_diagnostic, emit_syntax_error};
+use annotate_snippets::snippet::AnnotationType;
 use mun_project::Package;
 use std::collections::HashMap;
 use std::convert::TryInto;
@@ -38,6 +40,8 @@ pub struct Driver {
 
     file_id_to_temp_assembly_path: HashMap<FileId, PathBuf>,
 
+    lint_levels: HashMap<Lint, LintLevel>,
+
     display_color: DisplayColor,
 }
 
@@ -52,6 +56,7 @@ impl Driver {
             file_id_to_path: Default::default(),
             next_file_id: 0,
             file_id_to_temp_assembly_path: Default::default(),
+            lint_levels: Default::default(),
             display_color: config.display_color,
         })
     }
@@ -113,6 +118,11 @@ impl Driver {
         // Construct the driver
         let mut driver = Driver::with_config(config, output_dir)?;
 
+        // Use the lint levels that are configured in the manifest
+        driver
+            .lint_levels
+            .extend(mun_hir::manifest_lint_levels(package.manifest())?);
+
         // Iterate over all files in the source directory of the package and store their information in
         // the database
         let source_directory = package
@@ -192,8 +202,28 @@ impl Driver {
 }
 
 impl Driver {
+    /// Sets the level at which the specified lint is reported, unless an attribute in the source
+    /// specifies otherwise. By default all lints are reported as warnings.
+    pub fn set_lint_level(&mut self, lint: Lint, level: LintLevel) {
+        self.lint_levels.insert(lint, level);
+    }
+
+    /// Returns the level at which the specified warning is reported. Attributes in the source take
+    /// precedence over the levels that are configured for the driver.
+    fn lint_level(&self, diagnostic: &dyn mun_hir::Diagnostic) -> LintLevel {
+        diagnostic
+            .lint_level(&self.db)
+            .or_else(|| {
+                diagnostic
+                    .lint()
+                    .and_then(|lint| self.lint_levels.get(&lint).copied())
+            })
+            .unwrap_or(LintLevel::Warn)
+    }
+
     /// Emits all diagnostic messages currently in the database; returns true if errors were
-    /// emitted.
+    /// emitted. Denied lints are emitted as errors. Warnings are emitted together with the errors
+    /// of a file, in the order in which they are found.
     pub fn emit_diagnostics(&self, writer: &mut dyn std::io::Write) -> Result<bool, anyhow::Error> {
         // Iterate over all files in the workspace
         let emit_colors = self.display_color.should_enable();
@@ -222,8 +252,26 @@ impl Driver {
             mun_hir::Module::from(file_id).diagnostics(
                 &self.db,
                 &mut DiagnosticSink::new(|d| {
-                    has_error = true;
-                    if let Err(e) = emit_hir_diagnostic(d, &self.db, file_id, emit_colors, writer) {
+                    let annotation_type = match d.severity() {
+                        Severity::Error => AnnotationType::Error,
+                        Severity::Warning => match self.lint_level(d) {
+                            LintLevel::Allow => return,
+                            LintLevel::Warn => AnnotationType::Warning,
+                            LintLevel::Deny => AnnotationType::Error,
+                        },
+                    };
+                    if let AnnotationType::Error = annotation_type {
+                        has_error = true;
+                    }
+                    let result = emit_hir_diagnostic(
+                        d,
+                        annotation_type,
+                        &self.db,
+                        file_id,
+                        emit_colors,
+                        writer,
+                    );
+                    if let Err(e) = result {
                         error = Some(e)
                     };
                 }),
@@ -282,7 +330,11 @@ impl Driver {
             return Ok(false);
         }
 
-        // It did change or we are forced, so write it to disk
+        // It did change or we are forced, so write it to disk. The assemblies of a package are
+        // stored in the same directory structure as its source files.
+        if let Some(parent) = assembly_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
         assembly.copy_to(&assembly_path)?;
 
         // Store the information so we maybe don't have to write it next time
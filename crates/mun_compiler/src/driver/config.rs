@@ -12,6 +12,10 @@ pub struct Config {
     /// The optimization level to use for the IR generation.
     pub optimization_lvl: OptimizationLevel,
 
+    /// Whether integer arithmetic traps on overflow. Overflowing arithmetic wraps around if this is
+    /// disabled. Divisions by zero and oversized shifts always trap.
+    pub arithmetic_checks: bool,
+
     /// The optional output directory to store all outputs. If no directory is specified all output
     /// is stored in a temporary directory.
     pub out_dir: Option<PathBuf>,
@@ -28,6 +32,7 @@ impl Default for Config {
             // triple.
             target: target.unwrap(),
             optimization_lvl: OptimizationLevel::Default,
+            arithmetic_checks: false,
             out_dir: None,
             display_color: DisplayColor::Auto,
         }
@@ -1,9 +1,11 @@
 ///! This module provides conversion from a `mun_hir::Diagnostics` to a `crate::Diagnostics`.
 mod access_unknown_field;
+mod cannot_assign_to_immutable;
 mod duplicate_definition_error;
 mod expected_function;
 mod mismatched_type;
 mod missing_fields;
+mod missing_return_value;
 mod possibly_unitialized_variable;
 mod unresolved_type;
 mod unresolved_value;
@@ -36,6 +38,13 @@ impl<DB: mun_hir::HirDatabase> DiagnosticForWith<DB> for dyn mun_hir::Diagnostic
             ))
         } else if let Some(v) = self.downcast_ref::<mun_hir::diagnostics::MissingFields>() {
             f(&missing_fields::MissingFields::new(with, v))
+        } else if let Some(v) = self.downcast_ref::<mun_hir::diagnostics::CannotAssignToImmutable>()
+        {
+            f(&cannot_assign_to_immutable::CannotAssignToImmutable::new(
+                with, v,
+            ))
+        } else if let Some(v) = self.downcast_ref::<mun_hir::diagnostics::MissingReturnValue>() {
+            f(&missing_return_value::MissingReturnValue::new(with, v))
         } else {
             f(&GenericHirDiagnostic { diagnostic: self })
         }
@@ -0,0 +1,98 @@
+use super::HirDiagnostic;
+use crate::{Diagnostic, Fix, SecondaryAnnotation, SourceAnnotation};
+use mun_hir::diagnostics::ImmutablePlace;
+use mun_hir::{InFile, Name};
+use mun_syntax::{ast::NameOwner, AstNode, TextRange};
+
+/// An error that is emitted when assigning to a place that cannot be mutated.
+///
+/// ```mun
+/// static LIMIT: i32 = 10;
+///
+/// # fn main() {
+///     LIMIT = 5;  // cannot assign to immutable static `LIMIT`
+///     let a = 1;
+///     a = 2;      // cannot assign to immutable variable `a`
+/// # }
+/// ```
+pub struct CannotAssignToImmutable<'db, 'diag, DB: mun_hir::HirDatabase> {
+    _db: &'db DB,
+    diag: &'diag mun_hir::diagnostics::CannotAssignToImmutable,
+    /// The location at which `mut` can be inserted to make the place mutable, if any
+    mut_location: Option<InFile<TextRange>>,
+}
+
+impl<'db, 'diag, DB: mun_hir::HirDatabase> Diagnostic for CannotAssignToImmutable<'db, 'diag, DB> {
+    fn range(&self) -> TextRange {
+        self.diag.highlight_range()
+    }
+
+    fn title(&self) -> String {
+        self.diag.message()
+    }
+
+    fn primary_annotation(&self) -> Option<SourceAnnotation> {
+        Some(SourceAnnotation {
+            range: self.diag.highlight_range(),
+            message: "cannot assign".to_string(),
+        })
+    }
+
+    fn secondary_annotations(&self) -> Vec<SecondaryAnnotation> {
+        match (self.name(), self.mut_location) {
+            (Some(name), Some(location)) => vec![SecondaryAnnotation {
+                range: location,
+                message: format!("help: consider changing this to be mutable: `mut {}`", name),
+            }],
+            _ => Vec::new(),
+        }
+    }
+
+    fn fix(&self) -> Option<Fix> {
+        match (self.name(), self.mut_location) {
+            (Some(name), Some(location)) => Some(Fix {
+                label: format!("Make `{}` mutable", name),
+                range: location.map(|range| TextRange::offset_len(range.start(), 0.into())),
+                replacement: "mut ".to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl<'db, 'diag, DB: mun_hir::HirDatabase> CannotAssignToImmutable<'db, 'diag, DB> {
+    /// Constructs a new instance of `CannotAssignToImmutable`
+    pub fn new(db: &'db DB, diag: &'diag mun_hir::diagnostics::CannotAssignToImmutable) -> Self {
+        let mut_location = match &diag.place {
+            ImmutablePlace::Static { definition, .. } => {
+                let parse = db.parse(definition.file_id);
+                definition
+                    .value
+                    .to_node(&parse.syntax_node())
+                    .name()
+                    .map(|name| InFile::new(definition.file_id, name.syntax().text_range()))
+            }
+            ImmutablePlace::Binding {
+                declaration: Some(declaration),
+                ..
+            } => Some(declaration.as_ref().map(|ptr| ptr.range())),
+            _ => None,
+        };
+
+        CannotAssignToImmutable {
+            _db: db,
+            diag,
+            mut_location,
+        }
+    }
+
+    /// Returns the name of the immutable place, if it can be made mutable
+    fn name(&self) -> Option<&Name> {
+        match &self.diag.place {
+            ImmutablePlace::Static { name, .. } | ImmutablePlace::Binding { name, .. } => {
+                Some(name)
+            }
+            ImmutablePlace::Const { .. } | ImmutablePlace::Temporary => None,
+        }
+    }
+}
@@ -34,6 +34,9 @@ fn syntax_node_signature_range(
                 .map(|s| s.signature_range())
                 .unwrap_or_else(|| syntax_node_ptr.range())
         }
+        SyntaxKind::ENUM_DEF => ast::EnumDef::cast(syntax_node_ptr.to_node(parse.tree().syntax()))
+            .map(|e| e.signature_range())
+            .unwrap_or_else(|| syntax_node_ptr.range()),
         _ => syntax_node_ptr.range(),
     }
 }
@@ -60,7 +63,7 @@ fn syntax_node_identifier_range(
     parse: &Parse<SourceFile>,
 ) -> TextRange {
     match syntax_node_ptr.kind() {
-        SyntaxKind::FUNCTION_DEF | SyntaxKind::STRUCT_DEF => syntax_node_ptr
+        SyntaxKind::FUNCTION_DEF | SyntaxKind::STRUCT_DEF | SyntaxKind::ENUM_DEF => syntax_node_ptr
             .to_node(parse.tree().syntax())
             .children()
             .find(|n| n.kind() == SyntaxKind::NAME)
@@ -138,7 +141,10 @@ impl<'db, 'diag, DB: mun_hir::HirDatabase> Diagnostic for DuplicateDefinition<'d
 impl<'db, 'diag, DB: mun_hir::HirDatabase> DuplicateDefinition<'db, 'diag, DB> {
     /// Returns either `type` or `value` definition on the type of definition.
     fn value_or_type_string(&self) -> &'static str {
-        if self.diag.definition.kind() == SyntaxKind::STRUCT_DEF {
+        if matches!(
+            self.diag.definition.kind(),
+            SyntaxKind::STRUCT_DEF | SyntaxKind::ENUM_DEF
+        ) {
             "type"
         } else {
             "value"
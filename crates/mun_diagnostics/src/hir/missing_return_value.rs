@@ -0,0 +1,53 @@
+use super::HirDiagnostic;
+use crate::{Diagnostic, SourceAnnotation};
+use mun_hir::HirDisplay;
+use mun_syntax::TextRange;
+
+/// An error that is emitted when the end of a function that returns a value can be reached without
+/// returning one.
+///
+/// ```mun
+/// fn sign(a: i32) -> i32 {
+///     if a < 0 {
+///         return -1;
+///     } else if a > 0 {
+///         return 1;
+///     }   // the end of the function is reached if `a` is zero
+/// }
+/// ```
+pub struct MissingReturnValue<'db, 'diag, DB: mun_hir::HirDatabase> {
+    db: &'db DB,
+    diag: &'diag mun_hir::diagnostics::MissingReturnValue,
+}
+
+impl<'db, 'diag, DB: mun_hir::HirDatabase> Diagnostic for MissingReturnValue<'db, 'diag, DB> {
+    fn range(&self) -> TextRange {
+        self.diag.highlight_range()
+    }
+
+    fn title(&self) -> String {
+        format!(
+            "not all paths return a value of type `{}`",
+            self.diag.expected.display(self.db)
+        )
+    }
+
+    fn primary_annotation(&self) -> Option<SourceAnnotation> {
+        let message = if self.diag.missing_else {
+            "`if` without an `else` branch"
+        } else {
+            "ends without returning a value"
+        };
+        Some(SourceAnnotation {
+            range: self.diag.highlight_range(),
+            message: message.to_string(),
+        })
+    }
+}
+
+impl<'db, 'diag, DB: mun_hir::HirDatabase> MissingReturnValue<'db, 'diag, DB> {
+    /// Constructs a new instance of `MissingReturnValue`
+    pub fn new(db: &'db DB, diag: &'diag mun_hir::diagnostics::MissingReturnValue) -> Self {
+        MissingReturnValue { db, diag }
+    }
+}
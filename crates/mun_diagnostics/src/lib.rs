@@ -30,6 +30,19 @@ pub struct SecondaryAnnotation {
     pub message: String,
 }
 
+/// A change to the source code that resolves a diagnostic, e.g. making a static mutable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Fix {
+    /// A description of the change
+    pub label: String,
+
+    /// The range of source code that is replaced. An empty range inserts text.
+    pub range: InFile<TextRange>,
+
+    /// The text that replaces the range
+    pub replacement: String,
+}
+
 /// The base trait for all diagnostics in this crate.
 pub trait Diagnostic {
     /// Returns the primary message of the diagnostic.
@@ -50,6 +63,11 @@ pub trait Diagnostic {
     fn footer(&self) -> Vec<String> {
         Vec::new()
     }
+
+    /// Returns a change to the source code that resolves this diagnostic, if any.
+    fn fix(&self) -> Option<Fix> {
+        None
+    }
 }
 
 /// When implemented enables requesting `Diagnostic`s for the implementer.
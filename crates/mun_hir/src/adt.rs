@@ -3,10 +3,11 @@ use std::{fmt, sync::Arc};
 use crate::type_ref::{LocalTypeRefId, TypeRefBuilder, TypeRefMap, TypeRefSourceMap};
 use crate::{
     arena::{Arena, Idx},
-    ids::{StructId, TypeAliasId},
+    generics::GenericParams,
+    ids::{EnumId, StructId, TypeAliasId},
     AsName, DefDatabase, Name,
 };
-use mun_syntax::ast::{self, NameOwner, TypeAscriptionOwner};
+use mun_syntax::ast::{self, NameOwner, TypeAscriptionOwner, TypeParamsOwner};
 
 pub use mun_syntax::ast::StructMemoryKind;
 
@@ -54,6 +55,7 @@ pub type LocalStructFieldId = Idx<StructFieldData>;
 #[derive(Debug, PartialEq, Eq)]
 pub struct StructData {
     pub name: Name,
+    pub generic_params: GenericParams,
     pub fields: Arena<StructFieldData>,
     pub kind: StructKind,
     pub memory_kind: StructMemoryKind,
@@ -73,6 +75,8 @@ impl StructData {
             .map(|s| s.kind())
             .unwrap_or_default();
 
+        let generic_params = GenericParams::from_ast(src.type_param_list());
+
         let mut type_ref_builder = TypeRefBuilder::default();
         let (fields, kind) = match src.kind() {
             ast::StructKind::Record(r) => {
@@ -102,6 +106,7 @@ impl StructData {
         let (type_ref_map, type_ref_source_map) = type_ref_builder.finish();
         Arc::new(StructData {
             name: strukt.name.clone(),
+            generic_params,
             fields,
             kind,
             memory_kind,
@@ -119,6 +124,101 @@ impl StructData {
     }
 }
 
+/// A single variant of an enum
+/// ```mun
+/// enum Foo {
+///     A,      // <- this
+///     B(i32), // <- or this
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumVariantData {
+    pub name: Name,
+    pub fields: Arena<StructFieldData>,
+    pub kind: StructKind,
+}
+
+/// An identifier for an enum's variant
+pub type LocalEnumVariantId = Idx<EnumVariantData>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct EnumData {
+    pub name: Name,
+    pub variants: Arena<EnumVariantData>,
+    type_ref_map: TypeRefMap,
+    type_ref_source_map: TypeRefSourceMap,
+}
+
+impl EnumData {
+    pub(crate) fn enum_data_query(db: &dyn DefDatabase, id: EnumId) -> Arc<EnumData> {
+        let loc = id.lookup(db);
+        let item_tree = db.item_tree(loc.id.file_id);
+        let enum_def = &item_tree[loc.id.value];
+        let src = item_tree.source(db, loc.id);
+
+        let mut type_ref_builder = TypeRefBuilder::default();
+        let variants = src
+            .enum_variant_list()
+            .into_iter()
+            .flat_map(|list| list.variants())
+            .filter_map(|variant| {
+                let name = variant.name()?.as_name();
+                let (fields, kind) = match variant.kind() {
+                    ast::StructKind::Record(r) => {
+                        let fields = r
+                            .fields()
+                            .map(|fd| StructFieldData {
+                                name: fd.name().map(|n| n.as_name()).unwrap_or_else(Name::missing),
+                                type_ref: type_ref_builder
+                                    .alloc_from_node_opt(fd.ascribed_type().as_ref()),
+                            })
+                            .collect();
+                        (fields, StructKind::Record)
+                    }
+                    ast::StructKind::Tuple(t) => {
+                        let fields = t
+                            .fields()
+                            .enumerate()
+                            .map(|(index, fd)| StructFieldData {
+                                name: Name::new_tuple_field(index),
+                                type_ref: type_ref_builder
+                                    .alloc_from_node_opt(fd.type_ref().as_ref()),
+                            })
+                            .collect();
+                        (fields, StructKind::Tuple)
+                    }
+                    ast::StructKind::Unit => (Arena::default(), StructKind::Unit),
+                };
+                Some(EnumVariantData { name, fields, kind })
+            })
+            .collect();
+
+        let (type_ref_map, type_ref_source_map) = type_ref_builder.finish();
+        Arc::new(EnumData {
+            name: enum_def.name.clone(),
+            variants,
+            type_ref_map,
+            type_ref_source_map,
+        })
+    }
+
+    /// Returns the id of the variant with the specified name
+    pub fn variant(&self, name: &Name) -> Option<LocalEnumVariantId> {
+        self.variants
+            .iter()
+            .find(|(_, data)| data.name == *name)
+            .map(|(id, _)| id)
+    }
+
+    pub fn type_ref_source_map(&self) -> &TypeRefSourceMap {
+        &self.type_ref_source_map
+    }
+
+    pub fn type_ref_map(&self) -> &TypeRefMap {
+        &self.type_ref_map
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct TypeAliasData {
     pub name: Name,
@@ -45,12 +45,14 @@ pub enum BuiltinType {
     Float(BuiltinFloat),
     Int(BuiltinInt),
     Bool,
+    String,
 }
 
 impl BuiltinType {
     #[rustfmt::skip]
     pub const ALL: &'static [(Name, BuiltinType)] = &[
         (name![bool],  BuiltinType::Bool),
+        (name![string], BuiltinType::String),
 
         (name![isize], BuiltinType::Int(BuiltinInt::ISIZE)),
         (name![i8],    BuiltinType::Int(BuiltinInt::I8)),
@@ -75,6 +77,7 @@ impl fmt::Display for BuiltinType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let type_name = match self {
             BuiltinType::Bool => "bool",
+            BuiltinType::String => "string",
             BuiltinType::Int(BuiltinInt {
                 signedness,
                 bitness,
@@ -1,22 +1,35 @@
 pub(crate) mod src;
 
-use crate::adt::{LocalStructFieldId, StructData, TypeAliasData};
+use self::src::HasSource;
+use crate::adt::{
+    EnumData, LocalEnumVariantId, LocalStructFieldId, StructData, StructKind, TypeAliasData,
+};
 use crate::builtin_type::BuiltinType;
 use crate::code_model::diagnostics::ModuleDefinitionDiagnostic;
-use crate::diagnostics::DiagnosticSink;
-use crate::expr::validator::{ExprValidator, TypeAliasValidator};
-use crate::expr::{Body, BodySourceMap};
-use crate::ids::{FunctionLoc, Intern, Lookup, StructLoc, TypeAliasLoc};
+use crate::diagnostics::{
+    ArithmeticOverflow, ConstEvalRecursionLimit, CyclicConstant, DiagnosticSink, DivisionByZero,
+    NonConstantInitializer, SelfParamOutsideImpl,
+};
+use crate::expr::validator::{ExprValidator, ModuleValidator, TypeAliasValidator};
+use crate::expr::{Body, BodySourceMap, ExprId};
+use crate::generics::{GenericDef, GenericParams};
+use crate::ids::{
+    AssocContainerId, ConstLoc, EnumLoc, FunctionLoc, ImplLoc, Intern, Lookup, StaticLoc,
+    StructLoc, TraitLoc, TypeAliasLoc,
+};
 use crate::item_tree::ModItem;
+use crate::line_index::LineCol;
 use crate::name_resolution::Namespace;
 use crate::resolve::{Resolution, Resolver};
 use crate::ty::{lower::LowerBatchResult, InferenceResult};
-use crate::type_ref::{LocalTypeRefId, TypeRefBuilder, TypeRefMap, TypeRefSourceMap};
+use crate::type_ref::{LocalTypeRefId, TypeRef, TypeRefBuilder, TypeRefMap, TypeRefSourceMap};
 use crate::{
-    ids::{FunctionId, StructId, TypeAliasId},
-    DefDatabase, FileId, HirDatabase, InFile, Name, Ty,
+    const_eval::{ConstEvalError, ConstValue},
+    ids::{ConstId, EnumId, FunctionId, ImplId, StaticId, StructId, TraitId, TypeAliasId},
+    DefDatabase, FileId, HirDatabase, HirDisplay, InFile, Name, Substs, Ty,
 };
-use mun_syntax::ast::{TypeAscriptionOwner, VisibilityOwner};
+use mun_syntax::ast::{NameOwner, TypeAscriptionOwner, TypeParamsOwner};
+use mun_syntax::{ast, AstNode, AstPtr, SyntaxNodePtr};
 use rustc_hash::FxHashMap;
 use std::sync::Arc;
 
@@ -36,11 +49,73 @@ impl Module {
         self.file_id
     }
 
+    /// Returns the names of the modules from the root of the package to this module, e.g.
+    /// `[foo, bar]` for the module `foo::bar`. The path of the root module is empty.
+    pub fn path(self, db: &dyn DefDatabase) -> Vec<Name> {
+        let module_tree = db.module_tree(db.file_source_root(self.file_id));
+        module_tree
+            .module_for_file(self.file_id)
+            .map(|module_id| module_tree.path(module_id))
+            .unwrap_or_default()
+    }
+
     /// Returns all the definitions declared in this module.
     pub fn declarations(self, db: &dyn HirDatabase) -> Vec<ModuleDef> {
         db.module_data(self.file_id).definitions.clone()
     }
 
+    /// Returns all the `impl` blocks declared in this module.
+    pub fn impls(self, db: &dyn HirDatabase) -> Vec<Impl> {
+        db.module_data(self.file_id).impls.clone()
+    }
+
+    /// Returns all the functions declared in this module, including the functions declared in
+    /// `impl` blocks and traits.
+    pub fn functions(self, db: &dyn HirDatabase) -> Vec<Function> {
+        let declarations = self.declarations(db);
+        let declared_functions = declarations.iter().filter_map(|def| match def {
+            ModuleDef::Function(f) => Some(*f),
+            _ => None,
+        });
+        let trait_functions = declarations
+            .iter()
+            .filter_map(|def| match def {
+                ModuleDef::Trait(t) => Some(t.items(db)),
+                _ => None,
+            })
+            .flatten();
+        let impl_functions = self
+            .impls(db)
+            .into_iter()
+            .flat_map(|impl_def| impl_def.items(db));
+        declared_functions
+            .chain(trait_functions)
+            .chain(impl_functions)
+            .collect()
+    }
+
+    /// Returns all the constants declared in this module.
+    pub fn consts(self, db: &dyn HirDatabase) -> Vec<Const> {
+        self.declarations(db)
+            .into_iter()
+            .filter_map(|def| match def {
+                ModuleDef::Const(c) => Some(c),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns all the statics declared in this module.
+    pub fn statics(self, db: &dyn HirDatabase) -> Vec<Static> {
+        self.declarations(db)
+            .into_iter()
+            .filter_map(|def| match def {
+                ModuleDef::Static(s) => Some(s),
+                _ => None,
+            })
+            .collect()
+    }
+
     fn resolver(self, _db: &dyn DefDatabase) -> Resolver {
         Resolver::default().push_module_scope(self.file_id)
     }
@@ -53,16 +128,31 @@ impl Module {
             match decl {
                 ModuleDef::Function(f) => f.diagnostics(db, sink),
                 ModuleDef::Struct(s) => s.diagnostics(db, sink),
+                ModuleDef::Enum(e) => e.diagnostics(db, sink),
                 ModuleDef::TypeAlias(t) => t.diagnostics(db, sink),
-                ModuleDef::BuiltinType(_) => (),
+                ModuleDef::Const(c) => c.diagnostics(db, sink),
+                ModuleDef::Static(s) => s.diagnostics(db, sink),
+                ModuleDef::Trait(t) => t.diagnostics(db, sink),
+                ModuleDef::BuiltinType(_) | ModuleDef::EnumVariant(_) => (),
             }
         }
+        for impl_def in self.impls(db) {
+            impl_def.diagnostics(db, sink);
+        }
+        db.inherent_impls(self.file_id)
+            .add_diagnostics(db, self.file_id, sink);
+        db.trait_impls(self.file_id)
+            .add_diagnostics(db, self.file_id, sink);
+        db.module_imports(self.file_id)
+            .add_diagnostics(db, self.file_id, sink);
+        ModuleValidator::new(self, db).validate_module(sink);
     }
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
 pub struct ModuleData {
     definitions: Vec<ModuleDef>,
+    impls: Vec<Impl>,
     diagnostics: Vec<ModuleDefinitionDiagnostic>,
 }
 
@@ -80,7 +170,21 @@ impl ModuleData {
             let name = match item {
                 ModItem::Function(item) => items[*item].name.clone(),
                 ModItem::Struct(item) => items[*item].name.clone(),
+                ModItem::Enum(item) => items[*item].name.clone(),
                 ModItem::TypeAlias(item) => items[*item].name.clone(),
+                ModItem::Const(item) => items[*item].name.clone(),
+                ModItem::Static(item) => items[*item].name.clone(),
+                ModItem::Trait(item) => items[*item].name.clone(),
+                ModItem::Import(_) => continue,
+                ModItem::Impl(item) => {
+                    data.impls.push(Impl {
+                        id: ImplLoc {
+                            id: InFile::new(file_id, *item),
+                        }
+                        .intern(db),
+                    });
+                    continue;
+                }
             };
 
             if let Some(prev_definition) = definition_by_name.get(&name) {
@@ -97,6 +201,7 @@ impl ModuleData {
             match item {
                 ModItem::Function(item) => data.definitions.push(ModuleDef::Function(Function {
                     id: FunctionLoc {
+                        container: AssocContainerId::ModuleId(file_id),
                         id: InFile::new(file_id, *item),
                     }
                     .intern(db),
@@ -107,6 +212,12 @@ impl ModuleData {
                     }
                     .intern(db),
                 })),
+                ModItem::Enum(item) => data.definitions.push(ModuleDef::Enum(Enum {
+                    id: EnumLoc {
+                        id: InFile::new(file_id, *item),
+                    }
+                    .intern(db),
+                })),
                 ModItem::TypeAlias(item) => {
                     data.definitions.push(ModuleDef::TypeAlias(TypeAlias {
                         id: TypeAliasLoc {
@@ -115,6 +226,27 @@ impl ModuleData {
                         .intern(db),
                     }))
                 }
+                ModItem::Const(item) => data.definitions.push(ModuleDef::Const(Const {
+                    id: ConstLoc {
+                        id: InFile::new(file_id, *item),
+                    }
+                    .intern(db),
+                })),
+                ModItem::Static(item) => data.definitions.push(ModuleDef::Static(Static {
+                    id: StaticLoc {
+                        id: InFile::new(file_id, *item),
+                    }
+                    .intern(db),
+                })),
+                ModItem::Trait(item) => data.definitions.push(ModuleDef::Trait(Trait {
+                    id: TraitLoc {
+                        id: InFile::new(file_id, *item),
+                    }
+                    .intern(db),
+                })),
+                ModItem::Impl(_) | ModItem::Import(_) => {
+                    unreachable!("impl blocks and imports do not define a name")
+                }
             };
         }
         Arc::new(data)
@@ -130,7 +262,30 @@ pub enum ModuleDef {
     Function(Function),
     BuiltinType(BuiltinType),
     Struct(Struct),
+    Enum(Enum),
+    EnumVariant(EnumVariant),
     TypeAlias(TypeAlias),
+    Const(Const),
+    Static(Static),
+    Trait(Trait),
+}
+
+impl ModuleDef {
+    /// Returns the visibility of the definition. Builtin types are always public and enum variants
+    /// are as visible as their enum.
+    pub fn visibility(self, db: &dyn HirDatabase) -> Visibility {
+        match self {
+            ModuleDef::Function(f) => f.visibility(db),
+            ModuleDef::Struct(s) => s.visibility(db.upcast()),
+            ModuleDef::Enum(e) => e.visibility(db.upcast()),
+            ModuleDef::EnumVariant(v) => v.parent_enum().visibility(db.upcast()),
+            ModuleDef::TypeAlias(t) => t.visibility(db.upcast()),
+            ModuleDef::Const(c) => c.visibility(db.upcast()),
+            ModuleDef::Static(s) => s.visibility(db.upcast()),
+            ModuleDef::Trait(t) => t.visibility(db.upcast()),
+            ModuleDef::BuiltinType(_) => Visibility::Public,
+        }
+    }
 }
 
 impl From<Function> for ModuleDef {
@@ -151,12 +306,44 @@ impl From<Struct> for ModuleDef {
     }
 }
 
+impl From<Enum> for ModuleDef {
+    fn from(t: Enum) -> Self {
+        ModuleDef::Enum(t)
+    }
+}
+
+impl From<EnumVariant> for ModuleDef {
+    fn from(t: EnumVariant) -> Self {
+        ModuleDef::EnumVariant(t)
+    }
+}
+
+impl From<Const> for ModuleDef {
+    fn from(t: Const) -> Self {
+        ModuleDef::Const(t)
+    }
+}
+
+impl From<Static> for ModuleDef {
+    fn from(t: Static) -> Self {
+        ModuleDef::Static(t)
+    }
+}
+
+impl From<Trait> for ModuleDef {
+    fn from(t: Trait) -> Self {
+        ModuleDef::Trait(t)
+    }
+}
+
 /// The definitions that have a body.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DefWithBody {
     Function(Function),
+    Const(Const),
+    Static(Static),
 }
-impl_froms!(DefWithBody: Function);
+impl_froms!(DefWithBody: Function, Const, Static);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Visibility {
@@ -177,10 +364,20 @@ impl DefWithBody {
         db.body_with_source_map(self).1
     }
 
+    pub fn module(self, db: &dyn DefDatabase) -> Module {
+        match self {
+            DefWithBody::Function(f) => f.module(db),
+            DefWithBody::Const(c) => c.module(db),
+            DefWithBody::Static(s) => s.module(db),
+        }
+    }
+
     /// Builds a `Resolver` for code inside this item. A `Resolver` enables name resolution.
     pub(crate) fn resolver(self, db: &dyn HirDatabase) -> Resolver {
         match self {
             DefWithBody::Function(f) => f.resolver(db),
+            DefWithBody::Const(c) => c.resolver(db),
+            DefWithBody::Static(s) => s.resolver(db),
         }
     }
 }
@@ -236,12 +433,16 @@ pub struct Function {
 #[derive(Debug, PartialEq, Eq)]
 pub struct FunctionData {
     name: Name,
+    generic_params: GenericParams,
     params: Vec<LocalTypeRefId>,
     visibility: Visibility,
     ret_type: LocalTypeRefId,
     type_ref_map: TypeRefMap,
     type_ref_source_map: TypeRefSourceMap,
     is_extern: bool,
+    is_const: bool,
+    has_self_param: bool,
+    has_body: bool,
 }
 
 impl FunctionData {
@@ -253,13 +454,25 @@ impl FunctionData {
 
         let mut type_ref_builder = TypeRefBuilder::default();
 
-        let visibility = src
-            .visibility()
-            .map(|_v| Visibility::Public)
-            .unwrap_or(Visibility::Private);
+        // A function declared in a trait is generic over the type that implements the trait
+        let generic_params = match loc.container {
+            AssocContainerId::TraitId(_) => {
+                GenericParams::from_trait_function_ast(src.type_param_list())
+            }
+            _ => GenericParams::from_ast(src.type_param_list()),
+        };
 
+        // The `self` parameter is only valid for functions in an `impl` block or a trait, a
+        // diagnostic is emitted for all other functions.
         let mut params = Vec::new();
+        let mut has_self_param = false;
         if let Some(param_list) = src.param_list() {
+            if param_list.self_param().is_some() {
+                if let AssocContainerId::ImplId(_) | AssocContainerId::TraitId(_) = loc.container {
+                    params.push(type_ref_builder.self_type());
+                    has_self_param = true;
+                }
+            }
             for param in param_list.params() {
                 let type_ref = type_ref_builder.alloc_from_node_opt(param.ascribed_type().as_ref());
                 params.push(type_ref);
@@ -276,12 +489,16 @@ impl FunctionData {
 
         Arc::new(FunctionData {
             name: func.name.clone(),
+            generic_params,
             params,
-            visibility,
+            visibility: func.visibility,
             ret_type,
             type_ref_map,
             type_ref_source_map,
             is_extern: func.is_extern,
+            is_const: src.is_const(),
+            has_self_param,
+            has_body: src.body().is_some(),
         })
     }
 
@@ -289,6 +506,11 @@ impl FunctionData {
         &self.name
     }
 
+    /// Returns the generic type parameters of the function.
+    pub fn generic_params(&self) -> &GenericParams {
+        &self.generic_params
+    }
+
     pub fn params(&self) -> &[LocalTypeRefId] {
         &self.params
     }
@@ -308,6 +530,17 @@ impl FunctionData {
     pub fn type_ref_map(&self) -> &TypeRefMap {
         &self.type_ref_map
     }
+
+    /// Returns true if the function has a `self` parameter, which is always the first parameter.
+    pub fn has_self_param(&self) -> bool {
+        self.has_self_param
+    }
+
+    /// Returns true if the function has a body. Functions declared in a trait without a body have
+    /// to be implemented by every `impl` block of the trait.
+    pub fn has_body(&self) -> bool {
+        self.has_body
+    }
 }
 
 impl Function {
@@ -321,10 +554,92 @@ impl Function {
         self.data(db).name.clone()
     }
 
+    /// Returns the name of the function including the path of its module and the type or trait it
+    /// is associated with, if any (e.g. `physics::integrate`, `Foo::new` or `<Foo as Bar>::bar`).
+    /// This name uniquely identifies the function within its package.
+    pub fn full_name(self, db: &dyn HirDatabase) -> String {
+        full_name_in_module(db, self.module(db.upcast()), self.name_in_module(db))
+    }
+
+    /// Returns the name of the function including the type or trait it is associated with, if any.
+    fn name_in_module(self, db: &dyn HirDatabase) -> String {
+        if let Some(trait_def) = self.parent_trait(db.upcast()) {
+            return format!("{}::{}", trait_def.name(db.upcast()), self.name(db));
+        }
+        match self.impl_block(db.upcast()) {
+            Some(impl_def) => match impl_def.target_trait(db) {
+                Some(trait_def) => format!(
+                    "<{} as {}>::{}",
+                    impl_def.self_ty(db).display(db),
+                    trait_def.name(db.upcast()),
+                    self.name(db)
+                ),
+                None => format!("{}::{}", impl_def.self_ty(db).display(db), self.name(db)),
+            },
+            None => self.name(db).to_string(),
+        }
+    }
+
+    /// Returns the `impl` block in which this function is declared, if any.
+    pub fn impl_block(self, db: &dyn DefDatabase) -> Option<Impl> {
+        match self.id.lookup(db).container {
+            AssocContainerId::ImplId(id) => Some(Impl { id }),
+            AssocContainerId::ModuleId(_) | AssocContainerId::TraitId(_) => None,
+        }
+    }
+
+    /// Returns the trait in which this function is declared, if any.
+    pub fn parent_trait(self, db: &dyn DefDatabase) -> Option<Trait> {
+        match self.id.lookup(db).container {
+            AssocContainerId::TraitId(id) => Some(Trait { id }),
+            AssocContainerId::ModuleId(_) | AssocContainerId::ImplId(_) => None,
+        }
+    }
+
+    /// Returns the function that is called when this function is called with the type arguments
+    /// `substs`, together with the type arguments of that function. Calling a function declared in
+    /// a trait calls the implementation of the trait for the `Self` type argument, or the default
+    /// implementation of the trait if the `impl` block does not implement the function.
+    pub fn resolve_call(self, db: &dyn HirDatabase, substs: &Substs) -> (Function, Substs) {
+        let trait_def = match self.parent_trait(db.upcast()) {
+            Some(trait_def) => trait_def,
+            None => return (self, substs.clone()),
+        };
+        let self_ty = match substs.first() {
+            Some(ty) => ty,
+            None => return (self, substs.clone()),
+        };
+        let name = self.name(db);
+        let implementation = db
+            .trait_impls(trait_def.module(db.upcast()).file_id)
+            .find_impl(trait_def, self_ty)
+            .and_then(|impl_def| {
+                impl_def
+                    .items(db)
+                    .into_iter()
+                    .find(|function| function.name(db) == name)
+            });
+        match implementation {
+            Some(function) => (function, substs[1..].iter().cloned().collect()),
+            None => (self, substs.clone()),
+        }
+    }
+
     pub fn visibility(self, db: &dyn HirDatabase) -> Visibility {
         self.data(db).visibility()
     }
 
+    /// Returns the generic type parameters of the function.
+    pub fn generic_params(self, db: &dyn HirDatabase) -> GenericParams {
+        self.data(db).generic_params().clone()
+    }
+
+    /// Returns true if the function has generic type parameters. Generic functions are only
+    /// generated for the type arguments with which they are used.
+    pub fn is_generic(self, db: &dyn HirDatabase) -> bool {
+        !self.data(db).generic_params().is_empty()
+    }
+
     pub fn data(self, db: &dyn HirDatabase) -> Arc<FunctionData> {
         db.fn_data(self.id)
     }
@@ -342,25 +657,58 @@ impl Function {
         db.infer(self.into())
     }
 
+    /// Returns the zero-based line and column in the function's source file at which the
+    /// expression `expr` of its body starts.
+    pub fn expr_line_col(self, db: &dyn HirDatabase, expr: ExprId) -> Option<LineCol> {
+        let source = self.body_source_map(db).expr_syntax(expr)?;
+        let range = source
+            .value
+            .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr())
+            .range();
+        Some(db.line_index(source.file_id).line_col(range.start()))
+    }
+
     pub fn is_extern(self, db: &dyn HirDatabase) -> bool {
         db.fn_data(self.id).is_extern
     }
 
+    /// Returns true if the function is a `const fn`, which can be called from the initializer of a
+    /// `const` or `static` item.
+    pub fn is_const(self, db: &dyn HirDatabase) -> bool {
+        db.fn_data(self.id).is_const
+    }
+
     pub(crate) fn body_source_map(self, db: &dyn HirDatabase) -> Arc<BodySourceMap> {
         db.body_with_source_map(self.into()).1
     }
 
     pub(crate) fn resolver(self, db: &dyn HirDatabase) -> Resolver {
         // take the outer scope...
-        self.module(db.upcast()).resolver(db.upcast())
+        let resolver = self.module(db.upcast()).resolver(db.upcast());
+        let resolver = match self.impl_block(db.upcast()) {
+            Some(impl_def) => resolver.push_impl_block_scope(impl_def),
+            None => resolver,
+        };
+        resolver.push_generic_params_scope(self.generic_params(db))
     }
 
     pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        if self.impl_block(db.upcast()).is_none() && self.parent_trait(db.upcast()).is_none() {
+            let src = self.source(db.upcast());
+            if let Some(self_param) = src.value.param_list().and_then(|p| p.self_param()) {
+                sink.push(SelfParamOutsideImpl {
+                    self_param: InFile::new(src.file_id, AstPtr::new(&self_param)),
+                });
+            }
+        }
+
+        GenericDef::from(self).add_diagnostics(db, sink);
+
         let body = self.body(db);
         body.add_diagnostics(db, self.into(), sink);
         let infer = self.infer(db);
-        infer.add_diagnostics(db, self, sink);
-        let validator = ExprValidator::new(self, db);
+        infer.add_diagnostics(db, self.into(), sink);
+        let validator = ExprValidator::new(self.into(), db);
         validator.validate_body(sink);
     }
 }
@@ -400,6 +748,11 @@ impl Struct {
         }
     }
 
+    pub fn visibility(self, db: &dyn DefDatabase) -> Visibility {
+        let loc = self.id.lookup(db);
+        db.item_tree(loc.id.file_id)[loc.id.value].visibility
+    }
+
     pub fn data(self, db: &dyn DefDatabase) -> Arc<StructData> {
         db.struct_data(self.id)
     }
@@ -408,6 +761,15 @@ impl Struct {
         self.data(db).name.clone()
     }
 
+    /// Returns the name of the struct including the path of its module, e.g. `physics::Body`.
+    pub fn full_name(self, db: &dyn HirDatabase) -> String {
+        full_name_in_module(
+            db,
+            self.module(db.upcast()),
+            self.name(db.upcast()).to_string(),
+        )
+    }
+
     pub fn fields(self, db: &dyn HirDatabase) -> Vec<StructField> {
         self.data(db.upcast())
             .fields
@@ -424,6 +786,16 @@ impl Struct {
             .map(|(id, _)| StructField { parent: self, id })
     }
 
+    /// Returns the generic type parameters of the struct.
+    pub fn generic_params(self, db: &dyn DefDatabase) -> GenericParams {
+        self.data(db).generic_params.clone()
+    }
+
+    /// Returns true if the struct has generic type parameters.
+    pub fn is_generic(self, db: &dyn DefDatabase) -> bool {
+        !self.data(db).generic_params.is_empty()
+    }
+
     pub fn ty(self, db: &dyn HirDatabase) -> Ty {
         // TODO: Add detection of cyclick types
         db.type_for_def(self.into(), Namespace::Types).0
@@ -433,6 +805,122 @@ impl Struct {
         db.lower_struct(self)
     }
 
+    pub(crate) fn resolver(self, db: &dyn HirDatabase) -> Resolver {
+        // take the outer scope...
+        self.module(db.upcast())
+            .resolver(db.upcast())
+            .push_generic_params_scope(self.generic_params(db.upcast()))
+    }
+
+    pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        let data = self.data(db.upcast());
+        let lower = self.lower(db);
+        lower.add_diagnostics(
+            db,
+            self.module(db.upcast()).file_id,
+            data.type_ref_source_map(),
+            sink,
+        );
+        GenericDef::from(self).add_diagnostics(db, sink);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Enum {
+    pub(crate) id: EnumId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnumVariant {
+    pub(crate) parent: Enum,
+    pub(crate) id: LocalEnumVariantId,
+}
+
+impl EnumVariant {
+    pub fn parent_enum(self) -> Enum {
+        self.parent
+    }
+
+    pub fn name(self, db: &dyn HirDatabase) -> Name {
+        self.parent.data(db.upcast()).variants[self.id].name.clone()
+    }
+
+    pub fn kind(self, db: &dyn HirDatabase) -> StructKind {
+        self.parent.data(db.upcast()).variants[self.id].kind
+    }
+
+    /// Returns the index of the variant in its enum, which is also the value of its discriminant.
+    pub fn index(self) -> usize {
+        u32::from(self.id.into_raw()) as usize
+    }
+
+    /// Returns the types of the fields of this variant.
+    pub fn field_types(self, db: &dyn HirDatabase) -> Vec<Ty> {
+        let data = self.parent.data(db.upcast());
+        let lower = self.parent.lower(db);
+        data.variants[self.id]
+            .fields
+            .iter()
+            .map(|(_, field)| lower[field.type_ref].clone())
+            .collect()
+    }
+
+    pub fn id(self) -> LocalEnumVariantId {
+        self.id
+    }
+}
+
+impl Enum {
+    pub fn module(self, db: &dyn DefDatabase) -> Module {
+        Module {
+            file_id: self.id.lookup(db).id.file_id,
+        }
+    }
+
+    pub fn visibility(self, db: &dyn DefDatabase) -> Visibility {
+        let loc = self.id.lookup(db);
+        db.item_tree(loc.id.file_id)[loc.id.value].visibility
+    }
+
+    pub fn data(self, db: &dyn DefDatabase) -> Arc<EnumData> {
+        db.enum_data(self.id)
+    }
+
+    pub fn name(self, db: &dyn DefDatabase) -> Name {
+        self.data(db).name.clone()
+    }
+
+    /// Returns the name of the enum including the path of its module, e.g. `physics::Shape`.
+    pub fn full_name(self, db: &dyn HirDatabase) -> String {
+        full_name_in_module(
+            db,
+            self.module(db.upcast()),
+            self.name(db.upcast()).to_string(),
+        )
+    }
+
+    pub fn variants(self, db: &dyn HirDatabase) -> Vec<EnumVariant> {
+        self.data(db.upcast())
+            .variants
+            .iter()
+            .map(|(id, _)| EnumVariant { parent: self, id })
+            .collect()
+    }
+
+    pub fn variant(self, db: &dyn DefDatabase, name: &Name) -> Option<EnumVariant> {
+        self.data(db)
+            .variant(name)
+            .map(|id| EnumVariant { parent: self, id })
+    }
+
+    pub fn ty(self, db: &dyn HirDatabase) -> Ty {
+        db.type_for_def(self.into(), Namespace::Types).0
+    }
+
+    pub fn lower(self, db: &dyn HirDatabase) -> Arc<LowerBatchResult> {
+        db.lower_enum(self)
+    }
+
     pub(crate) fn resolver(self, db: &dyn HirDatabase) -> Resolver {
         // take the outer scope...
         self.module(db.upcast()).resolver(db.upcast())
@@ -462,6 +950,11 @@ impl TypeAlias {
         }
     }
 
+    pub fn visibility(self, db: &dyn DefDatabase) -> Visibility {
+        let loc = self.id.lookup(db);
+        db.item_tree(loc.id.file_id)[loc.id.value].visibility
+    }
+
     pub fn data(self, db: &dyn DefDatabase) -> Arc<TypeAliasData> {
         db.type_alias_data(self.id)
     }
@@ -498,6 +991,455 @@ impl TypeAlias {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Const {
+    pub(crate) id: ConstId,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConstData {
+    pub name: Name,
+    pub type_ref: LocalTypeRefId,
+    type_ref_map: TypeRefMap,
+    type_ref_source_map: TypeRefSourceMap,
+}
+
+impl ConstData {
+    pub(crate) fn const_data_query(db: &dyn DefDatabase, id: ConstId) -> Arc<ConstData> {
+        let loc = id.lookup(db);
+        let item_tree = db.item_tree(loc.id.file_id);
+        let src = item_tree.source(db, loc.id);
+        let mut type_ref_builder = TypeRefBuilder::default();
+        let type_ref = type_ref_builder.alloc_from_node_opt(src.ascribed_type().as_ref());
+        let (type_ref_map, type_ref_source_map) = type_ref_builder.finish();
+        Arc::new(ConstData {
+            name: item_tree[loc.id.value].name.clone(),
+            type_ref,
+            type_ref_map,
+            type_ref_source_map,
+        })
+    }
+
+    pub fn type_ref_source_map(&self) -> &TypeRefSourceMap {
+        &self.type_ref_source_map
+    }
+
+    pub fn type_ref_map(&self) -> &TypeRefMap {
+        &self.type_ref_map
+    }
+}
+
+impl Const {
+    pub fn module(self, db: &dyn DefDatabase) -> Module {
+        Module {
+            file_id: self.id.lookup(db).id.file_id,
+        }
+    }
+
+    pub fn visibility(self, db: &dyn DefDatabase) -> Visibility {
+        let loc = self.id.lookup(db);
+        db.item_tree(loc.id.file_id)[loc.id.value].visibility
+    }
+
+    pub fn data(self, db: &dyn DefDatabase) -> Arc<ConstData> {
+        db.const_data(self.id)
+    }
+
+    pub fn name(self, db: &dyn DefDatabase) -> Name {
+        self.data(db).name.clone()
+    }
+
+    /// Returns the name of the constant including the path of its module, e.g. `physics::GRAVITY`.
+    pub fn full_name(self, db: &dyn HirDatabase) -> String {
+        full_name_in_module(
+            db,
+            self.module(db.upcast()),
+            self.name(db.upcast()).to_string(),
+        )
+    }
+
+    pub fn ty(self, db: &dyn HirDatabase) -> Ty {
+        db.type_for_def(self.into(), Namespace::Values).0
+    }
+
+    pub fn body(self, db: &dyn HirDatabase) -> Arc<Body> {
+        db.body(self.into())
+    }
+
+    pub fn infer(self, db: &dyn HirDatabase) -> Arc<InferenceResult> {
+        db.infer(self.into())
+    }
+
+    /// Returns the value of the constant, which is evaluated at compile time.
+    pub fn value(self, db: &dyn HirDatabase) -> Result<ConstValue, ConstEvalError> {
+        db.const_eval(self.into())
+    }
+
+    pub(crate) fn resolver(self, db: &dyn HirDatabase) -> Resolver {
+        // take the outer scope...
+        self.module(db.upcast()).resolver(db.upcast())
+    }
+
+    pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        let src = self.source(db.upcast());
+        body_diagnostics(db, self.into(), src.map(|src| src.name()), sink);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Static {
+    pub(crate) id: StaticId,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct StaticData {
+    pub name: Name,
+    pub type_ref: LocalTypeRefId,
+    pub is_mut: bool,
+    type_ref_map: TypeRefMap,
+    type_ref_source_map: TypeRefSourceMap,
+}
+
+impl StaticData {
+    pub(crate) fn static_data_query(db: &dyn DefDatabase, id: StaticId) -> Arc<StaticData> {
+        let loc = id.lookup(db);
+        let item_tree = db.item_tree(loc.id.file_id);
+        let static_def = &item_tree[loc.id.value];
+        let src = item_tree.source(db, loc.id);
+        let mut type_ref_builder = TypeRefBuilder::default();
+        let type_ref = type_ref_builder.alloc_from_node_opt(src.ascribed_type().as_ref());
+        let (type_ref_map, type_ref_source_map) = type_ref_builder.finish();
+        Arc::new(StaticData {
+            name: static_def.name.clone(),
+            type_ref,
+            is_mut: static_def.is_mut,
+            type_ref_map,
+            type_ref_source_map,
+        })
+    }
+
+    pub fn type_ref_source_map(&self) -> &TypeRefSourceMap {
+        &self.type_ref_source_map
+    }
+
+    pub fn type_ref_map(&self) -> &TypeRefMap {
+        &self.type_ref_map
+    }
+}
+
+impl Static {
+    pub fn module(self, db: &dyn DefDatabase) -> Module {
+        Module {
+            file_id: self.id.lookup(db).id.file_id,
+        }
+    }
+
+    /// Returns the visibility of the static. A static is only accessible in the module in which it
+    /// is declared; a public static is exposed to the host through reflection.
+    pub fn visibility(self, db: &dyn DefDatabase) -> Visibility {
+        let loc = self.id.lookup(db);
+        db.item_tree(loc.id.file_id)[loc.id.value].visibility
+    }
+
+    pub fn data(self, db: &dyn DefDatabase) -> Arc<StaticData> {
+        db.static_data(self.id)
+    }
+
+    pub fn name(self, db: &dyn DefDatabase) -> Name {
+        self.data(db).name.clone()
+    }
+
+    /// Returns the name of the static including the path of its module, e.g. `physics::COUNTER`.
+    pub fn full_name(self, db: &dyn HirDatabase) -> String {
+        full_name_in_module(
+            db,
+            self.module(db.upcast()),
+            self.name(db.upcast()).to_string(),
+        )
+    }
+
+    /// Returns true if the static is declared as `static mut` and can therefore be assigned to.
+    pub fn is_mut(self, db: &dyn DefDatabase) -> bool {
+        self.data(db).is_mut
+    }
+
+    pub fn ty(self, db: &dyn HirDatabase) -> Ty {
+        db.type_for_def(self.into(), Namespace::Values).0
+    }
+
+    pub fn body(self, db: &dyn HirDatabase) -> Arc<Body> {
+        db.body(self.into())
+    }
+
+    pub fn infer(self, db: &dyn HirDatabase) -> Arc<InferenceResult> {
+        db.infer(self.into())
+    }
+
+    /// Returns the value with which the static is initialized, which is evaluated at compile time.
+    pub fn initial_value(self, db: &dyn HirDatabase) -> Result<ConstValue, ConstEvalError> {
+        db.const_eval(self.into())
+    }
+
+    pub(crate) fn resolver(self, db: &dyn HirDatabase) -> Resolver {
+        // take the outer scope...
+        self.module(db.upcast()).resolver(db.upcast())
+    }
+
+    pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        let src = self.source(db.upcast());
+        body_diagnostics(db, self.into(), src.map(|src| src.name()), sink);
+    }
+}
+
+/// Adds the diagnostics of the initializer of a `const` or `static` item to the `DiagnosticSink`,
+/// including the diagnostics of its compile-time evaluation.
+fn body_diagnostics(
+    db: &dyn HirDatabase,
+    def: DefWithBody,
+    name: InFile<Option<ast::Name>>,
+    sink: &mut DiagnosticSink,
+) {
+    def.body(db).add_diagnostics(db, def, sink);
+    def.infer(db).add_diagnostics(db, def, sink);
+    ExprValidator::new(def, db).validate_body(sink);
+
+    let file = name.file_id;
+    let expr_syntax = |expr| {
+        def.body_source_map(db)
+            .expr_syntax(expr)
+            .unwrap()
+            .value
+            .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr())
+    };
+    match db.const_eval(def) {
+        Err(ConstEvalError::NotConstant(expr)) => {
+            let expr = expr_syntax(expr);
+            sink.push(NonConstantInitializer { file, expr });
+        }
+        Err(ConstEvalError::Overflow(expr)) => {
+            let expr = expr_syntax(expr);
+            sink.push(ArithmeticOverflow { file, expr });
+        }
+        Err(ConstEvalError::DivisionByZero(expr)) => {
+            let expr = expr_syntax(expr);
+            sink.push(DivisionByZero { file, expr });
+        }
+        Err(ConstEvalError::RecursionLimit(expr)) => {
+            let expr = expr_syntax(expr);
+            sink.push(ConstEvalRecursionLimit { file, expr });
+        }
+        Err(ConstEvalError::Cycle) => {
+            if let Some(name) = name.value {
+                sink.push(CyclicConstant {
+                    file,
+                    name: SyntaxNodePtr::new(name.syntax()),
+                });
+            }
+        }
+        Err(ConstEvalError::Invalid) | Ok(_) => (),
+    }
+}
+
+/// Returns the `name` of an item prefixed by the path of the module in which it is declared.
+fn full_name_in_module(db: &dyn HirDatabase, module: Module, name: String) -> String {
+    let module_path = module.path(db.upcast());
+    if module_path.is_empty() {
+        name
+    } else {
+        let module_path: Vec<String> = module_path.iter().map(ToString::to_string).collect();
+        format!("{}::{}", module_path.join("::"), name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Impl {
+    pub(crate) id: ImplId,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ImplData {
+    pub self_ty: LocalTypeRefId,
+    /// The trait that is implemented by the `impl` block, if any
+    pub target_trait: Option<TypeRef>,
+    pub items: Vec<Function>,
+    type_ref_map: TypeRefMap,
+    type_ref_source_map: TypeRefSourceMap,
+}
+
+impl ImplData {
+    pub(crate) fn impl_data_query(db: &dyn DefDatabase, id: ImplId) -> Arc<ImplData> {
+        let loc = id.lookup(db);
+        let item_tree = db.item_tree(loc.id.file_id);
+        let impl_def = &item_tree[loc.id.value];
+        let src = item_tree.source(db, loc.id);
+
+        let mut type_ref_builder = TypeRefBuilder::default();
+        let self_ty = type_ref_builder.alloc_from_node_opt(src.target_type().as_ref());
+        let (type_ref_map, type_ref_source_map) = type_ref_builder.finish();
+
+        let items = impl_def
+            .items
+            .iter()
+            .map(|item| Function {
+                id: FunctionLoc {
+                    container: AssocContainerId::ImplId(id),
+                    id: InFile::new(loc.id.file_id, *item),
+                }
+                .intern(db),
+            })
+            .collect();
+
+        Arc::new(ImplData {
+            self_ty,
+            target_trait: impl_def.target_trait.clone(),
+            items,
+            type_ref_map,
+            type_ref_source_map,
+        })
+    }
+
+    pub fn type_ref_source_map(&self) -> &TypeRefSourceMap {
+        &self.type_ref_source_map
+    }
+
+    pub fn type_ref_map(&self) -> &TypeRefMap {
+        &self.type_ref_map
+    }
+}
+
+impl Impl {
+    pub fn module(self, db: &dyn DefDatabase) -> Module {
+        Module {
+            file_id: self.id.lookup(db).id.file_id,
+        }
+    }
+
+    pub fn data(self, db: &dyn DefDatabase) -> Arc<ImplData> {
+        db.impl_data(self.id)
+    }
+
+    /// Returns the type for which this `impl` block is defined.
+    pub fn self_ty(self, db: &dyn HirDatabase) -> Ty {
+        let data = self.data(db.upcast());
+        self.lower(db)[data.self_ty].clone()
+    }
+
+    /// Returns the trait that is implemented by this `impl` block, if any. Returns `None` if the
+    /// `impl` block is an inherent `impl` block or if the trait could not be resolved.
+    pub fn target_trait(self, db: &dyn HirDatabase) -> Option<Trait> {
+        let target_trait = self.data(db.upcast()).target_trait.clone()?;
+        self.resolver(db).resolve_trait(db, &target_trait)
+    }
+
+    /// Returns true if this `impl` block implements a trait, e.g. `impl Foo for Bar`.
+    pub fn is_trait_impl(self, db: &dyn DefDatabase) -> bool {
+        self.data(db).target_trait.is_some()
+    }
+
+    /// Returns the functions declared in this `impl` block.
+    pub fn items(self, db: &dyn HirDatabase) -> Vec<Function> {
+        self.data(db.upcast()).items.clone()
+    }
+
+    pub fn lower(self, db: &dyn HirDatabase) -> Arc<LowerBatchResult> {
+        db.lower_impl(self)
+    }
+
+    pub(crate) fn resolver(self, db: &dyn HirDatabase) -> Resolver {
+        // take the outer scope...
+        self.module(db.upcast()).resolver(db.upcast())
+    }
+
+    pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        let data = self.data(db.upcast());
+        let lower = self.lower(db);
+        lower.add_diagnostics(
+            db,
+            self.module(db.upcast()).file_id,
+            data.type_ref_source_map(),
+            sink,
+        );
+        for function in self.items(db) {
+            function.diagnostics(db, sink);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Trait {
+    pub(crate) id: TraitId,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TraitData {
+    pub name: Name,
+    pub items: Vec<Function>,
+}
+
+impl TraitData {
+    pub(crate) fn trait_data_query(db: &dyn DefDatabase, id: TraitId) -> Arc<TraitData> {
+        let loc = id.lookup(db);
+        let item_tree = db.item_tree(loc.id.file_id);
+        let trait_def = &item_tree[loc.id.value];
+
+        let items = trait_def
+            .items
+            .iter()
+            .map(|item| Function {
+                id: FunctionLoc {
+                    container: AssocContainerId::TraitId(id),
+                    id: InFile::new(loc.id.file_id, *item),
+                }
+                .intern(db),
+            })
+            .collect();
+
+        Arc::new(TraitData {
+            name: trait_def.name.clone(),
+            items,
+        })
+    }
+}
+
+impl Trait {
+    pub fn module(self, db: &dyn DefDatabase) -> Module {
+        Module {
+            file_id: self.id.lookup(db).id.file_id,
+        }
+    }
+
+    pub fn visibility(self, db: &dyn DefDatabase) -> Visibility {
+        let loc = self.id.lookup(db);
+        db.item_tree(loc.id.file_id)[loc.id.value].visibility
+    }
+
+    pub fn data(self, db: &dyn DefDatabase) -> Arc<TraitData> {
+        db.trait_data(self.id)
+    }
+
+    pub fn name(self, db: &dyn DefDatabase) -> Name {
+        self.data(db).name.clone()
+    }
+
+    /// Returns the functions declared in this trait.
+    pub fn items(self, db: &dyn HirDatabase) -> Vec<Function> {
+        self.data(db.upcast()).items.clone()
+    }
+
+    /// Returns the function with the specified `name` declared in this trait, if any.
+    pub fn function(self, db: &dyn HirDatabase, name: &Name) -> Option<Function> {
+        self.items(db)
+            .into_iter()
+            .find(|function| function.name(db) == *name)
+    }
+
+    pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        for function in self.items(db) {
+            function.diagnostics(db, sink);
+        }
+    }
+}
+
 mod diagnostics {
     use super::Module;
     use crate::diagnostics::{DiagnosticSink, DuplicateDefinition};
@@ -524,9 +1466,27 @@ mod diagnostics {
             ModItem::Struct(id) => {
                 SyntaxNodePtr::new(item_tree.source(db, ItemTreeId::new(file_id, id)).syntax())
             }
+            ModItem::Enum(id) => {
+                SyntaxNodePtr::new(item_tree.source(db, ItemTreeId::new(file_id, id)).syntax())
+            }
             ModItem::TypeAlias(id) => {
                 SyntaxNodePtr::new(item_tree.source(db, ItemTreeId::new(file_id, id)).syntax())
             }
+            ModItem::Const(id) => {
+                SyntaxNodePtr::new(item_tree.source(db, ItemTreeId::new(file_id, id)).syntax())
+            }
+            ModItem::Static(id) => {
+                SyntaxNodePtr::new(item_tree.source(db, ItemTreeId::new(file_id, id)).syntax())
+            }
+            ModItem::Impl(id) => {
+                SyntaxNodePtr::new(item_tree.source(db, ItemTreeId::new(file_id, id)).syntax())
+            }
+            ModItem::Trait(id) => {
+                SyntaxNodePtr::new(item_tree.source(db, ItemTreeId::new(file_id, id)).syntax())
+            }
+            ModItem::Import(id) => {
+                SyntaxNodePtr::new(item_tree.source(db, ItemTreeId::new(file_id, id)).syntax())
+            }
         }
     }
 
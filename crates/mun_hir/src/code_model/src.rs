@@ -1,5 +1,7 @@
-use crate::code_model::{Function, Struct, StructField, TypeAlias};
-use crate::ids::Lookup;
+use crate::code_model::{
+    Const, Enum, Function, Impl, Static, Struct, StructField, Trait, TypeAlias,
+};
+use crate::ids::{AssocItemLoc, Lookup};
 use crate::in_file::InFile;
 use crate::item_tree::ItemTreeNode;
 use crate::{DefDatabase, ItemLoc};
@@ -26,6 +28,22 @@ impl<N: ItemTreeNode> HasSource for ItemLoc<N> {
     }
 }
 
+impl<N: ItemTreeNode> HasSource for AssocItemLoc<N> {
+    type Ast = N::Source;
+
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
+        let tree = db.item_tree(self.id.file_id);
+        let ast_id_map = db.ast_id_map(self.id.file_id);
+        let root = db.parse(self.id.file_id);
+        let node = &tree[self.id.value];
+
+        InFile::new(
+            self.id.file_id,
+            ast_id_map.get(node.ast_id()).to_node(&root.syntax_node()),
+        )
+    }
+}
+
 impl HasSource for Function {
     type Ast = ast::FunctionDef;
     fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
@@ -40,6 +58,13 @@ impl HasSource for Struct {
     }
 }
 
+impl HasSource for Enum {
+    type Ast = ast::EnumDef;
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
+        self.id.lookup(db).source(db)
+    }
+}
+
 impl HasSource for StructField {
     type Ast = ast::RecordFieldDef;
 
@@ -69,3 +94,31 @@ impl HasSource for TypeAlias {
         self.id.lookup(db).source(db)
     }
 }
+
+impl HasSource for Const {
+    type Ast = ast::ConstDef;
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
+        self.id.lookup(db).source(db)
+    }
+}
+
+impl HasSource for Static {
+    type Ast = ast::StaticDef;
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
+        self.id.lookup(db).source(db)
+    }
+}
+
+impl HasSource for Impl {
+    type Ast = ast::ImplDef;
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
+        self.id.lookup(db).source(db)
+    }
+}
+
+impl HasSource for Trait {
+    type Ast = ast::TraitDef;
+    fn source(&self, db: &dyn DefDatabase) -> InFile<Self::Ast> {
+        self.id.lookup(db).source(db)
+    }
+}
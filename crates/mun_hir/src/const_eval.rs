@@ -0,0 +1,585 @@
+//! Compile-time evaluation of constant expressions. The initializers of `const` and `static` items
+//! are evaluated, as are the bodies of the `const fn`s that they call. An expression is constant
+//! if it only consists of literals, arithmetic, comparisons, casts, references to other constants,
+//! blocks, `if` expressions, calls to `const fn`s and struct or tuple literals of value types.
+
+use crate::{
+    adt::StructMemoryKind,
+    code_model::DefWithBody,
+    expr::{
+        resolver_for_expr, ArithOp, BinaryOp, Body, CmpOp, Expr, ExprId, Literal, LogicOp,
+        Ordering, Pat, PatId, Statement, UnaryOp,
+    },
+    resolve::Resolution,
+    ty::ResolveBitness,
+    ty_app, CallableDef, FloatBitness, FloatTy, HirDatabase, InferenceResult, IntTy, ModuleDef,
+    Signedness, Ty, TypeCtor,
+};
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+
+/// The maximum number of nested `const fn` calls, which guards against infinite recursion
+const MAX_CALL_DEPTH: usize = 64;
+
+/// The value of a constant expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstValue {
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    /// The values of the fields of a value struct or tuple, in the order in which they are declared
+    Struct(Vec<ConstValue>),
+}
+
+impl Eq for ConstValue {}
+
+/// The reason why an expression could not be evaluated at compile time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstEvalError {
+    /// The expression cannot be evaluated at compile time
+    NotConstant(ExprId),
+    /// The result of the arithmetic expression does not fit in its type
+    Overflow(ExprId),
+    /// The expression divides an integer by zero
+    DivisionByZero(ExprId),
+    /// The call exceeds the maximum number of nested `const fn` calls
+    RecursionLimit(ExprId),
+    /// The initializer (indirectly) refers to itself
+    Cycle,
+    /// The initializer is erroneous; the error is reported elsewhere
+    Invalid,
+}
+
+pub(crate) fn const_eval_query(
+    db: &dyn HirDatabase,
+    def: DefWithBody,
+) -> Result<ConstValue, ConstEvalError> {
+    let mut evaluator = ConstEvaluator::new(db, def);
+    let body_expr = evaluator.body.body_expr();
+    evaluator.eval(body_expr)
+}
+
+/// Recover from a cycle in the salsa database, e.g. `const A: i32 = B; const B: i32 = A;`
+pub(crate) fn const_eval_cycle_recover(
+    _db: &dyn HirDatabase,
+    _cycle: &[String],
+    _def: &DefWithBody,
+) -> Result<ConstValue, ConstEvalError> {
+    Err(ConstEvalError::Cycle)
+}
+
+/// Evaluates the expressions of a body at compile time
+pub(crate) struct ConstEvaluator<'a> {
+    db: &'a dyn HirDatabase,
+    body: Arc<Body>,
+    infer: Arc<InferenceResult>,
+    /// The values of the parameters and `let` bindings that have been evaluated
+    locals: FxHashMap<PatId, ConstValue>,
+    /// The number of `const fn` calls that are being evaluated
+    depth: usize,
+}
+
+impl<'a> ConstEvaluator<'a> {
+    pub(crate) fn new(db: &'a dyn HirDatabase, def: DefWithBody) -> Self {
+        ConstEvaluator {
+            db,
+            body: def.body(db),
+            infer: def.infer(db),
+            locals: FxHashMap::default(),
+            depth: 0,
+        }
+    }
+
+    /// Constructs an evaluator for which the values of some of the local bindings are known
+    pub(crate) fn with_locals(
+        db: &'a dyn HirDatabase,
+        def: DefWithBody,
+        locals: FxHashMap<PatId, ConstValue>,
+    ) -> Self {
+        ConstEvaluator {
+            locals,
+            ..ConstEvaluator::new(db, def)
+        }
+    }
+
+    /// Returns the values of the local bindings that are known after the evaluation
+    pub(crate) fn into_locals(self) -> FxHashMap<PatId, ConstValue> {
+        self.locals
+    }
+
+    pub(crate) fn eval(&mut self, expr: ExprId) -> Result<ConstValue, ConstEvalError> {
+        let body = self.body.clone();
+        match &body[expr] {
+            Expr::Missing => Err(ConstEvalError::Invalid),
+            Expr::Literal(literal) => match literal {
+                Literal::Bool(value) => Ok(ConstValue::Bool(*value)),
+                Literal::Int(int) => Ok(ConstValue::Int(int.value as i128)),
+                Literal::Float(float) => Ok(ConstValue::Float(float.value)),
+                Literal::String(_) => Err(ConstEvalError::NotConstant(expr)),
+            },
+            Expr::UnaryOp { expr: operand, op } => match (op, self.eval(*operand)?) {
+                (UnaryOp::Neg, ConstValue::Int(value)) => {
+                    let int_ty = self.int_ty(*operand)?;
+                    // Negating an unsigned integer is a type error, which is reported elsewhere
+                    if int_ty.signedness == Signedness::Unsigned {
+                        return Err(ConstEvalError::Invalid);
+                    }
+                    checked_int(expr, int_ty, value.checked_neg()).map(ConstValue::Int)
+                }
+                (UnaryOp::Neg, ConstValue::Float(value)) => Ok(ConstValue::Float(-value)),
+                (UnaryOp::Not, ConstValue::Bool(value)) => Ok(ConstValue::Bool(!value)),
+                (UnaryOp::Not, ConstValue::Int(value)) => {
+                    let int_ty = self.int_ty(*operand)?;
+                    Ok(ConstValue::Int(truncate_int(!value, int_ty)))
+                }
+                _ => Err(ConstEvalError::Invalid),
+            },
+            Expr::BinaryOp {
+                lhs,
+                rhs,
+                op: Some(op),
+            } => self.eval_binary_op(expr, *lhs, *rhs, *op),
+            Expr::Cast { expr: operand, .. } => self.eval_cast(expr, *operand),
+            Expr::Path(path) => {
+                let resolver = resolver_for_expr(self.body.clone(), self.db, expr);
+                match resolver
+                    .resolve_path_without_assoc_items(self.db, path)
+                    .take_values()
+                {
+                    Some(Resolution::LocalBinding(pat)) => self
+                        .locals
+                        .get(&pat)
+                        .cloned()
+                        .ok_or(ConstEvalError::NotConstant(expr)),
+                    // Errors in the initializer of the other constant are reported there
+                    Some(Resolution::Def(ModuleDef::Const(c))) => match c.value(self.db) {
+                        Err(ConstEvalError::Cycle) => Err(ConstEvalError::Cycle),
+                        Err(_) => Err(ConstEvalError::Invalid),
+                        Ok(value) => Ok(value),
+                    },
+                    Some(_) => Err(ConstEvalError::NotConstant(expr)),
+                    None => Err(ConstEvalError::Invalid),
+                }
+            }
+            Expr::Block { statements, tail } => {
+                for statement in statements.iter() {
+                    match statement {
+                        Statement::Let {
+                            pat,
+                            initializer: Some(initializer),
+                            ..
+                        } => {
+                            let value = self.eval(*initializer)?;
+                            if !self.bind_pat(*pat, value) {
+                                return Err(ConstEvalError::NotConstant(*initializer));
+                            }
+                        }
+                        Statement::Let { .. } => (),
+                        Statement::Expr(expr) => {
+                            self.eval(*expr)?;
+                        }
+                    }
+                }
+                match tail {
+                    Some(tail) => self.eval(*tail),
+                    None => Ok(ConstValue::Struct(Vec::new())),
+                }
+            }
+            Expr::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => match self.eval(*condition)? {
+                ConstValue::Bool(true) => self.eval(*then_branch),
+                ConstValue::Bool(false) => match else_branch {
+                    Some(else_branch) => self.eval(*else_branch),
+                    None => Ok(ConstValue::Struct(Vec::new())),
+                },
+                _ => Err(ConstEvalError::Invalid),
+            },
+            Expr::Call { callee, args } => self.eval_call(expr, *callee, args),
+            Expr::RecordLit {
+                fields,
+                spread: None,
+                ..
+            } => {
+                let s = match self.infer[expr].as_struct() {
+                    Some(s) if s.data(self.db.upcast()).memory_kind == StructMemoryKind::Value => s,
+                    _ => return Err(ConstEvalError::NotConstant(expr)),
+                };
+                s.fields(self.db)
+                    .into_iter()
+                    .map(|field| {
+                        let name = field.name(self.db);
+                        fields
+                            .iter()
+                            .find(|f| f.name == name)
+                            .map_or(Err(ConstEvalError::Invalid), |f| self.eval(f.expr))
+                    })
+                    .collect::<Result<_, _>>()
+                    .map(ConstValue::Struct)
+            }
+            Expr::Tuple(exprs) => exprs
+                .iter()
+                .map(|expr| self.eval(*expr))
+                .collect::<Result<_, _>>()
+                .map(ConstValue::Struct),
+            _ => Err(ConstEvalError::NotConstant(expr)),
+        }
+    }
+
+    /// Evaluates a binary operation. Logical operations short-circuit, just like at runtime.
+    fn eval_binary_op(
+        &mut self,
+        expr: ExprId,
+        lhs: ExprId,
+        rhs: ExprId,
+        op: BinaryOp,
+    ) -> Result<ConstValue, ConstEvalError> {
+        match op {
+            BinaryOp::LogicOp(op) => match (op, self.eval(lhs)?) {
+                (LogicOp::And, ConstValue::Bool(false)) => Ok(ConstValue::Bool(false)),
+                (LogicOp::Or, ConstValue::Bool(true)) => Ok(ConstValue::Bool(true)),
+                (_, ConstValue::Bool(_)) => self.eval(rhs),
+                _ => Err(ConstEvalError::Invalid),
+            },
+            BinaryOp::ArithOp(op) => {
+                let lhs_value = self.eval(lhs)?;
+                let rhs_value = self.eval(rhs)?;
+                let ty = self.resolve_ty(&self.infer[lhs]);
+                eval_arith_op(expr, op, &ty, lhs_value, rhs_value)
+            }
+            BinaryOp::CmpOp(op) => {
+                let lhs_value = self.eval(lhs)?;
+                let rhs_value = self.eval(rhs)?;
+                let ty = self.resolve_ty(&self.infer[lhs]);
+                eval_cmp_op(op, &ty, lhs_value, rhs_value)
+            }
+            BinaryOp::Assignment { op } => {
+                // Only the local bindings of a `const fn` can be assigned to
+                let pat = match &self.body[lhs] {
+                    Expr::Path(path) => {
+                        let resolver = resolver_for_expr(self.body.clone(), self.db, lhs);
+                        match resolver
+                            .resolve_path_without_assoc_items(self.db, path)
+                            .take_values()
+                        {
+                            Some(Resolution::LocalBinding(pat)) => pat,
+                            _ => return Err(ConstEvalError::NotConstant(expr)),
+                        }
+                    }
+                    _ => return Err(ConstEvalError::NotConstant(expr)),
+                };
+                let value = self.eval(rhs)?;
+                let value = match op {
+                    Some(op) => {
+                        let current = self
+                            .locals
+                            .get(&pat)
+                            .cloned()
+                            .ok_or(ConstEvalError::NotConstant(lhs))?;
+                        let ty = self.resolve_ty(&self.infer[lhs]);
+                        eval_arith_op(expr, op, &ty, current, value)?
+                    }
+                    None => value,
+                };
+                self.locals.insert(pat, value);
+                Ok(ConstValue::Struct(Vec::new()))
+            }
+        }
+    }
+
+    /// Evaluates an explicit cast, which has the same semantics as the code that is generated for
+    /// it: integers are truncated or extended and floats are converted to integers by saturation.
+    fn eval_cast(&mut self, expr: ExprId, operand: ExprId) -> Result<ConstValue, ConstEvalError> {
+        let value = self.eval(operand)?;
+        let from_ty = self.resolve_ty(&self.infer[operand]);
+        let to_ty = self.resolve_ty(&self.infer[expr]);
+        match (value, &from_ty, &to_ty) {
+            (ConstValue::Int(value), _, ty_app!(TypeCtor::Int(to))) => {
+                Ok(ConstValue::Int(truncate_int(value, *to)))
+            }
+            (ConstValue::Bool(value), _, ty_app!(TypeCtor::Int(_))) => {
+                Ok(ConstValue::Int(value as i128))
+            }
+            (
+                ConstValue::Int(value),
+                ty_app!(TypeCtor::Int(from)),
+                ty_app!(TypeCtor::Float(to)),
+            ) => Ok(ConstValue::Float(int_to_float(value, *from, *to))),
+            (ConstValue::Float(value), _, ty_app!(TypeCtor::Int(to))) => {
+                Ok(ConstValue::Int(float_to_int(value, *to)))
+            }
+            (ConstValue::Float(value), _, ty_app!(TypeCtor::Float(to))) => {
+                Ok(ConstValue::Float(round_float(value, *to)))
+            }
+            (value, _, _) if from_ty == to_ty => Ok(value),
+            _ => Err(ConstEvalError::Invalid),
+        }
+    }
+
+    /// Evaluates a call to a `const fn` or the constructor of a tuple struct of the value kind.
+    /// Errors in the body of a `const fn` are reported at the call, because the body is only
+    /// erroneous for the given arguments.
+    fn eval_call(
+        &mut self,
+        expr: ExprId,
+        callee: ExprId,
+        args: &[ExprId],
+    ) -> Result<ConstValue, ConstEvalError> {
+        match self.infer[callee].as_callable_def() {
+            Some(CallableDef::Function(f)) if f.is_const(self.db) => {
+                if self.depth >= MAX_CALL_DEPTH {
+                    return Err(ConstEvalError::RecursionLimit(expr));
+                }
+                let args = args
+                    .iter()
+                    .map(|arg| self.eval(*arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let mut evaluator = ConstEvaluator::new(self.db, f.into());
+                evaluator.depth = self.depth + 1;
+                let body = evaluator.body.clone();
+                for ((pat, _), arg) in body.params().iter().zip(args) {
+                    if !evaluator.bind_pat(*pat, arg) {
+                        return Err(ConstEvalError::NotConstant(expr));
+                    }
+                }
+                evaluator.eval(body.body_expr()).map_err(|err| match err {
+                    ConstEvalError::NotConstant(_) => ConstEvalError::NotConstant(expr),
+                    ConstEvalError::Overflow(_) => ConstEvalError::Overflow(expr),
+                    ConstEvalError::DivisionByZero(_) => ConstEvalError::DivisionByZero(expr),
+                    ConstEvalError::RecursionLimit(_) => ConstEvalError::RecursionLimit(expr),
+                    ConstEvalError::Cycle | ConstEvalError::Invalid => err,
+                })
+            }
+            Some(CallableDef::Struct(s))
+                if s.data(self.db.upcast()).memory_kind == StructMemoryKind::Value =>
+            {
+                args.iter()
+                    .map(|arg| self.eval(*arg))
+                    .collect::<Result<_, _>>()
+                    .map(ConstValue::Struct)
+            }
+            _ => Err(ConstEvalError::NotConstant(expr)),
+        }
+    }
+
+    /// Binds the `value` to the bindings of the pattern. Returns false if the pattern cannot be
+    /// matched at compile time.
+    pub(crate) fn bind_pat(&mut self, pat: PatId, value: ConstValue) -> bool {
+        let body = self.body.clone();
+        match (&body[pat], value) {
+            (Pat::Bind { .. }, value) => {
+                self.locals.insert(pat, value);
+                true
+            }
+            (Pat::Wild, _) => true,
+            (Pat::Tuple(pats), ConstValue::Struct(values)) => pats
+                .iter()
+                .zip(values)
+                .all(|(pat, value)| self.bind_pat(*pat, value)),
+            _ => false,
+        }
+    }
+
+    /// Returns the integer type of the expression, with its bitness resolved for the target
+    fn int_ty(&self, expr: ExprId) -> Result<IntTy, ConstEvalError> {
+        match self.resolve_ty(&self.infer[expr]) {
+            ty_app!(TypeCtor::Int(int_ty)) => Ok(int_ty),
+            _ => Err(ConstEvalError::Invalid),
+        }
+    }
+
+    /// Resolves the bitness of a numeric type for the target
+    fn resolve_ty(&self, ty: &Ty) -> Ty {
+        let data_layout = self.db.target_data_layout();
+        match ty {
+            ty_app!(TypeCtor::Int(int_ty)) => {
+                Ty::simple(TypeCtor::Int(int_ty.resolve(&data_layout)))
+            }
+            ty_app!(TypeCtor::Float(float_ty)) => {
+                Ty::simple(TypeCtor::Float(float_ty.resolve(&data_layout)))
+            }
+            ty => ty.clone(),
+        }
+    }
+}
+
+/// Evaluates an arithmetic operation on two values of type `ty`
+fn eval_arith_op(
+    expr: ExprId,
+    op: ArithOp,
+    ty: &Ty,
+    lhs: ConstValue,
+    rhs: ConstValue,
+) -> Result<ConstValue, ConstEvalError> {
+    match (lhs, rhs, ty) {
+        (ConstValue::Int(lhs), ConstValue::Int(rhs), ty_app!(TypeCtor::Int(int_ty))) => {
+            eval_int_op(expr, op, *int_ty, lhs, rhs).map(ConstValue::Int)
+        }
+        (ConstValue::Float(lhs), ConstValue::Float(rhs), ty_app!(TypeCtor::Float(float_ty))) => {
+            let value = match op {
+                ArithOp::Add => lhs + rhs,
+                ArithOp::Subtract => lhs - rhs,
+                ArithOp::Multiply => lhs * rhs,
+                ArithOp::Divide => lhs / rhs,
+                ArithOp::Remainder => lhs % rhs,
+                _ => return Err(ConstEvalError::Invalid),
+            };
+            Ok(ConstValue::Float(round_float(value, *float_ty)))
+        }
+        (ConstValue::Bool(lhs), ConstValue::Bool(rhs), _) => match op {
+            ArithOp::BitAnd => Ok(ConstValue::Bool(lhs & rhs)),
+            ArithOp::BitOr => Ok(ConstValue::Bool(lhs | rhs)),
+            ArithOp::BitXor => Ok(ConstValue::Bool(lhs ^ rhs)),
+            _ => Err(ConstEvalError::Invalid),
+        },
+        _ => Err(ConstEvalError::Invalid),
+    }
+}
+
+/// Evaluates an arithmetic operation on two integers of type `int_ty`, of which the bitness has
+/// been resolved. Returns an error if the result does not fit in `int_ty`.
+fn eval_int_op(
+    expr: ExprId,
+    op: ArithOp,
+    int_ty: IntTy,
+    lhs: i128,
+    rhs: i128,
+) -> Result<i128, ConstEvalError> {
+    if rhs == 0 && matches!(op, ArithOp::Divide | ArithOp::Remainder) {
+        return Err(ConstEvalError::DivisionByZero(expr));
+    }
+
+    // Shifting by at least the number of bits of the type overflows
+    let bits = int_ty.bits();
+    let shift = if rhs >= 0 && rhs < bits as i128 {
+        Some(rhs as u32)
+    } else {
+        None
+    };
+
+    let result = if int_ty.signedness.is_signed() || bits < 128 {
+        match op {
+            ArithOp::Add => lhs.checked_add(rhs),
+            ArithOp::Subtract => lhs.checked_sub(rhs),
+            ArithOp::Multiply => lhs.checked_mul(rhs),
+            ArithOp::Divide => lhs.checked_div(rhs),
+            ArithOp::Remainder => lhs.checked_rem(rhs),
+            ArithOp::LeftShift => shift.map(|shift| truncate_int(lhs << shift, int_ty)),
+            ArithOp::RightShift => shift.map(|shift| lhs >> shift),
+            ArithOp::BitAnd => Some(lhs & rhs),
+            ArithOp::BitOr => Some(lhs | rhs),
+            ArithOp::BitXor => Some(lhs ^ rhs),
+        }
+    } else {
+        // A `u128` is stored in the bits of an `i128`
+        let (lhs, rhs) = (lhs as u128, rhs as u128);
+        let result = match op {
+            ArithOp::Add => lhs.checked_add(rhs),
+            ArithOp::Subtract => lhs.checked_sub(rhs),
+            ArithOp::Multiply => lhs.checked_mul(rhs),
+            ArithOp::Divide => lhs.checked_div(rhs),
+            ArithOp::Remainder => lhs.checked_rem(rhs),
+            ArithOp::LeftShift => shift.map(|shift| lhs << shift),
+            ArithOp::RightShift => shift.map(|shift| lhs >> shift),
+            ArithOp::BitAnd => Some(lhs & rhs),
+            ArithOp::BitOr => Some(lhs | rhs),
+            ArithOp::BitXor => Some(lhs ^ rhs),
+        };
+        result.map(|value| value as i128)
+    };
+    checked_int(expr, int_ty, result)
+}
+
+/// Returns the `value` if it fits in `int_ty`, otherwise an overflow error
+fn checked_int(expr: ExprId, int_ty: IntTy, value: Option<i128>) -> Result<i128, ConstEvalError> {
+    match value {
+        Some(value) if truncate_int(value, int_ty) == value => Ok(value),
+        _ => Err(ConstEvalError::Overflow(expr)),
+    }
+}
+
+/// Truncates the `value` to the number of bits of `int_ty`, sign-extending it if the type is
+/// signed. A `u128` is stored in the bits of an `i128`.
+fn truncate_int(value: i128, int_ty: IntTy) -> i128 {
+    let bits = int_ty.bits();
+    if bits >= 128 {
+        return value;
+    }
+    let shift = 128 - bits;
+    if int_ty.signedness.is_signed() {
+        (value << shift) >> shift
+    } else {
+        (((value << shift) as u128) >> shift) as i128
+    }
+}
+
+/// Converts an integer of type `from` to the nearest float of type `to`
+fn int_to_float(value: i128, from: IntTy, to: FloatTy) -> f64 {
+    let is_u128 = !from.signedness.is_signed() && from.bits() == 128;
+    match (to.bitness, is_u128) {
+        (FloatBitness::X32, true) => (value as u128) as f32 as f64,
+        (FloatBitness::X32, false) => value as f32 as f64,
+        (FloatBitness::X64, true) => (value as u128) as f64,
+        (FloatBitness::X64, false) => value as f64,
+    }
+}
+
+/// Converts a float to an integer of type `to`, saturating at its bounds. `NaN` converts to zero.
+fn float_to_int(value: f64, to: IntTy) -> i128 {
+    match (to.signedness.is_signed(), to.bits()) {
+        (true, 8) => value as i8 as i128,
+        (true, 16) => value as i16 as i128,
+        (true, 32) => value as i32 as i128,
+        (true, 64) => value as i64 as i128,
+        (true, _) => value as i128,
+        (false, 8) => value as u8 as i128,
+        (false, 16) => value as u16 as i128,
+        (false, 32) => value as u32 as i128,
+        (false, 64) => value as u64 as i128,
+        (false, _) => value as u128 as i128,
+    }
+}
+
+/// Rounds the `value` to the precision of a float of type `float_ty`
+fn round_float(value: f64, float_ty: FloatTy) -> f64 {
+    match float_ty.bitness {
+        FloatBitness::X32 => value as f32 as f64,
+        FloatBitness::X64 => value,
+    }
+}
+
+/// Evaluates a comparison of two values of type `ty`
+fn eval_cmp_op(
+    op: CmpOp,
+    ty: &Ty,
+    lhs: ConstValue,
+    rhs: ConstValue,
+) -> Result<ConstValue, ConstEvalError> {
+    let ordering = match (lhs, rhs, ty) {
+        (ConstValue::Int(lhs), ConstValue::Int(rhs), ty_app!(TypeCtor::Int(int_ty)))
+            if !int_ty.signedness.is_signed() =>
+        {
+            (lhs as u128).partial_cmp(&(rhs as u128))
+        }
+        (ConstValue::Int(lhs), ConstValue::Int(rhs), _) => lhs.partial_cmp(&rhs),
+        (ConstValue::Float(lhs), ConstValue::Float(rhs), _) => lhs.partial_cmp(&rhs),
+        (ConstValue::Bool(lhs), ConstValue::Bool(rhs), _) => lhs.partial_cmp(&rhs),
+        _ => return Err(ConstEvalError::Invalid),
+    };
+    let result = match op {
+        CmpOp::Eq { negated } => (ordering == Some(std::cmp::Ordering::Equal)) != negated,
+        CmpOp::Ord {
+            ordering: expected,
+            strict,
+        } => match (ordering, expected) {
+            (Some(std::cmp::Ordering::Equal), _) => !strict,
+            (Some(std::cmp::Ordering::Less), Ordering::Less)
+            | (Some(std::cmp::Ordering::Greater), Ordering::Greater) => true,
+            _ => false,
+        },
+    };
+    Ok(ConstValue::Bool(result))
+}
@@ -3,17 +3,22 @@
 use crate::ids::FunctionId;
 use crate::input::{SourceRoot, SourceRootId};
 use crate::item_tree::{self, ItemTree};
+use crate::module_tree::ModuleTree;
 use crate::name_resolution::Namespace;
 use crate::ty::lower::LowerBatchResult;
 use crate::ty::{CallableDef, FnSig, Ty, TypableDef};
 use crate::{
-    adt::{StructData, TypeAliasData},
-    code_model::{DefWithBody, FunctionData, ModuleData},
+    adt::{EnumData, StructData, TypeAliasData},
+    code_model::{
+        ConstData, DefWithBody, FunctionData, ImplData, ModuleData, StaticData, TraitData,
+    },
+    const_eval::{ConstEvalError, ConstValue},
     ids,
     line_index::LineIndex,
-    name_resolution::ModuleScope,
+    name_resolution::{ModuleImports, ModuleScope},
+    ty::method_resolution::{InherentImpls, TraitImpls},
     ty::InferenceResult,
-    AstIdMap, ExprScopes, FileId, Struct, TypeAlias,
+    AstIdMap, ControlFlowGraph, Enum, ExprScopes, FileId, Impl, Struct, TypeAlias,
 };
 use mun_syntax::{ast, Parse, SourceFile};
 use mun_target::abi;
@@ -64,7 +69,7 @@ pub trait AstDatabase: SourceDatabase {
 }
 
 /// The `InternDatabase` maps certain datastructures to ids. These ids refer to instances of
-/// concepts like a `Function`, `Struct` or `TypeAlias` in a semi-stable way.
+/// concepts like a `Function`, `Struct`, `Enum` or `TypeAlias` in a semi-stable way.
 #[salsa::query_group(InternDatabaseStorage)]
 pub trait InternDatabase: SourceDatabase {
     #[salsa::interned]
@@ -72,7 +77,17 @@ pub trait InternDatabase: SourceDatabase {
     #[salsa::interned]
     fn intern_struct(&self, loc: ids::StructLoc) -> ids::StructId;
     #[salsa::interned]
+    fn intern_enum(&self, loc: ids::EnumLoc) -> ids::EnumId;
+    #[salsa::interned]
     fn intern_type_alias(&self, loc: ids::TypeAliasLoc) -> ids::TypeAliasId;
+    #[salsa::interned]
+    fn intern_const(&self, loc: ids::ConstLoc) -> ids::ConstId;
+    #[salsa::interned]
+    fn intern_static(&self, loc: ids::StaticLoc) -> ids::StaticId;
+    #[salsa::interned]
+    fn intern_impl(&self, loc: ids::ImplLoc) -> ids::ImplId;
+    #[salsa::interned]
+    fn intern_trait(&self, loc: ids::TraitLoc) -> ids::TraitId;
 }
 
 #[salsa::query_group(DefDatabaseStorage)]
@@ -83,12 +98,31 @@ pub trait DefDatabase: InternDatabase + AstDatabase + Upcast<dyn AstDatabase> {
     #[salsa::invoke(StructData::struct_data_query)]
     fn struct_data(&self, id: ids::StructId) -> Arc<StructData>;
 
+    #[salsa::invoke(EnumData::enum_data_query)]
+    fn enum_data(&self, id: ids::EnumId) -> Arc<EnumData>;
+
     #[salsa::invoke(TypeAliasData::type_alias_data_query)]
     fn type_alias_data(&self, id: ids::TypeAliasId) -> Arc<TypeAliasData>;
 
+    #[salsa::invoke(crate::code_model::ConstData::const_data_query)]
+    fn const_data(&self, id: ids::ConstId) -> Arc<ConstData>;
+
+    #[salsa::invoke(crate::code_model::StaticData::static_data_query)]
+    fn static_data(&self, id: ids::StaticId) -> Arc<StaticData>;
+
     #[salsa::invoke(crate::FunctionData::fn_data_query)]
     fn fn_data(&self, func: FunctionId) -> Arc<FunctionData>;
 
+    #[salsa::invoke(ImplData::impl_data_query)]
+    fn impl_data(&self, id: ids::ImplId) -> Arc<ImplData>;
+
+    #[salsa::invoke(TraitData::trait_data_query)]
+    fn trait_data(&self, id: ids::TraitId) -> Arc<TraitData>;
+
+    /// Returns the tree of modules of the package in the specified source root
+    #[salsa::invoke(crate::module_tree::ModuleTree::module_tree_query)]
+    fn module_tree(&self, source_root: SourceRootId) -> Arc<ModuleTree>;
+
     /// Returns the module data of the specified file
     #[salsa::invoke(crate::code_model::ModuleData::module_data_query)]
     fn module_data(&self, file_id: FileId) -> Arc<ModuleData>;
@@ -107,18 +141,45 @@ pub trait HirDatabase: DefDatabase + Upcast<dyn DefDatabase> {
     #[salsa::invoke(ExprScopes::expr_scopes_query)]
     fn expr_scopes(&self, def: DefWithBody) -> Arc<ExprScopes>;
 
+    /// Returns the control-flow graph of the body of the specified definition
+    #[salsa::invoke(ControlFlowGraph::control_flow_graph_query)]
+    fn control_flow_graph(&self, def: DefWithBody) -> Arc<ControlFlowGraph>;
+
     #[salsa::invoke(crate::name_resolution::module_scope_query)]
     fn module_scope(&self, file_id: FileId) -> Arc<ModuleScope>;
 
+    /// Returns the names that are imported into the scope of the specified file
+    #[salsa::invoke(crate::name_resolution::ModuleImports::module_imports_query)]
+    fn module_imports(&self, file_id: FileId) -> Arc<ModuleImports>;
+
     #[salsa::invoke(crate::ty::infer_query)]
     fn infer(&self, def: DefWithBody) -> Arc<InferenceResult>;
 
+    /// Evaluates the initializer of a `const` or `static` item at compile time
+    #[salsa::invoke(crate::const_eval::const_eval_query)]
+    #[salsa::cycle(crate::const_eval::const_eval_cycle_recover)]
+    fn const_eval(&self, def: DefWithBody) -> Result<ConstValue, ConstEvalError>;
+
     #[salsa::invoke(crate::ty::lower::lower_struct_query)]
     fn lower_struct(&self, def: Struct) -> Arc<LowerBatchResult>;
 
+    #[salsa::invoke(crate::ty::lower::lower_enum_query)]
+    fn lower_enum(&self, def: Enum) -> Arc<LowerBatchResult>;
+
     #[salsa::invoke(crate::ty::lower::lower_type_alias_query)]
     fn lower_type_alias(&self, def: TypeAlias) -> Arc<LowerBatchResult>;
 
+    #[salsa::invoke(crate::ty::lower::lower_impl_query)]
+    fn lower_impl(&self, def: Impl) -> Arc<LowerBatchResult>;
+
+    /// Returns the inherent `impl` blocks declared in the specified file
+    #[salsa::invoke(InherentImpls::inherent_impls_query)]
+    fn inherent_impls(&self, file_id: FileId) -> Arc<InherentImpls>;
+
+    /// Returns the `impl` blocks of traits declared in the specified file
+    #[salsa::invoke(TraitImpls::trait_impls_query)]
+    fn trait_impls(&self, file_id: FileId) -> Arc<TraitImpls>;
+
     #[salsa::invoke(crate::ty::callable_item_sig)]
     fn callable_sig(&self, def: CallableDef) -> FnSig;
 
@@ -1,5 +1,6 @@
 use crate::adt::StructKind;
 use crate::in_file::InFile;
+use crate::lint::Lint;
 use crate::{FileId, HirDatabase, IntTy, Name, Ty};
 use mun_syntax::{ast, AstPtr, SmolStr, SyntaxNode, SyntaxNodePtr, TextRange};
 use std::{any::Any, fmt};
@@ -19,9 +20,25 @@ pub trait Diagnostic: Any + Send + Sync + fmt::Debug + 'static {
     fn highlight_range(&self) -> TextRange {
         self.source().value.range()
     }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    /// Returns the lint that this diagnostic belongs to, if its level can be configured.
+    fn lint(&self) -> Option<Lint> {
+        None
+    }
     fn as_any(&self) -> &(dyn Any + Send + 'static);
 }
 
+/// The severity of a `Diagnostic`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// The code is invalid and cannot be compiled.
+    Error,
+    /// The code can be compiled but is likely to contain a mistake, e.g. an unused variable.
+    Warning,
+}
+
 pub trait AstDiagnostic {
     type AST;
     fn ast(&self, db: &dyn HirDatabase) -> Self::AST;
@@ -137,6 +154,93 @@ impl Diagnostic for CyclicType {
     }
 }
 
+#[derive(Debug)]
+pub struct TypeArgCountMismatch {
+    pub file: FileId,
+    pub type_ref: AstPtr<ast::TypeRef>,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Diagnostic for TypeArgCountMismatch {
+    fn message(&self) -> String {
+        format!(
+            "wrong number of type arguments: expected {}, found {}",
+            self.expected, self.found
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.type_ref.syntax_node_ptr())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct CannotInferNilType {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+}
+
+impl Diagnostic for CannotInferNilType {
+    fn message(&self) -> String {
+        "cannot infer the type of `nil`, consider adding a type annotation".to_owned()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidNullableType {
+    pub file: FileId,
+    pub type_ref: AstPtr<ast::TypeRef>,
+}
+
+impl Diagnostic for InvalidNullableType {
+    fn message(&self) -> String {
+        "only `gc` structs can be nullable".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.type_ref.syntax_node_ptr())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for the length of an array type that does not refer to a constant
+/// integer, e.g. `[f32; foo]` where `foo` is a function.
+#[derive(Debug)]
+pub struct InvalidArrayLength {
+    pub file: FileId,
+    pub type_ref: AstPtr<ast::TypeRef>,
+}
+
+impl Diagnostic for InvalidArrayLength {
+    fn message(&self) -> String {
+        "the length of an array must be a non-negative constant integer".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.type_ref.syntax_node_ptr())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
 #[derive(Debug)]
 pub struct ExpectedFunction {
     pub file: FileId,
@@ -253,6 +357,73 @@ impl Diagnostic for InvalidLHS {
     }
 }
 
+/// An error that is emitted when assigning to a place that cannot be mutated, e.g. an immutable
+/// static or a field of a temporary value
+#[derive(Debug)]
+pub struct CannotAssignToImmutable {
+    pub file: FileId,
+    /// The left-hand side of the assignment
+    pub lhs: SyntaxNodePtr,
+    /// The immutable place that contains the left-hand side
+    pub place: ImmutablePlace,
+    /// Whether a field or element of the place is assigned to, rather than the place itself
+    pub is_projection: bool,
+}
+
+/// A place that cannot be assigned to
+#[derive(Debug, Clone)]
+pub enum ImmutablePlace {
+    /// A static that is not declared `mut`. Its declaration can be made mutable to allow the
+    /// assignment.
+    Static {
+        name: Name,
+        definition: InFile<AstPtr<ast::StaticDef>>,
+    },
+    /// A local binding or parameter that is not declared `mut`. The binding can be made mutable
+    /// by inserting `mut` at the start of its declaration, e.g. `a` or `self`.
+    Binding {
+        name: Name,
+        declaration: Option<InFile<SyntaxNodePtr>>,
+    },
+    /// A constant
+    Const { name: Name },
+    /// A `value` struct that is not stored in a variable, e.g. the result of a function call
+    Temporary,
+}
+
+impl Diagnostic for CannotAssignToImmutable {
+    fn message(&self) -> String {
+        match (&self.place, self.is_projection) {
+            (ImmutablePlace::Static { name, .. }, false) => {
+                format!("cannot assign to immutable static `{}`", name)
+            }
+            (ImmutablePlace::Static { name, .. }, true) => {
+                format!("cannot assign to part of immutable static `{}`", name)
+            }
+            (ImmutablePlace::Binding { name, .. }, false) => {
+                format!("cannot assign to immutable variable `{}`", name)
+            }
+            (ImmutablePlace::Binding { name, .. }, true) => {
+                format!("cannot assign to part of immutable variable `{}`", name)
+            }
+            (ImmutablePlace::Const { name }, _) => {
+                format!("cannot assign to part of constant `{}`", name)
+            }
+            (ImmutablePlace::Temporary, _) => {
+                "cannot assign to a field of a temporary value".to_string()
+            }
+        }
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.lhs)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
 #[derive(Debug)]
 pub struct MissingElseBranch {
     pub file: FileId,
@@ -359,6 +530,35 @@ impl Diagnostic for ReturnMissingExpression {
     }
 }
 
+/// An error that is emitted when the end of the body of a function that returns a value can be
+/// reached without a value being returned. Instead of the whole body, the branch through which the
+/// end of the body is reached is reported.
+#[derive(Debug)]
+pub struct MissingReturnValue {
+    pub file: FileId,
+    /// The branch or block that ends without a value
+    pub branch: SyntaxNodePtr,
+    /// True if `branch` is an `if` expression of which the missing `else` branch ends without a
+    /// value
+    pub missing_else: bool,
+    /// The return type of the function
+    pub expected: Ty,
+}
+
+impl Diagnostic for MissingReturnValue {
+    fn message(&self) -> String {
+        "not all paths return a value".to_owned()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.branch)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
 #[derive(Debug)]
 pub struct BreakOutsideLoop {
     pub file: FileId,
@@ -400,20 +600,18 @@ impl Diagnostic for BreakWithValueOutsideLoop {
 }
 
 #[derive(Debug)]
-pub struct AccessUnknownField {
+pub struct ContinueOutsideLoop {
     pub file: FileId,
-    pub expr: SyntaxNodePtr,
-    pub receiver_ty: Ty,
-    pub name: Name,
+    pub continue_expr: SyntaxNodePtr,
 }
 
-impl Diagnostic for AccessUnknownField {
+impl Diagnostic for ContinueOutsideLoop {
     fn message(&self) -> String {
-        "attempted to access a non-existent field in a struct.".to_string()
+        "`continue` outside of a loop".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        InFile::new(self.file, self.expr)
+        InFile::new(self.file, self.continue_expr)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -421,23 +619,18 @@ impl Diagnostic for AccessUnknownField {
     }
 }
 
+/// An error that is emitted for a `break` or `continue` expression with a label that is not
+/// declared by any of the loops that enclose it
 #[derive(Debug)]
-pub struct FieldCountMismatch {
+pub struct UndeclaredLabel {
     pub file: FileId,
     pub expr: SyntaxNodePtr,
-    pub expected: usize,
-    pub found: usize,
+    pub label: Name,
 }
 
-impl Diagnostic for FieldCountMismatch {
+impl Diagnostic for UndeclaredLabel {
     fn message(&self) -> String {
-        format!(
-            "this tuple struct literal has {} field{} but {} field{} supplied",
-            self.expected,
-            if self.expected == 1 { "" } else { "s" },
-            self.found,
-            if self.found == 1 { " was" } else { "s were" },
-        )
+        format!("use of undeclared label `{}`", self.label)
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
@@ -450,25 +643,19 @@ impl Diagnostic for FieldCountMismatch {
 }
 
 #[derive(Debug)]
-pub struct MissingFields {
+pub struct ExpectedRange {
     pub file: FileId,
-    pub fields: SyntaxNodePtr,
-    pub struct_ty: Ty,
-    pub field_names: Vec<Name>,
+    pub expr: SyntaxNodePtr,
+    pub found: Ty,
 }
 
-impl Diagnostic for MissingFields {
+impl Diagnostic for ExpectedRange {
     fn message(&self) -> String {
-        use std::fmt::Write;
-        let mut message = "missing record fields:\n".to_string();
-        for field in &self.field_names {
-            writeln!(message, "- {}", field).unwrap();
-        }
-        message
+        "`for` loops can only iterate over a range".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        InFile::new(self.file, self.fields)
+        InFile::new(self.file, self.expr)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -477,19 +664,15 @@ impl Diagnostic for MissingFields {
 }
 
 #[derive(Debug)]
-pub struct MismatchedStructLit {
+pub struct NonIntegerRange {
     pub file: FileId,
     pub expr: SyntaxNodePtr,
-    pub expected: StructKind,
-    pub found: StructKind,
+    pub found: Ty,
 }
 
-impl Diagnostic for MismatchedStructLit {
+impl Diagnostic for NonIntegerRange {
     fn message(&self) -> String {
-        format!(
-            "mismatched struct literal kind. expected `{}`, found `{}`",
-            self.expected, self.found
-        )
+        "the bounds of a range must be integers".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
@@ -502,38 +685,39 @@ impl Diagnostic for MismatchedStructLit {
 }
 
 #[derive(Debug)]
-pub struct NoFields {
+pub struct RangeOutsideForLoop {
     pub file: FileId,
-    pub receiver_expr: SyntaxNodePtr,
-    pub found: Ty,
+    pub expr: SyntaxNodePtr,
 }
 
-impl Diagnostic for NoFields {
+impl Diagnostic for RangeOutsideForLoop {
     fn message(&self) -> String {
-        "attempted to access a field on a primitive type.".to_string()
+        "a range can only be used as the iterable of a `for` loop".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        InFile::new(self.file, self.receiver_expr)
+        InFile::new(self.file, self.expr)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
         self
     }
 }
+
 #[derive(Debug)]
-pub struct NoSuchField {
+pub struct CannotIndex {
     pub file: FileId,
-    pub field: SyntaxNodePtr,
+    pub base_expr: SyntaxNodePtr,
+    pub found: Ty,
 }
 
-impl Diagnostic for NoSuchField {
+impl Diagnostic for CannotIndex {
     fn message(&self) -> String {
-        "no such field".to_string()
+        "only arrays can be indexed".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        InFile::new(self.file, self.field)
+        InFile::new(self.file, self.base_expr)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -542,18 +726,19 @@ impl Diagnostic for NoSuchField {
 }
 
 #[derive(Debug)]
-pub struct PossiblyUninitializedVariable {
+pub struct CannotInferTypeArgs {
     pub file: FileId,
-    pub pat: SyntaxNodePtr,
+    pub expr: SyntaxNodePtr,
 }
 
-impl Diagnostic for PossiblyUninitializedVariable {
+impl Diagnostic for CannotInferTypeArgs {
     fn message(&self) -> String {
-        "use of possibly-uninitialized variable".to_string()
+        "cannot infer the type arguments of this generic definition, consider adding a type annotation"
+            .to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        InFile::new(self.file, self.pat)
+        InFile::new(self.file, self.expr)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -562,17 +747,19 @@ impl Diagnostic for PossiblyUninitializedVariable {
 }
 
 #[derive(Debug)]
-pub struct ExternCannotHaveBody {
-    pub func: InFile<SyntaxNodePtr>,
+pub struct CannotInferParamType {
+    pub file: FileId,
+    pub pat: SyntaxNodePtr,
 }
 
-impl Diagnostic for ExternCannotHaveBody {
+impl Diagnostic for CannotInferParamType {
     fn message(&self) -> String {
-        "extern functions cannot have bodies".to_string()
+        "cannot infer the type of this closure parameter, consider adding a type annotation"
+            .to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.func
+        InFile::new(self.file, self.pat)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -581,17 +768,19 @@ impl Diagnostic for ExternCannotHaveBody {
 }
 
 #[derive(Debug)]
-pub struct ExternNonPrimitiveParam {
-    pub param: InFile<SyntaxNodePtr>,
+pub struct CannotInferArrayType {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
 }
 
-impl Diagnostic for ExternNonPrimitiveParam {
+impl Diagnostic for CannotInferArrayType {
     fn message(&self) -> String {
-        "extern functions can only have primitives as parameter- and return types".to_string()
+        "cannot infer the element type of an empty array, consider adding a type annotation"
+            .to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.param
+        InFile::new(self.file, self.expr)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -599,19 +788,21 @@ impl Diagnostic for ExternNonPrimitiveParam {
     }
 }
 
-/// An error that is emitted if a literal is too large to even parse
 #[derive(Debug)]
-pub struct IntLiteralTooLarge {
-    pub literal: InFile<AstPtr<ast::Literal>>,
+pub struct MethodNotFound {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub receiver_ty: Ty,
+    pub name: Name,
 }
 
-impl Diagnostic for IntLiteralTooLarge {
+impl Diagnostic for MethodNotFound {
     fn message(&self) -> String {
-        "int literal is too large".to_owned()
+        format!("no method named `{}` found", self.name)
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.literal.map(|ptr| ptr.into())
+        InFile::new(self.file, self.expr)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -619,20 +810,21 @@ impl Diagnostic for IntLiteralTooLarge {
     }
 }
 
-/// An error that is emitted if a literal is too large for its suffix
 #[derive(Debug)]
-pub struct LiteralOutOfRange {
-    pub literal: InFile<AstPtr<ast::Literal>>,
-    pub int_ty: IntTy,
+pub struct AccessUnknownField {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub receiver_ty: Ty,
+    pub name: Name,
 }
 
-impl Diagnostic for LiteralOutOfRange {
+impl Diagnostic for AccessUnknownField {
     fn message(&self) -> String {
-        format!("literal out of range for `{}`", self.int_ty.as_str())
+        "attempted to access a non-existent field in a struct.".to_string()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.literal.map(|ptr| ptr.into())
+        InFile::new(self.file, self.expr)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -640,20 +832,21 @@ impl Diagnostic for LiteralOutOfRange {
     }
 }
 
-/// An error that is emitted for a literal with an invalid suffix (e.g. `123_foo`)
 #[derive(Debug)]
-pub struct InvalidLiteralSuffix {
-    pub literal: InFile<AstPtr<ast::Literal>>,
-    pub suffix: SmolStr,
+pub struct NullableFieldAccess {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub receiver_ty: Ty,
 }
 
-impl Diagnostic for InvalidLiteralSuffix {
+impl Diagnostic for NullableFieldAccess {
     fn message(&self) -> String {
-        format!("invalid suffix `{}`", self.suffix)
+        "cannot access a field of a value that might be `nil`, unwrap it with `if let` first"
+            .to_string()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.literal.map(|ptr| ptr.into())
+        InFile::new(self.file, self.expr)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -661,26 +854,26 @@ impl Diagnostic for InvalidLiteralSuffix {
     }
 }
 
-/// An error that is emitted for a literal with a floating point suffix with a non 10 base (e.g.
-/// `0x123_f32`)
+/// An error that is emitted when a value is explicitly cast to a type that it cannot be converted
+/// to, e.g. `Foo { a: 1 } as i32`.
 #[derive(Debug)]
-pub struct InvalidFloatingPointLiteral {
-    pub literal: InFile<AstPtr<ast::Literal>>,
-    pub base: u32,
+pub struct InvalidCast {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub from_ty_name: String,
+    pub to_ty_name: String,
 }
 
-impl Diagnostic for InvalidFloatingPointLiteral {
+impl Diagnostic for InvalidCast {
     fn message(&self) -> String {
-        match self.base {
-            2 => "binary float literal is not supported".to_owned(),
-            8 => "octal float literal is not supported".to_owned(),
-            16 => "hexadecimal float literal is not supported".to_owned(),
-            _ => "unsupported base for floating pointer literal".to_owned(),
-        }
+        format!(
+            "casting `{}` as `{}` is invalid",
+            self.from_ty_name, self.to_ty_name
+        )
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.literal.map(|ptr| ptr.into())
+        InFile::new(self.file, self.expr)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -688,15 +881,249 @@ impl Diagnostic for InvalidFloatingPointLiteral {
     }
 }
 
-/// An error that is emitted for a malformed literal (e.g. `0b22222`)
 #[derive(Debug)]
-pub struct InvalidLiteral {
-    pub literal: InFile<AstPtr<ast::Literal>>,
-}
-
-impl Diagnostic for InvalidLiteral {
+pub struct FieldCountMismatch {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Diagnostic for FieldCountMismatch {
     fn message(&self) -> String {
-        "invalid literal value".to_owned()
+        format!(
+            "this tuple struct literal has {} field{} but {} field{} supplied",
+            self.expected,
+            if self.expected == 1 { "" } else { "s" },
+            self.found,
+            if self.found == 1 { " was" } else { "s were" },
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct MissingFields {
+    pub file: FileId,
+    pub fields: SyntaxNodePtr,
+    pub struct_ty: Ty,
+    pub field_names: Vec<Name>,
+}
+
+impl Diagnostic for MissingFields {
+    fn message(&self) -> String {
+        use std::fmt::Write;
+        let mut message = "missing record fields:\n".to_string();
+        for field in &self.field_names {
+            writeln!(message, "- {}", field).unwrap();
+        }
+        message
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.fields)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct MismatchedStructLit {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub expected: StructKind,
+    pub found: StructKind,
+}
+
+impl Diagnostic for MismatchedStructLit {
+    fn message(&self) -> String {
+        format!(
+            "mismatched struct literal kind. expected `{}`, found `{}`",
+            self.expected, self.found
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct NoFields {
+    pub file: FileId,
+    pub receiver_expr: SyntaxNodePtr,
+    pub found: Ty,
+}
+
+impl Diagnostic for NoFields {
+    fn message(&self) -> String {
+        "attempted to access a field on a primitive type.".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.receiver_expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+#[derive(Debug)]
+pub struct NoSuchField {
+    pub file: FileId,
+    pub field: SyntaxNodePtr,
+}
+
+impl Diagnostic for NoSuchField {
+    fn message(&self) -> String {
+        "no such field".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.field)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct PossiblyUninitializedVariable {
+    pub file: FileId,
+    pub pat: SyntaxNodePtr,
+}
+
+impl Diagnostic for PossiblyUninitializedVariable {
+    fn message(&self) -> String {
+        "use of possibly-uninitialized variable".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.pat)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted when the arms of a `match` expression do not cover all possible values
+/// of the matched expression.
+#[derive(Debug)]
+pub struct MissingMatchArms {
+    pub file: FileId,
+    pub match_expr: SyntaxNodePtr,
+    pub missing: Vec<String>,
+}
+
+impl Diagnostic for MissingMatchArms {
+    fn message(&self) -> String {
+        const MAX_LISTED: usize = 3;
+        let mut patterns = self
+            .missing
+            .iter()
+            .take(MAX_LISTED)
+            .map(|pat| format!("`{}`", pat))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if self.missing.len() > MAX_LISTED {
+            patterns = format!("{} and {} more", patterns, self.missing.len() - MAX_LISTED);
+        }
+        format!("non-exhaustive patterns: {} not covered", patterns)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.match_expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for a `match` arm that can never be reached because the preceding arms
+/// already cover all values it matches.
+#[derive(Debug)]
+pub struct UnreachableMatchArm {
+    pub file: FileId,
+    pub pat: SyntaxNodePtr,
+}
+
+impl Diagnostic for UnreachableMatchArm {
+    fn message(&self) -> String {
+        "unreachable pattern".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.pat)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct ExternCannotHaveBody {
+    pub func: InFile<SyntaxNodePtr>,
+}
+
+impl Diagnostic for ExternCannotHaveBody {
+    fn message(&self) -> String {
+        "extern functions cannot have bodies".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.func
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct ExternNonPrimitiveParam {
+    pub param: InFile<SyntaxNodePtr>,
+}
+
+impl Diagnostic for ExternNonPrimitiveParam {
+    fn message(&self) -> String {
+        "extern functions can only have primitives as parameter- and return types".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.param
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted if a literal is too large to even parse
+#[derive(Debug)]
+pub struct IntLiteralTooLarge {
+    pub literal: InFile<AstPtr<ast::Literal>>,
+}
+
+impl Diagnostic for IntLiteralTooLarge {
+    fn message(&self) -> String {
+        "int literal is too large".to_owned()
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
@@ -708,18 +1135,732 @@ impl Diagnostic for InvalidLiteral {
     }
 }
 
+/// An error that is emitted if a literal is too large for its suffix
 #[derive(Debug)]
-pub struct FreeTypeAliasWithoutTypeRef {
-    pub type_alias_def: InFile<SyntaxNodePtr>,
+pub struct LiteralOutOfRange {
+    pub literal: InFile<AstPtr<ast::Literal>>,
+    pub int_ty: IntTy,
 }
 
-impl Diagnostic for FreeTypeAliasWithoutTypeRef {
+impl Diagnostic for LiteralOutOfRange {
     fn message(&self) -> String {
-        "free type alias without type ref".to_string()
+        format!("literal out of range for `{}`", self.int_ty.as_str())
     }
 
     fn source(&self) -> InFile<SyntaxNodePtr> {
-        self.type_alias_def
+        self.literal.map(|ptr| ptr.into())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for a literal with an invalid suffix (e.g. `123_foo`)
+#[derive(Debug)]
+pub struct InvalidLiteralSuffix {
+    pub literal: InFile<AstPtr<ast::Literal>>,
+    pub suffix: SmolStr,
+}
+
+impl Diagnostic for InvalidLiteralSuffix {
+    fn message(&self) -> String {
+        format!("invalid suffix `{}`", self.suffix)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.literal.map(|ptr| ptr.into())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for a literal with a floating point suffix with a non 10 base (e.g.
+/// `0x123_f32`)
+#[derive(Debug)]
+pub struct InvalidFloatingPointLiteral {
+    pub literal: InFile<AstPtr<ast::Literal>>,
+    pub base: u32,
+}
+
+impl Diagnostic for InvalidFloatingPointLiteral {
+    fn message(&self) -> String {
+        match self.base {
+            2 => "binary float literal is not supported".to_owned(),
+            8 => "octal float literal is not supported".to_owned(),
+            16 => "hexadecimal float literal is not supported".to_owned(),
+            _ => "unsupported base for floating pointer literal".to_owned(),
+        }
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.literal.map(|ptr| ptr.into())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for a malformed literal (e.g. `0b22222`)
+#[derive(Debug)]
+pub struct InvalidLiteral {
+    pub literal: InFile<AstPtr<ast::Literal>>,
+}
+
+impl Diagnostic for InvalidLiteral {
+    fn message(&self) -> String {
+        "invalid literal value".to_owned()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.literal.map(|ptr| ptr.into())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct FreeTypeAliasWithoutTypeRef {
+    pub type_alias_def: InFile<SyntaxNodePtr>,
+}
+
+impl Diagnostic for FreeTypeAliasWithoutTypeRef {
+    fn message(&self) -> String {
+        "free type alias without type ref".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.type_alias_def
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for an `impl` block of a type that is not a struct or enum declared in
+/// the same module (e.g. `impl i32 {}`)
+#[derive(Debug)]
+pub struct InvalidSelfTyImpl {
+    pub impl_def: InFile<SyntaxNodePtr>,
+}
+
+impl Diagnostic for InvalidSelfTyImpl {
+    fn message(&self) -> String {
+        "inherent `impl` blocks can only be added for structs and enums declared in the same module"
+            .to_owned()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.impl_def
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct GenericSelfTyImpl {
+    pub impl_def: InFile<SyntaxNodePtr>,
+}
+
+impl Diagnostic for GenericSelfTyImpl {
+    fn message(&self) -> String {
+        "inherent `impl` blocks cannot be added for generic structs".to_owned()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.impl_def
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for a `self` parameter of a function that is not declared in an `impl`
+/// block
+#[derive(Debug)]
+pub struct SelfParamOutsideImpl {
+    pub self_param: InFile<AstPtr<ast::SelfParam>>,
+}
+
+impl Diagnostic for SelfParamOutsideImpl {
+    fn message(&self) -> String {
+        "`self` parameter is only allowed in associated functions".to_owned()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.self_param.map(|ptr| ptr.into())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for a reference to a trait that could not be resolved, e.g. the trait
+/// of an `impl` block or the bound of a generic parameter
+#[derive(Debug)]
+pub struct UnresolvedTrait {
+    pub file: FileId,
+    pub type_ref: SyntaxNodePtr,
+    pub name: String,
+}
+
+impl Diagnostic for UnresolvedTrait {
+    fn message(&self) -> String {
+        format!("cannot find trait `{}`", self.name)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.type_ref)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for an `impl` block of a trait that does not implement all the
+/// functions of the trait that have no default implementation
+#[derive(Debug)]
+pub struct MissingTraitItems {
+    pub impl_def: InFile<SyntaxNodePtr>,
+    pub names: Vec<Name>,
+}
+
+impl Diagnostic for MissingTraitItems {
+    fn message(&self) -> String {
+        let names = self
+            .names
+            .iter()
+            .map(|name| format!("`{}`", name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("not all trait items implemented, missing: {}", names)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.impl_def
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for a function in an `impl` block of a trait that is not declared in
+/// the trait
+#[derive(Debug)]
+pub struct TraitItemNotMember {
+    pub file: FileId,
+    pub function: SyntaxNodePtr,
+    pub name: Name,
+    pub trait_name: Name,
+}
+
+impl Diagnostic for TraitItemNotMember {
+    fn message(&self) -> String {
+        format!(
+            "function `{}` is not a member of trait `{}`",
+            self.name, self.trait_name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.function)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for a function in an `impl` block of a trait of which the signature
+/// differs from the declaration in the trait
+#[derive(Debug)]
+pub struct TraitItemSignatureMismatch {
+    pub file: FileId,
+    pub function: SyntaxNodePtr,
+    pub name: Name,
+    pub trait_name: Name,
+}
+
+impl Diagnostic for TraitItemSignatureMismatch {
+    fn message(&self) -> String {
+        format!(
+            "signature of function `{}` does not match its declaration in trait `{}`",
+            self.name, self.trait_name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.function)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted when a trait is implemented more than once for the same type
+#[derive(Debug)]
+pub struct ConflictingTraitImpl {
+    pub impl_def: InFile<SyntaxNodePtr>,
+    pub trait_name: Name,
+    pub ty_name: String,
+}
+
+impl Diagnostic for ConflictingTraitImpl {
+    fn message(&self) -> String {
+        format!(
+            "conflicting implementations of trait `{}` for type `{}`",
+            self.trait_name, self.ty_name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.impl_def
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted when a generic definition is used with a type argument that does not
+/// implement a trait that the corresponding generic parameter is bound by
+#[derive(Debug)]
+pub struct TraitBoundNotSatisfied {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub trait_name: Name,
+    pub ty_name: String,
+}
+
+impl Diagnostic for TraitBoundNotSatisfied {
+    fn message(&self) -> String {
+        format!(
+            "the trait `{}` is not implemented for `{}`",
+            self.trait_name, self.ty_name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for an import of which the path does not refer to an item or module
+#[derive(Debug)]
+pub struct UnresolvedImport {
+    pub file: FileId,
+    pub use_tree: SyntaxNodePtr,
+}
+
+impl Diagnostic for UnresolvedImport {
+    fn message(&self) -> String {
+        "unresolved import".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.use_tree)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for an import of an item that is private to another module
+#[derive(Debug)]
+pub struct PrivateImport {
+    pub file: FileId,
+    pub use_tree: SyntaxNodePtr,
+    pub name: Name,
+}
+
+impl Diagnostic for PrivateImport {
+    fn message(&self) -> String {
+        format!("`{}` is private", self.name)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.use_tree)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for the initializer of a `const` or `static` item that cannot be
+/// evaluated at compile time
+#[derive(Debug)]
+pub struct NonConstantInitializer {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+}
+
+impl Diagnostic for NonConstantInitializer {
+    fn message(&self) -> String {
+        "expression cannot be evaluated at compile time".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for a constant of which the initializer (indirectly) refers to itself
+#[derive(Debug)]
+pub struct CyclicConstant {
+    pub file: FileId,
+    pub name: SyntaxNodePtr,
+}
+
+impl Diagnostic for CyclicConstant {
+    fn message(&self) -> String {
+        "cycle detected when evaluating constant".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.name)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for an arithmetic expression of which the result is known at compile
+/// time to overflow its type
+#[derive(Debug)]
+pub struct ArithmeticOverflow {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+}
+
+impl Diagnostic for ArithmeticOverflow {
+    fn message(&self) -> String {
+        "attempt to compute a value that overflows its type".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for an integer division or remainder of which the divisor is known at
+/// compile time to be zero
+#[derive(Debug)]
+pub struct DivisionByZero {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+}
+
+impl Diagnostic for DivisionByZero {
+    fn message(&self) -> String {
+        "attempt to divide by zero".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for a call that exceeds the maximum number of nested `const fn` calls
+/// during compile-time evaluation
+#[derive(Debug)]
+pub struct ConstEvalRecursionLimit {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+}
+
+impl Diagnostic for ConstEvalRecursionLimit {
+    fn message(&self) -> String {
+        "reached the recursion limit while evaluating `const fn` calls".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted when a static is accessed outside of the module in which it is
+/// declared
+#[derive(Debug)]
+pub struct StaticOutsideModule {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub name: Name,
+}
+
+impl Diagnostic for StaticOutsideModule {
+    fn message(&self) -> String {
+        format!(
+            "static `{}` can only be accessed in the module in which it is declared",
+            self.name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// A warning that is emitted for a variable that is bound by a `let` but never used
+#[derive(Debug)]
+pub struct UnusedVariable {
+    pub file: FileId,
+    pub pat: SyntaxNodePtr,
+    pub name: Name,
+}
+
+impl Diagnostic for UnusedVariable {
+    fn message(&self) -> String {
+        format!("unused variable: `{}`", self.name)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.pat)
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn lint(&self) -> Option<Lint> {
+        Some(Lint::UnusedVariables)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// A warning that is emitted for a private function or struct that is never used in the module in
+/// which it is declared
+#[derive(Debug)]
+pub struct UnusedItem {
+    pub file: FileId,
+    /// The name of the item in its declaration
+    pub item: SyntaxNodePtr,
+    /// The kind of item, e.g. `function` or `struct`
+    pub kind: &'static str,
+    pub name: Name,
+}
+
+impl Diagnostic for UnusedItem {
+    fn message(&self) -> String {
+        format!("{} `{}` is never used", self.kind, self.name)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.item)
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn lint(&self) -> Option<Lint> {
+        Some(Lint::DeadCode)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// A warning that is emitted for a statement or expression that follows an expression that never
+/// returns, e.g. a `return` or `break`
+#[derive(Debug)]
+pub struct UnreachableCode {
+    pub file: FileId,
+    pub stmt: SyntaxNodePtr,
+}
+
+impl Diagnostic for UnreachableCode {
+    fn message(&self) -> String {
+        "unreachable code".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.stmt)
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn lint(&self) -> Option<Lint> {
+        Some(Lint::UnreachableCode)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// A warning that is emitted for a `loop` that is never exited, because it does not contain a
+/// reachable `break` or `return`
+#[derive(Debug)]
+pub struct InfiniteLoop {
+    pub file: FileId,
+    pub loop_expr: SyntaxNodePtr,
+}
+
+impl Diagnostic for InfiniteLoop {
+    fn message(&self) -> String {
+        "infinite loop".to_string()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.loop_expr)
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn lint(&self) -> Option<Lint> {
+        Some(Lint::InfiniteLoops)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// A warning that is emitted for a `static mut` that is never assigned to
+#[derive(Debug)]
+pub struct UnusedMut {
+    pub file: FileId,
+    /// The name of the static in its declaration
+    pub static_def: SyntaxNodePtr,
+    pub name: Name,
+}
+
+impl Diagnostic for UnusedMut {
+    fn message(&self) -> String {
+        format!(
+            "static `{}` is never assigned to and does not need to be mutable",
+            self.name
+        )
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.static_def)
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn lint(&self) -> Option<Lint> {
+        Some(Lint::UnusedMut)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// A warning that is emitted for a local binding or parameter that is declared `mut` but that is
+/// never assigned to
+#[derive(Debug)]
+pub struct UnusedMutBinding {
+    pub file: FileId,
+    /// The pattern or `self` parameter that declares the binding
+    pub binding: SyntaxNodePtr,
+    pub name: Name,
+}
+
+impl Diagnostic for UnusedMutBinding {
+    fn message(&self) -> String {
+        format!("variable `{}` does not need to be mutable", self.name)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.binding)
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn lint(&self) -> Option<Lint> {
+        Some(Lint::UnusedMut)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// A warning that is emitted for a lint attribute, e.g. `#[allow(foo)]`, that refers to a lint that
+/// does not exist
+#[derive(Debug)]
+pub struct UnknownLint {
+    pub file: FileId,
+    /// The attribute that refers to the lint
+    pub attr: AstPtr<ast::Attr>,
+    /// The range of the name of the lint in the attribute
+    pub name_range: TextRange,
+    pub name: SmolStr,
+}
+
+impl Diagnostic for UnknownLint {
+    fn message(&self) -> String {
+        format!("unknown lint: `{}`", self.name)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.attr.syntax_node_ptr())
+    }
+
+    fn highlight_range(&self) -> TextRange {
+        self.name_range
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn lint(&self) -> Option<Lint> {
+        Some(Lint::UnknownLints)
     }
 
     fn as_any(&self) -> &(dyn Any + Send + 'static) {
@@ -2,7 +2,7 @@ use crate::{
     arena::map::ArenaMap,
     arena::{Arena, Idx},
     code_model::DefWithBody,
-    FileId, HirDatabase, Name, Path,
+    name, FileId, HirDatabase, Name, Path,
 };
 
 //pub use mun_syntax::ast::PrefixOp as UnaryOp;
@@ -11,12 +11,16 @@ use crate::name::AsName;
 use crate::type_ref::{LocalTypeRefId, TypeRef, TypeRefBuilder, TypeRefMap, TypeRefSourceMap};
 use either::Either;
 pub use mun_syntax::ast::PrefixOp as UnaryOp;
+pub use mun_syntax::ast::RangeOp;
 use mun_syntax::ast::{ArgListOwner, BinOp, LoopBodyOwner, NameOwner, TypeAscriptionOwner};
 use mun_syntax::{ast, AstNode, AstPtr, SmolStr, T};
 use rustc_hash::FxHashMap;
 use std::ops::Index;
 use std::sync::Arc;
 
+pub use self::control_flow::{
+    dataflow, BasicBlock, BasicBlockData, ControlFlowGraph, Step, Terminator,
+};
 pub use self::scope::ExprScopes;
 use crate::builtin_type::{BuiltinFloat, BuiltinInt};
 use crate::diagnostics::DiagnosticSink;
@@ -25,6 +29,7 @@ use crate::resolve::Resolver;
 use std::borrow::Cow;
 use std::str::FromStr;
 
+pub(crate) mod control_flow;
 pub(crate) mod scope;
 pub(crate) mod validator;
 
@@ -185,6 +190,12 @@ pub struct RecordLitField {
     pub expr: ExprId,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MatchArm {
+    pub pat: PatId,
+    pub expr: ExprId,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Statement {
     Let {
@@ -279,13 +290,34 @@ pub enum Expr {
     },
     Break {
         expr: Option<ExprId>,
+        label: Option<Name>,
+    },
+    Continue {
+        label: Option<Name>,
     },
     Loop {
         body: ExprId,
+        label: Option<Name>,
     },
     While {
         condition: ExprId,
         body: ExprId,
+        label: Option<Name>,
+    },
+    For {
+        pat: PatId,
+        iterable: ExprId,
+        body: ExprId,
+        label: Option<Name>,
+    },
+    Match {
+        expr: ExprId,
+        arms: Vec<MatchArm>,
+    },
+    Range {
+        lhs: ExprId,
+        rhs: ExprId,
+        op: RangeOp,
     },
     RecordLit {
         type_id: LocalTypeRefId,
@@ -296,7 +328,40 @@ pub enum Expr {
         expr: ExprId,
         name: Name,
     },
+    MethodCall {
+        receiver: ExprId,
+        method_name: Name,
+        args: Vec<ExprId>,
+    },
+    Index {
+        base: ExprId,
+        index: ExprId,
+    },
+    /// An explicit conversion of a value to another builtin type, e.g. `a as f32`.
+    Cast {
+        expr: ExprId,
+        type_ref: LocalTypeRefId,
+    },
+    Array(Vec<ExprId>),
+    /// A tuple, e.g. `(a, 1.0)`. The empty tuple `()` has the empty type.
+    Tuple(Vec<ExprId>),
     Literal(Literal),
+    /// The absence of a reference to a `gc` struct, which is a value of any nullable type.
+    Nil,
+    /// A pattern condition of an `if let` or `while let`, e.g. the `let Foo::A(a) = b` in
+    /// `if let Foo::A(a) = b { a }`. Evaluates to `true` if the value of `expr` matches `pat`, in
+    /// which case the bindings of `pat` are available in the branch or loop body it guards.
+    Let {
+        pat: PatId,
+        expr: ExprId,
+    },
+    /// A closure, e.g. `|a, b: i32| a + b`. The types of the parameters and the return type are
+    /// optional and inferred from the context if omitted.
+    Lambda {
+        args: Vec<(PatId, Option<LocalTypeRefId>)>,
+        ret_type: Option<LocalTypeRefId>,
+        body: ExprId,
+    },
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -339,6 +404,41 @@ pub enum ArithOp {
     BitXor,
 }
 
+/// The behavior of an integer arithmetic method, e.g. `a.wrapping_add(b)`, when its result does not
+/// fit in the type of its operands.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowBehavior {
+    /// The result wraps around at the bounds of the type
+    Wrapping,
+    /// The result saturates at the bounds of the type
+    Saturating,
+    /// The wrapped result is returned together with whether the operation overflowed, as a tuple
+    /// `(T, bool)`
+    Checked,
+}
+
+impl ArithOp {
+    /// Returns the operation and overflow behavior of the integer arithmetic method with the
+    /// specified name, e.g. `saturating_mul`.
+    pub fn from_int_method_name(name: &Name) -> Option<(ArithOp, OverflowBehavior)> {
+        let name = name.to_string();
+        let mut parts = name.splitn(2, '_');
+        let behavior = match parts.next()? {
+            "wrapping" => OverflowBehavior::Wrapping,
+            "saturating" => OverflowBehavior::Saturating,
+            "checked" => OverflowBehavior::Checked,
+            _ => return None,
+        };
+        let op = match parts.next()? {
+            "add" => ArithOp::Add,
+            "sub" => ArithOp::Subtract,
+            "mul" => ArithOp::Multiply,
+            _ => return None,
+        };
+        Some((op, behavior))
+    }
+}
+
 impl Expr {
     pub fn walk_child_exprs(&self, mut f: impl FnMut(ExprId)) {
         match self {
@@ -369,10 +469,28 @@ impl Expr {
                 f(*lhs);
                 f(*rhs);
             }
-            Expr::Field { expr, .. } | Expr::UnaryOp { expr, .. } => {
+            Expr::MethodCall { receiver, args, .. } => {
+                f(*receiver);
+                for arg in args {
+                    f(*arg);
+                }
+            }
+            Expr::Field { expr, .. }
+            | Expr::UnaryOp { expr, .. }
+            | Expr::Let { expr, .. }
+            | Expr::Cast { expr, .. } => {
                 f(*expr);
             }
-            Expr::Literal(_) => {}
+            Expr::Index { base, index } => {
+                f(*base);
+                f(*index);
+            }
+            Expr::Array(exprs) | Expr::Tuple(exprs) => {
+                for expr in exprs {
+                    f(*expr);
+                }
+            }
+            Expr::Literal(_) | Expr::Nil | Expr::Continue { .. } => {}
             Expr::If {
                 condition,
                 then_branch,
@@ -389,18 +507,34 @@ impl Expr {
                     f(*expr);
                 }
             }
-            Expr::Break { expr } => {
+            Expr::Break { expr, .. } => {
                 if let Some(expr) = expr {
                     f(*expr);
                 }
             }
-            Expr::Loop { body } => {
+            Expr::Loop { body, .. } | Expr::Lambda { body, .. } => {
                 f(*body);
             }
-            Expr::While { condition, body } => {
+            Expr::While {
+                condition, body, ..
+            } => {
                 f(*condition);
                 f(*body);
             }
+            Expr::For { iterable, body, .. } => {
+                f(*iterable);
+                f(*body);
+            }
+            Expr::Match { expr, arms } => {
+                f(*expr);
+                for arm in arms {
+                    f(arm.expr);
+                }
+            }
+            Expr::Range { lhs, rhs, .. } => {
+                f(*lhs);
+                f(*rhs);
+            }
             Expr::RecordLit { fields, spread, .. } => {
                 for field in fields {
                     f(field.expr);
@@ -416,14 +550,24 @@ impl Expr {
 /// Similar to `ast::PatKind`
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Pat {
-    Missing,             // Indicates an error
-    Wild,                // `_`
-    Path(Path),          // E.g. `foo::bar`
-    Bind { name: Name }, // E.g. `a`
+    Missing,                                      // Indicates an error
+    Wild,                                         // `_`
+    Path(Path),                                   // E.g. `foo::bar`
+    TupleStruct { path: Path, args: Vec<PatId> }, // E.g. `Foo::Bar(a, _)`
+    Tuple(Vec<PatId>),                            // E.g. `(a, _)`
+    Lit(ExprId),                                  // E.g. `1`, `-1` or `true`
+    Bind { name: Name, is_mut: bool },            // E.g. `a` or `mut a`
 }
 
 impl Pat {
-    pub fn walk_child_pats(&self, mut _f: impl FnMut(PatId)) {}
+    pub fn walk_child_pats(&self, mut f: impl FnMut(PatId)) {
+        match self {
+            Pat::TupleStruct { args, .. } | Pat::Tuple(args) => {
+                args.iter().copied().for_each(|pat| f(pat))
+            }
+            Pat::Missing | Pat::Wild | Pat::Path(_) | Pat::Lit(_) | Pat::Bind { .. } => {}
+        }
+    }
 }
 
 // Queries
@@ -494,6 +638,20 @@ impl<'a> ExprCollector<'a> {
 
     fn collect_fn_body(&mut self, node: &ast::FunctionDef) {
         if let Some(param_list) = node.param_list() {
+            let has_self_param = match self.owner {
+                DefWithBody::Function(f) => f.data(self.db).has_self_param(),
+                DefWithBody::Const(_) | DefWithBody::Static(_) => false,
+            };
+            if has_self_param {
+                let is_mut = param_list.self_param().map_or(false, |it| it.is_mut());
+                let self_pat = self.pats.alloc(Pat::Bind {
+                    name: name![self],
+                    is_mut,
+                });
+                let self_type = self.type_ref_builder.self_type();
+                self.params.push((self_pat, self_type));
+            }
+
             for param in param_list.params() {
                 let pat = if let Some(pat) = param.pat() {
                     pat
@@ -519,6 +677,14 @@ impl<'a> ExprCollector<'a> {
         self.ret_type = Some(ret_type);
     }
 
+    /// Collects the initializer of a `const` or `static` item, which is treated as a body without
+    /// parameters that returns the declared type of the item.
+    fn collect_initializer(&mut self, body: Option<ast::Expr>, type_ref: Option<ast::TypeRef>) {
+        let body = self.collect_expr_opt(body);
+        self.body_expr = Some(body);
+        self.ret_type = Some(self.type_ref_builder.alloc_from_node_opt(type_ref.as_ref()));
+    }
+
     fn collect_block_opt(&mut self, block: Option<ast::BlockExpr>) -> ExprId {
         if let Some(block) = block {
             self.collect_block(block)
@@ -574,8 +740,11 @@ impl<'a> ExprCollector<'a> {
         match expr.kind() {
             ast::ExprKind::LoopExpr(expr) => self.collect_loop(expr),
             ast::ExprKind::WhileExpr(expr) => self.collect_while(expr),
+            ast::ExprKind::ForExpr(expr) => self.collect_for(expr),
+            ast::ExprKind::MatchExpr(expr) => self.collect_match(expr),
             ast::ExprKind::ReturnExpr(r) => self.collect_return(r),
             ast::ExprKind::BreakExpr(r) => self.collect_break(r),
+            ast::ExprKind::ContinueExpr(r) => self.collect_continue(r),
             ast::ExprKind::BlockExpr(b) => self.collect_block(b),
             ast::ExprKind::Literal(e) => match e.kind() {
                 ast::LiteralKind::Bool => {
@@ -607,8 +776,16 @@ impl<'a> ExprCollector<'a> {
                     expr_id
                 }
                 ast::LiteralKind::String => {
-                    let lit = Literal::String(Default::default());
-                    self.alloc_expr(Expr::Literal(lit), syntax_ptr)
+                    let (text, _) = e.text_and_suffix();
+                    let (lit, errors) = string_lit(&text);
+                    let expr_id = self.alloc_expr(Expr::Literal(lit), syntax_ptr);
+
+                    for err in errors {
+                        self.diagnostics
+                            .push(ExprDiagnostic::LiteralError { expr: expr_id, err })
+                    }
+
+                    expr_id
                 }
             },
             ast::ExprKind::PrefixExpr(e) => {
@@ -821,6 +998,15 @@ impl<'a> ExprCollector<'a> {
                 self.source_map.expr_map.insert(src, inner);
                 inner
             }
+            ast::ExprKind::RangeExpr(e) => {
+                let lhs = self.collect_expr_opt(e.start());
+                let rhs = self.collect_expr_opt(e.end());
+                if let Some(op) = e.op_kind() {
+                    self.alloc_expr(Expr::Range { lhs, rhs, op }, syntax_ptr)
+                } else {
+                    self.alloc_expr(Expr::Missing, syntax_ptr)
+                }
+            }
             ast::ExprKind::CallExpr(e) => {
                 let callee = self.collect_expr_opt(e.expr());
                 let args = if let Some(arg_list) = e.arg_list() {
@@ -830,6 +1016,70 @@ impl<'a> ExprCollector<'a> {
                 };
                 self.alloc_expr(Expr::Call { callee, args }, syntax_ptr)
             }
+            ast::ExprKind::MethodCallExpr(e) => {
+                let receiver = self.collect_expr_opt(e.expr());
+                let args = if let Some(arg_list) = e.arg_list() {
+                    arg_list.args().map(|e| self.collect_expr(e)).collect()
+                } else {
+                    Vec::new()
+                };
+                let method_name = e
+                    .name_ref()
+                    .map(|nr| nr.as_name())
+                    .unwrap_or_else(Name::missing);
+                self.alloc_expr(
+                    Expr::MethodCall {
+                        receiver,
+                        method_name,
+                        args,
+                    },
+                    syntax_ptr,
+                )
+            }
+            ast::ExprKind::IndexExpr(e) => {
+                let base = self.collect_expr_opt(e.base());
+                let index = self.collect_expr_opt(e.index());
+                self.alloc_expr(Expr::Index { base, index }, syntax_ptr)
+            }
+            ast::ExprKind::ArrayExpr(e) => {
+                let exprs = e.exprs().map(|e| self.collect_expr(e)).collect();
+                self.alloc_expr(Expr::Array(exprs), syntax_ptr)
+            }
+            ast::ExprKind::TupleExpr(e) => {
+                let exprs = e.exprs().map(|e| self.collect_expr(e)).collect();
+                self.alloc_expr(Expr::Tuple(exprs), syntax_ptr)
+            }
+            ast::ExprKind::NilExpr(_) => self.alloc_expr(Expr::Nil, syntax_ptr),
+            ast::ExprKind::CastExpr(e) => {
+                let expr = self.collect_expr_opt(e.expr());
+                let type_ref = self
+                    .type_ref_builder
+                    .alloc_from_node_opt(e.type_ref().as_ref());
+                self.alloc_expr(Expr::Cast { expr, type_ref }, syntax_ptr)
+            }
+            ast::ExprKind::LambdaExpr(e) => {
+                let mut args = Vec::new();
+                for param in e.param_list().into_iter().flat_map(|list| list.params()) {
+                    let pat = self.collect_pat_opt(param.pat());
+                    let type_ref = param
+                        .ascribed_type()
+                        .map(|t| self.type_ref_builder.alloc_from_node(&t));
+                    args.push((pat, type_ref));
+                }
+                let ret_type = e
+                    .ret_type()
+                    .and_then(|rt| rt.type_ref())
+                    .map(|t| self.type_ref_builder.alloc_from_node(&t));
+                let body = self.collect_expr_opt(e.body());
+                self.alloc_expr(
+                    Expr::Lambda {
+                        args,
+                        ret_type,
+                        body,
+                    },
+                    syntax_ptr,
+                )
+            }
         }
     }
 
@@ -844,7 +1094,11 @@ impl<'a> ExprCollector<'a> {
     fn collect_condition(&mut self, cond: ast::Condition) -> ExprId {
         match cond.pat() {
             None => self.collect_expr_opt(cond.expr()),
-            _ => unreachable!("patterns in conditions are not yet supported"),
+            Some(pat) => {
+                let pat = self.collect_pat(pat);
+                let expr = self.collect_expr_opt(cond.expr());
+                self.exprs.alloc(Expr::Let { pat, expr })
+            }
         }
     }
 
@@ -855,9 +1109,43 @@ impl<'a> ExprCollector<'a> {
                     .name()
                     .map(|nr| nr.as_name())
                     .unwrap_or_else(Name::missing);
-                Pat::Bind { name }
+                Pat::Bind {
+                    name,
+                    is_mut: bp.is_mut(),
+                }
             }
             ast::PatKind::PlaceholderPat(_) => Pat::Wild,
+            ast::PatKind::PathPat(p) => p
+                .path()
+                .and_then(Path::from_ast)
+                .map(Pat::Path)
+                .unwrap_or(Pat::Missing),
+            ast::PatKind::TupleStructPat(p) => {
+                let args = p.args().map(|p| self.collect_pat(p)).collect();
+                match p.path().and_then(Path::from_ast) {
+                    Some(path) => Pat::TupleStruct { path, args },
+                    None => Pat::Missing,
+                }
+            }
+            ast::PatKind::TuplePat(p) => {
+                Pat::Tuple(p.args().map(|p| self.collect_pat(p)).collect())
+            }
+            ast::PatKind::ParenPat(p) => {
+                let inner = self.collect_pat_opt(p.pat());
+                // make the paren pattern point to the inner pattern as well
+                self.source_map.pat_map.insert(AstPtr::new(&pat), inner);
+                return inner;
+            }
+            ast::PatKind::LiteralPat(p) => {
+                let mut expr = self.collect_expr_opt(p.literal().map(ast::Expr::from));
+                if p.is_negated() {
+                    expr = self.exprs.alloc(Expr::UnaryOp {
+                        expr,
+                        op: UnaryOp::Neg,
+                    });
+                }
+                Pat::Lit(expr)
+            }
         };
         let ptr = AstPtr::new(&pat);
         self.alloc_pat(pattern, ptr)
@@ -871,21 +1159,84 @@ impl<'a> ExprCollector<'a> {
 
     fn collect_break(&mut self, expr: ast::BreakExpr) -> ExprId {
         let syntax_node_ptr = AstPtr::new(&expr.clone().into());
+        let label = expr.label_ident_token().map(|label| label.text().as_name());
         let expr = expr.expr().map(|e| self.collect_expr(e));
-        self.alloc_expr(Expr::Break { expr }, syntax_node_ptr)
+        self.alloc_expr(Expr::Break { expr, label }, syntax_node_ptr)
+    }
+
+    fn collect_continue(&mut self, expr: ast::ContinueExpr) -> ExprId {
+        let syntax_node_ptr = AstPtr::new(&expr.clone().into());
+        let label = expr.label_ident_token().map(|label| label.text().as_name());
+        self.alloc_expr(Expr::Continue { label }, syntax_node_ptr)
+    }
+
+    /// Returns the name of the label of a loop, e.g. `'outer` in `'outer: loop {}`.
+    fn collect_label(&mut self, expr: &impl LoopBodyOwner) -> Option<Name> {
+        expr.label()
+            .and_then(|label| label.label_ident_token())
+            .map(|label| label.text().as_name())
     }
 
     fn collect_loop(&mut self, expr: ast::LoopExpr) -> ExprId {
         let syntax_node_ptr = AstPtr::new(&expr.clone().into());
+        let label = self.collect_label(&expr);
         let body = self.collect_block_opt(expr.loop_body());
-        self.alloc_expr(Expr::Loop { body }, syntax_node_ptr)
+        self.alloc_expr(Expr::Loop { body, label }, syntax_node_ptr)
     }
 
     fn collect_while(&mut self, expr: ast::WhileExpr) -> ExprId {
         let syntax_node_ptr = AstPtr::new(&expr.clone().into());
+        let label = self.collect_label(&expr);
         let condition = self.collect_condition_opt(expr.condition());
         let body = self.collect_block_opt(expr.loop_body());
-        self.alloc_expr(Expr::While { condition, body }, syntax_node_ptr)
+        self.alloc_expr(
+            Expr::While {
+                condition,
+                body,
+                label,
+            },
+            syntax_node_ptr,
+        )
+    }
+
+    fn collect_for(&mut self, expr: ast::ForExpr) -> ExprId {
+        let syntax_node_ptr = AstPtr::new(&expr.clone().into());
+        let label = self.collect_label(&expr);
+        let pat = self.collect_pat_opt(expr.pat());
+        let iterable = self.collect_expr_opt(expr.iterable());
+        let body = self.collect_block_opt(expr.loop_body());
+        self.alloc_expr(
+            Expr::For {
+                pat,
+                iterable,
+                body,
+                label,
+            },
+            syntax_node_ptr,
+        )
+    }
+
+    fn collect_match(&mut self, expr: ast::MatchExpr) -> ExprId {
+        let syntax_node_ptr = AstPtr::new(&expr.clone().into());
+        let scrutinee = self.collect_expr_opt(expr.expr());
+        let arms = if let Some(arm_list) = expr.match_arm_list() {
+            arm_list
+                .arms()
+                .map(|arm| MatchArm {
+                    pat: self.collect_pat_opt(arm.pat()),
+                    expr: self.collect_expr_opt(arm.expr()),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        self.alloc_expr(
+            Expr::Match {
+                expr: scrutinee,
+                arms,
+            },
+            syntax_node_ptr,
+        )
     }
 
     fn finish(mut self) -> (Body, BodySourceMap) {
@@ -919,6 +1270,16 @@ pub(crate) fn body_with_source_map_query(
             collector = ExprCollector::new(def, src.file_id, db);
             collector.collect_fn_body(&src.value)
         }
+        DefWithBody::Const(ref c) => {
+            let src = c.source(db.upcast());
+            collector = ExprCollector::new(def, src.file_id, db);
+            collector.collect_initializer(src.value.body(), src.value.ascribed_type())
+        }
+        DefWithBody::Static(ref s) => {
+            let src = s.source(db.upcast());
+            collector = ExprCollector::new(def, src.file_id, db);
+            collector.collect_initializer(src.value.body(), src.value.ascribed_type())
+        }
     }
 
     let (body, source_map) = collector.finish();
@@ -993,7 +1354,7 @@ fn filtered_float_lit(str: &str, suffix: Option<&str>, base: u32) -> (Literal, V
 }
 
 /// Parses the given string into an integer literal
-fn integer_lit(str: &str, suffix: Option<&str>) -> (Literal, Vec<LiteralError>) {
+pub(crate) fn integer_lit(str: &str, suffix: Option<&str>) -> (Literal, Vec<LiteralError>) {
     let str = strip_underscores(str);
 
     let base = match str.as_bytes() {
@@ -1048,11 +1409,60 @@ fn integer_lit(str: &str, suffix: Option<&str>) -> (Literal, Vec<LiteralError>)
     (Literal::Int(LiteralInt { kind, value }), errors)
 }
 
+/// Parses the given quoted string into a string literal, replacing all escape sequences by the
+/// characters they represent.
+pub(crate) fn string_lit(str: &str) -> (Literal, Vec<LiteralError>) {
+    let mut errors = Vec::new();
+
+    // The lexer also produces strings of which the closing quote is missing
+    let mut chars = str.chars();
+    let quote = chars.next();
+    let contents = chars.as_str();
+    let contents = match contents.chars().last() {
+        Some(c) if Some(c) == quote && !ends_with_escape(&contents[..contents.len() - 1]) => {
+            &contents[..contents.len() - 1]
+        }
+        _ => {
+            errors.push(LiteralError::LexerError);
+            contents
+        }
+    };
+
+    let mut value = String::with_capacity(contents.len());
+    let mut chars = contents.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => value.push('\n'),
+            Some('r') => value.push('\r'),
+            Some('t') => value.push('\t'),
+            Some('0') => value.push('\0'),
+            Some(c @ '\\') | Some(c @ '"') | Some(c @ '\'') => value.push(c),
+            _ => {
+                if !errors.contains(&LiteralError::LexerError) {
+                    errors.push(LiteralError::LexerError);
+                }
+            }
+        }
+    }
+
+    (Literal::String(value), errors)
+}
+
+/// Returns true if the last character of `str` is escaped by an odd number of backslashes.
+fn ends_with_escape(str: &str) -> bool {
+    str.chars().rev().take_while(|c| *c == '\\').count() % 2 == 1
+}
+
 #[cfg(test)]
 mod test {
     use crate::builtin_type::{BuiltinFloat, BuiltinInt};
     use crate::expr::{float_lit, LiteralError, LiteralFloat, LiteralFloatKind};
-    use crate::expr::{integer_lit, LiteralInt, LiteralIntKind};
+    use crate::expr::{integer_lit, string_lit, LiteralInt, LiteralIntKind};
     use crate::Literal;
     use mun_syntax::SmolStr;
 
@@ -1364,6 +1774,48 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn test_string_literals() {
+        assert_eq!(
+            string_lit(r#""Hello, world!""#),
+            (Literal::String("Hello, world!".to_owned()), vec![])
+        );
+
+        assert_eq!(
+            string_lit(r#"'single quotes'"#),
+            (Literal::String("single quotes".to_owned()), vec![])
+        );
+
+        assert_eq!(
+            string_lit(r#""a\tb\nc \"quoted\" \\""#),
+            (Literal::String("a\tb\nc \"quoted\" \\".to_owned()), vec![])
+        );
+
+        assert_eq!(
+            string_lit(r#""unknown \q escape""#),
+            (
+                Literal::String("unknown  escape".to_owned()),
+                vec![LiteralError::LexerError]
+            )
+        );
+
+        assert_eq!(
+            string_lit(r#""unterminated"#),
+            (
+                Literal::String("unterminated".to_owned()),
+                vec![LiteralError::LexerError]
+            )
+        );
+
+        assert_eq!(
+            string_lit(r#""escaped quote\""#),
+            (
+                Literal::String("escaped quote\"".to_owned()),
+                vec![LiteralError::LexerError]
+            )
+        );
+    }
 }
 
 mod diagnostics {
@@ -0,0 +1,562 @@
+//! A control-flow graph describes the order in which the expressions of a `Body` are evaluated. The
+//! expressions are grouped into basic blocks: sequences of steps that are always executed together
+//! and that end in a terminator that transfers control to other blocks. Analyses that depend on
+//! the paths through a body, e.g. whether a binding is initialized before it is used, are
+//! implemented as walks over the graph instead of over the expression tree. The `dataflow` module
+//! provides a framework for such analyses.
+
+pub mod dataflow;
+mod pretty;
+#[cfg(test)]
+mod tests;
+
+use crate::arena::{Arena, Idx};
+use crate::code_model::DefWithBody;
+use crate::expr::{BinaryOp, Body, Expr, ExprId, LogicOp, PatId, Statement};
+use crate::{HirDatabase, Name};
+use rustc_hash::FxHashMap;
+use std::ops::Index;
+use std::sync::Arc;
+
+/// The ID of a basic block in a `ControlFlowGraph`
+pub type BasicBlock = Idx<BasicBlockData>;
+
+/// The control-flow graph of a `Body`. The body of every closure in the `Body` is lowered into a
+/// separate sub-graph with its own entry block.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ControlFlowGraph {
+    blocks: Arena<BasicBlockData>,
+    entry: BasicBlock,
+    /// The entry block of the body of each closure
+    lambda_entries: FxHashMap<ExprId, BasicBlock>,
+    /// The block that is jumped back to at the end of each iteration of a loop
+    loop_headers: FxHashMap<ExprId, BasicBlock>,
+    /// The block in which execution continues after a loop, e.g. after a `break`
+    loop_exits: FxHashMap<ExprId, BasicBlock>,
+    /// The block in which the evaluation of each expression completes
+    block_by_expr: FxHashMap<ExprId, BasicBlock>,
+    /// Whether each block can be reached from the entry block
+    reachable: Vec<bool>,
+}
+
+/// A sequence of steps that are always executed in order, followed by a terminator.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BasicBlockData {
+    pub steps: Vec<Step>,
+    pub terminator: Terminator,
+}
+
+/// A single step in the execution of a basic block
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// The expression is evaluated. Its sub-expressions have been evaluated by preceding steps,
+    /// except for the left-hand side of an assignment, which is only evaluated as far as needed to
+    /// determine the place that is assigned to.
+    Eval(ExprId),
+    /// The bindings of the pattern are initialized, e.g. by a `let` statement or a match arm
+    Bind(PatId),
+}
+
+/// Describes how control leaves a basic block
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Terminator {
+    /// Execution continues in the target block
+    Goto(BasicBlock),
+    /// Execution continues in `then_block` if `condition` evaluates to `true`, and in `else_block`
+    /// otherwise.
+    Branch {
+        condition: ExprId,
+        then_block: BasicBlock,
+        else_block: BasicBlock,
+    },
+    /// Execution continues in the block of the first arm of a `match` whose pattern matches the
+    /// value of `expr`. There is a target for every arm, in order.
+    Switch {
+        expr: ExprId,
+        targets: Vec<BasicBlock>,
+    },
+    /// Execution continues in `body_block` for every element of `iterable`, and in `exit_block`
+    /// once all elements have been visited.
+    Iterate {
+        iterable: ExprId,
+        body_block: BasicBlock,
+        exit_block: BasicBlock,
+    },
+    /// Returns from the function, or from the closure, with the value of the expression. A body
+    /// that evaluates to a value also returns it with this terminator.
+    Return(Option<ExprId>),
+    /// Execution cannot continue, e.g. after a `break` outside of a loop
+    Unreachable,
+}
+
+impl Terminator {
+    /// Returns the blocks to which control can be transferred by this terminator.
+    pub fn successors(&self) -> Vec<BasicBlock> {
+        match self {
+            Terminator::Goto(target) => vec![*target],
+            Terminator::Branch {
+                then_block,
+                else_block,
+                ..
+            } => vec![*then_block, *else_block],
+            Terminator::Switch { targets, .. } => targets.clone(),
+            Terminator::Iterate {
+                body_block,
+                exit_block,
+                ..
+            } => vec![*body_block, *exit_block],
+            Terminator::Return(_) | Terminator::Unreachable => Vec::new(),
+        }
+    }
+}
+
+impl ControlFlowGraph {
+    pub(crate) fn control_flow_graph_query(
+        db: &dyn HirDatabase,
+        def: DefWithBody,
+    ) -> Arc<ControlFlowGraph> {
+        let body = db.body(def);
+        Arc::new(ControlFlowGraph::new(&body))
+    }
+
+    fn new(body: &Body) -> ControlFlowGraph {
+        let mut builder = ControlFlowBuilder {
+            body,
+            blocks: Arena::default(),
+            current: None,
+            loops: Vec::new(),
+            lambda_entries: FxHashMap::default(),
+            loop_headers: FxHashMap::default(),
+            loop_exits: FxHashMap::default(),
+            block_by_expr: FxHashMap::default(),
+        };
+        let entry =
+            builder.lower_graph(body.params().iter().map(|(pat, _)| *pat), body.body_expr());
+
+        let mut graph = ControlFlowGraph {
+            blocks: builder.blocks,
+            entry,
+            lambda_entries: builder.lambda_entries,
+            loop_headers: builder.loop_headers,
+            loop_exits: builder.loop_exits,
+            block_by_expr: builder.block_by_expr,
+            reachable: Vec::new(),
+        };
+        graph.reachable = graph.compute_reachable();
+        graph
+    }
+
+    /// Returns the block at which the execution of the body starts
+    pub fn entry(&self) -> BasicBlock {
+        self.entry
+    }
+
+    /// Returns the block at which the execution of the body of the closure `lambda` starts.
+    pub fn lambda_entry(&self, lambda: ExprId) -> Option<BasicBlock> {
+        self.lambda_entries.get(&lambda).copied()
+    }
+
+    /// Returns the block that is jumped back to at the end of every iteration of the `loop`,
+    /// `while` or `for` expression `loop_expr`.
+    pub fn loop_header(&self, loop_expr: ExprId) -> Option<BasicBlock> {
+        self.loop_headers.get(&loop_expr).copied()
+    }
+
+    /// Returns the block in which execution continues after the `loop`, `while` or `for`
+    /// expression `loop_expr`, i.e. the target of a `break` out of the loop.
+    pub fn loop_exit(&self, loop_expr: ExprId) -> Option<BasicBlock> {
+        self.loop_exits.get(&loop_expr).copied()
+    }
+
+    /// Iterates over all the blocks in the graph
+    pub fn blocks(&self) -> impl Iterator<Item = (BasicBlock, &BasicBlockData)> {
+        self.blocks.iter()
+    }
+
+    /// Returns true if there is a path from the entry block to `block`. The body of a closure is
+    /// reachable if the closure is created in a reachable block.
+    pub fn is_reachable(&self, block: BasicBlock) -> bool {
+        self.reachable[block_index(block)]
+    }
+
+    /// Returns true if the evaluation of `expr` can complete, i.e. if there is a path on which
+    /// execution continues after the expression. This is never the case for expressions like
+    /// `return` and `break`, but also not for an expression of which every path ends in such an
+    /// expression.
+    pub fn completes(&self, expr: ExprId) -> bool {
+        self.block_by_expr
+            .get(&expr)
+            .map_or(false, |block| self.is_reachable(*block))
+    }
+
+    /// Returns all the blocks that can be reached from `block`, including `block` itself. The
+    /// bodies of closures are not considered to be reachable from the blocks that create them.
+    pub fn reachable_from(&self, block: BasicBlock) -> Vec<BasicBlock> {
+        let mut visited = vec![false; self.blocks.len()];
+        let mut stack = vec![block];
+        let mut result = Vec::new();
+        while let Some(block) = stack.pop() {
+            if visited[block_index(block)] {
+                continue;
+            }
+            visited[block_index(block)] = true;
+            result.push(block);
+            stack.extend(self[block].terminator.successors());
+        }
+        result
+    }
+
+    fn compute_reachable(&self) -> Vec<bool> {
+        let mut reachable = vec![false; self.blocks.len()];
+        let mut stack = vec![self.entry];
+        while let Some(block) = stack.pop() {
+            if reachable[block_index(block)] {
+                continue;
+            }
+            reachable[block_index(block)] = true;
+
+            let data = &self[block];
+            stack.extend(data.terminator.successors());
+            stack.extend(data.steps.iter().filter_map(|step| match step {
+                Step::Eval(expr) => self.lambda_entry(*expr),
+                Step::Bind(_) => None,
+            }));
+        }
+        reachable
+    }
+}
+
+impl Index<BasicBlock> for ControlFlowGraph {
+    type Output = BasicBlockData;
+
+    fn index(&self, block: BasicBlock) -> &BasicBlockData {
+        &self.blocks[block]
+    }
+}
+
+fn block_index(block: BasicBlock) -> usize {
+    u32::from(block.into_raw()) as usize
+}
+
+/// The targets of `break` and `continue` expressions in a loop
+struct LoopScope {
+    label: Option<Name>,
+    continue_block: BasicBlock,
+    break_block: BasicBlock,
+}
+
+struct ControlFlowBuilder<'a> {
+    body: &'a Body,
+    blocks: Arena<BasicBlockData>,
+    /// The block to which steps are currently added
+    current: Option<BasicBlock>,
+    /// The loops that enclose the expression that is currently lowered, innermost last
+    loops: Vec<LoopScope>,
+    lambda_entries: FxHashMap<ExprId, BasicBlock>,
+    loop_headers: FxHashMap<ExprId, BasicBlock>,
+    loop_exits: FxHashMap<ExprId, BasicBlock>,
+    block_by_expr: FxHashMap<ExprId, BasicBlock>,
+}
+
+impl<'a> ControlFlowBuilder<'a> {
+    /// Lowers a function or closure body with the specified parameters into a new sub-graph and
+    /// returns its entry block.
+    fn lower_graph(&mut self, params: impl Iterator<Item = PatId>, root: ExprId) -> BasicBlock {
+        let outer_block = self.current;
+        let outer_loops = std::mem::take(&mut self.loops);
+
+        let entry = self.new_block();
+        self.current = Some(entry);
+        for pat in params {
+            self.push(Step::Bind(pat));
+        }
+        self.lower_expr(root);
+        self.terminate(Terminator::Return(Some(root)));
+
+        self.current = outer_block;
+        self.loops = outer_loops;
+        entry
+    }
+
+    fn new_block(&mut self) -> BasicBlock {
+        self.blocks.alloc(BasicBlockData {
+            steps: Vec::new(),
+            terminator: Terminator::Unreachable,
+        })
+    }
+
+    fn current_block(&self) -> BasicBlock {
+        self.current
+            .expect("there must be a current block while lowering")
+    }
+
+    fn push(&mut self, step: Step) {
+        let block = self.current_block();
+        if let Step::Eval(expr) = step {
+            self.block_by_expr.insert(expr, block);
+        }
+        self.blocks[block].steps.push(step);
+    }
+
+    /// Ends the current block with the specified terminator
+    fn terminate(&mut self, terminator: Terminator) {
+        let block = self.current_block();
+        self.blocks[block].terminator = terminator;
+    }
+
+    /// Ends the current block with a jump to `target` and continues in `target`.
+    fn goto_and_continue(&mut self, target: BasicBlock) {
+        self.terminate(Terminator::Goto(target));
+        self.current = Some(target);
+    }
+
+    /// Ends the current block with the specified terminator and continues in a block that has no
+    /// predecessors, because the code that follows is unreachable.
+    fn diverge(&mut self, terminator: Terminator) {
+        self.terminate(terminator);
+        self.current = Some(self.new_block());
+    }
+
+    /// Returns the loop that is targeted by a `break` or `continue` with the specified label
+    fn find_loop(&self, label: &Option<Name>) -> Option<&LoopScope> {
+        self.loops
+            .iter()
+            .rev()
+            .find(|scope| label.is_none() || scope.label == *label)
+    }
+
+    /// Marks the bindings of an `if let` or `while let` condition as initialized.
+    fn bind_condition(&mut self, condition: ExprId) {
+        if let Expr::Let { pat, .. } = &self.body[condition] {
+            self.push(Step::Bind(*pat));
+        }
+    }
+
+    fn lower_expr(&mut self, expr: ExprId) {
+        let body = self.body;
+        match &body[expr] {
+            Expr::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.lower_expr(*condition);
+                let then_block = self.new_block();
+                let join_block = self.new_block();
+                let else_block = match else_branch {
+                    Some(_) => self.new_block(),
+                    None => join_block,
+                };
+                self.terminate(Terminator::Branch {
+                    condition: *condition,
+                    then_block,
+                    else_block,
+                });
+
+                self.current = Some(then_block);
+                self.bind_condition(*condition);
+                self.lower_expr(*then_branch);
+                self.terminate(Terminator::Goto(join_block));
+
+                if let Some(else_branch) = else_branch {
+                    self.current = Some(else_block);
+                    self.lower_expr(*else_branch);
+                    self.terminate(Terminator::Goto(join_block));
+                }
+                self.current = Some(join_block);
+            }
+            Expr::BinaryOp {
+                lhs,
+                rhs,
+                op: Some(BinaryOp::LogicOp(op)),
+            } => {
+                // The right-hand side is only evaluated if the left-hand side does not determine
+                // the result
+                self.lower_expr(*lhs);
+                let rhs_block = self.new_block();
+                let join_block = self.new_block();
+                let (then_block, else_block) = match op {
+                    LogicOp::And => (rhs_block, join_block),
+                    LogicOp::Or => (join_block, rhs_block),
+                };
+                self.terminate(Terminator::Branch {
+                    condition: *lhs,
+                    then_block,
+                    else_block,
+                });
+
+                self.current = Some(rhs_block);
+                self.lower_expr(*rhs);
+                self.goto_and_continue(join_block);
+            }
+            Expr::BinaryOp {
+                lhs,
+                rhs,
+                op: Some(BinaryOp::Assignment { op }),
+            } => {
+                if op.is_some() {
+                    self.lower_expr(*lhs);
+                } else {
+                    self.lower_place(*lhs);
+                }
+                self.lower_expr(*rhs);
+            }
+            Expr::Block { statements, tail } => {
+                for statement in statements.iter() {
+                    match statement {
+                        Statement::Let {
+                            pat, initializer, ..
+                        } => {
+                            if let Some(initializer) = initializer {
+                                self.lower_expr(*initializer);
+                                self.push(Step::Bind(*pat));
+                            }
+                        }
+                        Statement::Expr(expr) => self.lower_expr(*expr),
+                    }
+                }
+                if let Some(tail) = tail {
+                    self.lower_expr(*tail);
+                }
+            }
+            Expr::Return { expr } => {
+                if let Some(expr) = expr {
+                    self.lower_expr(*expr);
+                }
+                self.diverge(Terminator::Return(*expr));
+                return;
+            }
+            Expr::Break { expr, label } => {
+                if let Some(expr) = expr {
+                    self.lower_expr(*expr);
+                }
+                let terminator = match self.find_loop(label) {
+                    Some(scope) => Terminator::Goto(scope.break_block),
+                    None => Terminator::Unreachable,
+                };
+                self.diverge(terminator);
+                return;
+            }
+            Expr::Continue { label } => {
+                let terminator = match self.find_loop(label) {
+                    Some(scope) => Terminator::Goto(scope.continue_block),
+                    None => Terminator::Unreachable,
+                };
+                self.diverge(terminator);
+                return;
+            }
+            Expr::Loop { body, label } => {
+                let body_block = self.new_block();
+                let exit_block = self.new_block();
+                self.goto_and_continue(body_block);
+                self.lower_loop_body(expr, label, body_block, exit_block, *body);
+                self.current = Some(exit_block);
+            }
+            Expr::While {
+                condition,
+                body,
+                label,
+            } => {
+                let condition_block = self.new_block();
+                let body_block = self.new_block();
+                let exit_block = self.new_block();
+                self.goto_and_continue(condition_block);
+                self.lower_expr(*condition);
+                self.terminate(Terminator::Branch {
+                    condition: *condition,
+                    then_block: body_block,
+                    else_block: exit_block,
+                });
+
+                self.current = Some(body_block);
+                self.bind_condition(*condition);
+                self.lower_loop_body(expr, label, condition_block, exit_block, *body);
+                self.current = Some(exit_block);
+            }
+            Expr::For {
+                pat,
+                iterable,
+                body,
+                label,
+            } => {
+                self.lower_expr(*iterable);
+                let header_block = self.new_block();
+                let body_block = self.new_block();
+                let exit_block = self.new_block();
+                self.goto_and_continue(header_block);
+                self.terminate(Terminator::Iterate {
+                    iterable: *iterable,
+                    body_block,
+                    exit_block,
+                });
+
+                self.current = Some(body_block);
+                self.push(Step::Bind(*pat));
+                self.lower_loop_body(expr, label, header_block, exit_block, *body);
+                self.current = Some(exit_block);
+            }
+            Expr::Match {
+                expr: scrutinee,
+                arms,
+            } => {
+                self.lower_expr(*scrutinee);
+                let targets: Vec<_> = arms.iter().map(|_| self.new_block()).collect();
+                let join_block = self.new_block();
+                self.terminate(Terminator::Switch {
+                    expr: *scrutinee,
+                    targets: targets.clone(),
+                });
+
+                for (arm, target) in arms.iter().zip(targets) {
+                    self.current = Some(target);
+                    self.push(Step::Bind(arm.pat));
+                    self.lower_expr(arm.expr);
+                    self.terminate(Terminator::Goto(join_block));
+                }
+                self.current = Some(join_block);
+            }
+            Expr::Lambda { args, body, .. } => {
+                let entry = self.lower_graph(args.iter().map(|(pat, _)| *pat), *body);
+                self.lambda_entries.insert(expr, entry);
+            }
+            data => data.walk_child_exprs(|child| self.lower_expr(child)),
+        }
+        self.push(Step::Eval(expr));
+    }
+
+    /// Lowers the body of a loop that starts in the current block. At the end of the body,
+    /// execution jumps back to `header_block`.
+    fn lower_loop_body(
+        &mut self,
+        loop_expr: ExprId,
+        label: &Option<Name>,
+        header_block: BasicBlock,
+        exit_block: BasicBlock,
+        body: ExprId,
+    ) {
+        self.loop_headers.insert(loop_expr, header_block);
+        self.loop_exits.insert(loop_expr, exit_block);
+        self.loops.push(LoopScope {
+            label: label.clone(),
+            continue_block: header_block,
+            break_block: exit_block,
+        });
+        self.lower_expr(body);
+        self.terminate(Terminator::Goto(header_block));
+        self.loops.pop();
+    }
+
+    /// Lowers the left-hand side of an assignment. Only the parts of the expression that determine
+    /// the place that is assigned to are evaluated; a binding that is assigned to is not read.
+    fn lower_place(&mut self, expr: ExprId) {
+        match &self.body[expr] {
+            Expr::Path(_) => {}
+            Expr::Field { expr: base, .. } => self.lower_expr(*base),
+            Expr::Index { base, index } => {
+                self.lower_expr(*base);
+                self.lower_expr(*index);
+            }
+            _ => self.lower_expr(expr),
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! A framework for dataflow analyses over a `ControlFlowGraph`. An analysis describes a state,
+//! e.g. the set of bindings that are initialized, and how each step of a basic block changes that
+//! state. The state at the start of every block is computed by propagating the states through the
+//! graph until they no longer change.
+
+mod constant_propagation;
+mod initialization;
+mod liveness;
+
+pub use self::constant_propagation::ConstantPropagation;
+pub use self::initialization::InitializedBindings;
+pub use self::liveness::LiveBindings;
+
+use super::{BasicBlock, ControlFlowGraph, Step};
+use crate::arena::map::ArenaMap;
+use crate::expr::{resolver_for_expr, Body, Expr, ExprId, PatId, Statement};
+use crate::{HirDatabase, Resolution};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::sync::Arc;
+
+/// The direction in which the states of an analysis are propagated through the graph
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// From the entry block to the blocks that exit the body, in the order of execution
+    Forward,
+    /// From the blocks that exit the body to the entry block, against the order of execution
+    Backward,
+}
+
+/// A dataflow analysis that can be computed over a `ControlFlowGraph`
+pub trait Analysis {
+    /// The state that is tracked by the analysis
+    type Domain: Clone;
+
+    /// The direction in which the analysis is computed
+    const DIRECTION: Direction;
+
+    /// Returns the state at the start of the entry block of a forward analysis, or at the end of
+    /// every block of a backward analysis before the states of its successors are joined into it.
+    fn boundary_state(&self) -> Self::Domain;
+
+    /// Joins the state of another path into `state`. Returns true if `state` changed.
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) -> bool;
+
+    /// Applies the effect of `step` to `state`
+    fn apply_step(&self, state: &mut Self::Domain, step: Step);
+
+    /// Returns the state at the start of the body of a closure that is created when the state is
+    /// `state`. Only used by forward analyses; a backward analysis accounts for the captured
+    /// bindings when the closure is created.
+    fn closure_entry_state(&self, state: &Self::Domain) -> Self::Domain {
+        state.clone()
+    }
+}
+
+/// The result of computing an `Analysis` over a `ControlFlowGraph`
+pub struct DataflowResults<'cfg, A: Analysis> {
+    analysis: A,
+    cfg: &'cfg ControlFlowGraph,
+    /// The state at the start of each block in the direction of the analysis: before the first
+    /// step of a forward analysis, or after the terminator of a backward analysis. A forward
+    /// analysis has no state for unreachable blocks.
+    states: ArenaMap<BasicBlock, A::Domain>,
+}
+
+impl<'cfg, A: Analysis> DataflowResults<'cfg, A> {
+    /// Computes the states of the `analysis` for every block of the `cfg`
+    pub fn compute(analysis: A, cfg: &'cfg ControlFlowGraph) -> Self {
+        let mut results = DataflowResults {
+            analysis,
+            cfg,
+            states: ArenaMap::default(),
+        };
+        match A::DIRECTION {
+            Direction::Forward => results.compute_forward(),
+            Direction::Backward => results.compute_backward(),
+        }
+        results
+    }
+
+    /// Returns the analysis of which these are the results
+    pub fn analysis(&self) -> &A {
+        &self.analysis
+    }
+
+    /// Returns the state before the first step of `block`, or `None` if the block is not reached
+    /// by the analysis.
+    pub fn state_on_entry(&self, block: BasicBlock) -> Option<A::Domain> {
+        match A::DIRECTION {
+            Direction::Forward => self.states.get(block).cloned(),
+            Direction::Backward => self.apply_block(block),
+        }
+    }
+
+    /// Returns the state after the last step of `block`, or `None` if the block is not reached by
+    /// the analysis.
+    pub fn state_on_exit(&self, block: BasicBlock) -> Option<A::Domain> {
+        match A::DIRECTION {
+            Direction::Forward => self.apply_block(block),
+            Direction::Backward => self.states.get(block).cloned(),
+        }
+    }
+
+    /// Visits the steps of `block` in the direction of the analysis. Every step is passed together
+    /// with the state before its effect is applied: the state before the step of a forward
+    /// analysis, or the state after the step of a backward analysis.
+    pub fn visit_steps(&self, block: BasicBlock, mut f: impl FnMut(Step, &A::Domain)) {
+        let mut state = match self.states.get(block) {
+            Some(state) => state.clone(),
+            None => return,
+        };
+        let steps = &self.cfg[block].steps;
+        let mut visit = |step: &Step| {
+            f(*step, &state);
+            self.analysis.apply_step(&mut state, *step);
+        };
+        match A::DIRECTION {
+            Direction::Forward => steps.iter().for_each(&mut visit),
+            Direction::Backward => steps.iter().rev().for_each(&mut visit),
+        }
+    }
+
+    /// Applies all the steps of `block` to its state, in the direction of the analysis
+    fn apply_block(&self, block: BasicBlock) -> Option<A::Domain> {
+        let mut state = self.states.get(block)?.clone();
+        let steps = &self.cfg[block].steps;
+        match A::DIRECTION {
+            Direction::Forward => steps
+                .iter()
+                .for_each(|step| self.analysis.apply_step(&mut state, *step)),
+            Direction::Backward => steps
+                .iter()
+                .rev()
+                .for_each(|step| self.analysis.apply_step(&mut state, *step)),
+        }
+        Some(state)
+    }
+
+    /// Joins `state` into the state of `block`. Returns true if the state of the block changed.
+    fn join_into(&mut self, block: BasicBlock, state: A::Domain) -> bool {
+        match self.states.get_mut(block) {
+            Some(existing) => self.analysis.join(existing, &state),
+            None => {
+                self.states.insert(block, state);
+                true
+            }
+        }
+    }
+
+    /// Propagates the states from the entry block to its successors. The body of a closure is
+    /// entered from the block in which the closure is created.
+    fn compute_forward(&mut self) {
+        let entry = self.cfg.entry();
+        self.states.insert(entry, self.analysis.boundary_state());
+
+        let mut worklist = vec![entry];
+        while let Some(block) = worklist.pop() {
+            let mut state = self.states[block].clone();
+            let mut successors = Vec::new();
+            for step in self.cfg[block].steps.iter() {
+                self.analysis.apply_step(&mut state, *step);
+                if let Step::Eval(expr) = step {
+                    if let Some(entry) = self.cfg.lambda_entry(*expr) {
+                        successors.push((entry, self.analysis.closure_entry_state(&state)));
+                    }
+                }
+            }
+            for successor in self.cfg[block].terminator.successors() {
+                successors.push((successor, state.clone()));
+            }
+
+            for (successor, state) in successors {
+                if self.join_into(successor, state) {
+                    worklist.push(successor);
+                }
+            }
+        }
+    }
+
+    /// Propagates the states from the end of every block to its predecessors
+    fn compute_backward(&mut self) {
+        let mut predecessors: ArenaMap<BasicBlock, Vec<BasicBlock>> = ArenaMap::default();
+        for (block, data) in self.cfg.blocks() {
+            self.states.insert(block, self.analysis.boundary_state());
+            for successor in data.terminator.successors() {
+                match predecessors.get_mut(successor) {
+                    Some(blocks) => blocks.push(block),
+                    None => predecessors.insert(successor, vec![block]),
+                }
+            }
+        }
+
+        let mut worklist: Vec<BasicBlock> = self.cfg.blocks().map(|(block, _)| block).collect();
+        while let Some(block) = worklist.pop() {
+            let state = match self.apply_block(block) {
+                Some(state) => state,
+                None => continue,
+            };
+            for &predecessor in predecessors.get(block).into_iter().flatten() {
+                if self.join_into(predecessor, state.clone()) {
+                    worklist.push(predecessor);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the local binding that is accessed by each path expression that refers to one
+fn local_binding_accesses(db: &dyn HirDatabase, body: &Arc<Body>) -> FxHashMap<ExprId, PatId> {
+    body.exprs()
+        .filter_map(|(expr, data)| {
+            let path = match data {
+                Expr::Path(path) => path,
+                _ => return None,
+            };
+            let resolver = resolver_for_expr(body.clone(), db, expr);
+            // An unresolved path has already been reported by the inferencing step
+            match resolver
+                .resolve_path_without_assoc_items(db, path)
+                .take_values()?
+            {
+                Resolution::LocalBinding(pat) => Some((expr, pat)),
+                Resolution::Def(_) | Resolution::GenericParam(_) => None,
+            }
+        })
+        .collect()
+}
+
+/// Calls `f` for the pattern and all its sub-patterns
+fn walk_pat_bindings(body: &Body, pat: PatId, f: &mut impl FnMut(PatId)) {
+    f(pat);
+    body[pat].walk_child_pats(|pat| walk_pat_bindings(body, pat, f));
+}
+
+/// Collects the patterns that are declared by `expr` and its sub-expressions
+fn collect_declared_pats(body: &Body, expr: ExprId, pats: &mut FxHashSet<PatId>) {
+    let mut declare = |pat| {
+        walk_pat_bindings(body, pat, &mut |pat| {
+            pats.insert(pat);
+        })
+    };
+    match &body[expr] {
+        Expr::Block { statements, .. } => {
+            for statement in statements.iter() {
+                if let Statement::Let { pat, .. } = statement {
+                    declare(*pat);
+                }
+            }
+        }
+        Expr::Match { arms, .. } => arms.iter().for_each(|arm| declare(arm.pat)),
+        Expr::For { pat, .. } | Expr::Let { pat, .. } => declare(*pat),
+        Expr::Lambda { args, .. } => args.iter().for_each(|(pat, _)| declare(*pat)),
+        _ => {}
+    }
+    body[expr].walk_child_exprs(|child| collect_declared_pats(body, child, pats));
+}
@@ -0,0 +1,205 @@
+use super::{local_binding_accesses, walk_pat_bindings, Analysis, Direction};
+use crate::code_model::DefWithBody;
+use crate::const_eval::{ConstEvaluator, ConstValue};
+use crate::expr::{BinaryOp, Body, Expr, ExprId, PatId, Statement, Step};
+use crate::HirDatabase;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::sync::Arc;
+
+/// A forward analysis that computes the local bindings of which the value is a known constant. A
+/// binding is only constant if it has the same value on every path to a step. The values are
+/// computed by evaluating initializers and assignments with the `ConstEvaluator`.
+pub struct ConstantPropagation<'a> {
+    db: &'a dyn HirDatabase,
+    def: DefWithBody,
+    body: Arc<Body>,
+    local_bindings: FxHashMap<ExprId, PatId>,
+    /// The initializer of the pattern of each `let` statement
+    initializers: FxHashMap<PatId, ExprId>,
+    /// The bindings that are assigned to by a closure, of which the value is never known
+    assigned_in_closures: FxHashSet<PatId>,
+}
+
+impl<'a> ConstantPropagation<'a> {
+    pub fn new(db: &'a dyn HirDatabase, def: DefWithBody) -> Self {
+        let body = db.body(def);
+        let local_bindings = local_binding_accesses(db, &body);
+
+        let mut initializers = FxHashMap::default();
+        let mut lambdas = Vec::new();
+        for (expr, data) in body.exprs() {
+            match data {
+                Expr::Block { statements, .. } => {
+                    for statement in statements.iter() {
+                        if let Statement::Let {
+                            pat,
+                            initializer: Some(initializer),
+                            ..
+                        } = statement
+                        {
+                            initializers.insert(*pat, *initializer);
+                        }
+                    }
+                }
+                Expr::Lambda { .. } => lambdas.push(expr),
+                _ => {}
+            }
+        }
+
+        let mut analysis = ConstantPropagation {
+            db,
+            def,
+            body,
+            local_bindings,
+            initializers,
+            assigned_in_closures: FxHashSet::default(),
+        };
+        for lambda in lambdas {
+            analysis.collect_assigned_bindings(lambda);
+        }
+        analysis
+    }
+
+    /// Collects the bindings that are assigned to by `expr` and its sub-expressions into
+    /// `assigned_in_closures`.
+    fn collect_assigned_bindings(&mut self, expr: ExprId) {
+        let body = self.body.clone();
+        if let Expr::BinaryOp {
+            lhs,
+            op: Some(BinaryOp::Assignment { .. }),
+            ..
+        } = &body[expr]
+        {
+            if let Some(pat) = self.assigned_binding(*lhs) {
+                self.assigned_in_closures.insert(pat);
+            }
+        }
+        body[expr].walk_child_exprs(|child| self.collect_assigned_bindings(child));
+    }
+
+    /// Returns the local binding of which the value changes when `place` is assigned to, e.g. the
+    /// binding `a` for the place `a.b[1]`.
+    fn assigned_binding(&self, place: ExprId) -> Option<PatId> {
+        match &self.body[place] {
+            Expr::Field { expr, .. } | Expr::Index { base: expr, .. } => {
+                self.assigned_binding(*expr)
+            }
+            _ => self.local_bindings.get(&place).copied(),
+        }
+    }
+
+    /// Returns true if `expr` or one of its sub-expressions assigns to a place. The effects of
+    /// these assignments have already been applied by preceding steps, so the expression cannot
+    /// be evaluated again.
+    fn contains_assignment(&self, expr: ExprId) -> bool {
+        let mut found = matches!(
+            self.body[expr],
+            Expr::BinaryOp {
+                op: Some(BinaryOp::Assignment { .. }),
+                ..
+            }
+        );
+        self.body[expr].walk_child_exprs(|child| found |= self.contains_assignment(child));
+        found
+    }
+
+    /// Binds the value of the initializer of `pat`, if it is constant
+    fn bind_initializer(&self, state: &mut FxHashMap<PatId, ConstValue>, pat: PatId) {
+        let initializer = match self.initializers.get(&pat) {
+            Some(initializer) if !self.contains_assignment(*initializer) => *initializer,
+            _ => return,
+        };
+        let mut evaluator = ConstEvaluator::with_locals(self.db, self.def, state.clone());
+        let is_bound = match evaluator.eval(initializer) {
+            Ok(value) => evaluator.bind_pat(pat, value),
+            Err(_) => false,
+        };
+        if !is_bound {
+            return;
+        }
+
+        let locals = evaluator.into_locals();
+        walk_pat_bindings(&self.body, pat, &mut |pat| {
+            if let Some(value) = locals.get(&pat) {
+                if !self.assigned_in_closures.contains(&pat) {
+                    state.insert(pat, value.clone());
+                }
+            }
+        });
+    }
+
+    /// Returns the value of the binding `pat` after the evaluation of the assignment `expr`, if it
+    /// is constant.
+    fn eval_assignment(
+        &self,
+        state: &FxHashMap<PatId, ConstValue>,
+        expr: ExprId,
+        pat: PatId,
+    ) -> Option<ConstValue> {
+        let (lhs, rhs) = match &self.body[expr] {
+            Expr::BinaryOp { lhs, rhs, .. } => (*lhs, *rhs),
+            _ => return None,
+        };
+        // Assigning to a field or an element changes only part of the value
+        if self.local_bindings.get(&lhs) != Some(&pat)
+            || self.assigned_in_closures.contains(&pat)
+            || self.contains_assignment(rhs)
+        {
+            return None;
+        }
+
+        let mut evaluator = ConstEvaluator::with_locals(self.db, self.def, state.clone());
+        evaluator.eval(expr).ok()?;
+        evaluator.into_locals().remove(&pat)
+    }
+}
+
+impl<'a> Analysis for ConstantPropagation<'a> {
+    type Domain = FxHashMap<PatId, ConstValue>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary_state(&self) -> Self::Domain {
+        FxHashMap::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) -> bool {
+        let len = state.len();
+        state.retain(|pat, value| other.get(pat) == Some(value));
+        state.len() != len
+    }
+
+    fn apply_step(&self, state: &mut Self::Domain, step: Step) {
+        match step {
+            Step::Bind(pat) => {
+                walk_pat_bindings(&self.body, pat, &mut |pat| {
+                    state.remove(&pat);
+                });
+                self.bind_initializer(state, pat);
+            }
+            Step::Eval(expr) => {
+                let pat = match &self.body[expr] {
+                    Expr::BinaryOp {
+                        lhs,
+                        op: Some(BinaryOp::Assignment { .. }),
+                        ..
+                    } => match self.assigned_binding(*lhs) {
+                        Some(pat) => pat,
+                        None => return,
+                    },
+                    _ => return,
+                };
+                match self.eval_assignment(state, expr, pat) {
+                    Some(value) => state.insert(pat, value),
+                    None => state.remove(&pat),
+                };
+            }
+        }
+    }
+
+    /// A closure may be called after the bindings that it captures have been assigned to, so the
+    /// values of the captured bindings are unknown in its body.
+    fn closure_entry_state(&self, _state: &Self::Domain) -> Self::Domain {
+        FxHashMap::default()
+    }
+}
@@ -0,0 +1,81 @@
+use super::{local_binding_accesses, walk_pat_bindings, Analysis, Direction};
+use crate::code_model::DefWithBody;
+use crate::expr::{BinaryOp, Body, Expr, ExprId, PatId, Step};
+use crate::HirDatabase;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::sync::Arc;
+
+/// A forward analysis that computes the local bindings that are definitely initialized, i.e. that
+/// are bound by a pattern or assigned to on every path to a step.
+pub struct InitializedBindings {
+    body: Arc<Body>,
+    local_bindings: FxHashMap<ExprId, PatId>,
+    /// Whether a binding that is initialized on only some of the paths to a step is initialized
+    possibly: bool,
+}
+
+impl InitializedBindings {
+    pub fn new(db: &dyn HirDatabase, def: DefWithBody) -> Self {
+        let body = db.body(def);
+        let local_bindings = local_binding_accesses(db, &body);
+        InitializedBindings {
+            body,
+            local_bindings,
+            possibly: false,
+        }
+    }
+
+    /// Constructs an analysis that computes the local bindings that are possibly initialized, i.e.
+    /// that are bound by a pattern or assigned to on at least one path to a step.
+    pub fn possibly(db: &dyn HirDatabase, def: DefWithBody) -> Self {
+        InitializedBindings {
+            possibly: true,
+            ..InitializedBindings::new(db, def)
+        }
+    }
+
+    /// Returns the local binding that is read by the path expression `expr`
+    pub fn local_binding(&self, expr: ExprId) -> Option<PatId> {
+        self.local_bindings.get(&expr).copied()
+    }
+}
+
+impl Analysis for InitializedBindings {
+    type Domain = FxHashSet<PatId>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary_state(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) -> bool {
+        let len = state.len();
+        if self.possibly {
+            state.extend(other.iter().copied());
+        } else {
+            state.retain(|pat| other.contains(pat));
+        }
+        state.len() != len
+    }
+
+    fn apply_step(&self, state: &mut Self::Domain, step: Step) {
+        match step {
+            Step::Bind(pat) => walk_pat_bindings(&self.body, pat, &mut |pat| {
+                state.insert(pat);
+            }),
+            Step::Eval(expr) => {
+                if let Expr::BinaryOp {
+                    lhs,
+                    op: Some(BinaryOp::Assignment { op: None }),
+                    ..
+                } = &self.body[expr]
+                {
+                    if let Some(pat) = self.local_binding(*lhs) {
+                        state.insert(pat);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::{
+    collect_declared_pats, local_binding_accesses, walk_pat_bindings, Analysis, Direction,
+};
+use crate::code_model::DefWithBody;
+use crate::expr::{BinaryOp, Body, Expr, ExprId, PatId, Step};
+use crate::HirDatabase;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::sync::Arc;
+
+/// A backward analysis that computes the local bindings that are live, i.e. of which the current
+/// value may still be read after a step.
+pub struct LiveBindings {
+    body: Arc<Body>,
+    local_bindings: FxHashMap<ExprId, PatId>,
+    /// The bindings of the enclosing body that are read by each closure. They are live when the
+    /// closure is created.
+    captured_bindings: FxHashMap<ExprId, FxHashSet<PatId>>,
+}
+
+impl LiveBindings {
+    pub fn new(db: &dyn HirDatabase, def: DefWithBody) -> Self {
+        let body = db.body(def);
+        let local_bindings = local_binding_accesses(db, &body);
+        let captured_bindings = body
+            .exprs()
+            .filter(|(_, data)| matches!(data, Expr::Lambda { .. }))
+            .map(|(lambda, _)| {
+                let mut declared = FxHashSet::default();
+                collect_declared_pats(&body, lambda, &mut declared);
+
+                let mut captured = FxHashSet::default();
+                collect_binding_reads(&body, &local_bindings, lambda, &mut captured);
+                captured.retain(|pat| !declared.contains(pat));
+                (lambda, captured)
+            })
+            .collect();
+
+        LiveBindings {
+            body,
+            local_bindings,
+            captured_bindings,
+        }
+    }
+}
+
+impl Analysis for LiveBindings {
+    type Domain = FxHashSet<PatId>;
+
+    const DIRECTION: Direction = Direction::Backward;
+
+    fn boundary_state(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) -> bool {
+        let len = state.len();
+        state.extend(other.iter().copied());
+        state.len() != len
+    }
+
+    fn apply_step(&self, state: &mut Self::Domain, step: Step) {
+        match step {
+            Step::Bind(pat) => walk_pat_bindings(&self.body, pat, &mut |pat| {
+                state.remove(&pat);
+            }),
+            Step::Eval(expr) => {
+                // The value that is read by a compound assignment is read by a preceding step
+                if let Expr::BinaryOp {
+                    lhs,
+                    op: Some(BinaryOp::Assignment { .. }),
+                    ..
+                } = &self.body[expr]
+                {
+                    if let Some(pat) = self.local_bindings.get(lhs) {
+                        state.remove(pat);
+                    }
+                }
+                if let Some(pat) = self.local_bindings.get(&expr) {
+                    state.insert(*pat);
+                }
+                if let Some(captured) = self.captured_bindings.get(&expr) {
+                    state.extend(captured.iter().copied());
+                }
+            }
+        }
+    }
+}
+
+/// Collects the local bindings that are read by `expr` and its sub-expressions. The binding that
+/// is assigned to by an assignment is not read.
+fn collect_binding_reads(
+    body: &Body,
+    local_bindings: &FxHashMap<ExprId, PatId>,
+    expr: ExprId,
+    reads: &mut FxHashSet<PatId>,
+) {
+    if let Some(pat) = local_bindings.get(&expr) {
+        reads.insert(*pat);
+    }
+    match &body[expr] {
+        Expr::BinaryOp {
+            lhs,
+            rhs,
+            op: Some(BinaryOp::Assignment { op: None }),
+        } if local_bindings.contains_key(lhs) => {
+            collect_binding_reads(body, local_bindings, *rhs, reads)
+        }
+        data => {
+            data.walk_child_exprs(|child| collect_binding_reads(body, local_bindings, child, reads))
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use super::{BasicBlock, ControlFlowGraph, Step, Terminator};
+use crate::code_model::DefWithBody;
+use crate::expr::{BodySourceMap, ExprId, PatId};
+use crate::HirDatabase;
+use mun_syntax::AstNode;
+use rustc_hash::FxHashMap;
+use std::fmt::Write;
+
+/// The maximum number of characters of the source text of an expression or pattern in a dump
+const MAX_TEXT_LEN: usize = 20;
+
+impl ControlFlowGraph {
+    /// Returns a textual representation of the graph of the body of `def`, which is used to test
+    /// the lowering of bodies. Expressions and patterns are represented by their source text.
+    pub fn debug_dump(&self, db: &dyn HirDatabase, def: DefWithBody) -> String {
+        let printer = Printer {
+            db,
+            source_map: &def.body_source_map(db),
+        };
+        let closures: FxHashMap<BasicBlock, ExprId> = self
+            .lambda_entries
+            .iter()
+            .map(|(lambda, entry)| (*entry, *lambda))
+            .collect();
+
+        let mut buf = String::new();
+        for (block, data) in self.blocks() {
+            write!(buf, "{}", block_name(block)).unwrap();
+            if let Some(lambda) = closures.get(&block) {
+                write!(buf, " (closure {})", printer.expr(*lambda)).unwrap();
+            }
+            if !self.is_reachable(block) {
+                write!(buf, " (unreachable)").unwrap();
+            }
+            writeln!(buf, ":").unwrap();
+
+            for step in data.steps.iter() {
+                let step = match step {
+                    Step::Eval(expr) => format!("eval {}", printer.expr(*expr)),
+                    Step::Bind(pat) => format!("bind {}", printer.pat(*pat)),
+                };
+                writeln!(buf, "    {}", step).unwrap();
+            }
+
+            let terminator = match &data.terminator {
+                Terminator::Goto(target) => format!("goto {}", block_name(*target)),
+                Terminator::Branch {
+                    condition,
+                    then_block,
+                    else_block,
+                } => format!(
+                    "branch {} -> [{}, {}]",
+                    printer.expr(*condition),
+                    block_name(*then_block),
+                    block_name(*else_block)
+                ),
+                Terminator::Switch { expr, targets } => format!(
+                    "switch {} -> [{}]",
+                    printer.expr(*expr),
+                    targets
+                        .iter()
+                        .map(|target| block_name(*target))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                Terminator::Iterate {
+                    iterable,
+                    body_block,
+                    exit_block,
+                } => format!(
+                    "iterate {} -> [{}, {}]",
+                    printer.expr(*iterable),
+                    block_name(*body_block),
+                    block_name(*exit_block)
+                ),
+                Terminator::Return(Some(expr)) => format!("return {}", printer.expr(*expr)),
+                Terminator::Return(None) => String::from("return"),
+                Terminator::Unreachable => String::from("unreachable"),
+            };
+            writeln!(buf, "    {}", terminator).unwrap();
+        }
+        buf
+    }
+}
+
+/// Returns the name of a block in a dump, e.g. `bb3`
+pub(super) fn block_name(block: BasicBlock) -> String {
+    format!("bb{}", block.into_raw())
+}
+
+/// Prints expressions and patterns as their source text
+struct Printer<'a> {
+    db: &'a dyn HirDatabase,
+    source_map: &'a BodySourceMap,
+}
+
+impl<'a> Printer<'a> {
+    fn expr(&self, expr: ExprId) -> String {
+        let text = self.source_map.expr_syntax(expr).map(|src| {
+            let root = self.db.parse(src.file_id).syntax_node();
+            src.value
+                .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr())
+                .to_node(&root)
+                .text()
+                .to_string()
+        });
+        format_text(text)
+    }
+
+    fn pat(&self, pat: PatId) -> String {
+        let text = self.source_map.pat_syntax(pat).map(|src| {
+            let root = self.db.parse(src.file_id).syntax_node();
+            src.value.to_node(&root).syntax().text().to_string()
+        });
+        format_text(text)
+    }
+}
+
+/// Formats source text on a single line between backticks, shortening it if it is too long.
+/// Expressions that do not originate from source text are represented by `?`.
+fn format_text(text: Option<String>) -> String {
+    let text = match text {
+        Some(text) => text,
+        None => return String::from("?"),
+    };
+    let chars: Vec<char> = text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .collect();
+    if chars.len() <= MAX_TEXT_LEN {
+        return format!("`{}`", chars.into_iter().collect::<String>());
+    }
+    let prefix = (MAX_TEXT_LEN - 3) / 2;
+    let suffix = MAX_TEXT_LEN - 3 - prefix;
+    format!(
+        "`{}...{}`",
+        chars[..prefix].iter().collect::<String>(),
+        chars[chars.len() - suffix..].iter().collect::<String>()
+    )
+}
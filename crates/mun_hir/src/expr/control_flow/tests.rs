@@ -0,0 +1,221 @@
+use super::{pretty::block_name, BasicBlock};
+use crate::{
+    code_model::DefWithBody,
+    const_eval::ConstValue,
+    db::DefDatabase,
+    expr::dataflow::{
+        Analysis, ConstantPropagation, DataflowResults, InitializedBindings, LiveBindings,
+    },
+    fixture::WithFixture,
+    mock::MockDatabase,
+    HirDatabase, ModuleDef, PatId,
+};
+use mun_syntax::AstNode;
+use std::fmt::Write;
+
+#[test]
+fn test_lower_if() {
+    control_flow_snapshot(
+        r#"
+    fn abs(a: i32) -> i32 {
+        let b = if a > 0 { a } else { -a };
+        b
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_lower_loops() {
+    control_flow_snapshot(
+        r#"
+    fn count(n: i32) -> i32 {
+        let mut i = 0;
+        while i < n {
+            if i == 5 {
+                break;
+            }
+            i += 1;
+        }
+        i
+    }
+
+    fn sum(n: i32) -> i32 {
+        let mut total = 0;
+        for i in 0..n {
+            total += i;
+        }
+        total
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_lower_match_and_closures() {
+    control_flow_snapshot(
+        r#"
+    fn classify(a: i32, b: bool) -> i32 {
+        let double = |x: i32| x * 2;
+        let c = b && a > 0;
+        match a {
+            0 => 1,
+            n => double(n),
+        }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_dataflow() {
+    dataflow_snapshot(
+        r#"
+    fn branches(a: i32) -> i32 {
+        let b;
+        let mut c = 1;
+        if a > 0 {
+            b = c + 1;
+        } else {
+            b = 2;
+            c = 3;
+        }
+        b + c
+    }
+
+    fn count(n: i32) -> i32 {
+        let mut i = 0;
+        let step = 1;
+        while i < n {
+            i += step;
+        }
+        i
+    }
+
+    fn capture(a: i32) -> i32 {
+        let b = 2;
+        let add = |x: i32| x + b;
+        add(a)
+    }
+    "#,
+    )
+}
+
+/// Calls `f` with the name and the body of every function in the file
+fn for_each_function(content: &str, mut f: impl FnMut(&MockDatabase, String, DefWithBody)) {
+    let (db, file_id) = MockDatabase::with_single_file(content);
+    for item in db.module_data(file_id).definitions() {
+        if let ModuleDef::Function(func) = item {
+            f(&db, func.name(&db).to_string(), (*func).into());
+        }
+    }
+}
+
+fn control_flow_graphs(content: &str) -> String {
+    let mut graphs = String::new();
+    for_each_function(content, |db, name, def| {
+        let cfg = db.control_flow_graph(def);
+        write!(graphs, "fn {}:\n{}", name, cfg.debug_dump(db, def)).unwrap();
+    });
+    graphs
+}
+
+fn control_flow_snapshot(text: &str) {
+    let text = text.trim().replace("\n    ", "\n");
+    insta::assert_snapshot!(
+        insta::_macro_support::AutoName,
+        control_flow_graphs(&text),
+        &text
+    );
+}
+
+/// Returns the bindings that are initialized, live and constant at the start of every block
+fn dataflow(content: &str) -> String {
+    let mut states = String::new();
+    for_each_function(content, |db, name, def| {
+        let cfg = db.control_flow_graph(def);
+        let initialized = DataflowResults::compute(InitializedBindings::new(db, def), &cfg);
+        let live = DataflowResults::compute(LiveBindings::new(db, def), &cfg);
+        let constants = DataflowResults::compute(ConstantPropagation::new(db, def), &cfg);
+
+        writeln!(states, "fn {}:", name).unwrap();
+        for (block, _) in cfg.blocks() {
+            writeln!(
+                states,
+                "{}: initialized: {}, live: {}, constant: {}",
+                block_name(block),
+                format_bindings(db, def, &initialized, block),
+                format_bindings(db, def, &live, block),
+                format_constants(db, def, &constants, block),
+            )
+            .unwrap();
+        }
+    });
+    states
+}
+
+fn dataflow_snapshot(text: &str) {
+    let text = text.trim().replace("\n    ", "\n");
+    insta::assert_snapshot!(insta::_macro_support::AutoName, dataflow(&text), &text);
+}
+
+fn format_bindings<A>(
+    db: &dyn HirDatabase,
+    def: DefWithBody,
+    results: &DataflowResults<A>,
+    block: BasicBlock,
+) -> String
+where
+    A: Analysis<Domain = rustc_hash::FxHashSet<PatId>>,
+{
+    match results.state_on_entry(block) {
+        Some(pats) => format_set(pats.iter().map(|pat| pat_name(db, def, *pat)).collect()),
+        None => String::from("-"),
+    }
+}
+
+fn format_constants(
+    db: &dyn HirDatabase,
+    def: DefWithBody,
+    results: &DataflowResults<ConstantPropagation>,
+    block: BasicBlock,
+) -> String {
+    match results.state_on_entry(block) {
+        Some(values) => format_set(
+            values
+                .iter()
+                .map(|(pat, value)| {
+                    format!("{} = {}", pat_name(db, def, *pat), format_value(value))
+                })
+                .collect(),
+        ),
+        None => String::from("-"),
+    }
+}
+
+fn format_set(mut items: Vec<String>) -> String {
+    items.sort();
+    format!("{{{}}}", items.join(", "))
+}
+
+fn format_value(value: &ConstValue) -> String {
+    match value {
+        ConstValue::Bool(value) => value.to_string(),
+        ConstValue::Int(value) => value.to_string(),
+        ConstValue::Float(value) => value.to_string(),
+        ConstValue::Struct(values) => format!(
+            "({})",
+            values
+                .iter()
+                .map(format_value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn pat_name(db: &dyn HirDatabase, def: DefWithBody, pat: PatId) -> String {
+    let src = def.body_source_map(db).pat_syntax(pat).unwrap();
+    let root = db.parse(src.file_id).syntax_node();
+    src.value.to_node(&root).syntax().text().to_string()
+}
@@ -78,6 +78,39 @@ impl ExprScopes {
         &self.scope_by_expr
     }
 
+    /// Returns the local bindings that are used in the body of the closure `lambda` but that are
+    /// declared outside of it. These are the values that are captured by the closure, in order of
+    /// their first use.
+    pub fn captures(&self, lambda: ExprId) -> Vec<PatId> {
+        let outer_scopes: Vec<LocalScopeId> = self.scope_chain(self.scope_for(lambda)).collect();
+        let mut captures = Vec::new();
+        let mut stack = vec![lambda];
+        while let Some(expr) = stack.pop() {
+            let data = &self.body[expr];
+            if let Expr::Path(path) = data {
+                let binding = path.as_ident().and_then(|name| {
+                    self.scope_chain(self.scope_for(expr)).find_map(|scope| {
+                        self.entries(scope)
+                            .iter()
+                            .find(|entry| entry.name() == name)
+                            .map(|entry| (scope, entry.pat()))
+                    })
+                });
+                if let Some((scope, pat)) = binding {
+                    if outer_scopes.contains(&scope) && !captures.contains(&pat) {
+                        captures.push(pat);
+                    }
+                }
+            }
+
+            // Visit the children in source order
+            let len = stack.len();
+            data.walk_child_exprs(|child| stack.push(child));
+            stack[len..].reverse();
+        }
+        captures
+    }
+
     fn root_scope(&mut self) -> LocalScopeId {
         self.scopes.alloc(ScopeData {
             parent: None,
@@ -157,6 +190,74 @@ fn compute_expr_scopes(expr: ExprId, body: &Body, scopes: &mut ExprScopes, scope
         Expr::Block { statements, tail } => {
             compute_block_scopes(&statements, *tail, body, scopes, scope);
         }
+        Expr::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            let then_scope = compute_condition_scopes(*condition, body, scopes, scope);
+            compute_expr_scopes(*then_branch, body, scopes, then_scope);
+            if let Some(else_branch) = else_branch {
+                compute_expr_scopes(*else_branch, body, scopes, scope);
+            }
+        }
+        Expr::While {
+            condition,
+            body: body_expr,
+            ..
+        } => {
+            let body_scope = compute_condition_scopes(*condition, body, scopes, scope);
+            compute_expr_scopes(*body_expr, body, scopes, body_scope);
+        }
+        Expr::For {
+            pat,
+            iterable,
+            body: body_expr,
+            ..
+        } => {
+            compute_expr_scopes(*iterable, body, scopes, scope);
+            let scope = scopes.new_scope(scope);
+            scopes.add_bindings(body, scope, *pat);
+            compute_expr_scopes(*body_expr, body, scopes, scope);
+        }
+        Expr::Lambda {
+            args,
+            body: body_expr,
+            ..
+        } => {
+            let scope = scopes.new_scope(scope);
+            for (pat, _) in args {
+                scopes.add_bindings(body, scope, *pat);
+            }
+            compute_expr_scopes(*body_expr, body, scopes, scope);
+        }
+        Expr::Match { expr, arms } => {
+            compute_expr_scopes(*expr, body, scopes, scope);
+            for arm in arms {
+                let scope = scopes.new_scope(scope);
+                scopes.add_bindings(body, scope, arm.pat);
+                compute_expr_scopes(arm.expr, body, scopes, scope);
+            }
+        }
         e => e.walk_child_exprs(|e| compute_expr_scopes(e, body, scopes, scope)),
     };
 }
+
+/// Computes the scopes of the condition of an `if` or `while` expression and returns the scope in
+/// which the guarded branch or loop body is evaluated. For an `if let` or `while let` condition
+/// this is a new scope that contains the bindings of the pattern.
+fn compute_condition_scopes(
+    condition: ExprId,
+    body: &Body,
+    scopes: &mut ExprScopes,
+    scope: LocalScopeId,
+) -> LocalScopeId {
+    compute_expr_scopes(condition, body, scopes, scope);
+    if let Expr::Let { pat, .. } = &body[condition] {
+        let scope = scopes.new_scope(scope);
+        scopes.add_bindings(body, scope, *pat);
+        scope
+    } else {
+        scope
+    }
+}
@@ -2,86 +2,107 @@ use crate::code_model::src::HasSource;
 use crate::diagnostics::{
     ExternCannotHaveBody, ExternNonPrimitiveParam, FreeTypeAliasWithoutTypeRef,
 };
-use crate::expr::BodySourceMap;
+use crate::expr::{BodySourceMap, ControlFlowGraph};
 use crate::in_file::InFile;
 use crate::{
-    diagnostics::DiagnosticSink, Body, Expr, Function, HirDatabase, InferenceResult, TypeAlias,
+    code_model::DefWithBody, diagnostics::DiagnosticSink, Body, Expr, HirDatabase, InferenceResult,
+    Module, TypeAlias,
 };
 use mun_syntax::{AstNode, SyntaxNodePtr};
 use std::sync::Arc;
 
+mod constant_arithmetic;
+mod infinite_loops;
 mod literal_out_of_range;
+mod match_check;
+mod missing_return;
+mod mutability;
 mod uninitialized_access;
+mod unknown_lints;
+mod unreachable_code;
+mod unused_items;
+mod unused_mut;
+mod unused_variables;
 
 #[cfg(test)]
 mod tests;
 
 pub struct ExprValidator<'a> {
-    func: Function,
+    owner: DefWithBody,
     infer: Arc<InferenceResult>,
     body: Arc<Body>,
     body_source_map: Arc<BodySourceMap>,
+    cfg: Arc<ControlFlowGraph>,
     db: &'a dyn HirDatabase,
 }
 
 impl<'a> ExprValidator<'a> {
-    pub fn new(func: Function, db: &'a dyn HirDatabase) -> Self {
-        let (body, body_source_map) = db.body_with_source_map(func.into());
+    pub fn new(owner: DefWithBody, db: &'a dyn HirDatabase) -> Self {
+        let (body, body_source_map) = db.body_with_source_map(owner);
         ExprValidator {
-            func,
+            owner,
             db,
-            infer: db.infer(func.into()),
+            infer: db.infer(owner),
             body,
             body_source_map,
+            cfg: db.control_flow_graph(owner),
         }
     }
 
     pub fn validate_body(&self, sink: &mut DiagnosticSink) {
         self.validate_literal_ranges(sink);
+        self.validate_constant_arithmetic(sink);
         self.validate_uninitialized_access(sink);
+        self.validate_missing_return(sink);
+        self.validate_mutability(sink);
+        self.validate_match_exprs(sink);
         self.validate_extern(sink);
+        self.validate_unused_variables(sink);
+        self.validate_unused_mut_bindings(sink);
+        self.validate_unreachable_code(sink);
+        self.validate_infinite_loops(sink);
     }
 
     pub fn validate_extern(&self, sink: &mut DiagnosticSink) {
-        if !self.func.is_extern(self.db) {
-            return;
-        }
+        let func = match self.owner {
+            DefWithBody::Function(func) if func.is_extern(self.db) => func,
+            _ => return,
+        };
 
         // Validate that there is no body
-        match self.body[self.func.body(self.db).body_expr] {
+        match self.body[func.body(self.db).body_expr] {
             Expr::Missing => {}
             _ => sink.push(ExternCannotHaveBody {
-                func: self
-                    .func
+                func: func
                     .source(self.db.upcast())
                     .map(|f| SyntaxNodePtr::new(f.syntax())),
             }),
         }
 
-        if let Some(sig) = self.func.ty(self.db).callable_sig(self.db) {
-            let fn_data = self.func.data(self.db);
+        if let Some(sig) = func.ty(self.db).callable_sig(self.db) {
+            let fn_data = func.data(self.db);
             for (arg_ty, ty_ref) in sig.params().iter().zip(fn_data.params()) {
-                if arg_ty.as_struct().is_some() {
+                if arg_ty.as_struct().is_some() || arg_ty.as_enum().is_some() {
                     let arg_ptr = fn_data
                         .type_ref_source_map()
                         .type_ref_syntax(*ty_ref)
                         .map(|ptr| ptr.syntax_node_ptr())
                         .unwrap();
                     sink.push(ExternNonPrimitiveParam {
-                        param: InFile::new(self.func.source(self.db.upcast()).file_id, arg_ptr),
+                        param: InFile::new(func.source(self.db.upcast()).file_id, arg_ptr),
                     })
                 }
             }
 
             let return_ty = sig.ret();
-            if return_ty.as_struct().is_some() {
+            if return_ty.as_struct().is_some() || return_ty.as_enum().is_some() {
                 let arg_ptr = fn_data
                     .type_ref_source_map()
                     .type_ref_syntax(*fn_data.ret_type())
                     .map(|ptr| ptr.syntax_node_ptr())
                     .unwrap();
                 sink.push(ExternNonPrimitiveParam {
-                    param: InFile::new(self.func.source(self.db.upcast()).file_id, arg_ptr),
+                    param: InFile::new(func.source(self.db.upcast()).file_id, arg_ptr),
                 })
             }
         }
@@ -109,3 +130,36 @@ impl<'a> TypeAliasValidator<'a> {
         }
     }
 }
+
+pub struct ModuleValidator<'a> {
+    module: Module,
+    db: &'a dyn HirDatabase,
+}
+
+impl<'a> ModuleValidator<'a> {
+    /// Constructs a validator for the provided `Module`.
+    pub fn new(module: Module, db: &'a dyn HirDatabase) -> Self {
+        ModuleValidator { module, db }
+    }
+
+    /// Validates the items of the module in relation to each other, e.g. whether private items are
+    /// used by any other item, and the lint attributes of the module.
+    pub fn validate_module(&self, sink: &mut DiagnosticSink) {
+        self.validate_unused_items(sink);
+        self.validate_unused_mut(sink);
+        self.validate_unknown_lints(sink);
+    }
+
+    /// Returns all the definitions in the module that have a body.
+    fn bodies(&self) -> Vec<DefWithBody> {
+        let functions = self.module.functions(self.db);
+        let consts = self.module.consts(self.db);
+        let statics = self.module.statics(self.db);
+        functions
+            .into_iter()
+            .map(DefWithBody::from)
+            .chain(consts.into_iter().map(DefWithBody::from))
+            .chain(statics.into_iter().map(DefWithBody::from))
+            .collect()
+    }
+}
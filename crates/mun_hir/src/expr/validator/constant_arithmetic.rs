@@ -0,0 +1,59 @@
+use super::ExprValidator;
+use crate::code_model::DefWithBody;
+use crate::const_eval::{ConstEvalError, ConstEvaluator};
+use crate::diagnostics::{ArithmeticOverflow, DiagnosticSink, DivisionByZero};
+use crate::{BinaryOp, Expr, UnaryOp};
+use std::collections::HashSet;
+
+impl<'d> ExprValidator<'d> {
+    /// Evaluates the arithmetic expressions in the body of a function of which all operands are
+    /// constant, to report overflows and divisions by zero at compile time. The initializers of
+    /// `const` and `static` items are evaluated entirely, so they are reported elsewhere.
+    pub(super) fn validate_constant_arithmetic(&self, sink: &mut DiagnosticSink) {
+        if !matches!(self.owner, DefWithBody::Function(_)) {
+            return;
+        }
+
+        // An erroneous operand also makes the expressions that contain it erroneous, so only
+        // report each erroneous expression once
+        let mut reported = HashSet::new();
+        for (expr, data) in self.body.exprs() {
+            let is_arithmetic = match data {
+                Expr::BinaryOp {
+                    op: Some(BinaryOp::ArithOp(_)),
+                    ..
+                } => true,
+                Expr::UnaryOp {
+                    op: UnaryOp::Neg, ..
+                } => true,
+                _ => false,
+            };
+            if !is_arithmetic {
+                continue;
+            }
+
+            let (error_expr, is_division_by_zero) =
+                match ConstEvaluator::new(self.db, self.owner).eval(expr) {
+                    Err(ConstEvalError::Overflow(error_expr)) => (error_expr, false),
+                    Err(ConstEvalError::DivisionByZero(error_expr)) => (error_expr, true),
+                    _ => continue,
+                };
+            if !reported.insert(error_expr) {
+                continue;
+            }
+
+            let file = self.owner.module(self.db.upcast()).file_id();
+            let expr = self
+                .body_source_map
+                .expr_syntax(error_expr)
+                .unwrap()
+                .value
+                .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+            if is_division_by_zero {
+                sink.push(DivisionByZero { file, expr });
+            } else {
+                sink.push(ArithmeticOverflow { file, expr });
+            }
+        }
+    }
+}
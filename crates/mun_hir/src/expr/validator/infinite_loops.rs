@@ -0,0 +1,68 @@
+use super::ExprValidator;
+use crate::diagnostics::{DiagnosticSink, InfiniteLoop};
+use crate::expr::{Expr, ExprId, Terminator};
+use rustc_hash::FxHashMap;
+
+impl<'a> ExprValidator<'a> {
+    /// Validates that every `loop` expression can be exited by a `break` or a `return`. If a loop
+    /// is nested in another loop that is never exited, only the outer loop is reported.
+    pub(super) fn validate_infinite_loops(&self, sink: &mut DiagnosticSink) {
+        let infinite_loops: Vec<ExprId> = self
+            .body
+            .exprs()
+            .filter(|(expr, data)| {
+                matches!(data, Expr::Loop { .. }) && self.is_infinite_loop(*expr)
+            })
+            .map(|(expr, _)| expr)
+            .collect();
+        if infinite_loops.is_empty() {
+            return;
+        }
+
+        let mut parents = FxHashMap::default();
+        for (expr, data) in self.body.exprs() {
+            data.walk_child_exprs(|child| {
+                parents.insert(child, expr);
+            });
+        }
+
+        for &loop_expr in infinite_loops.iter() {
+            let mut ancestors =
+                std::iter::successors(parents.get(&loop_expr), |expr| parents.get(*expr));
+            if ancestors.any(|expr| infinite_loops.contains(expr)) {
+                continue;
+            }
+
+            if let Some(src) = self.body_source_map.expr_syntax(loop_expr) {
+                sink.push(InfiniteLoop {
+                    file: self.owner.module(self.db.upcast()).file_id(),
+                    loop_expr: src
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()),
+                })
+            }
+        }
+    }
+
+    /// Returns true if the loop `loop_expr` is reachable and repeats forever: there is a path back
+    /// to the start of the loop, but no path that exits it.
+    fn is_infinite_loop(&self, loop_expr: ExprId) -> bool {
+        let header = match self.cfg.loop_header(loop_expr) {
+            Some(header) if self.cfg.is_reachable(header) => header,
+            _ => return false,
+        };
+        if self.cfg.completes(loop_expr) {
+            return false;
+        }
+
+        let mut repeats = false;
+        for block in self.cfg.reachable_from(header) {
+            let terminator = &self.cfg[block].terminator;
+            if let Terminator::Return(_) = terminator {
+                return false;
+            }
+            repeats |= terminator.successors().contains(&header);
+        }
+        repeats
+    }
+}
@@ -0,0 +1,354 @@
+//! Checks `match` expressions for exhaustiveness and unreachable arms.
+//!
+//! The implementation is based on the usefulness algorithm described in "Warnings for pattern
+//! matching" by Luc Maranget. A pattern is useful with respect to a list of patterns if there is a
+//! value that it matches which is not matched by any of the patterns in the list. An arm is
+//! unreachable if its pattern is not useful with respect to the patterns of the arms that precede
+//! it, and a `match` is exhaustive if a wildcard is not useful with respect to all of its arms.
+
+use super::ExprValidator;
+use crate::diagnostics::{DiagnosticSink, MissingMatchArms, UnreachableMatchArm};
+use crate::expr::{Expr, ExprId, Literal, MatchArm, Pat, PatId, UnaryOp};
+use crate::{ty_app, EnumVariant, ModuleDef, Path, Resolution, Resolver, Substs, Ty, TypeCtor};
+
+/// Something that constructs a value: an enum variant, a tuple or a literal.
+#[derive(Clone, Debug, PartialEq)]
+enum Constructor {
+    Variant(EnumVariant),
+    /// A tuple with elements of the specified types
+    Tuple(Substs),
+    Bool(bool),
+    Int(i128),
+    Float(u64),
+    String(String),
+}
+
+/// A pattern that has been deconstructed into its constructor and the patterns of its fields.
+#[derive(Clone, Debug)]
+enum DeconstructedPat {
+    Wild,
+    Constructor(Constructor, Vec<DeconstructedPat>),
+}
+
+/// A row of patterns in a pattern matrix.
+type PatStack = Vec<DeconstructedPat>;
+
+impl<'a> ExprValidator<'a> {
+    /// Validates that all `match` expressions are exhaustive and do not contain unreachable arms.
+    pub(super) fn validate_match_exprs(&self, sink: &mut DiagnosticSink) {
+        let resolver = self.owner.resolver(self.db);
+        for (expr_id, expr) in self.body.exprs() {
+            if let Expr::Match { expr, arms } = expr {
+                self.validate_match(sink, &resolver, expr_id, *expr, arms);
+            }
+        }
+    }
+
+    fn validate_match(
+        &self,
+        sink: &mut DiagnosticSink,
+        resolver: &Resolver,
+        match_expr: ExprId,
+        scrutinee: ExprId,
+        arms: &[MatchArm],
+    ) {
+        let scrutinee_ty = &self.infer[scrutinee];
+        if *scrutinee_ty == Ty::Unknown {
+            // An error has already been reported for the scrutinee
+            return;
+        }
+
+        // Patterns that could not be lowered have already been reported during type inference.
+        // Checking the arms without them would result in bogus diagnostics.
+        let pats: Option<Vec<DeconstructedPat>> = arms
+            .iter()
+            .map(|arm| self.lower_pat(resolver, arm.pat, scrutinee_ty))
+            .collect();
+        let pats = match pats {
+            Some(pats) => pats,
+            None => return,
+        };
+
+        let file = self.owner.module(self.db.upcast()).file_id();
+        let tys = [scrutinee_ty.clone()];
+        let mut matrix = Vec::with_capacity(arms.len());
+        for (arm, pat) in arms.iter().zip(pats) {
+            let row = vec![pat];
+            if !self.is_useful(&matrix, &row, &tys) {
+                if let Some(pat) = self.body_source_map.pat_syntax(arm.pat) {
+                    sink.push(UnreachableMatchArm {
+                        file,
+                        pat: pat.value.syntax_node_ptr(),
+                    });
+                }
+            }
+            matrix.push(row);
+        }
+
+        if !self.is_useful(&matrix, &[DeconstructedPat::Wild], &tys) {
+            return;
+        }
+
+        let missing = match self.all_constructors(scrutinee_ty) {
+            Some(constructors) => constructors
+                .into_iter()
+                .filter(|ctor| {
+                    let arity = self.field_tys(ctor).len();
+                    let row = [DeconstructedPat::Constructor(
+                        ctor.clone(),
+                        vec![DeconstructedPat::Wild; arity],
+                    )];
+                    self.is_useful(&matrix, &row, &tys)
+                })
+                .map(|ctor| self.display_constructor(&ctor))
+                .collect(),
+            None => vec!["_".to_string()],
+        };
+
+        let expr = if let Some(ptr) = self.body_source_map.expr_syntax(scrutinee) {
+            ptr
+        } else if let Some(ptr) = self.body_source_map.expr_syntax(match_expr) {
+            ptr
+        } else {
+            return;
+        };
+        sink.push(MissingMatchArms {
+            file,
+            match_expr: expr
+                .value
+                .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()),
+            missing,
+        });
+    }
+
+    /// Lowers a pattern of the specified type to a `DeconstructedPat`. Returns `None` if the
+    /// pattern is invalid or does not match the type.
+    fn lower_pat(&self, resolver: &Resolver, pat: PatId, ty: &Ty) -> Option<DeconstructedPat> {
+        let pat = match &self.body[pat] {
+            Pat::Wild | Pat::Bind { .. } => DeconstructedPat::Wild,
+            Pat::Missing => return None,
+            Pat::Path(path) => {
+                let variant = self.resolve_variant(resolver, path, ty)?;
+                if !variant.field_types(self.db).is_empty() {
+                    return None;
+                }
+                DeconstructedPat::Constructor(Constructor::Variant(variant), Vec::new())
+            }
+            Pat::TupleStruct { path, args } => {
+                let variant = self.resolve_variant(resolver, path, ty)?;
+                let field_tys = variant.field_types(self.db);
+                if field_tys.len() != args.len() {
+                    return None;
+                }
+                let fields = args
+                    .iter()
+                    .zip(field_tys.iter())
+                    .map(|(arg, field_ty)| self.lower_pat(resolver, *arg, field_ty))
+                    .collect::<Option<Vec<_>>>()?;
+                DeconstructedPat::Constructor(Constructor::Variant(variant), fields)
+            }
+            // The empty tuple only has a single value
+            Pat::Tuple(args) if args.is_empty() && ty.is_empty() => DeconstructedPat::Wild,
+            Pat::Tuple(args) => {
+                let element_tys = ty.as_tuple()?;
+                if element_tys.len() != args.len() {
+                    return None;
+                }
+                let fields = args
+                    .iter()
+                    .zip(element_tys.iter())
+                    .map(|(arg, element_ty)| self.lower_pat(resolver, *arg, element_ty))
+                    .collect::<Option<Vec<_>>>()?;
+                DeconstructedPat::Constructor(Constructor::Tuple(element_tys.clone()), fields)
+            }
+            Pat::Lit(expr) => {
+                DeconstructedPat::Constructor(self.lower_literal(*expr, ty)?, Vec::new())
+            }
+        };
+        Some(pat)
+    }
+
+    /// Resolves the path of a pattern to a variant of the enum of the specified type.
+    fn resolve_variant(&self, resolver: &Resolver, path: &Path, ty: &Ty) -> Option<EnumVariant> {
+        match resolver
+            .resolve_path_without_assoc_items(self.db, path)
+            .take_values()
+        {
+            Some(Resolution::Def(ModuleDef::EnumVariant(variant)))
+                if ty.as_enum() == Some(variant.parent_enum()) =>
+            {
+                Some(variant)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the constructor of a literal pattern of the specified type.
+    fn lower_literal(&self, expr: ExprId, ty: &Ty) -> Option<Constructor> {
+        let (literal, negated) = match &self.body[expr] {
+            Expr::Literal(literal) => (literal, false),
+            Expr::UnaryOp {
+                expr,
+                op: UnaryOp::Neg,
+            } => match &self.body[*expr] {
+                Expr::Literal(literal) => (literal, true),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        match (literal, ty) {
+            (Literal::Bool(value), ty_app!(TypeCtor::Bool)) => Some(Constructor::Bool(*value)),
+            (Literal::Int(lit), ty_app!(TypeCtor::Int(_))) => {
+                let value = lit.value as i128;
+                Some(Constructor::Int(if negated { -value } else { value }))
+            }
+            (Literal::Float(lit), ty_app!(TypeCtor::Float(_))) => {
+                let value = if negated { -lit.value } else { lit.value };
+                Some(Constructor::Float(value.to_bits()))
+            }
+            (Literal::String(value), ty_app!(TypeCtor::String)) if !negated => {
+                Some(Constructor::String(value.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns all constructors of the specified type, or `None` if the number of constructors is
+    /// infinite (e.g. for integers).
+    fn all_constructors(&self, ty: &Ty) -> Option<Vec<Constructor>> {
+        match ty {
+            ty_app!(TypeCtor::Bool) => {
+                Some(vec![Constructor::Bool(false), Constructor::Bool(true)])
+            }
+            ty_app!(TypeCtor::Enum(e)) => Some(
+                e.variants(self.db)
+                    .into_iter()
+                    .map(Constructor::Variant)
+                    .collect(),
+            ),
+            ty_app!(TypeCtor::Tuple { .. }, element_tys) => {
+                Some(vec![Constructor::Tuple(element_tys.clone())])
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the types of the fields of a constructor.
+    fn field_tys(&self, ctor: &Constructor) -> Vec<Ty> {
+        match ctor {
+            Constructor::Variant(variant) => variant.field_types(self.db),
+            Constructor::Tuple(element_tys) => element_tys.to_vec(),
+            Constructor::Bool(_)
+            | Constructor::Int(_)
+            | Constructor::Float(_)
+            | Constructor::String(_) => Vec::new(),
+        }
+    }
+
+    fn display_constructor(&self, ctor: &Constructor) -> String {
+        match ctor {
+            Constructor::Variant(variant) => {
+                let name = format!(
+                    "{}::{}",
+                    variant.parent_enum().name(self.db.upcast()),
+                    variant.name(self.db)
+                );
+                match self.field_tys(ctor).len() {
+                    0 => name,
+                    arity => format!("{}({})", name, vec!["_"; arity].join(", ")),
+                }
+            }
+            Constructor::Tuple(element_tys) => {
+                format!("({})", vec!["_"; element_tys.len()].join(", "))
+            }
+            Constructor::Bool(value) => value.to_string(),
+            Constructor::Int(value) => value.to_string(),
+            Constructor::Float(bits) => f64::from_bits(*bits).to_string(),
+            Constructor::String(value) => format!("{:?}", value),
+        }
+    }
+
+    /// Returns true if there is a value that is matched by `row` but not by any of the rows of
+    /// `matrix`. `tys` contains the types of the columns.
+    fn is_useful(&self, matrix: &[PatStack], row: &[DeconstructedPat], tys: &[Ty]) -> bool {
+        let head = match row.first() {
+            Some(head) => head,
+            None => return matrix.is_empty(),
+        };
+
+        match head {
+            DeconstructedPat::Constructor(ctor, _) => {
+                self.is_useful_specialized(matrix, row, ctor, tys)
+            }
+            DeconstructedPat::Wild => {
+                let used_constructors: Vec<&Constructor> = matrix
+                    .iter()
+                    .filter_map(|row| match &row[0] {
+                        DeconstructedPat::Constructor(ctor, _) => Some(ctor),
+                        DeconstructedPat::Wild => None,
+                    })
+                    .collect();
+
+                match self.all_constructors(&tys[0]) {
+                    Some(constructors)
+                        if constructors
+                            .iter()
+                            .all(|ctor| used_constructors.contains(&ctor)) =>
+                    {
+                        constructors
+                            .iter()
+                            .any(|ctor| self.is_useful_specialized(matrix, row, ctor, tys))
+                    }
+                    _ => {
+                        // Not all constructors are covered by the matrix, so the wildcard is
+                        // useful if it is useful for the rows that start with a wildcard.
+                        let default_matrix: Vec<PatStack> = matrix
+                            .iter()
+                            .filter(|row| matches!(row[0], DeconstructedPat::Wild))
+                            .map(|row| row[1..].to_vec())
+                            .collect();
+                        self.is_useful(&default_matrix, &row[1..], &tys[1..])
+                    }
+                }
+            }
+        }
+    }
+
+    /// Specializes both the `matrix` and `row` for the constructor `ctor` and checks whether the
+    /// specialized row is useful.
+    fn is_useful_specialized(
+        &self,
+        matrix: &[PatStack],
+        row: &[DeconstructedPat],
+        ctor: &Constructor,
+        tys: &[Ty],
+    ) -> bool {
+        let mut specialized_tys = self.field_tys(ctor);
+        let arity = specialized_tys.len();
+        specialized_tys.extend_from_slice(&tys[1..]);
+
+        let specialized_matrix: Vec<PatStack> = matrix
+            .iter()
+            .filter_map(|row| specialize(row, ctor, arity))
+            .collect();
+        match specialize(row, ctor, arity) {
+            Some(row) => self.is_useful(&specialized_matrix, &row, &specialized_tys),
+            None => false,
+        }
+    }
+}
+
+/// Specializes a row for the constructor `ctor`. Returns `None` if the first pattern of the row
+/// does not match the constructor, otherwise the first pattern is replaced by the patterns of its
+/// fields.
+fn specialize(row: &[DeconstructedPat], ctor: &Constructor, arity: usize) -> Option<PatStack> {
+    let (head, tail) = row.split_first()?;
+    let mut result = match head {
+        DeconstructedPat::Wild => vec![DeconstructedPat::Wild; arity],
+        DeconstructedPat::Constructor(head_ctor, fields) if head_ctor == ctor => fields.clone(),
+        DeconstructedPat::Constructor(..) => return None,
+    };
+    result.extend_from_slice(tail);
+    Some(result)
+}
@@ -0,0 +1,115 @@
+use super::ExprValidator;
+use crate::code_model::DefWithBody;
+use crate::diagnostics::{DiagnosticSink, MissingReturnValue};
+use crate::expr::{Expr, ExprId, Statement};
+use crate::Ty;
+
+impl<'a> ExprValidator<'a> {
+    /// Validates that the end of the body of a function that returns a value cannot be reached
+    /// without a value. Instead of the body as a whole, the branches through which the end of the
+    /// body is reached are reported.
+    pub(super) fn validate_missing_return(&self, sink: &mut DiagnosticSink) {
+        let func = match self.owner {
+            DefWithBody::Function(func) => func,
+            _ => return,
+        };
+        let expected = match func.ty(self.db).callable_sig(self.db) {
+            Some(sig) => sig.ret().clone(),
+            None => return,
+        };
+        if expected.is_empty() || expected == Ty::Unknown {
+            return;
+        }
+
+        let body_expr = self.body.body_expr();
+        if self.cfg.completes(body_expr) {
+            self.report_missing_return(sink, &expected, body_expr, false);
+        }
+    }
+
+    /// Reports the branches through which the evaluation of `expr` completes without a value. If
+    /// `discarded` is false, the value of `expr` is returned from the function and an expression
+    /// that does evaluate to a value is checked by type inference instead.
+    fn report_missing_return(
+        &self,
+        sink: &mut DiagnosticSink,
+        expected: &Ty,
+        expr: ExprId,
+        discarded: bool,
+    ) {
+        match &self.body[expr] {
+            Expr::Block { statements, tail } => match (tail, statements.last()) {
+                (Some(tail), _) => self.report_missing_return(sink, expected, *tail, discarded),
+                (None, Some(Statement::Expr(last))) if self.has_branches(*last) => {
+                    self.report_missing_return(sink, expected, *last, true)
+                }
+                (None, _) => self.push_missing_return(sink, expected, expr, false),
+            },
+            Expr::If {
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                if self.cfg.completes(*then_branch) {
+                    self.report_missing_return(sink, expected, *then_branch, discarded);
+                }
+                match else_branch {
+                    Some(else_branch) => {
+                        if self.cfg.completes(*else_branch) {
+                            self.report_missing_return(sink, expected, *else_branch, discarded);
+                        }
+                    }
+                    // A missing `else` of an `if` whose branch has a value is reported by type
+                    // inference
+                    None => {
+                        let then_ty = &self.infer[*then_branch];
+                        if then_ty.is_empty() || then_ty.is_never() {
+                            self.push_missing_return(sink, expected, expr, true);
+                        }
+                    }
+                }
+            }
+            Expr::Match { arms, .. } => {
+                for arm in arms.iter() {
+                    if self.cfg.completes(arm.expr) {
+                        self.report_missing_return(sink, expected, arm.expr, discarded);
+                    }
+                }
+            }
+            Expr::Loop { .. } | Expr::While { .. } | Expr::For { .. }
+                if self.infer[expr].is_empty() =>
+            {
+                self.push_missing_return(sink, expected, expr, false)
+            }
+            _ if discarded => self.push_missing_return(sink, expected, expr, false),
+            _ => {}
+        }
+    }
+
+    /// Returns true if the value of `expr` is determined by one of multiple branches
+    fn has_branches(&self, expr: ExprId) -> bool {
+        matches!(
+            self.body[expr],
+            Expr::Block { .. } | Expr::If { .. } | Expr::Match { .. }
+        )
+    }
+
+    fn push_missing_return(
+        &self,
+        sink: &mut DiagnosticSink,
+        expected: &Ty,
+        branch: ExprId,
+        missing_else: bool,
+    ) {
+        if let Some(src) = self.body_source_map.expr_syntax(branch) {
+            sink.push(MissingReturnValue {
+                file: self.owner.module(self.db.upcast()).file_id(),
+                branch: src
+                    .value
+                    .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()),
+                missing_else,
+                expected: expected.clone(),
+            })
+        }
+    }
+}
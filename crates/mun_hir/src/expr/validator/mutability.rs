@@ -0,0 +1,212 @@
+use super::ExprValidator;
+use crate::code_model::src::HasSource;
+use crate::code_model::DefWithBody;
+use crate::diagnostics::{CannotAssignToImmutable, DiagnosticSink, ImmutablePlace};
+use crate::expr::dataflow::{DataflowResults, InitializedBindings};
+use crate::expr::{resolver_for_expr, BinaryOp, Expr, ExprId, Pat, PatId, Step};
+use crate::in_file::InFile;
+use crate::{ModuleDef, Resolution, StructMemoryKind};
+use mun_syntax::{AstNode, AstPtr, SyntaxNodePtr};
+use rustc_hash::FxHashSet;
+
+impl<'a> ExprValidator<'a> {
+    /// Validates that the left-hand side of every assignment refers to a place that can be
+    /// mutated. Type inference already verifies that the left-hand side is a place expression;
+    /// this pass reports places that are contained in an immutable static, a constant, a local
+    /// binding that is not declared `mut`, or a temporary `value` struct.
+    pub(super) fn validate_mutability(&self, sink: &mut DiagnosticSink) {
+        let reassignments = self.reassignments();
+        for (expr, data) in self.body.exprs() {
+            let (lhs, op) = match data {
+                Expr::BinaryOp {
+                    lhs,
+                    op: Some(BinaryOp::Assignment { op }),
+                    ..
+                } => (*lhs, *op),
+                _ => continue,
+            };
+
+            let place = match self.immutable_place(lhs) {
+                Some(place) => place,
+                None => continue,
+            };
+
+            let is_projection = !matches!(self.body[lhs], Expr::Path(_));
+            if !is_projection {
+                match place {
+                    // Assigning to a constant itself is reported as an invalid left-hand side
+                    ImmutablePlace::Const { .. } => continue,
+                    // An immutable binding that is declared without a value can be initialized
+                    // by an assignment
+                    ImmutablePlace::Binding { .. }
+                        if op.is_none() && !reassignments.contains(&expr) =>
+                    {
+                        continue
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(src) = self.body_source_map.expr_syntax(lhs) {
+                sink.push(CannotAssignToImmutable {
+                    file: self.owner.module(self.db.upcast()).file_id(),
+                    lhs: src
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()),
+                    place,
+                    is_projection,
+                })
+            }
+        }
+    }
+
+    /// Returns the assignments `a = value` to a local binding `a` that may already have been
+    /// initialized, i.e. that may assign a value to the binding for the second time.
+    pub(super) fn reassignments(&self) -> FxHashSet<ExprId> {
+        let analysis = InitializedBindings::possibly(self.db, self.owner);
+        let results = DataflowResults::compute(analysis, &self.cfg);
+
+        let mut reassignments = FxHashSet::default();
+        for (block, _) in self.cfg.blocks() {
+            results.visit_steps(block, |step, initialized_patterns| {
+                let expr = match step {
+                    Step::Eval(expr) => expr,
+                    Step::Bind(_) => return,
+                };
+                if let Expr::BinaryOp {
+                    lhs,
+                    op: Some(BinaryOp::Assignment { op: None }),
+                    ..
+                } = &self.body[expr]
+                {
+                    match results.analysis().local_binding(*lhs) {
+                        Some(pat) if initialized_patterns.contains(&pat) => {
+                            reassignments.insert(expr);
+                        }
+                        _ => {}
+                    }
+                }
+            });
+        }
+        reassignments
+    }
+
+    /// Returns the immutable place that contains the place expression `expr`, if any. The fields
+    /// of `gc` structs are stored on the heap, so they can always be mutated unless the struct is
+    /// stored in an immutable static or constant.
+    fn immutable_place(&self, expr: ExprId) -> Option<ImmutablePlace> {
+        match &self.body[expr] {
+            Expr::Path(path) => {
+                let resolver = resolver_for_expr(self.body.clone(), self.db, expr);
+                match resolver
+                    .resolve_path_without_assoc_items(self.db, path)
+                    .take_values()?
+                {
+                    Resolution::Def(ModuleDef::Static(s)) if !s.is_mut(self.db.upcast()) => {
+                        Some(ImmutablePlace::Static {
+                            name: s.name(self.db.upcast()),
+                            definition: s.source(self.db.upcast()).map(|src| AstPtr::new(&src)),
+                        })
+                    }
+                    Resolution::Def(ModuleDef::Const(c)) => Some(ImmutablePlace::Const {
+                        name: c.name(self.db.upcast()),
+                    }),
+                    Resolution::LocalBinding(pat) => match &self.body[pat] {
+                        Pat::Bind {
+                            name,
+                            is_mut: false,
+                        } => Some(ImmutablePlace::Binding {
+                            name: name.clone(),
+                            declaration: self.binding_declaration(pat),
+                        }),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            Expr::Field { expr: base, .. } => {
+                if self.is_temporary_value_struct(*base) {
+                    Some(ImmutablePlace::Temporary)
+                } else {
+                    self.immutable_base(*base)
+                }
+            }
+            Expr::Index { base, .. } => self.immutable_base(*base),
+            _ => None,
+        }
+    }
+
+    /// Returns the local binding that has to be declared `mut` to assign to the place expression
+    /// `expr`, if any, e.g. the binding `a` for the place `a.b[1]` if `a` is a `value` struct.
+    pub(super) fn mutated_binding(&self, expr: ExprId) -> Option<PatId> {
+        match &self.body[expr] {
+            Expr::Path(path) => {
+                let resolver = resolver_for_expr(self.body.clone(), self.db, expr);
+                match resolver
+                    .resolve_path_without_assoc_items(self.db, path)
+                    .take_values()?
+                {
+                    Resolution::LocalBinding(pat) => Some(pat),
+                    _ => None,
+                }
+            }
+            Expr::Field { expr: base, .. } | Expr::Index { base, .. }
+                if !self.is_heap_allocated(*base) =>
+            {
+                self.mutated_binding(*base)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the immutable place that contains the struct or array `base` of which a field or
+    /// element is assigned to. A local binding of a `gc` struct or a `[T]` array only refers to
+    /// memory on the heap, so its fields and elements can be mutated through an immutable binding.
+    fn immutable_base(&self, base: ExprId) -> Option<ImmutablePlace> {
+        match self.immutable_place(base)? {
+            ImmutablePlace::Binding { .. } if self.is_heap_allocated(base) => None,
+            place => Some(place),
+        }
+    }
+
+    /// Returns true if `expr` evaluates to a `value` struct that is not stored in a place, e.g.
+    /// the result of a function call or a struct literal.
+    fn is_temporary_value_struct(&self, expr: ExprId) -> bool {
+        if let Expr::Path(_) | Expr::Field { .. } | Expr::Index { .. } = self.body[expr] {
+            return false;
+        }
+        self.infer[expr].as_struct().map_or(false, |s| {
+            s.data(self.db.upcast()).memory_kind == StructMemoryKind::Value
+        })
+    }
+
+    /// Returns true if `expr` evaluates to a reference to memory on the heap, i.e. a `gc` struct
+    /// or a `[T]` array.
+    fn is_heap_allocated(&self, expr: ExprId) -> bool {
+        let ty = &self.infer[expr];
+        match ty.as_struct() {
+            Some(s) => s.data(self.db.upcast()).memory_kind == StructMemoryKind::GC,
+            None => matches!(ty.as_array(), Some((_, None))),
+        }
+    }
+
+    /// Returns the declaration of the local binding `pat`, at the start of which `mut` can be
+    /// inserted to make the binding mutable. The `self` parameter is not a pattern in the source,
+    /// so its declaration is looked up in the parameter list of the function.
+    pub(super) fn binding_declaration(&self, pat: PatId) -> Option<InFile<SyntaxNodePtr>> {
+        if let Some(src) = self.body_source_map.pat_syntax(pat) {
+            return Some(src.map(|ptr| ptr.syntax_node_ptr()));
+        }
+        match self.owner {
+            DefWithBody::Function(func) => {
+                let src = func.source(self.db.upcast());
+                let self_param = src.value.param_list()?.self_param()?;
+                Some(InFile::new(
+                    src.file_id,
+                    SyntaxNodePtr::new(self_param.syntax()),
+                ))
+            }
+            DefWithBody::Const(_) | DefWithBody::Static(_) => None,
+        }
+    }
+}
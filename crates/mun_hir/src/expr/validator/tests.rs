@@ -1,10 +1,10 @@
 use crate::{
-    db::DefDatabase,
-    diagnostics::DiagnosticSink,
+    db::{AstDatabase, DefDatabase},
+    diagnostics::{DiagnosticSink, Severity},
     expr::validator::{ExprValidator, TypeAliasValidator},
     fixture::WithFixture,
     mock::MockDatabase,
-    ModuleDef,
+    FileId, LintLevel, Module, ModuleDef,
 };
 use std::fmt::Write;
 
@@ -61,8 +61,8 @@ fn test_uninitialized_access_if() {
 fn test_uninitialized_access_while() {
     diagnostics_snapshot(
         r#"
-    fn foo(b:int) {
-        let a:int;
+    fn foo(mut b:int) {
+        let mut a:int;
         while b < 4 { b += 1; a = b; a += 1; }
         let c = a + 4;  // `a` is possibly-unitialized
     }
@@ -70,6 +70,57 @@ fn test_uninitialized_access_while() {
     )
 }
 
+#[test]
+fn test_match_exhaustiveness() {
+    diagnostics_snapshot(
+        r#"
+    enum Foo { A, B(bool), C }
+
+    fn exhaustive(f: Foo) -> i32 {
+        match f { Foo::A => 0, Foo::B(true) => 1, Foo::B(false) => 2, Foo::C => 3 }
+    }
+
+    fn missing(f: Foo) -> i32 {
+        match f { Foo::B(true) => 1 }   // `Foo::A`, `Foo::B(_)` and `Foo::C` are not covered
+    }
+
+    fn unreachable(f: Foo) -> i32 {
+        match f { Foo::A => 0, _ => 1, Foo::C => 2 }    // `Foo::C` is unreachable
+    }
+
+    fn booleans(b: bool) -> i32 {
+        match b { true => 0, false => 1, _ => 2 }   // `_` is unreachable
+    }
+
+    fn integers(a: i32) -> i32 {
+        match a { 0 => 1, -1 => 2, 0 => 3 }     // `0` is unreachable and `_` is not covered
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_constant_arithmetic() {
+    diagnostics_snapshot(
+        r#"
+    fn main() {
+        let a = 255u8 + 1u8;        // overflows `u8`
+        let b = 1 / 0;
+        let c = 7 % (3 - 3);
+        let d = 1 << 32;            // overflows `i32`
+        let e = -(-127i8 - 2i8);    // only the subtraction overflows
+        let f = a + 255u8;          // correct, `a` is not a constant
+        let g = 2 + square(3);
+        let h = 1 + square(65536);  // overflows in the body of `square`
+    }
+
+    const fn square(x: i32) -> i32 {
+        x * x
+    }
+    "#,
+    )
+}
+
 #[test]
 fn test_free_type_alias_without_type_ref() {
     diagnostics_snapshot(
@@ -79,19 +130,383 @@ fn test_free_type_alias_without_type_ref() {
     )
 }
 
-fn diagnostics(content: &str) -> String {
+#[test]
+fn test_unused_variables() {
+    lints_snapshot(
+        r#"
+    pub fn foo(a: i32) -> i32 {
+        let b = a + 1;          // `b` is never used
+        let c = 2;
+        let _d = 3;             // correct, starts with an underscore
+        let (e, f) = (c, 4);    // `f` is never used
+        let g: i32;
+        g = e;                  // assigning to `g` does not count as a use
+        e
+    }
+
+    pub fn bar(x: (i32, bool)) -> i32 {
+        if let (y, true) = x { 1 } else { 0 }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_unreachable_code() {
+    lints_snapshot(
+        r#"
+    pub fn foo(a: i32) -> i32 {
+        return a;
+        let b = a + 1;
+        b
+    }
+
+    pub fn bar(mut a: i32) {
+        loop {
+            if a > 3 {
+                break;
+                a += 1;
+            }
+        }
+    }
+
+    pub fn baz() -> i32 {
+        return 1;
+        2
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_unused_items() {
+    lints_snapshot(
+        r#"
+    fn unused() {}
+
+    fn recursive(n: i32) -> i32 {
+        if n > 0 { recursive(n - 1) } else { 0 }
+    }
+
+    fn used() -> i32 { 1 }
+
+    pub fn main() -> i32 {
+        used() + Foo::new().a
+    }
+
+    struct Foo { a: i32 }
+    struct Bar { foo: Foo }
+    struct Baz;
+    pub struct Public;
+
+    impl Foo {
+        fn new() -> Self { Foo { a: 1 } }
+        fn unused_method(self) -> i32 { self.a }
+        pub fn public_method(self) {}
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_unused_mut() {
+    lints_snapshot(
+        r#"
+    static mut COUNTER: i32 = 0;
+    static mut LIMIT: i32 = 10;     // never assigned to
+
+    pub fn increment() -> i32 {
+        COUNTER += 1;
+        if COUNTER > LIMIT {
+            COUNTER = 0;
+        }
+        COUNTER
+    }
+
+    struct(value) Vec2 { x: f32, y: f32 }
+    struct(gc) Entity { pos: Vec2 }
+
+    pub fn locals(mut a: i32, mut b: i32) -> i32 {   // `a` is never assigned to
+        let mut c;              // only initialized, never reassigned
+        c = b;
+        let mut d = 0;
+        d += c;
+        b = d;
+        a + b
+    }
+
+    pub fn fields(mut e: Entity, mut v: Vec2) -> f32 {   // `e` refers to a `gc` struct
+        e.pos.x = 1.0;
+        v.y = 2.0;
+        e.pos.x + v.y
+    }
+
+    impl Vec2 {
+        pub fn length(mut self) -> f32 {  // never assigned to
+            self.x + self.y
+        }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_assign_to_immutable() {
+    diagnostics_snapshot(
+        r#"
+    struct(value) Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    struct(gc) Entity {
+        pos: Vec2,
+    }
+
+    const ORIGIN: Vec2 = Vec2 { x: 0.0, y: 0.0 };
+    static SPAWN: Vec2 = Vec2 { x: 1.0, y: 1.0 };
+    static LIMIT: i32 = 10;
+    static mut COUNTER: i32 = 0;
+
+    fn make_vec() -> Vec2 {
+        Vec2 { x: 1.0, y: 1.0 }
+    }
+
+    fn make_entity() -> Entity {
+        Entity { pos: make_vec() }
+    }
+
+    fn main(v: Vec2) {
+        COUNTER += 1;
+        LIMIT = 5;                  // error: cannot assign to immutable static `LIMIT`
+        SPAWN.x = 2.0;              // error: cannot assign to part of immutable static `SPAWN`
+        ORIGIN.y = 3.0;             // error: cannot assign to part of constant `ORIGIN`
+        make_vec().x = 4.0;         // error: cannot assign to a field of a temporary value
+        make_entity().pos.x = 5.0;  // the fields of a `gc` struct are stored on the heap
+        v.y = 6.0;                  // error: cannot assign to part of immutable variable `v`
+    }
+
+    fn bindings(e: Entity, mut w: Vec2) {
+        let a = 1;
+        a = 2;                      // error: cannot assign to immutable variable `a`
+        let b;
+        b = 3;                      // a binding without a value can be initialized once
+        let mut c = a + b;
+        c += 4;
+        e.pos.x = 7.0;              // `e` refers to a `gc` struct on the heap
+        w.x = 8.0;
+        let f = |x: f32| { x = 9.0; };  // error: cannot assign to immutable variable `x`
+    }
+
+    impl Vec2 {
+        fn reset(self) { self.x = 0.0; }    // error: cannot assign to part of immutable variable `self`
+        fn clear(mut self) { self.y = 0.0; }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_lint_attributes() {
+    lints_snapshot(
+        r#"
+    #[allow(dead_code)]
+    fn foo() {
+        let a = 1;                      // not allowed by `dead_code`
+    }
+
+    #[deny(dead_code)]
+    struct Bar;
+
+    #[allow(unused_variables)]
+    pub fn baz() {
+        let b = 2;
+        #[warn(unused_variables)]
+        {
+            let c = 3;
+        }
+    }
+
+    #[deny(unused_variables, unreachable_code)]
+    pub fn qux() -> i32 {
+        let d = 4;
+        return 5;
+        6
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_unknown_lints() {
+    lints_snapshot(
+        r#"
+    #[allow(unused_variable)]
+    pub fn foo() {
+        let a = 1;
+    }
+
+    #[allow(unknown_lints)]
+    #[warn(dead_cod)]
+    pub fn bar() {}
+
+    #[deny(unknown_lints, unused_mutt)]
+    pub fn baz() {}
+    "#,
+    )
+}
+
+#[test]
+fn test_missing_return() {
+    diagnostics_snapshot(
+        r#"
+    fn sign(a: i32) -> i32 {
+        if a < 0 {
+            return -1;
+        } else if a > 0 {
+            return 1;
+        }                   // the `else` branch is missing
+    }
+
+    fn classify(a: i32) -> i32 {
+        match a {
+            0 => return 0,
+            1 => { foo(); },    // the arm does not evaluate to a value
+            _ => 2,
+        }
+    }
+
+    fn statement(a: bool) -> i32 {
+        if a {
+            return 1;
+        } else {
+            foo();          // the `else` branch does not return a value
+        };
+    }
+
+    fn countdown(n: i32) -> i32 {
+        while n > 0 {
+            return n;
+        }                   // the loop may not be entered
+    }
+
+    fn empty() -> i32 {}
+
+    fn diverges(a: i32) -> i32 {
+        loop {
+            if a > 3 {
+                return a;
+            }
+        }
+    }
+
+    fn foo() {}
+    "#,
+    )
+}
+
+#[test]
+fn test_missing_return_reported_once() {
+    // Type inference treats these tails as diverging and leaves reporting them to the validator
+    errors_snapshot(
+        r#"
+    fn nested(a: bool) -> i32 {
+        {
+            if a {
+                return 1;
+            }
+        }
+    }
+
+    fn loops(a: bool) -> i32 {
+        loop {
+            if a {
+                break;
+            }
+        }
+    }
+
+    fn else_block(a: bool) -> i32 {
+        if a {
+            return 1;
+        } else {
+            {}
+        }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn test_infinite_loops() {
+    lints_snapshot(
+        r#"
+    pub fn tick() {}
+
+    pub fn run() {
+        loop {
+            tick();
+        }
+    }
+
+    pub fn nested() {
+        loop {
+            loop {              // only the inner loop repeats
+                tick();
+            }
+        }
+    }
+
+    pub fn labeled() {
+        'outer: loop {
+            loop {
+                continue 'outer;
+            }
+        }
+    }
+
+    pub fn exits(a: i32) -> i32 {
+        let mut n = a;
+        loop {
+            if n > 10 {
+                return n;
+            }
+            n += 1;
+        }
+    }
+
+    pub fn breaks() {
+        loop {
+            break;
+        }
+    }
+    "#,
+    )
+}
+
+/// Constructs a database with a single file, asserting that `content` contains no syntax errors
+fn parse_single_file(content: &str) -> (MockDatabase, FileId) {
     let (db, file_id) = MockDatabase::with_single_file(content);
+    let errors = db.parse(file_id).errors().to_vec();
+    assert!(errors.is_empty(), "unexpected parse errors: {:?}", errors);
+    (db, file_id)
+}
+
+fn diagnostics(content: &str) -> String {
+    let (db, file_id) = parse_single_file(content);
 
     let mut diags = String::new();
 
     let mut diag_sink = DiagnosticSink::new(|diag| {
-        write!(diags, "{}: {}\n", diag.highlight_range(), diag.message()).unwrap();
+        if diag.severity() == Severity::Error {
+            write!(diags, "{}: {}\n", diag.highlight_range(), diag.message()).unwrap();
+        }
     });
 
     for item in db.module_data(file_id).definitions() {
         match item {
             ModuleDef::Function(item) => {
-                ExprValidator::new(*item, &db).validate_body(&mut diag_sink);
+                ExprValidator::new((*item).into(), &db).validate_body(&mut diag_sink);
             }
             ModuleDef::TypeAlias(item) => {
                 TypeAliasValidator::new(*item, &db).validate_target_type_existence(&mut diag_sink);
@@ -99,6 +514,11 @@ fn diagnostics(content: &str) -> String {
             _ => {}
         }
     }
+    for item in Module::from(file_id).impls(&db) {
+        for fun in item.items(&db) {
+            ExprValidator::new(fun.into(), &db).validate_body(&mut diag_sink);
+        }
+    }
 
     drop(diag_sink);
     diags
@@ -108,3 +528,61 @@ fn diagnostics_snapshot(text: &str) {
     let text = text.trim().replace("\n    ", "\n");
     insta::assert_snapshot!(insta::_macro_support::AutoName, diagnostics(&text), &text);
 }
+
+/// Returns all the warnings of the module that are not allowed by an attribute
+fn lints(content: &str) -> String {
+    let (db, file_id) = parse_single_file(content);
+
+    let mut diags = String::new();
+
+    let mut diag_sink = DiagnosticSink::new(|diag| {
+        if diag.severity() == Severity::Warning {
+            let suffix = match diag.lint_level(&db) {
+                Some(LintLevel::Allow) => return,
+                Some(LintLevel::Deny) => " (denied)",
+                _ => "",
+            };
+            write!(
+                diags,
+                "{}: {}{}\n",
+                diag.highlight_range(),
+                diag.message(),
+                suffix
+            )
+            .unwrap();
+        }
+    });
+
+    Module::from(file_id).diagnostics(&db, &mut diag_sink);
+
+    drop(diag_sink);
+    diags
+}
+
+fn lints_snapshot(text: &str) {
+    let text = text.trim().replace("\n    ", "\n");
+    insta::assert_snapshot!(insta::_macro_support::AutoName, lints(&text), &text);
+}
+
+/// Returns all the errors of the module, including those found by type inference
+fn errors(content: &str) -> String {
+    let (db, file_id) = parse_single_file(content);
+
+    let mut diags = String::new();
+
+    let mut diag_sink = DiagnosticSink::new(|diag| {
+        if diag.severity() == Severity::Error {
+            write!(diags, "{}: {}\n", diag.highlight_range(), diag.message()).unwrap();
+        }
+    });
+
+    Module::from(file_id).diagnostics(&db, &mut diag_sink);
+
+    drop(diag_sink);
+    diags
+}
+
+fn errors_snapshot(text: &str) {
+    let text = text.trim().replace("\n    ", "\n");
+    insta::assert_snapshot!(insta::_macro_support::AutoName, errors(&text), &text);
+}
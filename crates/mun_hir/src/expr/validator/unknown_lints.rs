@@ -0,0 +1,33 @@
+use super::ModuleValidator;
+use crate::diagnostics::{DiagnosticSink, UnknownLint};
+use crate::lint::{Lint, LintLevel};
+use mun_syntax::{ast, AstNode, AstPtr};
+
+impl<'a> ModuleValidator<'a> {
+    /// Validates that the `allow`, `warn` and `deny` attributes in the module only refer to lints
+    /// that exist. An unknown lint would otherwise be ignored without notice, e.g. when its name
+    /// contains a typo.
+    pub(super) fn validate_unknown_lints(&self, sink: &mut DiagnosticSink) {
+        let file_id = self.module.file_id();
+        let source_file = self.db.parse(file_id).syntax_node();
+        for attr in source_file.descendants().filter_map(ast::Attr::cast) {
+            let is_lint_attr = attr
+                .simple_name()
+                .map_or(false, |name| LintLevel::from_name(&name).is_some());
+            let token_tree = match attr.token_tree() {
+                Some(token_tree) if is_lint_attr => token_tree,
+                _ => continue,
+            };
+            for name in token_tree.idents() {
+                if Lint::from_name(name.text()).is_none() {
+                    sink.push(UnknownLint {
+                        file: file_id,
+                        attr: AstPtr::new(&attr),
+                        name_range: name.text_range(),
+                        name: name.text().clone(),
+                    })
+                }
+            }
+        }
+    }
+}
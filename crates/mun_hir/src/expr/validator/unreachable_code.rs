@@ -0,0 +1,60 @@
+use super::ExprValidator;
+use crate::diagnostics::{DiagnosticSink, UnreachableCode};
+use crate::expr::{Expr, ExprId, PatId, Statement};
+use mun_syntax::{ast, AstNode, SyntaxNodePtr};
+
+impl<'a> ExprValidator<'a> {
+    /// Validates that blocks do not contain code that follows an expression that never returns,
+    /// e.g. a `return`, `break` or `continue`. Only the first unreachable statement of a block is
+    /// reported.
+    pub(super) fn validate_unreachable_code(&self, sink: &mut DiagnosticSink) {
+        for (_, expr) in self.body.exprs() {
+            let (statements, tail) = match expr {
+                Expr::Block { statements, tail } => (statements, tail),
+                _ => continue,
+            };
+
+            let diverging_statement = statements.iter().position(|statement| match statement {
+                Statement::Let { initializer, .. } => {
+                    initializer.map_or(false, |expr| self.infer[expr].is_never())
+                }
+                Statement::Expr(expr) => self.infer[*expr].is_never(),
+            });
+
+            let unreachable = match diverging_statement {
+                Some(idx) => match statements.get(idx + 1) {
+                    Some(Statement::Let { pat, .. }) => self.let_statement_syntax(*pat),
+                    Some(Statement::Expr(expr)) => self.expr_syntax(*expr),
+                    None => tail.and_then(|tail| self.expr_syntax(tail)),
+                },
+                None => None,
+            };
+
+            if let Some(stmt) = unreachable {
+                sink.push(UnreachableCode {
+                    file: self.owner.module(self.db.upcast()).file_id(),
+                    stmt,
+                })
+            }
+        }
+    }
+
+    fn expr_syntax(&self, expr: ExprId) -> Option<SyntaxNodePtr> {
+        self.body_source_map.expr_syntax(expr).map(|src| {
+            src.value
+                .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr())
+        })
+    }
+
+    /// Returns the `let` statement that binds the specified pattern.
+    fn let_statement_syntax(&self, pat: PatId) -> Option<SyntaxNodePtr> {
+        let src = self.body_source_map.pat_syntax(pat)?;
+        let root = self.db.parse(src.file_id).syntax_node();
+        src.value
+            .to_node(&root)
+            .syntax()
+            .ancestors()
+            .find_map(ast::LetStmt::cast)
+            .map(|stmt| SyntaxNodePtr::new(stmt.syntax()))
+    }
+}
@@ -0,0 +1,166 @@
+use super::ModuleValidator;
+use crate::code_model::src::HasSource;
+use crate::diagnostics::{DiagnosticSink, UnusedItem};
+use crate::name_resolution::Namespace;
+use crate::ty::{CallableDef, TypableDef};
+use crate::{code_model::DefWithBody, Function, ModuleDef, Struct, Ty, TypeCtor};
+use mun_syntax::ast::NameOwner;
+use mun_syntax::{AstNode, SyntaxNodePtr};
+use std::collections::HashSet;
+
+/// The functions and structs that are referred to by an item.
+#[derive(Default)]
+struct UsedItems {
+    functions: HashSet<Function>,
+    structs: HashSet<Struct>,
+}
+
+impl UsedItems {
+    /// Marks all functions and structs that occur in the specified type as used.
+    fn add_ty(&mut self, ty: &Ty) {
+        ty.walk(&mut |ty| {
+            if let Ty::Apply(a_ty) = ty {
+                match a_ty.ctor {
+                    TypeCtor::Struct(s) | TypeCtor::FnDef(CallableDef::Struct(s)) => {
+                        self.structs.insert(s);
+                    }
+                    TypeCtor::FnDef(CallableDef::Function(f)) => {
+                        self.functions.insert(f);
+                    }
+                    _ => {}
+                }
+            }
+        })
+    }
+
+    fn extend(&mut self, other: UsedItems) {
+        self.functions.extend(other.functions);
+        self.structs.extend(other.structs);
+    }
+}
+
+impl<'a> ModuleValidator<'a> {
+    /// Validates that all private functions and structs in the module are used by another item.
+    pub(super) fn validate_unused_items(&self, sink: &mut DiagnosticSink) {
+        let used = self.used_items();
+
+        for def in self.module.declarations(self.db) {
+            match def {
+                ModuleDef::Function(f) => {
+                    if !f.is_extern(self.db) && !used.functions.contains(&f) {
+                        self.report_unused_function(sink, f, "function");
+                    }
+                }
+                ModuleDef::Struct(s) => {
+                    if s.visibility(self.db.upcast()).is_private() && !used.structs.contains(&s) {
+                        let src = s.source(self.db.upcast());
+                        if let Some(name) = src.value.name() {
+                            sink.push(UnusedItem {
+                                file: src.file_id,
+                                item: SyntaxNodePtr::new(name.syntax()),
+                                kind: "struct",
+                                name: s.name(self.db.upcast()),
+                            })
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // The functions of a trait implementation are used through the trait
+        for impl_def in self.module.impls(self.db) {
+            if impl_def.is_trait_impl(self.db.upcast()) {
+                continue;
+            }
+            for f in impl_def.items(self.db) {
+                if !used.functions.contains(&f) {
+                    let kind = if f.data(self.db).has_self_param() {
+                        "method"
+                    } else {
+                        "associated function"
+                    };
+                    self.report_unused_function(sink, f, kind);
+                }
+            }
+        }
+    }
+
+    fn report_unused_function(&self, sink: &mut DiagnosticSink, f: Function, kind: &'static str) {
+        if f.visibility(self.db).is_public() {
+            return;
+        }
+        let src = f.source(self.db.upcast());
+        if let Some(name) = src.value.name() {
+            sink.push(UnusedItem {
+                file: src.file_id,
+                item: SyntaxNodePtr::new(name.syntax()),
+                kind,
+                name: f.name(self.db),
+            })
+        }
+    }
+
+    /// Returns all the functions and structs that are used by the items of the module. An item
+    /// that only refers to itself, e.g. a recursive function, does not count as a use.
+    fn used_items(&self) -> UsedItems {
+        let mut used = UsedItems::default();
+
+        for def in self.bodies() {
+            let mut uses = UsedItems::default();
+            let body = def.body(self.db);
+            let infer = def.infer(self.db);
+            for (expr, _) in body.exprs() {
+                uses.add_ty(&infer[expr]);
+                if let Some((f, _)) = infer.method_resolution(expr) {
+                    uses.functions.insert(f);
+                }
+                if let Some((f, _)) = infer.function_value(expr) {
+                    uses.functions.insert(f);
+                }
+            }
+            for (pat, _) in body.pats() {
+                uses.add_ty(&infer[pat]);
+            }
+            if let DefWithBody::Function(f) = def {
+                uses.functions.remove(&f);
+                if let Some(sig) = f.ty(self.db).callable_sig(self.db) {
+                    sig.params().iter().for_each(|ty| uses.add_ty(ty));
+                    uses.add_ty(sig.ret());
+                }
+            }
+            used.extend(uses);
+        }
+
+        for def in self.module.declarations(self.db) {
+            match def {
+                ModuleDef::Struct(s) => {
+                    let mut uses = UsedItems::default();
+                    for field in s.fields(self.db) {
+                        uses.add_ty(&field.ty(self.db));
+                    }
+                    uses.structs.remove(&s);
+                    used.extend(uses);
+                }
+                ModuleDef::Enum(e) => {
+                    for variant in e.variants(self.db) {
+                        for ty in variant.field_types(self.db) {
+                            used.add_ty(&ty);
+                        }
+                    }
+                }
+                ModuleDef::TypeAlias(t) => {
+                    let (ty, _) = self
+                        .db
+                        .type_for_def(TypableDef::TypeAlias(t), Namespace::Types);
+                    used.add_ty(&ty);
+                }
+                ModuleDef::Const(c) => used.add_ty(&c.ty(self.db)),
+                ModuleDef::Static(s) => used.add_ty(&s.ty(self.db)),
+                _ => {}
+            }
+        }
+
+        used
+    }
+}
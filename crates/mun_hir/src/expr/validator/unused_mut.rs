@@ -0,0 +1,131 @@
+use super::{ExprValidator, ModuleValidator};
+use crate::code_model::src::HasSource;
+use crate::diagnostics::{DiagnosticSink, UnusedMut, UnusedMutBinding};
+use crate::expr::{resolver_for_expr, BinaryOp, Body, Expr, ExprId, Pat};
+use crate::{HirDatabase, ModuleDef, Resolution, Static};
+use mun_syntax::ast::NameOwner;
+use mun_syntax::{AstNode, SyntaxNodePtr};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+impl<'a> ModuleValidator<'a> {
+    /// Validates that all `static mut` items in the module are assigned to. Since a static can
+    /// only be accessed in the module in which it is declared, only the bodies of the module have
+    /// to be checked.
+    pub(super) fn validate_unused_mut(&self, sink: &mut DiagnosticSink) {
+        let mutable_statics = self
+            .module
+            .statics(self.db)
+            .into_iter()
+            .filter(|s| s.is_mut(self.db.upcast()))
+            .collect::<Vec<_>>();
+        if mutable_statics.is_empty() {
+            return;
+        }
+
+        let mut assigned = HashSet::new();
+        for def in self.bodies() {
+            let body = def.body(self.db);
+            for (_, expr) in body.exprs() {
+                if let Expr::BinaryOp {
+                    lhs,
+                    op: Some(BinaryOp::Assignment { .. }),
+                    ..
+                } = expr
+                {
+                    if let Some(s) = assigned_static(self.db, &body, *lhs) {
+                        assigned.insert(s);
+                    }
+                }
+            }
+        }
+
+        for s in mutable_statics {
+            if assigned.contains(&s) {
+                continue;
+            }
+            let src = s.source(self.db.upcast());
+            if let Some(name) = src.value.name() {
+                sink.push(UnusedMut {
+                    file: src.file_id,
+                    static_def: SyntaxNodePtr::new(name.syntax()),
+                    name: s.name(self.db.upcast()),
+                })
+            }
+        }
+    }
+}
+
+impl<'a> ExprValidator<'a> {
+    /// Validates that all local bindings and parameters that are declared `mut` are assigned to
+    /// in a way that requires them to be mutable. Initializing a binding that is declared without
+    /// a value, or assigning to a field of a `gc` struct it refers to, does not.
+    pub(super) fn validate_unused_mut_bindings(&self, sink: &mut DiagnosticSink) {
+        let mutable_bindings = self
+            .body
+            .pats()
+            .filter_map(|(pat, data)| match data {
+                Pat::Bind { name, is_mut: true } => Some((pat, name)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if mutable_bindings.is_empty() {
+            return;
+        }
+
+        let reassignments = self.reassignments();
+        let mut mutated = HashSet::new();
+        for (expr, data) in self.body.exprs() {
+            let (lhs, op) = match data {
+                Expr::BinaryOp {
+                    lhs,
+                    op: Some(BinaryOp::Assignment { op }),
+                    ..
+                } => (*lhs, *op),
+                _ => continue,
+            };
+            let is_initialization = op.is_none()
+                && matches!(self.body[lhs], Expr::Path(_))
+                && !reassignments.contains(&expr);
+            if is_initialization {
+                continue;
+            }
+            if let Some(pat) = self.mutated_binding(lhs) {
+                mutated.insert(pat);
+            }
+        }
+
+        for (pat, name) in mutable_bindings {
+            if mutated.contains(&pat) {
+                continue;
+            }
+            if let Some(declaration) = self.binding_declaration(pat) {
+                sink.push(UnusedMutBinding {
+                    file: declaration.file_id,
+                    binding: declaration.value,
+                    name: name.clone(),
+                })
+            }
+        }
+    }
+}
+
+/// Returns the static that contains the place that is assigned to by the specified expression,
+/// e.g. `FOO` for `FOO.bar[1]`.
+fn assigned_static(db: &dyn HirDatabase, body: &Arc<Body>, expr: ExprId) -> Option<Static> {
+    match &body[expr] {
+        Expr::Field { expr, .. } => assigned_static(db, body, *expr),
+        Expr::Index { base, .. } => assigned_static(db, body, *base),
+        Expr::Path(path) => {
+            let resolver = resolver_for_expr(body.clone(), db, expr);
+            match resolver
+                .resolve_path_without_assoc_items(db, path)
+                .take_values()
+            {
+                Some(Resolution::Def(ModuleDef::Static(s))) => Some(s),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,86 @@
+use super::ExprValidator;
+use crate::diagnostics::{DiagnosticSink, UnusedVariable};
+use crate::expr::{resolver_for_expr, BinaryOp, Expr, Pat, PatId, Statement};
+use crate::{Name, Resolution};
+use std::collections::HashSet;
+
+impl<'a> ExprValidator<'a> {
+    /// Validates that all variables bound by `let` statements and `let` conditions are used.
+    /// Variables of which the name starts with an underscore are ignored.
+    pub(super) fn validate_unused_variables(&self, sink: &mut DiagnosticSink) {
+        let mut bindings = Vec::new();
+        for (_, expr) in self.body.exprs() {
+            match expr {
+                Expr::Block { statements, .. } => {
+                    for statement in statements.iter() {
+                        if let Statement::Let { pat, .. } = statement {
+                            self.collect_bindings(*pat, &mut bindings);
+                        }
+                    }
+                }
+                Expr::Let { pat, .. } => self.collect_bindings(*pat, &mut bindings),
+                _ => {}
+            }
+        }
+
+        if bindings.is_empty() {
+            return;
+        }
+
+        // Assigning a new value to a variable does not count as a use of that variable
+        let assignment_targets = self
+            .body
+            .exprs()
+            .filter_map(|(_, expr)| match expr {
+                Expr::BinaryOp {
+                    lhs,
+                    op: Some(BinaryOp::Assignment { op: None }),
+                    ..
+                } => Some(*lhs),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        let mut used = HashSet::new();
+        for (expr_id, expr) in self.body.exprs() {
+            if let Expr::Path(path) = expr {
+                if assignment_targets.contains(&expr_id) {
+                    continue;
+                }
+                let resolver = resolver_for_expr(self.body.clone(), self.db, expr_id);
+                if let Some(Resolution::LocalBinding(pat)) = resolver
+                    .resolve_path_without_assoc_items(self.db, path)
+                    .take_values()
+                {
+                    used.insert(pat);
+                }
+            }
+        }
+
+        let file = self.owner.module(self.db.upcast()).file_id();
+        for (pat, name) in bindings {
+            if used.contains(&pat) {
+                continue;
+            }
+            if let Some(src) = self.body_source_map.pat_syntax(pat) {
+                sink.push(UnusedVariable {
+                    file,
+                    pat: src.value.syntax_node_ptr(),
+                    name: name.clone(),
+                })
+            }
+        }
+    }
+
+    /// Collects all the variables that are bound by the specified pattern.
+    fn collect_bindings<'b>(&'b self, pat: PatId, bindings: &mut Vec<(PatId, &'b Name)>) {
+        match &self.body[pat] {
+            Pat::Bind { name, .. } => {
+                if !name.to_string().starts_with('_') {
+                    bindings.push((pat, name));
+                }
+            }
+            pat => pat.walk_child_pats(|pat| self.collect_bindings(pat, bindings)),
+        }
+    }
+}
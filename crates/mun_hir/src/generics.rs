@@ -0,0 +1,187 @@
+//! Generic type parameters of functions and structs, e.g. the `T` in `fn foo<T>(a: T)`.
+
+use crate::diagnostics::{DiagnosticSink, UnresolvedTrait};
+use crate::resolve::Resolver;
+use crate::{
+    code_model::src::HasSource, ty::Substs, type_ref::TypeRef, AsName, Function, HirDatabase, Name,
+    Struct, Trait, Ty,
+};
+use mun_syntax::{
+    ast::{self, NameOwner},
+    AstNode, AstPtr,
+};
+
+/// A single generic type parameter of a definition.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct GenericParam {
+    /// The index of the parameter in the list of parameters of its definition
+    pub idx: u32,
+    pub name: Name,
+    /// The bounds of the parameter, e.g. `Foo` in `T: Foo`
+    pub bounds: Vec<TypeBound>,
+}
+
+/// A bound on a generic type parameter. A bound refers to a trait that the type argument of the
+/// parameter must implement.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct TypeBound {
+    pub type_ref: TypeRef,
+    pub(crate) ptr: AstPtr<ast::TypeBound>,
+}
+
+/// The generic type parameters of a definition. An empty list is used for definitions that are
+/// not generic.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Hash)]
+pub struct GenericParams {
+    pub params: Vec<GenericParam>,
+}
+
+impl GenericParams {
+    /// Constructs the generic parameters from an optional `ast::TypeParamList`.
+    pub(crate) fn from_ast(type_param_list: Option<ast::TypeParamList>) -> Self {
+        let mut params = GenericParams::default();
+        params.fill(type_param_list);
+        params
+    }
+
+    /// Constructs the generic parameters of a function that is declared in a trait. The type that
+    /// implements the trait is represented by an implicit first parameter named `Self`.
+    pub(crate) fn from_trait_function_ast(type_param_list: Option<ast::TypeParamList>) -> Self {
+        let mut params = GenericParams {
+            params: vec![GenericParam {
+                idx: 0,
+                name: name![Self],
+                bounds: Vec::new(),
+            }],
+        };
+        params.fill(type_param_list);
+        params
+    }
+
+    fn fill(&mut self, type_param_list: Option<ast::TypeParamList>) {
+        for param in type_param_list
+            .into_iter()
+            .flat_map(|list| list.type_params())
+        {
+            let bounds = param
+                .type_bound_list()
+                .into_iter()
+                .flat_map(|list| list.bounds())
+                .map(|bound| TypeBound {
+                    type_ref: TypeRef::from_ast_opt(bound.type_ref()),
+                    ptr: AstPtr::new(&bound),
+                })
+                .collect();
+            self.params.push(GenericParam {
+                idx: self.params.len() as u32,
+                name: param
+                    .name()
+                    .map(|n| n.as_name())
+                    .unwrap_or_else(Name::missing),
+                bounds,
+            });
+        }
+    }
+
+    /// Returns the number of generic parameters.
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Returns true if the definition is not generic.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Returns the parameter with the specified `name`, if any.
+    pub fn find_by_name(&self, name: &Name) -> Option<&GenericParam> {
+        self.params.iter().find(|param| param.name == *name)
+    }
+
+    /// Returns substitutions that map every parameter onto itself. This is the list of type
+    /// arguments with which a generic definition is referred to from within its own definition.
+    pub fn identity_substs(&self) -> Substs {
+        self.params
+            .iter()
+            .map(|param| Ty::Param {
+                idx: param.idx,
+                name: param.name.clone(),
+            })
+            .collect()
+    }
+}
+
+/// A definition that can have generic type parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GenericDef {
+    Function(Function),
+    Struct(Struct),
+}
+impl_froms!(GenericDef: Function, Struct);
+
+impl GenericDef {
+    /// Returns the generic type parameters of the definition.
+    pub fn generic_params(self, db: &dyn HirDatabase) -> GenericParams {
+        match self {
+            GenericDef::Function(f) => f.generic_params(db),
+            GenericDef::Struct(s) => s.generic_params(db.upcast()),
+        }
+    }
+
+    fn resolver(self, db: &dyn HirDatabase) -> Resolver {
+        match self {
+            GenericDef::Function(f) => f.resolver(db),
+            GenericDef::Struct(s) => s.resolver(db),
+        }
+    }
+
+    /// Returns the traits that the type argument of the generic parameter with index `idx` must
+    /// implement. Bounds that do not refer to a trait are ignored.
+    pub fn trait_bounds(self, db: &dyn HirDatabase, idx: u32) -> Vec<Trait> {
+        // The implicit `Self` parameter of a function in a trait is bound by that trait
+        if let GenericDef::Function(f) = self {
+            if let (0, Some(trait_def)) = (idx, f.parent_trait(db.upcast())) {
+                return vec![trait_def];
+            }
+        }
+
+        let params = self.generic_params(db);
+        let resolver = self.resolver(db);
+        params
+            .params
+            .get(idx as usize)
+            .map(|param| {
+                param
+                    .bounds
+                    .iter()
+                    .filter_map(|bound| resolver.resolve_trait(db, &bound.type_ref))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Reports all bounds of the generic parameters that do not refer to a trait.
+    pub(crate) fn add_diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
+        let file_id = match self {
+            GenericDef::Function(f) => f.source(db.upcast()).file_id,
+            GenericDef::Struct(s) => s.source(db.upcast()).file_id,
+        };
+        let resolver = self.resolver(db);
+        let root = db.parse(file_id).syntax_node();
+        for param in self.generic_params(db).params.iter() {
+            for bound in param.bounds.iter() {
+                if resolver.resolve_trait(db, &bound.type_ref).is_none() {
+                    let node = bound.ptr.to_node(&root);
+                    sink.push(UnresolvedTrait {
+                        file: file_id,
+                        type_ref: bound.ptr.syntax_node_ptr(),
+                        name: node
+                            .type_ref()
+                            .map(|type_ref| type_ref.syntax().text().to_string())
+                            .unwrap_or_default(),
+                    });
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use crate::item_tree::{Function, ItemTreeId, ItemTreeNode, Struct, TypeAlias};
-use crate::DefDatabase;
+use crate::item_tree::{
+    Const, Enum, Function, Impl, ItemTreeId, ItemTreeNode, Static, Struct, Trait, TypeAlias,
+};
+use crate::{DefDatabase, FileId};
 use std::hash::{Hash, Hasher};
 
 #[derive(Debug)]
@@ -27,6 +29,45 @@ impl<N: ItemTreeNode> Clone for ItemLoc<N> {
 }
 impl<N: ItemTreeNode> Copy for ItemLoc<N> {}
 
+/// The container of an item that can be defined either at the top level of a module, inside an
+/// `impl` block or inside a trait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssocContainerId {
+    ModuleId(FileId),
+    ImplId(ImplId),
+    TraitId(TraitId),
+}
+
+#[derive(Debug)]
+pub struct AssocItemLoc<N: ItemTreeNode> {
+    pub container: AssocContainerId,
+    pub id: ItemTreeId<N>,
+}
+
+impl<N: ItemTreeNode> PartialEq for AssocItemLoc<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.container == other.container && self.id == other.id
+    }
+}
+impl<N: ItemTreeNode> Eq for AssocItemLoc<N> {}
+
+impl<N: ItemTreeNode> Hash for AssocItemLoc<N> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.container.hash(hasher);
+        self.id.hash(hasher);
+    }
+}
+
+impl<N: ItemTreeNode> Clone for AssocItemLoc<N> {
+    fn clone(&self) -> AssocItemLoc<N> {
+        AssocItemLoc {
+            container: self.container,
+            id: self.id,
+        }
+    }
+}
+impl<N: ItemTreeNode> Copy for AssocItemLoc<N> {}
+
 macro_rules! impl_intern_key {
     ($name:ident) => {
         impl salsa::InternKey for $name {
@@ -62,7 +103,7 @@ macro_rules! impl_intern {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub struct FunctionId(salsa::InternId);
-pub(crate) type FunctionLoc = ItemLoc<Function>;
+pub(crate) type FunctionLoc = AssocItemLoc<Function>;
 impl_intern!(
     FunctionId,
     FunctionLoc,
@@ -75,6 +116,11 @@ pub struct StructId(salsa::InternId);
 pub(crate) type StructLoc = ItemLoc<Struct>;
 impl_intern!(StructId, StructLoc, intern_struct, lookup_intern_struct);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnumId(salsa::InternId);
+pub(crate) type EnumLoc = ItemLoc<Enum>;
+impl_intern!(EnumId, EnumLoc, intern_enum, lookup_intern_enum);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TypeAliasId(salsa::InternId);
 pub(crate) type TypeAliasLoc = ItemLoc<TypeAlias>;
@@ -85,6 +131,26 @@ impl_intern!(
     lookup_intern_type_alias
 );
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstId(salsa::InternId);
+pub(crate) type ConstLoc = ItemLoc<Const>;
+impl_intern!(ConstId, ConstLoc, intern_const, lookup_intern_const);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaticId(salsa::InternId);
+pub(crate) type StaticLoc = ItemLoc<Static>;
+impl_intern!(StaticId, StaticLoc, intern_static, lookup_intern_static);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImplId(salsa::InternId);
+pub(crate) type ImplLoc = ItemLoc<Impl>;
+impl_intern!(ImplId, ImplLoc, intern_impl, lookup_intern_impl);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraitId(salsa::InternId);
+pub(crate) type TraitLoc = ItemLoc<Trait>;
+impl_intern!(TraitId, TraitLoc, intern_trait, lookup_intern_trait);
+
 pub trait Intern {
     type ID;
     fn intern(self, db: &dyn DefDatabase) -> Self::ID;
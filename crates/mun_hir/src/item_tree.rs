@@ -4,9 +4,10 @@ mod tests;
 
 use crate::{
     arena::{Arena, Idx},
+    path::Path,
     source_id::FileAstId,
     type_ref::TypeRef,
-    DefDatabase, FileId, InFile, Name,
+    DefDatabase, FileId, InFile, Name, Visibility,
 };
 use mun_syntax::{ast, AstNode};
 use std::{
@@ -57,7 +58,14 @@ struct ItemTreeData {
     functions: Arena<Function>,
     structs: Arena<Struct>,
     fields: Arena<Field>,
+    enums: Arena<Enum>,
+    variants: Arena<Variant>,
     type_aliases: Arena<TypeAlias>,
+    consts: Arena<Const>,
+    statics: Arena<Static>,
+    impls: Arena<Impl>,
+    traits: Arena<Trait>,
+    imports: Arena<Import>,
 }
 
 /// Trait implemented by all item nodes in the item tree.
@@ -171,7 +179,13 @@ macro_rules! mod_items {
 mod_items! {
     Function in functions -> ast::FunctionDef,
     Struct in structs -> ast::StructDef,
+    Enum in enums -> ast::EnumDef,
     TypeAlias in type_aliases -> ast::TypeAliasDef,
+    Const in consts -> ast::ConstDef,
+    Static in statics -> ast::StaticDef,
+    Impl in impls -> ast::ImplDef,
+    Trait in traits -> ast::TraitDef,
+    Import in imports -> ast::Use,
 }
 
 macro_rules! impl_index {
@@ -188,7 +202,7 @@ macro_rules! impl_index {
     };
 }
 
-impl_index!(fields: Field);
+impl_index!(fields: Field, variants: Variant);
 
 impl<N: ItemTreeNode> Index<LocalItemTreeId<N>> for ItemTree {
     type Output = N;
@@ -200,6 +214,7 @@ impl<N: ItemTreeNode> Index<LocalItemTreeId<N>> for ItemTree {
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Function {
     pub name: Name,
+    pub visibility: Visibility,
     pub is_extern: bool,
     pub params: Box<[TypeRef]>,
     pub ret_type: TypeRef,
@@ -209,18 +224,85 @@ pub struct Function {
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Struct {
     pub name: Name,
+    pub visibility: Visibility,
     pub fields: Fields,
     pub ast_id: FileAstId<ast::StructDef>,
     pub kind: StructDefKind,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Enum {
+    pub name: Name,
+    pub visibility: Visibility,
+    pub variants: IdRange<Variant>,
+    pub ast_id: FileAstId<ast::EnumDef>,
+}
+
+/// A single variant of an enum
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Variant {
+    pub name: Name,
+    pub fields: Fields,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TypeAlias {
     pub name: Name,
+    pub visibility: Visibility,
     pub type_ref: Option<TypeRef>,
     pub ast_id: FileAstId<ast::TypeAliasDef>,
 }
 
+/// A constant (e.g. `const FOO: i32 = 5;`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Const {
+    pub name: Name,
+    pub visibility: Visibility,
+    pub type_ref: TypeRef,
+    pub ast_id: FileAstId<ast::ConstDef>,
+}
+
+/// A module-level variable (e.g. `static mut FOO: i32 = 5;`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Static {
+    pub name: Name,
+    pub visibility: Visibility,
+    pub is_mut: bool,
+    pub type_ref: TypeRef,
+    pub ast_id: FileAstId<ast::StaticDef>,
+}
+
+/// An `impl` block (e.g. `impl Foo { ... }` or `impl Bar for Foo { ... }`)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Impl {
+    pub self_ty: TypeRef,
+    /// The trait that is implemented by the block, if any
+    pub target_trait: Option<TypeRef>,
+    pub items: Box<[LocalItemTreeId<Function>]>,
+    pub ast_id: FileAstId<ast::ImplDef>,
+}
+
+/// A trait declaration (e.g. `trait Foo { ... }`)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Trait {
+    pub name: Name,
+    pub visibility: Visibility,
+    pub items: Box<[LocalItemTreeId<Function>]>,
+    pub ast_id: FileAstId<ast::TraitDef>,
+}
+
+/// A single path imported by a `use` item, e.g. `foo::bar` in `use foo::{bar, baz};`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Import {
+    pub path: Path,
+    /// Whether all the items of `path` are imported, e.g. `use foo::*;`
+    pub is_glob: bool,
+    pub visibility: Visibility,
+    pub ast_id: FileAstId<ast::Use>,
+    /// The index of this import among the imports of the `use` item
+    pub index: usize,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum StructDefKind {
     /// `struct S { ... }` - type namespace only.
@@ -1,19 +1,24 @@
 //! This module implements the logic to convert an AST to an `ItemTree`.
 
 use super::{
-    Field, Fields, Function, IdRange, ItemTree, ItemTreeData, ItemTreeNode, LocalItemTreeId,
-    ModItem, Struct, StructDefKind, TypeAlias,
+    Const, Enum, Field, Fields, Function, IdRange, Impl, Import, ItemTree, ItemTreeData,
+    ItemTreeNode, LocalItemTreeId, ModItem, Static, Struct, StructDefKind, Trait, TypeAlias,
+    Variant,
 };
 use crate::{
     arena::{Idx, RawId},
     name::AsName,
+    path::Path,
     source_id::AstIdMap,
     type_ref::TypeRef,
-    DefDatabase, FileId, Name,
+    DefDatabase, FileId, Name, Visibility,
 };
 use mun_syntax::{
     ast,
-    ast::{ExternOwner, ModuleItemOwner, NameOwner, StructKind, TypeAscriptionOwner},
+    ast::{
+        ExternOwner, FunctionDefOwner, ModuleItemOwner, NameOwner, StructKind, TypeAscriptionOwner,
+        VisibilityOwner,
+    },
 };
 use std::{convert::TryInto, marker::PhantomData, sync::Arc};
 
@@ -54,18 +59,29 @@ impl Context {
         }
     }
 
-    /// Lowers a single module item
-    fn lower_mod_item(&mut self, item: &ast::ModuleItem) -> Option<ModItem> {
-        match item.kind() {
+    /// Lowers a single module item. A `use` item is lowered to an import for every path it
+    /// imports.
+    fn lower_mod_item(&mut self, item: &ast::ModuleItem) -> Vec<ModItem> {
+        let item = match item.kind() {
             ast::ModuleItemKind::FunctionDef(ast) => self.lower_function(&ast).map(Into::into),
             ast::ModuleItemKind::StructDef(ast) => self.lower_struct(&ast).map(Into::into),
+            ast::ModuleItemKind::EnumDef(ast) => self.lower_enum(&ast).map(Into::into),
             ast::ModuleItemKind::TypeAliasDef(ast) => self.lower_type_alias(&ast).map(Into::into),
-        }
+            ast::ModuleItemKind::ConstDef(ast) => self.lower_const(&ast).map(Into::into),
+            ast::ModuleItemKind::StaticDef(ast) => self.lower_static(&ast).map(Into::into),
+            ast::ModuleItemKind::ImplDef(ast) => self.lower_impl(&ast).map(Into::into),
+            ast::ModuleItemKind::TraitDef(ast) => self.lower_trait(&ast).map(Into::into),
+            ast::ModuleItemKind::Use(ast) => {
+                return self.lower_use(&ast).into_iter().map(Into::into).collect();
+            }
+        };
+        item.into_iter().collect()
     }
 
     /// Lowers a function
     fn lower_function(&mut self, func: &ast::FunctionDef) -> Option<LocalItemTreeId<Function>> {
         let name = func.name()?.as_name();
+        let visibility = lower_visibility(func);
 
         // Lower all the params
         let mut params = Vec::new();
@@ -87,6 +103,7 @@ impl Context {
         let ast_id = self.source_ast_id_map.ast_id(func);
         let res = Function {
             name,
+            visibility,
             is_extern,
             params: params.into_boxed_slice(),
             ret_type,
@@ -99,6 +116,7 @@ impl Context {
     /// Lowers a struct
     fn lower_struct(&mut self, strukt: &ast::StructDef) -> Option<LocalItemTreeId<Struct>> {
         let name = strukt.name()?.as_name();
+        let visibility = lower_visibility(strukt);
         let fields = self.lower_fields(&strukt.kind());
         let ast_id = self.source_ast_id_map.ast_id(strukt);
         let kind = match strukt.kind() {
@@ -108,6 +126,7 @@ impl Context {
         };
         let res = Struct {
             name,
+            visibility,
             fields,
             ast_id,
             kind,
@@ -115,6 +134,43 @@ impl Context {
         Some(self.data.structs.alloc(res).into())
     }
 
+    /// Lowers an enum
+    fn lower_enum(&mut self, enum_def: &ast::EnumDef) -> Option<LocalItemTreeId<Enum>> {
+        let name = enum_def.name()?.as_name();
+        let visibility = lower_visibility(enum_def);
+        let variants = match enum_def.enum_variant_list() {
+            Some(variant_list) => self.lower_variants(&variant_list),
+            None => IdRange::new(self.next_variant_idx()..self.next_variant_idx()),
+        };
+        let ast_id = self.source_ast_id_map.ast_id(enum_def);
+        let res = Enum {
+            name,
+            visibility,
+            variants,
+            ast_id,
+        };
+        Some(self.data.enums.alloc(res).into())
+    }
+
+    /// Lowers the variants of an enum (e.g. `{ A, B(i32) }`)
+    fn lower_variants(&mut self, variants: &ast::EnumVariantList) -> IdRange<Variant> {
+        let start = self.next_variant_idx();
+        for variant in variants.variants() {
+            if let Some(data) = self.lower_variant(&variant) {
+                let _idx = self.data.variants.alloc(data);
+            }
+        }
+        let end = self.next_variant_idx();
+        IdRange::new(start..end)
+    }
+
+    /// Lowers a single enum variant (e.g. `B(i32)`)
+    fn lower_variant(&mut self, variant: &ast::EnumVariant) -> Option<Variant> {
+        let name = variant.name()?.as_name();
+        let fields = self.lower_fields(&variant.kind());
+        Some(Variant { name, fields })
+    }
+
     /// Lowers the fields of a struct or enum
     fn lower_fields(&mut self, struct_kind: &ast::StructKind) -> Fields {
         match struct_kind {
@@ -174,16 +230,120 @@ impl Context {
         type_alias: &ast::TypeAliasDef,
     ) -> Option<LocalItemTreeId<TypeAlias>> {
         let name = type_alias.name()?.as_name();
+        let visibility = lower_visibility(type_alias);
         let type_ref = type_alias.type_ref().map(|ty| self.lower_type_ref(&ty));
         let ast_id = self.source_ast_id_map.ast_id(type_alias);
         let res = TypeAlias {
             name,
+            visibility,
             type_ref,
             ast_id,
         };
         Some(self.data.type_aliases.alloc(res).into())
     }
 
+    /// Lowers a constant (e.g. `const FOO: i32 = 5;`)
+    fn lower_const(&mut self, konst: &ast::ConstDef) -> Option<LocalItemTreeId<Const>> {
+        let name = konst.name()?.as_name();
+        let visibility = lower_visibility(konst);
+        let type_ref = self.lower_type_ref_opt(konst.ascribed_type());
+        let ast_id = self.source_ast_id_map.ast_id(konst);
+        let res = Const {
+            name,
+            visibility,
+            type_ref,
+            ast_id,
+        };
+        Some(self.data.consts.alloc(res).into())
+    }
+
+    /// Lowers a module-level variable (e.g. `static mut FOO: i32 = 5;`)
+    fn lower_static(&mut self, statik: &ast::StaticDef) -> Option<LocalItemTreeId<Static>> {
+        let name = statik.name()?.as_name();
+        let visibility = lower_visibility(statik);
+        let type_ref = self.lower_type_ref_opt(statik.ascribed_type());
+        let ast_id = self.source_ast_id_map.ast_id(statik);
+        let res = Static {
+            name,
+            visibility,
+            is_mut: statik.is_mut(),
+            type_ref,
+            ast_id,
+        };
+        Some(self.data.statics.alloc(res).into())
+    }
+
+    /// Lowers an `impl` block (e.g. `impl Foo { ... }`). The functions of the block are stored in
+    /// the item tree but are not part of the top level items.
+    fn lower_impl(&mut self, impl_def: &ast::ImplDef) -> Option<LocalItemTreeId<Impl>> {
+        let self_ty = self.lower_type_ref_opt(impl_def.target_type());
+        let target_trait = impl_def
+            .target_trait()
+            .map(|type_ref| self.lower_type_ref(&type_ref));
+        let items = self.lower_item_list(impl_def.item_list());
+        let ast_id = self.source_ast_id_map.ast_id(impl_def);
+        let res = Impl {
+            self_ty,
+            target_trait,
+            items,
+            ast_id,
+        };
+        Some(self.data.impls.alloc(res).into())
+    }
+
+    /// Lowers a trait (e.g. `trait Foo { ... }`). Just like for an `impl` block, the functions of
+    /// the trait are not part of the top level items.
+    fn lower_trait(&mut self, trait_def: &ast::TraitDef) -> Option<LocalItemTreeId<Trait>> {
+        let name = trait_def.name()?.as_name();
+        let visibility = lower_visibility(trait_def);
+        let items = self.lower_item_list(trait_def.item_list());
+        let ast_id = self.source_ast_id_map.ast_id(trait_def);
+        let res = Trait {
+            name,
+            visibility,
+            items,
+            ast_id,
+        };
+        Some(self.data.traits.alloc(res).into())
+    }
+
+    /// Lowers a `use` item (e.g. `use foo::{bar, baz};`) into an import for every path it imports
+    fn lower_use(&mut self, use_item: &ast::Use) -> Vec<LocalItemTreeId<Import>> {
+        let visibility = lower_visibility(use_item);
+        let ast_id = self.source_ast_id_map.ast_id(use_item);
+
+        let mut imports = Vec::new();
+        Path::expand_use_item(use_item, |_, path, is_glob| {
+            imports.push(Import {
+                path,
+                is_glob,
+                visibility,
+                ast_id,
+                index: imports.len(),
+            })
+        });
+
+        imports
+            .into_iter()
+            .map(|import| self.data.imports.alloc(import).into())
+            .collect()
+    }
+
+    /// Lowers the functions of an `impl` block or trait
+    fn lower_item_list(
+        &mut self,
+        item_list: Option<ast::ItemList>,
+    ) -> Box<[LocalItemTreeId<Function>]> {
+        item_list
+            .map(|item_list| {
+                item_list
+                    .functions()
+                    .filter_map(|func| self.lower_function(&func))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Lowers an `ast::TypeRef`
     fn lower_type_ref(&self, type_ref: &ast::TypeRef) -> TypeRef {
         TypeRef::from_ast(type_ref.clone())
@@ -196,9 +356,27 @@ impl Context {
             .unwrap_or(TypeRef::Error)
     }
 
+    /// Returns the `Idx` of the next `Variant`
+    fn next_variant_idx(&self) -> Idx<Variant> {
+        let idx: u32 = self
+            .data
+            .variants
+            .len()
+            .try_into()
+            .expect("too many variants");
+        Idx::from_raw(RawId::from(idx))
+    }
+
     /// Returns the `Idx` of the next `Field`
     fn next_field_idx(&self) -> Idx<Field> {
         let idx: u32 = self.data.fields.len().try_into().expect("too many fields");
         Idx::from_raw(RawId::from(idx))
     }
 }
+
+/// Lowers the visibility of an item. Any form of `pub` makes an item public.
+fn lower_visibility(item: &impl VisibilityOwner) -> Visibility {
+    item.visibility()
+        .map(|_| Visibility::Public)
+        .unwrap_or(Visibility::Private)
+}
@@ -41,9 +41,44 @@ fn format_mod_item(out: &mut String, tree: &ItemTree, item: ModItem) -> fmt::Res
                 _ => {}
             };
         }
+        ModItem::Enum(item) => {
+            write!(out, "{:?}", tree[item])?;
+            for variant in tree[item].variants.clone() {
+                write!(children, "{:?}\n", tree[variant])?;
+                match &tree[variant].fields {
+                    Fields::Record(a) | Fields::Tuple(a) => {
+                        for field in a.clone() {
+                            write!(children, "  {:?}\n", tree[field])?;
+                        }
+                    }
+                    _ => {}
+                };
+            }
+        }
         ModItem::TypeAlias(item) => {
             write!(out, "{:?}", tree[item])?;
         }
+        ModItem::Const(item) => {
+            write!(out, "{:?}", tree[item])?;
+        }
+        ModItem::Static(item) => {
+            write!(out, "{:?}", tree[item])?;
+        }
+        ModItem::Impl(item) => {
+            write!(out, "{:?}", tree[item])?;
+            for function in tree[item].items.iter() {
+                write!(children, "{:?}\n", tree[*function])?;
+            }
+        }
+        ModItem::Trait(item) => {
+            write!(out, "{:?}", tree[item])?;
+            for function in tree[item].items.iter() {
+                write!(children, "{:?}\n", tree[*function])?;
+            }
+        }
+        ModItem::Import(item) => {
+            write!(out, "{:?}", tree[item])?;
+        }
     }
 
     for line in children.lines() {
@@ -81,3 +116,73 @@ fn top_level_items() {
     )
     .unwrap());
 }
+
+#[test]
+fn enums() {
+    insta::assert_snapshot!(print_item_tree(
+        r#"
+    enum Foo {
+        A,
+        B(i32, u8),
+    }
+    enum Bar {}
+    "#
+    )
+    .unwrap());
+}
+
+#[test]
+fn impls() {
+    insta::assert_snapshot!(print_item_tree(
+        r#"
+    struct Foo;
+    impl Foo {
+        fn new() -> Self {}
+        fn bar(self, a: i32) -> i32 {}
+    }
+    "#
+    )
+    .unwrap());
+}
+
+#[test]
+fn traits() {
+    insta::assert_snapshot!(print_item_tree(
+        r#"
+    trait Foo {
+        fn foo(self) -> i32;
+    }
+    struct Bar;
+    impl Foo for Bar {
+        fn foo(self) -> i32 {}
+    }
+    "#
+    )
+    .unwrap());
+}
+
+#[test]
+fn imports() {
+    insta::assert_snapshot!(print_item_tree(
+        r#"
+    use foo;
+    pub use package::bar::{baz, qux::*};
+    use super::super::Foo;
+    pub fn main() {}
+    "#
+    )
+    .unwrap());
+}
+
+#[test]
+fn consts_and_statics() {
+    insta::assert_snapshot!(print_item_tree(
+        r#"
+    const FOO: i32 = 5;
+    pub const BAR: f32 = 1.0;
+    static mut BAZ: i32 = 0;
+    pub static QUX = 1;
+    "#
+    )
+    .unwrap());
+}
@@ -12,17 +12,21 @@ mod arena;
 mod adt;
 mod builtin_type;
 mod code_model;
+mod const_eval;
 mod db;
 pub mod diagnostics;
 mod display;
 mod expr;
 mod fixture;
+mod generics;
 mod ids;
 mod in_file;
 mod input;
 mod item_tree;
 pub mod line_index;
+mod lint;
 mod model;
+mod module_tree;
 mod name;
 mod name_resolution;
 mod path;
@@ -43,27 +47,33 @@ pub use relative_path::{RelativePath, RelativePathBuf};
 
 pub use crate::{
     builtin_type::{FloatBitness, IntBitness, Signedness},
+    const_eval::{ConstEvalError, ConstValue},
     db::{
         AstDatabase, AstDatabaseStorage, DefDatabase, DefDatabaseStorage, HirDatabase,
         HirDatabaseStorage, InternDatabase, InternDatabaseStorage, SourceDatabase,
         SourceDatabaseStorage, Upcast,
     },
-    diagnostics::{Diagnostic, DiagnosticSink},
+    diagnostics::{Diagnostic, DiagnosticSink, Severity},
     display::HirDisplay,
     expr::{
-        resolver_for_expr, ArithOp, BinaryOp, Body, CmpOp, Expr, ExprId, ExprScopes, Literal,
-        LogicOp, Ordering, Pat, PatId, RecordLitField, Statement, UnaryOp,
+        dataflow, resolver_for_expr, ArithOp, BasicBlock, BasicBlockData, BinaryOp, Body, CmpOp,
+        ControlFlowGraph, Expr, ExprId, ExprScopes, Literal, LogicOp, MatchArm, Ordering,
+        OverflowBehavior, Pat, PatId, RangeOp, RecordLitField, Statement, Step, Terminator,
+        UnaryOp,
     },
+    generics::{GenericDef, GenericParam, GenericParams, TypeBound},
     ids::ItemLoc,
     in_file::InFile,
     input::{FileId, SourceRoot, SourceRootId},
+    lint::{manifest_lint_levels, Lint, LintLevel, UnknownLint},
+    module_tree::{LocalModuleId, ModuleTree, ModuleTreeNode},
     name::Name,
     name_resolution::PerNs,
     path::{Path, PathKind},
     resolve::{Resolution, Resolver},
     ty::{
-        lower::CallableDef, ApplicationTy, FloatTy, InferenceResult, IntTy, ResolveBitness, Ty,
-        TypeCtor,
+        lower::CallableDef, ApplicationTy, FloatTy, FnSig, InferenceResult, IntTy, ResolveBitness,
+        Substs, Ty, TypeCtor,
     },
 };
 
@@ -71,5 +81,6 @@ use crate::{name::AsName, source_id::AstIdMap};
 
 pub use self::adt::StructMemoryKind;
 pub use self::code_model::{
-    Function, FunctionData, Module, ModuleDef, Struct, TypeAlias, Visibility,
+    Const, Enum, EnumVariant, Function, FunctionData, Impl, Module, ModuleDef, Static, Struct,
+    Trait, TypeAlias, Visibility,
 };
@@ -0,0 +1,154 @@
+//! Lints are diagnostics that point out code that compiles but likely contains a mistake, e.g. an
+//! unused variable. The level at which a lint is reported can be configured with attributes, e.g.
+//! `#[allow(unused_variables)]`, on the item or block that contains the code.
+
+use crate::{Diagnostic, HirDatabase};
+use mun_syntax::{ast, AstNode, SyntaxNode};
+use std::{collections::HashMap, fmt};
+
+/// A configurable class of warnings.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// A local variable that is never used
+    UnusedVariables,
+    /// A private function, method or struct that is never used
+    DeadCode,
+    /// A statement or expression that can never be reached
+    UnreachableCode,
+    /// A `static mut` or a local variable declared `mut` that does not need to be mutable
+    UnusedMut,
+    /// A `loop` that is never exited
+    InfiniteLoops,
+    /// A lint attribute that refers to a lint that does not exist
+    UnknownLints,
+}
+
+impl Lint {
+    /// All the lints that are known to the compiler
+    pub const ALL: [Lint; 6] = [
+        Lint::UnusedVariables,
+        Lint::DeadCode,
+        Lint::UnreachableCode,
+        Lint::UnusedMut,
+        Lint::InfiniteLoops,
+        Lint::UnknownLints,
+    ];
+
+    /// Returns the name with which the lint is referred to in attributes and manifests.
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::UnusedVariables => "unused_variables",
+            Lint::DeadCode => "dead_code",
+            Lint::UnreachableCode => "unreachable_code",
+            Lint::UnusedMut => "unused_mut",
+            Lint::InfiniteLoops => "infinite_loops",
+            Lint::UnknownLints => "unknown_lints",
+        }
+    }
+
+    /// Returns the lint with the specified name, if any.
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.iter().copied().find(|lint| lint.name() == name)
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The level at which a lint is reported.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LintLevel {
+    /// The lint is not reported
+    Allow,
+    /// The lint is reported as a warning
+    Warn,
+    /// The lint is reported as an error
+    Deny,
+}
+
+impl LintLevel {
+    /// Returns the level that is set by an attribute with the specified name, e.g. `allow`.
+    pub fn from_name(name: &str) -> Option<LintLevel> {
+        match name {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+}
+
+impl From<mun_project::LintLevel> for LintLevel {
+    fn from(level: mun_project::LintLevel) -> Self {
+        match level {
+            mun_project::LintLevel::Allow => LintLevel::Allow,
+            mun_project::LintLevel::Warn => LintLevel::Warn,
+            mun_project::LintLevel::Deny => LintLevel::Deny,
+        }
+    }
+}
+
+/// An error that is returned when a manifest configures the level of a lint that does not exist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownLint {
+    pub name: String,
+}
+
+impl fmt::Display for UnknownLint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown lint `{}` in manifest", self.name)
+    }
+}
+
+impl std::error::Error for UnknownLint {}
+
+/// Returns the levels of the lints that are configured in the `lints` section of `manifest`. Fails
+/// if the manifest refers to a lint that does not exist.
+pub fn manifest_lint_levels(
+    manifest: &mun_project::Manifest,
+) -> Result<HashMap<Lint, LintLevel>, UnknownLint> {
+    manifest
+        .lints()
+        .iter()
+        .map(|(name, level)| {
+            let lint = Lint::from_name(name).ok_or_else(|| UnknownLint { name: name.clone() })?;
+            Ok((lint, LintLevel::from(*level)))
+        })
+        .collect()
+}
+
+impl dyn Diagnostic {
+    /// Returns the level of the lint of this diagnostic as specified by the attributes of the
+    /// items and blocks that contain the source of the diagnostic. Returns `None` if the diagnostic
+    /// is not a lint or if no attribute specifies its level.
+    pub fn lint_level(&self, db: &dyn HirDatabase) -> Option<LintLevel> {
+        let lint = self.lint()?;
+        self.syntax_node(db)
+            .ancestors()
+            .find_map(|node| lint_level_from_attrs(&node, lint))
+    }
+}
+
+/// Returns the level that the attributes of `node` specify for `lint`. If multiple attributes refer
+/// to the lint, the last one takes precedence.
+fn lint_level_from_attrs(node: &SyntaxNode, lint: Lint) -> Option<LintLevel> {
+    // Attributes are always direct children of the item or block they apply to
+    node.children()
+        .filter_map(ast::Attr::cast)
+        .filter_map(|attr| {
+            let level = LintLevel::from_name(&attr.simple_name()?)?;
+            if attr
+                .token_tree()?
+                .idents()
+                .any(|name| name.text() == lint.name())
+            {
+                Some(level)
+            } else {
+                None
+            }
+        })
+        .last()
+}
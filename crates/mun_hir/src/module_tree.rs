@@ -0,0 +1,163 @@
+//! The `ModuleTree` describes the hierarchy of modules in a package. Every source file defines a
+//! module of which the path is derived from the location of the file relative to its source root:
+//!
+//! * `main.mun` or `mod.mun` defines the root module of the package,
+//! * `foo.mun` or `foo/mod.mun` defines the module `foo`, and
+//! * `foo/bar.mun` defines the module `foo::bar`.
+//!
+//! A source root that contains a single file is always rooted in that file.
+
+use crate::{
+    arena::{Arena, Idx},
+    name::AsName,
+    DefDatabase, FileId, Name, SourceRootId,
+};
+use relative_path::{Component, RelativePath};
+use rustc_hash::FxHashMap;
+use std::{ops::Index, sync::Arc};
+
+/// The id of a module within a `ModuleTree`
+pub type LocalModuleId = Idx<ModuleTreeNode>;
+
+/// A single module in a `ModuleTree`
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ModuleTreeNode {
+    /// The parent of this module or `None` if this is the root module
+    pub parent: Option<LocalModuleId>,
+
+    /// The name of this module or `None` if this is the root module
+    pub name: Option<Name>,
+
+    /// The child modules of this module, accessible by name
+    pub children: FxHashMap<Name, LocalModuleId>,
+
+    /// The file that defines this module. A module without a file only contains child modules,
+    /// e.g. `foo` if there is a `foo/bar.mun` but no `foo.mun`.
+    pub file: Option<FileId>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ModuleTree {
+    root: LocalModuleId,
+    modules: Arena<ModuleTreeNode>,
+    file_to_module: FxHashMap<FileId, LocalModuleId>,
+}
+
+impl ModuleTree {
+    /// Constructs the `ModuleTree` of all the files in the specified source root
+    pub(crate) fn module_tree_query(
+        db: &dyn DefDatabase,
+        source_root_id: SourceRootId,
+    ) -> Arc<ModuleTree> {
+        let source_root = db.source_root(source_root_id);
+
+        // Sort the files by path to ensure that the tree is the same regardless of the order in
+        // which files were added.
+        let mut files: Vec<_> = source_root
+            .files()
+            .map(|file_id| (db.file_relative_path(file_id), file_id))
+            .collect();
+        files.sort();
+
+        let mut modules = Arena::default();
+        let root = modules.alloc(ModuleTreeNode::default());
+        let mut tree = ModuleTree {
+            root,
+            modules,
+            file_to_module: FxHashMap::default(),
+        };
+
+        if let [(_, file_id)] = files.as_slice() {
+            tree.set_file(root, *file_id);
+        } else {
+            for (relative_path, file_id) in files {
+                let module_id = module_path(&relative_path)
+                    .into_iter()
+                    .fold(root, |parent, name| tree.get_or_insert_child(parent, name));
+
+                // If two files define the same module, the first one is used.
+                if tree.modules[module_id].file.is_none() {
+                    tree.set_file(module_id, file_id);
+                }
+            }
+        }
+
+        Arc::new(tree)
+    }
+
+    /// Returns the root module of the package
+    pub fn root(&self) -> LocalModuleId {
+        self.root
+    }
+
+    /// Returns the module that is defined by the specified file, if any.
+    pub fn module_for_file(&self, file_id: FileId) -> Option<LocalModuleId> {
+        self.file_to_module.get(&file_id).copied()
+    }
+
+    /// Returns the child module of `module` with the specified `name`, if any.
+    pub fn child(&self, module: LocalModuleId, name: &Name) -> Option<LocalModuleId> {
+        self.modules[module].children.get(name).copied()
+    }
+
+    /// Returns the names of the modules from the root to the specified module, e.g. `[foo, bar]`
+    /// for `foo::bar`. The path of the root module is empty.
+    pub fn path(&self, module: LocalModuleId) -> Vec<Name> {
+        let mut path = Vec::new();
+        let mut current = &self.modules[module];
+        while let Some(parent) = current.parent {
+            path.extend(current.name.clone());
+            current = &self.modules[parent];
+        }
+        path.reverse();
+        path
+    }
+
+    /// Returns the child of `parent` with the specified `name`, creating it if it does not exist.
+    fn get_or_insert_child(&mut self, parent: LocalModuleId, name: Name) -> LocalModuleId {
+        if let Some(child) = self.child(parent, &name) {
+            return child;
+        }
+        let child = self.modules.alloc(ModuleTreeNode {
+            parent: Some(parent),
+            name: Some(name.clone()),
+            ..Default::default()
+        });
+        self.modules[parent].children.insert(name, child);
+        child
+    }
+
+    /// Associates the specified file with `module`
+    fn set_file(&mut self, module: LocalModuleId, file_id: FileId) {
+        self.modules[module].file = Some(file_id);
+        self.file_to_module.insert(file_id, module);
+    }
+}
+
+impl Index<LocalModuleId> for ModuleTree {
+    type Output = ModuleTreeNode;
+
+    fn index(&self, index: LocalModuleId) -> &Self::Output {
+        &self.modules[index]
+    }
+}
+
+/// Returns the names of the modules from the root to the module defined by the file at the
+/// specified path.
+fn module_path(relative_path: &RelativePath) -> Vec<Name> {
+    let mut path: Vec<Name> = relative_path
+        .parent()
+        .into_iter()
+        .flat_map(|parent| parent.components())
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.as_name()),
+            _ => None,
+        })
+        .collect();
+    match relative_path.file_stem() {
+        Some("mod") | None => (),
+        Some("main") if path.is_empty() => (),
+        Some(stem) => path.push(stem.as_name()),
+    }
+    path
+}
@@ -52,7 +52,7 @@ impl Name {
         Name::new_text("[missing name]".into())
     }
 
-    pub(crate) fn as_tuple_index(&self) -> Option<usize> {
+    pub fn as_tuple_index(&self) -> Option<usize> {
         match self.0 {
             Repr::TupleField(idx) => Some(idx),
             _ => None,
@@ -79,6 +79,12 @@ impl AsName for ast::Name {
     }
 }
 
+impl AsName for str {
+    fn as_name(&self) -> Name {
+        Name::resolve(&SmolStr::new(self))
+    }
+}
+
 impl AsName for ast::FieldKind {
     fn as_name(&self) -> Name {
         match self {
@@ -102,11 +108,23 @@ pub mod known {
     known_names!(
         // Primitives
         int, isize, i8, i16, i32, i64, i128, uint, usize, u8, u16, u32, u64, u128, float, f32, f64,
-        bool,
+        bool, string, len,
+        // Methods that overload operators
+        add, sub, mul, div, rem, shl, shr, bitand, bitor, bitxor, eq, lt, le, gt, ge,
     );
 
+    // self/Self cannot be used as an identifier
+    pub const SELF_PARAM: super::Name = super::Name::new_inline("self");
+    pub const SELF_TYPE: super::Name = super::Name::new_inline("Self");
+
     #[macro_export]
     macro_rules! name {
+        (self) => {
+            $crate::name::known::SELF_PARAM
+        };
+        (Self) => {
+            $crate::name::known::SELF_TYPE
+        };
         ($ident:ident) => {
             $crate::name::known::$ident
         };
@@ -1,7 +1,14 @@
+mod imports;
 mod per_ns;
 
+pub use self::imports::ModuleImports;
 pub use self::per_ns::{Namespace, PerNs};
-use crate::{builtin_type::BuiltinType, FileId, HirDatabase, ModuleDef, Name};
+use crate::{
+    builtin_type::BuiltinType,
+    module_tree::{LocalModuleId, ModuleTree},
+    path::{PathKind, PathSegment},
+    FileId, HirDatabase, ModuleDef, Name,
+};
 use once_cell::sync::Lazy;
 use rustc_hash::FxHashMap;
 use std::sync::Arc;
@@ -41,6 +48,69 @@ impl ModuleScope {
     pub fn get(&self, name: &Name) -> Option<&Resolution> {
         self.items.get(name).or_else(|| BUILTIN_SCOPE.get(name))
     }
+
+    /// Returns the item declared in the module with the specified name, ignoring builtin types.
+    pub fn get_declared(&self, name: &Name) -> Option<&Resolution> {
+        self.items.get(name)
+    }
+
+    /// Returns all the items declared in the module, ignoring builtin types.
+    pub fn declarations<'a>(&'a self) -> impl Iterator<Item = (&'a Name, &'a Resolution)> + 'a {
+        self.items.iter()
+    }
+}
+
+/// Resolves the module that a path of the specified `kind` and `segments` refers to, relative to
+/// `module`. Returns `None` if one of the segments does not refer to a module.
+pub(crate) fn resolve_module_path(
+    module_tree: &ModuleTree,
+    module: LocalModuleId,
+    kind: &PathKind,
+    segments: &[PathSegment],
+) -> Option<LocalModuleId> {
+    let mut current = match kind {
+        PathKind::Plain => module,
+        PathKind::Super(count) => {
+            let mut current = module;
+            for _ in 0..*count {
+                current = module_tree[current].parent?;
+            }
+            current
+        }
+        PathKind::Package => module_tree.root(),
+    };
+    for segment in segments {
+        current = module_tree.child(current, &segment.name)?;
+    }
+    Some(current)
+}
+
+/// Returns the definitions with the specified `name` that are declared in `module`. Private
+/// definitions are only returned if `from` is `module` itself.
+pub(crate) fn resolve_module_item(
+    db: &dyn HirDatabase,
+    module_tree: &ModuleTree,
+    module: LocalModuleId,
+    name: &Name,
+    from: LocalModuleId,
+) -> PerNs<ModuleDef> {
+    let file_id = match module_tree[module].file {
+        Some(file_id) => file_id,
+        None => return PerNs::none(),
+    };
+    let private_visible = from == module;
+    db.module_scope(file_id)
+        .get_declared(name)
+        .map(|resolution| {
+            resolution.def.and_then(|def| {
+                if private_visible || def.visibility(db).is_public() {
+                    Some(def)
+                } else {
+                    None
+                }
+            })
+        })
+        .unwrap_or_else(PerNs::none)
 }
 
 pub(crate) fn module_scope_query(db: &dyn HirDatabase, file_id: FileId) -> Arc<ModuleScope> {
@@ -64,6 +134,22 @@ pub(crate) fn module_scope_query(db: &dyn HirDatabase, file_id: FileId) -> Arc<M
                     },
                 );
             }
+            ModuleDef::Enum(e) => {
+                scope.items.insert(
+                    e.name(db.upcast()),
+                    Resolution {
+                        def: PerNs::types(*def),
+                    },
+                );
+            }
+            ModuleDef::Trait(t) => {
+                scope.items.insert(
+                    t.name(db.upcast()),
+                    Resolution {
+                        def: PerNs::types(*def),
+                    },
+                );
+            }
             ModuleDef::TypeAlias(t) => {
                 scope.items.insert(
                     t.name(db.upcast()),
@@ -72,7 +158,23 @@ pub(crate) fn module_scope_query(db: &dyn HirDatabase, file_id: FileId) -> Arc<M
                     },
                 );
             }
-            ModuleDef::BuiltinType(_) => {}
+            ModuleDef::Const(c) => {
+                scope.items.insert(
+                    c.name(db.upcast()),
+                    Resolution {
+                        def: PerNs::values(*def),
+                    },
+                );
+            }
+            ModuleDef::Static(s) => {
+                scope.items.insert(
+                    s.name(db.upcast()),
+                    Resolution {
+                        def: PerNs::values(*def),
+                    },
+                );
+            }
+            ModuleDef::BuiltinType(_) | ModuleDef::EnumVariant(_) => {}
         }
     }
     Arc::new(scope)
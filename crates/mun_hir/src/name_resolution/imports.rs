@@ -0,0 +1,161 @@
+//! Resolves the imports of a module, e.g. `use package::physics::integrate;`. Imports are resolved
+//! against the items that are declared in the imported modules; imported names are not exported
+//! again.
+
+use super::{resolve_module_item, resolve_module_path, PerNs, Resolution};
+use crate::{
+    diagnostics::{DiagnosticSink, PrivateImport, UnresolvedImport},
+    item_tree::{Import, ItemTreeId, LocalItemTreeId, ModItem},
+    module_tree::{LocalModuleId, ModuleTree},
+    FileId, HirDatabase, ModuleDef, Name, Path,
+};
+use mun_syntax::{AstNode, SyntaxNodePtr};
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+
+/// The names that are brought into the scope of a module by its `use` items.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ModuleImports {
+    items: FxHashMap<Name, Resolution>,
+    diagnostics: Vec<(LocalItemTreeId<Import>, ImportError)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportError {
+    /// The path of the import does not refer to an item or module.
+    Unresolved,
+    /// The imported item is private to another module.
+    PrivateItem,
+}
+
+impl ModuleImports {
+    pub(crate) fn module_imports_query(db: &dyn HirDatabase, file_id: FileId) -> Arc<Self> {
+        let item_tree = db.item_tree(file_id);
+        let module_tree = db.module_tree(db.file_source_root(file_id));
+        let mut imports = ModuleImports::default();
+
+        // Names that are imported explicitly take precedence over names imported by a glob import.
+        let (glob_imports, single_imports): (Vec<_>, Vec<_>) = item_tree
+            .top_level_items()
+            .iter()
+            .filter_map(|item| match item {
+                ModItem::Import(id) => Some(*id),
+                _ => None,
+            })
+            .partition(|id| item_tree[*id].is_glob);
+
+        for id in single_imports.into_iter().chain(glob_imports) {
+            let import = &item_tree[id];
+            let result = match module_tree.module_for_file(file_id) {
+                Some(module) => imports.resolve_import(db, &module_tree, module, import),
+                None => Err(ImportError::Unresolved),
+            };
+            if let Err(error) = result {
+                imports.diagnostics.push((id, error));
+            }
+        }
+
+        Arc::new(imports)
+    }
+
+    /// Returns the definitions that are imported with the specified name, if any.
+    pub fn get(&self, name: &Name) -> Option<&Resolution> {
+        self.items.get(name)
+    }
+
+    /// Resolves a single import of `module` and adds the imported names to this instance.
+    fn resolve_import(
+        &mut self,
+        db: &dyn HirDatabase,
+        module_tree: &ModuleTree,
+        module: LocalModuleId,
+        import: &Import,
+    ) -> Result<(), ImportError> {
+        let path = &import.path;
+
+        if import.is_glob {
+            let target = resolve_module_path(module_tree, module, &path.kind, &path.segments)
+                .ok_or(ImportError::Unresolved)?;
+            if let Some(file_id) = module_tree[target].file {
+                for (name, _) in db.module_scope(file_id).declarations() {
+                    let def = resolve_module_item(db, module_tree, target, name, module);
+                    self.insert(name, def);
+                }
+            }
+            return Ok(());
+        }
+
+        let (name, module_path) = path.segments.split_last().ok_or(ImportError::Unresolved)?;
+        let target = resolve_module_path(module_tree, module, &path.kind, module_path)
+            .ok_or(ImportError::Unresolved)?;
+        let def = resolve_module_item(db, module_tree, target, &name.name, module);
+        if def.is_none() {
+            // Determine whether the item does not exist or is not visible
+            let def = resolve_module_item(db, module_tree, target, &name.name, target);
+            return Err(if def.is_none() {
+                ImportError::Unresolved
+            } else {
+                ImportError::PrivateItem
+            });
+        }
+        self.insert(&name.name, def);
+        Ok(())
+    }
+
+    /// Imports the specified definitions with the specified name, unless the name was already
+    /// imported.
+    fn insert(&mut self, name: &Name, def: PerNs<ModuleDef>) {
+        if !def.is_none() && !self.items.contains_key(name) {
+            self.items.insert(name.clone(), Resolution { def });
+        }
+    }
+
+    /// Adds diagnostics for all the imports that could not be resolved to the `DiagnosticSink`.
+    pub(crate) fn add_diagnostics(
+        &self,
+        db: &dyn HirDatabase,
+        file_id: FileId,
+        sink: &mut DiagnosticSink,
+    ) {
+        let item_tree = db.item_tree(file_id);
+        for (id, error) in self.diagnostics.iter() {
+            match error {
+                ImportError::Unresolved => sink.push(UnresolvedImport {
+                    file: file_id,
+                    use_tree: use_tree_ptr(db, file_id, *id),
+                }),
+                ImportError::PrivateItem => sink.push(PrivateImport {
+                    file: file_id,
+                    use_tree: use_tree_ptr(db, file_id, *id),
+                    name: item_tree[*id]
+                        .path
+                        .segments
+                        .last()
+                        .map(|segment| segment.name.clone())
+                        .unwrap_or_else(Name::missing),
+                }),
+            }
+        }
+    }
+}
+
+/// Returns a pointer to the use tree from which the specified import originates.
+fn use_tree_ptr(
+    db: &dyn HirDatabase,
+    file_id: FileId,
+    id: LocalItemTreeId<Import>,
+) -> SyntaxNodePtr {
+    let item_tree = db.item_tree(file_id);
+    let index = item_tree[id].index;
+    let use_item = item_tree.source(db.upcast(), ItemTreeId::new(file_id, id));
+
+    let mut use_tree = None;
+    let mut current_index = 0;
+    Path::expand_use_item(&use_item, |tree, _, _| {
+        if current_index == index {
+            use_tree = Some(SyntaxNodePtr::new(tree.syntax()));
+        }
+        current_index += 1;
+    });
+    use_tree.unwrap_or_else(|| SyntaxNodePtr::new(use_item.syntax()))
+}
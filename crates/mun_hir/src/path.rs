@@ -1,4 +1,4 @@
-use crate::{AsName, Name};
+use crate::{name, type_ref::TypeRef, AsName, Name};
 use mun_syntax::ast;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -10,50 +10,94 @@ pub struct Path {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PathSegment {
     pub name: Name,
+    /// The type arguments of the segment, e.g. `<i32>` in `Foo<i32>`, if any
+    pub type_args: Option<Vec<TypeRef>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PathKind {
+    /// A path that is resolved relative to the current scope, e.g. `foo::bar`
     Plain,
-    Self_,
-    Super,
-    Abs,
+    /// A path that starts with a number of `super` keywords; `self::` is represented as `Super(0)`
+    Super(u8),
+    /// A path that is resolved relative to the root of the package, e.g. `package::foo`
+    Package,
 }
 
 impl Path {
     /// Converts an `ast::Path` to `Path`.
-    pub fn from_ast(path: ast::Path) -> Option<Path> {
+    pub fn from_ast(mut path: ast::Path) -> Option<Path> {
         let mut kind = PathKind::Plain;
         let mut segments = Vec::new();
-        //        loop {
-        let segment = path.segment()?;
+        loop {
+            let segment = path.segment()?;
 
-        if segment.has_colon_colon() {
-            kind = PathKind::Abs;
-        }
-
-        match segment.kind()? {
-            ast::PathSegmentKind::Name(name) => {
-                let segment = PathSegment {
-                    name: name.as_name(),
-                };
-                segments.push(segment);
-            }
-            ast::PathSegmentKind::SelfKw => {
-                kind = PathKind::Self_;
-                //                    break;
+            if segment.has_colon_colon() {
+                kind = PathKind::Package;
             }
-            ast::PathSegmentKind::SuperKw => {
-                kind = PathKind::Super;
-                //                    break;
+
+            match segment.kind()? {
+                ast::PathSegmentKind::Name(name) => {
+                    // `super` and `self` are only allowed at the start of a path
+                    if let PathKind::Super(_) = kind {
+                        return None;
+                    }
+                    let type_args = segment.type_arg_list().map(|list| {
+                        list.type_args()
+                            .map(|arg| TypeRef::from_ast_opt(arg.type_ref()))
+                            .collect()
+                    });
+                    let segment = PathSegment {
+                        name: name.as_name(),
+                        type_args,
+                    };
+                    segments.push(segment);
+                }
+                ast::PathSegmentKind::SelfKw => {
+                    // A lone `self` refers to the self parameter of a method
+                    if segments.is_empty() && path.qualifier().is_none() {
+                        segments.push(PathSegment {
+                            name: name![self],
+                            type_args: None,
+                        });
+                    } else if kind == PathKind::Plain {
+                        kind = PathKind::Super(0);
+                    }
+                    break;
+                }
+                ast::PathSegmentKind::SuperKw => {
+                    kind = match kind {
+                        PathKind::Super(n) => PathKind::Super(n + 1),
+                        _ => PathKind::Super(1),
+                    };
+                }
+                ast::PathSegmentKind::PackageKw => {
+                    if let PathKind::Super(_) = kind {
+                        return None;
+                    }
+                    kind = PathKind::Package;
+                    break;
+                }
             }
+
+            path = match path.qualifier() {
+                Some(qualifier) => qualifier,
+                None => break,
+            };
         }
-        //            break;
-        //        }
         segments.reverse();
         Some(Path { kind, segments })
     }
 
+    /// Calls `cb` for every path imported by the specified use item, together with the use tree
+    /// that imports it and whether it is a glob import. For example, `use foo::{bar, baz::*};`
+    /// imports `foo::bar` and all items in `foo::baz`.
+    pub(crate) fn expand_use_item(item: &ast::Use, mut cb: impl FnMut(ast::UseTree, Path, bool)) {
+        if let Some(tree) = item.use_tree() {
+            expand_use_tree(None, tree, &mut cb);
+        }
+    }
+
     /// Converts an `ast::NameRef` into a single-identifier `Path`.
     pub fn from_name_ref(name_ref: &ast::NameRef) -> Path {
         name_ref.as_name().into()
@@ -71,13 +115,71 @@ impl Path {
         }
         None
     }
+
+    /// If this path consists of exactly two segments, like `Foo::Bar`, returns the names of both
+    /// segments. This is the form in which enum variants are referred to.
+    pub fn as_enum_variant(&self) -> Option<(&Name, &Name)> {
+        match (&self.kind, self.segments.as_slice()) {
+            (PathKind::Plain, [first, second]) => Some((&first.name, &second.name)),
+            _ => None,
+        }
+    }
+}
+
+fn expand_use_tree(
+    prefix: Option<Path>,
+    tree: ast::UseTree,
+    cb: &mut dyn FnMut(ast::UseTree, Path, bool),
+) {
+    if let Some(list) = tree.use_tree_list() {
+        let prefix = match tree.path() {
+            None => prefix,
+            Some(path) => match convert_use_path(prefix, path) {
+                Some(path) => Some(path),
+                None => return,
+            },
+        };
+        for child_tree in list.use_trees() {
+            expand_use_tree(prefix.clone(), child_tree, cb);
+        }
+    } else {
+        let is_glob = tree.has_star();
+        let path = match tree.path() {
+            Some(path) => convert_use_path(prefix, path),
+            None if is_glob => prefix,
+            None => None,
+        };
+        if let Some(path) = path {
+            cb(tree, path, is_glob);
+        }
+    }
+}
+
+/// Appends the specified `path` to `prefix`. A lone `self`, e.g. in `use foo::{self};`, refers to
+/// the prefix itself.
+fn convert_use_path(prefix: Option<Path>, path: ast::Path) -> Option<Path> {
+    let path = Path::from_ast(path)?;
+    let mut prefix = match prefix {
+        Some(prefix) => prefix,
+        None => return Some(path),
+    };
+    if path.kind != PathKind::Plain {
+        return None;
+    }
+    if path.as_ident() != Some(&name![self]) {
+        prefix.segments.extend(path.segments);
+    }
+    Some(prefix)
 }
 
 impl From<Name> for Path {
     fn from(name: Name) -> Path {
         Path {
             kind: PathKind::Plain,
-            segments: vec![PathSegment { name }],
+            segments: vec![PathSegment {
+                name,
+                type_args: None,
+            }],
         }
     }
 }
@@ -1,6 +1,11 @@
 use crate::{
-    expr::scope::LocalScopeId, expr::PatId, ExprScopes, FileId, HirDatabase, ModuleDef, Name, Path,
-    PerNs,
+    expr::scope::LocalScopeId,
+    expr::PatId,
+    generics::GenericParams,
+    name,
+    name_resolution::{resolve_module_item, resolve_module_path},
+    type_ref::TypeRef,
+    ExprScopes, FileId, HirDatabase, Impl, Module, ModuleDef, Name, Path, PerNs, Trait,
 };
 use std::sync::Arc;
 
@@ -14,6 +19,12 @@ pub(crate) enum Scope {
     /// All the items and imported names of a module
     ModuleScope(ModuleItemMap),
 
+    /// Brings `Self` in scope
+    ImplBlockScope(Impl),
+
+    /// The generic type parameters of a function or struct
+    GenericParams(GenericParams),
+
     /// Local bindings
     ExprScope(ExprScope),
 }
@@ -39,6 +50,34 @@ impl Resolver {
         self.push_scope(Scope::ModuleScope(ModuleItemMap { file_id }))
     }
 
+    pub(crate) fn push_impl_block_scope(self, impl_def: Impl) -> Resolver {
+        self.push_scope(Scope::ImplBlockScope(impl_def))
+    }
+
+    pub(crate) fn push_generic_params_scope(self, params: GenericParams) -> Resolver {
+        if params.is_empty() {
+            self
+        } else {
+            self.push_scope(Scope::GenericParams(params))
+        }
+    }
+
+    /// Returns the innermost `impl` block that is in scope, if any.
+    pub(crate) fn impl_block(&self) -> Option<Impl> {
+        self.scopes.iter().rev().find_map(|scope| match scope {
+            Scope::ImplBlockScope(impl_def) => Some(*impl_def),
+            _ => None,
+        })
+    }
+
+    /// Returns the module in which names are resolved, if any.
+    pub(crate) fn module(&self) -> Option<Module> {
+        self.scopes.iter().rev().find_map(|scope| match scope {
+            Scope::ModuleScope(m) => Some(Module::from(m.file_id)),
+            _ => None,
+        })
+    }
+
     pub(crate) fn push_expr_scope(
         self,
         expr_scopes: Arc<ExprScopes>,
@@ -57,6 +96,8 @@ pub enum Resolution {
     Def(ModuleDef),
     /// A local binding (only value namespace)
     LocalBinding(PatId),
+    /// A generic type parameter, identified by its index (only types namespace)
+    GenericParam(u32),
 }
 
 impl Resolver {
@@ -79,9 +120,72 @@ impl Resolver {
         path: &Path,
     ) -> PerNs<Resolution> {
         if let Some(name) = path.as_ident() {
-            self.resolve_name(db, name)
-        } else {
-            PerNs::none()
+            return self.resolve_name(db, name);
+        }
+        if let Some((enum_name, variant_name)) = path.as_enum_variant() {
+            // An enum variant is resolved by first resolving the enum in the type namespace
+            if let Some(Resolution::Def(ModuleDef::Enum(e))) =
+                self.resolve_name(db, enum_name).take_types()
+            {
+                return e
+                    .variant(db.upcast(), variant_name)
+                    .map(|variant| PerNs::values(Resolution::Def(variant.into())))
+                    .unwrap_or_else(PerNs::none);
+            }
+        }
+        self.resolve_module_item_path(db, path)
+    }
+
+    /// Resolves a path to an item in a module, e.g. `package::physics::integrate`, or to a variant
+    /// of an enum in a module, e.g. `super::Shape::Circle`. Items that are private to another
+    /// module are not resolved.
+    fn resolve_module_item_path(&self, db: &dyn HirDatabase, path: &Path) -> PerNs<Resolution> {
+        let file_id = match self.module() {
+            Some(module) => module.file_id(),
+            None => return PerNs::none(),
+        };
+        let module_tree = db.module_tree(db.file_source_root(file_id));
+        let module = match module_tree.module_for_file(file_id) {
+            Some(module) => module,
+            None => return PerNs::none(),
+        };
+
+        let (item, module_path) = match path.segments.split_last() {
+            Some(it) => it,
+            None => return PerNs::none(),
+        };
+        if let Some(target) = resolve_module_path(&module_tree, module, &path.kind, module_path) {
+            return resolve_module_item(db, &module_tree, target, &item.name, module)
+                .map(Resolution::Def);
+        }
+
+        let (enum_name, module_path) = match module_path.split_last() {
+            Some(it) => it,
+            None => return PerNs::none(),
+        };
+        let enum_def =
+            resolve_module_path(&module_tree, module, &path.kind, module_path).and_then(|target| {
+                resolve_module_item(db, &module_tree, target, &enum_name.name, module).take_types()
+            });
+        match enum_def {
+            Some(ModuleDef::Enum(e)) => e
+                .variant(db.upcast(), &item.name)
+                .map(|variant| PerNs::values(Resolution::Def(variant.into())))
+                .unwrap_or_else(PerNs::none),
+            _ => PerNs::none(),
+        }
+    }
+
+    /// Resolves a reference to a trait, e.g. the bound of a generic parameter or the trait of an
+    /// `impl` block. Returns `None` if the reference does not refer to a trait.
+    pub fn resolve_trait(&self, db: &dyn HirDatabase, type_ref: &TypeRef) -> Option<Trait> {
+        let path = match type_ref {
+            TypeRef::Path(path) => path,
+            _ => return None,
+        };
+        match self.resolve_path_without_assoc_items(db, path).take_types() {
+            Some(Resolution::Def(ModuleDef::Trait(t))) => Some(t),
+            _ => None,
         }
     }
 }
@@ -89,12 +193,36 @@ impl Resolver {
 impl Scope {
     fn resolve_name(&self, db: &dyn HirDatabase, name: &Name) -> PerNs<Resolution> {
         match self {
-            Scope::ModuleScope(m) => db
-                .module_scope(m.file_id)
-                .get(name)
-                .map(|r| r.def)
-                .unwrap_or_else(PerNs::none)
-                .map(Resolution::Def),
+            Scope::ModuleScope(m) => {
+                // Declared items shadow imported items
+                let scope = db.module_scope(m.file_id);
+                let imports = db.module_imports(m.file_id);
+                scope
+                    .get(name)
+                    .or_else(|| imports.get(name))
+                    .map(|r| r.def)
+                    .unwrap_or_else(PerNs::none)
+                    .map(Resolution::Def)
+            }
+            Scope::ImplBlockScope(i) => {
+                if *name != name![Self] {
+                    return PerNs::none();
+                }
+                // `Self` resolves to the same definition as the name of the type
+                let self_ty = i.self_ty(db);
+                if let Some(s) = self_ty.as_struct() {
+                    let def = ModuleDef::Struct(s);
+                    PerNs::both(Resolution::Def(def), Resolution::Def(def))
+                } else if let Some(e) = self_ty.as_enum() {
+                    PerNs::types(Resolution::Def(ModuleDef::Enum(e)))
+                } else {
+                    PerNs::none()
+                }
+            }
+            Scope::GenericParams(params) => match params.find_by_name(name) {
+                Some(param) => PerNs::types(Resolution::GenericParam(param.idx)),
+                None => PerNs::none(),
+            },
             Scope::ExprScope(e) => {
                 let entry = e
                     .expr_scopes
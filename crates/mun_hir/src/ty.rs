@@ -1,14 +1,15 @@
 mod infer;
 pub(super) mod lower;
+pub(crate) mod method_resolution;
 mod op;
 mod primitives;
 mod resolve;
 
 use crate::display::{HirDisplay, HirFormatter};
 use crate::ty::infer::InferTy;
-use crate::ty::lower::fn_sig_for_struct_constructor;
+use crate::ty::lower::{fn_sig_for_enum_variant_constructor, fn_sig_for_struct_constructor};
 use crate::utils::make_mut_slice;
-use crate::{HirDatabase, Struct, StructMemoryKind, TypeAlias};
+use crate::{ty_app, Enum, HirDatabase, Name, Struct, StructMemoryKind, TypeAlias};
 pub(crate) use infer::infer_query;
 pub use infer::InferenceResult;
 pub(crate) use lower::{
@@ -16,6 +17,7 @@ pub(crate) use lower::{
 };
 pub use primitives::{FloatTy, IntTy};
 pub use resolve::ResolveBitness;
+use std::iter::FromIterator;
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 use std::{fmt, mem};
@@ -33,6 +35,15 @@ pub enum Ty {
     /// A type variable used during type checking. Not to be confused with a type parameter.
     Infer(InferTy),
 
+    /// A generic type parameter of the function or struct that is being checked, e.g. `T` in
+    /// `fn foo<T>(a: T)`. Type parameters are replaced by the actual type arguments through
+    /// substitution.
+    Param {
+        /// The index of the parameter in the list of generic parameters of its definition
+        idx: u32,
+        name: Name,
+    },
+
     /// A placeholder for a type which could not be computed; this is propagated to avoid useless
     /// error messages. Doubles as a placeholder where type variables are inserted before type
     /// checking, since we want to try to infer a better type here anyway -- for the IDE use case,
@@ -63,16 +74,38 @@ pub enum TypeCtor {
     /// The primitive boolean type. Written as `bool`.
     Bool,
 
-    /// An abstract datatype (structures, tuples, or enumerations)
-    /// TODO: Add tuples and enumerations
+    /// An immutable, garbage collected string of UTF-8 encoded text. Written as `string`.
+    String,
+
+    /// An abstract datatype (structures, tuples, or enumerations). The type arguments of a generic
+    /// struct are stored as the type parameters.
     Struct(Struct),
 
+    /// An anonymous tuple type, written as `(T, U)`. The element types are stored as the type
+    /// parameters. The empty tuple `()` is represented by `Ty::Empty`.
+    Tuple { cardinality: u16 },
+
+    /// A tagged union of which the active variant is identified by its discriminant.
+    Enum(Enum),
+
     /// A type alias
     TypeAlias(TypeAlias),
 
     /// The never type `never`.
     Never,
 
+    /// A fixed-size array of values, written as `[T; N]`. The element type is stored as the only
+    /// type parameter.
+    FixedArray(u64),
+
+    /// A garbage collected array of which the length is only known at runtime, written as `[T]`.
+    /// The element type is stored as the only type parameter.
+    Array,
+
+    /// A reference to a `gc` struct that may be `nil`, written as `?T`. The struct type is stored
+    /// as the only type parameter.
+    Nullable,
+
     /// The anonymous type of a function declaration/definition. Each
     /// function has a unique type, which is output (for a function
     /// named `foo` returning an `number`) as `fn() -> number {foo}`.
@@ -86,6 +119,11 @@ pub enum TypeCtor {
     /// let bar = foo; // bar: function() -> number {foo}
     /// ```
     FnDef(CallableDef),
+
+    /// A first-class function value, written as `fn(T, U) -> R`. Both closures and named
+    /// functions that are used as a value have this type. The parameter types followed by the
+    /// return type are stored as the type parameters.
+    FnPtr { num_args: u16 },
 }
 
 impl Ty {
@@ -96,6 +134,50 @@ impl Ty {
         })
     }
 
+    /// Constructs a fixed-size array type `[T; N]`
+    pub fn fixed_array(element_ty: Ty, len: u64) -> Ty {
+        Ty::Apply(ApplicationTy {
+            ctor: TypeCtor::FixedArray(len),
+            parameters: Substs::single(element_ty),
+        })
+    }
+
+    /// Constructs a garbage collected array type `[T]`
+    pub fn array(element_ty: Ty) -> Ty {
+        Ty::Apply(ApplicationTy {
+            ctor: TypeCtor::Array,
+            parameters: Substs::single(element_ty),
+        })
+    }
+
+    /// Constructs a nullable type `?T`
+    pub fn nullable(inner_ty: Ty) -> Ty {
+        Ty::Apply(ApplicationTy {
+            ctor: TypeCtor::Nullable,
+            parameters: Substs::single(inner_ty),
+        })
+    }
+
+    /// Constructs a function pointer type `fn(T, U) -> R` from a signature.
+    pub fn fn_ptr(sig: FnSig) -> Ty {
+        Ty::Apply(ApplicationTy {
+            ctor: TypeCtor::FnPtr {
+                num_args: sig.params().len() as u16,
+            },
+            parameters: sig.params_and_return.iter().cloned().collect(),
+        })
+    }
+
+    /// Constructs a tuple type `(T, U)` from the types of its elements.
+    pub fn tuple(element_tys: Substs) -> Ty {
+        Ty::Apply(ApplicationTy {
+            ctor: TypeCtor::Tuple {
+                cardinality: element_tys.len() as u16,
+            },
+            parameters: element_tys,
+        })
+    }
+
     pub fn as_simple(&self) -> Option<TypeCtor> {
         match self {
             Ty::Apply(ApplicationTy { ctor, parameters }) if parameters.0.is_empty() => Some(*ctor),
@@ -133,10 +215,73 @@ impl Ty {
         }
     }
 
+    pub fn as_enum(&self) -> Option<Enum> {
+        match self {
+            Ty::Apply(a_ty) => match a_ty.ctor {
+                TypeCtor::Enum(e) => Some(e),
+                TypeCtor::FnDef(CallableDef::EnumVariant(v)) => Some(v.parent_enum()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the types of the elements of a tuple or `None` if the type does not represent a
+    /// tuple.
+    pub fn as_tuple(&self) -> Option<&Substs> {
+        match self {
+            Ty::Apply(ApplicationTy {
+                ctor: TypeCtor::Tuple { .. },
+                parameters,
+            }) => Some(parameters),
+            _ => None,
+        }
+    }
+
+    /// Returns the element type of an array and, in case of a fixed-size array, its length or
+    /// `None` if the type does not represent an array.
+    pub fn as_array(&self) -> Option<(&Ty, Option<u64>)> {
+        match self {
+            Ty::Apply(ApplicationTy {
+                ctor: TypeCtor::FixedArray(len),
+                parameters,
+            }) => Some((&parameters[0], Some(*len))),
+            Ty::Apply(ApplicationTy {
+                ctor: TypeCtor::Array,
+                parameters,
+            }) => Some((&parameters[0], None)),
+            _ => None,
+        }
+    }
+
+    /// Returns the type that is referred to by a nullable type or `None` if the type does not
+    /// represent a nullable type.
+    pub fn as_nullable(&self) -> Option<&Ty> {
+        match self {
+            Ty::Apply(ApplicationTy {
+                ctor: TypeCtor::Nullable,
+                parameters,
+            }) => Some(&parameters[0]),
+            _ => None,
+        }
+    }
+
+    /// Returns the type parameters of this type, e.g. the type arguments of a generic struct, or
+    /// `None` if this is not an application of a type constructor.
+    pub fn substs(&self) -> Option<Substs> {
+        match self {
+            Ty::Apply(a_ty) => Some(a_ty.parameters.clone()),
+            _ => None,
+        }
+    }
+
     pub fn callable_sig(&self, db: &dyn HirDatabase) -> Option<FnSig> {
         match self {
             Ty::Apply(a_ty) => match a_ty.ctor {
-                TypeCtor::FnDef(def) => Some(db.callable_sig(def)),
+                TypeCtor::FnDef(def) => Some(db.callable_sig(def).subst(&a_ty.parameters)),
+                TypeCtor::FnPtr { .. } => Some(FnSig {
+                    params_and_return: a_ty.parameters.0.clone(),
+                }),
                 _ => None,
             },
             _ => None,
@@ -147,33 +292,110 @@ impl Ty {
     ///
     /// This name needs to be unique as it is used to generate a type's `Guid`.
     pub fn guid_string(&self, db: &dyn HirDatabase) -> Option<String> {
+        if let Some((element_ty, len)) = self.as_array() {
+            let element = element_ty.guid_string(db)?;
+            return Some(match len {
+                Some(len) => format!("[{}; {}]", element, len),
+                None => format!("[{}]", element),
+            });
+        }
+
+        if let Some(inner_ty) = self.as_nullable() {
+            return Some(format!("?{}", inner_ty.guid_string(db)?));
+        }
+
+        if let Some(element_tys) = self.as_tuple() {
+            let elements = element_tys
+                .iter()
+                .map(|ty| ty.guid_string(db))
+                .collect::<Option<Vec<_>>>()?;
+            return Some(format!("({})", elements.join(",")));
+        }
+
+        if let ty_app!(TypeCtor::FnPtr { .. }) = self {
+            // The return type is omitted for functions that do not return a value, like in the
+            // source syntax.
+            let sig = self.callable_sig(db)?;
+            let params = sig
+                .params()
+                .iter()
+                .map(|ty| ty.guid_string(db))
+                .collect::<Option<Vec<_>>>()?;
+            return Some(if sig.ret().is_empty() {
+                format!("fn({})", params.join(","))
+            } else {
+                format!("fn({}) -> {}", params.join(","), sig.ret().guid_string(db)?)
+            });
+        }
+
+        if let ty_app!(TypeCtor::Struct(s), parameters) = self {
+            // Every instantiation of a generic struct is a distinct type, so its type arguments
+            // are part of the name. Structs with the same name can be declared in different
+            // modules, so the path of its module is as well.
+            let mut name = s.full_name(db);
+            if !parameters.is_empty() {
+                let args = parameters
+                    .iter()
+                    .map(|ty| ty.guid_string(db))
+                    .collect::<Option<Vec<_>>>()?;
+                name = format!("{}<{}>", name, args.join(","));
+            }
+
+            return Some(if s.data(db.upcast()).memory_kind == StructMemoryKind::GC {
+                format!("struct {}", name)
+            } else {
+                let fields: Vec<String> = s
+                    .fields(db)
+                    .into_iter()
+                    .map(|f| {
+                        let ty_string = f
+                            .ty(db)
+                            .subst(parameters)
+                            .guid_string(db)
+                            .expect("type should be convertible to a string");
+                        format!("{}: {}", f.name(db).to_string(), ty_string)
+                    })
+                    .collect();
+
+                format!(
+                    "struct {name}{{{fields}}}",
+                    name = name,
+                    fields = fields.join(",")
+                )
+            });
+        }
+
         self.as_simple().and_then(|ty_ctor| match ty_ctor {
-            TypeCtor::Struct(s) => {
-                let name = s.name(db.upcast()).to_string();
-
-                Some(if s.data(db.upcast()).memory_kind == StructMemoryKind::GC {
-                    format!("struct {}", name)
-                } else {
-                    let fields: Vec<String> = s
-                        .fields(db)
-                        .into_iter()
-                        .map(|f| {
-                            let ty_string = f
-                                .ty(db)
-                                .guid_string(db)
-                                .expect("type should be convertible to a string");
-                            format!("{}: {}", f.name(db).to_string(), ty_string)
-                        })
-                        .collect();
-
-                    format!(
-                        "struct {name}{{{fields}}}",
-                        name = name,
-                        fields = fields.join(",")
-                    )
-                })
+            TypeCtor::Enum(e) => {
+                let variants: Vec<String> = e
+                    .variants(db)
+                    .into_iter()
+                    .map(|v| {
+                        let name = v.name(db).to_string();
+                        let fields: Vec<String> = v
+                            .field_types(db)
+                            .iter()
+                            .map(|ty| {
+                                ty.guid_string(db)
+                                    .expect("type should be convertible to a string")
+                            })
+                            .collect();
+                        if fields.is_empty() {
+                            name
+                        } else {
+                            format!("{}({})", name, fields.join(","))
+                        }
+                    })
+                    .collect();
+
+                Some(format!(
+                    "enum {name}{{{variants}}}",
+                    name = e.full_name(db),
+                    variants = variants.join(",")
+                ))
             }
             TypeCtor::Bool => Some("core::bool".to_string()),
+            TypeCtor::String => Some("core::string".to_string()),
             TypeCtor::Float(ty) => Some(format!("core::{}", ty.as_str())),
             TypeCtor::Int(ty) => Some(format!("core::{}", ty.as_str())),
             _ => None,
@@ -184,6 +406,29 @@ impl Ty {
     pub fn is_known(&self) -> bool {
         *self == Ty::Unknown
     }
+
+    /// Replaces all type parameters in this type by the corresponding type in `substs`.
+    pub fn subst(self, substs: &Substs) -> Ty {
+        if substs.is_empty() {
+            return self;
+        }
+        self.fold(&mut |ty| match ty {
+            Ty::Param { idx, name } => substs
+                .get(idx as usize)
+                .cloned()
+                .unwrap_or(Ty::Param { idx, name }),
+            ty => ty,
+        })
+    }
+
+    /// Returns true if this type contains type parameters that still have to be substituted.
+    pub fn has_params(&self) -> bool {
+        match self {
+            Ty::Param { .. } => true,
+            Ty::Apply(a_ty) => a_ty.parameters.iter().any(Ty::has_params),
+            Ty::Empty | Ty::Infer(_) | Ty::Unknown => false,
+        }
+    }
 }
 
 /// A list of substitutions for generic parameters.
@@ -198,6 +443,17 @@ impl Substs {
     pub fn single(ty: Ty) -> Substs {
         Substs(Arc::new([ty]))
     }
+
+    /// Applies the substitutions `substs` to all types in this list.
+    pub fn subst(&self, substs: &Substs) -> Substs {
+        self.iter().map(|ty| ty.clone().subst(substs)).collect()
+    }
+}
+
+impl FromIterator<Ty> for Substs {
+    fn from_iter<T: IntoIterator<Item = Ty>>(iter: T) -> Self {
+        Substs(iter.into_iter().collect())
+    }
 }
 
 impl Deref for Substs {
@@ -237,12 +493,28 @@ impl FnSig {
         &self.params_and_return[self.params_and_return.len() - 1]
     }
 
+    /// Replaces the type parameters in the signature by the corresponding types in `substs`.
+    pub fn subst(self, substs: &Substs) -> FnSig {
+        if substs.is_empty() {
+            return self;
+        }
+        FnSig {
+            params_and_return: self
+                .params_and_return
+                .iter()
+                .map(|ty| ty.clone().subst(substs))
+                .collect(),
+        }
+    }
+
     pub fn marshallable(&self, db: &dyn HirDatabase) -> bool {
         for ty in self.params_and_return.iter() {
             if let Some(s) = ty.as_struct() {
                 if s.data(db.upcast()).memory_kind == StructMemoryKind::Value {
                     return false;
                 }
+            } else if ty.as_enum().is_some() || ty.as_tuple().is_some() {
+                return false;
             }
         }
         true
@@ -255,6 +527,7 @@ impl HirDisplay for Ty {
             Ty::Apply(a_ty) => a_ty.hir_fmt(f),
             Ty::Unknown => write!(f, "{{unknown}}"),
             Ty::Empty => write!(f, "nothing"),
+            Ty::Param { name, .. } => write!(f, "{}", name),
             Ty::Infer(tv) => match tv {
                 InferTy::TypeVar(tv) => write!(f, "'{}", tv.0),
                 InferTy::IntVar(_) => write!(f, "{{integer}}"),
@@ -270,25 +543,63 @@ impl HirDisplay for ApplicationTy {
             TypeCtor::Float(ty) => write!(f, "{}", ty),
             TypeCtor::Int(ty) => write!(f, "{}", ty),
             TypeCtor::Bool => write!(f, "bool"),
-            TypeCtor::Struct(def) => write!(f, "{}", def.name(f.db.upcast())),
+            TypeCtor::String => write!(f, "string"),
+            TypeCtor::Struct(def) => {
+                write!(f, "{}", def.name(f.db.upcast()))?;
+                if !self.parameters.is_empty() {
+                    write!(f, "<")?;
+                    f.write_joined(self.parameters.iter(), ", ")?;
+                    write!(f, ">")?;
+                }
+                Ok(())
+            }
+            TypeCtor::Enum(def) => write!(f, "{}", def.name(f.db.upcast())),
             TypeCtor::TypeAlias(def) => write!(f, "{}", def.name(f.db.upcast())),
             TypeCtor::Never => write!(f, "never"),
+            TypeCtor::FixedArray(len) => {
+                write!(f, "[{}; {}]", self.parameters[0].display(f.db), len)
+            }
+            TypeCtor::Array => write!(f, "[{}]", self.parameters[0].display(f.db)),
+            TypeCtor::Nullable => write!(f, "?{}", self.parameters[0].display(f.db)),
+            TypeCtor::Tuple { cardinality } => {
+                write!(f, "(")?;
+                f.write_joined(self.parameters.iter(), ", ")?;
+                if cardinality == 1 {
+                    write!(f, ",")?;
+                }
+                write!(f, ")")
+            }
+            TypeCtor::FnPtr { num_args } => {
+                let (params, ret) = self.parameters.split_at(num_args as usize);
+                write!(f, "fn(")?;
+                f.write_joined(params, ", ")?;
+                write!(f, ") -> {}", ret[0].display(f.db))
+            }
             TypeCtor::FnDef(CallableDef::Function(def)) => {
-                let sig = fn_sig_for_fn(f.db, def);
-                let name = def.name(f.db);
+                let sig = fn_sig_for_fn(f.db, def).subst(&self.parameters);
+                let name = def.full_name(f.db);
                 write!(f, "function {}", name)?;
                 write!(f, "(")?;
                 f.write_joined(sig.params(), ", ")?;
                 write!(f, ") -> {}", sig.ret().display(f.db))
             }
             TypeCtor::FnDef(CallableDef::Struct(def)) => {
-                let sig = fn_sig_for_struct_constructor(f.db, def);
+                let sig = fn_sig_for_struct_constructor(f.db, def).subst(&self.parameters);
                 let name = def.name(f.db.upcast());
                 write!(f, "ctor {}", name)?;
                 write!(f, "(")?;
                 f.write_joined(sig.params(), ", ")?;
                 write!(f, ") -> {}", sig.ret().display(f.db))
             }
+            TypeCtor::FnDef(CallableDef::EnumVariant(def)) => {
+                let sig = fn_sig_for_enum_variant_constructor(f.db, def);
+                let name = def.name(f.db);
+                let enum_name = def.parent_enum().name(f.db.upcast());
+                write!(f, "ctor {}::{}", enum_name, name)?;
+                write!(f, "(")?;
+                f.write_joined(sig.params(), ", ")?;
+                write!(f, ") -> {}", sig.ret().display(f.db))
+            }
         }
     }
 }
@@ -300,6 +611,16 @@ impl HirDisplay for &Ty {
 }
 
 impl Ty {
+    /// Calls `f` for this type and all types it is composed of, e.g. the element type of an array.
+    pub(crate) fn walk(&self, f: &mut impl FnMut(&Ty)) {
+        if let Ty::Apply(ty) = self {
+            for t in ty.parameters.iter() {
+                t.walk(f);
+            }
+        }
+        f(self)
+    }
+
     fn walk_mut(&mut self, f: &mut impl FnMut(&mut Ty)) {
         match self {
             Ty::Apply(ty) => {
@@ -307,7 +628,7 @@ impl Ty {
                     t.walk_mut(f);
                 }
             }
-            Ty::Empty | Ty::Infer(_) | Ty::Unknown => {}
+            Ty::Empty | Ty::Infer(_) | Ty::Param { .. } | Ty::Unknown => {}
         }
         f(self)
     }
@@ -4,18 +4,23 @@ use crate::{
     code_model::{DefWithBody, DefWithStruct, Struct},
     diagnostics::DiagnosticSink,
     expr,
-    expr::{Body, Expr, ExprId, Literal, Pat, PatId, RecordLitField, Statement, UnaryOp},
+    expr::{Body, Expr, ExprId, Literal, MatchArm, Pat, PatId, RecordLitField, Statement, UnaryOp},
+    generics::GenericDef,
+    name::name,
     name_resolution::Namespace,
+    path::PathSegment,
     resolve::{Resolution, Resolver},
     ty::infer::diagnostics::InferenceDiagnostic,
     ty::infer::type_variable::TypeVariableTable,
     ty::lower::LowerDiagnostic,
+    ty::method_resolution::{lookup_associated_function, lookup_trait_method},
     ty::op,
-    ty::{Ty, TypableDef},
-    type_ref::LocalTypeRefId,
-    ApplicationTy, BinaryOp, Function, HirDatabase, Name, Path, TypeCtor,
+    ty::{lower::CallableDef, FnSig, Substs, Ty, TypableDef},
+    type_ref::{LocalTypeRefId, TypeRef},
+    ApplicationTy, BinaryOp, Function, HirDatabase, ModuleDef, Name, Path, Signedness, Trait,
+    TypeCtor,
 };
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::ops::Index;
 use std::sync::Arc;
 
@@ -49,6 +54,15 @@ mod coerce;
 /// The result of type inference: A mapping from expressions and patterns to types.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct InferenceResult {
+    /// For each method call expression and each operator expression of which the operator is
+    /// overloaded, records the function it resolves to and the type arguments of its generic
+    /// parameters.
+    method_resolutions: FxHashMap<ExprId, (Function, Substs)>,
+    /// For each path expression that refers to a function that is used as a value instead of
+    /// being called, records the function and the type arguments of its generic parameters.
+    function_values: FxHashMap<ExprId, (Function, Substs)>,
+    /// For each `break` and `continue` expression, records the loop it exits or continues.
+    loop_targets: FxHashMap<ExprId, ExprId>,
     pub(crate) type_of_expr: ArenaMap<ExprId, Ty>,
     pub(crate) type_of_pat: ArenaMap<PatId, Ty>,
     pub(crate) diagnostics: Vec<diagnostics::InferenceDiagnostic>,
@@ -69,11 +83,50 @@ impl Index<PatId> for InferenceResult {
 }
 
 impl InferenceResult {
+    /// Returns the function that is called by the specified method call expression or overloaded
+    /// operator expression, together with the type arguments of its generic parameters.
+    pub fn method_resolution(&self, expr: ExprId) -> Option<(Function, Substs)> {
+        self.method_resolutions.get(&expr).cloned()
+    }
+
+    /// Returns the function that is referred to by the specified path expression if the function
+    /// is used as a value, together with the type arguments of its generic parameters.
+    pub fn function_value(&self, expr: ExprId) -> Option<(Function, Substs)> {
+        self.function_values.get(&expr).cloned()
+    }
+
+    /// Returns the loop that is exited or continued by the specified `break` or `continue`
+    /// expression.
+    pub fn loop_target(&self, expr: ExprId) -> Option<ExprId> {
+        self.loop_targets.get(&expr).copied()
+    }
+
+    /// Returns a copy of the result in which all generic parameters are replaced by the
+    /// corresponding types in `substs`. This is used to generate an instantiation of a generic
+    /// function.
+    pub fn subst(&self, substs: &Substs) -> InferenceResult {
+        let mut result = self.clone();
+        for (_, ty) in result.type_of_expr.iter_mut() {
+            *ty = ty.clone().subst(substs);
+        }
+        for (_, ty) in result.type_of_pat.iter_mut() {
+            *ty = ty.clone().subst(substs);
+        }
+        for (_, function_substs) in result
+            .method_resolutions
+            .values_mut()
+            .chain(result.function_values.values_mut())
+        {
+            *function_substs = function_substs.subst(substs);
+        }
+        result
+    }
+
     /// Adds all the `InferenceDiagnostic`s of the result to the `DiagnosticSink`.
     pub(crate) fn add_diagnostics(
         &self,
         db: &dyn HirDatabase,
-        owner: Function,
+        owner: DefWithBody,
         sink: &mut DiagnosticSink,
     ) {
         self.diagnostics
@@ -87,10 +140,12 @@ impl InferenceResult {
 pub fn infer_query(db: &dyn HirDatabase, def: DefWithBody) -> Arc<InferenceResult> {
     let body = def.body(db);
     let resolver = def.resolver(db);
-    let mut ctx = InferenceResultBuilder::new(db, body, resolver);
+    let mut ctx = InferenceResultBuilder::new(db, def, body, resolver);
 
     match def {
-        DefWithBody::Function(_) => ctx.infer_signature(),
+        DefWithBody::Function(_) | DefWithBody::Const(_) | DefWithBody::Static(_) => {
+            ctx.infer_signature()
+        }
     }
 
     ctx.infer_body();
@@ -124,47 +179,85 @@ impl InferTy {
     }
 }
 
-enum ActiveLoop {
+enum LoopKind {
     Loop(Ty, Expectation),
     While,
     For,
 }
 
+/// A loop of which the body is being inferred.
+struct ActiveLoop {
+    /// The loop expression
+    expr: ExprId,
+    /// The label of the loop, e.g. `'outer` in `'outer: loop {}`
+    label: Option<Name>,
+    kind: LoopKind,
+}
+
 /// The inference context contains all information needed during type inference.
 struct InferenceResultBuilder<'a> {
     db: &'a dyn HirDatabase,
+    owner: DefWithBody,
     body: Arc<Body>,
     resolver: Resolver,
 
     type_of_expr: ArenaMap<ExprId, Ty>,
     type_of_pat: ArenaMap<PatId, Ty>,
+    method_resolutions: FxHashMap<ExprId, (Function, Substs)>,
+    function_values: FxHashMap<ExprId, (Function, Substs)>,
     diagnostics: Vec<InferenceDiagnostic>,
 
     type_variables: TypeVariableTable,
 
-    /// Information on the current loop that we're processing (or None if we're not in a loop) the
-    /// entry contains the current type of the loop statement (initially `never`) and the expected
-    /// type of the loop expression. Both these values are updated when a break statement is
-    /// encountered.
-    active_loop: Option<ActiveLoop>,
+    /// The expressions that instantiate a generic definition together with the definition, if
+    /// known, and the type variables that were created for its type arguments.
+    generic_instantiations: Vec<(ExprId, Option<GenericDef>, Substs)>,
+
+    /// The parameters of closures of which the type is inferred from their usage, together with
+    /// the type variable that was created for their type.
+    inferred_params: Vec<(PatId, Ty)>,
+
+    /// The loops that we're processing, from the outermost to the innermost loop. For a `loop`
+    /// the entry contains the current type of the loop statement (initially `never`) and the
+    /// expected type of the loop expression. Both these values are updated when a break statement
+    /// that targets the loop is encountered.
+    active_loops: Vec<ActiveLoop>,
+
+    /// For each `break` and `continue` expression, the loop it exits or continues.
+    loop_targets: FxHashMap<ExprId, ExprId>,
 
     /// The return type of the function being inferred.
     return_ty: Ty,
+
+    /// The expressions whose value is returned from the function, see `collect_return_positions`
+    return_positions: FxHashSet<ExprId>,
 }
 
 impl<'a> InferenceResultBuilder<'a> {
     /// Construct a new `InferenceContext` from a `Body` and a `Resolver` for that body.
-    fn new(db: &'a dyn HirDatabase, body: Arc<Body>, resolver: Resolver) -> Self {
+    fn new(
+        db: &'a dyn HirDatabase,
+        owner: DefWithBody,
+        body: Arc<Body>,
+        resolver: Resolver,
+    ) -> Self {
         InferenceResultBuilder {
             type_of_expr: ArenaMap::default(),
             type_of_pat: ArenaMap::default(),
+            method_resolutions: FxHashMap::default(),
+            function_values: FxHashMap::default(),
             diagnostics: Vec::default(),
-            active_loop: None,
+            active_loops: Vec::new(),
+            loop_targets: FxHashMap::default(),
             type_variables: TypeVariableTable::default(),
+            generic_instantiations: Vec::new(),
+            inferred_params: Vec::new(),
             db,
+            owner,
             body,
             resolver,
             return_ty: Ty::Unknown, // set in collect_fn_signature
+            return_positions: FxHashSet::default(),
         }
     }
 
@@ -197,12 +290,114 @@ impl<'a> InferenceResultBuilder<'a> {
                     InferenceDiagnostic::UnresolvedType { id }
                 }
                 LowerDiagnostic::CyclicType { id } => InferenceDiagnostic::CyclicType { id },
+                LowerDiagnostic::TypeArgCountMismatch {
+                    id,
+                    expected,
+                    found,
+                } => InferenceDiagnostic::TypeArgCountMismatch {
+                    id,
+                    expected,
+                    found,
+                },
+                LowerDiagnostic::InvalidNullableType { id } => {
+                    InferenceDiagnostic::InvalidNullableType { id }
+                }
+                LowerDiagnostic::InvalidArrayLength { id } => {
+                    InferenceDiagnostic::InvalidArrayLength { id }
+                }
             };
             self.diagnostics.push(diag);
         }
 
         result.ty
     }
+
+    /// Resolves the type of a record literal. Contrary to other type references, the type
+    /// arguments of a generic struct can be omitted in a record literal (e.g. `Foo { a: 1 }`), in
+    /// which case they are inferred.
+    fn resolve_record_lit_type(&mut self, expr: ExprId, type_ref: LocalTypeRefId) -> Ty {
+        let body = Arc::clone(&self.body); // avoid borrow checker problem
+        let path = match &body.type_refs()[type_ref] {
+            TypeRef::Path(path) => path,
+            _ => return self.resolve_type(type_ref),
+        };
+        let has_type_args = path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.type_args.is_some());
+        if !has_type_args {
+            if let Some((ty, _)) = Ty::from_hir_path(self.db, &self.resolver, path) {
+                let db = self.db.upcast();
+                if ty.as_struct().map_or(false, |s| s.is_generic(db)) {
+                    return self.instantiate_generics(expr, ty);
+                }
+            }
+        }
+        self.resolve_type(type_ref)
+    }
+
+    /// Replaces the generic parameters of the type of a generic function or struct, that is
+    /// referred to by `expr`, by fresh type variables. The type arguments are then inferred from
+    /// the way the definition is used.
+    fn instantiate_generics(&mut self, expr: ExprId, ty: Ty) -> Ty {
+        match ty.substs() {
+            Some(substs) if !substs.is_empty() => {
+                let def = match &ty {
+                    ty_app!(TypeCtor::FnDef(CallableDef::Function(f))) => Some((*f).into()),
+                    ty_app!(TypeCtor::FnDef(CallableDef::Struct(s)))
+                    | ty_app!(TypeCtor::Struct(s)) => Some((*s).into()),
+                    _ => None,
+                };
+                let type_vars: Substs = substs
+                    .iter()
+                    .map(|_| self.type_variables.new_type_var())
+                    .collect();
+                let ty = ty.subst(&type_vars);
+                self.generic_instantiations.push((expr, def, type_vars));
+                ty
+            }
+            _ => ty,
+        }
+    }
+
+    /// Returns the traits that bound the generic parameter with index `idx` of the definition
+    /// that is being inferred.
+    fn param_trait_bounds(&self, idx: u32) -> Vec<Trait> {
+        match self.owner {
+            DefWithBody::Function(f) => GenericDef::from(f).trait_bounds(self.db, idx),
+            DefWithBody::Const(_) | DefWithBody::Static(_) => Vec::new(),
+        }
+    }
+
+    /// Returns true if `ty` implements the trait `trait_def`. A generic parameter implements the
+    /// traits it is bound by.
+    fn implements_trait(&self, ty: &Ty, trait_def: Trait) -> bool {
+        match ty {
+            Ty::Unknown => true,
+            Ty::Param { idx, .. } => self.param_trait_bounds(*idx).contains(&trait_def),
+            _ => self
+                .db
+                .trait_impls(trait_def.module(self.db.upcast()).file_id())
+                .find_impl(trait_def, ty)
+                .is_some(),
+        }
+    }
+
+    /// Looks up a method declared in a trait that is implemented by `receiver_ty`. The methods of
+    /// a generic parameter are those of the traits that it is bound by.
+    fn lookup_trait_method(&self, receiver_ty: &Ty, name: &Name) -> Option<Function> {
+        let method = match receiver_ty {
+            Ty::Param { idx, .. } => self
+                .param_trait_bounds(*idx)
+                .into_iter()
+                .find_map(|trait_def| trait_def.function(self.db, name)),
+            _ => {
+                let module = self.resolver.module()?;
+                lookup_trait_method(self.db, module.file_id(), receiver_ty, name)
+            }
+        };
+        method.filter(|function| function.data(self.db).has_self_param())
+    }
 }
 
 impl<'a> InferenceResultBuilder<'a> {
@@ -225,23 +420,184 @@ impl<'a> InferenceResultBuilder<'a> {
     /// Record the type of the specified pattern and all sub-patterns.
     fn infer_pat(&mut self, pat: PatId, ty: Ty) {
         let body = Arc::clone(&self.body); // avoid borrow checker problem
-        #[allow(clippy::single_match)]
         match &body[pat] {
             Pat::Bind { .. } => {
                 self.set_pat_type(pat, ty);
             }
-            _ => {}
+            Pat::Path(path) => {
+                if let Some(pat_ty) = self.infer_path_pat(pat, path) {
+                    self.check_pat_ty(pat, &ty, pat_ty);
+                }
+                self.set_pat_type(pat, ty);
+            }
+            Pat::TupleStruct { path, args } => {
+                self.infer_tuple_struct_pat(pat, path, args, &ty);
+                self.set_pat_type(pat, ty);
+            }
+            Pat::Tuple(args) => {
+                self.infer_tuple_pat(pat, args, &ty);
+                self.set_pat_type(pat, ty);
+            }
+            Pat::Lit(expr) => {
+                let lit_ty =
+                    self.infer_expr_inner(*expr, &Expectation::none(), &CheckParams::default());
+                self.check_pat_ty(pat, &ty, lit_ty);
+                self.set_pat_type(pat, ty);
+            }
+            Pat::Wild | Pat::Missing => {}
+        }
+    }
+
+    /// Resolves the path of a pattern in the value namespace and returns its type.
+    fn infer_path_pat(&mut self, pat: PatId, path: &Path) -> Option<Ty> {
+        match self
+            .resolver
+            .resolve_path_without_assoc_items(self.db, path)
+            .take_values()
+        {
+            Some(Resolution::Def(def)) => {
+                let typable: Option<TypableDef> = def.into();
+                Some(self.db.type_for_def(typable?, Namespace::Values).0)
+            }
+            _ => {
+                self.diagnostics
+                    .push(InferenceDiagnostic::UnresolvedValue { id: pat.into() });
+                None
+            }
+        }
+    }
+
+    /// Infers the types of the sub-patterns of a tuple pattern (e.g. `(a, b)`).
+    fn infer_tuple_pat(&mut self, pat: PatId, args: &[PatId], ty: &Ty) {
+        if args.is_empty() {
+            self.check_pat_ty(pat, ty, Ty::Empty);
+            return;
+        }
+
+        let ty = self.resolve_ty_as_far_as_possible(ty.clone());
+        let element_tys: Vec<Ty> = match ty.as_tuple() {
+            Some(element_tys) => {
+                if element_tys.len() != args.len() {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::PatFieldCountMismatch {
+                            id: pat,
+                            found: args.len(),
+                            expected: element_tys.len(),
+                        });
+                }
+                element_tys.to_vec()
+            }
+            None => {
+                // Unify the type with a tuple of new type variables, so the types of the
+                // elements can be inferred from the way they are used.
+                let element_tys: Vec<Ty> = args
+                    .iter()
+                    .map(|_| self.type_variables.new_type_var())
+                    .collect();
+                self.check_pat_ty(pat, &ty, Ty::tuple(element_tys.iter().cloned().collect()));
+                element_tys
+            }
+        };
+
+        for (idx, arg) in args.iter().enumerate() {
+            let element_ty = element_tys.get(idx).cloned().unwrap_or(Ty::Unknown);
+            self.infer_pat(*arg, element_ty);
+        }
+    }
+
+    /// Infers the types of the sub-patterns of a tuple struct pattern (e.g. `Foo::Bar(a, b)`).
+    fn infer_tuple_struct_pat(&mut self, pat: PatId, path: &Path, args: &[PatId], ty: &Ty) {
+        let resolution = self
+            .resolver
+            .resolve_path_without_assoc_items(self.db, path)
+            .take_values();
+        let field_tys = match resolution {
+            Some(Resolution::Def(ModuleDef::EnumVariant(variant))) => {
+                let (ctor_ty, _) = self.db.type_for_def(variant.into(), Namespace::Values);
+                let sig = ctor_ty
+                    .callable_sig(self.db)
+                    .unwrap_or_else(|| FnSig::from_params_and_return(Vec::new(), ctor_ty));
+                self.check_pat_ty(pat, ty, sig.ret().clone());
+                if sig.params().len() != args.len() {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::PatFieldCountMismatch {
+                            id: pat,
+                            found: args.len(),
+                            expected: sig.params().len(),
+                        });
+                }
+                sig.params().to_vec()
+            }
+            Some(_) => {
+                if let Some(pat_ty) = self.infer_path_pat(pat, path) {
+                    self.check_pat_ty(pat, ty, pat_ty);
+                }
+                Vec::new()
+            }
+            None => {
+                self.diagnostics
+                    .push(InferenceDiagnostic::UnresolvedValue { id: pat.into() });
+                Vec::new()
+            }
+        };
+
+        for (idx, arg) in args.iter().enumerate() {
+            let field_ty = field_tys.get(idx).cloned().unwrap_or(Ty::Unknown);
+            self.infer_pat(*arg, field_ty);
+        }
+    }
+
+    /// Checks that the type of a pattern matches the type of the value it is matched against.
+    fn check_pat_ty(&mut self, pat: PatId, expected: &Ty, found: Ty) {
+        if !self.unify(&found, expected) {
+            self.diagnostics
+                .push(InferenceDiagnostic::MismatchedPatType {
+                    id: pat,
+                    expected: expected.clone(),
+                    found,
+                });
         }
     }
 
     /// Infer the types of all the expressions and sub-expressions in the body.
     fn infer_body(&mut self) {
+        if let DefWithBody::Function(_) = self.owner {
+            self.collect_return_positions(self.body.body_expr());
+        }
         self.infer_expr_coerce(
             self.body.body_expr(),
             &Expectation::has_type(self.return_ty.clone()),
         );
     }
 
+    /// Collects the expressions whose value is returned from the function: the body, the tail of
+    /// a block in return position and the branches of an `if` or `match` in return position.
+    fn collect_return_positions(&mut self, expr: ExprId) {
+        self.return_positions.insert(expr);
+        let body = Arc::clone(&self.body);
+        match &body[expr] {
+            Expr::Block {
+                tail: Some(tail), ..
+            } => self.collect_return_positions(*tail),
+            Expr::If {
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                self.collect_return_positions(*then_branch);
+                if let Some(else_branch) = else_branch {
+                    self.collect_return_positions(*else_branch);
+                }
+            }
+            Expr::Match { arms, .. } => {
+                for arm in arms.iter() {
+                    self.collect_return_positions(arm.expr);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Infers the type of the `tgt_expr`
     fn infer_expr(&mut self, tgt_expr: ExprId, expected: &Expectation) -> Ty {
         let ty = self.infer_expr_inner(tgt_expr, expected, &CheckParams::default());
@@ -267,12 +623,19 @@ impl<'a> InferenceResultBuilder<'a> {
     /// possible coercion. Adds a diagnostic message if coercion failed.
     fn coerce_expr_ty(&mut self, expr: ExprId, ty: Ty, expected: &Expectation) -> Ty {
         let ty = if !self.coerce(&ty, &expected.ty) {
-            self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
-                expected: expected.ty.clone(),
-                found: ty.clone(),
-                id: expr,
-            });
-            ty
+            if self.is_missing_return_value(expr, &ty) {
+                // Reported by the validator, which knows which paths reach the end of the
+                // function. The expression is treated as diverging to prevent errors in the
+                // enclosing expressions.
+                Ty::simple(TypeCtor::Never)
+            } else {
+                self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
+                    expected: expected.ty.clone(),
+                    found: ty.clone(),
+                    id: expr,
+                });
+                ty
+            }
         } else if expected.ty == Ty::Unknown {
             ty
         } else {
@@ -282,6 +645,20 @@ impl<'a> InferenceResultBuilder<'a> {
         self.resolve_ty_as_far_as_possible(ty)
     }
 
+    /// Returns true if `expr` is a block without a tail expression, an `if` without an `else`
+    /// branch or a loop that does not evaluate to a value, while its value is returned from the
+    /// function.
+    fn is_missing_return_value(&self, expr: ExprId, ty: &Ty) -> bool {
+        ty.is_empty()
+            && self.return_positions.contains(&expr)
+            && match &self.body[expr] {
+                Expr::Block { tail, .. } => tail.is_none(),
+                Expr::If { else_branch, .. } => else_branch.is_none(),
+                Expr::Loop { .. } | Expr::While { .. } | Expr::For { .. } => true,
+                _ => false,
+            }
+    }
+
     /// Infer the type of the given expression. Returns the type of the expression.
     fn infer_expr_inner(
         &mut self,
@@ -292,6 +669,7 @@ impl<'a> InferenceResultBuilder<'a> {
         let body = Arc::clone(&self.body); // avoid borrow checker problem
         let ty = match &body[tgt_expr] {
             Expr::Missing => Ty::Unknown,
+            Expr::Nil => self.infer_nil(tgt_expr, expected),
             Expr::Path(p) => {
                 // FIXME this could be more efficient...
                 let resolver = expr::resolver_for_expr(self.body.clone(), self.db, tgt_expr);
@@ -320,24 +698,17 @@ impl<'a> InferenceResultBuilder<'a> {
                             })
                         }
                     };
-                    let rhs_expected = op::binary_op_rhs_expectation(*op, lhs_ty.clone());
-                    if lhs_ty != Ty::Unknown && rhs_expected == Ty::Unknown {
-                        self.diagnostics
-                            .push(InferenceDiagnostic::CannotApplyBinaryOp {
-                                id: tgt_expr,
-                                lhs: lhs_ty,
-                                rhs: rhs_expected.clone(),
-                            })
+                    match self.infer_overloaded_binary_op(tgt_expr, *op, &lhs_ty, *rhs) {
+                        Some(ty) => ty,
+                        None => self.infer_builtin_binary_op(tgt_expr, *op, lhs_ty, *rhs),
                     }
-                    let rhs_ty = self.infer_expr(*rhs, &Expectation::has_type(rhs_expected));
-                    op::binary_op_return_ty(*op, rhs_ty)
                 }
                 _ => Ty::Unknown,
             },
             Expr::Block { statements, tail } => self.infer_block(statements, *tail, expected),
             Expr::Call { callee: call, args } => self.infer_call(tgt_expr, *call, args, expected),
             Expr::Literal(lit) => match lit {
-                Literal::String(_) => Ty::Unknown,
+                Literal::String(_) => Ty::simple(TypeCtor::String),
                 Literal::Bool(_) => Ty::simple(TypeCtor::Bool),
                 Literal::Int(LiteralInt {
                     kind: LiteralIntKind::Suffixed(suffix),
@@ -363,7 +734,7 @@ impl<'a> InferenceResultBuilder<'a> {
             },
             Expr::Return { expr } => {
                 if let Some(expr) = expr {
-                    self.infer_expr(*expr, &Expectation::has_type(self.return_ty.clone()));
+                    self.infer_expr_coerce(*expr, &Expectation::has_type(self.return_ty.clone()));
                 } else if self.return_ty != Ty::Empty {
                     self.diagnostics
                         .push(InferenceDiagnostic::ReturnMissingExpression { id: tgt_expr });
@@ -371,18 +742,49 @@ impl<'a> InferenceResultBuilder<'a> {
 
                 Ty::simple(TypeCtor::Never)
             }
-            Expr::Break { expr } => self.infer_break(tgt_expr, *expr),
-            Expr::Loop { body } => self.infer_loop_expr(tgt_expr, *body, expected),
-            Expr::While { condition, body } => {
-                self.infer_while_expr(tgt_expr, *condition, *body, expected)
+            Expr::Break { expr, label } => self.infer_break(tgt_expr, *expr, label.as_ref()),
+            Expr::Continue { label } => self.infer_continue(tgt_expr, label.as_ref()),
+            Expr::Loop { body, label } => {
+                self.infer_loop_expr(tgt_expr, *body, label.clone(), expected)
+            }
+            Expr::While {
+                condition,
+                body,
+                label,
+            } => self.infer_while_expr(tgt_expr, *condition, *body, label.clone(), expected),
+            Expr::For {
+                pat,
+                iterable,
+                body,
+                label,
+            } => self.infer_for_expr(tgt_expr, *pat, *iterable, *body, label.clone(), expected),
+            Expr::Match { expr, arms } => self.infer_match(tgt_expr, *expr, arms, expected),
+            Expr::Let { pat, expr } => {
+                // A nullable value is unwrapped; the pattern only matches if it is not `nil`
+                let input_ty = self.infer_expr(*expr, &Expectation::none());
+                let input_ty = match input_ty.as_nullable() {
+                    Some(inner_ty) => inner_ty.clone(),
+                    None => input_ty,
+                };
+                self.infer_pat(*pat, input_ty);
+                Ty::simple(TypeCtor::Bool)
+            }
+            Expr::Range { lhs, rhs, .. } => {
+                // The type of a range is only known as the iterable of a `for` loop, see
+                // `infer_for_expr`.
+                self.infer_range_bounds(*lhs, *rhs);
+                self.diagnostics
+                    .push(InferenceDiagnostic::RangeOutsideForLoop { id: tgt_expr });
+                Ty::Unknown
             }
             Expr::RecordLit {
                 type_id,
                 fields,
                 spread,
             } => {
-                let ty = self.resolve_type(*type_id);
+                let ty = self.resolve_record_lit_type(tgt_expr, *type_id);
                 let def_id = ty.as_struct();
+                let substs = ty.substs().unwrap_or_else(Substs::empty);
                 self.unify(&ty, &expected.ty);
 
                 for (idx, field) in fields.iter().enumerate() {
@@ -398,7 +800,7 @@ impl<'a> InferenceResultBuilder<'a> {
                                 None
                             }
                         })
-                        .map_or(Ty::Unknown, |field| field.ty(self.db));
+                        .map_or(Ty::Unknown, |field| field.ty(self.db).subst(&substs));
                     self.infer_expr_coerce(field.expr, &Expectation::has_type(field_ty));
                 }
                 if let Some(expr) = spread {
@@ -412,8 +814,9 @@ impl<'a> InferenceResultBuilder<'a> {
             Expr::Field { expr, name } => {
                 let receiver_ty = self.infer_expr(*expr, &Expectation::none());
                 match receiver_ty {
-                    ty_app!(TypeCtor::Struct(s)) => {
-                        match s.field(self.db, name).map(|field| field.ty(self.db)) {
+                    ty_app!(TypeCtor::Struct(s), ref parameters) => {
+                        let field = s.field(self.db, name);
+                        match field.map(|field| field.ty(self.db).subst(parameters)) {
                             Some(field_ty) => field_ty,
                             None => {
                                 self.diagnostics
@@ -427,6 +830,35 @@ impl<'a> InferenceResultBuilder<'a> {
                             }
                         }
                     }
+                    ty_app!(TypeCtor::Nullable, ref parameters) => {
+                        // The field is resolved anyway to avoid follow-up errors
+                        self.diagnostics
+                            .push(InferenceDiagnostic::NullableFieldAccess {
+                                id: tgt_expr,
+                                receiver_ty: receiver_ty.clone(),
+                            });
+                        match &parameters[0] {
+                            ty_app!(TypeCtor::Struct(s), parameters) => s
+                                .field(self.db, name)
+                                .map_or(Ty::Unknown, |field| field.ty(self.db).subst(parameters)),
+                            _ => Ty::Unknown,
+                        }
+                    }
+                    ty_app!(TypeCtor::Tuple { .. }, ref element_tys) => {
+                        match name.as_tuple_index().and_then(|idx| element_tys.get(idx)) {
+                            Some(element_ty) => element_ty.clone(),
+                            None => {
+                                self.diagnostics
+                                    .push(InferenceDiagnostic::AccessUnknownField {
+                                        id: tgt_expr,
+                                        receiver_ty,
+                                        name: name.clone(),
+                                    });
+
+                                Ty::Unknown
+                            }
+                        }
+                    }
                     _ => {
                         self.diagnostics.push(InferenceDiagnostic::NoFields {
                             id: *expr,
@@ -436,50 +868,50 @@ impl<'a> InferenceResultBuilder<'a> {
                     }
                 }
             }
+            Expr::MethodCall {
+                receiver,
+                method_name,
+                args,
+            } => self.infer_method_call(tgt_expr, *receiver, method_name, args),
+            Expr::Index { base, index } => {
+                let base_ty = self.infer_expr(*base, &Expectation::none());
+                self.infer_expr(
+                    *index,
+                    &Expectation::has_type(Ty::simple(TypeCtor::Int(IntTy::usize()))),
+                );
+                match base_ty.as_array() {
+                    Some((element_ty, _)) => element_ty.clone(),
+                    None => {
+                        if base_ty != Ty::Unknown {
+                            self.diagnostics.push(InferenceDiagnostic::CannotIndex {
+                                id: *base,
+                                found: base_ty,
+                            });
+                        }
+                        Ty::Unknown
+                    }
+                }
+            }
+            Expr::Cast { expr, type_ref } => self.infer_cast(tgt_expr, *expr, *type_ref),
+            Expr::Array(exprs) => self.infer_array(tgt_expr, exprs, expected),
+            Expr::Tuple(exprs) => self.infer_tuple(exprs, expected),
+            Expr::Lambda {
+                args,
+                ret_type,
+                body,
+            } => self.infer_lambda(args, *ret_type, *body, expected),
             Expr::UnaryOp { expr, op } => {
                 let inner_ty =
                     self.infer_expr_inner(*expr, &Expectation::none(), &CheckParams::default());
-                match op {
-                    UnaryOp::Not => match &inner_ty {
-                        Ty::Apply(ApplicationTy {
-                            ctor: TypeCtor::Bool,
-                            ..
-                        })
-                        | Ty::Apply(ApplicationTy {
-                            ctor: TypeCtor::Int(_),
-                            ..
-                        })
-                        | Ty::Infer(InferTy::IntVar(..)) => inner_ty,
-                        _ => {
-                            self.diagnostics
-                                .push(InferenceDiagnostic::CannotApplyUnaryOp {
-                                    id: *expr,
-                                    ty: inner_ty,
-                                });
-                            Ty::Unknown
-                        }
-                    },
-                    UnaryOp::Neg => match &inner_ty {
-                        Ty::Apply(ApplicationTy {
-                            ctor: TypeCtor::Float(_),
-                            ..
-                        })
-                        | Ty::Apply(ApplicationTy {
-                            ctor: TypeCtor::Int(_),
-                            ..
-                        })
-                        | Ty::Infer(InferTy::IntVar(..))
-                        | Ty::Infer(InferTy::FloatVar(..)) => inner_ty,
-                        _ => {
-                            self.diagnostics
-                                .push(InferenceDiagnostic::CannotApplyUnaryOp {
-                                    id: *expr,
-                                    ty: inner_ty,
-                                });
-                            Ty::Unknown
-                        }
-                    },
+                let ty = op::unary_op_return_ty(*op, inner_ty.clone());
+                if ty == Ty::Unknown {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::CannotApplyUnaryOp {
+                            id: *expr,
+                            ty: inner_ty,
+                        });
                 }
+                ty
             } //            Expr::Block { statements: _, tail: _ } => {}
         };
 
@@ -531,6 +963,36 @@ impl<'a> InferenceResultBuilder<'a> {
         }
     }
 
+    /// Inferences the type of a match expression. The arms of a match expression are merged the same
+    /// way the branches of an if expression are.
+    fn infer_match(
+        &mut self,
+        tgt_expr: ExprId,
+        expr: ExprId,
+        arms: &[MatchArm],
+        expected: &Expectation,
+    ) -> Ty {
+        let input_ty = self.infer_expr(expr, &Expectation::none());
+        let mut result_ty = Ty::simple(TypeCtor::Never);
+        for arm in arms {
+            self.infer_pat(arm.pat, input_ty.clone());
+            let arm_ty = self.infer_expr_coerce(arm.expr, expected);
+            result_ty = match self.coerce_merge_branch(&result_ty, &arm_ty) {
+                Some(ty) => ty,
+                None => {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::IncompatibleBranches {
+                            id: tgt_expr,
+                            then_ty: result_ty.clone(),
+                            else_ty: arm_ty,
+                        });
+                    result_ty
+                }
+            };
+        }
+        result_ty
+    }
+
     /// Inferences the type of a call expression.
     fn infer_call(
         &mut self,
@@ -544,6 +1006,7 @@ impl<'a> InferenceResultBuilder<'a> {
             &Expectation::none(),
             &CheckParams {
                 is_unit_struct: false,
+                is_callee: true,
             },
         );
 
@@ -571,7 +1034,7 @@ impl<'a> InferenceResultBuilder<'a> {
                 let (param_tys, ret_ty) = (sig.params().to_vec(), sig.ret().clone());
                 self.check_call_argument_count(
                     tgt_expr,
-                    def.is_struct(),
+                    def.is_struct() || def.is_enum_variant(),
                     args.len(),
                     param_tys.len(),
                 );
@@ -581,6 +1044,19 @@ impl<'a> InferenceResultBuilder<'a> {
 
                 ret_ty
             }
+            ty_app!(TypeCtor::FnPtr { .. }) => {
+                // Found a call of a closure or function value
+                let sig = callee_ty.callable_sig(self.db).unwrap();
+                self.check_call_argument_count(tgt_expr, false, args.len(), sig.params().len());
+                for (&arg, param_ty) in args.iter().zip(sig.params().iter()) {
+                    self.infer_expr_coerce(arg, &Expectation::has_type(param_ty.clone()));
+                }
+                for &arg in args.iter().skip(sig.params().len()) {
+                    self.infer_expr(arg, &Expectation::none());
+                }
+
+                sig.ret().clone()
+            }
             _ => {
                 self.diagnostics
                     .push(InferenceDiagnostic::ExpectedFunction {
@@ -686,6 +1162,10 @@ impl<'a> InferenceResultBuilder<'a> {
         {
             Some(resolution) => resolution,
             None => {
+                if let Some(function) = self.resolve_associated_function(resolver, path) {
+                    let ty = self.instantiate_generics(id, function.ty(self.db));
+                    return Some(self.check_function_value(id, function, ty, check_params));
+                }
                 self.diagnostics
                     .push(InferenceDiagnostic::UnresolvedValue { id: id.into() });
                 return None;
@@ -699,6 +1179,12 @@ impl<'a> InferenceResultBuilder<'a> {
                 Some(ty)
             }
             Resolution::Def(def) => {
+                if let ModuleDef::Static(s) = def {
+                    if s.module(self.db.upcast()) != self.owner.module(self.db.upcast()) {
+                        self.diagnostics
+                            .push(InferenceDiagnostic::StaticOutsideModule { id, static_def: s });
+                    }
+                }
                 let typable: Option<TypableDef> = def.into();
                 let typable = typable?;
                 // TODO: Add detection of cyclick types
@@ -708,9 +1194,52 @@ impl<'a> InferenceResultBuilder<'a> {
                         self.check_unit_struct_lit(id, s);
                     }
                 }
-                Some(ty)
+                let ty = self.instantiate_generics(id, ty);
+                Some(match typable {
+                    TypableDef::Function(f) => self.check_function_value(id, f, ty, check_params),
+                    _ => ty,
+                })
             }
+            Resolution::GenericParam(_) => None,
+        }
+    }
+
+    /// Returns the type of a path expression that refers to a function. Outside of a call
+    /// expression, the function is used as a value of a function pointer type.
+    fn check_function_value(
+        &mut self,
+        id: ExprId,
+        function: Function,
+        ty: Ty,
+        check_params: &CheckParams,
+    ) -> Ty {
+        if check_params.is_callee {
+            return ty;
+        }
+        let substs = ty.substs().unwrap_or_else(Substs::empty);
+        self.function_values.insert(id, (function, substs));
+        Ty::fn_ptr(ty.callable_sig(self.db).unwrap())
+    }
+
+    /// Resolves a path of the form `Foo::bar` or `foo::Foo::bar` to the associated function `bar`
+    /// of the type `Foo`.
+    fn resolve_associated_function(&self, resolver: &Resolver, path: &Path) -> Option<Function> {
+        let (function_segment, type_segments) = path.segments.split_last()?;
+        if type_segments.is_empty() {
+            return None;
         }
+        let type_path = Path {
+            kind: path.kind.clone(),
+            segments: type_segments
+                .iter()
+                .map(|segment| PathSegment {
+                    name: segment.name.clone(),
+                    type_args: None,
+                })
+                .collect(),
+        };
+        let ty = Ty::from_hir_path(self.db, resolver, &type_path)?.0;
+        lookup_associated_function(self.db, &ty, &function_segment.name)
     }
 
     fn resolve_all(mut self) -> InferenceResult {
@@ -727,6 +1256,29 @@ impl<'a> InferenceResultBuilder<'a> {
             }
             *ty = resolved;
         }
+        // The operand of a negation might have been an integer of which the type was only
+        // inferred afterwards, so unsigned operands are reported once all types are known
+        for (expr, data) in self.body.exprs() {
+            if let Expr::UnaryOp {
+                expr: operand,
+                op: UnaryOp::Neg,
+            } = data
+            {
+                let ty = match expr_types.get(expr) {
+                    Some(ty) => ty,
+                    None => continue,
+                };
+                if let ty_app!(TypeCtor::Int(int_ty)) = ty {
+                    if int_ty.signedness == Signedness::Unsigned {
+                        self.diagnostics
+                            .push(InferenceDiagnostic::CannotApplyUnaryOp {
+                                id: *operand,
+                                ty: ty.clone(),
+                            });
+                    }
+                }
+            }
+        }
         let mut pat_types = std::mem::take(&mut self.type_of_pat);
         for (pat, ty) in pat_types.iter_mut() {
             let was_unknown = ty == &mut Ty::Unknown;
@@ -738,8 +1290,60 @@ impl<'a> InferenceResultBuilder<'a> {
             }
             *ty = resolved;
         }
+        // Report the instantiations of generic definitions of which not all type arguments could be
+        // inferred and check that the type arguments of the others satisfy the trait bounds of
+        // the generic parameters.
+        for (expr, def, type_vars) in std::mem::take(&mut self.generic_instantiations) {
+            let type_args: Vec<Ty> = type_vars
+                .iter()
+                .map(|ty| self.type_variables.resolve_ty_completely(ty.clone()))
+                .collect();
+            if type_args.iter().any(|ty| *ty == Ty::Unknown) {
+                self.diagnostics
+                    .push(InferenceDiagnostic::CannotInferTypeArgs { id: expr });
+                continue;
+            }
+            let def = match def {
+                Some(def) => def,
+                None => continue,
+            };
+            for (idx, ty) in type_args.into_iter().enumerate() {
+                for trait_def in def.trait_bounds(self.db, idx as u32) {
+                    if !self.implements_trait(&ty, trait_def) {
+                        self.diagnostics
+                            .push(InferenceDiagnostic::TraitBoundNotSatisfied {
+                                id: expr,
+                                trait_def,
+                                ty: ty.clone(),
+                            });
+                    }
+                }
+            }
+        }
+
+        // Report the parameters of closures of which the type could not be inferred
+        for (pat, type_var) in std::mem::take(&mut self.inferred_params) {
+            if self.type_variables.resolve_ty_completely(type_var) == Ty::Unknown {
+                self.diagnostics
+                    .push(InferenceDiagnostic::CannotInferParamType { id: pat });
+            }
+        }
+
+        let mut method_resolutions = std::mem::take(&mut self.method_resolutions);
+        let mut function_values = std::mem::take(&mut self.function_values);
+        for (_, substs) in method_resolutions
+            .values_mut()
+            .chain(function_values.values_mut())
+        {
+            *substs = substs
+                .iter()
+                .map(|ty| self.type_variables.resolve_ty_completely(ty.clone()))
+                .collect();
+        }
         InferenceResult {
-            //            method_resolutions: self.method_resolutions,
+            method_resolutions,
+            function_values,
+            loop_targets: self.loop_targets,
             //            field_resolutions: self.field_resolutions,
             //            variant_resolutions: self.variant_resolutions,
             //            assoc_resolutions: self.assoc_resolutions,
@@ -778,12 +1382,17 @@ impl<'a> InferenceResultBuilder<'a> {
                         TypableDef::Struct(s) => (s.ty(self.db), Some(s.into())),
                         TypableDef::BuiltinType(_)
                         | TypableDef::Function(_)
-                        | TypableDef::TypeAlias(_) => (Ty::Unknown, None),
+                        | TypableDef::Enum(_)
+                        | TypableDef::EnumVariant(_)
+                        | TypableDef::TypeAlias(_)
+                        | TypableDef::Const(_)
+                        | TypableDef::Static(_) => (Ty::Unknown, None),
                     }
                 } else {
                     unreachable!();
                 }
             }
+            Resolution::GenericParam(_) => (Ty::Unknown, None),
         }
     }
 
@@ -842,19 +1451,50 @@ impl<'a> InferenceResultBuilder<'a> {
         }
     }
 
-    fn infer_break(&mut self, tgt_expr: ExprId, expr: Option<ExprId>) -> Ty {
-        let expected = match &self.active_loop {
-            Some(ActiveLoop::Loop(_, info)) => info.clone(),
-            Some(_) => {
-                if expr.is_some() {
+    /// Resolves the loop that is exited or continued by the `break` or `continue` expression
+    /// `tgt_expr`, which is the innermost loop or the loop with the specified `label`. Returns the
+    /// index of the loop in `active_loops`, or `None` if there is no such loop.
+    fn resolve_loop_target(&mut self, tgt_expr: ExprId, label: Option<&Name>) -> Option<usize> {
+        let index = match label {
+            Some(label) => {
+                let index = self
+                    .active_loops
+                    .iter()
+                    .rposition(|active_loop| active_loop.label.as_ref() == Some(label));
+                if index.is_none() {
+                    self.diagnostics.push(InferenceDiagnostic::UndeclaredLabel {
+                        id: tgt_expr,
+                        label: label.clone(),
+                    });
+                }
+                index
+            }
+            None => self.active_loops.len().checked_sub(1),
+        }?;
+
+        self.loop_targets
+            .insert(tgt_expr, self.active_loops[index].expr);
+        Some(index)
+    }
+
+    fn infer_break(&mut self, tgt_expr: ExprId, expr: Option<ExprId>, label: Option<&Name>) -> Ty {
+        let index = match self.resolve_loop_target(tgt_expr, label) {
+            Some(index) => index,
+            None => {
+                if label.is_none() {
                     self.diagnostics
-                        .push(InferenceDiagnostic::BreakWithValueOutsideLoop { id: tgt_expr });
+                        .push(InferenceDiagnostic::BreakOutsideLoop { id: tgt_expr });
                 }
                 return Ty::simple(TypeCtor::Never);
             }
-            None => {
-                self.diagnostics
-                    .push(InferenceDiagnostic::BreakOutsideLoop { id: tgt_expr });
+        };
+        let expected = match &self.active_loops[index].kind {
+            LoopKind::Loop(_, info) => info.clone(),
+            _ => {
+                if expr.is_some() {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::BreakWithValueOutsideLoop { id: tgt_expr });
+                }
                 return Ty::simple(TypeCtor::Never);
             }
         };
@@ -879,15 +1519,31 @@ impl<'a> InferenceResultBuilder<'a> {
         };
 
         // Update the expected type for the rest of the loop
-        self.active_loop = Some(ActiveLoop::Loop(ty.clone(), Expectation::has_type(ty)));
+        self.active_loops[index].kind = LoopKind::Loop(ty.clone(), Expectation::has_type(ty));
 
         Ty::simple(TypeCtor::Never)
     }
 
-    fn infer_loop_expr(&mut self, _tgt_expr: ExprId, body: ExprId, expected: &Expectation) -> Ty {
-        if let ActiveLoop::Loop(ty, _) = self.infer_loop_block(
-            body,
-            ActiveLoop::Loop(Ty::simple(TypeCtor::Never), expected.clone()),
+    fn infer_continue(&mut self, tgt_expr: ExprId, label: Option<&Name>) -> Ty {
+        if self.resolve_loop_target(tgt_expr, label).is_none() && label.is_none() {
+            self.diagnostics
+                .push(InferenceDiagnostic::ContinueOutsideLoop { id: tgt_expr });
+        }
+        Ty::simple(TypeCtor::Never)
+    }
+
+    fn infer_loop_expr(
+        &mut self,
+        tgt_expr: ExprId,
+        body: ExprId,
+        label: Option<Name>,
+        expected: &Expectation,
+    ) -> Ty {
+        if let LoopKind::Loop(ty, _) = self.infer_loop_block(
+            tgt_expr,
+            body,
+            label,
+            LoopKind::Loop(Ty::simple(TypeCtor::Never), expected.clone()),
         ) {
             ty
         } else {
@@ -895,21 +1551,32 @@ impl<'a> InferenceResultBuilder<'a> {
         }
     }
 
-    fn infer_loop_block(&mut self, body: ExprId, lp: ActiveLoop) -> ActiveLoop {
-        let top_level_loop = std::mem::replace(&mut self.active_loop, Some(lp));
+    fn infer_loop_block(
+        &mut self,
+        tgt_expr: ExprId,
+        body: ExprId,
+        label: Option<Name>,
+        kind: LoopKind,
+    ) -> LoopKind {
+        self.active_loops.push(ActiveLoop {
+            expr: tgt_expr,
+            label,
+            kind,
+        });
 
         // Infer the body of the loop
         self.infer_expr_coerce(body, &Expectation::has_type(Ty::Empty));
 
-        // Take the result of the loop information and replace with top level loop
-        std::mem::replace(&mut self.active_loop, top_level_loop).unwrap()
+        // Take the result of the loop information
+        self.active_loops.pop().unwrap().kind
     }
 
     fn infer_while_expr(
         &mut self,
-        _tgt_expr: ExprId,
+        tgt_expr: ExprId,
         condition: ExprId,
         body: ExprId,
+        label: Option<Name>,
         _expected: &Expectation,
     ) -> Ty {
         self.infer_expr(
@@ -917,26 +1584,412 @@ impl<'a> InferenceResultBuilder<'a> {
             &Expectation::has_type(Ty::simple(TypeCtor::Bool)),
         );
 
-        self.infer_loop_block(body, ActiveLoop::While);
+        self.infer_loop_block(tgt_expr, body, label, LoopKind::While);
         Ty::Empty
     }
 
+    fn infer_for_expr(
+        &mut self,
+        tgt_expr: ExprId,
+        pat: PatId,
+        iterable: ExprId,
+        body: ExprId,
+        label: Option<Name>,
+        _expected: &Expectation,
+    ) -> Ty {
+        let elem_ty = match &self.body[iterable] {
+            Expr::Range { lhs, rhs, .. } => {
+                let (lhs, rhs) = (*lhs, *rhs);
+                let elem_ty = self.infer_range_bounds(lhs, rhs);
+                match elem_ty {
+                    ty_app!(TypeCtor::Int(_)) | Ty::Infer(InferTy::IntVar(_)) | Ty::Unknown => {}
+                    _ => self.diagnostics.push(InferenceDiagnostic::NonIntegerRange {
+                        id: iterable,
+                        found: elem_ty.clone(),
+                    }),
+                }
+                elem_ty
+            }
+            _ => {
+                let found = self.infer_expr(iterable, &Expectation::none());
+                if found != Ty::Unknown {
+                    self.diagnostics.push(InferenceDiagnostic::ExpectedRange {
+                        id: iterable,
+                        found,
+                    });
+                }
+                Ty::Unknown
+            }
+        };
+
+        self.infer_pat(pat, elem_ty);
+        self.infer_loop_block(tgt_expr, body, label, LoopKind::For);
+        Ty::Empty
+    }
+
+    /// Infers the type of `nil`, which can only be determined from the nullable type that is
+    /// expected.
+    fn infer_nil(&mut self, tgt_expr: ExprId, expected: &Expectation) -> Ty {
+        let expected_ty = self.resolve_ty_as_far_as_possible(expected.ty.clone());
+        match expected_ty {
+            ty_app!(TypeCtor::Nullable) => expected_ty,
+            Ty::Unknown => {
+                self.diagnostics
+                    .push(InferenceDiagnostic::CannotInferNilType { id: tgt_expr });
+                Ty::Unknown
+            }
+            // A mismatch is reported by the caller
+            _ => Ty::nullable(self.type_variables.new_type_var()),
+        }
+    }
+
+    /// Infers the type of a cast, which is the type that the value is converted to. Numeric types
+    /// can be converted to one another and a `bool` can be converted to an integer. Any other
+    /// conversion is only valid if it could also be performed implicitly.
+    fn infer_cast(&mut self, tgt_expr: ExprId, expr: ExprId, type_ref: LocalTypeRefId) -> Ty {
+        let cast_ty = self.resolve_type(type_ref);
+        let expr_ty = self.infer_expr(expr, &Expectation::none());
+        let expr_ty = self.resolve_ty_as_far_as_possible(expr_ty);
+
+        let is_numeric = |ty: &Ty| {
+            matches!(
+                ty,
+                ty_app!(TypeCtor::Int(_))
+                    | ty_app!(TypeCtor::Float(_))
+                    | Ty::Infer(InferTy::IntVar(_))
+                    | Ty::Infer(InferTy::FloatVar(_))
+            )
+        };
+        let is_valid = match (&expr_ty, &cast_ty) {
+            (Ty::Unknown, _) | (_, Ty::Unknown) => true,
+            (ty_app!(TypeCtor::Bool), ty_app!(TypeCtor::Int(_))) => true,
+            (from, to) if is_numeric(from) && is_numeric(to) => true,
+            _ => self.coerce(&expr_ty, &cast_ty),
+        };
+        if !is_valid {
+            self.diagnostics.push(InferenceDiagnostic::InvalidCast {
+                id: tgt_expr,
+                from: expr_ty,
+                to: cast_ty.clone(),
+            });
+        }
+
+        cast_ty
+    }
+
+    /// Infers the type of an array literal. Without an expectation the literal is a fixed-size
+    /// array, if a `[T]` is expected the literal is allocated as a garbage collected array.
+    fn infer_array(&mut self, tgt_expr: ExprId, exprs: &[ExprId], expected: &Expectation) -> Ty {
+        let expected_ty = self.resolve_ty_as_far_as_possible(expected.ty.clone());
+        let (element_ty, is_gc) = match expected_ty.as_array() {
+            Some((element_ty, len)) => (element_ty.clone(), len.is_none()),
+            None => {
+                if exprs.is_empty() {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::CannotInferArrayType { id: tgt_expr });
+                }
+                (self.type_variables.new_type_var(), false)
+            }
+        };
+
+        for expr in exprs.iter() {
+            self.infer_expr_coerce(*expr, &Expectation::has_type(element_ty.clone()));
+        }
+
+        let element_ty = self.resolve_ty_as_far_as_possible(element_ty);
+        if is_gc {
+            Ty::array(element_ty)
+        } else {
+            Ty::fixed_array(element_ty, exprs.len() as u64)
+        }
+    }
+
+    /// Infers the type of a tuple expression. The types of the elements are coerced to the types
+    /// of the expected tuple type, if any.
+    fn infer_tuple(&mut self, exprs: &[ExprId], expected: &Expectation) -> Ty {
+        if exprs.is_empty() {
+            return Ty::Empty;
+        }
+
+        let expected_ty = self.resolve_ty_as_far_as_possible(expected.ty.clone());
+        let expected_element_tys = expected_ty
+            .as_tuple()
+            .filter(|element_tys| element_tys.len() == exprs.len())
+            .cloned();
+
+        let element_tys = exprs
+            .iter()
+            .enumerate()
+            .map(|(idx, expr)| match &expected_element_tys {
+                Some(element_tys) => {
+                    self.infer_expr_coerce(*expr, &Expectation::has_type(element_tys[idx].clone()))
+                }
+                None => self.infer_expr(*expr, &Expectation::none()),
+            })
+            .collect();
+        Ty::tuple(element_tys)
+    }
+
+    /// Infers the type of a closure. The types of the parameters and the return type that are not
+    /// annotated are taken from the expected function pointer type, if any, or otherwise inferred
+    /// from the way they are used.
+    fn infer_lambda(
+        &mut self,
+        args: &[(PatId, Option<LocalTypeRefId>)],
+        ret_type: Option<LocalTypeRefId>,
+        body: ExprId,
+        expected: &Expectation,
+    ) -> Ty {
+        let expected_ty = self.resolve_ty_as_far_as_possible(expected.ty.clone());
+        let expected_sig = match expected_ty {
+            ty_app!(TypeCtor::FnPtr { num_args }) if num_args as usize == args.len() => {
+                expected_ty.callable_sig(self.db)
+            }
+            _ => None,
+        };
+
+        let mut param_tys = Vec::with_capacity(args.len());
+        for (idx, (pat, type_ref)) in args.iter().enumerate() {
+            let ty = match (type_ref, &expected_sig) {
+                (Some(type_ref), _) => self.resolve_type(*type_ref),
+                (None, Some(sig)) => sig.params()[idx].clone(),
+                (None, None) => {
+                    let type_var = self.type_variables.new_type_var();
+                    self.inferred_params.push((*pat, type_var.clone()));
+                    type_var
+                }
+            };
+            self.infer_pat(*pat, ty.clone());
+            param_tys.push(ty);
+        }
+
+        let ret_ty = match (ret_type, &expected_sig) {
+            (Some(type_ref), _) => self.resolve_type(type_ref),
+            (None, Some(sig)) => sig.ret().clone(),
+            (None, None) => self.type_variables.new_type_var(),
+        };
+
+        // The body of the closure is inferred as a separate function; `return` statements refer
+        // to the closure and it cannot break out of an enclosing loop.
+        let outer_return_ty = mem::replace(&mut self.return_ty, ret_ty.clone());
+        let outer_loops = mem::take(&mut self.active_loops);
+        let body_ty = self.infer_expr_inner(
+            body,
+            &Expectation::has_type(ret_ty.clone()),
+            &CheckParams::default(),
+        );
+        if !body_ty.is_never() {
+            self.coerce_expr_ty(body, body_ty, &Expectation::has_type(ret_ty.clone()));
+        }
+        self.return_ty = outer_return_ty;
+        self.active_loops = outer_loops;
+
+        let param_tys = param_tys
+            .into_iter()
+            .map(|ty| self.resolve_ty_as_far_as_possible(ty))
+            .collect();
+        let ret_ty = self.resolve_ty_as_far_as_possible(ret_ty);
+        Ty::fn_ptr(FnSig::from_params_and_return(param_tys, ret_ty))
+    }
+
+    /// Infers the type of a method call. Apart from methods defined in `impl` blocks, intrinsics
+    /// exist for the length of arrays and strings, and for integer arithmetic.
+    fn infer_method_call(
+        &mut self,
+        tgt_expr: ExprId,
+        receiver: ExprId,
+        method_name: &Name,
+        args: &[ExprId],
+    ) -> Ty {
+        let receiver_ty = self.infer_expr(receiver, &Expectation::none());
+
+        // Methods of inherent `impl` blocks take precedence over methods of traits
+        let method = lookup_associated_function(self.db, &receiver_ty, method_name)
+            .filter(|function| function.data(self.db).has_self_param())
+            .or_else(|| self.lookup_trait_method(&receiver_ty, method_name));
+        if let Some(method) = method {
+            let method_ty = self.instantiate_generics(tgt_expr, method.ty(self.db));
+            let substs = method_ty.substs().unwrap_or_else(Substs::empty);
+            self.method_resolutions.insert(tgt_expr, (method, substs));
+
+            // The first parameter of the signature is the `self` parameter, which determines the
+            // `Self` type argument of a method declared in a trait
+            let sig = method_ty.callable_sig(self.db).unwrap();
+            self.unify(&sig.params()[0], &receiver_ty);
+            let param_tys = &sig.params()[1..];
+            self.check_call_argument_count(tgt_expr, false, args.len(), param_tys.len());
+            for (&arg, param_ty) in args.iter().zip(param_tys.iter()) {
+                self.infer_expr_coerce(arg, &Expectation::has_type(param_ty.clone()));
+            }
+            for &arg in args.iter().skip(param_tys.len()) {
+                self.infer_expr(arg, &Expectation::none());
+            }
+            return sig.ret().clone();
+        }
+
+        // Integers have arithmetic methods with an explicit overflow behavior, which take an
+        // operand of the same type
+        let is_int = matches!(
+            receiver_ty,
+            ty_app!(TypeCtor::Int(_)) | Ty::Infer(InferTy::IntVar(_))
+        );
+        if is_int {
+            if let Some((_, behavior)) = expr::ArithOp::from_int_method_name(method_name) {
+                self.check_call_argument_count(tgt_expr, false, args.len(), 1);
+                if let Some(&arg) = args.first() {
+                    self.infer_expr_coerce(arg, &Expectation::has_type(receiver_ty.clone()));
+                }
+                for &arg in args.iter().skip(1) {
+                    self.infer_expr(arg, &Expectation::none());
+                }
+                // A checked operation also returns whether it overflowed
+                return match behavior {
+                    expr::OverflowBehavior::Checked => {
+                        let bool_ty = Ty::simple(TypeCtor::Bool);
+                        Ty::tuple(vec![receiver_ty, bool_ty].into_iter().collect())
+                    }
+                    _ => receiver_ty,
+                };
+            }
+        }
+
+        for arg in args.iter() {
+            self.infer_expr(*arg, &Expectation::none());
+        }
+
+        let has_len =
+            receiver_ty.as_array().is_some() || receiver_ty == Ty::simple(TypeCtor::String);
+        if has_len && *method_name == name![len] {
+            if !args.is_empty() {
+                self.diagnostics
+                    .push(InferenceDiagnostic::ParameterCountMismatch {
+                        id: tgt_expr,
+                        found: args.len(),
+                        expected: 0,
+                    });
+            }
+            Ty::simple(TypeCtor::Int(IntTy::usize()))
+        } else {
+            if receiver_ty != Ty::Unknown {
+                self.diagnostics.push(InferenceDiagnostic::MethodNotFound {
+                    id: tgt_expr,
+                    receiver_ty,
+                    name: method_name.clone(),
+                });
+            }
+            Ty::Unknown
+        }
+    }
+
+    /// Infers the type of a binary operation on builtin types, e.g. numbers or strings.
+    fn infer_builtin_binary_op(
+        &mut self,
+        tgt_expr: ExprId,
+        op: BinaryOp,
+        lhs_ty: Ty,
+        rhs: ExprId,
+    ) -> Ty {
+        let rhs_expected = op::binary_op_rhs_expectation(self.db, op, lhs_ty.clone());
+        if lhs_ty != Ty::Unknown && rhs_expected == Ty::Unknown {
+            self.diagnostics
+                .push(InferenceDiagnostic::CannotApplyBinaryOp {
+                    id: tgt_expr,
+                    lhs: lhs_ty,
+                    rhs: rhs_expected.clone(),
+                })
+        }
+        let rhs_expected = Expectation::has_type(rhs_expected);
+        let rhs_ty = if let BinaryOp::Assignment { op: None } = op {
+            self.infer_expr_coerce(rhs, &rhs_expected)
+        } else {
+            self.infer_expr(rhs, &rhs_expected)
+        };
+        op::binary_op_return_ty(op, rhs_ty)
+    }
+
+    /// Infers the type of a binary operation of which the operator is overloaded by a method of
+    /// the type on the left hand side, e.g. `add` for `a + b`. Structs, enums and generic types
+    /// can overload operators. Returns `None` if the operator is not overloaded.
+    fn infer_overloaded_binary_op(
+        &mut self,
+        tgt_expr: ExprId,
+        op: BinaryOp,
+        lhs_ty: &Ty,
+        rhs: ExprId,
+    ) -> Option<Ty> {
+        let is_user_defined = matches!(
+            lhs_ty,
+            ty_app!(TypeCtor::Struct(_)) | ty_app!(TypeCtor::Enum(_)) | Ty::Param { .. }
+        );
+        if !is_user_defined {
+            return None;
+        }
+
+        // The method must take the operand on the right hand side as its only argument, and a
+        // comparison must return a boolean
+        let name = op::binary_op_method_name(op)?;
+        let method = lookup_associated_function(self.db, lhs_ty, &name)
+            .filter(|function| function.data(self.db).has_self_param())
+            .or_else(|| self.lookup_trait_method(lhs_ty, &name))?;
+        let sig = self.db.callable_sig(method.into());
+        let is_bool = *sig.ret() == Ty::simple(TypeCtor::Bool);
+        if sig.params().len() != 2 || (matches!(op, BinaryOp::CmpOp(_)) && !is_bool) {
+            return None;
+        }
+
+        let method_ty = self.instantiate_generics(tgt_expr, method.ty(self.db));
+        let substs = method_ty.substs().unwrap_or_else(Substs::empty);
+        self.method_resolutions.insert(tgt_expr, (method, substs));
+
+        let sig = method_ty.callable_sig(self.db).unwrap();
+        self.unify(&sig.params()[0], lhs_ty);
+        self.infer_expr_coerce(rhs, &Expectation::has_type(sig.params()[1].clone()));
+        let ret_ty = self.resolve_ty_as_far_as_possible(sig.ret().clone());
+        match op {
+            // `a += b` assigns the result of `a.add(b)` to `a`
+            BinaryOp::Assignment { .. } => {
+                if !self.unify(&ret_ty, lhs_ty) {
+                    self.diagnostics.push(InferenceDiagnostic::MismatchedTypes {
+                        expected: lhs_ty.clone(),
+                        found: ret_ty,
+                        id: tgt_expr,
+                    });
+                }
+                Some(Ty::Empty)
+            }
+            _ => Some(ret_ty),
+        }
+    }
+
+    /// Infers the type of the bounds of a range. Both bounds must have the same type, which is
+    /// returned.
+    fn infer_range_bounds(&mut self, lhs: ExprId, rhs: ExprId) -> Ty {
+        let lhs_ty = self.infer_expr(lhs, &Expectation::none());
+        let rhs_ty = self.infer_expr(rhs, &Expectation::has_type(lhs_ty.clone()));
+        let ty = if lhs_ty == Ty::Unknown {
+            rhs_ty
+        } else {
+            lhs_ty
+        };
+        self.resolve_ty_as_far_as_possible(ty)
+    }
+
     pub fn report_pat_inference_failure(&mut self, _pat: PatId) {
         //        self.diagnostics.push(InferenceDiagnostic::PatInferenceFailed {
         //            pat
         //        });
-        // Currently this should never happen because we can only infer integer and floating-point
-        // types which always have a fallback value.
-        panic!("pattern failed inferencing");
+        // Integer and floating-point types always have a fallback value. Other type variables are
+        // only created for the element type of an empty array and for the type arguments of a
+        // generic definition, for which a diagnostic has already been reported.
     }
 
     pub fn report_expr_inference_failure(&mut self, _expr: ExprId) {
         //        self.diagnostics.push(InferenceDiagnostic::ExprInferenceFailed {
         //            expr
         //        });
-        // Currently this should never happen because we can only infer integer and floating-point
-        // types which always have a fallback value.
-        panic!("expression failed inferencing");
+        // Integer and floating-point types always have a fallback value. Other type variables are
+        // only created for the element type of an empty array and for the type arguments of a
+        // generic definition, for which a diagnostic has already been reported.
     }
 }
 
@@ -971,12 +2024,16 @@ impl Expectation {
 struct CheckParams {
     /// Checks whether a `Expr::Path` of type struct, is actually a unit struct
     is_unit_struct: bool,
+    /// Whether the expression is the callee of a call expression. A function that is referred to
+    /// anywhere else is used as a value.
+    is_callee: bool,
 }
 
 impl Default for CheckParams {
     fn default() -> Self {
         Self {
             is_unit_struct: true,
+            is_callee: false,
         }
     }
 }
@@ -1002,17 +2059,24 @@ impl From<PatId> for ExprOrPatId {
 mod diagnostics {
     use crate::diagnostics::{
         AccessUnknownField, BreakOutsideLoop, BreakWithValueOutsideLoop, CannotApplyBinaryOp,
-        CannotApplyUnaryOp, ExpectedFunction, FieldCountMismatch, IncompatibleBranch, InvalidLHS,
-        LiteralOutOfRange, MismatchedStructLit, MismatchedType, MissingElseBranch, MissingFields,
-        NoFields, NoSuchField, ParameterCountMismatch, ReturnMissingExpression,
+        CannotApplyUnaryOp, CannotIndex, CannotInferArrayType, CannotInferNilType,
+        CannotInferParamType, CannotInferTypeArgs, ContinueOutsideLoop, ExpectedFunction,
+        ExpectedRange, FieldCountMismatch, IncompatibleBranch, InvalidCast, InvalidLHS,
+        LiteralOutOfRange, MethodNotFound, MismatchedStructLit, MismatchedType, MissingElseBranch,
+        MissingFields, NoFields, NoSuchField, NonIntegerRange, NullableFieldAccess,
+        ParameterCountMismatch, RangeOutsideForLoop, ReturnMissingExpression, StaticOutsideModule,
+        TraitBoundNotSatisfied, UndeclaredLabel,
     };
     use crate::{
         adt::StructKind,
-        code_model::src::HasSource,
-        diagnostics::{CyclicType, DiagnosticSink, UnresolvedType, UnresolvedValue},
+        code_model::DefWithBody,
+        diagnostics::{
+            CyclicType, DiagnosticSink, InvalidArrayLength, InvalidNullableType,
+            TypeArgCountMismatch, UnresolvedType, UnresolvedValue,
+        },
         ty::infer::ExprOrPatId,
         type_ref::LocalTypeRefId,
-        ExprId, Function, HirDatabase, IntTy, Name, Ty,
+        ExprId, HirDatabase, HirDisplay, IntTy, Name, PatId, Static, Trait, Ty,
     };
 
     #[derive(Debug, PartialEq, Eq, Clone)]
@@ -1026,6 +2090,17 @@ mod diagnostics {
         CyclicType {
             id: LocalTypeRefId,
         },
+        TypeArgCountMismatch {
+            id: LocalTypeRefId,
+            expected: usize,
+            found: usize,
+        },
+        InvalidNullableType {
+            id: LocalTypeRefId,
+        },
+        InvalidArrayLength {
+            id: LocalTypeRefId,
+        },
         ExpectedFunction {
             id: ExprId,
             found: Ty,
@@ -1040,6 +2115,11 @@ mod diagnostics {
             expected: Ty,
             found: Ty,
         },
+        MismatchedPatType {
+            id: PatId,
+            expected: Ty,
+            found: Ty,
+        },
         IncompatibleBranches {
             id: ExprId,
             then_ty: Ty,
@@ -1071,16 +2151,74 @@ mod diagnostics {
         BreakWithValueOutsideLoop {
             id: ExprId,
         },
+        ContinueOutsideLoop {
+            id: ExprId,
+        },
+        UndeclaredLabel {
+            id: ExprId,
+            label: Name,
+        },
+        ExpectedRange {
+            id: ExprId,
+            found: Ty,
+        },
+        NonIntegerRange {
+            id: ExprId,
+            found: Ty,
+        },
+        RangeOutsideForLoop {
+            id: ExprId,
+        },
+        CannotIndex {
+            id: ExprId,
+            found: Ty,
+        },
+        CannotInferArrayType {
+            id: ExprId,
+        },
+        CannotInferNilType {
+            id: ExprId,
+        },
+        CannotInferTypeArgs {
+            id: ExprId,
+        },
+        CannotInferParamType {
+            id: PatId,
+        },
+        TraitBoundNotSatisfied {
+            id: ExprId,
+            trait_def: Trait,
+            ty: Ty,
+        },
+        MethodNotFound {
+            id: ExprId,
+            receiver_ty: Ty,
+            name: Name,
+        },
         AccessUnknownField {
             id: ExprId,
             receiver_ty: Ty,
             name: Name,
         },
+        NullableFieldAccess {
+            id: ExprId,
+            receiver_ty: Ty,
+        },
+        InvalidCast {
+            id: ExprId,
+            from: Ty,
+            to: Ty,
+        },
         FieldCountMismatch {
             id: ExprId,
             found: usize,
             expected: usize,
         },
+        PatFieldCountMismatch {
+            id: PatId,
+            found: usize,
+            expected: usize,
+        },
         MissingFields {
             id: ExprId,
             struct_ty: Ty,
@@ -1103,16 +2241,20 @@ mod diagnostics {
             id: ExprId,
             literal_ty: IntTy,
         },
+        StaticOutsideModule {
+            id: ExprId,
+            static_def: Static,
+        },
     }
 
     impl InferenceDiagnostic {
         pub(crate) fn add_to(
             &self,
             db: &dyn HirDatabase,
-            owner: Function,
+            owner: DefWithBody,
             sink: &mut DiagnosticSink,
         ) {
-            let file = owner.source(db.upcast()).file_id;
+            let file = owner.module(db.upcast()).file_id();
             let body = owner.body_source_map(db);
             match self {
                 InferenceDiagnostic::UnresolvedValue { id } => {
@@ -1137,6 +2279,27 @@ mod diagnostics {
                     let type_ref = body.type_ref_syntax(*id).expect("If this is not found, it must be a type ref generated by the library which should never be unresolved.");
                     sink.push(CyclicType { file, type_ref });
                 }
+                InferenceDiagnostic::TypeArgCountMismatch {
+                    id,
+                    expected,
+                    found,
+                } => {
+                    let type_ref = body.type_ref_syntax(*id).unwrap();
+                    sink.push(TypeArgCountMismatch {
+                        file,
+                        type_ref,
+                        expected: *expected,
+                        found: *found,
+                    });
+                }
+                InferenceDiagnostic::InvalidNullableType { id } => {
+                    let type_ref = body.type_ref_syntax(*id).unwrap();
+                    sink.push(InvalidNullableType { file, type_ref });
+                }
+                InferenceDiagnostic::InvalidArrayLength { id } => {
+                    let type_ref = body.type_ref_syntax(*id).unwrap();
+                    sink.push(InvalidArrayLength { file, type_ref });
+                }
                 InferenceDiagnostic::ParameterCountMismatch {
                     id,
                     expected,
@@ -1183,6 +2346,19 @@ mod diagnostics {
                         expected: expected.clone(),
                     });
                 }
+                InferenceDiagnostic::MismatchedPatType {
+                    id,
+                    found,
+                    expected,
+                } => {
+                    let expr = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(MismatchedType {
+                        file,
+                        expr,
+                        found: found.clone(),
+                        expected: expected.clone(),
+                    });
+                }
                 InferenceDiagnostic::IncompatibleBranches {
                     id,
                     then_ty,
@@ -1287,6 +2463,131 @@ mod diagnostics {
                         break_expr: id,
                     });
                 }
+                InferenceDiagnostic::ContinueOutsideLoop { id } => {
+                    let id = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(ContinueOutsideLoop {
+                        file,
+                        continue_expr: id,
+                    });
+                }
+                InferenceDiagnostic::UndeclaredLabel { id, label } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(UndeclaredLabel {
+                        file,
+                        expr,
+                        label: label.clone(),
+                    });
+                }
+                InferenceDiagnostic::ExpectedRange { id, found } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(ExpectedRange {
+                        file,
+                        expr,
+                        found: found.clone(),
+                    });
+                }
+                InferenceDiagnostic::NonIntegerRange { id, found } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(NonIntegerRange {
+                        file,
+                        expr,
+                        found: found.clone(),
+                    });
+                }
+                InferenceDiagnostic::RangeOutsideForLoop { id } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(RangeOutsideForLoop { file, expr });
+                }
+                InferenceDiagnostic::CannotIndex { id, found } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(CannotIndex {
+                        file,
+                        base_expr: expr,
+                        found: found.clone(),
+                    });
+                }
+                InferenceDiagnostic::CannotInferArrayType { id } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(CannotInferArrayType { file, expr });
+                }
+                InferenceDiagnostic::CannotInferNilType { id } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(CannotInferNilType { file, expr });
+                }
+                InferenceDiagnostic::CannotInferTypeArgs { id } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(CannotInferTypeArgs { file, expr });
+                }
+                InferenceDiagnostic::CannotInferParamType { id } => {
+                    let pat = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(CannotInferParamType { file, pat });
+                }
+                InferenceDiagnostic::TraitBoundNotSatisfied { id, trait_def, ty } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(TraitBoundNotSatisfied {
+                        file,
+                        expr,
+                        trait_name: trait_def.name(db.upcast()),
+                        ty_name: ty.display(db).to_string(),
+                    });
+                }
+                InferenceDiagnostic::MethodNotFound {
+                    id,
+                    receiver_ty,
+                    name,
+                } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(MethodNotFound {
+                        file,
+                        expr,
+                        receiver_ty: receiver_ty.clone(),
+                        name: name.clone(),
+                    })
+                }
                 InferenceDiagnostic::AccessUnknownField {
                     id,
                     receiver_ty,
@@ -1304,6 +2605,31 @@ mod diagnostics {
                         name: name.clone(),
                     })
                 }
+                InferenceDiagnostic::NullableFieldAccess { id, receiver_ty } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(NullableFieldAccess {
+                        file,
+                        expr,
+                        receiver_ty: receiver_ty.clone(),
+                    })
+                }
+                InferenceDiagnostic::InvalidCast { id, from, to } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(InvalidCast {
+                        file,
+                        expr,
+                        from_ty_name: from.display(db).to_string(),
+                        to_ty_name: to.display(db).to_string(),
+                    })
+                }
                 InferenceDiagnostic::FieldCountMismatch {
                     id,
                     expected,
@@ -1321,6 +2647,19 @@ mod diagnostics {
                         found: *found,
                     })
                 }
+                InferenceDiagnostic::PatFieldCountMismatch {
+                    id,
+                    expected,
+                    found,
+                } => {
+                    let expr = body.pat_syntax(*id).unwrap().value.syntax_node_ptr();
+                    sink.push(FieldCountMismatch {
+                        file,
+                        expr,
+                        expected: *expected,
+                        found: *found,
+                    })
+                }
                 InferenceDiagnostic::MissingFields {
                     id,
                     struct_ty,
@@ -1388,6 +2727,18 @@ mod diagnostics {
                         int_ty: *literal_ty,
                     })
                 }
+                InferenceDiagnostic::StaticOutsideModule { id, static_def } => {
+                    let expr = body
+                        .expr_syntax(*id)
+                        .unwrap()
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr());
+                    sink.push(StaticOutsideModule {
+                        file,
+                        expr,
+                        name: static_def.name(db.upcast()),
+                    })
+                }
             }
         }
     }
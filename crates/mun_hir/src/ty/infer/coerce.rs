@@ -24,6 +24,10 @@ impl<'a> InferenceResultBuilder<'a> {
     fn coerce_inner(&mut self, from_ty: Ty, to_ty: &Ty) -> bool {
         match (&from_ty, to_ty) {
             (ty_app!(TypeCtor::Never), ..) => return true,
+            // A reference to a `gc` struct can be used where a nullable reference is expected
+            (ty_app!(TypeCtor::Struct(_)), ty_app!(TypeCtor::Nullable, to_params)) => {
+                return self.unify(&from_ty, &to_params[0]);
+            }
             _ => {
                 if self.type_variables.unify_inner_trivial(&from_ty, &to_ty) {
                     return true;
@@ -1,14 +1,17 @@
-use crate::{ty::infer::InferenceResultBuilder, Expr, ExprId, Path, Resolution, Resolver};
+use crate::{
+    ty::infer::InferenceResultBuilder, Expr, ExprId, ModuleDef, Path, Resolution, Resolver,
+};
 use std::sync::Arc;
 
 impl<'a> InferenceResultBuilder<'a> {
     /// Checks if the specified expression is a place-expression. A place expression represents a
-    /// memory location.
+    /// memory location. Whether that memory location can be mutated is verified by the
+    /// `ExprValidator`.
     pub(super) fn check_place_expression(&mut self, resolver: &Resolver, expr: ExprId) -> bool {
         let body = Arc::clone(&self.body); // avoid borrow checker problem
         match &body[expr] {
             Expr::Path(p) => self.check_place_path(resolver, p),
-            Expr::Field { .. } => true,
+            Expr::Field { .. } | Expr::Index { .. } => true,
             _ => false,
         }
     }
@@ -24,8 +27,8 @@ impl<'a> InferenceResultBuilder<'a> {
         };
 
         match resolution {
-            Resolution::LocalBinding(_) => true,
-            Resolution::Def(_) => false,
+            Resolution::LocalBinding(_) | Resolution::Def(ModuleDef::Static(_)) => true,
+            Resolution::Def(_) | Resolution::GenericParam(_) => false,
         }
     }
 }
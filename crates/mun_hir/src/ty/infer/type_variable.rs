@@ -165,6 +165,16 @@ impl TypeVariableTable {
                 true
             }
 
+            // Unify the type parameters of two applications of the same type constructor
+            (Ty::Apply(a_ty), Ty::Apply(b_ty))
+                if a_ty.ctor == b_ty.ctor && a_ty.parameters.len() == b_ty.parameters.len() =>
+            {
+                a_ty.parameters
+                    .iter()
+                    .zip(b_ty.parameters.iter())
+                    .all(|(a, b)| self.unify_inner(a, b))
+            }
+
             // Was not able to unify the types
             _ => false,
         }
@@ -3,11 +3,15 @@ use crate::adt::StructKind;
 use crate::arena::map::ArenaMap;
 use crate::builtin_type::BuiltinType;
 use crate::diagnostics::DiagnosticSink;
+use crate::name::name;
 use crate::name_resolution::Namespace;
 use crate::resolve::{Resolution, Resolver};
-use crate::ty::{FnSig, Ty, TypeCtor};
-use crate::type_ref::{LocalTypeRefId, TypeRef, TypeRefMap, TypeRefSourceMap};
-use crate::{FileId, Function, HirDatabase, ModuleDef, Path, Struct, TypeAlias};
+use crate::ty::{ApplicationTy, FnSig, Substs, Ty, TypeCtor};
+use crate::type_ref::{ArrayLen, LocalTypeRefId, TypeRef, TypeRefMap, TypeRefSourceMap};
+use crate::{
+    ty_app, Const, ConstValue, Enum, EnumVariant, FileId, Function, HirDatabase, Impl, ModuleDef,
+    Path, Static, Struct, StructMemoryKind, TypeAlias,
+};
 use std::ops::Index;
 use std::sync::Arc;
 
@@ -65,34 +69,183 @@ impl Ty {
         diagnostics: &mut Vec<LowerDiagnostic>,
         type_ref: LocalTypeRefId,
     ) -> Ty {
-        let res = match &type_ref_map[type_ref] {
-            TypeRef::Path(path) => Ty::from_hir_path(db, resolver, path),
+        Ty::from_type_ref(db, resolver, diagnostics, type_ref, &type_ref_map[type_ref])
+    }
+
+    /// Lowers the `TypeRef` with the specified `id`. Nested type references, like the element
+    /// type of an array, report their diagnostics on the outermost `TypeRef`.
+    fn from_type_ref(
+        db: &dyn HirDatabase,
+        resolver: &Resolver,
+        diagnostics: &mut Vec<LowerDiagnostic>,
+        id: LocalTypeRefId,
+        type_ref: &TypeRef,
+    ) -> Ty {
+        let res = match type_ref {
+            TypeRef::Path(path) => {
+                Ty::from_hir_path_with_type_args(db, resolver, diagnostics, id, path)
+            }
+            TypeRef::Array(element_type_ref, len) => {
+                let element_ty = Ty::from_type_ref(db, resolver, diagnostics, id, element_type_ref);
+                let ty = match len {
+                    Some(ArrayLen::Literal(len)) => Ty::fixed_array(element_ty, *len),
+                    Some(ArrayLen::Path(path)) => {
+                        match Ty::resolve_array_len(db, resolver, diagnostics, id, path) {
+                            Some(len) => Ty::fixed_array(element_ty, len),
+                            None => Ty::Unknown,
+                        }
+                    }
+                    None => Ty::array(element_ty),
+                };
+                Some((ty, false))
+            }
+            TypeRef::Fn(param_type_refs, ret_type_ref) => {
+                let params = param_type_refs
+                    .iter()
+                    .map(|type_ref| Ty::from_type_ref(db, resolver, diagnostics, id, type_ref))
+                    .collect();
+                let ret = Ty::from_type_ref(db, resolver, diagnostics, id, ret_type_ref);
+                let sig = FnSig::from_params_and_return(params, ret);
+                Some((Ty::fn_ptr(sig), false))
+            }
+            TypeRef::Tuple(field_type_refs) => {
+                let fields = field_type_refs
+                    .iter()
+                    .map(|type_ref| Ty::from_type_ref(db, resolver, diagnostics, id, type_ref))
+                    .collect();
+                Some((Ty::tuple(fields), false))
+            }
+            TypeRef::Nullable(inner_type_ref) => {
+                // Only references to `gc` structs can be `nil`
+                let inner_ty = Ty::from_type_ref(db, resolver, diagnostics, id, inner_type_ref);
+                let is_gc_struct = inner_ty.as_struct().map_or(false, |s| {
+                    s.data(db.upcast()).memory_kind == StructMemoryKind::GC
+                });
+                if !is_gc_struct && inner_ty != Ty::Unknown {
+                    diagnostics.push(LowerDiagnostic::InvalidNullableType { id });
+                }
+                Some((Ty::nullable(inner_ty), false))
+            }
             TypeRef::Error => Some((Ty::Unknown, false)),
             TypeRef::Empty => Some((Ty::Empty, false)),
             TypeRef::Never => Some((Ty::simple(TypeCtor::Never), false)),
         };
         if let Some((ty, is_cyclic)) = res {
             if is_cyclic {
-                diagnostics.push(LowerDiagnostic::CyclicType { id: type_ref })
+                diagnostics.push(LowerDiagnostic::CyclicType { id })
             }
             ty
         } else {
-            diagnostics.push(LowerDiagnostic::UnresolvedType { id: type_ref });
+            diagnostics.push(LowerDiagnostic::UnresolvedType { id });
             Ty::Unknown
         }
     }
 
+    /// Resolves the length of a fixed-size array that refers to a constant, e.g. `[f32; N]`. The
+    /// constant must be a non-negative integer.
+    fn resolve_array_len(
+        db: &dyn HirDatabase,
+        resolver: &Resolver,
+        diagnostics: &mut Vec<LowerDiagnostic>,
+        id: LocalTypeRefId,
+        path: &Path,
+    ) -> Option<u64> {
+        let value = match resolver
+            .resolve_path_without_assoc_items(db, path)
+            .take_values()
+        {
+            Some(Resolution::Def(ModuleDef::Const(c))) => c.value(db),
+            _ => {
+                diagnostics.push(LowerDiagnostic::InvalidArrayLength { id });
+                return None;
+            }
+        };
+        match value {
+            Ok(ConstValue::Int(len)) if len >= 0 && len <= u64::MAX as i128 => Some(len as u64),
+            // Errors in the initializer of the constant are reported there
+            Err(_) => None,
+            Ok(_) => {
+                diagnostics.push(LowerDiagnostic::InvalidArrayLength { id });
+                None
+            }
+        }
+    }
+
+    /// Lowers a path in a type position and applies the type arguments of its last segment, e.g.
+    /// `Foo<i32>`. A diagnostic is emitted if the number of type arguments does not match the
+    /// number of generic parameters of the type.
+    fn from_hir_path_with_type_args(
+        db: &dyn HirDatabase,
+        resolver: &Resolver,
+        diagnostics: &mut Vec<LowerDiagnostic>,
+        id: LocalTypeRefId,
+        path: &Path,
+    ) -> Option<(Self, bool)> {
+        let (ty, is_cyclic) = Ty::from_hir_path(db, resolver, path)?;
+        let type_args = path
+            .segments
+            .last()
+            .and_then(|segment| segment.type_args.as_deref())
+            .unwrap_or(&[]);
+
+        // Only a generic struct that is referred to by its name still has to be instantiated.
+        // Other types, like a type alias, are already complete.
+        let num_params = match &ty {
+            ty_app!(TypeCtor::Struct(s), parameters)
+                if *parameters == s.generic_params(db.upcast()).identity_substs() =>
+            {
+                parameters.len()
+            }
+            _ => 0,
+        };
+
+        if type_args.len() != num_params {
+            diagnostics.push(LowerDiagnostic::TypeArgCountMismatch {
+                id,
+                expected: num_params,
+                found: type_args.len(),
+            });
+        }
+
+        if num_params == 0 {
+            return Some((ty, is_cyclic));
+        }
+
+        // Missing type arguments are unknown, an error has already been reported for them
+        let substs: Substs = (0..num_params)
+            .map(|idx| match type_args.get(idx) {
+                Some(type_arg) => Ty::from_type_ref(db, resolver, diagnostics, id, type_arg),
+                None => Ty::Unknown,
+            })
+            .collect();
+        Some((ty.subst(&substs), is_cyclic))
+    }
+
+    /// Resolves the type that `path` refers to. The type arguments of the path are ignored; a
+    /// generic struct is returned with its own generic parameters as type arguments.
     pub(crate) fn from_hir_path(
         db: &dyn HirDatabase,
         resolver: &Resolver,
         path: &Path,
     ) -> Option<(Self, bool)> {
+        // Within an `impl` block, `Self` refers to the type of the `impl` block. If that type could
+        // not be resolved an error has already been reported for it.
+        if path.as_ident() == Some(&name![Self]) {
+            if let Some(impl_def) = resolver.impl_block() {
+                return Some((impl_def.self_ty(db), false));
+            }
+        }
+
         let resolution = resolver
             .resolve_path_without_assoc_items(db, path)
             .take_types();
 
         let def = match resolution {
             Some(Resolution::Def(def)) => def,
+            Some(Resolution::GenericParam(idx)) => {
+                let name = path.as_ident()?.clone();
+                return Some((Ty::Param { idx, name }, false));
+            }
             Some(Resolution::LocalBinding(..)) => {
                 // this should never happen
                 panic!("path resolved to local binding in type ns");
@@ -131,17 +284,31 @@ pub fn lower_struct_query(db: &dyn HirDatabase, s: Struct) -> Arc<LowerBatchResu
     types_from_hir(db, &s.resolver(db), data.type_ref_map())
 }
 
+pub fn lower_enum_query(db: &dyn HirDatabase, e: Enum) -> Arc<LowerBatchResult> {
+    let data = e.data(db.upcast());
+    types_from_hir(db, &e.resolver(db), data.type_ref_map())
+}
+
 pub fn lower_type_alias_query(db: &dyn HirDatabase, t: TypeAlias) -> Arc<LowerBatchResult> {
     let data = t.data(db.upcast());
     types_from_hir(db, &t.resolver(db), data.type_ref_map())
 }
 
+pub fn lower_impl_query(db: &dyn HirDatabase, i: Impl) -> Arc<LowerBatchResult> {
+    let data = i.data(db.upcast());
+    types_from_hir(db, &i.resolver(db), data.type_ref_map())
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TypableDef {
     Function(Function),
     BuiltinType(BuiltinType),
     Struct(Struct),
+    Enum(Enum),
+    EnumVariant(EnumVariant),
     TypeAlias(TypeAlias),
+    Const(Const),
+    Static(Static),
 }
 
 impl From<Function> for TypableDef {
@@ -162,13 +329,42 @@ impl From<Struct> for TypableDef {
     }
 }
 
+impl From<Enum> for TypableDef {
+    fn from(f: Enum) -> Self {
+        TypableDef::Enum(f)
+    }
+}
+
+impl From<EnumVariant> for TypableDef {
+    fn from(f: EnumVariant) -> Self {
+        TypableDef::EnumVariant(f)
+    }
+}
+
+impl From<Const> for TypableDef {
+    fn from(f: Const) -> Self {
+        TypableDef::Const(f)
+    }
+}
+
+impl From<Static> for TypableDef {
+    fn from(f: Static) -> Self {
+        TypableDef::Static(f)
+    }
+}
+
 impl From<ModuleDef> for Option<TypableDef> {
     fn from(d: ModuleDef) -> Self {
         match d {
             ModuleDef::Function(f) => Some(TypableDef::Function(f)),
             ModuleDef::BuiltinType(t) => Some(TypableDef::BuiltinType(t)),
             ModuleDef::Struct(t) => Some(TypableDef::Struct(t)),
+            ModuleDef::Enum(t) => Some(TypableDef::Enum(t)),
+            ModuleDef::EnumVariant(t) => Some(TypableDef::EnumVariant(t)),
             ModuleDef::TypeAlias(t) => Some(TypableDef::TypeAlias(t)),
+            ModuleDef::Const(c) => Some(TypableDef::Const(c)),
+            ModuleDef::Static(s) => Some(TypableDef::Static(s)),
+            ModuleDef::Trait(_) => None,
         }
     }
 }
@@ -177,8 +373,9 @@ impl From<ModuleDef> for Option<TypableDef> {
 pub enum CallableDef {
     Function(Function),
     Struct(Struct),
+    EnumVariant(EnumVariant),
 }
-impl_froms!(CallableDef: Function, Struct);
+impl_froms!(CallableDef: Function, Struct, EnumVariant);
 
 impl CallableDef {
     pub fn is_function(self) -> bool {
@@ -188,6 +385,10 @@ impl CallableDef {
     pub fn is_struct(self) -> bool {
         matches!(self, CallableDef::Struct(_))
     }
+
+    pub fn is_enum_variant(self) -> bool {
+        matches!(self, CallableDef::EnumVariant(_))
+    }
 }
 
 /// Build the declared type of an item. This depends on the namespace; e.g. for
@@ -200,12 +401,20 @@ pub(crate) fn type_for_def(db: &dyn HirDatabase, def: TypableDef, ns: Namespace)
         (TypableDef::BuiltinType(t), Namespace::Types) => type_for_builtin(t),
         (TypableDef::Struct(s), Namespace::Values) => type_for_struct_constructor(db, s),
         (TypableDef::Struct(s), Namespace::Types) => type_for_struct(db, s),
+        (TypableDef::Enum(e), Namespace::Types) => type_for_enum(db, e),
+        (TypableDef::EnumVariant(v), Namespace::Values) => type_for_enum_variant_constructor(db, v),
         (TypableDef::TypeAlias(t), Namespace::Types) => type_for_type_alias(db, t),
+        (TypableDef::Const(c), Namespace::Values) => type_for_const(db, c),
+        (TypableDef::Static(s), Namespace::Values) => type_for_static(db, s),
 
         // 'error' cases:
         (TypableDef::Function(_), Namespace::Types) => Ty::Unknown,
         (TypableDef::BuiltinType(_), Namespace::Values) => Ty::Unknown,
+        (TypableDef::Enum(_), Namespace::Values) => Ty::Unknown,
+        (TypableDef::EnumVariant(_), Namespace::Types) => Ty::Unknown,
         (TypableDef::TypeAlias(_), Namespace::Values) => Ty::Unknown,
+        (TypableDef::Const(_), Namespace::Types) => Ty::Unknown,
+        (TypableDef::Static(_), Namespace::Types) => Ty::Unknown,
     };
     (ty, false)
 }
@@ -226,19 +435,24 @@ fn type_for_builtin(def: BuiltinType) -> Ty {
         BuiltinType::Float(f) => TypeCtor::Float(f.into()),
         BuiltinType::Int(i) => TypeCtor::Int(i.into()),
         BuiltinType::Bool => TypeCtor::Bool,
+        BuiltinType::String => TypeCtor::String,
     })
 }
 
 /// Build the declared type of a function. This should not need to look at the
-/// function body.
-fn type_for_fn(_db: &dyn HirDatabase, def: Function) -> Ty {
-    Ty::simple(TypeCtor::FnDef(def.into()))
+/// function body. The type arguments of a generic function are its own generic parameters.
+fn type_for_fn(db: &dyn HirDatabase, def: Function) -> Ty {
+    Ty::Apply(ApplicationTy {
+        ctor: TypeCtor::FnDef(def.into()),
+        parameters: def.generic_params(db).identity_substs(),
+    })
 }
 
 pub(crate) fn callable_item_sig(db: &dyn HirDatabase, def: CallableDef) -> FnSig {
     match def {
         CallableDef::Function(f) => fn_sig_for_fn(db, f),
         CallableDef::Struct(s) => fn_sig_for_struct_constructor(db, s),
+        CallableDef::EnumVariant(v) => fn_sig_for_enum_variant_constructor(db, v),
     }
 }
 
@@ -270,14 +484,42 @@ pub(crate) fn fn_sig_for_struct_constructor(db: &dyn HirDatabase, def: Struct) -
 fn type_for_struct_constructor(db: &dyn HirDatabase, def: Struct) -> Ty {
     let struct_data = db.struct_data(def.id);
     if struct_data.kind == StructKind::Tuple {
-        Ty::simple(TypeCtor::FnDef(def.into()))
+        Ty::Apply(ApplicationTy {
+            ctor: TypeCtor::FnDef(def.into()),
+            parameters: struct_data.generic_params.identity_substs(),
+        })
     } else {
         type_for_struct(db, def)
     }
 }
 
-fn type_for_struct(_db: &dyn HirDatabase, def: Struct) -> Ty {
-    Ty::simple(TypeCtor::Struct(def))
+/// Build the type of a struct. The type arguments of a generic struct are its own generic
+/// parameters.
+fn type_for_struct(db: &dyn HirDatabase, def: Struct) -> Ty {
+    Ty::Apply(ApplicationTy {
+        ctor: TypeCtor::Struct(def),
+        parameters: def.generic_params(db.upcast()).identity_substs(),
+    })
+}
+
+pub(crate) fn fn_sig_for_enum_variant_constructor(db: &dyn HirDatabase, def: EnumVariant) -> FnSig {
+    let params = def.field_types(db);
+    let ret = type_for_enum(db, def.parent_enum());
+    FnSig::from_params_and_return(params, ret)
+}
+
+/// Build the type of an enum variant constructor. Unit variants are values of the enum type
+/// itself, tuple variants are functions that return the enum type.
+fn type_for_enum_variant_constructor(db: &dyn HirDatabase, def: EnumVariant) -> Ty {
+    if def.kind(db) == StructKind::Tuple {
+        Ty::simple(TypeCtor::FnDef(def.into()))
+    } else {
+        type_for_enum(db, def.parent_enum())
+    }
+}
+
+fn type_for_enum(_db: &dyn HirDatabase, def: Enum) -> Ty {
+    Ty::simple(TypeCtor::Enum(def))
 }
 
 fn type_for_type_alias(db: &dyn HirDatabase, def: TypeAlias) -> Ty {
@@ -287,8 +529,24 @@ fn type_for_type_alias(db: &dyn HirDatabase, def: TypeAlias) -> Ty {
     Ty::from_hir(db, &resolver, data.type_ref_map(), type_ref).ty
 }
 
+/// Build the declared type of a constant.
+fn type_for_const(db: &dyn HirDatabase, def: Const) -> Ty {
+    let data = def.data(db.upcast());
+    let resolver = def.resolver(db);
+    Ty::from_hir(db, &resolver, data.type_ref_map(), data.type_ref).ty
+}
+
+/// Build the declared type of a static.
+fn type_for_static(db: &dyn HirDatabase, def: Static) -> Ty {
+    let data = def.data(db.upcast());
+    let resolver = def.resolver(db);
+    Ty::from_hir(db, &resolver, data.type_ref_map(), data.type_ref).ty
+}
+
 pub mod diagnostics {
-    use crate::diagnostics::{CyclicType, UnresolvedType};
+    use crate::diagnostics::{
+        CyclicType, InvalidArrayLength, InvalidNullableType, TypeArgCountMismatch, UnresolvedType,
+    };
     use crate::{
         diagnostics::DiagnosticSink,
         type_ref::{LocalTypeRefId, TypeRefSourceMap},
@@ -297,8 +555,23 @@ pub mod diagnostics {
 
     #[derive(Debug, PartialEq, Eq, Clone)]
     pub(crate) enum LowerDiagnostic {
-        UnresolvedType { id: LocalTypeRefId },
-        CyclicType { id: LocalTypeRefId },
+        UnresolvedType {
+            id: LocalTypeRefId,
+        },
+        CyclicType {
+            id: LocalTypeRefId,
+        },
+        TypeArgCountMismatch {
+            id: LocalTypeRefId,
+            expected: usize,
+            found: usize,
+        },
+        InvalidNullableType {
+            id: LocalTypeRefId,
+        },
+        InvalidArrayLength {
+            id: LocalTypeRefId,
+        },
     }
 
     impl LowerDiagnostic {
@@ -318,6 +591,24 @@ pub mod diagnostics {
                     file: file_id,
                     type_ref: source_map.type_ref_syntax(*id).unwrap(),
                 }),
+                LowerDiagnostic::TypeArgCountMismatch {
+                    id,
+                    expected,
+                    found,
+                } => sink.push(TypeArgCountMismatch {
+                    file: file_id,
+                    type_ref: source_map.type_ref_syntax(*id).unwrap(),
+                    expected: *expected,
+                    found: *found,
+                }),
+                LowerDiagnostic::InvalidNullableType { id } => sink.push(InvalidNullableType {
+                    file: file_id,
+                    type_ref: source_map.type_ref_syntax(*id).unwrap(),
+                }),
+                LowerDiagnostic::InvalidArrayLength { id } => sink.push(InvalidArrayLength {
+                    file: file_id,
+                    type_ref: source_map.type_ref_syntax(*id).unwrap(),
+                }),
             }
         }
     }
@@ -0,0 +1,396 @@
+//! This module is concerned with finding the functions that are defined in the inherent `impl`
+//! blocks of a type and in the `impl` blocks of the traits it implements. Associated functions are
+//! resolved through paths (e.g. `Foo::new`) and methods through method calls (e.g. `foo.bar()`).
+
+use crate::code_model::src::HasSource;
+use crate::diagnostics::{
+    ConflictingTraitImpl, DiagnosticSink, DuplicateDefinition, GenericSelfTyImpl,
+    InvalidSelfTyImpl, MissingTraitItems, TraitItemNotMember, TraitItemSignatureMismatch,
+    UnresolvedTrait,
+};
+use crate::ty::TypeCtor;
+use crate::{
+    ty_app, FileId, Function, HirDatabase, HirDisplay, Impl, Module, Name, Substs, Trait, Ty,
+};
+use mun_syntax::{AstNode, SyntaxNodePtr};
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+
+/// The inherent `impl` blocks of a module, indexed by the type they are defined for.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct InherentImpls {
+    map: FxHashMap<TypeCtor, Vec<Impl>>,
+    diagnostics: Vec<InherentImplsDiagnostic>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum InherentImplsDiagnostic {
+    /// The `impl` block is defined for a type that is not a struct or enum of the same module.
+    InvalidSelfTy(Impl),
+    /// The `impl` block is defined for a generic struct.
+    GenericSelfTy(Impl),
+    /// An associated function with the same name is already defined for the type.
+    DuplicateDefinition {
+        name: Name,
+        definition: Function,
+        first_definition: Function,
+    },
+}
+
+impl InherentImpls {
+    pub(crate) fn inherent_impls_query(db: &dyn HirDatabase, file_id: FileId) -> Arc<Self> {
+        let mut impls = InherentImpls::default();
+        let mut functions_by_name: FxHashMap<(TypeCtor, Name), Function> = FxHashMap::default();
+
+        for impl_def in Module::from(file_id).impls(db) {
+            if impl_def.is_trait_impl(db.upcast()) {
+                continue;
+            }
+
+            let self_ty = impl_def.self_ty(db);
+            let ctor = match self_ty {
+                ty_app!(TypeCtor::Struct(s)) if s.is_generic(db.upcast()) => {
+                    impls
+                        .diagnostics
+                        .push(InherentImplsDiagnostic::GenericSelfTy(impl_def));
+                    continue;
+                }
+                ty_app!(TypeCtor::Struct(s)) if s.module(db.upcast()).file_id() == file_id => {
+                    TypeCtor::Struct(s)
+                }
+                ty_app!(TypeCtor::Enum(e)) if e.module(db.upcast()).file_id() == file_id => {
+                    TypeCtor::Enum(e)
+                }
+                // An error has already been reported for the self type
+                Ty::Unknown => continue,
+                _ => {
+                    impls
+                        .diagnostics
+                        .push(InherentImplsDiagnostic::InvalidSelfTy(impl_def));
+                    continue;
+                }
+            };
+
+            for function in impl_def.items(db) {
+                let name = function.name(db);
+                if let Some(first_definition) = functions_by_name.get(&(ctor, name.clone())) {
+                    impls
+                        .diagnostics
+                        .push(InherentImplsDiagnostic::DuplicateDefinition {
+                            name,
+                            definition: function,
+                            first_definition: *first_definition,
+                        });
+                } else {
+                    functions_by_name.insert((ctor, name), function);
+                }
+            }
+
+            impls.map.entry(ctor).or_default().push(impl_def);
+        }
+
+        Arc::new(impls)
+    }
+
+    /// Returns the inherent `impl` blocks defined for the specified type.
+    pub fn for_self_ty(&self, ty: &Ty) -> &[Impl] {
+        match ty {
+            Ty::Apply(a_ty) => self
+                .map
+                .get(&a_ty.ctor)
+                .map(|impls| impls.as_slice())
+                .unwrap_or(&[]),
+            _ => &[],
+        }
+    }
+
+    /// Adds all the `InherentImplsDiagnostic`s to the `DiagnosticSink`.
+    pub(crate) fn add_diagnostics(
+        &self,
+        db: &dyn HirDatabase,
+        file_id: FileId,
+        sink: &mut DiagnosticSink,
+    ) {
+        for diagnostic in self.diagnostics.iter() {
+            match diagnostic {
+                InherentImplsDiagnostic::InvalidSelfTy(impl_def) => sink.push(InvalidSelfTyImpl {
+                    impl_def: impl_def
+                        .source(db.upcast())
+                        .map(|src| SyntaxNodePtr::new(src.syntax())),
+                }),
+                InherentImplsDiagnostic::GenericSelfTy(impl_def) => sink.push(GenericSelfTyImpl {
+                    impl_def: impl_def
+                        .source(db.upcast())
+                        .map(|src| SyntaxNodePtr::new(src.syntax())),
+                }),
+                InherentImplsDiagnostic::DuplicateDefinition {
+                    name,
+                    definition,
+                    first_definition,
+                } => sink.push(DuplicateDefinition {
+                    file: file_id,
+                    name: name.to_string(),
+                    definition: SyntaxNodePtr::new(definition.source(db.upcast()).value.syntax()),
+                    first_definition: SyntaxNodePtr::new(
+                        first_definition.source(db.upcast()).value.syntax(),
+                    ),
+                }),
+            }
+        }
+    }
+}
+
+/// The `impl` blocks of traits in a module, together with the type they implement the trait for.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TraitImpls {
+    impls: Vec<(Trait, Ty, Impl)>,
+    diagnostics: Vec<TraitImplsDiagnostic>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum TraitImplsDiagnostic {
+    /// The trait of the `impl` block could not be resolved.
+    UnresolvedTrait(Impl),
+    /// The trait is already implemented for the type.
+    ConflictingImpl {
+        impl_def: Impl,
+        trait_def: Trait,
+        ty: Ty,
+    },
+    /// Not all the functions of the trait without a default implementation are implemented.
+    MissingItems { impl_def: Impl, names: Vec<Name> },
+    /// The function is not declared in the trait.
+    NotAMember {
+        function: Function,
+        trait_def: Trait,
+    },
+    /// The signature of the function differs from the declaration in the trait.
+    SignatureMismatch {
+        function: Function,
+        trait_def: Trait,
+    },
+}
+
+impl TraitImpls {
+    pub(crate) fn trait_impls_query(db: &dyn HirDatabase, file_id: FileId) -> Arc<Self> {
+        let mut impls = TraitImpls::default();
+
+        for impl_def in Module::from(file_id).impls(db) {
+            if !impl_def.is_trait_impl(db.upcast()) {
+                continue;
+            }
+
+            let trait_def = match impl_def.target_trait(db) {
+                Some(trait_def) => trait_def,
+                None => {
+                    impls
+                        .diagnostics
+                        .push(TraitImplsDiagnostic::UnresolvedTrait(impl_def));
+                    continue;
+                }
+            };
+
+            // An error has already been reported for the self type
+            let self_ty = impl_def.self_ty(db);
+            if self_ty == Ty::Unknown {
+                continue;
+            }
+
+            if impls.find_impl(trait_def, &self_ty).is_some() {
+                impls
+                    .diagnostics
+                    .push(TraitImplsDiagnostic::ConflictingImpl {
+                        impl_def,
+                        trait_def,
+                        ty: self_ty,
+                    });
+                continue;
+            }
+
+            let items = impl_def.items(db);
+            for function in items.iter() {
+                let diagnostic = match trait_def.function(db, &function.name(db)) {
+                    None => TraitImplsDiagnostic::NotAMember {
+                        function: *function,
+                        trait_def,
+                    },
+                    Some(declaration)
+                        if !signature_matches(db, declaration, *function, &self_ty) =>
+                    {
+                        TraitImplsDiagnostic::SignatureMismatch {
+                            function: *function,
+                            trait_def,
+                        }
+                    }
+                    Some(_) => continue,
+                };
+                impls.diagnostics.push(diagnostic);
+            }
+
+            let missing: Vec<Name> = trait_def
+                .items(db)
+                .into_iter()
+                .filter(|declaration| !declaration.data(db).has_body())
+                .map(|declaration| declaration.name(db))
+                .filter(|name| !items.iter().any(|function| function.name(db) == *name))
+                .collect();
+            if !missing.is_empty() {
+                impls.diagnostics.push(TraitImplsDiagnostic::MissingItems {
+                    impl_def,
+                    names: missing,
+                });
+            }
+
+            impls.impls.push((trait_def, self_ty, impl_def));
+        }
+
+        Arc::new(impls)
+    }
+
+    /// Returns the `impl` block that implements the trait `trait_def` for the type `ty`, if any.
+    pub fn find_impl(&self, trait_def: Trait, ty: &Ty) -> Option<Impl> {
+        self.impls
+            .iter()
+            .find(|(t, self_ty, _)| *t == trait_def && self_ty == ty)
+            .map(|(_, _, impl_def)| *impl_def)
+    }
+
+    /// Returns the traits that are implemented for the type `ty`, together with their `impl`
+    /// blocks.
+    pub fn for_self_ty<'a>(&'a self, ty: &'a Ty) -> impl Iterator<Item = (Trait, Impl)> + 'a {
+        self.impls
+            .iter()
+            .filter(move |(_, self_ty, _)| self_ty == ty)
+            .map(|(trait_def, _, impl_def)| (*trait_def, *impl_def))
+    }
+
+    /// Adds all the `TraitImplsDiagnostic`s to the `DiagnosticSink`.
+    pub(crate) fn add_diagnostics(
+        &self,
+        db: &dyn HirDatabase,
+        file_id: FileId,
+        sink: &mut DiagnosticSink,
+    ) {
+        for diagnostic in self.diagnostics.iter() {
+            match diagnostic {
+                TraitImplsDiagnostic::UnresolvedTrait(impl_def) => {
+                    let src = impl_def.source(db.upcast()).value;
+                    if let Some(target_trait) = src.target_trait() {
+                        sink.push(UnresolvedTrait {
+                            file: file_id,
+                            type_ref: SyntaxNodePtr::new(target_trait.syntax()),
+                            name: target_trait.syntax().text().to_string(),
+                        });
+                    }
+                }
+                TraitImplsDiagnostic::ConflictingImpl {
+                    impl_def,
+                    trait_def,
+                    ty,
+                } => sink.push(ConflictingTraitImpl {
+                    impl_def: impl_def
+                        .source(db.upcast())
+                        .map(|src| SyntaxNodePtr::new(src.syntax())),
+                    trait_name: trait_def.name(db.upcast()),
+                    ty_name: ty.display(db).to_string(),
+                }),
+                TraitImplsDiagnostic::MissingItems { impl_def, names } => {
+                    sink.push(MissingTraitItems {
+                        impl_def: impl_def
+                            .source(db.upcast())
+                            .map(|src| SyntaxNodePtr::new(src.syntax())),
+                        names: names.clone(),
+                    })
+                }
+                TraitImplsDiagnostic::NotAMember {
+                    function,
+                    trait_def,
+                } => sink.push(TraitItemNotMember {
+                    file: file_id,
+                    function: SyntaxNodePtr::new(function.source(db.upcast()).value.syntax()),
+                    name: function.name(db),
+                    trait_name: trait_def.name(db.upcast()),
+                }),
+                TraitImplsDiagnostic::SignatureMismatch {
+                    function,
+                    trait_def,
+                } => sink.push(TraitItemSignatureMismatch {
+                    file: file_id,
+                    function: SyntaxNodePtr::new(function.source(db.upcast()).value.syntax()),
+                    name: function.name(db),
+                    trait_name: trait_def.name(db.upcast()),
+                }),
+            }
+        }
+    }
+}
+
+/// Returns true if the signature of `function`, declared in an `impl` block of a trait for
+/// `self_ty`, matches the signature of its `declaration` in the trait.
+fn signature_matches(
+    db: &dyn HirDatabase,
+    declaration: Function,
+    function: Function,
+    self_ty: &Ty,
+) -> bool {
+    let declaration_data = declaration.data(db);
+    let function_data = function.data(db);
+    if declaration_data.has_self_param() != function_data.has_self_param()
+        || declaration_data.generic_params().len() != function_data.generic_params().len() + 1
+    {
+        return false;
+    }
+
+    // The `Self` parameter of the declaration is replaced by the type of the `impl` block, the
+    // other parameters by the generic parameters of the function.
+    let substs: Substs = std::iter::once(self_ty.clone())
+        .chain(
+            function_data
+                .generic_params()
+                .identity_substs()
+                .iter()
+                .cloned(),
+        )
+        .collect();
+    db.callable_sig(declaration.into()).subst(&substs) == db.callable_sig(function.into())
+}
+
+/// Looks up the function with the specified `name` in the inherent `impl` blocks of `ty` and in
+/// the `impl` blocks of the traits that `ty` implements.
+pub(crate) fn lookup_associated_function(
+    db: &dyn HirDatabase,
+    ty: &Ty,
+    name: &Name,
+) -> Option<Function> {
+    let module = match ty {
+        ty_app!(TypeCtor::Struct(s)) => s.module(db.upcast()),
+        ty_app!(TypeCtor::Enum(e)) => e.module(db.upcast()),
+        _ => return None,
+    };
+
+    db.inherent_impls(module.file_id())
+        .for_self_ty(ty)
+        .iter()
+        .flat_map(|impl_def| impl_def.items(db))
+        .find(|function| function.name(db) == *name)
+        .or_else(|| {
+            db.trait_impls(module.file_id())
+                .for_self_ty(ty)
+                .flat_map(|(_, impl_def)| impl_def.items(db))
+                .find(|function| function.name(db) == *name)
+        })
+}
+
+/// Looks up the function with the specified `name` in the traits that are implemented for `ty` in
+/// the module `file_id`. The declaration of the function in the trait is returned, the function
+/// that is called is only known once the `Self` type argument is known.
+pub(crate) fn lookup_trait_method(
+    db: &dyn HirDatabase,
+    file_id: FileId,
+    ty: &Ty,
+    name: &Name,
+) -> Option<Function> {
+    db.trait_impls(file_id)
+        .for_self_ty(ty)
+        .find_map(|(trait_def, _)| trait_def.function(db, name))
+}
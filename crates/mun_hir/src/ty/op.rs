@@ -1,16 +1,53 @@
 use crate::ty::infer::InferTy;
-use crate::{ApplicationTy, ArithOp, BinaryOp, Ty, TypeCtor};
+use crate::{
+    name::name, ApplicationTy, ArithOp, BinaryOp, CmpOp, HirDatabase, Name, Ordering, Signedness,
+    Struct, Ty, TypeCtor, UnaryOp,
+};
+
+/// Given a unary operation and the type of its operand, returns the type of the result of the
+/// operation or `Ty::Unknown` if such an operation is invalid.
+pub(super) fn unary_op_return_ty(op: UnaryOp, ty: Ty) -> Ty {
+    match op {
+        // `!` is the logical negation of a boolean and the bitwise NOT of an integer
+        UnaryOp::Not => match ty {
+            Ty::Apply(ApplicationTy { ctor, .. }) => match ctor {
+                TypeCtor::Bool | TypeCtor::Int(_) => ty,
+                _ => Ty::Unknown,
+            },
+            Ty::Infer(InferTy::IntVar(..)) => ty,
+            _ => Ty::Unknown,
+        },
+
+        // Only signed numbers can be negated
+        UnaryOp::Neg => match ty {
+            Ty::Apply(ApplicationTy { ctor, .. }) => match ctor {
+                TypeCtor::Float(_) => ty,
+                TypeCtor::Int(int_ty) if int_ty.signedness == Signedness::Signed => ty,
+                _ => Ty::Unknown,
+            },
+            Ty::Infer(InferTy::IntVar(..)) | Ty::Infer(InferTy::FloatVar(..)) => ty,
+            _ => Ty::Unknown,
+        },
+    }
+}
 
 /// Given a binary operation and the type on the left of that operation, returns the expected type
 /// for the right hand side of the operation or `Ty::Unknown` if such an operation is invalid.
-pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
+pub(super) fn binary_op_rhs_expectation(db: &dyn HirDatabase, op: BinaryOp, lhs_ty: Ty) -> Ty {
     match op {
         BinaryOp::LogicOp(..) => Ty::simple(TypeCtor::Bool),
 
-        // Compare operations are allowed for all scalar types
-        BinaryOp::CmpOp(..) => match lhs_ty {
+        // Compare operations are allowed for all scalar types. Strings, structs and tuples can only
+        // be compared for equality.
+        BinaryOp::CmpOp(cmp_op) => match lhs_ty {
             Ty::Apply(ApplicationTy { ctor, .. }) => match ctor {
                 TypeCtor::Int(_) | TypeCtor::Float(_) | TypeCtor::Bool => lhs_ty,
+                TypeCtor::String | TypeCtor::Struct(_) | TypeCtor::Tuple { .. }
+                    if matches!(cmp_op, CmpOp::Eq { .. })
+                        && is_equality_comparable(db, &lhs_ty) =>
+                {
+                    lhs_ty
+                }
                 _ => Ty::Unknown,
             },
             Ty::Infer(InferTy::IntVar(..)) | Ty::Infer(InferTy::FloatVar(..)) => lhs_ty,
@@ -19,12 +56,21 @@ pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
 
         BinaryOp::Assignment { op: None } => match lhs_ty {
             Ty::Apply(ApplicationTy { ctor, .. }) => match ctor {
-                TypeCtor::Int(_) | TypeCtor::Float(_) | TypeCtor::Bool | TypeCtor::Struct(_) => {
-                    lhs_ty
-                }
+                TypeCtor::Int(_)
+                | TypeCtor::Float(_)
+                | TypeCtor::Bool
+                | TypeCtor::String
+                | TypeCtor::Struct(_)
+                | TypeCtor::FixedArray(_)
+                | TypeCtor::Array
+                | TypeCtor::Nullable
+                | TypeCtor::Tuple { .. }
+                | TypeCtor::FnPtr { .. } => lhs_ty,
                 _ => Ty::Unknown,
             },
             Ty::Infer(InferTy::IntVar(..)) | Ty::Infer(InferTy::FloatVar(..)) => lhs_ty,
+            // Values of a generic type can be assigned, but nothing else is known about them
+            Ty::Param { .. } => lhs_ty,
             _ => Ty::Unknown,
         },
         BinaryOp::Assignment {
@@ -55,10 +101,12 @@ pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
             _ => Ty::Unknown,
         },
 
-        // Arithmetic operations are supported only on number types
-        BinaryOp::Assignment { op: Some(_) } | BinaryOp::ArithOp(_) => match lhs_ty {
+        // Arithmetic operations are supported only on number types, strings can only be
+        // concatenated
+        BinaryOp::Assignment { op: Some(arith_op) } | BinaryOp::ArithOp(arith_op) => match lhs_ty {
             Ty::Apply(ApplicationTy { ctor, .. }) => match ctor {
                 TypeCtor::Int(_) | TypeCtor::Float(_) => lhs_ty,
+                TypeCtor::String if arith_op == ArithOp::Add => lhs_ty,
                 _ => Ty::Unknown,
             },
             Ty::Infer(InferTy::IntVar(..)) | Ty::Infer(InferTy::FloatVar(..)) => lhs_ty,
@@ -71,9 +119,14 @@ pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
 /// the return type of that operation.
 pub(super) fn binary_op_return_ty(op: BinaryOp, rhs_ty: Ty) -> Ty {
     match op {
-        BinaryOp::ArithOp(_) => match rhs_ty {
+        BinaryOp::ArithOp(arith_op) => match rhs_ty {
             Ty::Apply(ApplicationTy { ctor, .. }) => match ctor {
-                TypeCtor::Int(_) | TypeCtor::Float(_) => rhs_ty,
+                TypeCtor::Int(_) | TypeCtor::Float(_) | TypeCtor::String => rhs_ty,
+                TypeCtor::Bool
+                    if matches!(arith_op, ArithOp::BitAnd | ArithOp::BitOr | ArithOp::BitXor) =>
+                {
+                    rhs_ty
+                }
                 _ => Ty::Unknown,
             },
             Ty::Infer(InferTy::IntVar(..)) | Ty::Infer(InferTy::FloatVar(..)) => rhs_ty,
@@ -83,3 +136,65 @@ pub(super) fn binary_op_return_ty(op: BinaryOp, rhs_ty: Ty) -> Ty {
         BinaryOp::Assignment { .. } => Ty::Empty,
     }
 }
+
+/// Returns the name of the method that overloads the binary operation `op` for a user-defined type,
+/// e.g. `add` for both `a + b` and `a += b`, or `None` if the operation cannot be overloaded. The
+/// result of `a != b` is the negation of `a.eq(b)`.
+pub(super) fn binary_op_method_name(op: BinaryOp) -> Option<Name> {
+    let name = match op {
+        BinaryOp::ArithOp(op) | BinaryOp::Assignment { op: Some(op) } => match op {
+            ArithOp::Add => name![add],
+            ArithOp::Subtract => name![sub],
+            ArithOp::Multiply => name![mul],
+            ArithOp::Divide => name![div],
+            ArithOp::Remainder => name![rem],
+            ArithOp::LeftShift => name![shl],
+            ArithOp::RightShift => name![shr],
+            ArithOp::BitAnd => name![bitand],
+            ArithOp::BitOr => name![bitor],
+            ArithOp::BitXor => name![bitxor],
+        },
+        BinaryOp::CmpOp(CmpOp::Eq { .. }) => name![eq],
+        BinaryOp::CmpOp(CmpOp::Ord { ordering, strict }) => match (ordering, strict) {
+            (Ordering::Less, true) => name![lt],
+            (Ordering::Less, false) => name![le],
+            (Ordering::Greater, true) => name![gt],
+            (Ordering::Greater, false) => name![ge],
+        },
+        BinaryOp::LogicOp(_) | BinaryOp::Assignment { op: None } => return None,
+    };
+    Some(name)
+}
+
+/// Returns whether values of type `ty` can be compared for equality. Structs and tuples are
+/// compared field by field, so all of their fields must be comparable. Recursive structs cannot be
+/// compared.
+fn is_equality_comparable(db: &dyn HirDatabase, ty: &Ty) -> bool {
+    fn is_comparable(db: &dyn HirDatabase, ty: &Ty, visiting: &mut Vec<Struct>) -> bool {
+        match ty {
+            Ty::Apply(ApplicationTy { ctor, parameters }) => match ctor {
+                TypeCtor::Int(_) | TypeCtor::Float(_) | TypeCtor::Bool | TypeCtor::String => true,
+                TypeCtor::Tuple { .. } => {
+                    parameters.iter().all(|ty| is_comparable(db, ty, visiting))
+                }
+                TypeCtor::Struct(s) => {
+                    if visiting.contains(s) {
+                        return false;
+                    }
+                    visiting.push(*s);
+                    let fields_comparable = s
+                        .fields(db)
+                        .into_iter()
+                        .all(|field| is_comparable(db, &field.ty(db).subst(parameters), visiting));
+                    visiting.pop();
+                    fields_comparable
+                }
+                _ => false,
+            },
+            Ty::Infer(InferTy::IntVar(..)) | Ty::Infer(InferTy::FloatVar(..)) => true,
+            _ => false,
+        }
+    }
+
+    is_comparable(db, ty, &mut Vec::new())
+}
@@ -145,6 +145,18 @@ impl IntTy {
             },
         }
     }
+
+    /// Returns the number of bits that this instance occupies.
+    pub fn bits(self) -> u32 {
+        match self.bitness {
+            IntBitness::X8 => 8,
+            IntBitness::X16 => 16,
+            IntBitness::X32 => 32,
+            IntBitness::X64 => 64,
+            IntBitness::X128 => 128,
+            IntBitness::Xsize => unreachable!("cannot determine size of variable bitness"),
+        }
+    }
 }
 
 impl From<abi::Integer> for IntBitness {
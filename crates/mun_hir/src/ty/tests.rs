@@ -1,8 +1,14 @@
 use crate::fixture::WithFixture;
 use crate::{
-    db::DefDatabase, diagnostics::DiagnosticSink, expr::BodySourceMap, mock::MockDatabase,
-    HirDisplay, InferenceResult, ModuleDef,
+    code_model::{src::HasSource, DefWithBody},
+    db::DefDatabase,
+    diagnostics::{DiagnosticSink, Severity},
+    expr::BodySourceMap,
+    mock::MockDatabase,
+    FileId, HirDatabase, HirDisplay, InferenceResult, Module, ModuleDef, SourceDatabase,
+    SourceRootId,
 };
+use mun_syntax::AstNode;
 use std::{fmt::Write, sync::Arc};
 
 #[test]
@@ -12,7 +18,7 @@ fn comparison_not_implemented_for_struct() {
     struct Foo;
 
     fn main() -> bool {
-        Foo == Foo
+        Foo < Foo
     }",
     )
 }
@@ -173,7 +179,7 @@ fn void_return() {
 fn place_expressions() {
     infer_snapshot(
         r#"
-    fn foo(a:i32) {
+    fn foo(mut a:i32) {
         a += 3;
         3 = 5; // error: invalid left hand side of expression
     }
@@ -185,7 +191,7 @@ fn place_expressions() {
 fn update_operators() {
     infer_snapshot(
         r#"
-    fn foo(a:i32, b:f64) {
+    fn foo(mut a:i32, mut b:f64) {
         a += 3;
         a -= 3;
         a *= 3;
@@ -207,7 +213,7 @@ fn update_operators() {
 fn infer_unary_ops() {
     infer_snapshot(
         r#"
-    fn foo(a: i32, b: bool) {
+    fn foo(mut a: i32, mut b: bool) {
         a = -a;
         b = !b;
     }
@@ -219,7 +225,7 @@ fn infer_unary_ops() {
 fn invalid_unary_ops() {
     infer_snapshot(
         r#"
-    fn bar(a: f64, b: bool) {
+    fn bar(mut a: f64, mut b: bool) {
         a = !a; // mismatched type
         b = -b; // mismatched type
     }
@@ -227,6 +233,93 @@ fn invalid_unary_ops() {
     )
 }
 
+#[test]
+fn infer_neg_op() {
+    infer_snapshot(
+        r#"
+    fn foo(a: i32, b: u8, c: f32) {
+        let x = -a;
+        let y = -c;
+        let z = -1;
+        -b;                     // error: cannot apply unary operator
+        let w: u32 = -1;        // error: cannot apply unary operator
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_not_op() {
+    infer_snapshot(
+        r#"
+    fn foo(a: bool, b: u8, c: i64) {
+        let x = !a;
+        let y = !b;
+        let z = !c;
+        let w = !0;
+        !1.0;                   // error: cannot apply unary operator
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_cmp_ops() {
+    infer_snapshot(
+        r#"
+    struct Foo { a: i32, b: bool }
+    struct(value) Bar(f64, Foo);
+    struct Baz { a: [i32] }
+
+    fn foo(a: Foo, b: Bar, c: Baz, s: string) -> bool {
+        a == a;
+        b != b;
+        (1, true) == (2, false);
+        s == s;
+        true < false;
+        c == c;                 // error: cannot apply binary operator
+        a < a                   // error: cannot apply binary operator
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_bit_and_shift_ops() {
+    infer_snapshot(
+        r#"
+    fn foo(mut a: u8, mut b: bool, c: f32) {
+        let x = a & 15;
+        let y = b ^ true;
+        let z = a << 2;
+        a >>= 1;
+        b |= false;
+        c & c;                  // error: cannot apply binary operator
+        c << 1;                 // error: cannot apply binary operator
+        let s = 1u32 << 32;     // error: overflow
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_compound_assignment_on_fields() {
+    infer_snapshot(
+        r#"
+    struct Foo { a: i32, b: f64, c: bool, s: string }
+
+    fn foo(f: Foo) {
+        f.a += 1;
+        f.a <<= 2;
+        f.b *= 2.0;
+        f.c ^= true;
+        f.s += "!";
+        f.b -= 1;               // error: mismatched type
+    }
+    "#,
+    )
+}
+
 #[test]
 fn infer_loop() {
     infer_snapshot(
@@ -259,7 +352,7 @@ fn infer_while() {
     infer_snapshot(
         r#"
     fn foo() {
-        let n = 0;
+        let mut n = 0;
         while n < 3 { n += 1; };
         while n < 3 { n += 1; break; };
         while n < 3 { break 3; };   // error: break with value can only appear in a loop
@@ -269,6 +362,206 @@ fn infer_while() {
     )
 }
 
+#[test]
+fn infer_for() {
+    infer_snapshot(
+        r#"
+    fn foo() {
+        let mut n = 0;
+        for i in 0..10 { n += i; };
+        for i in 0..=n { break; };
+        for _ in 0..3 { break 3; };     // error: break with value can only appear in a loop
+        for f in 1.0..2.0 {};           // error: range bounds must be integers
+        for x in n {};                  // error: can only iterate over a range
+        let r = 0..3;                   // error: range outside of `for` loop
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_labeled_loops() {
+    infer_snapshot(
+        r#"
+    fn foo() {
+        let a = 'outer: loop {
+            while true {
+                continue 'outer;
+            }
+            break 'outer 5;
+        };
+        'a: for i in 0..3 { loop { break 'a; } };
+        break 'b;                                   // error: undeclared label
+        continue;                                   // error: continue outside of a loop
+        'c: while true { loop { break 'c 1; } };   // error: break with value can only appear in a loop
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_arrays() {
+    infer_snapshot(
+        r#"
+    fn foo(a: [i32; 3], b: [f64]) -> usize {
+        let c = [1, 2, 3];
+        let d: [f64] = [1.0, 2.0];
+        let e: [bool; 2] = [true];      // error: mismatched type
+        let f = [];                     // error: cannot infer the element type
+        a[0] + c[1];
+        b[true];                        // error: mismatched type
+        let g = 5;
+        g[0];                           // error: only arrays can be indexed
+        a.foo();                        // error: no method named `foo` found
+        a.len() + d.len()
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_strings() {
+    infer_snapshot(
+        r#"
+    fn foo(a: string) -> usize {
+        let b = "hello";
+        let c = a + b;
+        let d = c == "world";
+        let e = a < b;          // error: cannot apply binary operator
+        let f = a - b;          // error: cannot apply binary operator
+        c.len()
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_enums() {
+    infer_snapshot(
+        r#"
+    enum Foo { A, B(i32, bool) }
+
+    fn foo(f: Foo) -> i32 {
+        let a = Foo::A;
+        let b = Foo::B(3, true);
+        match f {
+            Foo::A => 0,
+            Foo::B(x, true) => x,
+            Foo::B(_, false) => -1,
+        }
+    }
+
+    fn bar(f: Foo) {
+        match f {
+            Foo::B(x) => {},    // error: expected 2 fields
+            Foo::C => {},       // error: undefined value
+            3 => {},            // error: mismatched type
+        }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_if_let_and_while_let() {
+    infer_snapshot(
+        r#"
+    enum Foo { A, B(i32, bool) }
+
+    fn foo(f: Foo) -> i32 {
+        if let Foo::B(x, true) = f { x } else { 0 }
+    }
+
+    fn bar(f: Foo) {
+        let mut n = 0;
+        while let (Foo::B(x, _), true) = (f, n < 3) { n += x; };
+        if let Foo::B(y, _) = f {} else { y; };     // error: undefined value
+        if let 3 = f {};                            // error: mismatched type
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_nullable() {
+    infer_snapshot(
+        r#"
+    struct Foo { a: i32 }
+    struct(value) Bar;
+
+    fn get(maybe: ?Foo) -> i32 {
+        if let f = maybe { f.a } else { 0 }
+    }
+
+    fn wrap(value: Foo) -> ?Foo {
+        let mut a: ?Foo = nil;
+        a = value;
+        a.a;                // error: cannot access a field of a value that might be `nil`
+        let b = nil;        // error: cannot infer the type of `nil`
+        value
+    }
+
+    fn invalid(a: ?Bar) {} // error: only `gc` structs can be nullable
+    "#,
+    )
+}
+
+#[test]
+fn infer_casts() {
+    infer_snapshot(
+        r#"
+    struct Foo {
+        a: i32,
+    }
+
+    fn main() {
+        let a = 5 as f32;
+        let b = a as u8;
+        let c = true as i64;
+        let d = a as f64 as usize;
+        let e = Foo { a: 1 };
+        let f = e as Foo;
+        let g = e as i32;
+        let h = b as bool;
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_methods() {
+    infer_snapshot(
+        r#"
+    struct Foo { a: i32 }
+
+    impl Foo {
+        fn new(a: i32) -> Self {
+            Self { a }
+        }
+
+        fn get(self) -> i32 {
+            self.a
+        }
+    }
+
+    fn main() {
+        let foo = Foo::new(3);
+        let a = foo.get();
+        foo.get(1);     // error: this function takes 0 parameters but 1 parameters was supplied
+        foo.new();      // error: no method named `new` found
+    }
+
+    impl Foo {
+        fn get(self) -> i32 { 0 }   // error: the name `get` is defined multiple times
+    }
+
+    impl i32 {}     // error: inherent `impl` blocks can only be added for structs and enums
+
+    fn bar(self) {} // error: `self` parameter is only allowed in associated functions
+    "#,
+    )
+}
+
 #[test]
 fn invalid_binary_ops() {
     infer_snapshot(
@@ -423,14 +716,352 @@ fn recursive_alias() {
     )
 }
 
+#[test]
+fn infer_generics() {
+    infer_snapshot(
+        r#"
+    struct Pair<T, U> { a: T, b: U }
+    struct Wrapper<T>(T);
+
+    fn first<T, U>(pair: Pair<T, U>) -> T {
+        pair.a
+    }
+
+    fn unused<T>(a: i32) -> i32 {
+        a
+    }
+
+    fn main() {
+        let p = Pair { a: 1, b: true };
+        let a = first(p);
+        let w = Wrapper(1.0);
+        let b: Pair<i32, f64> = Pair { a: 2, b: 3.0 };
+        unused(a);  // error: cannot infer the type arguments
+    }
+
+    fn errors(a: Pair<i32>, b: Wrapper<bool, bool>) {}  // error: wrong number of type arguments
+    "#,
+    )
+}
+
+#[test]
+fn infer_traits() {
+    infer_snapshot(
+        r#"
+    trait Shape {
+        fn area(self) -> f64;
+        fn scaled(self, factor: f64) -> f64 { self.area() * factor }
+    }
+
+    trait Named {
+        fn id(self) -> i32;
+    }
+
+    struct Square { side: f64 }
+
+    fn total<T: Shape>(a: T, b: T) -> f64 {
+        a.area() + b.scaled(2.0)
+    }
+
+    fn main() {
+        let s = Square { side: 2.0 };
+        let a = s.area();
+        let b = s.scaled(3.0);
+        let c = total(s, s);
+        total(1, 2);    // error: the trait `Shape` is not implemented for `i32`
+    }
+
+    fn unbound<T>(a: T) -> i32 {
+        a.id()          // error: no method named `id` found
+    }
+
+    fn unknown<T: Missing>(a: T) {} // error: cannot find trait `Missing`
+
+    impl Shape for Square {
+        fn area(self) -> f64 { self.side * self.side }
+    }
+
+    impl Named for Square {}        // error: not all trait items implemented, missing: `id`
+
+    impl Shape for Square {}        // error: conflicting implementations of trait `Shape`
+
+    impl Missing for Square {}      // error: cannot find trait `Missing`
+
+    impl Named for i32 {
+        fn id(self) -> i32 { self }
+        fn other(self) {}           // error: function `other` is not a member of trait `Named`
+    }
+
+    impl Named for bool {
+        fn id(self) -> bool { self } // error: signature does not match the trait
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_overloaded_ops() {
+    infer_snapshot(
+        r#"
+    struct(value) Vec2 { x: f64, y: f64 }
+
+    trait Add {
+        fn add(self, other: Self) -> Self;
+    }
+
+    fn sum(a: Vec2, b: Vec2) -> Vec2 {
+        let mut c = a + b;
+        c += a * 2.0;
+        c
+    }
+
+    fn compare(a: Vec2, b: Vec2) -> bool {
+        a == b || a != b && a < b
+    }
+
+    fn double<T: Add>(a: T) -> T {
+        a + a
+    }
+
+    fn invalid(a: Vec2) {
+        a - a;                  // error: cannot apply binary operator
+        a * a;                  // error: mismatched type
+    }
+
+    impl Vec2 {
+        fn add(self, other: Self) -> Self { other }
+        fn mul(self, factor: f64) -> Self { self }
+        fn eq(self, other: Self) -> bool { true }
+        fn lt(self, other: Self) -> bool { false }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_closures() {
+    infer_snapshot(
+        r#"
+    struct Counter { step: fn(i32) -> i32 }
+
+    fn apply(f: fn(i32) -> i32, a: i32) -> i32 {
+        f(a)
+    }
+
+    fn double(a: i32) -> i32 {
+        a * 2
+    }
+
+    fn main() {
+        let offset = 3;
+        let add = |x: i32| x + offset;
+        let a = apply(add, 1);
+        let b = apply(double, 2);
+        let c = apply(|x| x * 2, 3);
+        let d = |x: i32| -> i32 { return x; };
+        let counter = Counter { step: add };
+        let e = (counter.step)(4);
+        let f: fn() = || {};
+        let g = |x| x;  // error: cannot infer the type of this closure parameter
+        a(1);           // error: expected function type
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_tuples() {
+    infer_snapshot(
+        r#"
+    fn pair(a: i32, b: f32) -> (i32, f32) {
+        (a, b)
+    }
+
+    fn main() {
+        let t = pair(1, 2.0);
+        let (a, b) = t;
+        let c = t.1;
+        let u: (bool,) = (true,);
+        let e = ();
+        let (x, (y, _)) = (1, (false, 3.0));
+        t.2;            // error: attempted to access a non-existent field in a struct.
+        let (p, q) = 1; // error: mismatched type
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_imports() {
+    infer_snapshot(
+        r#"
+    //- /main.mun
+    use package::physics::{integrate, Body};
+    use physics::shapes::*;
+    use package::physics::secret;
+    use package::missing;
+
+    pub fn gravity() -> f32 {
+        9.81
+    }
+
+    fn main() -> f32 {
+        let body = Body { mass: 2.0 };
+        let a = integrate(body, 0.5);
+        let b = physics::integrate(body, 1.0);
+        let c = area(Shape::Circle);
+        let d = physics::shapes::Shape::Square;
+        a + b + c
+    }
+
+    //- /physics.mun
+    pub struct Body {
+        mass: f32,
+    }
+
+    pub fn integrate(body: Body, dt: f32) -> f32 {
+        body.mass * package::gravity() * dt
+    }
+
+    fn secret() {}
+
+    //- /physics/shapes.mun
+    pub enum Shape {
+        Circle,
+        Square,
+    }
+
+    pub fn area(shape: Shape) -> f32 {
+        super::super::gravity()
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_consts_and_statics() {
+    infer_snapshot(
+        r#"
+    struct(value) Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    const GRAVITY: f32 = 9.81;
+    pub const UP: Vec2 = Vec2 { x: 0.0, y: -GRAVITY };
+    const PAIR: (i32, bool) = (-1, !false);
+    static mut COUNTER: i32 = 0;
+    static LIMIT: i32 = 10;
+
+    fn main() -> f32 {
+        COUNTER += 1;
+        LIMIT = 5;      // error: cannot assign to immutable static `LIMIT`
+        UP.y = 1.0;     // error: cannot assign to part of constant `UP`
+        UP.y * GRAVITY
+    }
+
+    const CALL: f32 = main();   // error: expression cannot be evaluated at compile time
+    const A: i32 = B;           // error: cycle detected when evaluating constant
+    const B: i32 = A;           // error: cycle detected when evaluating constant
+    "#,
+    )
+}
+
+#[test]
+fn infer_const_fn() {
+    infer_snapshot(
+        r#"
+    const fn square(x: i32) -> i32 {
+        x * x
+    }
+
+    const fn factorial(n: u64) -> u64 {
+        if n <= 1 { 1 } else { n * factorial(n - 1) }
+    }
+
+    const fn forever(n: i32) -> i32 {
+        forever(n + 1)
+    }
+
+    const SIZE: i32 = square(3);
+    const NEGATIVE: i32 = -SIZE;
+    const LARGE: u64 = factorial(25);   // error: value overflows its type
+    const RATIO: i32 = 1 / (SIZE - 9);  // error: division by zero
+    const FOREVER: i32 = forever(0);    // error: reached the recursion limit
+
+    fn main(a: [f32; SIZE]) -> [f32; SIZE] {
+        a
+    }
+
+    fn invalid(a: [f32; NEGATIVE]) {}  // error: invalid array length
+    "#,
+    )
+}
+
+#[test]
+fn infer_int_arithmetic_methods() {
+    infer_snapshot(
+        r#"
+    fn main(a: u8, b: i32) {
+        let c = a.wrapping_add(200);
+        let d = b.saturating_mul(2);
+        let e = 5;
+        e.checked_sub(b);
+        a.wrapping_add(b);      // error: mismatched type
+        a.saturating_sub();     // error: this function takes 1 parameters but 0 parameters was supplied
+        a.wrapping_div(2);      // error: no method named `wrapping_div` found
+    }
+    "#,
+    )
+}
+
+#[test]
+fn infer_statics_in_other_module() {
+    infer_snapshot(
+        r#"
+    //- /main.mun
+    use package::state::COUNTER;
+
+    fn main() -> i32 {
+        COUNTER + state::LIMIT + state::MAX
+    }
+
+    //- /state.mun
+    pub static mut COUNTER: i32 = 0;
+    pub static LIMIT: i32 = 10;
+    pub const MAX: i32 = 100;
+    "#,
+    )
+}
+
 fn infer_snapshot(text: &str) {
     let text = text.trim().replace("\n    ", "\n");
     insta::assert_snapshot!(insta::_macro_support::AutoName, infer(&text), &text);
 }
 
 fn infer(content: &str) -> String {
-    let (db, file_id) = MockDatabase::with_single_file(content);
+    let db = MockDatabase::with_files(content);
+    let mut file_ids: Vec<FileId> = db.source_root(SourceRootId(0)).files().collect();
+    file_ids.sort();
 
+    // The output of a fixture with multiple files is prefixed by the path of each file
+    if let [file_id] = file_ids.as_slice() {
+        return infer_file(&db, *file_id);
+    }
+    file_ids
+        .into_iter()
+        .map(|file_id| {
+            format!(
+                "//- /{}\n{}",
+                db.file_relative_path(file_id),
+                infer_file(&db, file_id)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn infer_file(db: &MockDatabase, file_id: FileId) -> String {
     let mut acc = String::new();
 
     let mut infer_def = |infer_result: Arc<InferenceResult>,
@@ -460,7 +1091,7 @@ fn infer(content: &str) -> String {
             (src_ptr.value.range().start(), src_ptr.value.range().end())
         });
         for (src_ptr, ty) in &types {
-            let node = src_ptr.value.to_node(&src_ptr.file_syntax(&db));
+            let node = src_ptr.value.to_node(&src_ptr.file_syntax(db));
 
             let (range, text) = (
                 src_ptr.value.range(),
@@ -471,7 +1102,7 @@ fn infer(content: &str) -> String {
                 "{} '{}': {}\n",
                 range,
                 ellipsize(text, 15),
-                ty.display(&db)
+                ty.display(db)
             )
             .unwrap();
         }
@@ -479,27 +1110,63 @@ fn infer(content: &str) -> String {
 
     let mut diags = String::new();
 
+    // Warnings are covered by the tests of the validators that emit them
     let mut diag_sink = DiagnosticSink::new(|diag| {
-        write!(diags, "{}: {}\n", diag.highlight_range(), diag.message()).unwrap();
+        if diag.severity() == Severity::Error {
+            write!(diags, "{}: {}\n", diag.highlight_range(), diag.message()).unwrap();
+        }
     });
 
     for item in db.module_data(file_id).definitions() {
         match item {
             ModuleDef::Function(fun) => {
-                let source_map = fun.body_source_map(&db);
-                let infer_result = fun.infer(&db);
+                let source_map = fun.body_source_map(db);
+                let infer_result = fun.infer(db);
 
-                fun.diagnostics(&db, &mut diag_sink);
+                fun.diagnostics(db, &mut diag_sink);
 
                 infer_def(infer_result, source_map);
             }
             ModuleDef::TypeAlias(item) => {
-                item.diagnostics(&db, &mut diag_sink);
+                item.diagnostics(db, &mut diag_sink);
+            }
+            ModuleDef::Const(item) => {
+                item.diagnostics(db, &mut diag_sink);
+                let def = DefWithBody::from(*item);
+                infer_def(def.infer(db), def.body_source_map(db));
+            }
+            ModuleDef::Static(item) => {
+                item.diagnostics(db, &mut diag_sink);
+                let def = DefWithBody::from(*item);
+                infer_def(def.infer(db), def.body_source_map(db));
+            }
+            ModuleDef::Trait(item) => {
+                item.diagnostics(db, &mut diag_sink);
+                for fun in item.items(db) {
+                    infer_def(fun.infer(db), fun.body_source_map(db));
+                }
             }
             _ => {}
         }
     }
 
+    let mut impl_functions = Vec::new();
+    for item in Module::from(file_id).impls(db) {
+        item.diagnostics(db, &mut diag_sink);
+        impl_functions.extend(item.items(db));
+    }
+    // Sort the functions of impls by their position in the source for consistency
+    impl_functions.sort_by_key(|fun| fun.source(db).value.syntax().text_range().start());
+    for fun in impl_functions {
+        infer_def(fun.infer(db), fun.body_source_map(db));
+    }
+    db.inherent_impls(file_id)
+        .add_diagnostics(db, file_id, &mut diag_sink);
+    db.trait_impls(file_id)
+        .add_diagnostics(db, file_id, &mut diag_sink);
+    db.module_imports(file_id)
+        .add_diagnostics(db, file_id, &mut diag_sink);
+
     drop(diag_sink);
 
     acc.truncate(acc.trim_end().len());
@@ -3,9 +3,13 @@
 
 use crate::{
     arena::{map::ArenaMap, Arena, Idx},
-    Path,
+    expr::{integer_lit, Literal},
+    name, Path,
+};
+use mun_syntax::{
+    ast::{self, TypeAscriptionOwner},
+    AstPtr,
 };
-use mun_syntax::{ast, AstPtr};
 use rustc_hash::FxHashMap;
 use std::ops::Index;
 
@@ -16,11 +20,28 @@ pub type LocalTypeRefId = Idx<TypeRef>;
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum TypeRef {
     Path(Path),
+    /// An array type. `[T; N]` when a length is specified, or `[T]` otherwise.
+    Array(Box<TypeRef>, Option<ArrayLen>),
+    /// A function pointer type, e.g. `fn(i32) -> f32`, with its parameter and return types.
+    Fn(Vec<TypeRef>, Box<TypeRef>),
+    /// A tuple type, e.g. `(i32, f32)`.
+    Tuple(Vec<TypeRef>),
+    /// A nullable type, e.g. `?Foo`, of which the value is either a `Foo` or `nil`.
+    Nullable(Box<TypeRef>),
     Never,
     Empty,
     Error,
 }
 
+/// The length of a fixed-size array type
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ArrayLen {
+    /// An integer literal, e.g. the `4` in `[f32; 4]`
+    Literal(u64),
+    /// A path to a constant, e.g. the `N` in `[f32; N]`
+    Path(Path),
+}
+
 impl TypeRef {
     /// Converts an `ast::TypeRef` to a `hir::TypeRef`.
     pub fn from_ast(node: ast::TypeRef) -> Self {
@@ -34,9 +55,69 @@ impl TypeRef {
                     .map(TypeRef::Path)
                     .unwrap_or(TypeRef::Error)
             }
+            ast::TypeRefKind::ArrayType(inner) => TypeRef::from_array_ast(&inner),
+            ast::TypeRefKind::FnPointerType(inner) => TypeRef::from_fn_pointer_ast(&inner),
+            ast::TypeRefKind::ParenType(inner) => TypeRef::from_ast_opt(inner.type_ref()),
+            ast::TypeRefKind::TupleType(inner) => TypeRef::from_tuple_ast(&inner),
+            ast::TypeRefKind::NullableType(inner) => {
+                TypeRef::Nullable(Box::new(TypeRef::from_ast_opt(inner.type_ref())))
+            }
+        }
+    }
+
+    /// Converts an `ast::TupleType` to a `hir::TypeRef`. A tuple without fields refers to the empty
+    /// type.
+    fn from_tuple_ast(node: &ast::TupleType) -> Self {
+        let fields: Vec<TypeRef> = node.fields().map(TypeRef::from_ast).collect();
+        if fields.is_empty() {
+            TypeRef::Empty
+        } else {
+            TypeRef::Tuple(fields)
         }
     }
 
+    /// Converts an `ast::FnPointerType` to a `hir::TypeRef`. A missing return type refers to the
+    /// empty type.
+    fn from_fn_pointer_ast(node: &ast::FnPointerType) -> Self {
+        let params = node
+            .param_list()
+            .into_iter()
+            .flat_map(|list| list.params())
+            .map(|param| TypeRef::from_ast_opt(param.ascribed_type()))
+            .collect();
+        let ret_type = node
+            .ret_type()
+            .map(|ret_type| TypeRef::from_ast_opt(ret_type.type_ref()))
+            .unwrap_or(TypeRef::Empty);
+        TypeRef::Fn(params, Box::new(ret_type))
+    }
+
+    /// Converts an `ast::ArrayType` to a `hir::TypeRef`. The length of a fixed-size array has to
+    /// be an integer literal or a path to a constant, otherwise `TypeRef::Error` is returned.
+    fn from_array_ast(node: &ast::ArrayType) -> Self {
+        let element_type = Box::new(TypeRef::from_ast_opt(node.type_ref()));
+        let len = match node.expr().map(|expr| expr.kind()) {
+            None => return TypeRef::Array(element_type, None),
+            Some(ast::ExprKind::Literal(lit)) if lit.kind() == ast::LiteralKind::IntNumber => {
+                let (text, suffix) = lit.text_and_suffix();
+                let len = match integer_lit(&text, suffix.as_ref().map(|s| s.as_str())) {
+                    (Literal::Int(lit), errors) if errors.is_empty() => lit.value,
+                    _ => return TypeRef::Error,
+                };
+                if len > u64::MAX as u128 {
+                    return TypeRef::Error;
+                }
+                ArrayLen::Literal(len as u64)
+            }
+            Some(ast::ExprKind::PathExpr(path)) => match path.path().and_then(Path::from_ast) {
+                Some(path) => ArrayLen::Path(path),
+                None => return TypeRef::Error,
+            },
+            _ => return TypeRef::Error,
+        };
+        TypeRef::Array(element_type, Some(len))
+    }
+
     pub fn from_ast_opt(node: Option<ast::TypeRef>) -> Self {
         if let Some(node) = node {
             TypeRef::from_ast(node)
@@ -114,10 +195,24 @@ impl TypeRefBuilder {
                 .map(TypeRef::Path)
                 .unwrap_or(TypeRef::Error),
             NeverType(_) => TypeRef::Never,
+            ArrayType(inner) => TypeRef::from_array_ast(&inner),
+            FnPointerType(inner) => TypeRef::from_fn_pointer_ast(&inner),
+            ParenType(inner) => TypeRef::from_ast_opt(inner.type_ref()),
+            TupleType(inner) => TypeRef::from_tuple_ast(&inner),
+            NullableType(inner) => {
+                TypeRef::Nullable(Box::new(TypeRef::from_ast_opt(inner.type_ref())))
+            }
         };
         self.alloc_type_ref(type_ref, ptr)
     }
 
+    /// Allocates a reference to the `Self` type, e.g. for the `self` parameter of a method.
+    pub fn self_type(&mut self) -> LocalTypeRefId {
+        self.map
+            .type_refs
+            .alloc(TypeRef::Path(Path::from(name![Self])))
+    }
+
     pub fn unit(&mut self) -> LocalTypeRefId {
         self.map.type_refs.alloc(TypeRef::Empty)
     }
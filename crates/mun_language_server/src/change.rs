@@ -10,6 +10,7 @@ pub struct AnalysisChange {
     new_roots: Vec<hir::SourceRootId>,
     roots_changed: HashMap<hir::SourceRootId, RootChange>,
     files_changed: Vec<(hir::FileId, Arc<String>)>,
+    lint_levels: Option<HashMap<hir::Lint, hir::LintLevel>>,
 }
 
 impl fmt::Debug for AnalysisChange {
@@ -24,6 +25,9 @@ impl fmt::Debug for AnalysisChange {
         if !self.files_changed.is_empty() {
             d.field("files_changed", &self.files_changed.len());
         }
+        if let Some(lint_levels) = &self.lint_levels {
+            d.field("lint_levels", lint_levels);
+        }
         d.finish()
     }
 }
@@ -78,6 +82,12 @@ impl AnalysisChange {
             .removed
             .push(file);
     }
+
+    /// Records the levels at which lints are reported, unless an attribute in the source specifies
+    /// otherwise. Replaces all previously configured lint levels.
+    pub fn set_lint_levels(&mut self, lint_levels: HashMap<hir::Lint, hir::LintLevel>) {
+        self.lint_levels = Some(lint_levels);
+    }
 }
 
 /// Represents the addition of a file to a source root.
@@ -126,6 +136,7 @@ impl AnalysisDatabase {
             for add_file in root_change.added {
                 self.set_file_text(add_file.file_id, add_file.text);
                 self.set_file_relative_path(add_file.file_id, add_file.path.clone());
+                self.set_file_source_root(add_file.file_id, root_id);
                 source_root.insert_file(add_file.file_id)
             }
             for remove_file in root_change.removed {
@@ -139,5 +150,10 @@ impl AnalysisDatabase {
         for (file_id, text) in change.files_changed {
             self.set_file_text(file_id, text)
         }
+
+        // Update the configured lint levels
+        if let Some(lint_levels) = change.lint_levels {
+            self.lint_levels = Arc::new(lint_levels);
+        }
     }
 }
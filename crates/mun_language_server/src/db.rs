@@ -1,10 +1,12 @@
 #![allow(clippy::enum_variant_names)] // This is a HACK because we use salsa
 
 use crate::cancelation::Canceled;
-use hir::{HirDatabase, Upcast};
+use hir::{HirDatabase, Lint, LintLevel, Upcast};
 use mun_target::spec::Target;
 use salsa::{Database, Snapshot};
+use std::collections::HashMap;
 use std::panic;
+use std::sync::Arc;
 
 /// The `AnalysisDatabase` provides the database for all analyses. A database is given input and
 /// produces output based on these inputs through the use of queries. These queries are memoized
@@ -26,12 +28,16 @@ use std::panic;
 )]
 pub(crate) struct AnalysisDatabase {
     storage: salsa::Storage<Self>,
+    /// The levels at which lints are reported, unless an attribute in the source specifies
+    /// otherwise
+    pub(crate) lint_levels: Arc<HashMap<Lint, LintLevel>>,
 }
 
 impl AnalysisDatabase {
     pub fn new() -> Self {
         let mut db = AnalysisDatabase {
             storage: Default::default(),
+            lint_levels: Default::default(),
         };
 
         db.set_target(Target::host_target().expect("could not determine host target spec"));
@@ -99,6 +105,7 @@ impl salsa::ParallelDatabase for AnalysisDatabase {
     fn snapshot(&self) -> Snapshot<Self> {
         Snapshot::new(AnalysisDatabase {
             storage: self.storage.snapshot(),
+            lint_levels: self.lint_levels.clone(),
         })
     }
 }
@@ -1,7 +1,9 @@
 use crate::db::AnalysisDatabase;
 use hir::AstDatabase;
 use hir::InFile;
-use mun_diagnostics::DiagnosticForWith;
+use hir::LintLevel;
+use hir::Severity;
+use mun_diagnostics::{DiagnosticForWith, Fix};
 use mun_syntax::{Location, TextRange};
 use std::cell::RefCell;
 
@@ -16,8 +18,8 @@ pub struct Diagnostic {
     pub message: String,
     pub range: TextRange,
     pub additional_annotations: Vec<SourceAnnotation>,
-    // pub fix: Option<SourceChange>,
-    // pub severity: Severity,
+    pub severity: Severity,
+    pub fix: Option<Fix>,
 }
 
 /// Converts a location to a a range for use in diagnostics
@@ -38,11 +40,26 @@ pub(crate) fn diagnostics(db: &AnalysisDatabase, file_id: hir::FileId) -> Vec<Di
         message: format!("parse error: {}", err.to_string()),
         range: location_to_range(err.location()),
         additional_annotations: vec![],
+        severity: Severity::Error,
+        fix: None,
     }));
 
     // Add all HIR diagnostics
     let result = RefCell::new(result);
     let mut sink = hir::diagnostics::DiagnosticSink::new(|d| {
+        // Lints are reported at the level that is specified by the attributes in the source or,
+        // if there are none, by the manifest
+        let severity = match d.severity() {
+            Severity::Error => Severity::Error,
+            Severity::Warning => match d
+                .lint_level(db)
+                .or_else(|| d.lint().and_then(|lint| db.lint_levels.get(&lint).copied()))
+            {
+                Some(LintLevel::Allow) => return,
+                Some(LintLevel::Deny) => Severity::Error,
+                _ => Severity::Warning,
+            },
+        };
         result.borrow_mut().push(d.with_diagnostic(db, |d| {
             Diagnostic {
                 message: format!("{}\n{}", d.title(), d.footer().join("\n"))
@@ -57,6 +74,8 @@ pub(crate) fn diagnostics(db: &AnalysisDatabase, file_id: hir::FileId) -> Vec<Di
                         range: annotation.range,
                     })
                     .collect(),
+                severity,
+                fix: d.fix(),
             }
         }));
     });
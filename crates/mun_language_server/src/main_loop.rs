@@ -2,18 +2,23 @@ use crate::analysis::{Analysis, AnalysisSnapshot, Cancelable};
 use crate::change::AnalysisChange;
 use crate::config::{Config, FilesWatcher};
 use crate::conversion::{convert_range, url_from_path_with_drive_lowercasing};
-use crate::protocol::{Connection, Message, Notification, Request, RequestId};
+use crate::protocol::{Connection, Message, Notification, Request, RequestId, Response};
 use crate::Result;
 use anyhow::anyhow;
 use async_std::sync::RwLock;
 use futures::channel::mpsc::{unbounded, Sender, UnboundedReceiver, UnboundedSender};
 use futures::{SinkExt, StreamExt};
 use lsp_types::notification::PublishDiagnostics;
-use lsp_types::{PublishDiagnosticsParams, Url};
-use ra_vfs::{RootEntry, Vfs, VfsChange, VfsFile};
+use lsp_types::request::CodeActionRequest;
+use lsp_types::{
+    CodeAction, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
+    PublishDiagnosticsParams, TextEdit, Url, WorkspaceEdit,
+};
+use ra_vfs::{RelativePath, RootEntry, Vfs, VfsChange, VfsFile};
 use serde::{de::DeserializeOwned, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 /// A `Task` is something that is send from async tasks to the entry point for processing. This
@@ -43,6 +48,12 @@ struct LanguageServerState {
 
     /// All the roots in the workspace
     pub local_source_roots: Vec<hir::SourceRootId>,
+
+    /// The directories of the workspaces, which contain their manifests
+    pub workspace_roots: Vec<PathBuf>,
+
+    /// The files in the vfs that refer to the manifests of the workspaces
+    pub manifest_files: HashSet<VfsFile>,
 }
 
 /// A snapshot of the state of the language server
@@ -96,10 +107,34 @@ impl ra_vfs::Filter for MunFilter {
     }
 
     fn include_file(&self, file_path: &ra_vfs::RelativePath) -> bool {
-        file_path.extension() == Some("mun")
+        file_path.extension() == Some("mun") || is_manifest(file_path)
     }
 }
 
+/// Returns true if `file_path`, relative to the root of a workspace, refers to its manifest.
+fn is_manifest(file_path: &RelativePath) -> bool {
+    file_path.as_str() == mun_project::MANIFEST_FILENAME
+}
+
+/// Loads the levels of the lints that are configured in the manifests of the workspaces. A
+/// manifest that cannot be loaded, or that refers to an unknown lint, is reported and ignored.
+fn load_lint_levels(workspace_roots: &[PathBuf]) -> HashMap<hir::Lint, hir::LintLevel> {
+    let mut lint_levels = HashMap::new();
+    for root in workspace_roots.iter() {
+        let manifest_path = root.join(mun_project::MANIFEST_FILENAME);
+        if !manifest_path.is_file() {
+            continue;
+        }
+        let levels = mun_project::Manifest::from_file(&manifest_path)
+            .and_then(|manifest| hir::manifest_lint_levels(&manifest).map_err(anyhow::Error::from));
+        match levels {
+            Ok(levels) => lint_levels.extend(levels),
+            Err(e) => log::error!("could not load '{}': {}", manifest_path.display(), e),
+        }
+    }
+    lint_levels
+}
+
 impl LanguageServerState {
     pub fn new(config: Config) -> Self {
         // Create a channel for use by the vfs
@@ -110,7 +145,8 @@ impl LanguageServerState {
         let vfs = Vfs::new(
             config
                 .workspace_roots
-                .into_iter()
+                .iter()
+                .cloned()
                 .map(|root| RootEntry::new(root, Box::new(MunFilter {})))
                 .collect(),
             task_sender,
@@ -124,6 +160,7 @@ impl LanguageServerState {
             change.add_root(hir::SourceRootId(root.0));
             source_roots.push(hir::SourceRootId(root.0));
         }
+        change.set_lint_levels(load_lint_levels(&config.workspace_roots));
 
         // Construct the state that will hold all the analysis
         let mut analysis = Analysis::new();
@@ -134,17 +171,25 @@ impl LanguageServerState {
             vfs_task_receiver: task_receiver,
             analysis,
             local_source_roots: source_roots,
+            workspace_roots: config.workspace_roots,
+            manifest_files: HashSet::new(),
         }
     }
 }
 
-/// Registers file watchers with the client to monitor all mun files in the workspaces
+/// Registers file watchers with the client to monitor all mun files and manifests in the
+/// workspaces
 async fn register_client_file_watcher(connection_state: &mut ConnectionState, config: &Config) {
     let registration_options = lsp_types::DidChangeWatchedFilesRegistrationOptions {
         watchers: config
             .workspace_roots
             .iter()
-            .map(|root| format!("{}/**/*.mun", root.display()))
+            .flat_map(|root| {
+                vec![
+                    format!("{}/**/*.mun", root.display()),
+                    format!("{}/{}", root.display(), mun_project::MANIFEST_FILENAME),
+                ]
+            })
             .map(|glob_pattern| lsp_types::FileSystemWatcher {
                 glob_pattern,
                 kind: None,
@@ -233,13 +278,78 @@ enum LoopState {
 }
 
 /// Handles a received request
-async fn handle_request(request: Request, connection: &mut ConnectionState) -> Result<LoopState> {
+async fn handle_request(
+    request: Request,
+    connection: &mut ConnectionState,
+    state: &LanguageServerState,
+) -> Result<LoopState> {
     if connection.connection.handle_shutdown(&request).await? {
         return Ok(LoopState::Shutdown);
     };
+
+    // When the client requests the code actions for a range of a text document
+    if let Ok((id, params)) = cast_request::<CodeActionRequest>(request) {
+        let result = handle_code_action(state.snapshot(), params).await?;
+        connection
+            .connection
+            .sender
+            .send(Response::new_ok(id, result).into())
+            .await?;
+    }
+
     Ok(LoopState::Continue)
 }
 
+/// Returns the fixes of the diagnostics that overlap with the requested range as code actions
+async fn handle_code_action(
+    state: LanguageServerSnapshot,
+    params: CodeActionParams,
+) -> Result<Option<CodeActionResponse>> {
+    let uri = params.text_document.uri;
+    let path = uri
+        .to_file_path()
+        .map_err(|()| anyhow!("invalid uri: {}", uri))?;
+    let file_id = match state.vfs.read().await.path2file(&path) {
+        Some(file) => hir::FileId(file.0),
+        None => return Ok(None),
+    };
+
+    let line_index = state.analysis.file_line_index(file_id)?;
+    let mut actions = Vec::new();
+    for diagnostic in state.analysis.diagnostics(file_id)? {
+        let fix = match diagnostic.fix {
+            Some(fix) => fix,
+            None => continue,
+        };
+        if !ranges_overlap(convert_range(diagnostic.range, &line_index), params.range) {
+            continue;
+        }
+
+        let edit = TextEdit::new(
+            convert_range(
+                fix.range.value,
+                &state.analysis.file_line_index(fix.range.file_id)?,
+            ),
+            fix.replacement,
+        );
+        let mut changes = HashMap::new();
+        changes.insert(state.file_id_to_uri(fix.range.file_id).await?, vec![edit]);
+        actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+            title: fix.label,
+            edit: Some(WorkspaceEdit::new(changes)),
+            ..Default::default()
+        }));
+    }
+
+    Ok(Some(actions))
+}
+
+/// Returns true if the two ranges have at least one position in common
+fn ranges_overlap(a: lsp_types::Range, b: lsp_types::Range) -> bool {
+    (a.start.line, a.start.character) <= (b.end.line, b.end.character)
+        && (b.start.line, b.start.character) <= (a.end.line, a.end.character)
+}
+
 /// Handles a received notification
 async fn on_notification(
     notification: Notification,
@@ -399,7 +509,10 @@ async fn handle_diagnostics(
                 for d in diagnostics {
                     lsp_diagnostics.push(lsp_types::Diagnostic {
                         range: convert_range(d.range, &line_index),
-                        severity: Some(lsp_types::DiagnosticSeverity::Error),
+                        severity: Some(match d.severity {
+                            hir::Severity::Error => lsp_types::DiagnosticSeverity::Error,
+                            hir::Severity::Warning => lsp_types::DiagnosticSeverity::Warning,
+                        }),
                         code: None,
                         source: Some("mun".to_string()),
                         message: d.message,
@@ -475,7 +588,7 @@ async fn handle_lsp_message(
     state: &LanguageServerState,
 ) -> Result<LoopState> {
     match msg {
-        Message::Request(req) => handle_request(req, connection_state).await,
+        Message::Request(req) => handle_request(req, connection_state, state).await,
         Message::Response(response) => {
             let removed = connection_state.pending_responses.remove(&response.id);
             if !removed {
@@ -508,6 +621,15 @@ where
     Notification::new(N::METHOD.to_string(), params)
 }
 
+/// Casts a request to the specified type.
+fn cast_request<R>(request: Request) -> std::result::Result<(RequestId, R::Params), Request>
+where
+    R: lsp_types::request::Request,
+    R::Params: DeserializeOwned,
+{
+    request.try_extract(R::METHOD)
+}
+
 /// Casts a notification to the specified type.
 fn cast_notification<N>(notification: Notification) -> std::result::Result<N::Params, Notification>
 where
@@ -528,8 +650,8 @@ impl LanguageServerState {
     }
 
     /// Processes any and all changes that have been applied to the virtual filesystem. Generates
-    /// an `AnalysisChange` and applies it if there are changes. True is returned if things changed,
-    /// otherwise false.
+    /// an `AnalysisChange` and applies it if there are changes. The lint levels are reloaded if a
+    /// manifest changed. True is returned if things changed, otherwise false.
     pub async fn process_vfs_changes(&mut self) -> bool {
         // Get all the changes since the last time we processed
         let changes = self.vfs.write().await.commit_changes();
@@ -539,10 +661,16 @@ impl LanguageServerState {
 
         // Construct an AnalysisChange to apply
         let mut analysis_change = AnalysisChange::new();
+        let mut manifest_changed = false;
         for change in changes {
             match change {
                 VfsChange::AddRoot { root, files } => {
                     for (file, path, text) in files {
+                        if is_manifest(&path) {
+                            self.manifest_files.insert(file);
+                            manifest_changed = true;
+                            continue;
+                        }
                         analysis_change.add_file(
                             hir::SourceRootId(root.0),
                             hir::FileId(file.0),
@@ -557,6 +685,11 @@ impl LanguageServerState {
                     path,
                     text,
                 } => {
+                    if is_manifest(&path) {
+                        self.manifest_files.insert(file);
+                        manifest_changed = true;
+                        continue;
+                    }
                     analysis_change.add_file(
                         hir::SourceRootId(root.0),
                         hir::FileId(file.0),
@@ -564,17 +697,31 @@ impl LanguageServerState {
                         text,
                     );
                 }
-                VfsChange::RemoveFile { root, file, path } => analysis_change.remove_file(
-                    hir::SourceRootId(root.0),
-                    hir::FileId(file.0),
-                    path,
-                ),
+                VfsChange::RemoveFile { root, file, path } => {
+                    if self.manifest_files.remove(&file) {
+                        manifest_changed = true;
+                        continue;
+                    }
+                    analysis_change.remove_file(
+                        hir::SourceRootId(root.0),
+                        hir::FileId(file.0),
+                        path,
+                    )
+                }
                 VfsChange::ChangeFile { file, text } => {
+                    if self.manifest_files.contains(&file) {
+                        manifest_changed = true;
+                        continue;
+                    }
                     analysis_change.change_file(hir::FileId(file.0), text);
                 }
             }
         }
 
+        if manifest_changed {
+            analysis_change.set_lint_levels(load_lint_levels(&self.workspace_roots));
+        }
+
         // Apply the change
         self.analysis.apply_change(analysis_change);
         true
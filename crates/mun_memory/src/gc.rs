@@ -28,6 +28,10 @@ pub trait GcRuntime<T: TypeMemory + TypeTrace>: Send + Sync {
     /// Allocates an object of the given type returning a GcPtr
     fn alloc(&self, ty: T) -> GcPtr;
 
+    /// Allocates an array of the given type with `length` elements returning a GcPtr. The length
+    /// of the array is stored in front of its elements, see [`array_layout`](crate::array_layout).
+    fn alloc_array(&self, ty: T, length: usize) -> GcPtr;
+
     /// Returns the type of the specified `obj`.
     fn ptr_type(&self, obj: GcPtr) -> T;
 
@@ -1,12 +1,13 @@
 use crate::{
     cast,
     gc::{Event, GcPtr, GcRuntime, Observer, RawGcPtr, Stats, TypeTrace},
-    mapping::{self, FieldMapping, MemoryMapper},
+    mapping::{self, FieldMapping, MemoryMapper, ValueMapping},
     TypeDesc, TypeMemory,
 };
 use mapping::{Conversion, Mapping};
 use parking_lot::RwLock;
 use std::{
+    alloc::Layout,
     collections::{HashMap, VecDeque},
     hash::Hash,
     ops::Deref,
@@ -55,10 +56,10 @@ where
     }
 
     /// Logs an allocation
-    fn log_alloc(&self, handle: GcPtr, ty: T) {
+    fn log_alloc(&self, handle: GcPtr, layout: Layout) {
         {
             let mut stats = self.stats.write();
-            stats.allocated_memory += ty.layout().size();
+            stats.allocated_memory += layout.size();
         }
 
         self.observer.event(Event::Allocation(handle));
@@ -68,36 +69,72 @@ where
     pub fn observer(&self) -> &O {
         &self.observer
     }
+
+    /// Stores a newly allocated object, returning its handle
+    fn insert_obj(&self, object: Pin<Box<ObjectInfo<T>>>) -> GcPtr {
+        let layout = object.layout;
+
+        // We want to return a pointer to the `ObjectInfo`, to be used as handle.
+        let handle = (object.as_ref().deref() as *const _ as RawGcPtr).into();
+
+        {
+            let mut objects = self.objects.write();
+            objects.insert(handle, object);
+        }
+
+        self.log_alloc(handle, layout);
+        handle
+    }
 }
 
 fn alloc_obj<T: Clone + TypeMemory + TypeTrace>(ty: T) -> Pin<Box<ObjectInfo<T>>> {
-    let ptr = unsafe { std::alloc::alloc(ty.layout()) };
+    let layout = ty.layout();
+    alloc_obj_with_layout(ty, layout)
+}
+
+fn alloc_obj_with_layout<T: Clone + TypeMemory + TypeTrace>(
+    ty: T,
+    layout: Layout,
+) -> Pin<Box<ObjectInfo<T>>> {
+    let ptr = unsafe { std::alloc::alloc(layout) };
     Box::pin(ObjectInfo {
         ptr,
-        ty,
         roots: 0,
         color: Color::White,
+        ty,
+        layout,
     })
 }
 
+fn alloc_array_obj<T: Clone + TypeMemory + TypeTrace>(
+    ty: T,
+    length: usize,
+) -> Pin<Box<ObjectInfo<T>>> {
+    let element_layout = ty
+        .element_layout()
+        .expect("cannot allocate an array of a type that is not an array");
+    let (layout, _) = crate::array_layout(element_layout, length);
+    let object = alloc_obj_with_layout(ty, layout);
+
+    // Store the length of the array in its header
+    unsafe { *object.ptr.cast::<usize>() = length };
+
+    object
+}
+
 impl<T, O> GcRuntime<T> for MarkSweep<T, O>
 where
     T: TypeMemory + TypeTrace + Clone,
     O: Observer<Event = Event>,
 {
     fn alloc(&self, ty: T) -> GcPtr {
-        let object = alloc_obj(ty.clone());
-
-        // We want to return a pointer to the `ObjectInfo`, to be used as handle.
-        let handle = (object.as_ref().deref() as *const _ as RawGcPtr).into();
-
-        {
-            let mut objects = self.objects.write();
-            objects.insert(handle, object);
-        }
+        let object = alloc_obj(ty);
+        self.insert_obj(object)
+    }
 
-        self.log_alloc(handle, ty);
-        handle
+    fn alloc_array(&self, ty: T, length: usize) -> GcPtr {
+        let object = alloc_array_obj(ty, length);
+        self.insert_obj(object)
     }
 
     fn ptr_type(&self, handle: GcPtr) -> T {
@@ -188,11 +225,11 @@ where
                 }
                 true
             } else {
-                unsafe { std::alloc::dealloc(obj.ptr, obj.ty.layout()) };
+                unsafe { std::alloc::dealloc(obj.ptr, obj.layout) };
                 self.observer.event(Event::Deallocation(*h));
                 {
                     let mut stats = self.stats.write();
-                    stats.allocated_memory -= obj.ty.layout().size();
+                    stats.allocated_memory -= obj.layout.size();
                 }
                 false
             }
@@ -234,6 +271,7 @@ where
                         roots: object_info.roots,
                         color: object_info.color,
                         ty: new_ty.clone(),
+                        layout: object_info.layout,
                     });
                 }
             }
@@ -245,45 +283,137 @@ where
             for object_info in objects.values_mut() {
                 if object_info.ty == *old_ty {
                     let src = unsafe { NonNull::new_unchecked(object_info.ptr) };
-                    let dest = unsafe {
-                        NonNull::new_unchecked(std::alloc::alloc_zeroed(conversion.new_ty.layout()))
-                    };
+                    let (dest, layout) = if let Some(new_element_layout) =
+                        conversion.new_ty.element_layout()
+                    {
+                        let old_element_layout = old_ty
+                            .element_layout()
+                            .expect("an array can only be converted from an array");
+
+                        // The size of an array depends on the length stored in its header
+                        let length = unsafe { *src.cast::<usize>().as_ptr() };
+                        let (_, old_offset) = crate::array_layout(old_element_layout, length);
+                        let (layout, new_offset) = crate::array_layout(new_element_layout, length);
+                        let dest =
+                            unsafe { NonNull::new_unchecked(std::alloc::alloc_zeroed(layout)) };
+                        unsafe { *dest.cast::<usize>().as_ptr() = length };
+
+                        // Map each of the elements
+                        for idx in 0..length {
+                            map_fields(
+                                self,
+                                &mut new_allocations,
+                                &mapping.conversions,
+                                &conversion.field_mapping,
+                                element_ptr(src, old_offset, old_element_layout, idx),
+                                element_ptr(dest, new_offset, new_element_layout, idx),
+                            );
+                        }
 
-                    map_fields(
-                        self,
-                        &mut new_allocations,
-                        &mapping.conversions,
-                        &conversion.field_mapping,
-                        src,
-                        dest,
-                    );
+                        (dest, layout)
+                    } else {
+                        let layout = conversion.new_ty.layout();
+                        let dest =
+                            unsafe { NonNull::new_unchecked(std::alloc::alloc_zeroed(layout)) };
+
+                        map_fields(
+                            self,
+                            &mut new_allocations,
+                            &mapping.conversions,
+                            &conversion.field_mapping,
+                            src,
+                            dest,
+                        );
+
+                        (dest, layout)
+                    };
 
-                    unsafe { std::alloc::dealloc(src.as_ptr(), old_ty.layout()) };
+                    unsafe { std::alloc::dealloc(src.as_ptr(), object_info.layout) };
+                    {
+                        let mut stats = self.stats.write();
+                        stats.allocated_memory =
+                            stats.allocated_memory - object_info.layout.size() + layout.size();
+                    }
 
                     object_info.set(ObjectInfo {
                         ptr: dest.as_ptr(),
                         roots: object_info.roots,
                         color: object_info.color,
                         ty: conversion.new_ty.clone(),
+                        layout,
                     });
                 }
             }
         }
 
+        // Map values that are stored outside of the garbage collector
+        for value in mapping.values.iter() {
+            let ValueMapping {
+                old_ty,
+                new_ty,
+                src,
+                dest,
+            } = value;
+
+            if old_ty == new_ty {
+                unsafe {
+                    std::ptr::copy_nonoverlapping(
+                        src.as_ptr(),
+                        dest.as_ptr(),
+                        new_ty.layout().size(),
+                    )
+                };
+            } else if old_ty.group().is_struct() {
+                // Only map in-memory structs to in-memory structs of the same name, otherwise the
+                // new value is retained
+                if new_ty.group().is_struct()
+                    && old_ty.name() == new_ty.name()
+                    && old_ty.is_stack_allocated()
+                    && new_ty.is_stack_allocated()
+                {
+                    if let Some(conversion) = mapping.conversions.get(old_ty) {
+                        map_fields(
+                            self,
+                            &mut new_allocations,
+                            &mapping.conversions,
+                            &conversion.field_mapping,
+                            *src,
+                            *dest,
+                        );
+                    }
+                }
+            } else if !cast::try_cast_from_to(*old_ty.guid(), *new_ty.guid(), *src, *dest) {
+                // Failed to cast. Retain the new value instead
+            }
+        }
+
         // Retroactively store newly allocated objects
         // This cannot be done while mapping because we hold a mutable reference to objects
         for object in new_allocations {
-            let ty = object.ty.clone();
+            let layout = object.layout;
             // We want to return a pointer to the `ObjectInfo`, to
             // be used as handle.
             let handle = (object.as_ref().deref() as *const _ as RawGcPtr).into();
             objects.insert(handle, object);
 
-            self.log_alloc(handle, ty);
+            self.log_alloc(handle, layout);
         }
 
         return deleted;
 
+        /// Returns a pointer to the element at `idx` of an array, of which the elements start at
+        /// `offset`.
+        fn element_ptr(
+            array: NonNull<u8>,
+            offset: usize,
+            element_layout: Layout,
+            idx: usize,
+        ) -> NonNull<u8> {
+            let mut ptr = array.as_ptr() as usize;
+            ptr += offset + idx * element_layout.pad_to_align().size();
+            unsafe { NonNull::new_unchecked(ptr as *mut u8) }
+        }
+
         fn map_fields<T, O>(
             gc: &MarkSweep<T, O>,
             new_allocations: &mut Vec<Pin<Box<ObjectInfo<T>>>>,
@@ -445,6 +575,36 @@ where
                                     // Use previously zero-initialized memory
                                 }
                             }
+                        } else if old_ty.group().is_array() && !old_ty.is_stack_allocated() {
+                            // array(gc) -> array(gc)
+                            let field_dest = field_dest.cast::<GcPtr>();
+
+                            let is_converted = conversions
+                                .get(old_ty)
+                                .map_or(false, |conversion| conversion.new_ty == *new_ty);
+                            if is_converted {
+                                // Only copy the `GcPtr`. Memory will already be mapped.
+                                unsafe {
+                                    *field_dest = *field_src.cast::<GcPtr>();
+                                }
+                            } else if new_ty.group().is_array() && !new_ty.is_stack_allocated() {
+                                // The elements cannot be converted, so use an empty array instead
+                                let object = alloc_array_obj(new_ty.clone(), 0);
+
+                                // We want to return a pointer to the `ObjectInfo`, to be used as
+                                // handle.
+                                let handle =
+                                    (object.as_ref().deref() as *const _ as RawGcPtr).into();
+
+                                // Write handle to field
+                                unsafe {
+                                    *field_dest = handle;
+                                }
+
+                                new_allocations.push(object);
+                            } else {
+                                // Use the previously zero-initialized value
+                            }
                         } else if !cast::try_cast_from_to(
                             *old_ty.guid(),
                             *new_ty.guid(),
@@ -519,6 +679,8 @@ struct ObjectInfo<T: TypeMemory + TypeTrace + Clone> {
     pub roots: u32,
     pub color: Color,
     pub ty: T,
+    /// The layout of the allocated memory, which differs from the type's layout for arrays
+    pub layout: Layout,
 }
 
 /// An `ObjectInfo` is thread-safe.
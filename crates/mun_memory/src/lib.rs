@@ -26,6 +26,31 @@ pub trait TypeMemory: Send + Sync {
     fn layout(&self) -> Layout;
     /// Returns whether the memory is stack-allocated.
     fn is_stack_allocated(&self) -> bool;
+    /// Returns the memory layout of a single element, if this type is an array.
+    fn element_layout(&self) -> Option<Layout> {
+        None
+    }
+}
+
+/// Returns the memory layout of a heap-allocated array with `length` elements of
+/// `element_layout`, and the offset of its first element.
+///
+/// The array's length is stored as a `usize` at the start of the allocation, followed by its
+/// elements.
+pub fn array_layout(element_layout: Layout, length: usize) -> (Layout, usize) {
+    let elements = Layout::from_size_align(
+        element_layout
+            .pad_to_align()
+            .size()
+            .checked_mul(length)
+            .expect("array is too large"),
+        element_layout.align(),
+    )
+    .expect("array is too large");
+    let (layout, offset) = Layout::new::<usize>()
+        .extend(elements)
+        .expect("array is too large");
+    (layout.pad_to_align(), offset)
 }
 
 /// A trait used to obtain a type's fields.
@@ -34,4 +59,8 @@ pub trait TypeFields<T>: Send + Sync {
     fn fields(&self) -> Vec<(&str, T)>;
     /// Returns the type's fields' offsets.
     fn offsets(&self) -> &[u16];
+    /// Returns the type of a single element, if this type is an array.
+    fn element_type(&self) -> Option<T> {
+        None
+    }
 }
@@ -6,15 +6,20 @@ use crate::{
 use std::{
     collections::{HashMap, HashSet},
     hash::Hash,
+    ptr::NonNull,
 };
 
 pub struct Mapping<T: Eq + Hash, U: TypeDesc + TypeMemory> {
     pub deletions: HashSet<T>,
     pub conversions: HashMap<T, Conversion<U>>,
     pub identical: Vec<(T, T)>,
+    /// Values that are not allocated by the `MemoryMapper` but that need to be mapped along with
+    /// its allocated memory, e.g. the values of global variables.
+    pub values: Vec<ValueMapping<T>>,
 }
 
 pub struct Conversion<T: TypeDesc + TypeMemory> {
+    /// The mapping of the fields of a struct or, for an array, of each of its elements.
     pub field_mapping: Vec<FieldMapping<T>>,
     pub new_ty: T,
 }
@@ -35,6 +40,16 @@ pub enum Action<T: TypeDesc + TypeMemory> {
     Insert,
 }
 
+/// Description of the mapping of a single value that is stored outside of the `MemoryMapper`. The
+/// memory at `dest` is expected to already contain a valid value of type `new_ty`, which is
+/// retained when the old value cannot be mapped.
+pub struct ValueMapping<T> {
+    pub old_ty: T,
+    pub new_ty: T,
+    pub src: NonNull<u8>,
+    pub dest: NonNull<u8>,
+}
+
 impl<T> Mapping<T, T>
 where
     T: TypeDesc + TypeFields<T> + TypeMemory + Copy + Eq + Hash,
@@ -78,12 +93,16 @@ where
             }
         }
 
+        // Garbage collected arrays are not diffed, because they have no fields. Instead, an array
+        // is converted element-wise when the type of its elements changed.
+        append_array_mapping(old, new, &mut conversions, &mut deletions, &mut insertions);
+
         // These candidates are used to collect a list of `new_index -> old_index` mappings for
         // identical types.
         let mut new_candidates: HashSet<T> = new
             .iter()
-            // Filter non-struct types
-            .filter(|ty| ty.group().is_struct())
+            // Filter types that are neither structs nor arrays
+            .filter(|ty| ty.group().is_struct() || is_gc_array(*ty))
             // Filter inserted structs
             .filter(|ty| !insertions.contains(*ty))
             .cloned()
@@ -91,8 +110,8 @@ where
 
         let mut old_candidates: HashSet<T> = old
             .iter()
-            // Filter non-struct types
-            .filter(|ty| ty.group().is_struct())
+            // Filter types that are neither structs nor arrays
+            .filter(|ty| ty.group().is_struct() || is_gc_array(*ty))
             // Filter deleted structs
             .filter(|ty| !deletions.contains(*ty))
             // Filter edited types
@@ -127,8 +146,94 @@ where
             deletions,
             conversions,
             identical,
+            values: Vec::new(),
         }
     }
+
+    /// Adds a value that needs to be mapped from `src` to `dest` along with the allocated memory.
+    ///
+    /// # Safety
+    ///
+    /// `src` must point to a valid value of type `old_ty` and `dest` must point to a valid value
+    /// of type `new_ty`. Both must remain valid until the mapping has been applied.
+    pub unsafe fn add_value(&mut self, old_ty: T, new_ty: T, src: NonNull<u8>, dest: NonNull<u8>) {
+        self.values.push(ValueMapping {
+            old_ty,
+            new_ty,
+            src,
+            dest,
+        });
+    }
+}
+
+/// Returns whether `ty` is an array that is allocated by the garbage collector.
+fn is_gc_array<T: TypeDesc + TypeMemory>(ty: &T) -> bool {
+    ty.group().is_array() && !ty.is_stack_allocated()
+}
+
+/// Pairs the garbage collected arrays that only exist in `old` with those that only exist in `new`,
+/// when the types of their elements can be converted, e.g. from `[i32]` to `[i64]` or between two
+/// versions of a struct with the same name. The conversion of a paired array maps each of its
+/// elements, whereas the arrays that could not be paired are deleted or inserted.
+fn append_array_mapping<T>(
+    old: &[T],
+    new: &[T],
+    conversions: &mut HashMap<T, Conversion<T>>,
+    deletions: &mut HashSet<T>,
+    insertions: &mut HashSet<T>,
+) where
+    T: TypeDesc + TypeFields<T> + TypeMemory + Copy + Eq + Hash,
+{
+    let mut inserted: Vec<T> = new
+        .iter()
+        .filter(|ty| is_gc_array(*ty) && !old.contains(ty))
+        .cloned()
+        .collect();
+
+    for old_ty in old
+        .iter()
+        .filter(|ty| is_gc_array(*ty) && !new.contains(ty))
+    {
+        let old_element_ty = old_ty
+            .element_type()
+            .expect("an array must have an element type");
+        let paired = inserted.iter().position(|new_ty| {
+            let new_element_ty = new_ty
+                .element_type()
+                .expect("an array must have an element type");
+            let (old_group, new_group) = (old_element_ty.group(), new_element_ty.group());
+            (old_group.is_fundamental() && new_group.is_fundamental())
+                || (old_group.is_struct()
+                    && new_group.is_struct()
+                    && old_element_ty.name() == new_element_ty.name())
+        });
+
+        match paired {
+            Some(idx) => {
+                let new_ty = inserted.swap_remove(idx);
+                let new_element_ty = new_ty.element_type().unwrap();
+                conversions.insert(
+                    *old_ty,
+                    Conversion {
+                        field_mapping: vec![FieldMapping {
+                            new_ty: new_element_ty,
+                            new_offset: 0,
+                            action: Action::Cast {
+                                old_offset: 0,
+                                old_ty: old_element_ty,
+                            },
+                        }],
+                        new_ty,
+                    },
+                );
+            }
+            None => {
+                deletions.insert(*old_ty);
+            }
+        }
+    }
+
+    insertions.extend(inserted);
 }
 
 /// Given a set of `old_fields` of type `T` and their corresponding `diff`, calculates the mapping
@@ -1,6 +1,9 @@
-use super::util::{EventAggregator, HasTypeInfo, TypeInfo};
-use mun_memory::gc::{Event, GcRootPtr, GcRuntime, MarkSweep};
-use std::sync::Arc;
+use super::util::{EventAggregator, HasTypeInfo, TypeInfo, ARRAY_OF_I64};
+use mun_memory::{
+    array_layout,
+    gc::{Event, GcRootPtr, GcRuntime, HasIndirectionPtr, MarkSweep},
+};
+use std::{alloc::Layout, sync::Arc};
 
 #[test]
 fn alloc() {
@@ -14,6 +17,30 @@ fn alloc() {
     assert_eq!(events.next(), None);
 }
 
+#[test]
+fn alloc_array() {
+    let runtime = MarkSweep::<&'static TypeInfo, EventAggregator<Event>>::default();
+    let handle = runtime.alloc_array(&ARRAY_OF_I64, 5);
+
+    assert!(std::ptr::eq(runtime.ptr_type(handle), &ARRAY_OF_I64));
+
+    // The length of the array is stored in front of its elements
+    let (layout, offset) = array_layout(Layout::new::<i64>(), 5);
+    assert_eq!(unsafe { *handle.deref::<usize>() }, 5);
+    assert_eq!(offset, std::mem::size_of::<usize>());
+    assert_eq!(runtime.stats().allocated_memory, layout.size());
+
+    runtime.collect();
+
+    let mut events = runtime.observer().take_all().into_iter();
+    assert_eq!(events.next(), Some(Event::Allocation(handle)));
+    assert_eq!(events.next(), Some(Event::Start));
+    assert_eq!(events.next(), Some(Event::Deallocation(handle)));
+    assert_eq!(events.next(), Some(Event::End));
+    assert_eq!(events.next(), None);
+    assert_eq!(runtime.stats().allocated_memory, 0);
+}
+
 #[test]
 fn collect_simple() {
     let runtime = MarkSweep::<&'static TypeInfo, EventAggregator<Event>>::default();
@@ -7,6 +7,7 @@ use std::alloc::Layout;
 pub struct TypeInfo {
     pub size: usize,
     pub alignment: usize,
+    pub element: Option<&'static TypeInfo>,
     pub tracer: Option<&'static fn(handle: GcPtr) -> Vec<GcPtr>>,
 }
 
@@ -29,6 +30,7 @@ macro_rules! impl_primitive_types {
                 static [<TYPE_ $ty>]: TypeInfo = TypeInfo {
                     size: std::mem::size_of::<$ty>(),
                     alignment: std::mem::align_of::<$ty>(),
+                    element: None,
                     tracer: None
                 };
 
@@ -58,6 +60,7 @@ macro_rules! impl_struct_ty {
             static [<TYPE_ $ty>]: TypeInfo = TypeInfo {
                 size: std::mem::size_of::<$ty>(),
                 alignment: std::mem::align_of::<$ty>(),
+                element: None,
                 tracer: Some(&([<trace_ $ty>] as fn(handle: GcPtr) -> Vec<GcPtr>))
             };
 
@@ -72,6 +75,14 @@ macro_rules! impl_struct_ty {
 
 impl_primitive_types!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, bool);
 
+/// The type of a heap-allocated array of `i64`s
+pub static ARRAY_OF_I64: TypeInfo = TypeInfo {
+    size: std::mem::size_of::<usize>(),
+    alignment: std::mem::align_of::<usize>(),
+    element: Some(&TYPE_i64),
+    tracer: None,
+};
+
 impl mun_memory::TypeMemory for &'static TypeInfo {
     fn layout(&self) -> Layout {
         Layout::from_size_align(self.size as usize, self.alignment as usize)
@@ -82,6 +93,10 @@ impl mun_memory::TypeMemory for &'static TypeInfo {
         // NOTE: This contrived test does not support structs
         true
     }
+
+    fn element_layout(&self) -> Option<Layout> {
+        self.element.map(|element| mun_memory::TypeMemory::layout(&element))
+    }
 }
 
 impl gc::TypeTrace for &'static TypeInfo {
@@ -1,7 +1,7 @@
 mod manifest;
 mod package;
 
-pub use manifest::{Manifest, ManifestMetadata, PackageId};
+pub use manifest::{LintLevel, Manifest, ManifestMetadata, PackageId};
 pub use package::Package;
 
 pub const MANIFEST_FILENAME: &str = "mun.toml";
@@ -1,3 +1,5 @@
+use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::path::Path;
 use std::str::FromStr;
@@ -9,6 +11,7 @@ mod toml;
 pub struct Manifest {
     package_id: PackageId,
     metadata: ManifestMetadata,
+    lints: BTreeMap<String, LintLevel>,
 }
 
 /// General metadata for a package.
@@ -17,6 +20,15 @@ pub struct ManifestMetadata {
     pub authors: Vec<String>,
 }
 
+/// The level at which a lint is reported, as specified in the `lints` section of a mun.toml file.
+#[derive(Deserialize, Serialize, PartialEq, Eq, Copy, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
 /// Unique identifier of a package and version
 #[derive(PartialEq, Clone, Debug)]
 pub struct PackageId {
@@ -52,6 +64,11 @@ impl Manifest {
     pub fn metadata(&self) -> &ManifestMetadata {
         &self.metadata
     }
+
+    /// Returns the levels of the lints that are configured for the package, by name
+    pub fn lints(&self) -> &BTreeMap<String, LintLevel> {
+        &self.lints
+    }
 }
 
 impl PackageId {
@@ -85,7 +102,7 @@ impl std::str::FromStr for Manifest {
 
 #[cfg(test)]
 mod tests {
-    use crate::Manifest;
+    use crate::{LintLevel, Manifest};
     use std::str::FromStr;
 
     #[test]
@@ -107,5 +124,41 @@ mod tests {
         );
         assert_eq!(manifest.metadata().authors, vec!["Mun Team"]);
         assert_eq!(format!("{}", manifest.package_id()), "test v0.2.0");
+        assert!(manifest.lints().is_empty());
+    }
+
+    #[test]
+    fn parse_lints() {
+        let manifest = Manifest::from_str(
+            r#"
+        [package]
+        name="test"
+        version="0.2.0"
+
+        [lints]
+        unused_variables = "allow"
+        dead_code = "deny"
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.lints().get("unused_variables"),
+            Some(&LintLevel::Allow)
+        );
+        assert_eq!(manifest.lints().get("dead_code"), Some(&LintLevel::Deny));
+        assert_eq!(manifest.lints().get("unreachable_code"), None);
+
+        assert!(Manifest::from_str(
+            r#"
+        [package]
+        name="test"
+        version="0.2.0"
+
+        [lints]
+        unused_variables = "forbid"
+        "#,
+        )
+        .is_err());
     }
 }
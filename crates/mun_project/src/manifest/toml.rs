@@ -1,11 +1,14 @@
-use super::{Manifest, ManifestMetadata, PackageId};
+use super::{LintLevel, Manifest, ManifestMetadata, PackageId};
 use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// A manifest as specified in a mun.toml file.
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct TomlManifest {
     package: TomlProject,
+    #[serde(default)]
+    lints: BTreeMap<String, LintLevel>,
 }
 
 /// Represents the `package` section of a mun.toml file.
@@ -32,6 +35,7 @@ impl TomlManifest {
             metadata: ManifestMetadata {
                 authors: self.package.authors.unwrap_or_default(),
             },
+            lints: self.lints,
         })
     }
 }
@@ -6,7 +6,7 @@ use crate::{
     },
     Runtime,
 };
-use memory::gc::{GcRuntime, HasIndirectionPtr};
+use memory::gc::{GcRuntime, HasIndirectionPtr, RawGcPtr};
 use once_cell::sync::OnceCell;
 use std::cell::{Ref, RefCell};
 use std::{
@@ -21,7 +21,7 @@ use std::{
 /// Represents a Mun struct pointer.
 #[repr(transparent)]
 #[derive(Clone)]
-pub struct RawStruct(GcPtr);
+pub struct RawStruct(pub(crate) GcPtr);
 
 impl RawStruct {
     /// Returns a pointer to the struct memory.
@@ -148,7 +148,7 @@ impl<'s> StructRef<'s> {
         let field_ptr =
             unsafe { self.field_offset_unchecked::<T::MunType>(struct_info, field_idx) };
         let old = Marshal::marshal_from_ptr(field_ptr, self.runtime, Some(field_type));
-        Marshal::marshal_to_ptr(value, field_ptr, Some(field_type));
+        Marshal::marshal_to_ptr(value, field_ptr, self.runtime, Some(field_type));
         Ok(old)
     }
 
@@ -180,7 +180,7 @@ impl<'s> StructRef<'s> {
 
         let field_ptr =
             unsafe { self.field_offset_unchecked::<T::MunType>(struct_info, field_idx) };
-        Marshal::marshal_to_ptr(value, field_ptr, Some(field_type));
+        Marshal::marshal_to_ptr(value, field_ptr, self.runtime, Some(field_type));
         Ok(())
     }
 }
@@ -222,7 +222,7 @@ impl<'s> Marshal<'s> for StructRef<'s> {
         StructRef::new(value, runtime)
     }
 
-    fn marshal_into<'r>(self) -> Self::MunType {
+    fn marshal_into<'r>(self, _runtime: &'r Runtime) -> Self::MunType {
         self.into_raw()
     }
 
@@ -271,6 +271,7 @@ impl<'s> Marshal<'s> for StructRef<'s> {
     fn marshal_to_ptr(
         value: Self,
         mut ptr: NonNull<Self::MunType>,
+        _runtime: &Runtime,
         type_info: Option<&abi::TypeInfo>,
     ) {
         // `type_info` is only `None` for the `()` type
@@ -287,6 +288,275 @@ impl<'s> Marshal<'s> for StructRef<'s> {
     }
 }
 
+/// A nullable reference to a Mun `gc` struct, e.g. `?Foo`, is marshalled as an `Option`. `nil`
+/// corresponds to `None`.
+impl<'r> ArgumentReflection for Option<StructRef<'r>> {
+    fn type_guid(&self, runtime: &Runtime) -> abi::Guid {
+        match self {
+            Some(s) => s.type_guid(runtime),
+            None => <Self as ReturnTypeReflection>::type_guid(),
+        }
+    }
+
+    fn type_name<'s>(&'s self, runtime: &'s Runtime) -> &'s str {
+        match self {
+            Some(s) => s.type_name(runtime),
+            None => "nil",
+        }
+    }
+}
+
+impl<'r> ReturnTypeReflection for Option<StructRef<'r>> {
+    fn type_name() -> &'static str {
+        "?struct"
+    }
+
+    fn type_guid() -> abi::Guid {
+        // TODO: Once `const_fn` lands, replace this with a const md5 hash
+        static GUID: OnceCell<abi::Guid> = OnceCell::new();
+        *GUID.get_or_init(|| abi::Guid(md5::compute(<Self as ReturnTypeReflection>::type_name()).0))
+    }
+}
+
+impl<'s> Marshal<'s> for Option<StructRef<'s>> {
+    type MunType = RawStruct;
+
+    fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+    where
+        Self: 's,
+        'r: 's,
+    {
+        let raw: RawGcPtr = value.0.into();
+        if raw.is_null() {
+            None
+        } else {
+            Some(StructRef::new(value, runtime))
+        }
+    }
+
+    fn marshal_into<'r>(self, _runtime: &'r Runtime) -> Self::MunType {
+        match self {
+            Some(s) => s.into_raw(),
+            None => RawStruct(ptr::null::<*mut std::ffi::c_void>().into()),
+        }
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'r Runtime,
+        _type_info: Option<&abi::TypeInfo>,
+    ) -> Self
+    where
+        Self: 's,
+        'r: 's,
+    {
+        // A nullable reference is always stored as a `GcPtr`, which is null for `nil`
+        let value = unsafe { ptr.as_ptr().read() };
+        Marshal::marshal_from(value, runtime)
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        runtime: &Runtime,
+        _type_info: Option<&abi::TypeInfo>,
+    ) {
+        unsafe { *ptr.as_mut() = value.marshal_into(runtime) };
+    }
+}
+
+/// Represents a Mun enum pointer.
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct RawEnum(GcPtr);
+
+impl RawEnum {
+    /// Returns a pointer to the enum memory.
+    pub unsafe fn get_ptr(&self) -> *const u8 {
+        self.0.deref()
+    }
+}
+
+/// Type-agnostic wrapper for interoperability with a Mun enum. Enums always have value semantics,
+/// so this is a reference to a copy of the Mun enum, that will be garbage collected.
+#[derive(Clone)]
+pub struct EnumRef<'e> {
+    raw: RawEnum,
+    runtime: &'e Runtime,
+}
+
+impl<'e> EnumRef<'e> {
+    /// Creates an `EnumRef` that wraps a raw Mun enum.
+    fn new<'r>(raw: RawEnum, runtime: &'r Runtime) -> Self
+    where
+        'r: 'e,
+    {
+        Self { raw, runtime }
+    }
+
+    /// Consumes the `EnumRef`, returning a raw Mun enum.
+    pub fn into_raw(self) -> RawEnum {
+        self.raw
+    }
+
+    /// Returns the type information of the enum.
+    pub fn type_info(&self) -> &abi::TypeInfo {
+        // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+        // `Runtime` does not change. As the lifetime of `TypeInfo` is tied to the lifetime of
+        // `Runtime`, this is safe.
+        unsafe { &*self.runtime.gc.ptr_type(self.raw.0).into_inner().as_ptr() }
+    }
+
+    /// Returns the discriminant of the enum, i.e. the index of its active variant.
+    pub fn discriminant(&self) -> usize {
+        // Safety: `as_enum` is guaranteed to return `Some` for `EnumRef`s.
+        let enum_info = self.type_info().as_enum().unwrap();
+        unsafe { enum_info.read_discriminant(self.raw.get_ptr()) }
+    }
+
+    /// Returns the name of the enum's active variant.
+    pub fn variant_name(&self) -> &str {
+        // Safety: `as_enum` is guaranteed to return `Some` for `EnumRef`s.
+        let enum_info = self.type_info().as_enum().unwrap();
+        enum_info
+            .variant_names()
+            .nth(self.discriminant())
+            .expect("invalid discriminant")
+    }
+
+    /// Retrieves the value of the field at `field_idx` of the enum's active variant.
+    pub fn get<T: ReturnTypeReflection + Marshal<'e>>(&self, field_idx: usize) -> Result<T, String>
+    where
+        T: 'e,
+    {
+        let type_info = self.type_info();
+
+        // Safety: `as_enum` is guaranteed to return `Some` for `EnumRef`s.
+        let enum_info = type_info.as_enum().unwrap();
+        let variant_idx = self.discriminant();
+        let variant_name = self.variant_name();
+        let variant_info = &enum_info.variant_types()[variant_idx];
+
+        let field_type = variant_info.field_types().get(field_idx).ok_or_else(|| {
+            format!(
+                "Variant `{}::{}` does not contain field `{}`.",
+                type_info.name(),
+                variant_name,
+                field_idx
+            )
+        })?;
+        equals_return_type::<T>(field_type).map_err(|(expected, found)| {
+            format!(
+                "Mismatched types for `{}::{}.{}`. Expected: `{}`. Found: `{}`.",
+                type_info.name(),
+                variant_name,
+                field_idx,
+                expected,
+                found,
+            )
+        })?;
+
+        // If we found the `field_type`, we are guaranteed to also have the `field_offset`
+        let offset = variant_info.field_offsets()[field_idx];
+        // Safety: self.raw's memory pointer is never null
+        let field_ptr = unsafe {
+            NonNull::new_unchecked(
+                self.raw.get_ptr().add(offset as usize).cast::<T::MunType>() as *mut _
+            )
+        };
+        Ok(Marshal::marshal_from_ptr(
+            field_ptr,
+            self.runtime,
+            Some(field_type),
+        ))
+    }
+}
+
+impl<'r> ArgumentReflection for EnumRef<'r> {
+    fn type_guid(&self, runtime: &Runtime) -> abi::Guid {
+        // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+        // `Runtime` does not change. As we hold a shared reference to `Runtime`, this is safe.
+        unsafe { runtime.gc().ptr_type(self.raw.0).into_inner().as_ref().guid }
+    }
+
+    fn type_name(&self, runtime: &Runtime) -> &str {
+        // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+        // `Runtime` does not change. As we hold a shared reference to `Runtime`, this is safe.
+        unsafe { (&*runtime.gc().ptr_type(self.raw.0).into_inner().as_ptr()).name() }
+    }
+}
+
+impl<'r> ReturnTypeReflection for EnumRef<'r> {
+    fn type_name() -> &'static str {
+        "enum"
+    }
+
+    fn type_guid() -> abi::Guid {
+        // TODO: Once `const_fn` lands, replace this with a const md5 hash
+        static GUID: OnceCell<abi::Guid> = OnceCell::new();
+        *GUID.get_or_init(|| abi::Guid(md5::compute(<Self as ReturnTypeReflection>::type_name()).0))
+    }
+}
+
+impl<'e> Marshal<'e> for EnumRef<'e> {
+    type MunType = RawEnum;
+
+    fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+    where
+        Self: 'e,
+        'r: 'e,
+    {
+        EnumRef::new(value, runtime)
+    }
+
+    fn marshal_into<'r>(self, _runtime: &'r Runtime) -> Self::MunType {
+        self.into_raw()
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'r Runtime,
+        type_info: Option<&abi::TypeInfo>,
+    ) -> EnumRef<'e>
+    where
+        Self: 'e,
+        'r: 'e,
+    {
+        // Safety: `type_info` is only `None` for the `()` type
+        let type_info = type_info.unwrap();
+
+        // An enum is always stored by value, so `ptr` points to an enum value. Copy it into a new
+        // object using the runtime's intrinsic.
+        let mut gc_handle = runtime.gc().alloc(
+            // Safety: `ty` is a shared reference, so is guaranteed to not be `ptr::null()`.
+            UnsafeTypeInfo::new(unsafe {
+                NonNull::new_unchecked(type_info as *const abi::TypeInfo as *mut _)
+            }),
+        );
+
+        let src = ptr.cast::<u8>().as_ptr() as *const _;
+        let dest = unsafe { gc_handle.deref_mut::<u8>() };
+        let size = type_info.size_in_bytes();
+        unsafe { ptr::copy_nonoverlapping(src, dest, size) };
+
+        EnumRef::new(RawEnum(gc_handle), runtime)
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        ptr: NonNull<Self::MunType>,
+        _runtime: &Runtime,
+        type_info: Option<&abi::TypeInfo>,
+    ) {
+        // `type_info` is only `None` for the `()` type
+        let type_info = type_info.unwrap();
+
+        let dest = ptr.cast::<u8>().as_ptr();
+        let size = type_info.size_in_bytes();
+        unsafe { ptr::copy_nonoverlapping(value.into_raw().get_ptr(), dest, size) };
+    }
+}
+
 /// Type-agnostic wrapper for interoperability with a Mun struct, that has been rooted. To marshal,
 /// obtain a `StructRef` for the `RootedStruct`.
 pub struct RootedStruct {
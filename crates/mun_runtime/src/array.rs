@@ -0,0 +1,194 @@
+use crate::garbage_collector::GcPtr;
+use crate::{
+    marshal::Marshal,
+    reflection::{equals_return_type, ArgumentReflection, ReturnTypeReflection},
+    Runtime,
+};
+use memory::{
+    gc::{GcRuntime, HasIndirectionPtr},
+    TypeMemory,
+};
+use once_cell::sync::OnceCell;
+use std::ptr::NonNull;
+
+/// Represents a Mun array pointer.
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct RawArray(GcPtr);
+
+impl RawArray {
+    /// Returns a pointer to the array memory.
+    pub unsafe fn get_ptr(&self) -> *const u8 {
+        self.0.deref()
+    }
+}
+
+/// Type-agnostic wrapper for interoperability with a garbage collected Mun array (`[T]`). This is
+/// merely a reference to the Mun array, that will be garbage collected unless it is rooted.
+#[derive(Clone)]
+pub struct ArrayRef<'a> {
+    raw: RawArray,
+    runtime: &'a Runtime,
+}
+
+impl<'a> ArrayRef<'a> {
+    /// Creates an `ArrayRef` that wraps a raw Mun array.
+    fn new<'r>(raw: RawArray, runtime: &'r Runtime) -> Self
+    where
+        'r: 'a,
+    {
+        Self { raw, runtime }
+    }
+
+    /// Consumes the `ArrayRef`, returning a raw Mun array.
+    pub fn into_raw(self) -> RawArray {
+        self.raw
+    }
+
+    /// Returns the type information of the array.
+    pub fn type_info(&self) -> &abi::TypeInfo {
+        // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+        // `Runtime` does not change. As the lifetime of `TypeInfo` is tied to the lifetime of
+        // `Runtime`, this is safe.
+        unsafe { &*self.runtime.gc.ptr_type(self.raw.0).into_inner().as_ptr() }
+    }
+
+    /// Returns the number of elements in the array.
+    pub fn len(&self) -> usize {
+        // Safety: The length of a garbage collected array is stored at the start of its memory.
+        unsafe { *self.raw.get_ptr().cast::<usize>() }
+    }
+
+    /// Returns true if the array contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Retrieves the value of the element at `index`.
+    pub fn get<T: ReturnTypeReflection + Marshal<'a>>(&self, index: usize) -> Result<T, String>
+    where
+        T: 'a,
+    {
+        let type_info = self.type_info();
+
+        // Safety: `as_array` is guaranteed to return `Some` for `ArrayRef`s.
+        let element_type = type_info.as_array().unwrap().element_type();
+        equals_return_type::<T>(element_type).map_err(|(expected, found)| {
+            format!(
+                "Mismatched types for `{}` element. Expected: `{}`. Found: `{}`.",
+                type_info.name(),
+                expected,
+                found,
+            )
+        })?;
+
+        let len = self.len();
+        if index >= len {
+            return Err(format!(
+                "Index out of bounds for `{}`. The length is {} but the index is {}.",
+                type_info.name(),
+                len,
+                index,
+            ));
+        }
+
+        let element_ptr = unsafe { self.element_ptr_unchecked::<T::MunType>(index) };
+        Ok(Marshal::marshal_from_ptr(
+            element_ptr,
+            self.runtime,
+            Some(element_type),
+        ))
+    }
+
+    /// Returns a pointer to the element at `index`.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be smaller than the length of the array.
+    unsafe fn element_ptr_unchecked<T>(&self, index: usize) -> NonNull<T> {
+        let element_layout = self
+            .runtime
+            .gc
+            .ptr_type(self.raw.0)
+            .element_layout()
+            .expect("expected an array type");
+        let (_, offset) = memory::array_layout(element_layout, 0);
+        let stride = element_layout.pad_to_align().size();
+
+        // Safety: self.raw's memory pointer is never null
+        NonNull::new_unchecked(self.raw.get_ptr().add(offset + index * stride).cast::<T>() as *mut _)
+    }
+}
+
+impl<'r> ArgumentReflection for ArrayRef<'r> {
+    fn type_guid(&self, runtime: &Runtime) -> abi::Guid {
+        // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+        // `Runtime` does not change. As we hold a shared reference to `Runtime`, this is safe.
+        unsafe { runtime.gc().ptr_type(self.raw.0).into_inner().as_ref().guid }
+    }
+
+    fn type_name(&self, runtime: &Runtime) -> &str {
+        // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+        // `Runtime` does not change. As we hold a shared reference to `Runtime`, this is safe.
+        unsafe { (&*runtime.gc().ptr_type(self.raw.0).into_inner().as_ptr()).name() }
+    }
+}
+
+impl<'r> ReturnTypeReflection for ArrayRef<'r> {
+    fn type_name() -> &'static str {
+        "array"
+    }
+
+    fn type_guid() -> abi::Guid {
+        // TODO: Once `const_fn` lands, replace this with a const md5 hash
+        static GUID: OnceCell<abi::Guid> = OnceCell::new();
+        *GUID.get_or_init(|| abi::Guid(md5::compute(<Self as ReturnTypeReflection>::type_name()).0))
+    }
+}
+
+impl<'a> Marshal<'a> for ArrayRef<'a> {
+    type MunType = RawArray;
+
+    fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+    where
+        Self: 'a,
+        'r: 'a,
+    {
+        ArrayRef::new(value, runtime)
+    }
+
+    fn marshal_into<'r>(self, _runtime: &'r Runtime) -> Self::MunType {
+        self.into_raw()
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'r Runtime,
+        type_info: Option<&abi::TypeInfo>,
+    ) -> ArrayRef<'a>
+    where
+        Self: 'a,
+        'r: 'a,
+    {
+        // Safety: `type_info` is only `None` for the `()` type
+        let array_info = type_info.unwrap().as_array().unwrap();
+        assert_eq!(
+            array_info.memory_kind,
+            abi::StructMemoryKind::GC,
+            "fixed-size arrays cannot be marshalled"
+        );
+
+        // For a garbage collected array, `ptr` points to a `GcPtr`.
+        let gc_handle = unsafe { *ptr.cast::<GcPtr>().as_ptr() };
+        ArrayRef::new(RawArray(gc_handle), runtime)
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        _runtime: &Runtime,
+        _type_info: Option<&abi::TypeInfo>,
+    ) {
+        unsafe { *ptr.as_mut() = value.into_raw() };
+    }
+}
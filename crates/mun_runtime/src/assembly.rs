@@ -7,7 +7,7 @@ use libloader::{MunLibrary, TempLibrary};
 
 use crate::garbage_collector::{GarbageCollector, UnsafeTypeInfo};
 use memory::mapping::{Mapping, MemoryMapper};
-use std::{collections::HashSet, ptr::NonNull, sync::Arc};
+use std::{collections::HashMap, ptr::NonNull, sync::Arc};
 
 /// An assembly is a hot reloadable compilation unit, consisting of one or more Mun modules.
 pub struct Assembly {
@@ -16,16 +16,16 @@ pub struct Assembly {
     legacy_libs: Vec<TempLibrary>,
     info: AssemblyInfo,
     allocator: Arc<GarbageCollector>,
+    // The indices of the entries in the dispatch table that are linked by the runtime, i.e. the
+    // functions that are defined by other assemblies or by the runtime.
+    linked_fns: Vec<u32>,
 }
 
 impl Assembly {
     /// Loads an assembly and its information for the shared library at `library_path`. The
-    /// resulting `Assembly` is ensured to be linkable.
-    pub fn load(
-        library_path: &Path,
-        gc: Arc<GarbageCollector>,
-        runtime_dispatch_table: &DispatchTable,
-    ) -> Result<Self, anyhow::Error> {
+    /// resulting `Assembly` can only be linked once all of its dependencies have been loaded, which
+    /// is checked by `ensure_linkable`.
+    pub fn load(library_path: &Path, gc: Arc<GarbageCollector>) -> Result<Self, anyhow::Error> {
         let mut library = MunLibrary::new(library_path)?;
 
         let version = library.get_abi_version();
@@ -41,36 +41,46 @@ impl Assembly {
         library.set_allocator_handle(allocator_ptr);
 
         let info = library.get_info();
-        let assembly = Assembly {
+
+        // Entries that do *not* yet have a function pointer assigned by the compiler are linked by
+        // the runtime.
+        let linked_fns = info
+            .dispatch_table
+            .iter()
+            .enumerate()
+            .filter(|(_, (fn_ptr, _))| fn_ptr.is_null())
+            .map(|(idx, _)| idx as u32)
+            .collect();
+
+        Ok(Assembly {
             library_path: library_path.to_path_buf(),
             library: library.into_inner(),
             legacy_libs: Vec::new(),
             info,
             allocator: gc,
-        };
+            linked_fns,
+        })
+    }
 
-        // Ensure that any loaded `Assembly` can be linked safely.
-        assembly.ensure_linkable(runtime_dispatch_table)?;
-        Ok(assembly)
+    /// Returns the prototypes of the functions that are linked by the runtime.
+    pub fn linked_fns(&self) -> impl Iterator<Item = &abi::FunctionPrototype> {
+        let prototypes = self.info.dispatch_table.prototypes();
+        self.linked_fns
+            .iter()
+            .map(move |idx| &prototypes[*idx as usize])
     }
 
     /// Verifies that the `Assembly` resolves all dependencies in the `DispatchTable`.
-    fn ensure_linkable(&self, runtime_dispatch_table: &DispatchTable) -> Result<(), io::Error> {
-        let fn_names: HashSet<&str> = self
+    pub fn ensure_linkable(&self, runtime_dispatch_table: &DispatchTable) -> Result<(), io::Error> {
+        let fn_definitions: HashMap<&str, &abi::FunctionDefinition> = self
             .info
             .symbols
             .functions()
             .iter()
-            .map(|f| f.prototype.name())
+            .map(|f| (f.prototype.name(), f))
             .collect();
 
-        for (fn_ptr, fn_prototype) in self.info.dispatch_table.iter() {
-            // Only take signatures into account that do *not* yet have a function pointer assigned
-            // by the compiler.
-            if !fn_ptr.is_null() {
-                continue;
-            }
-
+        for fn_prototype in self.linked_fns() {
             // Ensure that the required function is in the runtime dispatch table and that its signature
             // is the same.
             match runtime_dispatch_table.get_fn(fn_prototype.name()) {
@@ -94,29 +104,28 @@ impl Assembly {
             }
         }
 
+        // Ensure that the functions that other assemblies depend on still exist and that their
+        // signatures are the same.
         if let Some(dependencies) = runtime_dispatch_table
             .fn_dependencies
             .get(self.info.symbols.path())
         {
-            for fn_name in dependencies.keys() {
-                if !fn_names.contains(&fn_name.as_str()) {
-                    return Err(io::Error::new(
-                        io::ErrorKind::NotFound,
-                        format!("Failed to link: function `{}` is missing.", fn_name),
-                    ));
-                }
-            }
-
-            for fn_definition in self.info.symbols.functions().iter() {
-                let (fn_prototype, _) = dependencies
-                    .get(fn_definition.prototype.name())
-                    .expect("The dependency must exist after the previous check.");
-
-                if fn_prototype.signature != fn_definition.prototype.signature {
-                    return Err(io::Error::new(
-                        io::ErrorKind::NotFound,
-                        format!("Failed to link: function '{}' is missing. A function with the same name does exist, but the signatures do not match (expected: {}, found: {}).", fn_prototype.name(), fn_prototype, fn_definition.prototype),
-                    ));
+            for (fn_name, (fn_prototype, _)) in dependencies.iter() {
+                match fn_definitions.get(fn_name.as_str()) {
+                    Some(fn_definition) => {
+                        if fn_prototype.signature != fn_definition.prototype.signature {
+                            return Err(io::Error::new(
+                                io::ErrorKind::NotFound,
+                                format!("Failed to link: function '{}' is missing. A function with the same name does exist, but the signatures do not match (expected: {}, found: {}).", fn_prototype.name(), fn_prototype, fn_definition.prototype),
+                            ));
+                        }
+                    }
+                    None => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::NotFound,
+                            format!("Failed to link: function `{}` is missing.", fn_name),
+                        ))
+                    }
                 }
             }
         }
@@ -124,23 +133,36 @@ impl Assembly {
         Ok(())
     }
 
-    /// Links the assembly using the runtime's dispatch table.
-    ///
-    /// Requires that `ensure_linkable` has been called beforehand. This happens upon creation of
-    /// an `Assembly` - in the `load` function - making this function safe.
-    pub fn link(&mut self, runtime_dispatch_table: &mut DispatchTable) {
+    /// Adds the functions of the assembly to the runtime's dispatch table.
+    pub fn register(&self, runtime_dispatch_table: &mut DispatchTable) {
         for function in self.info.symbols.functions() {
             runtime_dispatch_table.insert_fn(function.prototype.name(), function.clone());
         }
+    }
 
-        for (dispatch_ptr, fn_prototype) in self.info.dispatch_table.iter_mut() {
-            if dispatch_ptr.is_null() {
-                let fn_ptr = runtime_dispatch_table
-                    .get_fn(fn_prototype.name())
-                    .unwrap_or_else(|| panic!("Function '{}' is expected to exist.", fn_prototype))
-                    .fn_ptr;
-                *dispatch_ptr = fn_ptr;
-            }
+    /// Removes the functions of the assembly from the runtime's dispatch table.
+    pub fn unregister(&self, runtime_dispatch_table: &mut DispatchTable) {
+        for function in self.info.symbols.functions() {
+            runtime_dispatch_table.remove_fn(function.prototype.name());
+        }
+    }
+
+    /// Links the assembly using the runtime's dispatch table. An assembly is linked again when
+    /// one of the assemblies it depends on is reloaded.
+    ///
+    /// Requires that `ensure_linkable` has been called beforehand.
+    pub fn link(&mut self, runtime_dispatch_table: &DispatchTable) {
+        for idx in self.linked_fns.iter() {
+            let fn_name = self.info.dispatch_table.prototypes()[*idx as usize].name();
+            let fn_ptr = runtime_dispatch_table
+                .get_fn(fn_name)
+                .unwrap_or_else(|| panic!("Function '{}' is expected to exist.", fn_name))
+                .fn_ptr;
+            *self
+                .info
+                .dispatch_table
+                .get_ptr_mut(*idx)
+                .expect("linked function index is out of bounds") = fn_ptr;
         }
     }
 
@@ -150,8 +172,8 @@ impl Assembly {
         library_path: &Path,
         runtime_dispatch_table: &mut DispatchTable,
     ) -> Result<(), anyhow::Error> {
-        let mut new_assembly =
-            Assembly::load(library_path, self.allocator.clone(), runtime_dispatch_table)?;
+        let mut new_assembly = Assembly::load(library_path, self.allocator.clone())?;
+        new_assembly.ensure_linkable(runtime_dispatch_table)?;
 
         let old_types: Vec<UnsafeTypeInfo> = self
             .info
@@ -179,14 +201,45 @@ impl Assembly {
             })
             .collect();
 
-        let mapping = Mapping::new(&old_types, &new_types);
-        let deleted_objects = self.allocator.map_memory(mapping);
+        let mut mapping = Mapping::new(&old_types, &new_types);
 
-        // Remove the old assembly's functions
-        for function in self.info.symbols.functions() {
-            runtime_dispatch_table.remove_fn(function.prototype.name());
+        // Retain the values of statics by mapping them to the new assembly's statics of the same
+        // name
+        let old_statics: HashMap<&str, &abi::GlobalDefinition> = self
+            .info
+            .symbols
+            .globals()
+            .iter()
+            .filter(|global| global.is_mutable)
+            .map(|global| (global.name(), global))
+            .collect();
+
+        for new_static in new_assembly
+            .info
+            .symbols
+            .globals()
+            .iter()
+            .filter(|global| global.is_mutable)
+        {
+            if let Some(old_static) = old_statics.get(new_static.name()) {
+                // Safety: both pointers refer to the values of statics that remain loaded until the
+                // mapping has been applied.
+                unsafe {
+                    mapping.add_value(
+                        UnsafeTypeInfo::new(NonNull::from(old_static.type_info())),
+                        UnsafeTypeInfo::new(NonNull::from(new_static.type_info())),
+                        NonNull::new_unchecked(old_static.ptr.cast()),
+                        NonNull::new_unchecked(new_static.ptr.cast()),
+                    )
+                };
+            }
         }
 
+        let deleted_objects = self.allocator.map_memory(mapping);
+
+        // Replace the old assembly's functions
+        self.unregister(runtime_dispatch_table);
+        new_assembly.register(runtime_dispatch_table);
         new_assembly.link(runtime_dispatch_table);
 
         // Retain all existing legacy libs
@@ -0,0 +1,285 @@
+use crate::garbage_collector::{GcPtr, GcRootPtr, UnsafeTypeInfo};
+use crate::{
+    marshal::Marshal,
+    reflection::{equals_return_type, ArgumentReflection, ReturnTypeReflection},
+    Runtime,
+};
+use memory::gc::{GcRuntime, HasIndirectionPtr, RawGcPtr};
+use once_cell::sync::OnceCell;
+use std::{
+    cell::{Ref, RefCell},
+    ffi,
+    ptr::NonNull,
+    rc::Rc,
+    sync::Arc,
+};
+
+/// Represents a Mun closure pointer.
+///
+/// A closure is stored in garbage collected memory as a pointer to its function, followed by a
+/// handle to the garbage collected object that contains its captured variables. The handle is null
+/// if the closure does not capture any variables.
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct RawClosure(GcPtr);
+
+impl RawClosure {
+    /// Returns a pointer to the closure memory.
+    pub unsafe fn get_ptr(&self) -> *const u8 {
+        self.0.deref()
+    }
+
+    /// Returns the function pointer and the handle to the captured environment of the closure.
+    unsafe fn parts(&self) -> (*const ffi::c_void, RawGcPtr) {
+        let ptr = self.get_ptr().cast::<*const ffi::c_void>();
+        (*ptr, *ptr.add(1).cast::<RawGcPtr>())
+    }
+}
+
+/// Type-agnostic wrapper for interoperability with a Mun closure (`fn(T) -> U`). This is merely a
+/// reference to the Mun closure, that will be garbage collected unless it is rooted.
+///
+/// The function of a closure is part of the assembly that created it, so a closure should not be
+/// invoked after that assembly has been hot reloaded.
+#[derive(Clone)]
+pub struct ClosureRef<'c> {
+    raw: RawClosure,
+    runtime: &'c Runtime,
+}
+
+impl<'c> ClosureRef<'c> {
+    /// Creates a `ClosureRef` that wraps a raw Mun closure.
+    fn new<'r>(raw: RawClosure, runtime: &'r Runtime) -> Self
+    where
+        'r: 'c,
+    {
+        Self { raw, runtime }
+    }
+
+    /// Consumes the `ClosureRef`, returning a raw Mun closure.
+    pub fn into_raw(self) -> RawClosure {
+        self.raw
+    }
+
+    /// Roots the `ClosureRef`, so it can be stored and invoked later.
+    pub fn root(self, runtime: Rc<RefCell<Runtime>>) -> RootedClosure {
+        RootedClosure::new(&self.runtime.gc, runtime, self.raw)
+    }
+
+    /// Returns the type information of the closure.
+    pub fn type_info(&self) -> &abi::TypeInfo {
+        // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+        // `Runtime` does not change. As the lifetime of `TypeInfo` is tied to the lifetime of
+        // `Runtime`, this is safe.
+        unsafe { &*self.runtime.gc.ptr_type(self.raw.0).into_inner().as_ptr() }
+    }
+
+    /// Returns the signature of the closure.
+    pub fn signature(&self) -> &abi::FunctionSignature {
+        // Safety: `as_function` is guaranteed to return `Some` for `ClosureRef`s.
+        self.type_info().as_function().unwrap()
+    }
+
+    /// Validates that the closure accepts `args` and returns a value of type `Output`.
+    fn check_signature<Output: ReturnTypeReflection>(
+        &self,
+        args: &[(&abi::Guid, &str)],
+    ) -> Result<(), String> {
+        let signature = self.signature();
+        let arg_types = signature.arg_types();
+        if arg_types.len() != args.len() {
+            return Err(format!(
+                "Invalid number of arguments. Expected: {}. Found: {}.",
+                arg_types.len(),
+                args.len(),
+            ));
+        }
+
+        for (idx, (arg_type, (guid, name))) in arg_types.iter().zip(args.iter()).enumerate() {
+            if arg_type.guid != **guid {
+                return Err(format!(
+                    "Invalid argument type at index {}. Expected: {}. Found: {}.",
+                    idx,
+                    arg_type.name(),
+                    name,
+                ));
+            }
+        }
+
+        if let Some(return_type) = signature.return_type() {
+            equals_return_type::<Output>(return_type)
+        } else if <() as ReturnTypeReflection>::type_guid() != Output::type_guid() {
+            Err((
+                <() as ReturnTypeReflection>::type_name(),
+                Output::type_name(),
+            ))
+        } else {
+            Ok(())
+        }
+        .map_err(|(expected, found)| {
+            format!(
+                "Invalid return type. Expected: {}. Found: {}",
+                expected, found,
+            )
+        })
+    }
+}
+
+macro_rules! invoke_closure_impl {
+    ($(
+        fn $FnName:ident($($Arg:ident: $T:ident),*);
+    )+) => {
+        impl<'c> ClosureRef<'c> {
+            $(
+                /// Invokes the closure with the specified arguments. An error is returned if the
+                /// arguments or the return type do not match the signature of the closure, or if
+                /// the closure panics.
+                #[allow(clippy::too_many_arguments)]
+                pub fn $FnName<'i, 'o, $($T: ArgumentReflection + Marshal<'i>,)* Output: 'o + ReturnTypeReflection + Marshal<'o>>(
+                    &self,
+                    $($Arg: $T,)*
+                ) -> Result<Output, String>
+                where
+                    'c: 'o,
+                {
+                    let runtime = self.runtime;
+                    self.check_signature::<Output>(&[
+                        $((&$Arg.type_guid(runtime), $Arg.type_name(runtime)),)*
+                    ])?;
+
+                    // Safety: The signature of the closure has been validated. Its function
+                    // receives the handle to the captured environment as its first argument.
+                    let (function, env) = unsafe {
+                        let (fn_ptr, env) = self.raw.parts();
+                        let function: fn(RawGcPtr, $($T::MunType),*) -> Output::MunType =
+                            core::mem::transmute(fn_ptr);
+                        (function, env)
+                    };
+                    let result = crate::catch_panic(|| {
+                        function(env, $($Arg.marshal_into(runtime)),*)
+                    })
+                    .map_err(|panic| panic.to_string())?;
+
+                    Ok(Marshal::marshal_from(result, runtime))
+                }
+            )+
+        }
+    }
+}
+
+invoke_closure_impl! {
+    fn invoke0();
+    fn invoke1(arg1: A);
+    fn invoke2(arg1: A, arg2: B);
+    fn invoke3(arg1: A, arg2: B, arg3: C);
+    fn invoke4(arg1: A, arg2: B, arg3: C, arg4: D);
+    fn invoke5(arg1: A, arg2: B, arg3: C, arg4: D, arg5: E);
+    fn invoke6(arg1: A, arg2: B, arg3: C, arg4: D, arg5: E, arg6: F);
+}
+
+impl<'r> ArgumentReflection for ClosureRef<'r> {
+    fn type_guid(&self, runtime: &Runtime) -> abi::Guid {
+        // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+        // `Runtime` does not change. As we hold a shared reference to `Runtime`, this is safe.
+        unsafe { runtime.gc().ptr_type(self.raw.0).into_inner().as_ref().guid }
+    }
+
+    fn type_name(&self, runtime: &Runtime) -> &str {
+        // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+        // `Runtime` does not change. As we hold a shared reference to `Runtime`, this is safe.
+        unsafe { (&*runtime.gc().ptr_type(self.raw.0).into_inner().as_ptr()).name() }
+    }
+}
+
+impl<'r> ReturnTypeReflection for ClosureRef<'r> {
+    fn type_name() -> &'static str {
+        "closure"
+    }
+
+    fn type_guid() -> abi::Guid {
+        // TODO: Once `const_fn` lands, replace this with a const md5 hash
+        static GUID: OnceCell<abi::Guid> = OnceCell::new();
+        *GUID.get_or_init(|| abi::Guid(md5::compute(<Self as ReturnTypeReflection>::type_name()).0))
+    }
+}
+
+impl<'c> Marshal<'c> for ClosureRef<'c> {
+    type MunType = RawClosure;
+
+    fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+    where
+        Self: 'c,
+        'r: 'c,
+    {
+        ClosureRef::new(value, runtime)
+    }
+
+    fn marshal_into<'r>(self, _runtime: &'r Runtime) -> Self::MunType {
+        self.into_raw()
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'r Runtime,
+        _type_info: Option<&abi::TypeInfo>,
+    ) -> ClosureRef<'c>
+    where
+        Self: 'c,
+        'r: 'c,
+    {
+        // Closures are always garbage collected, so `ptr` points to a `GcPtr`.
+        let gc_handle = unsafe { *ptr.cast::<GcPtr>().as_ptr() };
+        ClosureRef::new(RawClosure(gc_handle), runtime)
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        _runtime: &Runtime,
+        _type_info: Option<&abi::TypeInfo>,
+    ) {
+        unsafe { *ptr.as_mut() = value.into_raw() };
+    }
+}
+
+/// Type-agnostic wrapper for interoperability with a Mun closure, that has been rooted. To marshal,
+/// obtain a `ClosureRef` for the `RootedClosure`.
+pub struct RootedClosure {
+    handle: GcRootPtr,
+    runtime: Rc<RefCell<Runtime>>,
+}
+
+impl RootedClosure {
+    /// Creates a `RootedClosure` that wraps a raw Mun closure.
+    fn new<G: GcRuntime<UnsafeTypeInfo>>(
+        gc: &Arc<G>,
+        runtime: Rc<RefCell<Runtime>>,
+        raw: RawClosure,
+    ) -> Self {
+        let handle = {
+            let runtime_ref = runtime.borrow();
+            // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+            // `Runtime` does not change. As we hold a shared reference to `Runtime`, this is safe.
+            assert!(unsafe { gc.ptr_type(raw.0).into_inner().as_ref().group.is_function() });
+
+            GcRootPtr::new(&runtime_ref.gc, raw.0)
+        };
+
+        Self { runtime, handle }
+    }
+
+    /// Converts the `RootedClosure` into a `ClosureRef`, using an external shared reference to a
+    /// `Runtime`.
+    ///
+    /// # Safety
+    ///
+    /// The `RootedClosure` should have been allocated by the `Runtime`.
+    pub unsafe fn as_ref<'r>(&self, runtime: &'r Runtime) -> ClosureRef<'r> {
+        ClosureRef::new(RawClosure(self.handle.handle()), runtime)
+    }
+
+    /// Borrows the closure's runtime.
+    pub fn borrow_runtime(&self) -> Ref<Runtime> {
+        self.runtime.borrow()
+    }
+}
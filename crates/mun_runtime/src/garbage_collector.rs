@@ -78,38 +78,104 @@ impl memory::TypeFields<UnsafeTypeInfo> for UnsafeTypeInfo {
             &[]
         }
     }
+
+    fn element_type(&self) -> Option<Self> {
+        unsafe { self.0.as_ref().as_array() }
+            .map(|a| UnsafeTypeInfo::new(NonNull::from(a.element_type())))
+    }
 }
 
 unsafe impl Send for UnsafeTypeInfo {}
 unsafe impl Sync for UnsafeTypeInfo {}
 
+/// Iterates over all garbage collected objects that are referenced by an object.
 pub struct Trace {
-    obj: GcPtr,
-    ty: UnsafeTypeInfo,
-    index: usize,
+    handles: std::vec::IntoIter<GcPtr>,
 }
 
 impl Iterator for Trace {
     type Item = GcPtr;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let struct_ty = unsafe { self.ty.0.as_ref() }.as_struct()?;
-        let field_count = struct_ty.field_types().len();
-        while self.index < field_count {
-            let index = self.index;
-            self.index += 1;
-
-            let field_ty = struct_ty.field_types()[index];
-            if let Some(field_struct_ty) = field_ty.as_struct() {
-                if field_struct_ty.memory_kind == abi::StructMemoryKind::GC {
-                    let offset = struct_ty.field_offsets()[index];
-                    return Some(unsafe {
-                        *self.obj.deref::<u8>().add(offset as usize).cast::<GcPtr>()
-                    });
-                }
-            }
+        self.handles.next()
+    }
+}
+
+/// Returns whether values of type `ty` are stored inline, instead of being referenced through a
+/// `GcPtr`.
+fn is_value_type(ty: &abi::TypeInfo) -> bool {
+    if let Some(s) = ty.as_struct() {
+        s.memory_kind == abi::StructMemoryKind::Value
+    } else if let Some(a) = ty.as_array() {
+        a.memory_kind == abi::StructMemoryKind::Value
+    } else {
+        !ty.group.is_string() && !ty.group.is_function() && !ty.group.is_nullable()
+    }
+}
+
+/// Returns the memory layout of a value of type `ty` when it is stored as a struct field or an
+/// array element.
+fn inline_layout(ty: &abi::TypeInfo) -> Layout {
+    if is_value_type(ty) {
+        Layout::from_size_align(ty.size_in_bytes(), ty.alignment())
+            .unwrap_or_else(|_| panic!("invalid layout from Mun Type: {:?}", ty))
+    } else {
+        Layout::new::<GcPtr>()
+    }
+}
+
+/// Collects all garbage collected objects referenced by the value of type `ty` at `ptr`.
+///
+/// # Safety
+///
+/// `ptr` must point to a valid value of type `ty`.
+unsafe fn trace_value(ty: &abi::TypeInfo, ptr: *const u8, handles: &mut Vec<GcPtr>) {
+    if !is_value_type(ty) {
+        // A nullable reference that is `nil` does not refer to an object
+        let handle = *ptr.cast::<gc::RawGcPtr>();
+        if !handle.is_null() {
+            handles.push(handle.into());
         }
-        None
+    } else if let Some(s) = ty.as_struct() {
+        trace_fields(s, ptr, handles);
+    } else if let Some(a) = ty.as_array() {
+        let length = a.length().expect("value arrays have a fixed length");
+        trace_elements(a.element_type(), ptr, length, handles);
+    } else if let Some(e) = ty.as_enum() {
+        trace_variant(e, ptr, handles);
+    }
+}
+
+/// Collects all garbage collected objects referenced by the fields of the struct at `ptr`.
+unsafe fn trace_fields(s: &abi::StructInfo, ptr: *const u8, handles: &mut Vec<GcPtr>) {
+    for (field_ty, offset) in s.field_types().iter().zip(s.field_offsets()) {
+        trace_value(field_ty, ptr.add(*offset as usize), handles);
+    }
+}
+
+/// Collects all garbage collected objects referenced by the fields of the active variant of the
+/// enum at `ptr`.
+unsafe fn trace_variant(e: &abi::EnumInfo, ptr: *const u8, handles: &mut Vec<GcPtr>) {
+    let discriminant = e.read_discriminant(ptr);
+    trace_fields(&e.variant_types()[discriminant], ptr, handles);
+}
+
+/// Collects all garbage collected objects referenced by `length` consecutive elements of type
+/// `ty`, starting at `ptr`.
+unsafe fn trace_elements(
+    ty: &abi::TypeInfo,
+    ptr: *const u8,
+    length: usize,
+    handles: &mut Vec<GcPtr>,
+) {
+    // Fundamental types never reference other objects
+    if ty.group.is_fundamental() {
+        return;
+    }
+
+    let stride = inline_layout(ty).pad_to_align().size();
+    for index in 0..length {
+        trace_value(ty, ptr.add(index * stride), handles);
     }
 }
 
@@ -121,11 +187,16 @@ impl memory::TypeMemory for UnsafeTypeInfo {
     }
 
     fn is_stack_allocated(&self) -> bool {
-        unsafe {
-            self.0
-                .as_ref()
-                .as_struct()
-                .map_or(true, |s| s.memory_kind == abi::StructMemoryKind::Value)
+        is_value_type(unsafe { self.0.as_ref() })
+    }
+
+    fn element_layout(&self) -> Option<Layout> {
+        let ty = unsafe { self.0.as_ref() };
+        if ty.group.is_string() {
+            // A string stores its UTF-8 encoded bytes the same way an array of `u8` would
+            Some(Layout::new::<u8>())
+        } else {
+            ty.as_array().map(|a| inline_layout(a.element_type()))
         }
     }
 }
@@ -134,10 +205,33 @@ impl gc::TypeTrace for UnsafeTypeInfo {
     type Trace = Trace;
 
     fn trace(&self, obj: GcPtr) -> Self::Trace {
+        let ty = unsafe { self.0.as_ref() };
+        let ptr = unsafe { obj.deref::<u8>() };
+
+        let mut handles = Vec::new();
+        if let Some(s) = ty.as_struct() {
+            unsafe { trace_fields(s, ptr, &mut handles) };
+        } else if let Some(a) = ty.as_array() {
+            // A garbage collected array stores its length in front of its elements
+            let element_ty = a.element_type();
+            let (_, offset) = memory::array_layout(inline_layout(element_ty), 0);
+            unsafe {
+                let length = *ptr.cast::<usize>();
+                trace_elements(element_ty, ptr.add(offset), length, &mut handles);
+            }
+        } else if let Some(e) = ty.as_enum() {
+            unsafe { trace_variant(e, ptr, &mut handles) };
+        } else if ty.group.is_function() {
+            // A closure stores its function pointer in front of the handle to its captured
+            // environment, which is null if the closure does not capture anything
+            let env = unsafe { *ptr.cast::<gc::RawGcPtr>().add(1) };
+            if !env.is_null() {
+                handles.push(env.into());
+            }
+        }
+
         Trace {
-            ty: *self,
-            obj,
-            index: 0,
+            handles: handles.into_iter(),
         }
     }
 }
@@ -10,11 +10,16 @@ mod macros;
 #[macro_use]
 mod garbage_collector;
 mod adt;
+mod array;
+mod closure;
 mod marshal;
+mod panic;
 mod reflection;
+mod string;
+mod tuple;
 
 use anyhow::Error;
-use garbage_collector::GarbageCollector;
+use garbage_collector::{GarbageCollector, GcPtr};
 use memory::gc::{self, GcRuntime};
 use notify::{DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
 use rustc_hash::FxHashMap;
@@ -34,11 +39,15 @@ use std::{
 };
 
 pub use crate::{
-    adt::{RootedStruct, StructRef},
+    adt::{EnumRef, RootedStruct, StructRef},
+    array::ArrayRef,
     assembly::Assembly,
+    closure::{ClosureRef, RootedClosure},
     garbage_collector::UnsafeTypeInfo,
     marshal::Marshal,
+    panic::{catch_panic, Panic, PanicLocation},
     reflection::{ArgumentReflection, ReturnTypeReflection},
+    string::StringRef,
 };
 pub use abi::IntoFunctionDefinition;
 
@@ -198,6 +207,60 @@ extern "C" fn new(
     handle.into()
 }
 
+extern "C" fn new_array(
+    type_info: *const abi::TypeInfo,
+    length: usize,
+    alloc_handle: *mut ffi::c_void,
+) -> *const *mut ffi::c_void {
+    // Safety: `new_array` is only called from within Mun assemblies' core logic, so we are
+    // guaranteed that the `Runtime` and its `GarbageCollector` still exist if this function is
+    // called, and will continue to do so for the duration of this function.
+    let allocator = unsafe { get_allocator(alloc_handle) };
+    // Safety: the Mun Compiler guarantees that `new_array` is never called with `ptr::null()`.
+    let type_info = UnsafeTypeInfo::new(unsafe { NonNull::new_unchecked(type_info as *mut _) });
+    let handle = allocator.alloc_array(type_info, length);
+
+    // Prevent destruction of the allocator
+    mem::forget(allocator);
+
+    handle.into()
+}
+
+extern "C" fn string_concat(
+    lhs: *const *mut ffi::c_void,
+    rhs: *const *mut ffi::c_void,
+    alloc_handle: *mut ffi::c_void,
+) -> *const *mut ffi::c_void {
+    // Safety: `string_concat` is only called from within Mun assemblies' core logic, so we are
+    // guaranteed that the `Runtime` and its `GarbageCollector` still exist if this function is
+    // called, and will continue to do so for the duration of this function.
+    let allocator = unsafe { get_allocator(alloc_handle) };
+    // Safety: the Mun Compiler guarantees that `string_concat` is only called with handles to
+    // garbage collected strings.
+    let (lhs, rhs): (GcPtr, GcPtr) = (lhs.into(), rhs.into());
+    let handle = unsafe {
+        string::alloc_string(
+            allocator.as_ref(),
+            allocator.ptr_type(lhs),
+            &[
+                string::string_as_str(lhs).as_bytes(),
+                string::string_as_str(rhs).as_bytes(),
+            ],
+        )
+    };
+
+    // Prevent destruction of the allocator
+    mem::forget(allocator);
+
+    handle.into()
+}
+
+extern "C" fn string_eq(lhs: *const *mut ffi::c_void, rhs: *const *mut ffi::c_void) -> bool {
+    // Safety: the Mun Compiler guarantees that `string_eq` is only called with handles to garbage
+    // collected strings.
+    unsafe { string::string_as_str(lhs.into()) == string::string_as_str(rhs.into()) }
+}
+
 impl Runtime {
     /// Constructs a new `Runtime` that loads the library at `library_path` and its
     /// dependencies. The `Runtime` contains a file watcher that is triggered with an interval
@@ -212,6 +275,36 @@ impl Runtime {
             new as extern "C" fn(*const abi::TypeInfo, *mut ffi::c_void) -> *const *mut ffi::c_void,
             "new",
         ));
+        options.user_functions.push(IntoFunctionDefinition::into(
+            new_array
+                as extern "C" fn(
+                    *const abi::TypeInfo,
+                    usize,
+                    *mut ffi::c_void,
+                ) -> *const *mut ffi::c_void,
+            "new_array",
+        ));
+        options.user_functions.push(IntoFunctionDefinition::into(
+            string_concat
+                as extern "C" fn(
+                    *const *mut ffi::c_void,
+                    *const *mut ffi::c_void,
+                    *mut ffi::c_void,
+                ) -> *const *mut ffi::c_void,
+            "string_concat",
+        ));
+        options.user_functions.push(IntoFunctionDefinition::into(
+            string_eq as extern "C" fn(*const *mut ffi::c_void, *const *mut ffi::c_void) -> bool,
+            "string_eq",
+        ));
+        options.user_functions.push(IntoFunctionDefinition::into(
+            panic::panic as extern "C" fn(*const u8, *const u8, u32, u32),
+            "panic",
+        ));
+        options.user_functions.push(IntoFunctionDefinition::into(
+            panic::panicking as extern "C" fn() -> bool,
+            "panicking",
+        ));
 
         let mut storages = Vec::with_capacity(options.user_functions.len());
         for (info, storage) in options.user_functions.into_iter() {
@@ -233,7 +326,9 @@ impl Runtime {
         Ok(runtime)
     }
 
-    /// Adds an assembly corresponding to the library at `library_path`.
+    /// Adds an assembly corresponding to the library at `library_path`, together with the
+    /// assemblies it depends on. The assemblies of a package can depend on each other, so all of
+    /// them are loaded before any of them is linked.
     fn add_assembly(&mut self, library_path: &Path) -> Result<(), Error> {
         let library_path = library_path.canonicalize()?;
         if self.assemblies.contains_key(&library_path) {
@@ -244,16 +339,77 @@ impl Runtime {
             .into());
         }
 
-        let mut assembly = Assembly::load(&library_path, self.gc.clone(), &self.dispatch_table)?;
-        for dependency in assembly.info().dependencies() {
-            self.add_assembly(Path::new(dependency))?;
+        // Load the assembly and all of its (transitive) dependencies. The path of a dependency is
+        // relative to the directory of the assembly that depends on it.
+        let mut loaded = HashMap::new();
+        let mut to_load = vec![library_path];
+        while let Some(library_path) = to_load.pop() {
+            if self.assemblies.contains_key(&library_path) || loaded.contains_key(&library_path) {
+                continue;
+            }
+
+            let assembly = Assembly::load(&library_path, self.gc.clone())?;
+            let library_dir = library_path.parent().unwrap();
+            for dependency in assembly.info().dependencies() {
+                to_load.push(library_dir.join(dependency).canonicalize()?);
+            }
+            loaded.insert(library_path, assembly);
+        }
+
+        for assembly in loaded.values() {
+            assembly.register(&mut self.dispatch_table);
+        }
+
+        // Ensure that all loaded assemblies can be linked safely.
+        for assembly in loaded.values() {
+            if let Err(e) = assembly.ensure_linkable(&self.dispatch_table) {
+                for assembly in loaded.values() {
+                    assembly.unregister(&mut self.dispatch_table);
+                }
+                return Err(e.into());
+            }
         }
-        assembly.link(&mut self.dispatch_table);
 
-        self.watcher
-            .watch(library_path.parent().unwrap(), RecursiveMode::NonRecursive)?;
+        let library_paths: Vec<PathBuf> = loaded.keys().cloned().collect();
+        for (library_path, mut assembly) in loaded {
+            assembly.link(&self.dispatch_table);
 
-        self.assemblies.insert(library_path, assembly);
+            self.watcher
+                .watch(library_path.parent().unwrap(), RecursiveMode::NonRecursive)?;
+
+            self.assemblies.insert(library_path, assembly);
+        }
+
+        // Track the functions that the new assemblies use from other assemblies, so reloading an
+        // assembly that no longer defines them fails.
+        for library_path in library_paths {
+            let dependencies: Vec<(String, abi::FunctionPrototype)> = self.assemblies
+                [&library_path]
+                .linked_fns()
+                .filter_map(|fn_prototype| {
+                    self.assemblies
+                        .values()
+                        .find(|provider| {
+                            provider
+                                .info()
+                                .symbols
+                                .functions()
+                                .iter()
+                                .any(|f| f.prototype.name() == fn_prototype.name())
+                        })
+                        .map(|provider| {
+                            let assembly_path = provider.info().symbols.path().to_string();
+                            (assembly_path, fn_prototype.clone())
+                        })
+                })
+                .collect();
+
+            for (assembly_path, fn_prototype) in dependencies {
+                let fn_path = fn_prototype.name().to_string();
+                self.dispatch_table
+                    .add_fn_dependency(assembly_path, fn_path, fn_prototype);
+            }
+        }
         Ok(())
     }
 
@@ -275,6 +431,20 @@ impl Runtime {
         None
     }
 
+    /// Retrieves the definition of the constant or static corresponding to `global_name`, if
+    /// available.
+    pub fn get_global(&self, global_name: &str) -> Option<&abi::GlobalDefinition> {
+        for assembly in self.assemblies.values() {
+            for global in assembly.info().symbols.globals().iter() {
+                if global.name() == global_name {
+                    return Some(global);
+                }
+            }
+        }
+
+        None
+    }
+
     /// Updates the state of the runtime. This includes checking for file changes, and reloading
     /// compiled assemblies.
     pub fn update(&mut self) -> bool {
@@ -290,6 +460,12 @@ impl Runtime {
                                 e
                             );
                         } else {
+                            // The functions of the reloaded assembly have moved, so the assemblies
+                            // that depend on them need to be linked again.
+                            for assembly in self.assemblies.values_mut() {
+                                assembly.link(&self.dispatch_table);
+                            }
+
                             println!(
                                 "Succesfully reloaded assembly: '{}'",
                                 path.to_string_lossy()
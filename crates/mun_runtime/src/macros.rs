@@ -18,6 +18,7 @@ macro_rules! invoke_fn_impl {
             /// the function invocation using the `Retriable` trait.
             pub struct $ErrName<'i, 's, $($T: ArgumentReflection + Marshal<'i>,)*> {
                 msg: String,
+                panic: Option<crate::Panic>,
                 function_name: &'s str,
                 $($Arg: $T,)*
                 input: core::marker::PhantomData<&'i ()>,
@@ -41,18 +42,27 @@ macro_rules! invoke_fn_impl {
                 }
             }
 
-            impl<'i, 's, $($T: ArgumentReflection + Marshal<'i>,)*> $ErrName<'i, 's, $($T,)*> {
+            impl<'i, 's, $($T: ArgumentReflection + Marshal<'i>,)*> $ErrName<'i, 's, $($T,)*>
+            where
+                $($T::MunType: Clone,)*
+            {
                 /// Constructs a new invocation error.
                 #[allow(clippy::too_many_arguments)]
                 pub fn new(err_msg: String, function_name: &'s str, $($Arg: $T),*) -> Self {
                     Self {
                         msg: err_msg,
+                        panic: None,
                         function_name,
                         $($Arg,)*
                         input: core::marker::PhantomData,
                     }
                 }
 
+                /// Returns the panic that aborted the invocation, if the error was caused by one.
+                pub fn panic(&self) -> Option<&crate::Panic> {
+                    self.panic.as_ref()
+                }
+
                 /// Retries a function invocation once, resulting in a potentially successful
                 /// invocation.
                 // FIXME: `unwrap_or_else` does not compile for `StructRef`, due to
@@ -60,7 +70,7 @@ macro_rules! invoke_fn_impl {
                 pub fn retry<'r, 'o, Output>(self, runtime: &'r mut Runtime) -> Result<Output, Self>
                 where
                     Output: 'o + ReturnTypeReflection + Marshal<'o>,
-                    'r: 'o,
+                    'r: 'i + 'o,
                 {
                     // Safety: The output of `retry_impl` is guaranteed to only contain a shared
                     // reference.
@@ -73,7 +83,7 @@ macro_rules! invoke_fn_impl {
                 pub fn wait<'r, 'o, Output>(mut self, runtime: &'r mut Runtime) -> Output
                 where
                     Output: 'o + ReturnTypeReflection + Marshal<'o>,
-                    'r: 'o,
+                    'r: 'i + 'o,
                 {
                     // Safety: The output of `retry_impl` is guaranteed to only contain a shared
                     // reference.
@@ -98,7 +108,7 @@ macro_rules! invoke_fn_impl {
                 unsafe fn retry_impl<'r, 'o, Output>(self, runtime: &'r Runtime) -> Result<Output, Self>
                 where
                     Output: 'o + ReturnTypeReflection + Marshal<'o>,
-                    'r: 'o,
+                    'r: 'i + 'o,
                 {
                     #[allow(clippy::cast_ref_to_mut)]
                     let runtime = &mut *(runtime as *const Runtime as *mut Runtime);
@@ -124,7 +134,8 @@ macro_rules! invoke_fn_impl {
                     $($Arg: $T,)*
                 ) -> core::result::Result<Output, $ErrName<'i, 's, $($T,)*>>
                 where
-                    'r: 'o,
+                    $($T::MunType: Clone,)*
+                    'r: 'i + 'o,
                 {
                     match runtime
                         .get_function_definition(function_name)
@@ -177,10 +188,25 @@ macro_rules! invoke_fn_impl {
                             let function: fn($($T::MunType),*) -> Output::MunType = unsafe {
                                 core::mem::transmute(function_info.fn_ptr)
                             };
-                            let result = function($($Arg.marshal_into()),*);
+                            $(let $Arg = $Arg.marshal_into(runtime);)*
 
-                            // Marshall the result
-                            return Ok(Marshal::marshal_from(result, runtime))
+                            // A panic aborts the invocation, e.g. on an arithmetic overflow. The
+                            // marshalled arguments are retained to be able to retry the invocation.
+                            let result = crate::catch_panic(|| function($($Arg.clone()),*));
+
+                            match result {
+                                // Marshall the result
+                                Ok(result) => Ok(Marshal::marshal_from(result, runtime)),
+                                Err(panic) => {
+                                    let mut err = $ErrName::new(
+                                        panic.to_string(),
+                                        function_name,
+                                        $($T::marshal_from($Arg, runtime)),*
+                                    );
+                                    err.panic = Some(panic);
+                                    Err(err)
+                                }
+                            }
                         }
                         Err(e) => Err($ErrName::new(e, function_name, $($Arg),*))
                     }
@@ -16,7 +16,7 @@ pub trait Marshal<'t>: Sized {
         'r: 't;
 
     /// Marshals itself into a `Marshalled` value (i.e. Rust -> Mun).
-    fn marshal_into(self) -> Self::MunType;
+    fn marshal_into<'r>(self, runtime: &'r Runtime) -> Self::MunType;
 
     /// Marshals the value at memory location `ptr` into a `Marshalled` value (i.e. Mun -> Rust).
     fn marshal_from_ptr<'r>(
@@ -29,5 +29,10 @@ pub trait Marshal<'t>: Sized {
         'r: 't;
 
     /// Marshals `value` to memory location `ptr` (i.e. Rust -> Mun).
-    fn marshal_to_ptr(value: Self, ptr: NonNull<Self::MunType>, type_info: Option<&abi::TypeInfo>);
+    fn marshal_to_ptr(
+        value: Self,
+        ptr: NonNull<Self::MunType>,
+        runtime: &Runtime,
+        type_info: Option<&abi::TypeInfo>,
+    );
 }
@@ -0,0 +1,94 @@
+use std::{cell::RefCell, ffi, fmt};
+
+/// The location in Mun source code at which the invocation of a function panicked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PanicLocation {
+    /// The path of the source file, relative to the source directory of the package
+    pub file: String,
+    /// The one-based line in `file`
+    pub line: u32,
+    /// The one-based column in `line`
+    pub column: u32,
+}
+
+impl fmt::Display for PanicLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// A panic that aborted the invocation of a function, e.g. because of an arithmetic overflow or
+/// an out-of-bounds array index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    message: String,
+    location: PanicLocation,
+}
+
+impl Panic {
+    /// Returns the reason of the panic.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the location in Mun source code at which the panic occurred.
+    pub fn location(&self) -> &PanicLocation {
+        &self.location
+    }
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.location)
+    }
+}
+
+impl std::error::Error for Panic {}
+
+thread_local! {
+    /// The panic that aborted the invocation of a Mun function on this thread, if any
+    static PANIC: RefCell<Option<Panic>> = RefCell::new(None);
+}
+
+/// Calls `f`, catching a panic of the Mun functions that it invokes.
+///
+/// Panics of Mun functions do not unwind the stack. Instead, a Mun function returns immediately
+/// after it or one of its callees panicked, leaving its return value undefined. As such, Mun
+/// functions must only be invoked through this function, which discards the return value of `f`
+/// if a panic occurred.
+pub fn catch_panic<F: FnOnce() -> R, R>(f: F) -> Result<R, Panic> {
+    let result = f();
+    match PANIC.with(|panic| panic.borrow_mut().take()) {
+        Some(panic) => Err(panic),
+        None => Ok(result),
+    }
+}
+
+/// The `panic` intrinsic, which is called by Mun code to abort the invocation of a function.
+pub(crate) extern "C" fn panic(message: *const u8, file: *const u8, line: u32, column: u32) {
+    // Safety: the Mun Compiler guarantees that `panic` is only called with null-terminated
+    // strings.
+    let (message, file) = unsafe {
+        (
+            ffi::CStr::from_ptr(message as *const _),
+            ffi::CStr::from_ptr(file as *const _),
+        )
+    };
+
+    // The panic is taken by `catch_panic`, after all Mun functions have returned
+    let panic = Panic {
+        message: message.to_string_lossy().into_owned(),
+        location: PanicLocation {
+            file: file.to_string_lossy().into_owned(),
+            line,
+            column,
+        },
+    };
+    PANIC.with(|current| *current.borrow_mut() = Some(panic));
+}
+
+/// The `panicking` intrinsic, which is called by Mun code after every call to a Mun function to
+/// check whether the callee panicked.
+pub(crate) extern "C" fn panicking() -> bool {
+    PANIC.with(|panic| panic.borrow().is_some())
+}
@@ -1,4 +1,4 @@
-use crate::{marshal::Marshal, Runtime, StructRef};
+use crate::{marshal::Marshal, ArrayRef, ClosureRef, EnumRef, Runtime, StructRef};
 use abi::HasStaticTypeInfo;
 use once_cell::sync::OnceCell;
 
@@ -8,7 +8,15 @@ pub fn equals_argument_type<'e, 'f, T: ArgumentReflection>(
     type_info: &'e abi::TypeInfo,
     arg: &'f T,
 ) -> Result<(), (&'e str, &'f str)> {
-    if type_info.guid != arg.type_guid(runtime) {
+    let arg_guid = arg.type_guid(runtime);
+
+    // A nullable type also accepts a reference to the struct it refers to, or `nil`
+    let is_nullable_match = type_info.as_nullable().map_or(false, |inner| {
+        inner.guid == arg_guid
+            || arg_guid == <Option<StructRef> as ReturnTypeReflection>::type_guid()
+    });
+
+    if type_info.guid != arg_guid && !is_nullable_match {
         Err((type_info.name(), arg.type_name(runtime)))
     } else {
         Ok(())
@@ -20,16 +28,36 @@ pub fn equals_return_type<T: ReturnTypeReflection>(
     type_info: &abi::TypeInfo,
 ) -> Result<(), (&str, &str)> {
     match type_info.group {
-        abi::TypeGroup::FundamentalTypes => {
+        abi::TypeGroup::FundamentalTypes | abi::TypeGroup::StringTypes => {
             if type_info.guid != T::type_guid() {
                 return Err((type_info.name(), T::type_name()));
             }
         }
         abi::TypeGroup::StructTypes => {
-            if <StructRef as ReturnTypeReflection>::type_guid() != T::type_guid() {
+            if !T::accepts_struct(type_info) {
                 return Err(("struct", T::type_name()));
             }
         }
+        abi::TypeGroup::ArrayTypes => {
+            if <ArrayRef as ReturnTypeReflection>::type_guid() != T::type_guid() {
+                return Err(("array", T::type_name()));
+            }
+        }
+        abi::TypeGroup::EnumTypes => {
+            if <EnumRef as ReturnTypeReflection>::type_guid() != T::type_guid() {
+                return Err(("enum", T::type_name()));
+            }
+        }
+        abi::TypeGroup::FunctionTypes => {
+            if <ClosureRef as ReturnTypeReflection>::type_guid() != T::type_guid() {
+                return Err(("closure", T::type_name()));
+            }
+        }
+        abi::TypeGroup::NullableTypes => {
+            if <Option<StructRef> as ReturnTypeReflection>::type_guid() != T::type_guid() {
+                return Err(("nullable struct", T::type_name()));
+            }
+        }
     }
     Ok(())
 }
@@ -41,6 +69,12 @@ pub trait ReturnTypeReflection: Sized {
 
     /// Retrieves the type's name.
     fn type_name() -> &'static str;
+
+    /// Returns whether a value of the struct type `type_info` can be marshalled to this type. By
+    /// default, only `StructRef` accepts structs.
+    fn accepts_struct(_type_info: &abi::TypeInfo) -> bool {
+        <StructRef as ReturnTypeReflection>::type_guid() == Self::type_guid()
+    }
 }
 
 /// A type to emulate dynamic typing across compilation units for statically typed values.
@@ -86,7 +120,7 @@ macro_rules! impl_primitive_type {
                     value
                 }
 
-                fn marshal_into<'r>(self) -> Self::MunType {
+                fn marshal_into<'r>(self, _runtime: &'r Runtime) -> Self::MunType {
                     self
                 }
 
@@ -107,6 +141,7 @@ macro_rules! impl_primitive_type {
                 fn marshal_to_ptr(
                     value: Self,
                     mut ptr: std::ptr::NonNull<Self::MunType>,
+                    _runtime: &Runtime,
                     _type_info: Option<&abi::TypeInfo>,
                 ) {
                     unsafe { *ptr.as_mut() = value };
@@ -142,7 +177,7 @@ impl<'t> Marshal<'t> for () {
         value
     }
 
-    fn marshal_into<'r>(self) -> Self::MunType {
+    fn marshal_into<'r>(self, _runtime: &'r Runtime) -> Self::MunType {
         self
     }
 
@@ -160,6 +195,7 @@ impl<'t> Marshal<'t> for () {
     fn marshal_to_ptr(
         _value: Self,
         mut ptr: std::ptr::NonNull<Self::MunType>,
+        _runtime: &Runtime,
         _type_info: Option<&abi::TypeInfo>,
     ) {
         unsafe { *ptr.as_mut() = () };
@@ -0,0 +1,299 @@
+use crate::garbage_collector::{GcPtr, UnsafeTypeInfo};
+use crate::{
+    marshal::Marshal,
+    reflection::{ArgumentReflection, ReturnTypeReflection},
+    Runtime,
+};
+use abi::HasStaticTypeInfo;
+use memory::gc::{GcRuntime, HasIndirectionPtr};
+use std::{
+    alloc::Layout,
+    ptr::{self, NonNull},
+    slice, str,
+};
+
+/// Represents a Mun string pointer.
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct RawString(GcPtr);
+
+impl RawString {
+    /// Returns a pointer to the string memory.
+    pub unsafe fn get_ptr(&self) -> *const u8 {
+        self.0.deref()
+    }
+}
+
+/// Wrapper for interoperability with a garbage collected Mun string (`string`). This is merely a
+/// reference to the Mun string, that will be garbage collected unless it is rooted.
+#[derive(Clone)]
+pub struct StringRef<'s> {
+    raw: RawString,
+    runtime: &'s Runtime,
+}
+
+impl<'s> StringRef<'s> {
+    /// Allocates a new Mun string that contains a copy of `value`.
+    pub fn new<'r>(runtime: &'r Runtime, value: &str) -> Self
+    where
+        'r: 's,
+    {
+        let raw = RawString(alloc_string(
+            runtime.gc(),
+            string_type_info(),
+            &[value.as_bytes()],
+        ));
+        Self { raw, runtime }
+    }
+
+    /// Creates a `StringRef` that wraps a raw Mun string.
+    fn from_raw<'r>(raw: RawString, runtime: &'r Runtime) -> Self
+    where
+        'r: 's,
+    {
+        Self { raw, runtime }
+    }
+
+    /// Consumes the `StringRef`, returning a raw Mun string.
+    pub fn into_raw(self) -> RawString {
+        self.raw
+    }
+
+    /// Returns the type information of the string.
+    pub fn type_info(&self) -> &abi::TypeInfo {
+        // Safety: The type returned from `ptr_type` is guaranteed to live at least as long as
+        // `Runtime` does not change. As the lifetime of `TypeInfo` is tied to the lifetime of
+        // `Runtime`, this is safe.
+        unsafe { &*self.runtime.gc.ptr_type(self.raw.0).into_inner().as_ptr() }
+    }
+
+    /// Returns the length of the string in bytes.
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns true if the string has a length of zero bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the contents of the string.
+    pub fn as_str(&self) -> &str {
+        // Safety: Mun strings always contain valid UTF-8 and `self.raw` refers to a string that is
+        // alive for as long as we hold a reference to the `Runtime`.
+        unsafe { string_as_str(self.raw.0) }
+    }
+}
+
+/// Returns the `TypeInfo` that is used to allocate strings from the host.
+pub(crate) fn string_type_info() -> UnsafeTypeInfo {
+    let type_info = <str as HasStaticTypeInfo>::type_info();
+    // Safety: `type_info` is a shared reference, so is guaranteed to not be `ptr::null()`.
+    UnsafeTypeInfo::new(unsafe { NonNull::new_unchecked(type_info as *const _ as *mut _) })
+}
+
+/// Allocates a garbage collected string of type `ty` that contains the concatenation of `parts`.
+pub(crate) fn alloc_string<G: GcRuntime<UnsafeTypeInfo> + ?Sized>(
+    gc: &G,
+    ty: UnsafeTypeInfo,
+    parts: &[&[u8]],
+) -> GcPtr {
+    let length = parts.iter().map(|part| part.len()).sum();
+    let mut handle = gc.alloc_array(ty, length);
+
+    // The text of a string is stored behind its length, just like the elements of an array
+    let (_, offset) = memory::array_layout(Layout::new::<u8>(), 0);
+    unsafe {
+        let mut dest = handle.deref_mut::<u8>().add(offset);
+        for part in parts {
+            ptr::copy_nonoverlapping(part.as_ptr(), dest, part.len());
+            dest = dest.add(part.len());
+        }
+    }
+
+    handle
+}
+
+/// Returns the contents of the garbage collected string referred to by `handle`.
+///
+/// # Safety
+///
+/// `handle` must refer to a garbage collected string that outlives `'a`.
+pub(crate) unsafe fn string_as_str<'a>(handle: GcPtr) -> &'a str {
+    let ptr = handle.deref::<u8>();
+    let length = *ptr.cast::<usize>();
+    let (_, offset) = memory::array_layout(Layout::new::<u8>(), 0);
+    str::from_utf8_unchecked(slice::from_raw_parts(ptr.add(offset), length))
+}
+
+impl<'s> ArgumentReflection for StringRef<'s> {
+    fn type_guid(&self, _runtime: &Runtime) -> abi::Guid {
+        <str as HasStaticTypeInfo>::type_info().guid
+    }
+
+    fn type_name(&self, _runtime: &Runtime) -> &str {
+        <str as HasStaticTypeInfo>::type_info().name()
+    }
+}
+
+impl<'s> ReturnTypeReflection for StringRef<'s> {
+    fn type_guid() -> abi::Guid {
+        <str as HasStaticTypeInfo>::type_info().guid
+    }
+
+    fn type_name() -> &'static str {
+        <str as HasStaticTypeInfo>::type_info().name()
+    }
+}
+
+impl<'s> Marshal<'s> for StringRef<'s> {
+    type MunType = RawString;
+
+    fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+    where
+        Self: 's,
+        'r: 's,
+    {
+        StringRef::from_raw(value, runtime)
+    }
+
+    fn marshal_into<'r>(self, _runtime: &'r Runtime) -> Self::MunType {
+        self.into_raw()
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'r Runtime,
+        _type_info: Option<&abi::TypeInfo>,
+    ) -> StringRef<'s>
+    where
+        Self: 's,
+        'r: 's,
+    {
+        // A string is always garbage collected, so `ptr` points to a `GcPtr`.
+        let gc_handle = unsafe { *ptr.cast::<GcPtr>().as_ptr() };
+        StringRef::from_raw(RawString(gc_handle), runtime)
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        _runtime: &Runtime,
+        _type_info: Option<&abi::TypeInfo>,
+    ) {
+        unsafe { *ptr.as_mut() = value.into_raw() };
+    }
+}
+
+impl ArgumentReflection for String {
+    fn type_guid(&self, _runtime: &Runtime) -> abi::Guid {
+        <str as HasStaticTypeInfo>::type_info().guid
+    }
+
+    fn type_name(&self, _runtime: &Runtime) -> &str {
+        <str as HasStaticTypeInfo>::type_info().name()
+    }
+}
+
+impl ReturnTypeReflection for String {
+    fn type_guid() -> abi::Guid {
+        <str as HasStaticTypeInfo>::type_info().guid
+    }
+
+    fn type_name() -> &'static str {
+        <str as HasStaticTypeInfo>::type_info().name()
+    }
+}
+
+impl<'t> Marshal<'t> for String {
+    type MunType = RawString;
+
+    fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+    where
+        Self: 't,
+        'r: 't,
+    {
+        StringRef::from_raw(value, runtime).as_str().to_owned()
+    }
+
+    fn marshal_into<'r>(self, runtime: &'r Runtime) -> Self::MunType {
+        self.as_str().marshal_into(runtime)
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        runtime: &'r Runtime,
+        type_info: Option<&abi::TypeInfo>,
+    ) -> Self
+    where
+        Self: 't,
+        'r: 't,
+    {
+        StringRef::marshal_from_ptr(ptr, runtime, type_info)
+            .as_str()
+            .to_owned()
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        ptr: NonNull<Self::MunType>,
+        runtime: &Runtime,
+        type_info: Option<&abi::TypeInfo>,
+    ) {
+        Marshal::marshal_to_ptr(value.as_str(), ptr, runtime, type_info)
+    }
+}
+
+impl<'a> ArgumentReflection for &'a str {
+    fn type_guid(&self, _runtime: &Runtime) -> abi::Guid {
+        <str as HasStaticTypeInfo>::type_info().guid
+    }
+
+    fn type_name(&self, _runtime: &Runtime) -> &str {
+        <str as HasStaticTypeInfo>::type_info().name()
+    }
+}
+
+impl<'t> Marshal<'t> for &'t str {
+    type MunType = RawString;
+
+    fn marshal_from<'r>(value: Self::MunType, _runtime: &'r Runtime) -> Self
+    where
+        Self: 't,
+        'r: 't,
+    {
+        // Safety: The string is alive for as long as we hold a reference to the `Runtime`.
+        unsafe { string_as_str(value.0) }
+    }
+
+    fn marshal_into<'r>(self, runtime: &'r Runtime) -> Self::MunType {
+        RawString(alloc_string(
+            runtime.gc(),
+            string_type_info(),
+            &[self.as_bytes()],
+        ))
+    }
+
+    fn marshal_from_ptr<'r>(
+        ptr: NonNull<Self::MunType>,
+        _runtime: &'r Runtime,
+        _type_info: Option<&abi::TypeInfo>,
+    ) -> Self
+    where
+        Self: 't,
+        'r: 't,
+    {
+        // Safety: A string is always garbage collected, so `ptr` points to a `GcPtr`. The string is
+        // alive for as long as we hold a reference to the `Runtime`.
+        unsafe { string_as_str(*ptr.cast::<GcPtr>().as_ptr()) }
+    }
+
+    fn marshal_to_ptr(
+        value: Self,
+        mut ptr: NonNull<Self::MunType>,
+        runtime: &Runtime,
+        _type_info: Option<&abi::TypeInfo>,
+    ) {
+        unsafe { *ptr.as_mut() = value.marshal_into(runtime) };
+    }
+}
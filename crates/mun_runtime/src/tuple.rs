@@ -0,0 +1,174 @@
+use crate::garbage_collector::UnsafeTypeInfo;
+use crate::{
+    adt::RawStruct,
+    marshal::Marshal,
+    reflection::{equals_return_type, ArgumentReflection, ReturnTypeReflection},
+    Runtime,
+};
+use memory::gc::{GcRuntime, HasIndirectionPtr};
+use once_cell::sync::OnceCell;
+use std::ptr::NonNull;
+
+/// The name of Rust tuples in reflection errors.
+const TUPLE_TYPE_NAME: &str = "tuple";
+
+/// Returns the `Guid` of Rust tuples, which is used when the type of a Mun tuple is unknown.
+fn tuple_guid() -> abi::Guid {
+    // TODO: Once `const_fn` lands, replace this with a const md5 hash
+    static GUID: OnceCell<abi::Guid> = OnceCell::new();
+    *GUID.get_or_init(|| abi::Guid(md5::compute(TUPLE_TYPE_NAME).0))
+}
+
+/// Retrieves the type information of the Mun tuple with elements of the types `element_names`,
+/// e.g. `(core::i32, core::f32)`. A tuple type is only available if it is used by an assembly.
+fn tuple_type_info<'r>(runtime: &'r Runtime, element_names: &[&str]) -> Option<&'r abi::TypeInfo> {
+    runtime.get_type_info(&format!("({})", element_names.join(", ")))
+}
+
+/// Returns a pointer to the element at `idx` of the tuple value stored at `ptr`.
+///
+/// # Safety
+///
+/// `ptr` must point to a tuple value that is described by `struct_info` and `idx` must be smaller
+/// than the number of elements of the tuple.
+unsafe fn element_ptr<T>(
+    ptr: NonNull<u8>,
+    struct_info: &abi::StructInfo,
+    idx: usize,
+) -> NonNull<T> {
+    let offset = *struct_info.field_offsets().get_unchecked(idx);
+    NonNull::new_unchecked(ptr.as_ptr().add(offset as usize).cast::<T>())
+}
+
+/// Implements marshalling of Rust tuples to and from Mun tuples. Mun tuples are value structs of
+/// which the fields are named after their index, so they cross the ABI as garbage collected
+/// structs.
+macro_rules! impl_tuple {
+    ($len:expr; $($idx:tt $T:ident),+) => {
+        impl<$($T: ArgumentReflection),+> ArgumentReflection for ($($T,)+) {
+            fn type_guid(&self, runtime: &Runtime) -> abi::Guid {
+                match tuple_type_info(runtime, &[$(self.$idx.type_name(runtime)),+]) {
+                    Some(type_info) => type_info.guid,
+                    None => tuple_guid(),
+                }
+            }
+
+            fn type_name<'r>(&'r self, runtime: &'r Runtime) -> &'r str {
+                match tuple_type_info(runtime, &[$(self.$idx.type_name(runtime)),+]) {
+                    Some(type_info) => type_info.name(),
+                    None => TUPLE_TYPE_NAME,
+                }
+            }
+        }
+
+        impl<$($T: ReturnTypeReflection),+> ReturnTypeReflection for ($($T,)+) {
+            fn type_name() -> &'static str {
+                TUPLE_TYPE_NAME
+            }
+
+            fn type_guid() -> abi::Guid {
+                tuple_guid()
+            }
+
+            fn accepts_struct(type_info: &abi::TypeInfo) -> bool {
+                let field_types = match type_info.as_struct() {
+                    Some(struct_info) => struct_info.field_types(),
+                    None => return false,
+                };
+                field_types.len() == $len
+                    $(&& equals_return_type::<$T>(field_types[$idx]).is_ok())+
+            }
+        }
+
+        impl<'t, $($T: ArgumentReflection + Marshal<'t>),+> Marshal<'t> for ($($T,)+) {
+            type MunType = RawStruct;
+
+            fn marshal_from<'r>(value: Self::MunType, runtime: &'r Runtime) -> Self
+            where
+                Self: 't,
+                'r: 't,
+            {
+                // Safety: The type returned from `ptr_type` is guaranteed to live at least as long
+                // as `Runtime` does not change. As we hold a shared reference to `Runtime`, this
+                // is safe.
+                let type_info = unsafe { &*runtime.gc().ptr_type(value.0).into_inner().as_ptr() };
+
+                // Safety: The memory pointer of a `RawStruct` is never null
+                let ptr = unsafe { NonNull::new_unchecked(value.get_ptr() as *mut u8) };
+                Self::marshal_from_ptr(ptr.cast(), runtime, Some(type_info))
+            }
+
+            fn marshal_into<'r>(self, runtime: &'r Runtime) -> Self::MunType {
+                let type_info = tuple_type_info(runtime, &[$(self.$idx.type_name(runtime)),+])
+                    .expect("tuple type is not used by any assembly");
+
+                // Create a new object using the runtime's intrinsic
+                let mut gc_handle = runtime.gc().alloc(
+                    // Safety: `type_info` is a shared reference, so is guaranteed to not be
+                    // `ptr::null()`.
+                    UnsafeTypeInfo::new(unsafe {
+                        NonNull::new_unchecked(type_info as *const abi::TypeInfo as *mut _)
+                    }),
+                );
+
+                // Safety: The memory pointer of a newly allocated object is never null
+                let ptr = unsafe { NonNull::new_unchecked(gc_handle.deref_mut::<u8>()) };
+                Self::marshal_to_ptr(self, ptr.cast(), runtime, Some(type_info));
+
+                RawStruct(gc_handle)
+            }
+
+            fn marshal_from_ptr<'r>(
+                ptr: NonNull<Self::MunType>,
+                runtime: &'r Runtime,
+                type_info: Option<&abi::TypeInfo>,
+            ) -> Self
+            where
+                Self: 't,
+                'r: 't,
+            {
+                // Safety: `type_info` is only `None` for the `()` type
+                let struct_info = type_info.unwrap().as_struct().unwrap();
+                let field_types = struct_info.field_types();
+
+                // A tuple is stored as a value, so `ptr` points to its elements
+                ($(
+                    $T::marshal_from_ptr(
+                        // Safety: The return type was checked to have `$len` elements
+                        unsafe { element_ptr(ptr.cast(), struct_info, $idx) },
+                        runtime,
+                        Some(field_types[$idx]),
+                    ),
+                )+)
+            }
+
+            fn marshal_to_ptr(
+                value: Self,
+                ptr: NonNull<Self::MunType>,
+                runtime: &Runtime,
+                type_info: Option<&abi::TypeInfo>,
+            ) {
+                // Safety: `type_info` is only `None` for the `()` type
+                let struct_info = type_info.unwrap().as_struct().unwrap();
+                let field_types = struct_info.field_types();
+
+                $(
+                    $T::marshal_to_ptr(
+                        value.$idx,
+                        // Safety: The argument type was checked to have `$len` elements
+                        unsafe { element_ptr(ptr.cast(), struct_info, $idx) },
+                        runtime,
+                        Some(field_types[$idx]),
+                    );
+                )+
+            }
+        }
+    };
+}
+
+impl_tuple!(1; 0 A);
+impl_tuple!(2; 0 A, 1 B);
+impl_tuple!(3; 0 A, 1 B, 2 C);
+impl_tuple!(4; 0 A, 1 B, 2 C, 3 D);
+impl_tuple!(5; 0 A, 1 B, 2 C, 3 D, 4 E);
+impl_tuple!(6; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
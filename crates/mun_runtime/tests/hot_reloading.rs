@@ -64,3 +64,84 @@ fn hotreload_struct_decl() {
     "#,
     );
 }
+
+#[test]
+fn hotreload_static_mut() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r#"
+    static mut COUNTER: i32 = 0;
+
+    pub fn increment() -> i32 {
+        COUNTER += 1;
+        COUNTER
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+    assert_invoke_eq!(i32, 1, driver, "increment");
+    assert_invoke_eq!(i32, 2, driver, "increment");
+
+    // The value of a static is retained, and cast if its type changed
+    let runtime = driver.runtime();
+    driver.update(
+        runtime.borrow(),
+        r#"
+    static mut COUNTER: i64 = 100;
+
+    pub fn increment() -> i64 {
+        COUNTER += 10;
+        COUNTER
+    }
+    "#,
+    );
+    assert_invoke_eq!(i64, 12, driver, "increment");
+}
+
+#[test]
+fn hotreload_static_mut_struct() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r#"
+    struct(value) State {
+        count: i32,
+    }
+
+    static mut STATE: State = State { count: 0 };
+
+    pub fn increment() -> i32 {
+        STATE.count += 1;
+        STATE.count
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+    assert_invoke_eq!(i32, 1, driver, "increment");
+    assert_invoke_eq!(i32, 2, driver, "increment");
+
+    // Existing fields are mapped, whereas inserted fields are initialized with the new initial
+    // value
+    let runtime = driver.runtime();
+    driver.update(
+        runtime.borrow(),
+        r#"
+    struct(value) State {
+        total: f64,
+        count: i32,
+    }
+
+    static mut STATE: State = State { total: 1.5, count: 100 };
+
+    pub fn increment() -> i32 {
+        STATE.count += 1;
+        STATE.count
+    }
+
+    pub fn total() -> f64 {
+        STATE.total
+    }
+    "#,
+    );
+    assert_invoke_eq!(i32, 3, driver, "increment");
+    assert_invoke_eq!(f64, 1.5, driver, "total");
+}
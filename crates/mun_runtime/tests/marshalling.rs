@@ -1,5 +1,9 @@
-use mun_runtime::{invoke_fn, ArgumentReflection, Marshal, ReturnTypeReflection, StructRef};
+use mun_runtime::{
+    invoke_fn, ArgumentReflection, ArrayRef, ClosureRef, EnumRef, Marshal, PanicLocation,
+    ReturnTypeReflection, StringRef, StructRef,
+};
 
+use compiler::{Config, OptimizationLevel};
 use mun_test::CompileAndRunTestDriver;
 
 #[macro_use]
@@ -113,6 +117,123 @@ fn booleans() {
     assert_invoke_eq!(bool, true, driver, "greater_equalf", 64f64, 64f64);
 }
 
+#[test]
+fn operators() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    struct Foo { a: i32, name: string }
+    struct(value) Bar(f64, Foo);
+
+    pub fn foo_eq(a: i32, b: i32) -> bool {
+        Foo { a: a, name: "foo" } == Foo { a: b, name: "foo" }
+    }
+    pub fn bar_ne(a: f64, b: f64) -> bool {
+        let foo = Foo { a: 1, name: "bar" };
+        Bar(a, foo) != Bar(b, foo)
+    }
+    pub fn tuple_eq(a: i32, b: bool) -> bool {
+        (a, b, "tuple") == (1, true, "tuple")
+    }
+    pub fn bit_not(a: u8) -> u8 {
+        !a
+    }
+    pub fn update_fields(a: i32) -> i32 {
+        let foo = Foo { a: a, name: "x" };
+        foo.a += 2;
+        foo.a <<= 1;
+        foo.name += "y";
+        if foo.name == "xy" { foo.a } else { 0 }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(bool, true, driver, "foo_eq", 1i32, 1i32);
+    assert_invoke_eq!(bool, false, driver, "foo_eq", 1i32, 2i32);
+    assert_invoke_eq!(bool, false, driver, "bar_ne", 1f64, 1f64);
+    assert_invoke_eq!(bool, true, driver, "bar_ne", 1f64, 2f64);
+    assert_invoke_eq!(bool, true, driver, "tuple_eq", 1i32, true);
+    assert_invoke_eq!(bool, false, driver, "tuple_eq", 1i32, false);
+    assert_invoke_eq!(u8, 0xf0, driver, "bit_not", 0x0fu8);
+    assert_invoke_eq!(i32, 10, driver, "update_fields", 3i32);
+}
+
+#[test]
+fn overloaded_operators() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    struct(value) Vec3 { x: f64, y: f64, z: f64 }
+    struct Money { cents: i64 }
+
+    impl Vec3 {
+        fn add(self, other: Self) -> Self {
+            Vec3 { x: self.x + other.x, y: self.y + other.y, z: self.z + other.z }
+        }
+        fn mul(self, factor: f64) -> Self {
+            Vec3 { x: self.x * factor, y: self.y * factor, z: self.z * factor }
+        }
+        fn eq(self, other: Self) -> bool {
+            self.x == other.x && self.y == other.y && self.z == other.z
+        }
+    }
+
+    impl Money {
+        fn sub(self, other: Self) -> Self {
+            Money { cents: self.cents - other.cents }
+        }
+        fn lt(self, other: Self) -> bool {
+            self.cents < other.cents
+        }
+    }
+
+    trait Add {
+        fn add(self, other: Self) -> Self;
+    }
+
+    impl Add for Money {
+        fn add(self, other: Self) -> Self {
+            Money { cents: self.cents + other.cents }
+        }
+    }
+
+    fn double<T: Add>(value: T) -> T {
+        value + value
+    }
+
+    pub fn vec_sum(x: f64) -> f64 {
+        let a = Vec3 { x: x, y: 2.0, z: 3.0 };
+        let mut b = a + a * 2.0;
+        b += Vec3 { x: 1.0, y: 1.0, z: 1.0 };
+        b.x + b.y + b.z
+    }
+    pub fn updated_element(x: f64) -> f64 {
+        let mut points = [Vec3 { x: 0.0, y: 0.0, z: 0.0 }, Vec3 { x: x, y: 0.0, z: 0.0 }];
+        let mut updates = 0;
+        points[{ updates += 1; 1 }] += Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        points[1].x + points[1].y + points[1].z + updates as f64 * 100.0
+    }
+    pub fn vec_ne(x: f64) -> bool {
+        Vec3 { x: x, y: 0.0, z: 0.0 } != Vec3 { x: 1.0, y: 0.0, z: 0.0 }
+    }
+    pub fn money_left(budget: i64, price: i64) -> i64 {
+        let left = Money { cents: budget } - Money { cents: price };
+        let zero = Money { cents: 0 };
+        if left < zero { 0 } else { double(left).cents }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(f64, 21.0, driver, "vec_sum", 1f64);
+    assert_invoke_eq!(f64, 107.0, driver, "updated_element", 1f64);
+    assert_invoke_eq!(bool, false, driver, "vec_ne", 1f64);
+    assert_invoke_eq!(bool, true, driver, "vec_ne", 2f64);
+    assert_invoke_eq!(i64, 60, driver, "money_left", 100i64, 70i64);
+    assert_invoke_eq!(i64, 0, driver, "money_left", 50i64, 70i64);
+}
+
 #[test]
 fn fibonacci() {
     let driver = CompileAndRunTestDriver::new(
@@ -139,9 +260,9 @@ fn fibonacci_loop() {
     let driver = CompileAndRunTestDriver::new(
         r#"
     pub fn fibonacci(n:i64)->i64 {
-        let a = 0;
-        let b = 1;
-        let i = 1;
+        let mut a = 0;
+        let mut b = 1;
+        let mut i = 1;
         loop {
             if i > n {
                 return a
@@ -168,9 +289,9 @@ fn fibonacci_loop_break() {
     let driver = CompileAndRunTestDriver::new(
         r#"
     pub fn fibonacci(n:i64)->i64 {
-        let a = 0;
-        let b = 1;
-        let i = 1;
+        let mut a = 0;
+        let mut b = 1;
+        let mut i = 1;
         loop {
             if i > n {
                 break a;
@@ -197,9 +318,9 @@ fn fibonacci_while() {
     let driver = CompileAndRunTestDriver::new(
         r#"
     pub fn fibonacci(n:i64)->i64 {
-        let a = 0;
-        let b = 1;
-        let i = 1;
+        let mut a = 0;
+        let mut b = 1;
+        let mut i = 1;
         while i <= n {
             let sum = a + b;
             a = b;
@@ -219,6 +340,601 @@ fn fibonacci_while() {
     assert_invoke_eq!(i64, 46368, driver, "fibonacci", 24i64);
 }
 
+#[test]
+fn fibonacci_for() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn fibonacci(n:i64)->i64 {
+        let mut a = 0;
+        let mut b = 1;
+        for _ in 1..=n {
+            let sum = a + b;
+            a = b;
+            b = sum;
+        }
+        a
+    }
+
+    pub fn sum_exclusive(start:i32, end:i32)->i32 {
+        let mut sum = 0;
+        for i in start..end {
+            sum += i;
+        }
+        sum
+    }
+
+    pub fn count_to_max(start:u8)->u32 {
+        let mut count = 0;
+        for _ in start..=255 {
+            count += 1;
+        }
+        count
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i64, 5, driver, "fibonacci", 5i64);
+    assert_invoke_eq!(i64, 89, driver, "fibonacci", 11i64);
+    assert_invoke_eq!(i64, 46368, driver, "fibonacci", 24i64);
+    assert_invoke_eq!(i32, 45, driver, "sum_exclusive", 0i32, 10i32);
+    assert_invoke_eq!(i32, 0, driver, "sum_exclusive", 10i32, 0i32);
+    assert_invoke_eq!(u32, 6, driver, "count_to_max", 250u8);
+}
+
+#[test]
+fn continue_and_labeled_loops() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn sum_odd(n:i32)->i32 {
+        let mut sum = 0;
+        for i in 0..n {
+            if i % 2 == 0 {
+                continue;
+            }
+            sum += i;
+        }
+        sum
+    }
+
+    pub fn count_down(mut n:i32)->i32 {
+        let mut steps = 0;
+        while n > 0 {
+            n -= 1;
+            if n == 3 {
+                continue;
+            }
+            steps += 1;
+        }
+        steps
+    }
+
+    pub fn count_pairs(n:i32)->i32 {
+        let mut count = 0;
+        'outer: for a in 0..n {
+            for b in 0..n {
+                if b > a {
+                    continue 'outer;
+                }
+                count += 1;
+            }
+        }
+        count
+    }
+
+    pub fn find_pair(sum:i32)->i32 {
+        let mut a = 0;
+        'search: loop {
+            let mut b = 0;
+            loop {
+                if a + b == sum {
+                    break 'search a * 10 + b;
+                }
+                if b == a {
+                    break;
+                }
+                b += 1;
+            }
+            a += 1;
+        }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 25, driver, "sum_odd", 10i32);
+    assert_invoke_eq!(i32, 4, driver, "count_down", 5i32);
+    assert_invoke_eq!(i32, 10, driver, "count_pairs", 4i32);
+    assert_invoke_eq!(i32, 22, driver, "find_pair", 4i32);
+    assert_invoke_eq!(i32, 54, driver, "find_pair", 9i32);
+}
+
+#[test]
+fn arrays() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn sum_fixed() -> i32 {
+        let a: [i32; 4] = [1, 2, 3, 4];
+        let mut sum = 0;
+        for i in 0..a.len() {
+            sum += a[i];
+        }
+        sum
+    }
+
+    pub fn assign_fixed(value: i32) -> i32 {
+        let mut a = [0, 0, 0];
+        a[1] = value;
+        a[0] + a[1] + a[2]
+    }
+
+    pub fn new_array(a: i64, b: i64) -> [i64] {
+        [a, b, a + b]
+    }
+
+    pub fn array_len(a: [i64]) -> usize {
+        a.len()
+    }
+
+    pub fn array_get(a: [i64], index: usize) -> i64 {
+        a[index]
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 10, driver, "sum_fixed");
+    assert_invoke_eq!(i32, 5, driver, "assign_fixed", 5i32);
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let array: ArrayRef = invoke_fn!(runtime_ref, "new_array", 3i64, 4i64).unwrap();
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.get::<i64>(0), Ok(3));
+    assert_eq!(array.get::<i64>(2), Ok(7));
+    assert!(array.get::<i64>(3).is_err());
+    assert!(array.get::<f64>(0).is_err());
+
+    let len: usize = invoke_fn!(runtime_ref, "array_len", array.clone()).unwrap();
+    assert_eq!(len, 3);
+    let value: i64 = invoke_fn!(runtime_ref, "array_get", array, 1usize).unwrap();
+    assert_eq!(value, 4);
+}
+
+#[test]
+fn enums() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub enum Shape {
+        Empty,
+        Circle(f64),
+        Rect(f64, f64),
+    }
+
+    pub fn new_rect(width: f64, height: f64) -> Shape {
+        Shape::Rect(width, height)
+    }
+
+    pub fn area(shape: Shape) -> f64 {
+        match shape {
+            Shape::Empty => 0.0,
+            Shape::Circle(radius) => 3.0 * radius * radius,
+            Shape::Rect(width, height) => width * height,
+        }
+    }
+
+    pub fn classify(value: i32) -> i32 {
+        match value {
+            0 => 10,
+            1 => 20,
+            _ => 30,
+        }
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let rect: EnumRef = invoke_fn!(runtime_ref, "new_rect", 2.0f64, 3.0f64).unwrap();
+    assert_eq!(rect.discriminant(), 2);
+    assert_eq!(rect.variant_name(), "Rect");
+    assert_eq!(rect.get::<f64>(0), Ok(2.0));
+    assert_eq!(rect.get::<f64>(1), Ok(3.0));
+    assert!(rect.get::<f64>(2).is_err());
+    assert!(rect.get::<i64>(0).is_err());
+
+    let area: f64 = invoke_fn!(runtime_ref, "area", rect).unwrap();
+    assert_eq!(area, 6.0);
+
+    let class: i32 = invoke_fn!(runtime_ref, "classify", 1i32).unwrap();
+    assert_eq!(class, 20);
+    let class: i32 = invoke_fn!(runtime_ref, "classify", 5i32).unwrap();
+    assert_eq!(class, 30);
+}
+
+#[test]
+fn if_let_and_while_let() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub enum Shape {
+        Empty,
+        Circle(f64),
+        Rect(f64, f64),
+    }
+
+    pub fn new_rect(width: f64, height: f64) -> Shape {
+        Shape::Rect(width, height)
+    }
+
+    pub fn width(shape: Shape) -> f64 {
+        if let Shape::Rect(width, _) = shape {
+            width
+        } else {
+            0.0
+        }
+    }
+
+    pub fn count_down(n: i32) -> i32 {
+        let mut steps = 0;
+        let mut state = (n, n > 0);
+        while let (i, true) = state {
+            steps += 1;
+            state = (i - 1, i > 1);
+        }
+        steps
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let rect: EnumRef = invoke_fn!(runtime_ref, "new_rect", 2.0f64, 3.0f64).unwrap();
+    let width: f64 = invoke_fn!(runtime_ref, "width", rect).unwrap();
+    assert_eq!(width, 2.0);
+
+    let steps: i32 = invoke_fn!(runtime_ref, "count_down", 4i32).unwrap();
+    assert_eq!(steps, 4);
+    let steps: i32 = invoke_fn!(runtime_ref, "count_down", -1i32).unwrap();
+    assert_eq!(steps, 0);
+}
+
+#[test]
+fn logic_op_short_circuit() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn ratio_above(a: i32, b: i32, limit: i32) -> bool {
+        b != 0 && a / b > limit
+    }
+
+    pub fn zero_or_ratio_above(a: i32, b: i32, limit: i32) -> bool {
+        b == 0 || a / b > limit
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    // The division by zero on the right-hand side is never evaluated
+    let result: bool = invoke_fn!(runtime_ref, "ratio_above", 6i32, 0i32, 2i32).unwrap();
+    assert!(!result);
+    let result: bool = invoke_fn!(runtime_ref, "zero_or_ratio_above", 6i32, 0i32, 2i32).unwrap();
+    assert!(result);
+
+    let result: bool = invoke_fn!(runtime_ref, "ratio_above", 6i32, 2i32, 2i32).unwrap();
+    assert!(result);
+    let result: bool = invoke_fn!(runtime_ref, "zero_or_ratio_above", 6i32, 3i32, 2i32).unwrap();
+    assert!(!result);
+}
+
+#[test]
+fn nullable_structs() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Node {
+        value: i32,
+        next: ?Node,
+    }
+
+    pub fn new_node(value: i32, next: ?Node) -> Node {
+        Node { value, next }
+    }
+
+    pub fn next(node: Node) -> ?Node {
+        node.next
+    }
+
+    pub fn sum(list: ?Node) -> i32 {
+        let mut total = 0;
+        let mut current = list;
+        while let node = current {
+            total += node.value;
+            current = node.next;
+        }
+        total
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let last: StructRef = invoke_fn!(runtime_ref, "new_node", 3i32, None::<StructRef>).unwrap();
+    let mut list: StructRef =
+        invoke_fn!(runtime_ref, "new_node", 2i32, Some(last.clone())).unwrap();
+
+    let next: Option<StructRef> = invoke_fn!(runtime_ref, "next", last.clone()).unwrap();
+    assert!(next.is_none());
+    let next = list.get::<Option<StructRef>>("next").unwrap();
+    assert_eq!(next.unwrap().get::<i32>("value"), Ok(3));
+
+    let sum: i32 = invoke_fn!(runtime_ref, "sum", Some(list.clone())).unwrap();
+    assert_eq!(sum, 5);
+    let sum: i32 = invoke_fn!(runtime_ref, "sum", None::<StructRef>).unwrap();
+    assert_eq!(sum, 0);
+
+    // A struct can be passed where a nullable struct is expected
+    list.set("next", None::<StructRef>).unwrap();
+    let sum: i32 = invoke_fn!(runtime_ref, "sum", list.clone()).unwrap();
+    assert_eq!(sum, 2);
+    assert!(list.set("next", 1i32).is_err());
+}
+
+#[test]
+fn methods() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Vector2 {
+        x: f64,
+        y: f64,
+    }
+
+    impl Vector2 {
+        pub fn new(x: f64, y: f64) -> Self {
+            Vector2 { x, y }
+        }
+
+        pub fn dot(self, other: Self) -> f64 {
+            self.x * other.x + self.y * other.y
+        }
+
+        pub fn length_squared(self) -> f64 {
+            self.dot(self)
+        }
+    }
+
+    pub fn unit_x() -> Vector2 {
+        Vector2::new(1.0, 0.0)
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let a: StructRef = invoke_fn!(runtime_ref, "Vector2::new", 3.0f64, 4.0f64).unwrap();
+    assert_eq!(a.get::<f64>("x"), Ok(3.0));
+    assert_eq!(a.get::<f64>("y"), Ok(4.0));
+
+    let length_squared: f64 = invoke_fn!(runtime_ref, "Vector2::length_squared", a).unwrap();
+    assert_eq!(length_squared, 25.0);
+
+    let a: StructRef = invoke_fn!(runtime_ref, "Vector2::new", 3.0f64, 4.0f64).unwrap();
+    let b: StructRef = invoke_fn!(runtime_ref, "unit_x").unwrap();
+    let dot: f64 = invoke_fn!(runtime_ref, "Vector2::dot", a, b).unwrap();
+    assert_eq!(dot, 3.0);
+}
+
+#[test]
+fn generics() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Pair<T, U> {
+        a: T,
+        b: U,
+    }
+
+    fn select<T>(cond: bool, a: T, b: T) -> T {
+        if cond { a } else { b }
+    }
+
+    fn swap<T, U>(pair: Pair<T, U>) -> Pair<U, T> {
+        Pair { a: pair.b, b: pair.a }
+    }
+
+    pub fn max_i32(a: i32, b: i32) -> i32 {
+        select(a > b, a, b)
+    }
+
+    pub fn max_f64(a: f64, b: f64) -> f64 {
+        select(a > b, a, b)
+    }
+
+    pub fn swapped(a: i32, b: f64) -> Pair<f64, i32> {
+        swap(Pair { a, b })
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let max: i32 = invoke_fn!(runtime_ref, "max_i32", 3i32, 7i32).unwrap();
+    assert_eq!(max, 7);
+
+    let max: f64 = invoke_fn!(runtime_ref, "max_f64", 2.5f64, -1.0f64).unwrap();
+    assert_eq!(max, 2.5);
+
+    let pair: StructRef = invoke_fn!(runtime_ref, "swapped", 1i32, 2.0f64).unwrap();
+    assert_eq!(pair.get::<f64>("a"), Ok(2.0));
+    assert_eq!(pair.get::<i32>("b"), Ok(1));
+}
+
+#[test]
+fn traits() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    trait Shape {
+        fn area(self) -> f64;
+        fn scaled_area(self, factor: f64) -> f64 {
+            self.area() * factor * factor
+        }
+    }
+
+    pub struct Square { side: f64 }
+    pub struct Circle { radius: f64 }
+
+    impl Shape for Square {
+        fn area(self) -> f64 { self.side * self.side }
+    }
+
+    impl Shape for Circle {
+        fn area(self) -> f64 { 3.0 * self.radius * self.radius }
+        fn scaled_area(self, factor: f64) -> f64 { 0.0 }
+    }
+
+    fn sum_areas<A: Shape, B: Shape>(a: A, b: B) -> f64 {
+        a.area() + b.area()
+    }
+
+    pub fn square_area(side: f64) -> f64 {
+        let square = Square { side };
+        square.area()
+    }
+
+    pub fn scaled_square_area(side: f64, factor: f64) -> f64 {
+        let square = Square { side };
+        square.scaled_area(factor)
+    }
+
+    pub fn scaled_circle_area(radius: f64, factor: f64) -> f64 {
+        let circle = Circle { radius };
+        circle.scaled_area(factor)
+    }
+
+    pub fn total_area(side: f64, radius: f64) -> f64 {
+        sum_areas(Square { side }, Circle { radius })
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let area: f64 = invoke_fn!(runtime_ref, "square_area", 2.0f64).unwrap();
+    assert_eq!(area, 4.0);
+
+    let area: f64 = invoke_fn!(runtime_ref, "scaled_square_area", 2.0f64, 3.0f64).unwrap();
+    assert_eq!(area, 36.0);
+
+    let area: f64 = invoke_fn!(runtime_ref, "scaled_circle_area", 1.0f64, 3.0f64).unwrap();
+    assert_eq!(area, 0.0);
+
+    let area: f64 = invoke_fn!(runtime_ref, "total_area", 2.0f64, 1.0f64).unwrap();
+    assert_eq!(area, 7.0);
+}
+
+#[test]
+fn strings() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Player {
+        name: string,
+        score: i32,
+    }
+
+    pub fn greet(name: string) -> string {
+        let mut greeting = "Hello, ";
+        greeting += name;
+        greeting + "!"
+    }
+
+    pub fn name_len(name: string) -> usize {
+        name.len()
+    }
+
+    pub fn is_admin(name: string) -> bool {
+        name == "admin"
+    }
+
+    pub fn is_not_admin(name: string) -> bool {
+        name != "admin"
+    }
+
+    pub fn rank(name: string) -> i32 {
+        match name {
+            "admin" => 0,
+            "moderator" => 1,
+            _ => 2,
+        }
+    }
+
+    pub fn new_player(name: string) -> Player {
+        Player { name, score: 0 }
+    }
+
+    pub fn escaped() -> string {
+        "tab\tquote\"\n"
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let greeting: String = invoke_fn!(runtime_ref, "greet", "Mun").unwrap();
+    assert_eq!(greeting, "Hello, Mun!");
+    let greeting: StringRef = invoke_fn!(runtime_ref, "greet", String::from("world")).unwrap();
+    assert_eq!(greeting.as_str(), "Hello, world!");
+    assert_eq!(greeting.len(), 13);
+
+    let len: usize = invoke_fn!(runtime_ref, "name_len", greeting).unwrap();
+    assert_eq!(len, 13);
+    let len: usize = invoke_fn!(runtime_ref, "name_len", "").unwrap();
+    assert_eq!(len, 0);
+    let len: usize = invoke_fn!(runtime_ref, "name_len", "ü").unwrap();
+    assert_eq!(len, 2);
+
+    assert_invoke_eq!(bool, true, driver, "is_admin", "admin");
+    assert_invoke_eq!(bool, false, driver, "is_admin", "Admin");
+    assert_invoke_eq!(bool, true, driver, "is_not_admin", "Admin");
+    assert_invoke_eq!(i32, 0, driver, "rank", "admin");
+    assert_invoke_eq!(i32, 1, driver, "rank", "moderator");
+    assert_invoke_eq!(i32, 2, driver, "rank", "player");
+
+    let name = StringRef::new(&runtime_ref, "Bob");
+    let mut player: StructRef = invoke_fn!(runtime_ref, "new_player", name).unwrap();
+    assert_eq!(player.get::<String>("name"), Ok(String::from("Bob")));
+    player.set("name", "Alice").unwrap();
+    assert_eq!(player.get::<StringRef>("name").unwrap().as_str(), "Alice");
+    assert!(player.set("score", "Alice").is_err());
+
+    let escaped: String = invoke_fn!(runtime_ref, "escaped").unwrap();
+    assert_eq!(escaped, "tab\tquote\"\n");
+}
+
 #[test]
 fn true_is_true() {
     let driver = CompileAndRunTestDriver::new(
@@ -728,3 +1444,391 @@ fn signed_and_unsigned_rem() {
     assert_invoke_eq!(i32, -2, driver, "signed");
     assert_invoke_eq!(i32, 2, driver, "unsigned");
 }
+
+#[test]
+fn casts() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn float_to_int(a: f32) -> i32 {
+        a as i32
+    }
+
+    pub fn float_to_unsigned(a: f64) -> u8 {
+        a as u8
+    }
+
+    pub fn narrow(a: i32) -> u8 {
+        a as u8
+    }
+
+    pub fn widen(a: i8) -> u64 {
+        a as u64
+    }
+
+    pub fn int_to_float(a: u64) -> f32 {
+        a as f32
+    }
+
+    pub fn float_to_float(a: f64) -> f32 {
+        a as f32
+    }
+
+    pub fn bool_to_int(a: bool) -> i32 {
+        a as i32
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    // Floating-point values are truncated and saturate at the bounds of the integer type
+    assert_invoke_eq!(i32, 3, driver, "float_to_int", 3.7f32);
+    assert_invoke_eq!(i32, -3, driver, "float_to_int", -3.7f32);
+    assert_invoke_eq!(i32, i32::MAX, driver, "float_to_int", 1e10f32);
+    assert_invoke_eq!(i32, i32::MIN, driver, "float_to_int", -1e10f32);
+    assert_invoke_eq!(i32, 0, driver, "float_to_int", f32::NAN);
+    assert_invoke_eq!(u8, 255, driver, "float_to_unsigned", 300.0f64);
+    assert_invoke_eq!(u8, 0, driver, "float_to_unsigned", -1.0f64);
+
+    // Integers are truncated or extended based on the signedness of their original type
+    assert_invoke_eq!(u8, 44, driver, "narrow", 300i32);
+    assert_invoke_eq!(u8, 255, driver, "narrow", -1i32);
+    assert_invoke_eq!(u64, u64::MAX, driver, "widen", -1i8);
+
+    assert_invoke_eq!(f32, 16777216.0, driver, "int_to_float", 16777217u64);
+    assert_invoke_eq!(f32, 0.1f64 as f32, driver, "float_to_float", 0.1f64);
+    assert_invoke_eq!(i32, 1, driver, "bool_to_int", true);
+    assert_invoke_eq!(i32, 0, driver, "bool_to_int", false);
+}
+
+#[test]
+fn closures() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    pub fn adder(a: i32) -> fn(i32) -> i32 {
+        |x| x + a
+    }
+
+    pub fn doubler() -> fn(i32) -> i32 {
+        double
+    }
+
+    pub fn apply(f: fn(i32) -> i32, x: i32) -> i32 {
+        f(x)
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let add: ClosureRef = invoke_fn!(runtime_ref, "adder", 3i32).unwrap();
+    assert_eq!(add.signature().arg_types().len(), 1);
+    assert_eq!(add.invoke1::<i32, i32>(4).unwrap(), 7);
+    assert!(add.invoke1::<f32, i32>(4.0).is_err());
+    assert!(add.invoke0::<i32>().is_err());
+    assert!(add.invoke1::<i32, f32>(4).is_err());
+
+    let double: ClosureRef = invoke_fn!(runtime_ref, "doubler").unwrap();
+    assert_eq!(double.invoke1::<i32, i32>(4).unwrap(), 8);
+
+    let result: i32 = invoke_fn!(runtime_ref, "apply", add.clone(), 5i32).unwrap();
+    assert_eq!(result, 8);
+    let result: i32 = invoke_fn!(runtime_ref, "apply", double, 5i32).unwrap();
+    assert_eq!(result, 10);
+
+    // A rooted closure keeps its captured variables alive
+    let add = add.root(driver.runtime());
+    assert_eq!(runtime_ref.gc_collect(), true);
+    let add = unsafe { add.as_ref(&runtime_ref) };
+    assert_eq!(add.invoke1::<i32, i32>(1).unwrap(), 4);
+}
+
+#[test]
+fn tuples() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Foo {
+        a: i32,
+    }
+
+    pub fn divmod(a: i32, b: i32) -> (i32, i32) {
+        (a / b, a % b)
+    }
+
+    pub fn swap(pair: (i32, f32)) -> (f32, i32) {
+        let (a, b) = pair;
+        (b, a)
+    }
+
+    pub fn first(pair: (Foo, bool)) -> i32 {
+        pair.0.a
+    }
+
+    pub fn new_pair(a: i32) -> (Foo, bool) {
+        (Foo { a }, a > 0)
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let result: (i32, i32) = invoke_fn!(runtime_ref, "divmod", 7i32, 2i32).unwrap();
+    assert_eq!(result, (3, 1));
+
+    let result: (f32, i32) = invoke_fn!(runtime_ref, "swap", (4i32, 2.5f32)).unwrap();
+    assert_eq!(result, (2.5, 4));
+
+    // Specify invalid argument type
+    let result: Result<(f32, i32), _> = invoke_fn!(runtime_ref, "swap", (4i32, 2.5f64));
+    assert!(result.is_err());
+
+    // Specify invalid return types
+    let result: Result<(i32, f32), _> = invoke_fn!(runtime_ref, "divmod", 7i32, 2i32);
+    assert!(result.is_err());
+    let result: Result<(i32,), _> = invoke_fn!(runtime_ref, "divmod", 7i32, 2i32);
+    assert!(result.is_err());
+
+    // Tuples can contain structs
+    let (foo, positive): (StructRef, bool) = invoke_fn!(runtime_ref, "new_pair", 5i32).unwrap();
+    assert_eq!(foo.get::<i32>("a").unwrap(), 5);
+    assert!(positive);
+
+    let result: i32 = invoke_fn!(runtime_ref, "first", (foo, positive)).unwrap();
+    assert_eq!(result, 5);
+
+    // A tuple can also be marshalled as a struct with fields named after their index
+    let pair: StructRef = invoke_fn!(runtime_ref, "divmod", 7i32, 2i32).unwrap();
+    assert_eq!(pair.get::<i32>("0").unwrap(), 3);
+    assert_eq!(pair.get::<i32>("1").unwrap(), 1);
+}
+
+#[test]
+fn integer_arithmetic() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn add(a: u8, b: u8) -> u8 {
+        a + b
+    }
+
+    pub fn divide(a: i32, b: i32) -> i32 {
+        a / b
+    }
+
+    pub fn negate(a: i64) -> i64 {
+        -a
+    }
+
+    pub fn wrapping_add(a: u8, b: u8) -> u8 {
+        a.wrapping_add(b)
+    }
+
+    pub fn saturating_sub(a: u8, b: u8) -> u8 {
+        a.saturating_sub(b)
+    }
+
+    pub fn saturating_mul(a: i16, b: i16) -> i16 {
+        a.saturating_mul(b)
+    }
+
+    pub fn checked_mul(a: i32, b: i32) -> (i32, bool) {
+        a.checked_mul(b)
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(u8, 255, driver, "add", 200u8, 55u8);
+    assert_invoke_eq!(i32, -3, driver, "divide", 7i32, -2i32);
+    assert_invoke_eq!(i64, 5, driver, "negate", -5i64);
+
+    assert_invoke_eq!(u8, 4, driver, "wrapping_add", 200u8, 60u8);
+    assert_invoke_eq!(u8, 0, driver, "saturating_sub", 20u8, 60u8);
+    assert_invoke_eq!(u8, 40, driver, "saturating_sub", 60u8, 20u8);
+    assert_invoke_eq!(i16, i16::MAX, driver, "saturating_mul", 300i16, 200i16);
+    assert_invoke_eq!(i16, i16::MIN, driver, "saturating_mul", -300i16, 200i16);
+    assert_invoke_eq!(i16, -6, driver, "saturating_mul", -3i16, 2i16);
+
+    // Checked operations report an overflow instead of aborting the invocation
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+    let result: (i32, bool) = invoke_fn!(runtime_ref, "checked_mul", 30i32, 20i32).unwrap();
+    assert_eq!(result, (600, false));
+    let result: (i32, bool) = invoke_fn!(runtime_ref, "checked_mul", i32::MAX, 2i32).unwrap();
+    assert_eq!(result, (-2, true));
+
+    // Overflows and divisions by zero abort the invocation with an error
+    let result: Result<u8, _> = invoke_fn!(runtime_ref, "add", 200u8, 56u8);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "attempt to add with overflow at main.mun:2:5"
+    );
+    let result: Result<i32, _> = invoke_fn!(runtime_ref, "divide", 7i32, 0i32);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "attempt to divide by zero at main.mun:6:5"
+    );
+    let result: Result<i32, _> = invoke_fn!(runtime_ref, "divide", i32::MIN, -1i32);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "attempt to divide with overflow at main.mun:6:5"
+    );
+    let result: Result<i64, _> = invoke_fn!(runtime_ref, "negate", i64::MIN);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "attempt to negate with overflow at main.mun:10:5"
+    );
+}
+
+#[test]
+fn integer_arithmetic_without_overflow_checks() {
+    let driver = CompileAndRunTestDriver::with_compiler_config(
+        r#"
+    pub fn add(a: u8, b: u8) -> u8 {
+        a + b
+    }
+
+    pub fn divide(a: i32, b: i32) -> i32 {
+        a / b
+    }
+
+    pub fn remainder(a: u64, b: u64) -> u64 {
+        a % b
+    }
+
+    pub fn shift_left(a: i32, b: i32) -> i32 {
+        a << b
+    }
+    "#,
+        |config| Config {
+            optimization_lvl: OptimizationLevel::Aggressive,
+            arithmetic_checks: false,
+            ..config
+        },
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    // Overflowing arithmetic wraps around
+    assert_invoke_eq!(u8, 0, driver, "add", 200u8, 56u8);
+    assert_invoke_eq!(i32, 8, driver, "shift_left", 1i32, 3i32);
+
+    // Divisions by zero and oversized shifts are undefined, so they are always checked
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+    let result: Result<i32, _> = invoke_fn!(runtime_ref, "divide", 7i32, 0i32);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "attempt to divide by zero at main.mun:6:5"
+    );
+    let result: Result<i32, _> = invoke_fn!(runtime_ref, "divide", i32::MIN, -1i32);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "attempt to divide with overflow at main.mun:6:5"
+    );
+    let result: Result<u64, _> = invoke_fn!(runtime_ref, "remainder", 7u64, 0u64);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "attempt to calculate the remainder with a divisor of zero at main.mun:10:5"
+    );
+    let result: Result<i32, _> = invoke_fn!(runtime_ref, "shift_left", 1i32, 32i32);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "attempt to shift left with overflow at main.mun:14:5"
+    );
+}
+
+#[test]
+fn array_index_out_of_bounds() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub fn get(index: usize) -> i32 {
+        let array = [1, 2, 3];
+        array[index]
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 3, driver, "get", 2usize);
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+    let result: Result<i32, _> = invoke_fn!(runtime_ref, "get", 3usize);
+    let err = result.unwrap_err();
+    assert_eq!(err.to_string(), "index out of bounds at main.mun:3:5");
+
+    let panic = err.panic().expect("expected a panic");
+    assert_eq!(panic.message(), "index out of bounds");
+    assert_eq!(
+        panic.location(),
+        &PanicLocation {
+            file: String::from("main.mun"),
+            line: 3,
+            column: 5,
+        }
+    );
+}
+
+#[test]
+fn panic_in_nested_call() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Pair(i32, i32);
+
+    fn get(array: [i32], index: usize) -> i32 {
+        array[index]
+    }
+
+    fn pair(array: [i32], index: usize) -> Pair {
+        Pair(get(array, index), get(array, index + 1))
+    }
+
+    pub fn sum(index: usize) -> i32 {
+        let array = [1, 2, 3];
+        let add = |index: usize| {
+            let pair = pair(array, index);
+            pair.0 + pair.1
+        };
+        add(index) * 2
+    }
+
+    pub fn summer() -> fn(usize) -> i32 {
+        sum
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    assert_invoke_eq!(i32, 10, driver, "sum", 1usize);
+
+    // The panic returns through the closure and all functions that it calls
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+    let result: Result<i32, _> = invoke_fn!(runtime_ref, "sum", 2usize);
+    let err = result.unwrap_err();
+    assert_eq!(err.to_string(), "index out of bounds at main.mun:4:5");
+
+    // The panic does not affect later invocations
+    let result: i32 = invoke_fn!(runtime_ref, "sum", 0usize).unwrap();
+    assert_eq!(result, 6);
+
+    let sum: ClosureRef = invoke_fn!(runtime_ref, "summer").unwrap();
+    assert_eq!(
+        sum.invoke1::<usize, i32>(3).unwrap_err(),
+        "index out of bounds at main.mun:4:5"
+    );
+    assert_eq!(sum.invoke1::<usize, i32>(1).unwrap(), 10);
+}
@@ -1,4 +1,4 @@
-use mun_runtime::{invoke_fn, StructRef};
+use mun_runtime::{invoke_fn, ArrayRef, StructRef};
 use mun_test::CompileAndRunTestDriver;
 
 #[macro_use]
@@ -45,6 +45,52 @@ fn gc_trace() {
     assert_eq!(runtime_ref.gc_stats().allocated_memory, 0);
 }
 
+#[test]
+fn gc_trace_array() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct Foo {
+        a: i64,
+    }
+
+    pub struct Foos {
+        foos: [Foo],
+    }
+
+    pub fn new_foos() -> Foos {
+        Foos {
+            foos: [Foo { a: 1 }, Foo { a: 2 }]
+        }
+    }
+
+    pub fn sum(foos: Foos) -> i64 {
+        foos.foos[0].a + foos.foos[1].a
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let value: StructRef = invoke_fn!(runtime_ref, "new_foos").unwrap();
+    let value = value.root(driver.runtime());
+
+    // The array and its elements are reachable through the rooted struct
+    let allocated_memory = runtime_ref.gc_stats().allocated_memory;
+    assert_eq!(runtime_ref.gc_collect(), false);
+    assert_eq!(runtime_ref.gc_stats().allocated_memory, allocated_memory);
+
+    let sum: i64 = invoke_fn!(runtime_ref, "sum", unsafe { value.as_ref(&runtime_ref) }).unwrap();
+    assert_eq!(sum, 3);
+
+    drop(value);
+
+    assert_eq!(runtime_ref.gc_collect(), true);
+    assert_eq!(runtime_ref.gc_stats().allocated_memory, 0);
+}
+
 #[test]
 fn map_struct_insert_field1() {
     let mut driver = CompileAndRunTestDriver::new(
@@ -583,6 +629,77 @@ fn map_struct_all() {
     assert_eq!(foo.by_ref().get::<i32>("f").unwrap(), 0);
 }
 
+#[test]
+fn map_array_element_type() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r#"
+        struct(value) Bar {
+            a: i32,
+        }
+
+        struct Foo {
+            values: [i32],
+            bars: [Bar],
+        }
+
+        pub fn foo_new(a: i32, b: i32, c: i32) -> Foo {
+            Foo {
+                values: [a, b, c],
+                bars: [Bar { a }, Bar { a: b }],
+            }
+        }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    let a = 5i32;
+    let b = -2i32;
+    let c = 3i32;
+    let foo: StructRef = invoke_fn!(runtime_ref, "foo_new", a, b, c).unwrap();
+    let foo = foo.root(driver.runtime());
+
+    driver.update(
+        runtime_ref,
+        r#"
+        struct(value) Bar {
+            a: i64,
+            b: f64,
+        }
+
+        struct Foo {
+            values: [i64],
+            bars: [Bar],
+        }
+
+        pub fn sum(foo: Foo) -> i64 {
+            foo.values[0] + foo.values[1] + foo.values[2] + foo.bars[0].a + foo.bars[1].a
+        }
+    "#,
+    );
+
+    // The arrays are reallocated with the size of their new elements, retaining their length
+    let runtime_ref = runtime.borrow();
+    let foo_ref = unsafe { foo.as_ref(&runtime_ref) };
+    let values: ArrayRef = foo_ref.get("values").unwrap();
+    assert_eq!(values.len(), 3);
+    assert_eq!(values.get::<i64>(0).unwrap(), a.into());
+    assert_eq!(values.get::<i64>(1).unwrap(), b.into());
+    assert_eq!(values.get::<i64>(2).unwrap(), c.into());
+
+    let bars: ArrayRef = foo_ref.get("bars").unwrap();
+    assert_eq!(bars.len(), 2);
+    let bar: StructRef = bars.get(1).unwrap();
+    assert_eq!(bar.get::<i64>("a").unwrap(), b.into());
+    assert_eq!(bar.get::<f64>("b").unwrap(), 0.0);
+
+    let sum: i64 = invoke_fn!(runtime_ref, "sum", foo_ref).unwrap();
+    assert_eq!(sum, i64::from(a + b + c + a + b));
+}
+
 #[test]
 fn delete_used_struct() {
     let mut driver = CompileAndRunTestDriver::new(
@@ -1,3 +1,4 @@
+use mun_runtime::{invoke_fn, StructRef};
 use mun_test::CompileAndRunTestDriver;
 use std::io;
 
@@ -47,3 +48,163 @@ fn arg_missing_bug() {
     );
     driver.unwrap();
 }
+
+#[test]
+fn multi_file_package() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    //- /main.mun
+    use package::physics::{integrate, Body, Vec2};
+    use physics::shapes::*;
+
+    pub fn gravity() -> f32 {
+        10.0
+    }
+
+    pub fn main() -> f32 {
+        let body = Body { mass: 2.0, velocity: Vec2 { x: 1.0, y: 0.0 } };
+        let velocity = integrate(body, 0.5);
+        velocity.y + area(2.0)
+    }
+
+    //- /physics.mun
+    pub struct Body {
+        mass: f32,
+        velocity: Vec2,
+    }
+
+    pub struct(value) Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    pub fn integrate(body: Body, dt: f32) -> Vec2 {
+        Vec2 { x: body.velocity.x, y: body.velocity.y - package::gravity() * dt }
+    }
+
+    //- /physics/shapes.mun
+    pub fn area(side: f32) -> f32 {
+        side * side
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+    let result: f32 = invoke_fn!(runtime_ref, "main").unwrap();
+    assert_eq!(result, -1.0);
+
+    // The functions of other modules are exposed by their full path
+    let result: f32 = invoke_fn!(runtime_ref, "physics::shapes::area", 3.0f32).unwrap();
+    assert_eq!(result, 9.0);
+}
+
+#[test]
+fn same_named_structs_in_different_modules() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    //- /main.mun
+    pub struct Point {
+        x: f32,
+        y: f32,
+    }
+
+    pub fn new_point(x: f32, y: f32) -> Point {
+        Point { x, y }
+    }
+
+    //- /grid.mun
+    pub struct Point {
+        row: i64,
+        column: i64,
+        label: i32,
+    }
+
+    pub fn new_point(row: i64, column: i64) -> Point {
+        Point { row, column, label: 1 }
+    }
+
+    pub fn row(point: Point) -> i64 {
+        point.row
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+
+    // Both structs are reflected by the path of their module
+    let point_info = runtime_ref.get_type_info("Point").unwrap();
+    let grid_point_info = runtime_ref.get_type_info("grid::Point").unwrap();
+    assert_ne!(point_info.guid, grid_point_info.guid);
+
+    let point: StructRef = invoke_fn!(runtime_ref, "new_point", 1.0f32, 2.0f32).unwrap();
+    assert_eq!(point.type_info().name(), "Point");
+    assert_eq!(point.get::<f32>("y"), Ok(2.0));
+
+    let grid_point: StructRef = invoke_fn!(runtime_ref, "grid::new_point", 3i64, 4i64).unwrap();
+    assert_eq!(grid_point.type_info().name(), "grid::Point");
+    assert_eq!(grid_point.get::<i64>("column"), Ok(4));
+    assert_eq!(grid_point.get::<i32>("label"), Ok(1));
+
+    let row: i64 = invoke_fn!(runtime_ref, "grid::row", grid_point).unwrap();
+    assert_eq!(row, 3);
+    let row: Result<i64, _> = invoke_fn!(runtime_ref, "grid::row", point);
+    assert!(row.is_err());
+}
+
+#[test]
+fn consts_and_statics() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    struct(value) Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    pub const GRAVITY: f32 = 9.81;
+    const UP: Vec2 = Vec2 { x: 0.0, y: -GRAVITY };
+    static mut COUNTER: i32 = 0;
+
+    pub fn up() -> f32 {
+        UP.y
+    }
+
+    pub fn increment() -> i32 {
+        COUNTER += 1;
+        COUNTER
+    }
+    "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let runtime = driver.runtime();
+    let runtime_ref = runtime.borrow();
+    let result: f32 = invoke_fn!(runtime_ref, "up").unwrap();
+    assert_eq!(result, -9.81);
+    let result: i32 = invoke_fn!(runtime_ref, "increment").unwrap();
+    assert_eq!(result, 1);
+    let result: i32 = invoke_fn!(runtime_ref, "increment").unwrap();
+    assert_eq!(result, 2);
+
+    // Public constants and all statics are reflected
+    let gravity = runtime_ref
+        .get_global("GRAVITY")
+        .expect("public constants should be reflected");
+    assert!(!gravity.is_mutable);
+    assert_eq!(gravity.type_info().name(), "core::f32");
+    assert_eq!(unsafe { *(gravity.ptr as *const f32) }, 9.81);
+
+    let counter = runtime_ref
+        .get_global("COUNTER")
+        .expect("statics should be reflected");
+    assert!(counter.is_mutable);
+    assert_eq!(unsafe { *(counter.ptr as *const i32) }, 2);
+
+    assert!(runtime_ref.get_global("UP").is_none());
+}
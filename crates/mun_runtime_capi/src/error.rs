@@ -7,6 +7,7 @@ use std::ptr;
 
 use crate::hub::HUB;
 use crate::{Token, TypedHandle};
+use runtime::Panic;
 
 /// A C-style handle to an error.
 #[repr(C)]
@@ -43,3 +44,41 @@ pub extern "C" fn mun_error_message(error_handle: ErrorHandle) -> *const c_char
     let message = format!("{}", error);
     CString::new(message).unwrap().into_raw() as *const _
 }
+
+/// Retrieves the source location of the panic corresponding to `error_handle`, as returned by
+/// [`mun_catch_panic`]. If the error is a panic, `file` is set to the path of the source file
+/// relative to the source directory, `line` and `column` are set to its one-based line and column,
+/// and `true` is returned. Otherwise `false` is returned.
+///
+/// The `file` string must be manually destructed using [`mun_destroy_string`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+/// `false` is returned. Passing pointers to invalid data, will lead to undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_error_panic_location(
+    error_handle: ErrorHandle,
+    file: *mut *const c_char,
+    line: *mut u32,
+    column: *mut u32,
+) -> bool {
+    let (file, line, column) = match (file.as_mut(), line.as_mut(), column.as_mut()) {
+        (Some(file), Some(line), Some(column)) => (file, line, column),
+        _ => return false,
+    };
+
+    let errors = HUB.errors.get_data();
+    let location = match errors
+        .get(&error_handle)
+        .and_then(|error| error.downcast_ref::<Panic>())
+    {
+        Some(panic) => panic.location(),
+        None => return false,
+    };
+
+    *file = CString::new(location.file.as_str()).unwrap().into_raw() as *const _;
+    *line = location.line;
+    *column = location.column;
+    true
+}
@@ -7,6 +7,7 @@
 pub mod error;
 pub mod gc;
 pub mod hub;
+pub mod string;
 
 #[cfg(test)]
 mod tests;
@@ -268,6 +269,43 @@ pub unsafe extern "C" fn mun_runtime_update(
     ErrorHandle::default()
 }
 
+/// Calls `callback` with `user_data`, catching panics of the Mun functions that it invokes, e.g.
+/// because of an arithmetic overflow or an out-of-bounds array index. If a panic occurs, a
+/// non-zero error handle is returned, of which the source location can be retrieved with
+/// [`mun_error_panic_location`].
+///
+/// Mun functions must only be invoked through this function. A panic does not unwind the stack;
+/// instead, the invoked Mun function returns immediately with an undefined return value, which
+/// `callback` must discard if a non-zero error handle is returned.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If `callback` is a null pointer, an error
+/// will be returned. Passing a `user_data` pointer that is invalid for `callback`, will lead to
+/// undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_catch_panic(
+    callback: Option<unsafe extern "C" fn(*mut c_void)>,
+    user_data: *mut c_void,
+) -> ErrorHandle {
+    let callback = match callback {
+        Some(callback) => callback,
+        None => {
+            return HUB
+                .errors
+                .register(anyhow!("Invalid argument: 'callback' is null pointer."))
+        }
+    };
+
+    match runtime::catch_panic(|| callback(user_data)) {
+        Ok(()) => ErrorHandle::default(),
+        Err(panic) => HUB.errors.register(panic.into()),
+    }
+}
+
 /// Deallocates a string that was allocated by the runtime.
 ///
 /// # Safety
@@ -0,0 +1,175 @@
+//! Exposes Mun strings.
+
+use crate::{ErrorHandle, RuntimeHandle, HUB};
+use abi::HasStaticTypeInfo;
+use anyhow::anyhow;
+use memory::gc::{GcPtr, HasIndirectionPtr};
+use runtime::{Runtime, UnsafeTypeInfo};
+use std::{alloc::Layout, os::raw::c_char, ptr::NonNull, slice, str};
+
+/// Returns the offset of the UTF-8 bytes of a string, relative to the start of its memory.
+fn data_offset() -> usize {
+    // The text of a string is stored behind its length, just like the elements of an array
+    let (_, offset) = memory::array_layout(Layout::new::<u8>(), 0);
+    offset
+}
+
+/// Returns whether the object referred to by `obj` is a string.
+unsafe fn is_string(runtime: &Runtime, obj: GcPtr) -> bool {
+    let type_info = runtime.gc().ptr_type(obj).into_inner();
+    type_info.as_ref().group.is_string()
+}
+
+/// Allocates a string in the runtime that contains a copy of the `len` UTF-8 encoded bytes at
+/// `data`. If successful, `obj` is set, otherwise a non-zero error handle is returned.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+/// an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_string_new(
+    handle: RuntimeHandle,
+    data: *const c_char,
+    len: usize,
+    obj: *mut GcPtr,
+) -> ErrorHandle {
+    let runtime = match (handle.0 as *mut Runtime).as_ref() {
+        Some(runtime) => runtime,
+        None => {
+            return HUB
+                .errors
+                .register(anyhow!("Invalid argument: 'runtime' is null pointer."))
+        }
+    };
+
+    if data.is_null() {
+        return HUB
+            .errors
+            .register(anyhow!("Invalid argument: 'data' is null pointer."));
+    }
+
+    let data = slice::from_raw_parts(data.cast::<u8>(), len);
+    if str::from_utf8(data).is_err() {
+        return HUB
+            .errors
+            .register(anyhow!("Invalid argument: 'data' is not UTF-8 encoded."));
+    }
+
+    let obj = match obj.as_mut() {
+        Some(obj) => obj,
+        None => {
+            return HUB
+                .errors
+                .register(anyhow!("Invalid argument: 'obj' is null pointer."))
+        }
+    };
+
+    let type_info = <str as HasStaticTypeInfo>::type_info();
+    let type_info = UnsafeTypeInfo::new(NonNull::new_unchecked(type_info as *const _ as *mut _));
+
+    let mut string = runtime.gc().alloc_array(type_info, len);
+    std::ptr::copy_nonoverlapping(
+        data.as_ptr(),
+        string.deref_mut::<u8>().add(data_offset()),
+        len,
+    );
+
+    *obj = string;
+    ErrorHandle::default()
+}
+
+/// Retrieves the length in bytes of the string `obj`. If successful, `len` is set, otherwise a
+/// non-zero error handle is returned.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+/// an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_string_len(
+    handle: RuntimeHandle,
+    obj: GcPtr,
+    len: *mut usize,
+) -> ErrorHandle {
+    let runtime = match (handle.0 as *mut Runtime).as_ref() {
+        Some(runtime) => runtime,
+        None => {
+            return HUB
+                .errors
+                .register(anyhow!("Invalid argument: 'runtime' is null pointer."))
+        }
+    };
+
+    let len = match len.as_mut() {
+        Some(len) => len,
+        None => {
+            return HUB
+                .errors
+                .register(anyhow!("Invalid argument: 'len' is null pointer."))
+        }
+    };
+
+    if !is_string(runtime, obj) {
+        return HUB
+            .errors
+            .register(anyhow!("Invalid argument: 'obj' is not a string."));
+    }
+
+    // The length of a string is stored at the start of its memory
+    *len = *obj.deref::<usize>();
+    ErrorHandle::default()
+}
+
+/// Retrieves a pointer to the UTF-8 encoded bytes of the string `obj`. The bytes are not null
+/// terminated; use [`mun_string_len`] to retrieve their number. If successful, `data` is set,
+/// otherwise a non-zero error handle is returned.
+///
+/// The pointer remains valid for as long as the string is not collected by the garbage collector.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If any of the arguments is a null pointer,
+/// an error will be returned. Passing pointers to invalid data, will lead to undefined behavior.
+#[no_mangle]
+pub unsafe extern "C" fn mun_string_data(
+    handle: RuntimeHandle,
+    obj: GcPtr,
+    data: *mut *const c_char,
+) -> ErrorHandle {
+    let runtime = match (handle.0 as *mut Runtime).as_ref() {
+        Some(runtime) => runtime,
+        None => {
+            return HUB
+                .errors
+                .register(anyhow!("Invalid argument: 'runtime' is null pointer."))
+        }
+    };
+
+    let data = match data.as_mut() {
+        Some(data) => data,
+        None => {
+            return HUB
+                .errors
+                .register(anyhow!("Invalid argument: 'data' is null pointer."))
+        }
+    };
+
+    if !is_string(runtime, obj) {
+        return HUB
+            .errors
+            .register(anyhow!("Invalid argument: 'obj' is not a string."));
+    }
+
+    *data = obj.deref::<u8>().add(data_offset()).cast::<c_char>();
+    ErrorHandle::default()
+}
@@ -1,4 +1,4 @@
-use crate::{error::*, gc::*, *};
+use crate::{error::*, gc::*, string::*, *};
 use compiler::{Config, Driver, PathOrInline, RelativePathBuf};
 use memory::gc::{GcPtr, HasIndirectionPtr, RawGcPtr};
 use runtime::UnsafeTypeInfo;
@@ -323,6 +323,105 @@ fn test_runtime_update() {
     assert_eq!(handle.token(), 0);
 }
 
+#[test]
+fn test_catch_panic_invalid_callback() {
+    let handle = unsafe { mun_catch_panic(None, ptr::null_mut()) };
+
+    let message = unsafe { CStr::from_ptr(mun_error_message(handle)) };
+    assert_eq!(
+        message.to_str().unwrap(),
+        "Invalid argument: 'callback' is null pointer."
+    );
+
+    unsafe { mun_destroy_string(message.as_ptr()) };
+}
+
+#[test]
+fn test_catch_panic() {
+    let driver = TestDriver::new(
+        r#"
+pub fn get(index: usize) -> i32 {
+    let array = [1, 2, 3];
+    array[index]
+}
+    "#,
+    );
+
+    let fn_name = CString::new("get").expect("Invalid function name");
+    let mut has_fn_info = false;
+    let mut fn_definition = MaybeUninit::uninit();
+    let handle = unsafe {
+        mun_runtime_get_function_definition(
+            driver.runtime,
+            fn_name.as_ptr(),
+            &mut has_fn_info as *mut _,
+            fn_definition.as_mut_ptr(),
+        )
+    };
+    assert_eq!(handle.token(), 0);
+    assert!(has_fn_info);
+    let fn_definition = unsafe { fn_definition.assume_init() };
+
+    struct Invocation {
+        fn_ptr: extern "C" fn(usize) -> i32,
+        index: usize,
+        result: i32,
+    }
+
+    unsafe extern "C" fn invoke(user_data: *mut c_void) {
+        let invocation = &mut *(user_data as *mut Invocation);
+        invocation.result = (invocation.fn_ptr)(invocation.index);
+    }
+
+    let mut invocation = Invocation {
+        fn_ptr: unsafe { mem::transmute(fn_definition.fn_ptr) },
+        index: 2,
+        result: 0,
+    };
+    let handle = unsafe {
+        mun_catch_panic(
+            Some(invoke),
+            &mut invocation as *mut Invocation as *mut c_void,
+        )
+    };
+    assert_eq!(handle.token(), 0);
+    assert_eq!(invocation.result, 3);
+
+    invocation.index = 3;
+    let handle = unsafe {
+        mun_catch_panic(
+            Some(invoke),
+            &mut invocation as *mut Invocation as *mut c_void,
+        )
+    };
+    assert_ne!(handle.token(), 0);
+
+    let message = unsafe { CStr::from_ptr(mun_error_message(handle)) };
+    assert_eq!(
+        message.to_str().unwrap(),
+        "index out of bounds at main.mun:4:5"
+    );
+    unsafe { mun_destroy_string(message.as_ptr()) };
+
+    let mut file = ptr::null();
+    let mut line = 0;
+    let mut column = 0;
+    assert!(unsafe {
+        mun_error_panic_location(
+            handle,
+            &mut file as *mut _,
+            &mut line as *mut _,
+            &mut column as *mut _,
+        )
+    });
+    let file = unsafe { CStr::from_ptr(file) };
+    assert_eq!(file.to_str().unwrap(), "main.mun");
+    assert_eq!((line, column), (4, 5));
+
+    unsafe { mun_destroy_string(file.as_ptr()) };
+    mun_error_destroy(handle);
+}
+
 #[test]
 fn test_gc_alloc_invalid_obj() {
     let driver = TestDriver::new(
@@ -544,3 +643,137 @@ fn test_gc_ptr_collect_invalid_reclaimed() {
 
     unsafe { mun_destroy_string(message.as_ptr()) };
 }
+
+#[test]
+fn test_string_new_invalid_data() {
+    let driver = TestDriver::new(
+        r#"
+        pub fn main() -> string { "Hello, world!" }
+    "#,
+    );
+
+    let mut obj = MaybeUninit::uninit();
+    let handle = unsafe { mun_string_new(driver.runtime, ptr::null(), 0, obj.as_mut_ptr()) };
+    let message = unsafe { CStr::from_ptr(mun_error_message(handle)) };
+    assert_eq!(
+        message.to_str().unwrap(),
+        "Invalid argument: 'data' is null pointer."
+    );
+
+    unsafe { mun_destroy_string(message.as_ptr()) };
+}
+
+#[test]
+fn test_string_new_invalid_data_encoding() {
+    let driver = TestDriver::new(
+        r#"
+        pub fn main() -> string { "Hello, world!" }
+    "#,
+    );
+
+    let data = [0xC3u8, 0x28];
+    let mut obj = MaybeUninit::uninit();
+    let handle = unsafe {
+        mun_string_new(
+            driver.runtime,
+            data.as_ptr().cast(),
+            data.len(),
+            obj.as_mut_ptr(),
+        )
+    };
+    let message = unsafe { CStr::from_ptr(mun_error_message(handle)) };
+    assert_eq!(
+        message.to_str().unwrap(),
+        "Invalid argument: 'data' is not UTF-8 encoded."
+    );
+
+    unsafe { mun_destroy_string(message.as_ptr()) };
+}
+
+#[test]
+fn test_string_len_invalid_obj() {
+    let driver = TestDriver::new(
+        r#"
+        struct Foo;
+
+        pub fn main() -> Foo { Foo }
+    "#,
+    );
+    let fn_name = CString::new("main").expect("Invalid function name");
+    let mut has_fn_info = false;
+    let mut fn_definition = MaybeUninit::uninit();
+    let handle = unsafe {
+        mun_runtime_get_function_definition(
+            driver.runtime,
+            fn_name.as_ptr(),
+            &mut has_fn_info as *mut _,
+            fn_definition.as_mut_ptr(),
+        )
+    };
+    assert_eq!(handle.token(), 0);
+
+    let fn_definition = unsafe { fn_definition.assume_init() };
+    let return_type = fn_definition.prototype.signature.return_type().unwrap();
+    let return_type =
+        UnsafeTypeInfo::new(NonNull::new(return_type as *const abi::TypeInfo as *mut _).unwrap());
+
+    let mut obj = MaybeUninit::uninit();
+    let handle = unsafe { mun_gc_alloc(driver.runtime, return_type, obj.as_mut_ptr()) };
+    assert_eq!(handle.token(), 0);
+
+    let obj = unsafe { obj.assume_init() };
+    let mut len = 0;
+    let handle = unsafe { mun_string_len(driver.runtime, obj, &mut len as *mut _) };
+    let message = unsafe { CStr::from_ptr(mun_error_message(handle)) };
+    assert_eq!(
+        message.to_str().unwrap(),
+        "Invalid argument: 'obj' is not a string."
+    );
+
+    unsafe { mun_destroy_string(message.as_ptr()) };
+}
+
+#[test]
+fn test_string() {
+    let driver = TestDriver::new(
+        r#"
+        pub fn main() -> string { "Hello, world!" }
+    "#,
+    );
+
+    let text = "Grüß Gott";
+    let mut obj = MaybeUninit::uninit();
+    let handle = unsafe {
+        mun_string_new(
+            driver.runtime,
+            text.as_ptr().cast(),
+            text.len(),
+            obj.as_mut_ptr(),
+        )
+    };
+    assert_eq!(handle.token(), 0);
+
+    let obj = unsafe { obj.assume_init() };
+    let handle = unsafe { mun_gc_root(driver.runtime, obj) };
+    assert_eq!(handle.token(), 0);
+
+    let mut len = 0;
+    let handle = unsafe { mun_string_len(driver.runtime, obj, &mut len as *mut _) };
+    assert_eq!(handle.token(), 0);
+    assert_eq!(len, text.len());
+
+    let mut data = ptr::null();
+    let handle = unsafe { mun_string_data(driver.runtime, obj, &mut data as *mut _) };
+    assert_eq!(handle.token(), 0);
+
+    let bytes = unsafe { std::slice::from_raw_parts(data.cast::<u8>(), len) };
+    assert_eq!(std::str::from_utf8(bytes).unwrap(), text);
+
+    let handle = unsafe { mun_gc_unroot(driver.runtime, obj) };
+    assert_eq!(handle.token(), 0);
+
+    let mut reclaimed = false;
+    let handle = unsafe { mun_gc_collect(driver.runtime, &mut reclaimed as *mut _) };
+    assert_eq!(handle.token(), 0);
+    assert!(reclaimed);
+}
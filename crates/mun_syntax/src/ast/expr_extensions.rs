@@ -158,6 +158,56 @@ impl BinExpr {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RangeOp {
+    /// The `..` operator for a range that excludes its upper bound
+    Exclusive,
+    /// The `..=` operator for a range that includes its upper bound
+    Inclusive,
+}
+
+impl ast::RangeExpr {
+    fn op_details(&self) -> Option<(SyntaxToken, RangeOp)> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find_map(|c| {
+                let range_op = match c.kind() {
+                    T![..] => RangeOp::Exclusive,
+                    T![..=] => RangeOp::Inclusive,
+                    _ => return None,
+                };
+                Some((c, range_op))
+            })
+    }
+
+    pub fn op_kind(&self) -> Option<RangeOp> {
+        self.op_details().map(|t| t.1)
+    }
+
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        self.op_details().map(|t| t.0)
+    }
+
+    pub fn start(&self) -> Option<ast::Expr> {
+        children(self).next()
+    }
+
+    pub fn end(&self) -> Option<ast::Expr> {
+        children(self).nth(1)
+    }
+}
+
+impl ast::IndexExpr {
+    pub fn base(&self) -> Option<ast::Expr> {
+        children(self).next()
+    }
+
+    pub fn index(&self) -> Option<ast::Expr> {
+        children(self).nth(1)
+    }
+}
+
 #[derive(PartialEq, Eq)]
 pub enum FieldKind {
     Name(ast::NameRef),
@@ -201,6 +251,32 @@ impl ast::FieldExpr {
     }
 }
 
+/// Returns the label token of `node`, e.g. `'outer` in `break 'outer` or `'outer: loop {}`.
+fn label_ident_token(node: &impl AstNode) -> Option<SyntaxToken> {
+    node.syntax()
+        .children_with_tokens()
+        .find(|e| e.kind() == SyntaxKind::LABEL_IDENT)
+        .and_then(|e| e.into_token())
+}
+
+impl ast::Label {
+    pub fn label_ident_token(&self) -> Option<SyntaxToken> {
+        label_ident_token(self)
+    }
+}
+
+impl ast::BreakExpr {
+    pub fn label_ident_token(&self) -> Option<SyntaxToken> {
+        label_ident_token(self)
+    }
+}
+
+impl ast::ContinueExpr {
+    pub fn label_ident_token(&self) -> Option<SyntaxToken> {
+        label_ident_token(self)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum LiteralKind {
     String,
@@ -2,7 +2,7 @@ use crate::{
     ast::{self, child_opt, AstNode, NameOwner},
     SyntaxKind, T,
 };
-use crate::{SmolStr, SyntaxNode};
+use crate::{SmolStr, SyntaxNode, SyntaxToken};
 use abi::StructMemoryKind;
 use text_unit::TextRange;
 
@@ -55,6 +55,35 @@ impl ast::FunctionDef {
     }
 }
 
+impl ast::ImplDef {
+    /// Returns the trait that is implemented by the `impl` block, e.g. `Foo` in
+    /// `impl Foo for Bar {}`. Returns `None` for an inherent `impl` block.
+    pub fn target_trait(&self) -> Option<ast::TypeRef> {
+        if self.has_for_kw() {
+            child_opt(self)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the type for which the `impl` block is defined, e.g. `Bar` in `impl Foo for Bar {}`
+    /// or in `impl Bar {}`.
+    pub fn target_type(&self) -> Option<ast::TypeRef> {
+        let mut type_refs = self.syntax().children().filter_map(ast::TypeRef::cast);
+        if self.has_for_kw() {
+            type_refs.nth(1)
+        } else {
+            type_refs.next()
+        }
+    }
+
+    fn has_for_kw(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|child| child.kind() == T![for])
+    }
+}
+
 fn text_of_first_token(node: &SyntaxNode) -> &SmolStr {
     node.green()
         .children()
@@ -69,6 +98,7 @@ pub enum PathSegmentKind {
     Name(ast::NameRef),
     SelfKw,
     SuperKw,
+    PackageKw,
 }
 
 impl ast::PathSegment {
@@ -86,6 +116,7 @@ impl ast::PathSegment {
             match self.syntax().first_child_or_token()?.kind() {
                 T![self] => PathSegmentKind::SelfKw,
                 T![super] => PathSegmentKind::SuperKw,
+                T![package] => PathSegmentKind::PackageKw,
                 _ => return None,
             }
         };
@@ -168,6 +199,111 @@ impl ast::StructDef {
     }
 }
 
+impl ast::EnumDef {
+    pub fn signature_range(&self) -> TextRange {
+        let enum_kw = self
+            .syntax()
+            .children_with_tokens()
+            .find(|p| p.kind() == T![enum])
+            .map(|kw| kw.text_range());
+        let name = self.name().map(|n| n.syntax.text_range());
+
+        let start = enum_kw
+            .map(|kw| kw.start())
+            .unwrap_or_else(|| self.syntax.text_range().start());
+
+        let end = name
+            .map(|name| name.end())
+            .or_else(|| enum_kw.map(|kw| kw.end()))
+            .unwrap_or_else(|| self.syntax().text_range().end());
+
+        TextRange::from_to(start, end)
+    }
+}
+
+impl ast::EnumVariant {
+    pub fn kind(&self) -> StructKind {
+        StructKind::from_node(self)
+    }
+}
+
+impl ast::FunctionDef {
+    /// Returns true if the function can be evaluated at compile time, e.g. `const fn foo() {}`.
+    pub fn is_const(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![const])
+    }
+}
+
+impl ast::StaticDef {
+    /// Returns true if the static can be assigned to, e.g. `static mut FOO: i32 = 0;`.
+    pub fn is_mut(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![mut])
+    }
+}
+
+impl ast::BindPat {
+    /// Returns true if the binding can be assigned to, e.g. `let mut a = 0;`.
+    pub fn is_mut(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![mut])
+    }
+}
+
+impl ast::SelfParam {
+    /// Returns true if the `self` parameter can be assigned to, e.g. `fn foo(mut self)`.
+    pub fn is_mut(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![mut])
+    }
+}
+
+impl ast::Attr {
+    /// Returns the name of the attribute if its path consists of a single identifier, e.g. `allow`
+    /// for `#[allow(unused_variables)]`.
+    pub fn simple_name(&self) -> Option<SmolStr> {
+        let path = self.path()?;
+        if path.qualifier().is_some() {
+            return None;
+        }
+        Some(path.segment()?.name_ref()?.text().clone())
+    }
+}
+
+impl ast::TokenTree {
+    /// Returns the identifier tokens that are directly contained in the token tree, e.g. `a` and
+    /// `b` for `(a, b)`.
+    pub fn idents(&self) -> impl Iterator<Item = SyntaxToken> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|token| token.kind() == SyntaxKind::IDENT)
+    }
+}
+
+impl ast::UseTree {
+    /// Returns true if the use tree imports all items, e.g. `use foo::*;`.
+    pub fn has_star(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![*])
+    }
+}
+
+impl ast::LiteralPat {
+    /// Returns true if the literal is preceded by a minus sign (e.g. `-1`).
+    pub fn is_negated(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![-])
+    }
+}
+
 pub enum VisibilityKind {
     PubPackage,
     PubSuper,
@@ -43,6 +43,98 @@ impl ArgList {
     }
 }
 
+// ArrayExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ArrayExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ARRAY_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ArrayExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ArrayExpr {
+    pub fn exprs(&self) -> impl Iterator<Item = Expr> {
+        super::children(self)
+    }
+}
+
+// ArrayType
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ArrayType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ARRAY_TYPE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ArrayType { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ArrayType {
+    pub fn type_ref(&self) -> Option<TypeRef> {
+        super::child_opt(self)
+    }
+
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// Attr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Attr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for Attr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ATTR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Attr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl Attr {
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+
+    pub fn token_tree(&self) -> Option<TokenTree> {
+        super::child_opt(self)
+    }
+}
+
 // BinExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -118,6 +210,7 @@ impl AstNode for BlockExpr {
         &self.syntax
     }
 }
+impl ast::AttrsOwner for BlockExpr {}
 impl BlockExpr {
     pub fn statements(&self) -> impl Iterator<Item = Stmt> {
         super::children(self)
@@ -185,6 +278,38 @@ impl CallExpr {
     }
 }
 
+// CastExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CastExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for CastExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CAST_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(CastExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl CastExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+
+    pub fn type_ref(&self) -> Option<TypeRef> {
+        super::child_opt(self)
+    }
+}
+
 // Condition
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -217,6 +342,149 @@ impl Condition {
     }
 }
 
+// ConstDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ConstDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CONST_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ConstDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for ConstDef {}
+impl ast::VisibilityOwner for ConstDef {}
+impl ast::DocCommentsOwner for ConstDef {}
+impl ast::AttrsOwner for ConstDef {}
+impl ast::TypeAscriptionOwner for ConstDef {}
+impl ConstDef {
+    pub fn body(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// ContinueExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContinueExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ContinueExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CONTINUE_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ContinueExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ContinueExpr {}
+
+// EnumDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnumDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for EnumDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ENUM_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(EnumDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for EnumDef {}
+impl ast::VisibilityOwner for EnumDef {}
+impl ast::DocCommentsOwner for EnumDef {}
+impl ast::AttrsOwner for EnumDef {}
+impl EnumDef {
+    pub fn enum_variant_list(&self) -> Option<EnumVariantList> {
+        super::child_opt(self)
+    }
+}
+
+// EnumVariant
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnumVariant {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for EnumVariant {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ENUM_VARIANT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(EnumVariant { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for EnumVariant {}
+impl ast::DocCommentsOwner for EnumVariant {}
+impl EnumVariant {}
+
+// EnumVariantList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnumVariantList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for EnumVariantList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ENUM_VARIANT_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(EnumVariantList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl EnumVariantList {
+    pub fn variants(&self) -> impl Iterator<Item = EnumVariant> {
+        super::children(self)
+    }
+}
+
 // Expr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -233,15 +501,26 @@ impl AstNode for Expr {
                 | PATH_EXPR
                 | BIN_EXPR
                 | PAREN_EXPR
+                | TUPLE_EXPR
                 | CALL_EXPR
                 | FIELD_EXPR
+                | METHOD_CALL_EXPR
+                | INDEX_EXPR
+                | ARRAY_EXPR
                 | IF_EXPR
                 | LOOP_EXPR
                 | WHILE_EXPR
+                | FOR_EXPR
+                | MATCH_EXPR
                 | RETURN_EXPR
                 | BREAK_EXPR
+                | CONTINUE_EXPR
                 | BLOCK_EXPR
                 | RECORD_LIT
+                | RANGE_EXPR
+                | LAMBDA_EXPR
+                | NIL_EXPR
+                | CAST_EXPR
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -262,15 +541,26 @@ pub enum ExprKind {
     PathExpr(PathExpr),
     BinExpr(BinExpr),
     ParenExpr(ParenExpr),
+    TupleExpr(TupleExpr),
     CallExpr(CallExpr),
     FieldExpr(FieldExpr),
+    MethodCallExpr(MethodCallExpr),
+    IndexExpr(IndexExpr),
+    ArrayExpr(ArrayExpr),
     IfExpr(IfExpr),
     LoopExpr(LoopExpr),
     WhileExpr(WhileExpr),
+    ForExpr(ForExpr),
+    MatchExpr(MatchExpr),
     ReturnExpr(ReturnExpr),
     BreakExpr(BreakExpr),
+    ContinueExpr(ContinueExpr),
     BlockExpr(BlockExpr),
     RecordLit(RecordLit),
+    RangeExpr(RangeExpr),
+    LambdaExpr(LambdaExpr),
+    NilExpr(NilExpr),
+    CastExpr(CastExpr),
 }
 impl From<Literal> for Expr {
     fn from(n: Literal) -> Expr {
@@ -297,6 +587,11 @@ impl From<ParenExpr> for Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<TupleExpr> for Expr {
+    fn from(n: TupleExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 impl From<CallExpr> for Expr {
     fn from(n: CallExpr) -> Expr {
         Expr { syntax: n.syntax }
@@ -307,6 +602,21 @@ impl From<FieldExpr> for Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<MethodCallExpr> for Expr {
+    fn from(n: MethodCallExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
+impl From<IndexExpr> for Expr {
+    fn from(n: IndexExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
+impl From<ArrayExpr> for Expr {
+    fn from(n: ArrayExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 impl From<IfExpr> for Expr {
     fn from(n: IfExpr) -> Expr {
         Expr { syntax: n.syntax }
@@ -322,6 +632,16 @@ impl From<WhileExpr> for Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<ForExpr> for Expr {
+    fn from(n: ForExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
+impl From<MatchExpr> for Expr {
+    fn from(n: MatchExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 impl From<ReturnExpr> for Expr {
     fn from(n: ReturnExpr) -> Expr {
         Expr { syntax: n.syntax }
@@ -332,6 +652,11 @@ impl From<BreakExpr> for Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<ContinueExpr> for Expr {
+    fn from(n: ContinueExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 impl From<BlockExpr> for Expr {
     fn from(n: BlockExpr) -> Expr {
         Expr { syntax: n.syntax }
@@ -342,6 +667,26 @@ impl From<RecordLit> for Expr {
         Expr { syntax: n.syntax }
     }
 }
+impl From<RangeExpr> for Expr {
+    fn from(n: RangeExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
+impl From<LambdaExpr> for Expr {
+    fn from(n: LambdaExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
+impl From<NilExpr> for Expr {
+    fn from(n: NilExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
+impl From<CastExpr> for Expr {
+    fn from(n: CastExpr) -> Expr {
+        Expr { syntax: n.syntax }
+    }
+}
 
 impl Expr {
     pub fn kind(&self) -> ExprKind {
@@ -351,15 +696,30 @@ impl Expr {
             PATH_EXPR => ExprKind::PathExpr(PathExpr::cast(self.syntax.clone()).unwrap()),
             BIN_EXPR => ExprKind::BinExpr(BinExpr::cast(self.syntax.clone()).unwrap()),
             PAREN_EXPR => ExprKind::ParenExpr(ParenExpr::cast(self.syntax.clone()).unwrap()),
+            TUPLE_EXPR => ExprKind::TupleExpr(TupleExpr::cast(self.syntax.clone()).unwrap()),
             CALL_EXPR => ExprKind::CallExpr(CallExpr::cast(self.syntax.clone()).unwrap()),
             FIELD_EXPR => ExprKind::FieldExpr(FieldExpr::cast(self.syntax.clone()).unwrap()),
+            METHOD_CALL_EXPR => {
+                ExprKind::MethodCallExpr(MethodCallExpr::cast(self.syntax.clone()).unwrap())
+            }
+            INDEX_EXPR => ExprKind::IndexExpr(IndexExpr::cast(self.syntax.clone()).unwrap()),
+            ARRAY_EXPR => ExprKind::ArrayExpr(ArrayExpr::cast(self.syntax.clone()).unwrap()),
             IF_EXPR => ExprKind::IfExpr(IfExpr::cast(self.syntax.clone()).unwrap()),
             LOOP_EXPR => ExprKind::LoopExpr(LoopExpr::cast(self.syntax.clone()).unwrap()),
             WHILE_EXPR => ExprKind::WhileExpr(WhileExpr::cast(self.syntax.clone()).unwrap()),
+            FOR_EXPR => ExprKind::ForExpr(ForExpr::cast(self.syntax.clone()).unwrap()),
+            MATCH_EXPR => ExprKind::MatchExpr(MatchExpr::cast(self.syntax.clone()).unwrap()),
             RETURN_EXPR => ExprKind::ReturnExpr(ReturnExpr::cast(self.syntax.clone()).unwrap()),
             BREAK_EXPR => ExprKind::BreakExpr(BreakExpr::cast(self.syntax.clone()).unwrap()),
+            CONTINUE_EXPR => {
+                ExprKind::ContinueExpr(ContinueExpr::cast(self.syntax.clone()).unwrap())
+            }
             BLOCK_EXPR => ExprKind::BlockExpr(BlockExpr::cast(self.syntax.clone()).unwrap()),
             RECORD_LIT => ExprKind::RecordLit(RecordLit::cast(self.syntax.clone()).unwrap()),
+            RANGE_EXPR => ExprKind::RangeExpr(RangeExpr::cast(self.syntax.clone()).unwrap()),
+            LAMBDA_EXPR => ExprKind::LambdaExpr(LambdaExpr::cast(self.syntax.clone()).unwrap()),
+            NIL_EXPR => ExprKind::NilExpr(NilExpr::cast(self.syntax.clone()).unwrap()),
+            CAST_EXPR => ExprKind::CastExpr(CastExpr::cast(self.syntax.clone()).unwrap()),
             _ => unreachable!(),
         }
     }
@@ -427,20 +787,20 @@ impl FieldExpr {
     }
 }
 
-// FunctionDef
+// FnPointerType
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct FunctionDef {
+pub struct FnPointerType {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for FunctionDef {
+impl AstNode for FnPointerType {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, FUNCTION_DEF)
+        matches!(kind, FN_POINTER_TYPE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(FunctionDef { syntax })
+            Some(FnPointerType { syntax })
         } else {
             None
         }
@@ -449,38 +809,30 @@ impl AstNode for FunctionDef {
         &self.syntax
     }
 }
-impl ast::NameOwner for FunctionDef {}
-impl ast::VisibilityOwner for FunctionDef {}
-impl ast::DocCommentsOwner for FunctionDef {}
-impl ast::ExternOwner for FunctionDef {}
-impl FunctionDef {
+impl FnPointerType {
     pub fn param_list(&self) -> Option<ParamList> {
         super::child_opt(self)
     }
 
-    pub fn body(&self) -> Option<BlockExpr> {
-        super::child_opt(self)
-    }
-
     pub fn ret_type(&self) -> Option<RetType> {
         super::child_opt(self)
     }
 }
 
-// IfExpr
+// ForExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct IfExpr {
+pub struct ForExpr {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for IfExpr {
+impl AstNode for ForExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, IF_EXPR)
+        matches!(kind, FOR_EXPR)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(IfExpr { syntax })
+            Some(ForExpr { syntax })
         } else {
             None
         }
@@ -489,26 +841,31 @@ impl AstNode for IfExpr {
         &self.syntax
     }
 }
-impl IfExpr {
-    pub fn condition(&self) -> Option<Condition> {
+impl ast::LoopBodyOwner for ForExpr {}
+impl ForExpr {
+    pub fn pat(&self) -> Option<Pat> {
         super::child_opt(self)
     }
-}
 
-// LetStmt
+    pub fn iterable(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// FunctionDef
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct LetStmt {
+pub struct FunctionDef {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for LetStmt {
+impl AstNode for FunctionDef {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, LET_STMT)
+        matches!(kind, FUNCTION_DEF)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(LetStmt { syntax })
+            Some(FunctionDef { syntax })
         } else {
             None
         }
@@ -517,31 +874,40 @@ impl AstNode for LetStmt {
         &self.syntax
     }
 }
-impl ast::TypeAscriptionOwner for LetStmt {}
-impl LetStmt {
-    pub fn pat(&self) -> Option<Pat> {
+impl ast::NameOwner for FunctionDef {}
+impl ast::VisibilityOwner for FunctionDef {}
+impl ast::DocCommentsOwner for FunctionDef {}
+impl ast::AttrsOwner for FunctionDef {}
+impl ast::ExternOwner for FunctionDef {}
+impl ast::TypeParamsOwner for FunctionDef {}
+impl FunctionDef {
+    pub fn param_list(&self) -> Option<ParamList> {
         super::child_opt(self)
     }
 
-    pub fn initializer(&self) -> Option<Expr> {
+    pub fn body(&self) -> Option<BlockExpr> {
+        super::child_opt(self)
+    }
+
+    pub fn ret_type(&self) -> Option<RetType> {
         super::child_opt(self)
     }
 }
 
-// Literal
+// IfExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Literal {
+pub struct IfExpr {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for Literal {
+impl AstNode for IfExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, LITERAL)
+        matches!(kind, IF_EXPR)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(Literal { syntax })
+            Some(IfExpr { syntax })
         } else {
             None
         }
@@ -550,22 +916,26 @@ impl AstNode for Literal {
         &self.syntax
     }
 }
-impl Literal {}
+impl IfExpr {
+    pub fn condition(&self) -> Option<Condition> {
+        super::child_opt(self)
+    }
+}
 
-// LoopExpr
+// ImplDef
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct LoopExpr {
+pub struct ImplDef {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for LoopExpr {
+impl AstNode for ImplDef {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, LOOP_EXPR)
+        matches!(kind, IMPL_DEF)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(LoopExpr { syntax })
+            Some(ImplDef { syntax })
         } else {
             None
         }
@@ -574,23 +944,28 @@ impl AstNode for LoopExpr {
         &self.syntax
     }
 }
-impl ast::LoopBodyOwner for LoopExpr {}
-impl LoopExpr {}
+impl ast::DocCommentsOwner for ImplDef {}
+impl ast::AttrsOwner for ImplDef {}
+impl ImplDef {
+    pub fn item_list(&self) -> Option<ItemList> {
+        super::child_opt(self)
+    }
+}
 
-// MemoryTypeSpecifier
+// IndexExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct MemoryTypeSpecifier {
+pub struct IndexExpr {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for MemoryTypeSpecifier {
+impl AstNode for IndexExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, MEMORY_TYPE_SPECIFIER)
+        matches!(kind, INDEX_EXPR)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(MemoryTypeSpecifier { syntax })
+            Some(IndexExpr { syntax })
         } else {
             None
         }
@@ -599,22 +974,22 @@ impl AstNode for MemoryTypeSpecifier {
         &self.syntax
     }
 }
-impl MemoryTypeSpecifier {}
+impl IndexExpr {}
 
-// ModuleItem
+// ItemList
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ModuleItem {
+pub struct ItemList {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for ModuleItem {
+impl AstNode for ItemList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, FUNCTION_DEF | STRUCT_DEF | TYPE_ALIAS_DEF)
+        matches!(kind, ITEM_LIST)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(ModuleItem { syntax })
+            Some(ItemList { syntax })
         } else {
             None
         }
@@ -623,59 +998,47 @@ impl AstNode for ModuleItem {
         &self.syntax
     }
 }
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum ModuleItemKind {
-    FunctionDef(FunctionDef),
-    StructDef(StructDef),
-    TypeAliasDef(TypeAliasDef),
-}
-impl From<FunctionDef> for ModuleItem {
-    fn from(n: FunctionDef) -> ModuleItem {
-        ModuleItem { syntax: n.syntax }
-    }
-}
-impl From<StructDef> for ModuleItem {
-    fn from(n: StructDef) -> ModuleItem {
-        ModuleItem { syntax: n.syntax }
-    }
-}
-impl From<TypeAliasDef> for ModuleItem {
-    fn from(n: TypeAliasDef) -> ModuleItem {
-        ModuleItem { syntax: n.syntax }
-    }
+impl ast::FunctionDefOwner for ItemList {}
+impl ItemList {}
+
+// Label
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Label {
+    pub(crate) syntax: SyntaxNode,
 }
 
-impl ModuleItem {
-    pub fn kind(&self) -> ModuleItemKind {
-        match self.syntax.kind() {
-            FUNCTION_DEF => {
-                ModuleItemKind::FunctionDef(FunctionDef::cast(self.syntax.clone()).unwrap())
-            }
-            STRUCT_DEF => ModuleItemKind::StructDef(StructDef::cast(self.syntax.clone()).unwrap()),
-            TYPE_ALIAS_DEF => {
-                ModuleItemKind::TypeAliasDef(TypeAliasDef::cast(self.syntax.clone()).unwrap())
-            }
-            _ => unreachable!(),
+impl AstNode for Label {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, LABEL)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Label { syntax })
+        } else {
+            None
         }
     }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
 }
+impl Label {}
 
-impl ModuleItem {}
-
-// Name
+// LambdaExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Name {
+pub struct LambdaExpr {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for Name {
+impl AstNode for LambdaExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, NAME)
+        matches!(kind, LAMBDA_EXPR)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(Name { syntax })
+            Some(LambdaExpr { syntax })
         } else {
             None
         }
@@ -684,22 +1047,34 @@ impl AstNode for Name {
         &self.syntax
     }
 }
-impl Name {}
+impl LambdaExpr {
+    pub fn param_list(&self) -> Option<ParamList> {
+        super::child_opt(self)
+    }
 
-// NameRef
+    pub fn ret_type(&self) -> Option<RetType> {
+        super::child_opt(self)
+    }
+
+    pub fn body(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// LetStmt
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct NameRef {
+pub struct LetStmt {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for NameRef {
+impl AstNode for LetStmt {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, NAME_REF)
+        matches!(kind, LET_STMT)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(NameRef { syntax })
+            Some(LetStmt { syntax })
         } else {
             None
         }
@@ -708,22 +1083,31 @@ impl AstNode for NameRef {
         &self.syntax
     }
 }
-impl NameRef {}
+impl ast::TypeAscriptionOwner for LetStmt {}
+impl LetStmt {
+    pub fn pat(&self) -> Option<Pat> {
+        super::child_opt(self)
+    }
 
-// NeverType
+    pub fn initializer(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// Literal
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct NeverType {
+pub struct Literal {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for NeverType {
+impl AstNode for Literal {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, NEVER_TYPE)
+        matches!(kind, LITERAL)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(NeverType { syntax })
+            Some(Literal { syntax })
         } else {
             None
         }
@@ -732,22 +1116,22 @@ impl AstNode for NeverType {
         &self.syntax
     }
 }
-impl NeverType {}
+impl Literal {}
 
-// Param
+// LiteralPat
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Param {
+pub struct LiteralPat {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for Param {
+impl AstNode for LiteralPat {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PARAM)
+        matches!(kind, LITERAL_PAT)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(Param { syntax })
+            Some(LiteralPat { syntax })
         } else {
             None
         }
@@ -756,27 +1140,26 @@ impl AstNode for Param {
         &self.syntax
     }
 }
-impl ast::TypeAscriptionOwner for Param {}
-impl Param {
-    pub fn pat(&self) -> Option<Pat> {
+impl LiteralPat {
+    pub fn literal(&self) -> Option<Literal> {
         super::child_opt(self)
     }
 }
 
-// ParamList
+// LoopExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ParamList {
+pub struct LoopExpr {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for ParamList {
+impl AstNode for LoopExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PARAM_LIST)
+        matches!(kind, LOOP_EXPR)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(ParamList { syntax })
+            Some(LoopExpr { syntax })
         } else {
             None
         }
@@ -785,26 +1168,23 @@ impl AstNode for ParamList {
         &self.syntax
     }
 }
-impl ParamList {
-    pub fn params(&self) -> impl Iterator<Item = Param> {
-        super::children(self)
-    }
-}
+impl ast::LoopBodyOwner for LoopExpr {}
+impl LoopExpr {}
 
-// ParenExpr
+// MatchArm
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ParenExpr {
+pub struct MatchArm {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for ParenExpr {
+impl AstNode for MatchArm {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PAREN_EXPR)
+        matches!(kind, MATCH_ARM)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(ParenExpr { syntax })
+            Some(MatchArm { syntax })
         } else {
             None
         }
@@ -813,26 +1193,30 @@ impl AstNode for ParenExpr {
         &self.syntax
     }
 }
-impl ParenExpr {
+impl MatchArm {
+    pub fn pat(&self) -> Option<Pat> {
+        super::child_opt(self)
+    }
+
     pub fn expr(&self) -> Option<Expr> {
         super::child_opt(self)
     }
 }
 
-// Pat
+// MatchArmList
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Pat {
+pub struct MatchArmList {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for Pat {
+impl AstNode for MatchArmList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, BIND_PAT | PLACEHOLDER_PAT)
+        matches!(kind, MATCH_ARM_LIST)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(Pat { syntax })
+            Some(MatchArmList { syntax })
         } else {
             None
         }
@@ -841,50 +1225,26 @@ impl AstNode for Pat {
         &self.syntax
     }
 }
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum PatKind {
-    BindPat(BindPat),
-    PlaceholderPat(PlaceholderPat),
-}
-impl From<BindPat> for Pat {
-    fn from(n: BindPat) -> Pat {
-        Pat { syntax: n.syntax }
-    }
-}
-impl From<PlaceholderPat> for Pat {
-    fn from(n: PlaceholderPat) -> Pat {
-        Pat { syntax: n.syntax }
-    }
-}
-
-impl Pat {
-    pub fn kind(&self) -> PatKind {
-        match self.syntax.kind() {
-            BIND_PAT => PatKind::BindPat(BindPat::cast(self.syntax.clone()).unwrap()),
-            PLACEHOLDER_PAT => {
-                PatKind::PlaceholderPat(PlaceholderPat::cast(self.syntax.clone()).unwrap())
-            }
-            _ => unreachable!(),
-        }
+impl MatchArmList {
+    pub fn arms(&self) -> impl Iterator<Item = MatchArm> {
+        super::children(self)
     }
 }
 
-impl Pat {}
-
-// Path
+// MatchExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Path {
+pub struct MatchExpr {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for Path {
+impl AstNode for MatchExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PATH)
+        matches!(kind, MATCH_EXPR)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(Path { syntax })
+            Some(MatchExpr { syntax })
         } else {
             None
         }
@@ -893,30 +1253,30 @@ impl AstNode for Path {
         &self.syntax
     }
 }
-impl Path {
-    pub fn segment(&self) -> Option<PathSegment> {
+impl MatchExpr {
+    pub fn expr(&self) -> Option<Expr> {
         super::child_opt(self)
     }
 
-    pub fn qualifier(&self) -> Option<Path> {
+    pub fn match_arm_list(&self) -> Option<MatchArmList> {
         super::child_opt(self)
     }
 }
 
-// PathExpr
+// MemoryTypeSpecifier
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PathExpr {
+pub struct MemoryTypeSpecifier {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for PathExpr {
+impl AstNode for MemoryTypeSpecifier {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PATH_EXPR)
+        matches!(kind, MEMORY_TYPE_SPECIFIER)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(PathExpr { syntax })
+            Some(MemoryTypeSpecifier { syntax })
         } else {
             None
         }
@@ -925,26 +1285,22 @@ impl AstNode for PathExpr {
         &self.syntax
     }
 }
-impl PathExpr {
-    pub fn path(&self) -> Option<Path> {
-        super::child_opt(self)
-    }
-}
+impl MemoryTypeSpecifier {}
 
-// PathSegment
+// MethodCallExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PathSegment {
+pub struct MethodCallExpr {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for PathSegment {
+impl AstNode for MethodCallExpr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PATH_SEGMENT)
+        matches!(kind, METHOD_CALL_EXPR)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(PathSegment { syntax })
+            Some(MethodCallExpr { syntax })
         } else {
             None
         }
@@ -953,26 +1309,42 @@ impl AstNode for PathSegment {
         &self.syntax
     }
 }
-impl PathSegment {
+impl ast::ArgListOwner for MethodCallExpr {}
+impl MethodCallExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+
     pub fn name_ref(&self) -> Option<NameRef> {
         super::child_opt(self)
     }
 }
 
-// PathType
+// ModuleItem
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PathType {
+pub struct ModuleItem {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for PathType {
+impl AstNode for ModuleItem {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PATH_TYPE)
+        matches!(
+            kind,
+            FUNCTION_DEF
+                | STRUCT_DEF
+                | ENUM_DEF
+                | TYPE_ALIAS_DEF
+                | CONST_DEF
+                | STATIC_DEF
+                | IMPL_DEF
+                | TRAIT_DEF
+                | USE
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(PathType { syntax })
+            Some(ModuleItem { syntax })
         } else {
             None
         }
@@ -981,26 +1353,225 @@ impl AstNode for PathType {
         &self.syntax
     }
 }
-impl PathType {
-    pub fn path(&self) -> Option<Path> {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModuleItemKind {
+    FunctionDef(FunctionDef),
+    StructDef(StructDef),
+    EnumDef(EnumDef),
+    TypeAliasDef(TypeAliasDef),
+    ConstDef(ConstDef),
+    StaticDef(StaticDef),
+    ImplDef(ImplDef),
+    TraitDef(TraitDef),
+    Use(Use),
+}
+impl From<FunctionDef> for ModuleItem {
+    fn from(n: FunctionDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<StructDef> for ModuleItem {
+    fn from(n: StructDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<EnumDef> for ModuleItem {
+    fn from(n: EnumDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<TypeAliasDef> for ModuleItem {
+    fn from(n: TypeAliasDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<ConstDef> for ModuleItem {
+    fn from(n: ConstDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<StaticDef> for ModuleItem {
+    fn from(n: StaticDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<ImplDef> for ModuleItem {
+    fn from(n: ImplDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<TraitDef> for ModuleItem {
+    fn from(n: TraitDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+impl From<Use> for ModuleItem {
+    fn from(n: Use) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
+
+impl ModuleItem {
+    pub fn kind(&self) -> ModuleItemKind {
+        match self.syntax.kind() {
+            FUNCTION_DEF => {
+                ModuleItemKind::FunctionDef(FunctionDef::cast(self.syntax.clone()).unwrap())
+            }
+            STRUCT_DEF => ModuleItemKind::StructDef(StructDef::cast(self.syntax.clone()).unwrap()),
+            ENUM_DEF => ModuleItemKind::EnumDef(EnumDef::cast(self.syntax.clone()).unwrap()),
+            TYPE_ALIAS_DEF => {
+                ModuleItemKind::TypeAliasDef(TypeAliasDef::cast(self.syntax.clone()).unwrap())
+            }
+            CONST_DEF => ModuleItemKind::ConstDef(ConstDef::cast(self.syntax.clone()).unwrap()),
+            STATIC_DEF => ModuleItemKind::StaticDef(StaticDef::cast(self.syntax.clone()).unwrap()),
+            IMPL_DEF => ModuleItemKind::ImplDef(ImplDef::cast(self.syntax.clone()).unwrap()),
+            TRAIT_DEF => ModuleItemKind::TraitDef(TraitDef::cast(self.syntax.clone()).unwrap()),
+            USE => ModuleItemKind::Use(Use::cast(self.syntax.clone()).unwrap()),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl ModuleItem {}
+
+// Name
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Name {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for Name {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, NAME)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Name { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl Name {}
+
+// NameRef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NameRef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for NameRef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, NAME_REF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(NameRef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl NameRef {}
+
+// NeverType
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NeverType {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for NeverType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, NEVER_TYPE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(NeverType { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl NeverType {}
+
+// NilExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NilExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for NilExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, NIL_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(NilExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl NilExpr {}
+
+// NullableType
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NullableType {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for NullableType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, NULLABLE_TYPE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(NullableType { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl NullableType {
+    pub fn type_ref(&self) -> Option<TypeRef> {
         super::child_opt(self)
     }
 }
 
-// PlaceholderPat
+// Param
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PlaceholderPat {
+pub struct Param {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for PlaceholderPat {
+impl AstNode for Param {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PLACEHOLDER_PAT)
+        matches!(kind, PARAM)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(PlaceholderPat { syntax })
+            Some(Param { syntax })
         } else {
             None
         }
@@ -1009,22 +1580,952 @@ impl AstNode for PlaceholderPat {
         &self.syntax
     }
 }
-impl PlaceholderPat {}
+impl ast::TypeAscriptionOwner for Param {}
+impl Param {
+    pub fn pat(&self) -> Option<Pat> {
+        super::child_opt(self)
+    }
+}
 
-// PrefixExpr
+// ParamList
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrefixExpr {
+pub struct ParamList {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for PrefixExpr {
+impl AstNode for ParamList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PREFIX_EXPR)
+        matches!(kind, PARAM_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ParamList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ParamList {
+    pub fn params(&self) -> impl Iterator<Item = Param> {
+        super::children(self)
+    }
+
+    pub fn self_param(&self) -> Option<SelfParam> {
+        super::child_opt(self)
+    }
+}
+
+// ParenExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParenExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ParenExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PAREN_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ParenExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ParenExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// ParenPat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParenPat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ParenPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PAREN_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ParenPat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ParenPat {
+    pub fn pat(&self) -> Option<Pat> {
+        super::child_opt(self)
+    }
+}
+
+// ParenType
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParenType {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ParenType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PAREN_TYPE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ParenType { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ParenType {
+    pub fn type_ref(&self) -> Option<TypeRef> {
+        super::child_opt(self)
+    }
+}
+
+// Pat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for Pat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            BIND_PAT
+                | PLACEHOLDER_PAT
+                | PATH_PAT
+                | TUPLE_STRUCT_PAT
+                | TUPLE_PAT
+                | PAREN_PAT
+                | LITERAL_PAT
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Pat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatKind {
+    BindPat(BindPat),
+    PlaceholderPat(PlaceholderPat),
+    PathPat(PathPat),
+    TupleStructPat(TupleStructPat),
+    TuplePat(TuplePat),
+    ParenPat(ParenPat),
+    LiteralPat(LiteralPat),
+}
+impl From<BindPat> for Pat {
+    fn from(n: BindPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<PlaceholderPat> for Pat {
+    fn from(n: PlaceholderPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<PathPat> for Pat {
+    fn from(n: PathPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<TupleStructPat> for Pat {
+    fn from(n: TupleStructPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<TuplePat> for Pat {
+    fn from(n: TuplePat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<ParenPat> for Pat {
+    fn from(n: ParenPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+impl From<LiteralPat> for Pat {
+    fn from(n: LiteralPat) -> Pat {
+        Pat { syntax: n.syntax }
+    }
+}
+
+impl Pat {
+    pub fn kind(&self) -> PatKind {
+        match self.syntax.kind() {
+            BIND_PAT => PatKind::BindPat(BindPat::cast(self.syntax.clone()).unwrap()),
+            PLACEHOLDER_PAT => {
+                PatKind::PlaceholderPat(PlaceholderPat::cast(self.syntax.clone()).unwrap())
+            }
+            PATH_PAT => PatKind::PathPat(PathPat::cast(self.syntax.clone()).unwrap()),
+            TUPLE_STRUCT_PAT => {
+                PatKind::TupleStructPat(TupleStructPat::cast(self.syntax.clone()).unwrap())
+            }
+            TUPLE_PAT => PatKind::TuplePat(TuplePat::cast(self.syntax.clone()).unwrap()),
+            PAREN_PAT => PatKind::ParenPat(ParenPat::cast(self.syntax.clone()).unwrap()),
+            LITERAL_PAT => PatKind::LiteralPat(LiteralPat::cast(self.syntax.clone()).unwrap()),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Pat {}
+
+// Path
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Path {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for Path {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PATH)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Path { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl Path {
+    pub fn segment(&self) -> Option<PathSegment> {
+        super::child_opt(self)
+    }
+
+    pub fn qualifier(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+}
+
+// PathExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for PathExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PATH_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(PathExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl PathExpr {
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+}
+
+// PathPat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathPat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for PathPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PATH_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(PathPat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl PathPat {
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+}
+
+// PathSegment
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathSegment {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for PathSegment {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PATH_SEGMENT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(PathSegment { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl PathSegment {
+    pub fn name_ref(&self) -> Option<NameRef> {
+        super::child_opt(self)
+    }
+
+    pub fn type_arg_list(&self) -> Option<TypeArgList> {
+        super::child_opt(self)
+    }
+}
+
+// PathType
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathType {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for PathType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PATH_TYPE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(PathType { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl PathType {
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+}
+
+// PlaceholderPat
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlaceholderPat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for PlaceholderPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PLACEHOLDER_PAT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(PlaceholderPat { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl PlaceholderPat {}
+
+// PrefixExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrefixExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for PrefixExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, PREFIX_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(PrefixExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl PrefixExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// RangeExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RangeExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RANGE_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RangeExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RangeExpr {}
+
+// RecordField
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordField {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RecordField {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RECORD_FIELD)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RecordField { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RecordField {
+    pub fn name_ref(&self) -> Option<NameRef> {
+        super::child_opt(self)
+    }
+
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// RecordFieldDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordFieldDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RecordFieldDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RECORD_FIELD_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RecordFieldDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for RecordFieldDef {}
+impl ast::VisibilityOwner for RecordFieldDef {}
+impl ast::DocCommentsOwner for RecordFieldDef {}
+impl ast::TypeAscriptionOwner for RecordFieldDef {}
+impl RecordFieldDef {}
+
+// RecordFieldDefList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordFieldDefList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RecordFieldDefList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RECORD_FIELD_DEF_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RecordFieldDefList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RecordFieldDefList {
+    pub fn fields(&self) -> impl Iterator<Item = RecordFieldDef> {
+        super::children(self)
+    }
+}
+
+// RecordFieldList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordFieldList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RecordFieldList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RECORD_FIELD_LIST)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RecordFieldList { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RecordFieldList {
+    pub fn fields(&self) -> impl Iterator<Item = RecordField> {
+        super::children(self)
+    }
+
+    pub fn spread(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// RecordLit
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordLit {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RecordLit {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RECORD_LIT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RecordLit { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RecordLit {
+    pub fn type_ref(&self) -> Option<TypeRef> {
+        super::child_opt(self)
+    }
+
+    pub fn record_field_list(&self) -> Option<RecordFieldList> {
+        super::child_opt(self)
+    }
+}
+
+// RetType
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RetType {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for RetType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RET_TYPE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(RetType { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl RetType {
+    pub fn type_ref(&self) -> Option<TypeRef> {
+        super::child_opt(self)
+    }
+}
+
+// ReturnExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReturnExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ReturnExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, RETURN_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ReturnExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ReturnExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// SelfParam
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SelfParam {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for SelfParam {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, SELF_PARAM)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(SelfParam { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl SelfParam {}
+
+// SourceFile
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceFile {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for SourceFile {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, SOURCE_FILE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(SourceFile { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::ModuleItemOwner for SourceFile {}
+impl ast::FunctionDefOwner for SourceFile {}
+impl SourceFile {}
+
+// StaticDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StaticDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for StaticDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, STATIC_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(StaticDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for StaticDef {}
+impl ast::VisibilityOwner for StaticDef {}
+impl ast::DocCommentsOwner for StaticDef {}
+impl ast::AttrsOwner for StaticDef {}
+impl ast::TypeAscriptionOwner for StaticDef {}
+impl StaticDef {
+    pub fn body(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
+// Stmt
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stmt {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for Stmt {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, LET_STMT | EXPR_STMT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Stmt { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StmtKind {
+    LetStmt(LetStmt),
+    ExprStmt(ExprStmt),
+}
+impl From<LetStmt> for Stmt {
+    fn from(n: LetStmt) -> Stmt {
+        Stmt { syntax: n.syntax }
+    }
+}
+impl From<ExprStmt> for Stmt {
+    fn from(n: ExprStmt) -> Stmt {
+        Stmt { syntax: n.syntax }
+    }
+}
+
+impl Stmt {
+    pub fn kind(&self) -> StmtKind {
+        match self.syntax.kind() {
+            LET_STMT => StmtKind::LetStmt(LetStmt::cast(self.syntax.clone()).unwrap()),
+            EXPR_STMT => StmtKind::ExprStmt(ExprStmt::cast(self.syntax.clone()).unwrap()),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Stmt {}
+
+// StructDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StructDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for StructDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, STRUCT_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(StructDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for StructDef {}
+impl ast::VisibilityOwner for StructDef {}
+impl ast::DocCommentsOwner for StructDef {}
+impl ast::AttrsOwner for StructDef {}
+impl ast::TypeParamsOwner for StructDef {}
+impl StructDef {
+    pub fn memory_type_specifier(&self) -> Option<MemoryTypeSpecifier> {
+        super::child_opt(self)
+    }
+}
+
+// TokenTree
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TokenTree {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TokenTree {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TOKEN_TREE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TokenTree { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TokenTree {}
+
+// TraitDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TraitDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TraitDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TRAIT_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TraitDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::NameOwner for TraitDef {}
+impl ast::VisibilityOwner for TraitDef {}
+impl ast::DocCommentsOwner for TraitDef {}
+impl ast::AttrsOwner for TraitDef {}
+impl TraitDef {
+    pub fn item_list(&self) -> Option<ItemList> {
+        super::child_opt(self)
+    }
+}
+
+// TupleExpr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TupleExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TupleExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TUPLE_EXPR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TupleExpr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TupleExpr {
+    pub fn exprs(&self) -> impl Iterator<Item = Expr> {
+        super::children(self)
+    }
+}
+
+// TupleFieldDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TupleFieldDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TupleFieldDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TUPLE_FIELD_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TupleFieldDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::VisibilityOwner for TupleFieldDef {}
+impl TupleFieldDef {
+    pub fn type_ref(&self) -> Option<TypeRef> {
+        super::child_opt(self)
+    }
+}
+
+// TupleFieldDefList
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TupleFieldDefList {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TupleFieldDefList {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TUPLE_FIELD_DEF_LIST)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(PrefixExpr { syntax })
+            Some(TupleFieldDefList { syntax })
         } else {
             None
         }
@@ -1033,26 +2534,26 @@ impl AstNode for PrefixExpr {
         &self.syntax
     }
 }
-impl PrefixExpr {
-    pub fn expr(&self) -> Option<Expr> {
-        super::child_opt(self)
+impl TupleFieldDefList {
+    pub fn fields(&self) -> impl Iterator<Item = TupleFieldDef> {
+        super::children(self)
     }
 }
 
-// RecordField
+// TuplePat
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RecordField {
+pub struct TuplePat {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for RecordField {
+impl AstNode for TuplePat {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, RECORD_FIELD)
+        matches!(kind, TUPLE_PAT)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(RecordField { syntax })
+            Some(TuplePat { syntax })
         } else {
             None
         }
@@ -1061,30 +2562,26 @@ impl AstNode for RecordField {
         &self.syntax
     }
 }
-impl RecordField {
-    pub fn name_ref(&self) -> Option<NameRef> {
-        super::child_opt(self)
-    }
-
-    pub fn expr(&self) -> Option<Expr> {
-        super::child_opt(self)
+impl TuplePat {
+    pub fn args(&self) -> impl Iterator<Item = Pat> {
+        super::children(self)
     }
 }
 
-// RecordFieldDef
+// TupleStructPat
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RecordFieldDef {
+pub struct TupleStructPat {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for RecordFieldDef {
+impl AstNode for TupleStructPat {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, RECORD_FIELD_DEF)
+        matches!(kind, TUPLE_STRUCT_PAT)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(RecordFieldDef { syntax })
+            Some(TupleStructPat { syntax })
         } else {
             None
         }
@@ -1093,26 +2590,30 @@ impl AstNode for RecordFieldDef {
         &self.syntax
     }
 }
-impl ast::NameOwner for RecordFieldDef {}
-impl ast::VisibilityOwner for RecordFieldDef {}
-impl ast::DocCommentsOwner for RecordFieldDef {}
-impl ast::TypeAscriptionOwner for RecordFieldDef {}
-impl RecordFieldDef {}
+impl TupleStructPat {
+    pub fn args(&self) -> impl Iterator<Item = Pat> {
+        super::children(self)
+    }
 
-// RecordFieldDefList
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+}
+
+// TupleType
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RecordFieldDefList {
+pub struct TupleType {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for RecordFieldDefList {
+impl AstNode for TupleType {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, RECORD_FIELD_DEF_LIST)
+        matches!(kind, TUPLE_TYPE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(RecordFieldDefList { syntax })
+            Some(TupleType { syntax })
         } else {
             None
         }
@@ -1121,26 +2622,26 @@ impl AstNode for RecordFieldDefList {
         &self.syntax
     }
 }
-impl RecordFieldDefList {
-    pub fn fields(&self) -> impl Iterator<Item = RecordFieldDef> {
+impl TupleType {
+    pub fn fields(&self) -> impl Iterator<Item = TypeRef> {
         super::children(self)
     }
 }
 
-// RecordFieldList
+// TypeAliasDef
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RecordFieldList {
+pub struct TypeAliasDef {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for RecordFieldList {
+impl AstNode for TypeAliasDef {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, RECORD_FIELD_LIST)
+        matches!(kind, TYPE_ALIAS_DEF)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(RecordFieldList { syntax })
+            Some(TypeAliasDef { syntax })
         } else {
             None
         }
@@ -1149,30 +2650,30 @@ impl AstNode for RecordFieldList {
         &self.syntax
     }
 }
-impl RecordFieldList {
-    pub fn fields(&self) -> impl Iterator<Item = RecordField> {
-        super::children(self)
-    }
-
-    pub fn spread(&self) -> Option<Expr> {
+impl ast::NameOwner for TypeAliasDef {}
+impl ast::VisibilityOwner for TypeAliasDef {}
+impl ast::DocCommentsOwner for TypeAliasDef {}
+impl ast::AttrsOwner for TypeAliasDef {}
+impl TypeAliasDef {
+    pub fn type_ref(&self) -> Option<TypeRef> {
         super::child_opt(self)
     }
 }
 
-// RecordLit
+// TypeArg
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RecordLit {
+pub struct TypeArg {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for RecordLit {
+impl AstNode for TypeArg {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, RECORD_LIT)
+        matches!(kind, TYPE_ARG)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(RecordLit { syntax })
+            Some(TypeArg { syntax })
         } else {
             None
         }
@@ -1181,30 +2682,26 @@ impl AstNode for RecordLit {
         &self.syntax
     }
 }
-impl RecordLit {
+impl TypeArg {
     pub fn type_ref(&self) -> Option<TypeRef> {
         super::child_opt(self)
     }
-
-    pub fn record_field_list(&self) -> Option<RecordFieldList> {
-        super::child_opt(self)
-    }
 }
 
-// RetType
+// TypeArgList
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RetType {
+pub struct TypeArgList {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for RetType {
+impl AstNode for TypeArgList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, RET_TYPE)
+        matches!(kind, TYPE_ARG_LIST)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(RetType { syntax })
+            Some(TypeArgList { syntax })
         } else {
             None
         }
@@ -1213,26 +2710,26 @@ impl AstNode for RetType {
         &self.syntax
     }
 }
-impl RetType {
-    pub fn type_ref(&self) -> Option<TypeRef> {
-        super::child_opt(self)
+impl TypeArgList {
+    pub fn type_args(&self) -> impl Iterator<Item = TypeArg> {
+        super::children(self)
     }
 }
 
-// ReturnExpr
+// TypeBound
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ReturnExpr {
+pub struct TypeBound {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for ReturnExpr {
+impl AstNode for TypeBound {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, RETURN_EXPR)
+        matches!(kind, TYPE_BOUND)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(ReturnExpr { syntax })
+            Some(TypeBound { syntax })
         } else {
             None
         }
@@ -1241,26 +2738,26 @@ impl AstNode for ReturnExpr {
         &self.syntax
     }
 }
-impl ReturnExpr {
-    pub fn expr(&self) -> Option<Expr> {
+impl TypeBound {
+    pub fn type_ref(&self) -> Option<TypeRef> {
         super::child_opt(self)
     }
 }
 
-// SourceFile
+// TypeBoundList
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SourceFile {
+pub struct TypeBoundList {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for SourceFile {
+impl AstNode for TypeBoundList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, SOURCE_FILE)
+        matches!(kind, TYPE_BOUND_LIST)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(SourceFile { syntax })
+            Some(TypeBoundList { syntax })
         } else {
             None
         }
@@ -1269,24 +2766,26 @@ impl AstNode for SourceFile {
         &self.syntax
     }
 }
-impl ast::ModuleItemOwner for SourceFile {}
-impl ast::FunctionDefOwner for SourceFile {}
-impl SourceFile {}
+impl TypeBoundList {
+    pub fn bounds(&self) -> impl Iterator<Item = TypeBound> {
+        super::children(self)
+    }
+}
 
-// Stmt
+// TypeParam
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Stmt {
+pub struct TypeParam {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for Stmt {
+impl AstNode for TypeParam {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, LET_STMT | EXPR_STMT)
+        matches!(kind, TYPE_PARAM)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(Stmt { syntax })
+            Some(TypeParam { syntax })
         } else {
             None
         }
@@ -1295,48 +2794,27 @@ impl AstNode for Stmt {
         &self.syntax
     }
 }
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum StmtKind {
-    LetStmt(LetStmt),
-    ExprStmt(ExprStmt),
-}
-impl From<LetStmt> for Stmt {
-    fn from(n: LetStmt) -> Stmt {
-        Stmt { syntax: n.syntax }
-    }
-}
-impl From<ExprStmt> for Stmt {
-    fn from(n: ExprStmt) -> Stmt {
-        Stmt { syntax: n.syntax }
-    }
-}
-
-impl Stmt {
-    pub fn kind(&self) -> StmtKind {
-        match self.syntax.kind() {
-            LET_STMT => StmtKind::LetStmt(LetStmt::cast(self.syntax.clone()).unwrap()),
-            EXPR_STMT => StmtKind::ExprStmt(ExprStmt::cast(self.syntax.clone()).unwrap()),
-            _ => unreachable!(),
-        }
+impl ast::NameOwner for TypeParam {}
+impl TypeParam {
+    pub fn type_bound_list(&self) -> Option<TypeBoundList> {
+        super::child_opt(self)
     }
 }
 
-impl Stmt {}
-
-// StructDef
+// TypeParamList
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StructDef {
+pub struct TypeParamList {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for StructDef {
+impl AstNode for TypeParamList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, STRUCT_DEF)
+        matches!(kind, TYPE_PARAM_LIST)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(StructDef { syntax })
+            Some(TypeParamList { syntax })
         } else {
             None
         }
@@ -1345,29 +2823,35 @@ impl AstNode for StructDef {
         &self.syntax
     }
 }
-impl ast::NameOwner for StructDef {}
-impl ast::VisibilityOwner for StructDef {}
-impl ast::DocCommentsOwner for StructDef {}
-impl StructDef {
-    pub fn memory_type_specifier(&self) -> Option<MemoryTypeSpecifier> {
-        super::child_opt(self)
+impl TypeParamList {
+    pub fn type_params(&self) -> impl Iterator<Item = TypeParam> {
+        super::children(self)
     }
 }
 
-// TupleFieldDef
+// TypeRef
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TupleFieldDef {
+pub struct TypeRef {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for TupleFieldDef {
+impl AstNode for TypeRef {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, TUPLE_FIELD_DEF)
+        matches!(
+            kind,
+            PATH_TYPE
+                | NEVER_TYPE
+                | ARRAY_TYPE
+                | FN_POINTER_TYPE
+                | PAREN_TYPE
+                | TUPLE_TYPE
+                | NULLABLE_TYPE
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(TupleFieldDef { syntax })
+            Some(TypeRef { syntax })
         } else {
             None
         }
@@ -1376,27 +2860,87 @@ impl AstNode for TupleFieldDef {
         &self.syntax
     }
 }
-impl ast::VisibilityOwner for TupleFieldDef {}
-impl TupleFieldDef {
-    pub fn type_ref(&self) -> Option<TypeRef> {
-        super::child_opt(self)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeRefKind {
+    PathType(PathType),
+    NeverType(NeverType),
+    ArrayType(ArrayType),
+    FnPointerType(FnPointerType),
+    ParenType(ParenType),
+    TupleType(TupleType),
+    NullableType(NullableType),
+}
+impl From<PathType> for TypeRef {
+    fn from(n: PathType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
+impl From<NeverType> for TypeRef {
+    fn from(n: NeverType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
+impl From<ArrayType> for TypeRef {
+    fn from(n: ArrayType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
+impl From<FnPointerType> for TypeRef {
+    fn from(n: FnPointerType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
+impl From<ParenType> for TypeRef {
+    fn from(n: ParenType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
+impl From<TupleType> for TypeRef {
+    fn from(n: TupleType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
+    }
+}
+impl From<NullableType> for TypeRef {
+    fn from(n: NullableType) -> TypeRef {
+        TypeRef { syntax: n.syntax }
     }
 }
 
-// TupleFieldDefList
+impl TypeRef {
+    pub fn kind(&self) -> TypeRefKind {
+        match self.syntax.kind() {
+            PATH_TYPE => TypeRefKind::PathType(PathType::cast(self.syntax.clone()).unwrap()),
+            NEVER_TYPE => TypeRefKind::NeverType(NeverType::cast(self.syntax.clone()).unwrap()),
+            ARRAY_TYPE => TypeRefKind::ArrayType(ArrayType::cast(self.syntax.clone()).unwrap()),
+            FN_POINTER_TYPE => {
+                TypeRefKind::FnPointerType(FnPointerType::cast(self.syntax.clone()).unwrap())
+            }
+            PAREN_TYPE => TypeRefKind::ParenType(ParenType::cast(self.syntax.clone()).unwrap()),
+            TUPLE_TYPE => TypeRefKind::TupleType(TupleType::cast(self.syntax.clone()).unwrap()),
+            NULLABLE_TYPE => {
+                TypeRefKind::NullableType(NullableType::cast(self.syntax.clone()).unwrap())
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl TypeRef {}
+
+// Use
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TupleFieldDefList {
+pub struct Use {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for TupleFieldDefList {
+impl AstNode for Use {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, TUPLE_FIELD_DEF_LIST)
+        matches!(kind, USE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(TupleFieldDefList { syntax })
+            Some(Use { syntax })
         } else {
             None
         }
@@ -1405,26 +2949,28 @@ impl AstNode for TupleFieldDefList {
         &self.syntax
     }
 }
-impl TupleFieldDefList {
-    pub fn fields(&self) -> impl Iterator<Item = TupleFieldDef> {
-        super::children(self)
+impl ast::VisibilityOwner for Use {}
+impl ast::DocCommentsOwner for Use {}
+impl Use {
+    pub fn use_tree(&self) -> Option<UseTree> {
+        super::child_opt(self)
     }
 }
 
-// TypeAliasDef
+// UseTree
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TypeAliasDef {
+pub struct UseTree {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for TypeAliasDef {
+impl AstNode for UseTree {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, TYPE_ALIAS_DEF)
+        matches!(kind, USE_TREE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(TypeAliasDef { syntax })
+            Some(UseTree { syntax })
         } else {
             None
         }
@@ -1433,28 +2979,30 @@ impl AstNode for TypeAliasDef {
         &self.syntax
     }
 }
-impl ast::NameOwner for TypeAliasDef {}
-impl ast::DocCommentsOwner for TypeAliasDef {}
-impl TypeAliasDef {
-    pub fn type_ref(&self) -> Option<TypeRef> {
+impl UseTree {
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+
+    pub fn use_tree_list(&self) -> Option<UseTreeList> {
         super::child_opt(self)
     }
 }
 
-// TypeRef
+// UseTreeList
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TypeRef {
+pub struct UseTreeList {
     pub(crate) syntax: SyntaxNode,
 }
 
-impl AstNode for TypeRef {
+impl AstNode for UseTreeList {
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, PATH_TYPE | NEVER_TYPE)
+        matches!(kind, USE_TREE_LIST)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
-            Some(TypeRef { syntax })
+            Some(UseTreeList { syntax })
         } else {
             None
         }
@@ -1463,34 +3011,12 @@ impl AstNode for TypeRef {
         &self.syntax
     }
 }
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum TypeRefKind {
-    PathType(PathType),
-    NeverType(NeverType),
-}
-impl From<PathType> for TypeRef {
-    fn from(n: PathType) -> TypeRef {
-        TypeRef { syntax: n.syntax }
-    }
-}
-impl From<NeverType> for TypeRef {
-    fn from(n: NeverType) -> TypeRef {
-        TypeRef { syntax: n.syntax }
-    }
-}
-
-impl TypeRef {
-    pub fn kind(&self) -> TypeRefKind {
-        match self.syntax.kind() {
-            PATH_TYPE => TypeRefKind::PathType(PathType::cast(self.syntax.clone()).unwrap()),
-            NEVER_TYPE => TypeRefKind::NeverType(NeverType::cast(self.syntax.clone()).unwrap()),
-            _ => unreachable!(),
-        }
+impl UseTreeList {
+    pub fn use_trees(&self) -> impl Iterator<Item = UseTree> {
+        super::children(self)
     }
 }
 
-impl TypeRef {}
-
 // Visibility
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
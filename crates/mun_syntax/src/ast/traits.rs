@@ -20,6 +20,12 @@ pub trait NameOwner: AstNode {
     }
 }
 
+pub trait TypeParamsOwner: AstNode {
+    fn type_param_list(&self) -> Option<ast::TypeParamList> {
+        child_opt(self)
+    }
+}
+
 pub trait TypeAscriptionOwner: AstNode {
     fn ascribed_type(&self) -> Option<ast::TypeRef> {
         child_opt(self)
@@ -33,6 +39,10 @@ pub trait VisibilityOwner: AstNode {
 }
 
 pub trait LoopBodyOwner: AstNode {
+    fn label(&self) -> Option<ast::Label> {
+        child_opt(self)
+    }
+
     fn loop_body(&self) -> Option<ast::BlockExpr> {
         child_opt(self)
     }
@@ -52,6 +62,12 @@ pub trait DocCommentsOwner: AstNode {
     }
 }
 
+pub trait AttrsOwner: AstNode {
+    fn attrs(&self) -> AstChildren<ast::Attr> {
+        children(self)
+    }
+}
+
 pub struct CommentIter {
     iter: SyntaxElementChildren,
 }
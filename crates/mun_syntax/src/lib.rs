@@ -163,7 +163,13 @@ fn api_walkthrough() {
         match item.kind() {
             ast::ModuleItemKind::FunctionDef(f) => func = Some(f),
             ast::ModuleItemKind::StructDef(_) => (),
+            ast::ModuleItemKind::EnumDef(_) => (),
             ast::ModuleItemKind::TypeAliasDef(_) => (),
+            ast::ModuleItemKind::ConstDef(_) => (),
+            ast::ModuleItemKind::StaticDef(_) => (),
+            ast::ModuleItemKind::ImplDef(_) => (),
+            ast::ModuleItemKind::TraitDef(_) => (),
+            ast::ModuleItemKind::Use(_) => (),
         }
     }
 
@@ -1,10 +1,14 @@
 mod adt;
+mod attributes;
 mod declarations;
 mod expressions;
 mod params;
 mod paths;
 mod patterns;
+mod type_args;
+mod type_params;
 mod types;
+mod use_item;
 
 use super::{
     parser::{CompletedMarker, Marker, Parser},
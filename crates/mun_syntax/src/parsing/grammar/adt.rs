@@ -5,6 +5,7 @@ pub(super) fn struct_def(p: &mut Parser, m: Marker) {
     p.bump(T![struct]);
     opt_memory_type_specifier(p);
     name_recovery(p, declarations::DECLARATION_RECOVERY_SET);
+    type_params::opt_type_param_list(p);
     match p.current() {
         T![;] => {
             p.bump(T![;]);
@@ -18,6 +19,46 @@ pub(super) fn struct_def(p: &mut Parser, m: Marker) {
     m.complete(p, STRUCT_DEF);
 }
 
+pub(super) fn enum_def(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![enum]));
+    p.bump(T![enum]);
+    name_recovery(p, declarations::DECLARATION_RECOVERY_SET);
+    if p.at(T!['{']) {
+        enum_variant_list(p);
+    } else {
+        p.error("expected '{'");
+    }
+    m.complete(p, ENUM_DEF);
+}
+
+pub(super) fn enum_variant_list(p: &mut Parser) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(T!['}']) && !p.at(EOF) {
+        if p.at(T!['{']) {
+            error_block(p, "expected an enum variant");
+            continue;
+        }
+        let var = p.start();
+        if p.at(IDENT) {
+            name(p);
+            if p.at(T!['(']) {
+                tuple_field_def_list(p);
+            }
+            var.complete(p, ENUM_VARIANT);
+        } else {
+            var.abandon(p);
+            p.error_and_bump("expected an enum variant");
+        }
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, ENUM_VARIANT_LIST);
+}
+
 pub(super) fn type_alias_def(p: &mut Parser, m: Marker) {
     assert!(p.at(T![type]));
     p.bump(T![type]);
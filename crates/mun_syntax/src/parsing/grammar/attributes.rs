@@ -0,0 +1,48 @@
+use super::*;
+
+/// Parses all attributes that precede an item or a block, e.g. `#[allow(unused_variables)]`.
+pub(super) fn outer_attributes(p: &mut Parser) {
+    while p.at(T![#]) {
+        attribute(p);
+    }
+}
+
+fn attribute(p: &mut Parser) {
+    assert!(p.at(T![#]));
+    let m = p.start();
+    p.bump(T![#]);
+    if p.expect(T!['[']) {
+        if paths::is_path_start(p) {
+            paths::use_path(p);
+        } else {
+            p.error("expected an attribute name");
+        }
+        if p.at(T!['(']) {
+            token_tree(p);
+        }
+        p.expect(T![']']);
+    }
+    m.complete(p, ATTR);
+}
+
+/// Parses a sequence of arbitrary tokens between balanced delimiters, e.g. the arguments
+/// `(unused_variables, dead_code)` of an attribute.
+fn token_tree(p: &mut Parser) {
+    let closing = match p.current() {
+        T!['('] => T![')'],
+        T!['['] => T![']'],
+        T!['{'] => T!['}'],
+        _ => unreachable!(),
+    };
+    let m = p.start();
+    p.bump_any();
+    while !p.at(EOF) && !p.at(closing) {
+        match p.current() {
+            T!['('] | T!['['] | T!['{'] => token_tree(p),
+            T![')'] | T![']'] | T!['}'] => p.error_and_bump("unmatched delimiter"),
+            _ => p.bump_any(),
+        }
+    }
+    p.expect(closing);
+    m.complete(p, TOKEN_TREE);
+}
@@ -1,7 +1,8 @@
 use super::*;
 use crate::T;
 
-pub(super) const DECLARATION_RECOVERY_SET: TokenSet = token_set![FN_KW, PUB_KW, STRUCT_KW];
+pub(super) const DECLARATION_RECOVERY_SET: TokenSet =
+    token_set![FN_KW, PUB_KW, STRUCT_KW, ENUM_KW, IMPL_KW, TRAIT_KW, USE_KW, CONST_KW, STATIC_KW];
 
 pub(super) fn mod_contents(p: &mut Parser) {
     while !p.at(EOF) {
@@ -11,6 +12,7 @@ pub(super) fn mod_contents(p: &mut Parser) {
 
 pub(super) fn declaration(p: &mut Parser) {
     let m = p.start();
+    attributes::outer_attributes(p);
     let m = match maybe_declaration(p, m) {
         Ok(()) => return,
         Err(m) => m,
@@ -39,6 +41,7 @@ pub(super) fn maybe_declaration(p: &mut Parser, m: Marker) -> Result<(), Marker>
         Err(m) => m,
     };
 
+    p.eat(T![const]);
     if p.at(T![extern]) {
         abi(p);
     }
@@ -65,19 +68,115 @@ fn declarations_without_modifiers(p: &mut Parser, m: Marker) -> Result<(), Marke
         T![struct] => {
             adt::struct_def(p, m);
         }
+        T![enum] => {
+            adt::enum_def(p, m);
+        }
         T![type] => {
             adt::type_alias_def(p, m);
         }
+        T![impl] => {
+            impl_def(p, m);
+        }
+        T![trait] => {
+            trait_def(p, m);
+        }
+        T![use] => {
+            use_item::use_(p, m);
+        }
+        T![const] if p.nth(1) != T![fn] => {
+            const_def(p, m);
+        }
+        T![static] => {
+            static_def(p, m);
+        }
         _ => return Err(m),
     };
     Ok(())
 }
 
+fn const_def(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![const]));
+    p.bump(T![const]);
+    const_or_static_body(p);
+    m.complete(p, CONST_DEF);
+}
+
+fn static_def(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![static]));
+    p.bump(T![static]);
+    p.eat(T![mut]);
+    const_or_static_body(p);
+    m.complete(p, STATIC_DEF);
+}
+
+/// Parses the part of a `const` or `static` item that follows the keywords, e.g. `FOO: i32 = 5;`
+fn const_or_static_body(p: &mut Parser) {
+    name(p);
+    types::ascription(p);
+    if p.expect(T![=]) {
+        expressions::expr(p);
+    }
+    p.expect(T![;]);
+}
+
+fn impl_def(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![impl]));
+    p.bump(T![impl]);
+    types::type_(p);
+    if p.eat(T![for]) {
+        types::type_(p);
+    }
+    if p.at(T!['{']) {
+        impl_item_list(p);
+    } else {
+        p.error("expected '{'");
+    }
+    m.complete(p, IMPL_DEF);
+}
+
+fn trait_def(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![trait]));
+    p.bump(T![trait]);
+    name_recovery(p, DECLARATION_RECOVERY_SET.union(token_set![L_CURLY]));
+    if p.at(T!['{']) {
+        impl_item_list(p);
+    } else {
+        p.error("expected '{'");
+    }
+    m.complete(p, TRAIT_DEF);
+}
+
+fn impl_item_list(p: &mut Parser) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(T!['}']) && !p.at(EOF) {
+        if p.at(T!['{']) {
+            error_block(p, "expected a function");
+            continue;
+        }
+        let item = p.start();
+        attributes::outer_attributes(p);
+        opt_visibility(p);
+        p.eat(T![const]);
+        if p.at(T![fn]) {
+            fn_def(p);
+            item.complete(p, FUNCTION_DEF);
+        } else {
+            item.abandon(p);
+            p.error_and_bump("expected a function");
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, ITEM_LIST);
+}
+
 pub(super) fn fn_def(p: &mut Parser) {
     assert!(p.at(T![fn]));
     p.bump(T![fn]);
 
     name_recovery(p, DECLARATION_RECOVERY_SET.union(token_set![L_PAREN]));
+    type_params::opt_type_param_list(p);
 
     if p.at(T!['(']) {
         params::param_list(p);
@@ -94,7 +193,7 @@ pub(super) fn fn_def(p: &mut Parser) {
     }
 }
 
-fn opt_fn_ret_type(p: &mut Parser) -> bool {
+pub(super) fn opt_fn_ret_type(p: &mut Parser) -> bool {
     if p.at(T![->]) {
         let m = p.start();
         p.bump(T![->]);
@@ -10,11 +10,20 @@ const ATOM_EXPR_FIRST: TokenSet = LITERAL_FIRST.union(PATH_FIRST).union(token_se
     IDENT,
     T!['('],
     T!['{'],
+    T!['['],
+    T![#],
     T![if],
     T![loop],
     T![return],
     T![break],
+    T![continue],
     T![while],
+    T![for],
+    T![match],
+    T![nil],
+    T![|],
+    T![||],
+    LABEL_IDENT,
 ]);
 
 const LHS_FIRST: TokenSet = ATOM_EXPR_FIRST.union(token_set![EXCLAMATION, MINUS]);
@@ -53,9 +62,13 @@ pub(crate) fn block(p: &mut Parser) {
 }
 
 fn block_expr(p: &mut Parser) -> CompletedMarker {
-    assert!(p.at(T!['{']));
+    assert!(p.at(T!['{']) || p.at(T![#]));
     let m = p.start();
-    p.bump(T!['{']);
+    attributes::outer_attributes(p);
+    if !p.eat(T!['{']) {
+        p.error("expected a block");
+        return m.complete(p, ERROR);
+    }
     expr_block_contents(p);
     p.expect(T!['}']);
     m.complete(p, BLOCK_EXPR)
@@ -141,11 +154,22 @@ fn expr_bp(p: &mut Parser, r: Restrictions, bp: u8) -> (Option<CompletedMarker>,
             break;
         }
 
+        if p.at(T![as]) {
+            lhs = cast_expr(p, lhs);
+            continue;
+        }
+
+        let kind = if matches!(op, T![..] | T![..=]) {
+            RANGE_EXPR
+        } else {
+            BIN_EXPR
+        };
+
         let m = lhs.precede(p);
         p.bump(op);
 
         expr_bp(p, r, op_bp + 1);
-        lhs = m.complete(p, BIN_EXPR);
+        lhs = m.complete(p, kind);
     }
 
     (Some(lhs), BlockLike::NotBlock)
@@ -173,6 +197,8 @@ fn current_op(p: &Parser) -> (u8, SyntaxKind) {
         T![^] => (7, T![^]),
         T![=] if p.at(T![==]) => (5, T![==]),
         T![=] => (1, T![=]),
+        T![.] if p.at(T![..=]) => (2, T![..=]),
+        T![.] if p.at(T![..]) => (2, T![..]),
         T![!] if p.at(T![!=]) => (5, T![!=]),
         T![>] if p.at(T![>>=]) => (1, T![>>=]),
         T![>] if p.at(T![>>]) => (9, T![>>]),
@@ -182,6 +208,7 @@ fn current_op(p: &Parser) -> (u8, SyntaxKind) {
         T![<] if p.at(T![<<=]) => (1, T![<<=]),
         T![<] if p.at(T![<<]) => (9, T![<<]),
         T![<] => (5, T![<]),
+        T![as] => (12, T![as]),
         _ => (0, T![_]),
     }
 }
@@ -211,7 +238,8 @@ fn postfix_expr(
     loop {
         lhs = match p.current() {
             T!['('] => call_expr(p, lhs),
-            T![.] => match postfix_dot_expr(p, lhs) {
+            T!['['] => index_expr(p, lhs),
+            T![.] if !p.at(T![..]) => match postfix_dot_expr(p, lhs) {
                 Ok(it) => it,
                 Err(it) => {
                     lhs = it;
@@ -225,6 +253,14 @@ fn postfix_expr(
     (lhs, BlockLike::NotBlock)
 }
 
+fn cast_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    assert!(p.at(T![as]));
+    let m = lhs.precede(p);
+    p.bump(T![as]);
+    types::type_(p);
+    m.complete(p, CAST_EXPR)
+}
+
 fn call_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
     assert!(p.at(T!['(']));
     let m = lhs.precede(p);
@@ -232,6 +268,15 @@ fn call_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
     m.complete(p, CALL_EXPR)
 }
 
+fn index_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    assert!(p.at(T!['[']));
+    let m = lhs.precede(p);
+    p.bump(T!['[']);
+    expr(p);
+    p.expect(T![']']);
+    m.complete(p, INDEX_EXPR)
+}
+
 fn arg_list(p: &mut Parser) {
     assert!(p.at(T!['(']));
     let m = p.start();
@@ -257,12 +302,21 @@ fn postfix_dot_expr(
 ) -> Result<CompletedMarker, CompletedMarker> {
     assert!(p.at(T![.]));
     if p.nth(1) == IDENT && p.nth(2) == T!['('] {
-        unimplemented!("Method calls are not supported yet.");
+        return Ok(method_call_expr(p, lhs));
     }
 
     Ok(field_expr(p, lhs))
 }
 
+fn method_call_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    assert!(p.at(T![.]) && p.nth(1) == IDENT && p.nth(2) == T!['(']);
+    let m = lhs.precede(p);
+    p.bump(T![.]);
+    name_ref(p);
+    arg_list(p);
+    m.complete(p, METHOD_CALL_EXPR)
+}
+
 fn field_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
     assert!(p.at(T![.]) || p.at(INDEX));
     let m = lhs.precede(p);
@@ -288,19 +342,38 @@ fn atom_expr(p: &mut Parser, r: Restrictions) -> Option<(CompletedMarker, BlockL
 
     let marker = match p.current() {
         T!['('] => paren_expr(p),
-        T!['{'] => block_expr(p),
+        T!['{'] | T![#] => block_expr(p),
+        T!['['] => array_expr(p),
         T![if] => if_expr(p),
-        T![loop] => loop_expr(p),
+        T![loop] => loop_expr(p, None),
         T![return] => ret_expr(p),
-        T![while] => while_expr(p),
+        T![while] => while_expr(p, None),
+        T![for] => for_expr(p, None),
+        T![match] => match_expr(p),
         T![break] => break_expr(p, r),
+        T![continue] => continue_expr(p),
+        LABEL_IDENT if p.nth_at(1, T![:]) => {
+            let m = p.start();
+            label(p);
+            match p.current() {
+                T![loop] => loop_expr(p, Some(m)),
+                T![while] => while_expr(p, Some(m)),
+                T![for] => for_expr(p, Some(m)),
+                _ => {
+                    p.error("expected a loop");
+                    m.complete(p, ERROR)
+                }
+            }
+        }
+        T![|] | T![||] => lambda_expr(p),
+        T![nil] => nil_expr(p),
         _ => {
             p.error_recover("expected expression", EXPR_RECOVERY_SET);
             return None;
         }
     };
     let blocklike = match marker.kind() {
-        IF_EXPR | WHILE_EXPR | LOOP_EXPR | BLOCK_EXPR => BlockLike::Block,
+        IF_EXPR | WHILE_EXPR | LOOP_EXPR | FOR_EXPR | MATCH_EXPR | BLOCK_EXPR => BlockLike::Block,
         _ => BlockLike::NotBlock,
     };
     Some((marker, blocklike))
@@ -320,7 +393,7 @@ fn path_expr(p: &mut Parser, r: Restrictions) -> (CompletedMarker, BlockLike) {
     }
 }
 
-fn literal(p: &mut Parser) -> Option<CompletedMarker> {
+pub(super) fn literal(p: &mut Parser) -> Option<CompletedMarker> {
     if !p.at_ts(LITERAL_FIRST) {
         return None;
     }
@@ -329,13 +402,55 @@ fn literal(p: &mut Parser) -> Option<CompletedMarker> {
     Some(m.complete(p, LITERAL))
 }
 
+/// Parses a parenthesized expression (e.g. `(a)`) or a tuple expression (e.g. `()`, `(a,)` or
+/// `(a, b)`)
 fn paren_expr(p: &mut Parser) -> CompletedMarker {
     assert!(p.at(T!['(']));
     let m = p.start();
     p.bump(T!['(']);
-    expr(p);
+    let mut num_exprs = 0;
+    let mut trailing_comma = false;
+    while !p.at(EOF) && !p.at(T![')']) {
+        if !p.at_ts(EXPR_FIRST) {
+            p.error("expected expression");
+            break;
+        }
+
+        num_exprs += 1;
+        expr(p);
+        if p.eat(T![,]) {
+            trailing_comma = true;
+        } else {
+            trailing_comma = false;
+            break;
+        }
+    }
     p.expect(T![')']);
-    m.complete(p, PAREN_EXPR)
+
+    if num_exprs == 1 && !trailing_comma {
+        m.complete(p, PAREN_EXPR)
+    } else {
+        m.complete(p, TUPLE_EXPR)
+    }
+}
+
+fn array_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T!['[']));
+    let m = p.start();
+    p.bump(T!['[']);
+    while !p.at(T![']']) && !p.at(EOF) {
+        if !p.at_ts(EXPR_FIRST) {
+            p.error("expected expression");
+            break;
+        }
+
+        expr(p);
+        if !p.at(T![']']) && !p.expect(T![,]) {
+            break;
+        }
+    }
+    p.expect(T![']']);
+    m.complete(p, ARRAY_EXPR)
 }
 
 fn if_expr(p: &mut Parser) -> CompletedMarker {
@@ -355,9 +470,18 @@ fn if_expr(p: &mut Parser) -> CompletedMarker {
     m.complete(p, IF_EXPR)
 }
 
-fn loop_expr(p: &mut Parser) -> CompletedMarker {
-    assert!(p.at(T![loop]));
+/// Parses the label of a loop, e.g. `'outer:` in `'outer: loop {}`.
+fn label(p: &mut Parser) {
+    assert!(p.at(LABEL_IDENT) && p.nth_at(1, T![:]));
     let m = p.start();
+    p.bump(LABEL_IDENT);
+    p.bump(T![:]);
+    m.complete(p, LABEL);
+}
+
+fn loop_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
+    assert!(p.at(T![loop]));
+    let m = m.unwrap_or_else(|| p.start());
     p.bump(T![loop]);
     block(p);
     m.complete(p, LOOP_EXPR)
@@ -365,10 +489,21 @@ fn loop_expr(p: &mut Parser) -> CompletedMarker {
 
 fn cond(p: &mut Parser) {
     let m = p.start();
+    if p.eat(T![let]) {
+        patterns::pattern(p);
+        p.expect(T![=]);
+    }
     expr_no_struct(p);
     m.complete(p, CONDITION);
 }
 
+fn nil_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![nil]));
+    let m = p.start();
+    p.bump(T![nil]);
+    m.complete(p, NIL_EXPR)
+}
+
 fn ret_expr(p: &mut Parser) -> CompletedMarker {
     assert!(p.at(T![return]));
     let m = p.start();
@@ -383,21 +518,98 @@ fn break_expr(p: &mut Parser, r: Restrictions) -> CompletedMarker {
     assert!(p.at(T![break]));
     let m = p.start();
     p.bump(T![break]);
+    p.eat(LABEL_IDENT);
     if p.at_ts(EXPR_FIRST) && !(r.forbid_structs && p.at(T!['{'])) {
         expr(p);
     }
     m.complete(p, BREAK_EXPR)
 }
 
-fn while_expr(p: &mut Parser) -> CompletedMarker {
-    assert!(p.at(T![while]));
+fn continue_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![continue]));
+    let m = p.start();
+    p.bump(T![continue]);
+    p.eat(LABEL_IDENT);
+    m.complete(p, CONTINUE_EXPR)
+}
+
+fn lambda_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![|]) || p.at(T![||]));
     let m = p.start();
+    params::lambda_param_list(p);
+    if declarations::opt_fn_ret_type(p) {
+        // With an explicit return type the body must be a block
+        block(p);
+    } else if p.at_ts(EXPR_FIRST) {
+        expr(p);
+    } else {
+        p.error("expected expression");
+    }
+    m.complete(p, LAMBDA_EXPR)
+}
+
+fn while_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
+    assert!(p.at(T![while]));
+    let m = m.unwrap_or_else(|| p.start());
     p.bump(T![while]);
     cond(p);
     block(p);
     m.complete(p, WHILE_EXPR)
 }
 
+fn for_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
+    assert!(p.at(T![for]));
+    let m = m.unwrap_or_else(|| p.start());
+    p.bump(T![for]);
+    patterns::pattern(p);
+    p.expect(T![in]);
+    expr_no_struct(p);
+    block(p);
+    m.complete(p, FOR_EXPR)
+}
+
+fn match_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T![match]));
+    let m = p.start();
+    p.bump(T![match]);
+    expr_no_struct(p);
+    if p.at(T!['{']) {
+        match_arm_list(p);
+    } else {
+        p.error("expected `{`");
+    }
+    m.complete(p, MATCH_EXPR)
+}
+
+fn match_arm_list(p: &mut Parser) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if p.at(T!['{']) {
+            error_block(p, "expected match arm");
+            continue;
+        }
+        let blocklike = match_arm(p);
+        if blocklike.is_block() {
+            p.eat(T![,]);
+        } else if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, MATCH_ARM_LIST);
+}
+
+fn match_arm(p: &mut Parser) -> BlockLike {
+    let m = p.start();
+    patterns::pattern(p);
+    p.expect(T![=>]);
+    let (_, blocklike) = expr_stmt(p);
+    m.complete(p, MATCH_ARM);
+    blocklike
+}
+
 fn record_field_list(p: &mut Parser) {
     assert!(p.at(T!['{']));
     let m = p.start();
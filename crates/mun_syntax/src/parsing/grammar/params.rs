@@ -4,10 +4,54 @@ pub(super) fn param_list(p: &mut Parser) {
     list(p)
 }
 
+/// Parses the parameters of a lambda expression, e.g. `|a, b: i32|`. The types of the parameters
+/// are optional.
+pub(super) fn lambda_param_list(p: &mut Parser) {
+    let m = p.start();
+    if !p.eat(T![||]) {
+        p.bump(T![|]);
+        while !p.at(EOF) && !p.at(T![|]) {
+            if !p.at_ts(VALUE_PARAMETER_FIRST) {
+                p.error("expected value parameter");
+                break;
+            }
+            lambda_param(p);
+            if !p.at(T![|]) {
+                p.expect(T![,]);
+            }
+        }
+        p.expect(T![|]);
+    }
+    m.complete(p, PARAM_LIST);
+}
+
+/// Parses the parameters of a function pointer type, e.g. `(i32, f64)`. The parameters only
+/// consist of a type.
+pub(super) fn fn_pointer_param_list(p: &mut Parser) {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.bump(T!['(']);
+    while !p.at(EOF) && !p.at(T![')']) {
+        if !p.at_ts(types::TYPE_FIRST) {
+            p.error("expected type");
+            break;
+        }
+        let param = p.start();
+        types::type_(p);
+        param.complete(p, PARAM);
+        if !p.at(T![')']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T![')']);
+    m.complete(p, PARAM_LIST);
+}
+
 fn list(p: &mut Parser) {
     assert!(p.at(T!['(']));
     let m = p.start();
     p.bump(T!['(']);
+    opt_self_param(p);
     while !p.at(EOF) && !p.at(T![')']) {
         if !p.at_ts(VALUE_PARAMETER_FIRST) {
             p.error("expected value parameter");
@@ -22,6 +66,18 @@ fn list(p: &mut Parser) {
     m.complete(p, PARAM_LIST);
 }
 
+fn opt_self_param(p: &mut Parser) {
+    if p.at(T![self]) || p.at(T![mut]) && p.nth_at(1, T![self]) {
+        let m = p.start();
+        p.eat(T![mut]);
+        p.bump(T![self]);
+        m.complete(p, SELF_PARAM);
+        if !p.at(T![')']) {
+            p.expect(T![,]);
+        }
+    }
+}
+
 const VALUE_PARAMETER_FIRST: TokenSet = patterns::PATTERN_FIRST;
 
 fn param(p: &mut Parser) {
@@ -30,3 +86,12 @@ fn param(p: &mut Parser) {
     types::ascription(p);
     m.complete(p, PARAM);
 }
+
+fn lambda_param(p: &mut Parser) {
+    let m = p.start();
+    patterns::pattern(p);
+    if p.at(T![:]) {
+        types::ascription(p);
+    }
+    m.complete(p, PARAM);
+}
@@ -1,9 +1,13 @@
 use super::*;
 
-pub(super) const PATH_FIRST: TokenSet = token_set![IDENT, SELF_KW, SUPER_KW, COLONCOLON];
+pub(super) const PATH_FIRST: TokenSet =
+    token_set![IDENT, SELF_KW, SUPER_KW, PACKAGE_KW, COLONCOLON];
 
 pub(super) fn is_path_start(p: &Parser) -> bool {
-    matches!(p.current(), IDENT | T![::])
+    matches!(
+        p.current(),
+        IDENT | T![self] | T![super] | T![package] | T![::]
+    )
 }
 
 pub(super) fn type_path(p: &mut Parser) {
@@ -12,9 +16,13 @@ pub(super) fn type_path(p: &mut Parser) {
 pub(super) fn expr_path(p: &mut Parser) {
     path(p, Mode::Expr)
 }
+pub(super) fn use_path(p: &mut Parser) {
+    path(p, Mode::Use)
+}
 
 #[derive(Clone, Copy, Eq, PartialEq)]
 enum Mode {
+    Use,
     Type,
     Expr,
 }
@@ -37,7 +45,7 @@ fn path(p: &mut Parser, mode: Mode) {
     }
 }
 
-fn path_segment(p: &mut Parser, _mode: Mode, first: bool) {
+fn path_segment(p: &mut Parser, mode: Mode, first: bool) {
     let m = p.start();
     if first {
         p.eat(T![::]);
@@ -45,8 +53,11 @@ fn path_segment(p: &mut Parser, _mode: Mode, first: bool) {
     match p.current() {
         IDENT => {
             name_ref(p);
+            if mode == Mode::Type {
+                type_args::opt_type_arg_list(p);
+            }
         }
-        T![self] | T![super] => p.bump_any(),
+        T![self] | T![super] | T![package] => p.bump_any(),
         _ => p.error_recover(
             "expected identifier",
             declarations::DECLARATION_RECOVERY_SET,
@@ -2,7 +2,7 @@ use super::*;
 
 pub(super) const PATTERN_FIRST: TokenSet = expressions::LITERAL_FIRST
     .union(paths::PATH_FIRST)
-    .union(token_set![MINUS, UNDERSCORE]);
+    .union(token_set![MINUS, UNDERSCORE, L_PAREN, MUT_KW]);
 
 pub(super) fn pattern(p: &mut Parser) {
     pattern_r(p, PATTERN_FIRST);
@@ -14,12 +14,21 @@ pub(super) fn pattern_r(p: &mut Parser, recovery_set: TokenSet) {
 
 fn atom_pat(p: &mut Parser, recovery_set: TokenSet) -> Option<CompletedMarker> {
     let t1 = p.nth(0);
-    if t1 == IDENT {
+    if t1 == T![mut] || t1 == IDENT && !(p.nth_at(1, T![::]) || p.nth_at(1, T!['('])) {
         return Some(bind_pat(p));
     }
 
+    if paths::is_path_start(p) {
+        return Some(path_pat(p));
+    }
+
+    if is_literal_pat_start(p) {
+        return Some(literal_pat(p));
+    }
+
     let m = match t1 {
         T![_] => placeholder_pat(p),
+        T!['('] => tuple_pat(p),
         _ => {
             p.error_recover("expected pattern", recovery_set);
             return None;
@@ -35,8 +44,88 @@ fn placeholder_pat(p: &mut Parser) -> CompletedMarker {
     m.complete(p, PLACEHOLDER_PAT)
 }
 
+/// Parses a parenthesized pattern (e.g. `(a)`) or a tuple pattern (e.g. `()`, `(a,)` or
+/// `(a, _)`)
+fn tuple_pat(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.bump(T!['(']);
+    let mut num_pats = 0;
+    let mut trailing_comma = false;
+    while !p.at(EOF) && !p.at(T![')']) {
+        if !p.at_ts(PATTERN_FIRST) {
+            p.error("expected a pattern");
+            break;
+        }
+
+        num_pats += 1;
+        pattern(p);
+        if p.eat(T![,]) {
+            trailing_comma = true;
+        } else {
+            trailing_comma = false;
+            break;
+        }
+    }
+    p.expect(T![')']);
+
+    if num_pats == 1 && !trailing_comma {
+        m.complete(p, PAREN_PAT)
+    } else {
+        m.complete(p, TUPLE_PAT)
+    }
+}
+
+/// Parses a binding pattern, e.g. `a` or `mut a`
 fn bind_pat(p: &mut Parser) -> CompletedMarker {
     let m = p.start();
+    p.eat(T![mut]);
     name(p);
     m.complete(p, BIND_PAT)
 }
+
+fn is_literal_pat_start(p: &Parser) -> bool {
+    p.at(T![-]) && (p.nth(1) == INT_NUMBER || p.nth(1) == FLOAT_NUMBER)
+        || p.at_ts(expressions::LITERAL_FIRST)
+}
+
+/// Parses a literal pattern, e.g. `3`, `-1` or `true`
+fn literal_pat(p: &mut Parser) -> CompletedMarker {
+    assert!(is_literal_pat_start(p));
+    let m = p.start();
+    if p.at(T![-]) {
+        p.bump(T![-]);
+    }
+    expressions::literal(p);
+    m.complete(p, LITERAL_PAT)
+}
+
+/// Parses a path pattern (e.g. `Foo::Bar`) or a tuple struct pattern (e.g. `Foo::Bar(a, _)`)
+fn path_pat(p: &mut Parser) -> CompletedMarker {
+    assert!(paths::is_path_start(p));
+    let m = p.start();
+    paths::expr_path(p);
+    let kind = if p.at(T!['(']) {
+        tuple_pat_fields(p);
+        TUPLE_STRUCT_PAT
+    } else {
+        PATH_PAT
+    };
+    m.complete(p, kind)
+}
+
+fn tuple_pat_fields(p: &mut Parser) {
+    assert!(p.at(T!['(']));
+    p.bump(T!['(']);
+    while !p.at(EOF) && !p.at(T![')']) {
+        if !p.at_ts(PATTERN_FIRST) {
+            p.error("expected a pattern");
+            break;
+        }
+        pattern(p);
+        if !p.at(T![')']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T![')']);
+}
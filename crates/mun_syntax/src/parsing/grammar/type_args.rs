@@ -0,0 +1,31 @@
+use super::*;
+
+pub(super) fn opt_type_arg_list(p: &mut Parser) {
+    if p.at(T![<]) {
+        type_arg_list(p);
+    }
+}
+
+fn type_arg_list(p: &mut Parser) {
+    assert!(p.at(T![<]));
+    let m = p.start();
+    p.bump(T![<]);
+    while !p.at(EOF) && !p.at(T![>]) {
+        if !p.at_ts(types::TYPE_FIRST) {
+            p.error("expected type argument");
+            break;
+        }
+        type_arg(p);
+        if !p.at(T![>]) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T![>]);
+    m.complete(p, TYPE_ARG_LIST);
+}
+
+fn type_arg(p: &mut Parser) {
+    let m = p.start();
+    types::type_(p);
+    m.complete(p, TYPE_ARG);
+}
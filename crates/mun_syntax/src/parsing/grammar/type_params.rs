@@ -0,0 +1,54 @@
+use super::*;
+
+pub(super) fn opt_type_param_list(p: &mut Parser) {
+    if p.at(T![<]) {
+        type_param_list(p);
+    }
+}
+
+fn type_param_list(p: &mut Parser) {
+    assert!(p.at(T![<]));
+    let m = p.start();
+    p.bump(T![<]);
+    while !p.at(EOF) && !p.at(T![>]) {
+        if !p.at(IDENT) {
+            p.error("expected type parameter");
+            break;
+        }
+        type_param(p);
+        if !p.at(T![>]) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T![>]);
+    m.complete(p, TYPE_PARAM_LIST);
+}
+
+fn type_param(p: &mut Parser) {
+    assert!(p.at(IDENT));
+    let m = p.start();
+    name(p);
+    if p.at(T![:]) {
+        type_bound_list(p);
+    }
+    m.complete(p, TYPE_PARAM);
+}
+
+fn type_bound_list(p: &mut Parser) {
+    assert!(p.at(T![:]));
+    let m = p.start();
+    p.bump(T![:]);
+    loop {
+        type_bound(p);
+        if !p.eat(T![+]) {
+            break;
+        }
+    }
+    m.complete(p, TYPE_BOUND_LIST);
+}
+
+fn type_bound(p: &mut Parser) {
+    let m = p.start();
+    types::type_(p);
+    m.complete(p, TYPE_BOUND);
+}
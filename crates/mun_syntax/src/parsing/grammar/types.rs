@@ -1,6 +1,7 @@
 use super::*;
 
-pub(super) const TYPE_FIRST: TokenSet = paths::PATH_FIRST.union(token_set![T![never],]);
+pub(super) const TYPE_FIRST: TokenSet =
+    paths::PATH_FIRST.union(token_set![T![never], T!['['], T!['('], T![fn], T![?]]);
 
 pub(super) const TYPE_RECOVERY_SET: TokenSet = token_set![R_PAREN, COMMA];
 
@@ -12,6 +13,10 @@ pub(super) fn ascription(p: &mut Parser) {
 pub(super) fn type_(p: &mut Parser) {
     match p.current() {
         T![never] => never_type(p),
+        T!['['] => array_type(p),
+        T!['('] => paren_or_tuple_type(p),
+        T![fn] => fn_pointer_type(p),
+        T![?] => nullable_type(p),
         _ if paths::is_path_start(p) => path_type(p),
         _ => {
             p.error_recover("expected type", TYPE_RECOVERY_SET);
@@ -31,3 +36,65 @@ fn never_type(p: &mut Parser) {
     p.bump(T![never]);
     m.complete(p, NEVER_TYPE);
 }
+
+/// Parses a parenthesized type (e.g. `(i32)`) or a tuple type (e.g. `()`, `(i32,)` or
+/// `(i32, f32)`)
+fn paren_or_tuple_type(p: &mut Parser) {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.bump(T!['(']);
+    let mut num_types = 0;
+    let mut trailing_comma = false;
+    while !p.at(EOF) && !p.at(T![')']) {
+        num_types += 1;
+        type_(p);
+        if p.eat(T![,]) {
+            trailing_comma = true;
+        } else {
+            trailing_comma = false;
+            break;
+        }
+    }
+    p.expect(T![')']);
+
+    let kind = if num_types == 1 && !trailing_comma {
+        PAREN_TYPE
+    } else {
+        TUPLE_TYPE
+    };
+    m.complete(p, kind);
+}
+
+fn array_type(p: &mut Parser) {
+    assert!(p.at(T!['[']));
+    let m = p.start();
+    p.bump(T!['[']);
+    type_(p);
+    if p.eat(T![;]) {
+        expressions::expr(p);
+    }
+    p.expect(T![']']);
+    m.complete(p, ARRAY_TYPE);
+}
+
+fn fn_pointer_type(p: &mut Parser) {
+    assert!(p.at(T![fn]));
+    let m = p.start();
+    p.bump(T![fn]);
+    if p.at(T!['(']) {
+        params::fn_pointer_param_list(p);
+    } else {
+        p.error("expected parameters");
+    }
+    declarations::opt_fn_ret_type(p);
+    m.complete(p, FN_POINTER_TYPE);
+}
+
+/// Parses a nullable type, e.g. `?Foo`
+fn nullable_type(p: &mut Parser) {
+    assert!(p.at(T![?]));
+    let m = p.start();
+    p.bump(T![?]);
+    type_(p);
+    m.complete(p, NULLABLE_TYPE);
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+pub(super) fn use_(p: &mut Parser, m: Marker) {
+    assert!(p.at(T![use]));
+    p.bump(T![use]);
+    use_tree(p, true);
+    p.expect(T![;]);
+    m.complete(p, USE);
+}
+
+/// Parses a use "tree", such as `foo::bar` in `use foo::bar;`, `foo::*` in `use foo::*;` or
+/// `foo::{bar, baz}` in `use foo::{bar, baz};`.
+fn use_tree(p: &mut Parser, top_level: bool) {
+    let m = p.start();
+    match p.current() {
+        T![*] => p.bump(T![*]),
+        T!['{'] => use_tree_list(p),
+        _ if paths::is_path_start(p) => {
+            paths::use_path(p);
+            match p.current() {
+                T![::] if p.nth(1) == T![*] => {
+                    p.bump(T![::]);
+                    p.bump(T![*]);
+                }
+                T![::] if p.nth(1) == T!['{'] => {
+                    p.bump(T![::]);
+                    use_tree_list(p);
+                }
+                _ => {}
+            }
+        }
+        _ => {
+            m.abandon(p);
+            let msg = "expected one of `*`, `::`, `{`, `self`, `super`, `package` or an identifier";
+            if top_level {
+                p.error_recover(msg, declarations::DECLARATION_RECOVERY_SET);
+            } else {
+                // if we are parsing a nested tree, we have to eat a token to remain balanced `{}`
+                p.error_and_bump(msg);
+            }
+            return;
+        }
+    }
+    m.complete(p, USE_TREE);
+}
+
+/// Parses a list of use trees, e.g. `{bar, baz::*}` in `use foo::{bar, baz::*};`.
+fn use_tree_list(p: &mut Parser) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        use_tree(p, false);
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, USE_TREE_LIST);
+}
@@ -27,7 +27,29 @@ pub fn tokenize(text: &str) -> Vec<Token> {
     let mut text = text;
     let mut result = Vec::new();
     while !text.is_empty() {
-        let token = next_token(text);
+        // The second dot of a range operator (e.g. `0..10`) must not be lexed as the start of a
+        // tuple index (e.g. `.10`). The parser cannot undo this, because the dot is part of the
+        // `INDEX` token.
+        let is_range_dot =
+            text.starts_with('.') && matches!(result.last(), Some(Token { kind: DOT, .. }));
+        let prev_kind = result
+            .iter()
+            .rev()
+            .map(|token| token.kind)
+            .find(|kind| !kind.is_trivia());
+        let token = if is_range_dot {
+            Token {
+                kind: DOT,
+                len: TextUnit::from_usize(1),
+            }
+        } else if let Some(len) = scan_label(text, prev_kind) {
+            Token {
+                kind: LABEL_IDENT,
+                len,
+            }
+        } else {
+            next_token(text)
+        };
         result.push(token);
         let len: u32 = token.len.into();
         text = &text[len as usize..];
@@ -35,6 +57,38 @@ pub fn tokenize(text: &str) -> Vec<Token> {
     result
 }
 
+/// Scans a loop label (e.g. `'outer`) at the start of `text` and returns its length. Single-quoted
+/// strings also start with a `'`, so a label is only recognized where it can occur: after a `break`
+/// or `continue` keyword, which is the token of kind `prev_kind`, or when it is followed by a colon
+/// and a loop (e.g. `'outer: loop`).
+fn scan_label(text: &str, prev_kind: Option<SyntaxKind>) -> Option<TextUnit> {
+    let ident = text.strip_prefix('\'')?;
+    if !ident.starts_with(is_ident_start) {
+        return None;
+    }
+    let ident_len = ident.find(|c| !is_ident_continue(c)).unwrap_or(ident.len());
+    let rest = &ident[ident_len..];
+
+    // A string like `'a'` is not a label
+    if rest.starts_with('\'') {
+        return None;
+    }
+
+    let is_label = match prev_kind {
+        Some(BREAK_KW) | Some(CONTINUE_KW) => true,
+        _ => rest.strip_prefix(':').map_or(false, |rest| {
+            let rest = rest.trim_start_matches(is_whitespace);
+            let keyword_len = rest.find(|c| !is_ident_continue(c)).unwrap_or(rest.len());
+            matches!(&rest[..keyword_len], "loop" | "while" | "for")
+        }),
+    };
+    if is_label {
+        Some(TextUnit::from_usize(1 + ident_len))
+    } else {
+        None
+    }
+}
+
 /// Get the next token from a string
 pub fn next_token(text: &str) -> Token {
     assert!(!text.is_empty());
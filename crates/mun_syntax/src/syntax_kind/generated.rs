@@ -41,6 +41,7 @@ pub enum SyntaxKind {
     COLON,
     COMMA,
     EXCLAMATION,
+    QUESTION,
     UNDERSCORE,
     EQEQ,
     NEQ,
@@ -61,6 +62,7 @@ pub enum SyntaxKind {
     DOTDOTEQ,
     COLONCOLON,
     THIN_ARROW,
+    FAT_ARROW,
     AMPAMP,
     PIPEPIPE,
     SHL,
@@ -78,23 +80,33 @@ pub enum SyntaxKind {
     TRUE_KW,
     WHILE_KW,
     LOOP_KW,
+    CONTINUE_KW,
     LET_KW,
     MUT_KW,
     CLASS_KW,
     STRUCT_KW,
+    ENUM_KW,
+    IMPL_KW,
+    TRAIT_KW,
+    MATCH_KW,
     NEVER_KW,
     PUB_KW,
     TYPE_KW,
+    USE_KW,
     PACKAGE_KW,
     SUPER_KW,
     SELF_KW,
     EXTERN_KW,
+    CONST_KW,
+    STATIC_KW,
+    AS_KW,
     INT_NUMBER,
     FLOAT_NUMBER,
     STRING,
     ERROR,
     IDENT,
     INDEX,
+    LABEL_IDENT,
     WHITESPACE,
     COMMENT,
     GC_KW,
@@ -106,15 +118,35 @@ pub enum SyntaxKind {
     VISIBILITY,
     PARAM_LIST,
     PARAM,
+    SELF_PARAM,
     STRUCT_DEF,
     TYPE_ALIAS_DEF,
+    CONST_DEF,
+    STATIC_DEF,
     MEMORY_TYPE_SPECIFIER,
     RECORD_FIELD_DEF_LIST,
     RECORD_FIELD_DEF,
     TUPLE_FIELD_DEF_LIST,
     TUPLE_FIELD_DEF,
+    ENUM_DEF,
+    ENUM_VARIANT_LIST,
+    ENUM_VARIANT,
+    IMPL_DEF,
+    TRAIT_DEF,
+    ITEM_LIST,
     PATH_TYPE,
     NEVER_TYPE,
+    ARRAY_TYPE,
+    FN_POINTER_TYPE,
+    PAREN_TYPE,
+    TUPLE_TYPE,
+    NULLABLE_TYPE,
+    TYPE_PARAM_LIST,
+    TYPE_PARAM,
+    TYPE_BOUND_LIST,
+    TYPE_BOUND,
+    TYPE_ARG_LIST,
+    TYPE_ARG,
     LET_STMT,
     EXPR_STMT,
     PATH_EXPR,
@@ -122,25 +154,49 @@ pub enum SyntaxKind {
     LITERAL,
     BIN_EXPR,
     PAREN_EXPR,
+    TUPLE_EXPR,
     CALL_EXPR,
     FIELD_EXPR,
+    METHOD_CALL_EXPR,
+    INDEX_EXPR,
+    ARRAY_EXPR,
     IF_EXPR,
     BLOCK_EXPR,
     RETURN_EXPR,
     WHILE_EXPR,
     LOOP_EXPR,
+    FOR_EXPR,
+    MATCH_EXPR,
+    MATCH_ARM_LIST,
+    MATCH_ARM,
     BREAK_EXPR,
+    CONTINUE_EXPR,
+    LABEL,
+    RANGE_EXPR,
+    LAMBDA_EXPR,
+    NIL_EXPR,
+    CAST_EXPR,
     CONDITION,
     BIND_PAT,
     PLACEHOLDER_PAT,
+    PATH_PAT,
+    TUPLE_STRUCT_PAT,
+    TUPLE_PAT,
+    PAREN_PAT,
+    LITERAL_PAT,
     ARG_LIST,
     NAME,
     NAME_REF,
     PATH,
     PATH_SEGMENT,
+    USE,
+    USE_TREE,
+    USE_TREE_LIST,
     RECORD_LIT,
     RECORD_FIELD_LIST,
     RECORD_FIELD,
+    ATTR,
+    TOKEN_TREE,
     // Technical kind so that we can cast from u16 safely
     #[doc(hidden)]
     __LAST,
@@ -218,6 +274,9 @@ macro_rules! T {
     (!) => {
         $crate::SyntaxKind::EXCLAMATION
     };
+    (?) => {
+        $crate::SyntaxKind::QUESTION
+    };
     (_) => {
         $crate::SyntaxKind::UNDERSCORE
     };
@@ -278,6 +337,9 @@ macro_rules! T {
     (->) => {
         $crate::SyntaxKind::THIN_ARROW
     };
+    (=>) => {
+        $crate::SyntaxKind::FAT_ARROW
+    };
     (&&) => {
         $crate::SyntaxKind::AMPAMP
     };
@@ -329,6 +391,9 @@ macro_rules! T {
     (loop) => {
         $crate::SyntaxKind::LOOP_KW
     };
+    (continue) => {
+        $crate::SyntaxKind::CONTINUE_KW
+    };
     (let) => {
         $crate::SyntaxKind::LET_KW
     };
@@ -341,6 +406,18 @@ macro_rules! T {
     (struct) => {
         $crate::SyntaxKind::STRUCT_KW
     };
+    (enum) => {
+        $crate::SyntaxKind::ENUM_KW
+    };
+    (impl) => {
+        $crate::SyntaxKind::IMPL_KW
+    };
+    (trait) => {
+        $crate::SyntaxKind::TRAIT_KW
+    };
+    (match) => {
+        $crate::SyntaxKind::MATCH_KW
+    };
     (never) => {
         $crate::SyntaxKind::NEVER_KW
     };
@@ -350,6 +427,9 @@ macro_rules! T {
     (type) => {
         $crate::SyntaxKind::TYPE_KW
     };
+    (use) => {
+        $crate::SyntaxKind::USE_KW
+    };
     (package) => {
         $crate::SyntaxKind::PACKAGE_KW
     };
@@ -362,6 +442,15 @@ macro_rules! T {
     (extern) => {
         $crate::SyntaxKind::EXTERN_KW
     };
+    (const) => {
+        $crate::SyntaxKind::CONST_KW
+    };
+    (static) => {
+        $crate::SyntaxKind::STATIC_KW
+    };
+    (as) => {
+        $crate::SyntaxKind::AS_KW
+    };
 }
 
 impl From<u16> for SyntaxKind {
@@ -394,17 +483,26 @@ impl SyntaxKind {
         | TRUE_KW
         | WHILE_KW
         | LOOP_KW
+        | CONTINUE_KW
         | LET_KW
         | MUT_KW
         | CLASS_KW
         | STRUCT_KW
+        | ENUM_KW
+        | IMPL_KW
+        | TRAIT_KW
+        | MATCH_KW
         | NEVER_KW
         | PUB_KW
         | TYPE_KW
+        | USE_KW
         | PACKAGE_KW
         | SUPER_KW
         | SELF_KW
         | EXTERN_KW
+        | CONST_KW
+        | STATIC_KW
+        | AS_KW
         )
     }
 
@@ -434,6 +532,7 @@ impl SyntaxKind {
         | COLON
         | COMMA
         | EXCLAMATION
+        | QUESTION
         | UNDERSCORE
         | EQEQ
         | NEQ
@@ -454,6 +553,7 @@ impl SyntaxKind {
         | DOTDOTEQ
         | COLONCOLON
         | THIN_ARROW
+        | FAT_ARROW
         | AMPAMP
         | PIPEPIPE
         | SHL
@@ -496,6 +596,7 @@ impl SyntaxKind {
             COLON => &SyntaxInfo { name: "COLON" },
             COMMA => &SyntaxInfo { name: "COMMA" },
             EXCLAMATION => &SyntaxInfo { name: "EXCLAMATION" },
+            QUESTION => &SyntaxInfo { name: "QUESTION" },
             UNDERSCORE => &SyntaxInfo { name: "UNDERSCORE" },
             EQEQ => &SyntaxInfo { name: "EQEQ" },
             NEQ => &SyntaxInfo { name: "NEQ" },
@@ -516,6 +617,7 @@ impl SyntaxKind {
             DOTDOTEQ => &SyntaxInfo { name: "DOTDOTEQ" },
             COLONCOLON => &SyntaxInfo { name: "COLONCOLON" },
             THIN_ARROW => &SyntaxInfo { name: "THIN_ARROW" },
+            FAT_ARROW => &SyntaxInfo { name: "FAT_ARROW" },
             AMPAMP => &SyntaxInfo { name: "AMPAMP" },
             PIPEPIPE => &SyntaxInfo { name: "PIPEPIPE" },
             SHL => &SyntaxInfo { name: "SHL" },
@@ -533,23 +635,33 @@ impl SyntaxKind {
             TRUE_KW => &SyntaxInfo { name: "TRUE_KW" },
             WHILE_KW => &SyntaxInfo { name: "WHILE_KW" },
             LOOP_KW => &SyntaxInfo { name: "LOOP_KW" },
+            CONTINUE_KW => &SyntaxInfo { name: "CONTINUE_KW" },
             LET_KW => &SyntaxInfo { name: "LET_KW" },
             MUT_KW => &SyntaxInfo { name: "MUT_KW" },
             CLASS_KW => &SyntaxInfo { name: "CLASS_KW" },
             STRUCT_KW => &SyntaxInfo { name: "STRUCT_KW" },
+            ENUM_KW => &SyntaxInfo { name: "ENUM_KW" },
+            IMPL_KW => &SyntaxInfo { name: "IMPL_KW" },
+            TRAIT_KW => &SyntaxInfo { name: "TRAIT_KW" },
+            MATCH_KW => &SyntaxInfo { name: "MATCH_KW" },
             NEVER_KW => &SyntaxInfo { name: "NEVER_KW" },
             PUB_KW => &SyntaxInfo { name: "PUB_KW" },
             TYPE_KW => &SyntaxInfo { name: "TYPE_KW" },
+            USE_KW => &SyntaxInfo { name: "USE_KW" },
             PACKAGE_KW => &SyntaxInfo { name: "PACKAGE_KW" },
             SUPER_KW => &SyntaxInfo { name: "SUPER_KW" },
             SELF_KW => &SyntaxInfo { name: "SELF_KW" },
             EXTERN_KW => &SyntaxInfo { name: "EXTERN_KW" },
+            CONST_KW => &SyntaxInfo { name: "CONST_KW" },
+            STATIC_KW => &SyntaxInfo { name: "STATIC_KW" },
+            AS_KW => &SyntaxInfo { name: "AS_KW" },
             INT_NUMBER => &SyntaxInfo { name: "INT_NUMBER" },
             FLOAT_NUMBER => &SyntaxInfo { name: "FLOAT_NUMBER" },
             STRING => &SyntaxInfo { name: "STRING" },
             ERROR => &SyntaxInfo { name: "ERROR" },
             IDENT => &SyntaxInfo { name: "IDENT" },
             INDEX => &SyntaxInfo { name: "INDEX" },
+            LABEL_IDENT => &SyntaxInfo { name: "LABEL_IDENT" },
             WHITESPACE => &SyntaxInfo { name: "WHITESPACE" },
             COMMENT => &SyntaxInfo { name: "COMMENT" },
             GC_KW => &SyntaxInfo { name: "GC_KW" },
@@ -561,15 +673,35 @@ impl SyntaxKind {
             VISIBILITY => &SyntaxInfo { name: "VISIBILITY" },
             PARAM_LIST => &SyntaxInfo { name: "PARAM_LIST" },
             PARAM => &SyntaxInfo { name: "PARAM" },
+            SELF_PARAM => &SyntaxInfo { name: "SELF_PARAM" },
             STRUCT_DEF => &SyntaxInfo { name: "STRUCT_DEF" },
             TYPE_ALIAS_DEF => &SyntaxInfo { name: "TYPE_ALIAS_DEF" },
+            CONST_DEF => &SyntaxInfo { name: "CONST_DEF" },
+            STATIC_DEF => &SyntaxInfo { name: "STATIC_DEF" },
             MEMORY_TYPE_SPECIFIER => &SyntaxInfo { name: "MEMORY_TYPE_SPECIFIER" },
             RECORD_FIELD_DEF_LIST => &SyntaxInfo { name: "RECORD_FIELD_DEF_LIST" },
             RECORD_FIELD_DEF => &SyntaxInfo { name: "RECORD_FIELD_DEF" },
             TUPLE_FIELD_DEF_LIST => &SyntaxInfo { name: "TUPLE_FIELD_DEF_LIST" },
             TUPLE_FIELD_DEF => &SyntaxInfo { name: "TUPLE_FIELD_DEF" },
+            ENUM_DEF => &SyntaxInfo { name: "ENUM_DEF" },
+            ENUM_VARIANT_LIST => &SyntaxInfo { name: "ENUM_VARIANT_LIST" },
+            ENUM_VARIANT => &SyntaxInfo { name: "ENUM_VARIANT" },
+            IMPL_DEF => &SyntaxInfo { name: "IMPL_DEF" },
+            TRAIT_DEF => &SyntaxInfo { name: "TRAIT_DEF" },
+            ITEM_LIST => &SyntaxInfo { name: "ITEM_LIST" },
             PATH_TYPE => &SyntaxInfo { name: "PATH_TYPE" },
             NEVER_TYPE => &SyntaxInfo { name: "NEVER_TYPE" },
+            ARRAY_TYPE => &SyntaxInfo { name: "ARRAY_TYPE" },
+            FN_POINTER_TYPE => &SyntaxInfo { name: "FN_POINTER_TYPE" },
+            PAREN_TYPE => &SyntaxInfo { name: "PAREN_TYPE" },
+            TUPLE_TYPE => &SyntaxInfo { name: "TUPLE_TYPE" },
+            NULLABLE_TYPE => &SyntaxInfo { name: "NULLABLE_TYPE" },
+            TYPE_PARAM_LIST => &SyntaxInfo { name: "TYPE_PARAM_LIST" },
+            TYPE_PARAM => &SyntaxInfo { name: "TYPE_PARAM" },
+            TYPE_BOUND_LIST => &SyntaxInfo { name: "TYPE_BOUND_LIST" },
+            TYPE_BOUND => &SyntaxInfo { name: "TYPE_BOUND" },
+            TYPE_ARG_LIST => &SyntaxInfo { name: "TYPE_ARG_LIST" },
+            TYPE_ARG => &SyntaxInfo { name: "TYPE_ARG" },
             LET_STMT => &SyntaxInfo { name: "LET_STMT" },
             EXPR_STMT => &SyntaxInfo { name: "EXPR_STMT" },
             PATH_EXPR => &SyntaxInfo { name: "PATH_EXPR" },
@@ -577,25 +709,49 @@ impl SyntaxKind {
             LITERAL => &SyntaxInfo { name: "LITERAL" },
             BIN_EXPR => &SyntaxInfo { name: "BIN_EXPR" },
             PAREN_EXPR => &SyntaxInfo { name: "PAREN_EXPR" },
+            TUPLE_EXPR => &SyntaxInfo { name: "TUPLE_EXPR" },
             CALL_EXPR => &SyntaxInfo { name: "CALL_EXPR" },
             FIELD_EXPR => &SyntaxInfo { name: "FIELD_EXPR" },
+            METHOD_CALL_EXPR => &SyntaxInfo { name: "METHOD_CALL_EXPR" },
+            INDEX_EXPR => &SyntaxInfo { name: "INDEX_EXPR" },
+            ARRAY_EXPR => &SyntaxInfo { name: "ARRAY_EXPR" },
             IF_EXPR => &SyntaxInfo { name: "IF_EXPR" },
             BLOCK_EXPR => &SyntaxInfo { name: "BLOCK_EXPR" },
             RETURN_EXPR => &SyntaxInfo { name: "RETURN_EXPR" },
             WHILE_EXPR => &SyntaxInfo { name: "WHILE_EXPR" },
             LOOP_EXPR => &SyntaxInfo { name: "LOOP_EXPR" },
+            FOR_EXPR => &SyntaxInfo { name: "FOR_EXPR" },
+            MATCH_EXPR => &SyntaxInfo { name: "MATCH_EXPR" },
+            MATCH_ARM_LIST => &SyntaxInfo { name: "MATCH_ARM_LIST" },
+            MATCH_ARM => &SyntaxInfo { name: "MATCH_ARM" },
             BREAK_EXPR => &SyntaxInfo { name: "BREAK_EXPR" },
+            CONTINUE_EXPR => &SyntaxInfo { name: "CONTINUE_EXPR" },
+            LABEL => &SyntaxInfo { name: "LABEL" },
+            RANGE_EXPR => &SyntaxInfo { name: "RANGE_EXPR" },
+            LAMBDA_EXPR => &SyntaxInfo { name: "LAMBDA_EXPR" },
+            NIL_EXPR => &SyntaxInfo { name: "NIL_EXPR" },
+            CAST_EXPR => &SyntaxInfo { name: "CAST_EXPR" },
             CONDITION => &SyntaxInfo { name: "CONDITION" },
             BIND_PAT => &SyntaxInfo { name: "BIND_PAT" },
             PLACEHOLDER_PAT => &SyntaxInfo { name: "PLACEHOLDER_PAT" },
+            PATH_PAT => &SyntaxInfo { name: "PATH_PAT" },
+            TUPLE_STRUCT_PAT => &SyntaxInfo { name: "TUPLE_STRUCT_PAT" },
+            TUPLE_PAT => &SyntaxInfo { name: "TUPLE_PAT" },
+            PAREN_PAT => &SyntaxInfo { name: "PAREN_PAT" },
+            LITERAL_PAT => &SyntaxInfo { name: "LITERAL_PAT" },
             ARG_LIST => &SyntaxInfo { name: "ARG_LIST" },
             NAME => &SyntaxInfo { name: "NAME" },
             NAME_REF => &SyntaxInfo { name: "NAME_REF" },
             PATH => &SyntaxInfo { name: "PATH" },
             PATH_SEGMENT => &SyntaxInfo { name: "PATH_SEGMENT" },
+            USE => &SyntaxInfo { name: "USE" },
+            USE_TREE => &SyntaxInfo { name: "USE_TREE" },
+            USE_TREE_LIST => &SyntaxInfo { name: "USE_TREE_LIST" },
             RECORD_LIT => &SyntaxInfo { name: "RECORD_LIT" },
             RECORD_FIELD_LIST => &SyntaxInfo { name: "RECORD_FIELD_LIST" },
             RECORD_FIELD => &SyntaxInfo { name: "RECORD_FIELD" },
+            ATTR => &SyntaxInfo { name: "ATTR" },
+            TOKEN_TREE => &SyntaxInfo { name: "TOKEN_TREE" },
             TOMBSTONE => &SyntaxInfo { name: "TOMBSTONE" },
             EOF => &SyntaxInfo { name: "EOF" },
             __LAST => &SyntaxInfo { name: "__LAST" },
@@ -617,17 +773,26 @@ impl SyntaxKind {
             "true" => TRUE_KW,
             "while" => WHILE_KW,
             "loop" => LOOP_KW,
+            "continue" => CONTINUE_KW,
             "let" => LET_KW,
             "mut" => MUT_KW,
             "class" => CLASS_KW,
             "struct" => STRUCT_KW,
+            "enum" => ENUM_KW,
+            "impl" => IMPL_KW,
+            "trait" => TRAIT_KW,
+            "match" => MATCH_KW,
             "never" => NEVER_KW,
             "pub" => PUB_KW,
             "type" => TYPE_KW,
+            "use" => USE_KW,
             "package" => PACKAGE_KW,
             "super" => SUPER_KW,
             "self" => SELF_KW,
             "extern" => EXTERN_KW,
+            "const" => CONST_KW,
+            "static" => STATIC_KW,
+            "as" => AS_KW,
             _ => return None,
         };
         Some(kw)
@@ -658,6 +823,7 @@ impl SyntaxKind {
             ':' => COLON,
             ',' => COMMA,
             '!' => EXCLAMATION,
+            '?' => QUESTION,
             '_' => UNDERSCORE,
             _ => return None,
         };
@@ -37,6 +37,17 @@ fn numbers() {
     )
 }
 
+#[test]
+fn ranges() {
+    lex_snapshot(
+        r#"
+    1..2
+    1.0..2.0
+    a.0..b
+    1. .2"#,
+    )
+}
+
 #[test]
 fn comments() {
     lex_snapshot(
@@ -123,6 +134,20 @@ fn keywords() {
     )
 }
 
+#[test]
+fn labels() {
+    lex_snapshot(
+        r#"
+    'outer: loop
+    'inner:for
+    break 'outer
+    continue 'inner;
+    break 'a'
+    'a' 'b: c'
+    "#,
+    )
+}
+
 #[test]
 fn unclosed_string() {
     lex_snapshot(
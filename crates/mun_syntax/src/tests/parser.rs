@@ -80,6 +80,50 @@ fn struct_def() {
     )
 }
 
+#[test]
+fn enum_def() {
+    snapshot_test(
+        r#"
+    enum Foo {}
+    enum Bar { A, B, }
+    pub enum Baz {
+        A(i32, f64),
+        B
+    }
+    "#,
+    )
+}
+
+#[test]
+fn impl_def() {
+    snapshot_test(
+        r#"
+    impl Foo {}
+    impl Foo {
+        pub fn new() -> Self {}
+        fn bar(self, a: i32) -> i32 {
+            self.a
+        }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn trait_def() {
+    snapshot_test(
+        r#"
+    trait Foo {}
+    pub trait Bar {
+        fn bar(self, a: i32);
+        fn baz(self) -> i32 { 0 }
+    }
+    impl Bar for Foo {}
+    fn foo<T: Foo + Bar>(a: T) {}
+    "#,
+    )
+}
+
 #[test]
 fn unary_expr() {
     snapshot_test(
@@ -286,6 +330,77 @@ fn while_expr() {
     )
 }
 
+#[test]
+fn condition_let() {
+    snapshot_test(
+        r#"
+    fn foo() {
+        if let Foo::A(x) = a {} else {};
+        while let (x, _) = b {};
+    }
+    "#,
+    )
+}
+
+#[test]
+fn for_expr() {
+    snapshot_test(
+        r#"
+    fn foo() {
+        for i in 0..10 {};
+        for _ in a..=b { break; }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn mut_bindings() {
+    snapshot_test(
+        r#"
+    fn foo(mut a: i32) {
+        let mut b = a;
+        let (mut c, d) = (b, 1);
+    }
+    impl Foo {
+        fn bar(mut self) {}
+    }
+    "#,
+    )
+}
+
+#[test]
+fn match_expr() {
+    snapshot_test(
+        r#"
+    fn foo(a: Foo) {
+        match a {
+            Foo::A => 1,
+            Foo::B(x, _) => { x }
+            Foo::C(-2, true) => -1,
+            Bar(3) => 2,
+            _ => 0
+        };
+        match a {}
+    }
+    "#,
+    )
+}
+
+#[test]
+fn arrays() {
+    snapshot_test(
+        r#"
+    fn foo(a: [i32; 3], b: [Foo]) {
+        let c = [1, 2, 3,];
+        let d: [f64] = [];
+        a[0] + b[c[1]].x;
+        a.len();
+    }
+    "#,
+    )
+}
+
 #[test]
 fn struct_lit() {
     snapshot_test(
@@ -365,3 +480,144 @@ fn type_alias_def() {
     "#,
     )
 }
+
+#[test]
+fn generics() {
+    snapshot_test(
+        r#"
+    fn max<T>(a: T, b: T) -> T {}
+    struct Pair<T, U> { a: T, b: U }
+    struct(gc) Wrapper<T>(T);
+    fn foo(a: Pair<i32, Wrapper<bool>>, b: Wrapper<[f32]>) {}
+    "#,
+    )
+}
+
+#[test]
+fn closures() {
+    snapshot_test(
+        r#"
+    fn foo(f: fn(i32) -> i32, g: fn()) {
+        let a = || 1;
+        let b = |x| x + 1;
+        let c = |x: i32, y| -> i32 { x * y };
+    }
+    "#,
+    )
+}
+
+#[test]
+fn tuples() {
+    snapshot_test(
+        r#"
+    fn foo(a: (i32, f32), b: (), c: (i32,), d: (i32)) -> (i32, (f32, bool)) {
+        let (x, _) = a;
+        let (y,) = (1,);
+        let (z) = ();
+        let w = (x, (2.0, true));
+        (w.0, w.1.0)
+    }
+    "#,
+    )
+}
+
+#[test]
+fn use_() {
+    snapshot_test(
+        r#"
+    use foo;
+    pub use package::physics::integrate;
+    use super::*;
+    use self::bar::{baz, qux::*};
+    pub type Foo = package::Bar;
+    fn main() {
+        super::foo();
+    }
+    "#,
+    )
+}
+
+#[test]
+fn const_and_static_def() {
+    snapshot_test(
+        r#"
+    const GRAVITY: f32 = 9.81;
+    pub const MIN: i32 = -5;
+    static mut COUNTER: i32 = 0;
+    pub static ORIGIN: Vec2 = Vec2 { x: 0.0, y: 0.0 };
+    "#,
+    )
+}
+
+#[test]
+fn nullable() {
+    snapshot_test(
+        r#"
+    fn foo(a: ?Foo) -> ?Foo {
+        let b: ?Foo = nil;
+        nil
+    }
+    "#,
+    )
+}
+
+#[test]
+fn cast() {
+    snapshot_test(
+        r#"
+    fn foo(a: f32, b: i32) {
+        let c = a as i32 + b * 2 as u8;
+        let d = -a as u64;
+        let e = (b as f64) as f32 as i8;
+    }
+    "#,
+    )
+}
+
+#[test]
+fn const_fn() {
+    snapshot_test(
+        r#"
+    const fn square(x: i32) -> i32 { x * x }
+    pub const fn cube(x: i32) -> i32 { x * square(x) }
+    const SIZE: i32 = cube(2);
+    impl Foo {
+        const fn new() -> Self { Foo }
+    }
+    "#,
+    )
+}
+
+#[test]
+fn labeled_loops() {
+    snapshot_test(
+        r#"
+    fn foo() {
+        'outer: loop {
+            while true {
+                continue 'outer;
+            }
+            break 'outer 5;
+        }
+        'a: for i in 0..3 { break 'a; }
+        let b = loop { continue };
+    }
+    "#,
+    )
+}
+
+#[test]
+fn attributes() {
+    snapshot_test(
+        r#"
+    #[allow(dead_code)]
+    fn foo() {
+        #[allow(unreachable_code)] {}
+    }
+    impl Foo {
+        #[deny(unused_variables)]
+        fn bar() {}
+    }
+    "#,
+    )
+}
@@ -1,4 +1,5 @@
-use compiler::{Config, DisplayColor, Driver, FileId, PathOrInline, RelativePathBuf};
+use crate::Fixture;
+use compiler::{Config, DisplayColor, Driver, FileId, PathOrInline};
 use runtime::{Runtime, RuntimeBuilder};
 use std::{
     cell::{Ref, RefCell},
@@ -19,19 +20,38 @@ pub struct CompileTestDriver {
 }
 
 impl CompileTestDriver {
-    /// Constructs a new `CompileTestDriver` from a single Mun source.
+    /// Constructs a new `CompileTestDriver` from a single Mun source, or from multiple Mun sources
+    /// that are described by a [`Fixture`]. The generated library is that of `main.mun`.
     pub fn new(text: &str) -> Self {
+        Self::with_config(text, |config| config)
+    }
+
+    /// Constructs a new `CompileTestDriver` like [`CompileTestDriver::new`], of which the
+    /// compiler [`Config`] is modified by `config_fn`, e.g. to disable arithmetic checks.
+    pub fn with_config(text: &str, config_fn: impl FnOnce(Config) -> Config) -> Self {
         let temp_dir = tempfile::TempDir::new().unwrap();
-        let config = Config {
+        let config = config_fn(Config {
             out_dir: Some(temp_dir.path().to_path_buf()),
+            arithmetic_checks: true,
             display_color: DisplayColor::Disable,
             ..Config::default()
-        };
+        });
+
+        let mut fixtures = Fixture::parse(text);
+        let main_idx = fixtures
+            .iter()
+            .position(|fixture| fixture.relative_path == "main.mun")
+            .unwrap_or(0);
+        let main = fixtures.remove(main_idx);
         let input = PathOrInline::Inline {
-            rel_path: RelativePathBuf::from("main.mun"),
-            contents: text.to_owned(),
+            rel_path: main.relative_path,
+            contents: main.text,
         };
         let (mut driver, file_id) = Driver::with_file(config, input).unwrap();
+        for fixture in fixtures {
+            driver.add_file(fixture.relative_path, fixture.text);
+        }
+
         let mut compiler_errors: Vec<u8> = Vec::new();
         if driver
             .emit_diagnostics(&mut Cursor::new(&mut compiler_errors))
@@ -44,7 +64,7 @@ impl CompileTestDriver {
             )
         }
         let out_path = driver.assembly_output_path(file_id);
-        driver.write_assembly(file_id, true).unwrap();
+        driver.write_all_assemblies().unwrap();
         CompileTestDriver {
             _temp_dir: temp_dir,
             driver,
@@ -117,7 +137,17 @@ impl CompileAndRunTestDriver {
         text: &str,
         config_fn: impl FnOnce(RuntimeBuilder) -> RuntimeBuilder,
     ) -> Result<Self, anyhow::Error> {
-        let driver = CompileTestDriver::new(text);
+        Self::with_compiler_config(text, |config| config, config_fn)
+    }
+
+    /// Constructs a `CompileAndRunTestDriver` like [`CompileAndRunTestDriver::new`], of which the
+    /// compiler [`Config`] is modified by `compiler_config_fn`.
+    pub fn with_compiler_config(
+        text: &str,
+        compiler_config_fn: impl FnOnce(Config) -> Config,
+        config_fn: impl FnOnce(RuntimeBuilder) -> RuntimeBuilder,
+    ) -> Result<Self, anyhow::Error> {
+        let driver = CompileTestDriver::with_config(text, compiler_config_fn);
         let builder = RuntimeBuilder::new(driver.lib_path());
         let runtime = config_fn(builder).spawn()?;
 